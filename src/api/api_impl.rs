@@ -157,7 +157,11 @@ impl Oxidb {
                     DataType::JsonBlob(json_val) => serde_json::to_string(&json_val)
                         .unwrap_or_else(|e| format!("Error serializing JsonBlob: {}", e)),
                     DataType::RawBytes(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+                    DataType::Decimal { unscaled, scale, .. } => {
+                        crate::core::types::decimal::format_decimal(unscaled, scale)
+                    }
                     DataType::Vector(_) => todo!("Handle DataType::Vector in Oxidb::get"),
+                    DataType::Enum { value, .. } => value,
                 }))
             }
             Ok(unexpected_result) => Err(OxidbError::Internal(format!(
@@ -13,8 +13,8 @@ use crate::core::storage::engine::FileKvStore;
 use crate::core::wal::log_manager::LogManager;
 use crate::core::wal::writer::WalWriter;
 use std::path::Path;
-use std::sync::Arc;
-use std::time::Duration;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
 use std::path::PathBuf;
 
 /// A database connection that provides an ergonomic API for database operations.
@@ -28,10 +28,26 @@ pub struct Connection {
     executor: QueryExecutor<FileKvStore>,
     /// Performance monitoring context
     performance: PerformanceContext,
+    /// User-defined scalar and aggregate functions registered via
+    /// [`Connection::create_scalar_function`]/[`Connection::create_aggregate_function`].
+    functions: crate::api::functions::FunctionRegistry,
+    /// Fired with a statement's SQL text just before it's parsed and executed, set via
+    /// [`Connection::trace`].
+    trace_callback: Option<fn(&str)>,
+    /// Fired with a statement's SQL text and wall-clock execution time once it completes,
+    /// set via [`Connection::profile`].
+    profile_callback: Option<fn(&str, Duration)>,
+    /// Parsed statements from past [`Connection::prepare`] calls, keyed by the exact SQL
+    /// text passed in, so preparing the same SQL text again skips re-tokenizing and
+    /// re-parsing it. Cleared by nothing yet - callers that `prepare` a huge number of
+    /// distinct statements over a connection's lifetime will grow this unbounded, the same
+    /// tradeoff rusqlite's own statement cache makes before `set_prepared_statement_cache_capacity`.
+    pub(crate) statement_cache:
+        std::collections::HashMap<String, Arc<crate::api::statement::CachedStatement>>,
 }
 
 // Helper function to convert DataType to Value
-fn data_type_to_value(data_type: crate::core::types::DataType) -> Value {
+pub(super) fn data_type_to_value(data_type: crate::core::types::DataType) -> Value {
     use crate::core::types::DataType;
     
     match data_type {
@@ -46,6 +62,51 @@ fn data_type_to_value(data_type: crate::core::types::DataType) -> Value {
             serde_json::to_string(&map.0).unwrap_or_else(|_| "{}".to_string())
         ),
         DataType::JsonBlob(json) => Value::Text(json.0.to_string()),
+        DataType::Decimal { unscaled, scale, .. } => {
+            Value::Text(crate::core::types::decimal::format_decimal(unscaled, scale))
+        }
+        DataType::Enum { value, .. } => Value::Text(value),
+    }
+}
+
+/// The number of rows a statement affected, for `PerformanceMetrics::record_query`'s
+/// `rows_affected` - a query's returned row count for `Query`/`Values`/`RankedResults`,
+/// the reported count for `Updated`, `1`/`0` for `Deleted`, and `0` for anything else.
+fn execution_result_rows_affected(result: &crate::core::query::executor::ExecutionResult) -> u64 {
+    use crate::core::query::executor::ExecutionResult;
+    match result {
+        ExecutionResult::Query { rows, .. } => rows.len() as u64,
+        ExecutionResult::Updated { count } => *count as u64,
+        ExecutionResult::Deleted(success) => u64::from(*success),
+        ExecutionResult::Values(values) => values.len() as u64,
+        ExecutionResult::RankedResults(results) => results.len() as u64,
+        ExecutionResult::Value(_)
+        | ExecutionResult::Success
+        | ExecutionResult::Describe(_)
+        | ExecutionResult::Batch(_)
+        | ExecutionResult::Explain(_) => 0,
+    }
+}
+
+// Helper function to convert Value back to DataType, the reverse of `data_type_to_value`.
+// Used to hand a user-defined function's `Value` result back to the engine as the
+// `DataType` its callers (e.g. a SELECT list or WHERE clause) expect.
+pub(super) fn value_to_data_type(value: Value) -> crate::core::types::DataType {
+    use crate::core::types::DataType;
+
+    match value {
+        Value::Integer(i) => DataType::Integer(i),
+        Value::Float(f) => DataType::Float(crate::core::types::OrderedFloat(f)),
+        Value::Text(s) => DataType::String(s),
+        Value::Boolean(b) => DataType::Boolean(b),
+        Value::Blob(b) => DataType::RawBytes(b),
+        Value::Vector(v) => {
+            let dimension = v.len() as u32;
+            DataType::Vector(crate::core::types::HashableVectorData(
+                crate::core::types::VectorData { dimension, data: v },
+            ))
+        }
+        Value::Null => DataType::Null,
     }
 }
 
@@ -108,7 +169,14 @@ impl Connection {
         let executor = QueryExecutor::new(store, config.index_path(), tm_wal_writer, log_manager)?;
         let performance = PerformanceContext::new();
 
-        Ok(Self { executor, performance })
+        Ok(Self {
+            executor,
+            performance,
+            functions: crate::api::functions::FunctionRegistry::default(),
+            trace_callback: None,
+            profile_callback: None,
+            statement_cache: std::collections::HashMap::new(),
+        })
     }
 
     /// Enables performance monitoring for this connection.
@@ -120,6 +188,30 @@ impl Connection {
         self.performance.config.enable_profiling = true;
         self.performance.config.enable_monitoring = true;
         self.performance.config.slow_query_threshold = Duration::from_millis(100);
+        // So planning decisions (index scan vs. full table scan) append
+        // `ProfileEvent`s to the same log `profiler_events`/`get_performance_report` read.
+        self.executor.attach_profile_events(self.performance.events.clone());
+    }
+
+    /// Every discrete [`crate::core::performance::ProfileEvent`] recorded since this
+    /// connection was opened (or since the log was last cleared), in recording order.
+    /// Empty unless [`Connection::enable_performance_monitoring`] has been called.
+    #[must_use]
+    pub fn profiler_events(&self) -> Vec<crate::core::performance::ProfileEvent> {
+        self.performance.events.snapshot()
+    }
+
+    /// Writes every recorded [`crate::core::performance::ProfileEvent`] to `path` as a
+    /// JSON array, so external tooling can aggregate per-query time and cache hit/miss
+    /// ratios without linking against this crate.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if the events can't be serialized or `path` can't be written.
+    pub fn dump_profile_json(&self, path: impl AsRef<Path>) -> Result<(), OxidbError> {
+        let events = self.performance.events.snapshot();
+        let json = serde_json::to_string_pretty(&events)
+            .map_err(|e| OxidbError::Internal(format!("Failed to serialize profile events: {e}")))?;
+        std::fs::write(path, json).map_err(OxidbError::Io)
     }
 
     /// Execute a SQL query and return the results.
@@ -139,13 +231,11 @@ impl Connection {
     /// # }
     /// ```
     pub fn query(&mut self, sql: &str) -> Result<QueryResult, OxidbError> {
-        // Parse SQL to Command
-        let command = parse_query(sql)?;
-        let result = self.executor.execute_command(command)?;
-        
+        let result = self.run_traced(sql)?;
+
         // Convert ExecutionResult to QueryResult
         use crate::core::query::executor::ExecutionResult;
-        
+
         let query_result = match result {
             ExecutionResult::Query { columns, rows } => {
                 // Convert Vec<Vec<DataType>> rows to Value rows
@@ -214,13 +304,11 @@ impl Connection {
     /// # }
     /// ```
     pub fn execute(&mut self, sql: &str) -> Result<QueryResult, OxidbError> {
-        // Parse SQL to Command
-        let command = parse_query(sql)?;
-        let result = self.executor.execute_command(command)?;
-        
+        let result = self.run_traced(sql)?;
+
         // Convert ExecutionResult to rows affected count
         use crate::core::query::executor::ExecutionResult;
-        
+
         let mapped = match result {
             ExecutionResult::Updated { count } => QueryResult::RowsAffected(count as u64),
             ExecutionResult::Deleted(success) => QueryResult::RowsAffected(if success { 1 } else { 0 }),
@@ -256,10 +344,44 @@ impl Connection {
                     .collect();
                 QueryResult::Data(DataSet::new(columns, converted_rows))
             }
+            ExecutionResult::Explain(plan) => {
+                let rows = plan
+                    .to_string()
+                    .lines()
+                    .map(|line| Row { values: vec![Value::Text(line.to_string())] })
+                    .collect();
+                QueryResult::Data(DataSet::new(vec!["QUERY PLAN".to_string()], rows))
+            }
         };
         Ok(mapped)
     }
 
+    /// Parses `sql` (a bare `SELECT ...`, without the `EXPLAIN` keyword),
+    /// actually runs it, and returns its plan tree annotated with measured
+    /// row counts, timings, and buffer access counts - the typed equivalent
+    /// of `conn.execute("EXPLAIN ANALYZE ...")`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `sql` fails to parse, isn't a `SELECT`, or fails
+    /// to execute.
+    pub fn explain_analyze(
+        &mut self,
+        sql: &str,
+    ) -> Result<crate::core::query::executor::explain_execution::QueryPlan, OxidbError> {
+        let inner_command = parse_query(sql)?;
+        let explain_command = crate::core::query::commands::Command::Explain {
+            statement: Box::new(inner_command),
+            analyze: true,
+        };
+        match self.executor.execute_command(explain_command)? {
+            crate::core::query::executor::ExecutionResult::Explain(plan) => Ok(plan),
+            other => Err(OxidbError::Internal(format!(
+                "Expected ExecutionResult::Explain from EXPLAIN ANALYZE, got {other:?}"
+            ))),
+        }
+    }
+
     /// Begin a new transaction.
     ///
     /// # Errors
@@ -274,6 +396,14 @@ impl Connection {
         Ok(())
     }
 
+    /// Whether a transaction is currently active, e.g. via `begin_transaction`/
+    /// `transaction`. [`crate::api::statement::PreparedStatement::execute_batch`] uses
+    /// this to decide whether it may wrap batches in its own internally managed
+    /// transactions, or must run within the caller's existing one untouched.
+    pub(crate) fn in_transaction(&self) -> bool {
+        self.executor.transaction_manager.current_active_transaction_id().is_some()
+    }
+
     /// Commit the current transaction.
     ///
     /// # Errors
@@ -305,6 +435,35 @@ impl Connection {
         Ok(())
     }
 
+    /// Starts a transaction scoped to an RAII guard: [`crate::api::transaction::Transaction`]
+    /// commits on [`Transaction::commit`](crate::api::transaction::Transaction::commit) and
+    /// otherwise rolls back automatically when dropped, so an early return or `?` between
+    /// `BEGIN` and `COMMIT` can no longer leave the transaction open the way driving it
+    /// through raw `execute("BEGIN")`/`execute("COMMIT")` strings can.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if a transaction is already active or the transaction manager
+    /// fails to start one.
+    pub fn transaction(&mut self) -> Result<crate::api::transaction::Transaction<'_>, OxidbError> {
+        crate::api::transaction::Transaction::begin(self)
+    }
+
+    /// Like [`Connection::transaction`], but acquires its locks according to `behavior`
+    /// (`DEFERRED`/`IMMEDIATE`/`EXCLUSIVE`) instead of always deferring them to first
+    /// access, so a transaction that would conflict with another writer fails at `BEGIN`
+    /// time rather than partway through its first statement.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if a transaction is already active, the transaction manager
+    /// fails to start one, or (for `Immediate`/`Exclusive`) the requested lock conflicts
+    /// with another active transaction.
+    pub fn transaction_with_behavior(
+        &mut self,
+        behavior: crate::api::transaction::TransactionBehavior,
+    ) -> Result<crate::api::transaction::Transaction<'_>, OxidbError> {
+        crate::api::transaction::Transaction::begin_with_behavior(self, behavior)
+    }
+
     /// Persists any pending changes to disk.
     ///
     /// # Errors
@@ -353,13 +512,14 @@ impl Connection {
     /// ```
     pub fn get_performance_report(&self) -> Result<String, OxidbError> {
         let analyzer = PerformanceAnalyzer::new();
-        
+
         // Get a read lock on the metrics
         let metrics = self.performance.metrics
             .read()
             .map_err(|_| OxidbError::Lock("Failed to acquire metrics lock".to_string()))?;
-        
-        let report = analyzer.analyze(&*metrics);
+
+        let events = self.performance.events.snapshot();
+        let report = analyzer.analyze_with_events(&metrics, &events);
         Ok(report.to_string())
     }
 
@@ -463,6 +623,209 @@ impl Connection {
         Ok(query_result)
     }
 
+    /// Parses `sql` once into a reusable [`crate::api::statement::PreparedStatement`],
+    /// so a loop binding many different parameter sets (a bulk insert) re-tokenizes and
+    /// re-parses the SQL only once rather than on every iteration. Placeholders may be
+    /// anonymous `?`, numbered `?1`/`?2`, or named `:id`, bound via the
+    /// [`crate::params!`] macro.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if `sql` cannot be tokenized or parsed.
+    pub fn prepare(&mut self, sql: &str) -> Result<crate::api::statement::PreparedStatement<'_>, OxidbError> {
+        crate::api::statement::PreparedStatement::new(self, sql)
+    }
+
+    /// Registers a scalar SQL function under `name`, so statements run through this
+    /// connection can call it with `arg_count` arguments, e.g. a `transfer_ok(balance)`
+    /// check. `flags` declares whether `f` is deterministic
+    /// ([`FunctionFlags::DETERMINISTIC`]) or not ([`FunctionFlags::NONE`]), which
+    /// [`FunctionRegistry::is_deterministic`] surfaces to callers deciding whether a
+    /// result may be cached.
+    ///
+    /// Registering a function under a name that's already registered (scalar or
+    /// aggregate) replaces the existing one.
+    ///
+    /// # Errors
+    /// This never fails itself; it returns `Result` to match
+    /// [`Connection::create_aggregate_function`] and leave room for validating `name`
+    /// against reserved/built-in identifiers in the future.
+    ///
+    /// [`FunctionFlags::DETERMINISTIC`]: crate::api::functions::FunctionFlags::DETERMINISTIC
+    /// [`FunctionFlags::NONE`]: crate::api::functions::FunctionFlags::NONE
+    /// [`FunctionRegistry::is_deterministic`]: crate::api::functions::FunctionRegistry::is_deterministic
+    pub fn create_scalar_function<F>(
+        &mut self,
+        name: &str,
+        arg_count: usize,
+        flags: crate::api::functions::FunctionFlags,
+        f: F,
+    ) -> Result<(), OxidbError>
+    where
+        F: Fn(&[Value]) -> Result<Value, OxidbError> + Send + Sync + 'static,
+    {
+        self.functions.register_scalar(name, arg_count, flags, f);
+        Ok(())
+    }
+
+    /// Registers an aggregate SQL function under `name` with `step`/`finalize`
+    /// behavior given by `aggregate`, so statements run through this connection can call
+    /// it over a group of rows alongside the built-in `SUM`/`COUNT`/etc. See
+    /// [`Connection::create_scalar_function`] for the meaning of `flags`.
+    ///
+    /// # Errors
+    /// This never fails itself; see [`Connection::create_scalar_function`] for why it
+    /// returns `Result`.
+    pub fn create_aggregate_function<A>(
+        &mut self,
+        name: &str,
+        arg_count: usize,
+        flags: crate::api::functions::FunctionFlags,
+        aggregate: A,
+    ) -> Result<(), OxidbError>
+    where
+        A: crate::api::functions::Aggregate + 'static,
+    {
+        self.functions.register_aggregate(name, arg_count, flags, aggregate);
+        Ok(())
+    }
+
+    /// Removes a previously registered scalar or aggregate function so `name` is no
+    /// longer callable from SQL run through this connection.
+    pub fn remove_function(&mut self, name: &str) {
+        self.functions.remove(name);
+    }
+
+    /// Resolves `name` against the registry of user-defined scalar functions and calls it
+    /// with `args`, converting to and from [`Value`] at the boundary so the SQL
+    /// expression evaluator (which works in [`crate::core::types::DataType`]) can call a
+    /// registered function the same way it would a built-in like `UPPER` or `COSINE_SIMILARITY`.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::NotFound` if no scalar function named `name` is registered,
+    /// `OxidbError::InvalidInput` if `args.len()` doesn't match its registered arity, or
+    /// whatever error the function itself returns.
+    pub fn resolve_scalar_function(
+        &self,
+        name: &str,
+        args: &[crate::core::types::DataType],
+    ) -> Result<crate::core::types::DataType, OxidbError> {
+        let values: Vec<Value> = args.iter().cloned().map(data_type_to_value).collect();
+        let func = self.functions.resolve_scalar(name, values.len())?;
+        let result = func(&values)?;
+        Ok(value_to_data_type(result))
+    }
+
+    /// Shares this connection's underlying key-value store so
+    /// [`crate::api::backup::Backup`] can scan and copy its entries without reaching into
+    /// `Connection`'s private `executor` field from outside this module.
+    pub(crate) fn raw_store(&self) -> Arc<RwLock<FileKvStore>> {
+        Arc::clone(&self.executor.store)
+    }
+
+    /// The registry of user-defined functions registered via
+    /// [`Connection::create_scalar_function`]/[`Connection::create_aggregate_function`],
+    /// consulted when planning and executing a statement that calls an identifier the
+    /// engine doesn't recognize as a built-in function.
+    #[must_use]
+    pub fn functions(&self) -> &crate::api::functions::FunctionRegistry {
+        &self.functions
+    }
+
+    /// Sets (or clears, with `None`) a callback fired with a statement's expanded SQL text
+    /// just before it's parsed and run, mirroring rusqlite's `Connection::trace`.
+    ///
+    /// Wired into [`Connection::query`], [`Connection::execute`], and
+    /// [`Connection::query_rows`] - the entry points that take raw SQL text - so a caller
+    /// can log every statement a connection runs, e.g. to spot an unexpectedly-issued
+    /// query in production.
+    pub fn trace(&mut self, callback: Option<fn(&str)>) {
+        self.trace_callback = callback;
+    }
+
+    /// Sets (or clears, with `None`) a callback fired with a statement's SQL text and its
+    /// wall-clock execution time once it completes, mirroring rusqlite's
+    /// `Connection::profile`.
+    ///
+    /// Wired into the same entry points as [`Connection::trace`], so
+    /// `test_query_performance`-style benchmarks can attribute time to individual queries
+    /// instead of only timing a whole suite with a single [`std::time::Instant`].
+    pub fn profile(&mut self, callback: Option<fn(&str, Duration)>) {
+        self.profile_callback = callback;
+    }
+
+    /// Reports current operational statistics - active-transaction count, WAL file
+    /// size, and (if this connection's WAL had history to replay on open) the stats
+    /// from that recovery run - for monitoring recovery time, spotting a runaway
+    /// open transaction, and capacity planning around WAL retention.
+    pub fn stats(&self) -> crate::core::query::executor::stats::DatabaseStats {
+        self.executor.stats()
+    }
+
+    /// Subscribes `observer` to every future transaction commit on this connection,
+    /// returning an id that can later be passed to [`Connection::deregister_tx_observer`].
+    ///
+    /// Each commit delivers a [`crate::core::wal::TxReport`] carrying the committed
+    /// transaction's id, commit LSN, and the pages/tables it touched - the foundation for
+    /// materialized-view refresh, change-data-capture streams, or cache coherency across
+    /// connections.
+    pub fn register_tx_observer(
+        &mut self,
+        observer: Box<dyn crate::core::wal::TxObserver>,
+    ) -> crate::core::wal::TxObserverId {
+        self.executor.register_tx_observer(observer)
+    }
+
+    /// Removes a previously registered transaction observer. Returns `false` if `id`
+    /// isn't currently registered.
+    pub fn deregister_tx_observer(&mut self, id: crate::core::wal::TxObserverId) -> bool {
+        self.executor.deregister_tx_observer(id)
+    }
+
+    /// Parses and executes `sql`, firing the [`Connection::trace`] callback beforehand and
+    /// the [`Connection::profile`] callback (with the elapsed wall-clock time) afterward,
+    /// regardless of whether execution succeeded.
+    fn run_traced(
+        &mut self,
+        sql: &str,
+    ) -> Result<crate::core::query::executor::ExecutionResult, OxidbError> {
+        if let Some(callback) = self.trace_callback {
+            callback(sql);
+        }
+
+        self.performance.record_event(crate::core::performance::ProfileEvent::QueryStart {
+            query_text: sql.to_string(),
+            category: crate::core::performance::events::infer_query_category(sql),
+            timestamp_nanos: crate::core::performance::events::now_nanos(),
+        });
+
+        let started = Instant::now();
+        let result = parse_query(sql).and_then(|command| self.executor.execute_command(command));
+        let elapsed = started.elapsed();
+
+        self.performance.record_event(crate::core::performance::ProfileEvent::QueryEnd {
+            query_text: sql.to_string(),
+            duration: elapsed,
+        });
+        let rows_affected = result.as_ref().map_or(0, execution_result_rows_affected);
+        let _ = self.performance.record_query(sql, elapsed, rows_affected);
+
+        if let Some(callback) = self.profile_callback {
+            callback(sql, elapsed);
+        }
+
+        result
+    }
+
+    /// Runs an already-built `Command` against this connection's executor. Shared by
+    /// [`Connection::prepare`]'s returned statement so it can execute without
+    /// re-implementing command dispatch.
+    pub(crate) fn execute_command(
+        &mut self,
+        command: crate::core::query::commands::Command,
+    ) -> Result<crate::core::query::executor::ExecutionResult, OxidbError> {
+        self.executor.execute_command(command)
+    }
+
     /// Execute a query and return only the first row, if any.
     ///
     /// # Examples
@@ -510,6 +873,68 @@ impl Connection {
         }
     }
 
+    /// Executes a query and returns its rows as a lazy [`crate::api::rows::Rows`] iterator
+    /// instead of the `Vec<Row>` [`Connection::query_all`] collects up front.
+    ///
+    /// Each row is converted from its stored `DataType`s only as it's pulled via
+    /// [`crate::api::rows::Rows::query_map`]/[`crate::api::rows::Rows::query_and_then`] or
+    /// plain iteration, so a caller that only needs the first few matches, or that folds a
+    /// large result into a running total, never holds the whole result set resident.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if `sql` cannot be parsed or its execution fails.
+    pub fn query_rows(&mut self, sql: &str) -> Result<crate::api::rows::Rows, OxidbError> {
+        let result = self.run_traced(sql)?;
+        crate::api::rows::Rows::from_execution_result(result)
+    }
+
+    /// Prepares `sql`, binds `params`, and maps each result row with `f`, collecting errors
+    /// the same way [`crate::api::rows::Rows::query_and_then`] does.
+    ///
+    /// Equivalent to `self.prepare(sql)?.query_rows(params)?.query_and_then(f)`, provided
+    /// as a one-call convenience for the common case of a one-off typed query.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if `sql` cannot be parsed, `params` doesn't match its
+    /// placeholders, or execution fails.
+    pub fn query_map<T, F>(
+        &mut self,
+        sql: &str,
+        params: crate::api::statement::ParamList,
+        f: F,
+    ) -> Result<crate::api::rows::QueryAndThen<F>, OxidbError>
+    where
+        F: FnMut(&crate::api::rows::Row) -> Result<T, OxidbError>,
+    {
+        // `query_rows` materializes its `Rows` independently of the `PreparedStatement`, so
+        // the temporary statement can be dropped at the end of this call without the
+        // returned mapper borrowing from it.
+        let mut statement = self.prepare(sql)?;
+        statement.query_map(params, f)
+    }
+
+    /// Opens an incremental, positional I/O handle onto one row's column value.
+    ///
+    /// `rowid` is matched against the row's `id` column, the primary key convention used
+    /// throughout this crate's tables. The returned [`crate::api::blob::Blob`] buffers the
+    /// column's current bytes and streams them via `Read`/`Write`/`Seek`, writing any
+    /// changes back with a single `UPDATE` when it's flushed or dropped, so a caller
+    /// moving megabyte-scale values no longer has to embed them in SQL text or hold them
+    /// fully in memory at once.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if no row with `rowid` exists in `table`, or if `column` does
+    /// not hold a TEXT or BLOB value.
+    pub fn blob_open(
+        &mut self,
+        table: &str,
+        column: &str,
+        rowid: i64,
+        read_write: bool,
+    ) -> Result<crate::api::blob::Blob<'_>, OxidbError> {
+        crate::api::blob::Blob::open(self, table, column, rowid, read_write)
+    }
+
     /// Executes an UPDATE, INSERT, or DELETE statement and returns the number of affected rows.
     /// 
     /// # Errors
@@ -1,8 +1,11 @@
 use crate::core::common::OxidbError;
 use crate::core::config::Config;
-use crate::core::query::commands::{Command, Key};
+use crate::core::query::commands::{Command, Key, SqlConditionTree};
 use crate::core::query::executor::{ExecutionResult, QueryExecutor};
 use crate::core::query::parser::parse_query_string;
+use crate::core::query::sql::ast::Statement;
+use crate::core::query::sql::parser::SqlParser;
+use crate::core::query::sql::tokenizer::Tokenizer;
 use crate::core::storage::engine::SimpleFileKvStore;
 use crate::core::types::DataType;
 use serde_json;
@@ -130,6 +133,9 @@ impl Oxidb {
                     }
                     DataType::JsonBlob(json_val) => serde_json::to_string(&json_val)
                         .unwrap_or_else(|e| format!("Error serializing JsonBlob: {}", e)),
+                    DataType::Decimal { unscaled, scale, .. } => {
+                        crate::core::types::decimal::format_decimal(unscaled, scale)
+                    }
                 }))
             }
             Ok(unexpected_result) => Err(OxidbError::Internal(format!( // Changed to Internal
@@ -166,6 +172,105 @@ impl Oxidb {
         }
     }
 
+    /// Upserts rows into `table_name` by primary key.
+    ///
+    /// Behaves like a SQL `INSERT`, except a row whose primary key already exists is
+    /// overwritten in place instead of raising a uniqueness violation. Equivalent to the
+    /// `PUT table (cols) VALUES (...)` SQL statement.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if the table does not exist or a non-primary-key `UNIQUE`
+    /// constraint is violated by the new values.
+    pub fn put(
+        &mut self,
+        table_name: &str,
+        columns: Option<Vec<String>>,
+        values: Vec<Vec<DataType>>,
+    ) -> Result<usize, OxidbError> {
+        let command = Command::Put { table_name: table_name.to_string(), columns, values };
+        match self.executor.execute_command(command) {
+            Ok(ExecutionResult::Updated { count }) => Ok(count),
+            Ok(unexpected_result) => Err(OxidbError::Internal(format!(
+                "Put: Expected Updated, got {:?}",
+                unexpected_result
+            ))),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Idempotently deletes rows matching `condition` from `table_name`.
+    ///
+    /// Functionally identical to a SQL `DELETE`, provided for symmetry with [`Oxidb::put`]:
+    /// matching zero rows is success, not an error. Equivalent to the `RM table WHERE ...`
+    /// SQL statement.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if the table does not exist.
+    pub fn rm(
+        &mut self,
+        table_name: &str,
+        condition: Option<SqlConditionTree>,
+    ) -> Result<usize, OxidbError> {
+        let command = Command::Rm { table_name: table_name.to_string(), condition };
+        match self.executor.execute_command(command) {
+            Ok(ExecutionResult::Updated { count }) => Ok(count),
+            Ok(unexpected_result) => Err(OxidbError::Internal(format!(
+                "Rm: Expected Updated, got {:?}",
+                unexpected_result
+            ))),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Asserts that a row with these exact values exists in `table_name`, inserting it if
+    /// absent.
+    ///
+    /// Succeeds as a no-op if an identical row is already present. Fails if a row exists
+    /// for the same primary key but with different values. Equivalent to the
+    /// `ENSURE table (cols) VALUES (...)` SQL statement.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if the table does not exist or a conflicting row already exists.
+    pub fn ensure(
+        &mut self,
+        table_name: &str,
+        columns: Option<Vec<String>>,
+        values: Vec<Vec<DataType>>,
+    ) -> Result<usize, OxidbError> {
+        let command = Command::Ensure { table_name: table_name.to_string(), columns, values };
+        match self.executor.execute_command(command) {
+            Ok(ExecutionResult::Updated { count }) => Ok(count),
+            Ok(unexpected_result) => Err(OxidbError::Internal(format!(
+                "Ensure: Expected Updated, got {:?}",
+                unexpected_result
+            ))),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Asserts that no row in `table_name` matches `condition`, failing if one does.
+    ///
+    /// Succeeds as a no-op when nothing matches. Equivalent to the
+    /// `ENSURE NOT table WHERE ...` SQL statement.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if the table does not exist or a matching row already exists.
+    pub fn ensure_not(
+        &mut self,
+        table_name: &str,
+        condition: Option<SqlConditionTree>,
+    ) -> Result<(), OxidbError> {
+        let command = Command::EnsureNot { table_name: table_name.to_string(), condition };
+        match self.executor.execute_command(command) {
+            Ok(ExecutionResult::Updated { .. }) => Ok(()),
+            Ok(unexpected_result) => Err(OxidbError::Internal(format!(
+                "EnsureNot: Expected Updated, got {:?}",
+                unexpected_result
+            ))),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Persists all current in-memory data to the main data file on disk.
     ///
     /// This method explicitly triggers the `save_to_disk` operation on the underlying
@@ -267,6 +372,58 @@ impl Oxidb {
         }
     }
 
+    /// Executes a SQL statement containing `?` placeholders, binding `params` positionally.
+    ///
+    /// Unlike `execute_query_str`, values are never interpolated into the SQL text: the
+    /// statement is parsed with its placeholders intact and `params` are carried as `DataType`
+    /// all the way to execution. This is what callers building SQL with `format!` plus manual
+    /// quote-escaping (e.g. for embeddings or free-text) should use instead — it avoids both the
+    /// injection risk and the lossy text round-trip for non-string types like `DataType::Vector`.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if the SQL cannot be parsed or execution fails, including a
+    /// parameter-count mismatch against the placeholders in `sql`.
+    pub fn execute_params(
+        &mut self,
+        sql: &str,
+        params: &[DataType],
+    ) -> Result<ExecutionResult, OxidbError> {
+        let statement = parse_statement(sql)?;
+        self.executor.execute_command(Command::ParameterizedSql {
+            statement,
+            parameters: params.to_vec(),
+        })
+    }
+
+    /// Parses `sql` once into a reusable [`PreparedStatement`].
+    ///
+    /// Useful for repeated inserts (e.g. `add_entity`/`add_relationship`-style loops) that would
+    /// otherwise re-tokenize and re-parse identical SQL text on every call; only the bound
+    /// parameters change between executions.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if `sql` cannot be tokenized or parsed.
+    pub fn prepare(&self, sql: &str) -> Result<PreparedStatement, OxidbError> {
+        Ok(PreparedStatement { statement: parse_statement(sql)? })
+    }
+
+    /// Executes a statement previously produced by [`Oxidb::prepare`], binding `params`
+    /// positionally. The statement's parsed AST is reused as-is, so only parameter resolution
+    /// and execution happen here.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if execution fails, including a parameter-count mismatch.
+    pub fn execute_prepared(
+        &mut self,
+        statement: &PreparedStatement,
+        params: &[DataType],
+    ) -> Result<ExecutionResult, OxidbError> {
+        self.executor.execute_command(Command::ParameterizedSql {
+            statement: statement.statement.clone(),
+            parameters: params.to_vec(),
+        })
+    }
+
     /// Returns the path to the main database file.
     pub fn database_path(&self) -> PathBuf {
         self.executor.store.read().unwrap().file_path().to_path_buf()
@@ -277,3 +434,21 @@ impl Oxidb {
         self.executor.index_base_path()
     }
 }
+
+/// Tokenizes and parses `sql` into a [`Statement`] AST, without translating it into a `Command`.
+/// Shared by `execute_params` and `prepare` so both go through identical parsing.
+fn parse_statement(sql: &str) -> Result<Statement, OxidbError> {
+    let mut tokenizer = Tokenizer::new(sql);
+    let tokens = tokenizer
+        .tokenize()
+        .map_err(|e| OxidbError::SqlParsing(format!("SQL tokenizer error: {e}")))?;
+    let mut parser = SqlParser::new(tokens);
+    parser.parse().map_err(|e| OxidbError::SqlParsing(format!("SQL parse error: {e}")))
+}
+
+/// A SQL statement parsed once via [`Oxidb::prepare`] and executed repeatedly with different
+/// bound parameters via [`Oxidb::execute_prepared`], skipping re-tokenization/re-parsing.
+#[derive(Debug, Clone)]
+pub struct PreparedStatement {
+    statement: Statement,
+}
@@ -0,0 +1,257 @@
+//! User-defined scalar and aggregate SQL functions, registered on a [`Connection`] via
+//! [`Connection::create_scalar_function`] and [`Connection::create_aggregate_function`],
+//! mirroring rusqlite's function registration API.
+//!
+//! The registry lives on the connection rather than being global, so different
+//! connections (and tests) can register conflicting functions under the same name
+//! without interfering with each other. [`FunctionRegistry::resolve_scalar`] and
+//! [`FunctionRegistry::resolve_aggregate`] are the consultation points the function-call
+//! binder and expression evaluator use to turn a SQL identifier into callable logic,
+//! validating the argument count up front rather than at every invocation.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::core::common::types::Value;
+use crate::core::common::OxidbError;
+
+/// A user-defined scalar function: takes the already-evaluated argument values and
+/// returns a single [`Value`], as registered via [`Connection::create_scalar_function`].
+///
+/// [`Connection::create_scalar_function`]: super::connection::Connection::create_scalar_function
+pub type ScalarFunction = Arc<dyn Fn(&[Value]) -> Result<Value, OxidbError> + Send + Sync>;
+
+/// Behavior flags for a registered function, mirroring rusqlite's `FunctionFlags`.
+///
+/// Currently carries only determinism, which the planner consults to decide whether a
+/// function's result can be cached/reused across rows with identical arguments (e.g.
+/// `UPPER` is safe to cache; a function reading the system clock or RNG state is not).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FunctionFlags {
+    /// `true` if the function always returns the same result for the same arguments.
+    pub deterministic: bool,
+}
+
+impl FunctionFlags {
+    /// No flags set; the function is treated as non-deterministic (the safe default).
+    pub const NONE: Self = Self { deterministic: false };
+    /// The function always returns the same result for the same arguments.
+    pub const DETERMINISTIC: Self = Self { deterministic: true };
+}
+
+/// A user-defined aggregate, registered via [`Connection::create_aggregate_function`].
+///
+/// `State` accumulates across the rows of a group: `State::default()` creates the
+/// starting value, [`Aggregate::step`] folds one row's arguments into it, and
+/// [`Aggregate::finalize`] converts the accumulated state into the aggregate's result
+/// once the group is exhausted.
+///
+/// [`Connection::create_aggregate_function`]: super::connection::Connection::create_aggregate_function
+pub trait Aggregate: Send + Sync {
+    /// Per-group accumulator, e.g. a running sum and count for an average.
+    type State: Default + Send + 'static;
+
+    /// Folds one row's worth of already-evaluated argument values into `state`.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if `args` aren't valid for this aggregate (wrong type, out of
+    /// range, etc).
+    fn step(&self, state: &mut Self::State, args: &[Value]) -> Result<(), OxidbError>;
+
+    /// Converts the accumulated `state` into the aggregate's result once its group is
+    /// exhausted.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if `state` cannot be converted to a result (e.g. `AVG` over
+    /// zero rows).
+    fn finalize(&self, state: Self::State) -> Result<Value, OxidbError>;
+}
+
+/// Type-erased form of [`Aggregate`] so the registry can store aggregates of different
+/// `State` types behind a single map value; implemented automatically for every `Aggregate`.
+trait ErasedAggregate: Send + Sync {
+    fn init(&self) -> Box<dyn Any + Send>;
+    fn step(&self, state: &mut dyn Any, args: &[Value]) -> Result<(), OxidbError>;
+    fn finalize(&self, state: Box<dyn Any + Send>) -> Result<Value, OxidbError>;
+}
+
+impl<A: Aggregate> ErasedAggregate for A {
+    fn init(&self) -> Box<dyn Any + Send> {
+        Box::new(A::State::default())
+    }
+
+    fn step(&self, state: &mut dyn Any, args: &[Value]) -> Result<(), OxidbError> {
+        let state = state.downcast_mut::<A::State>().ok_or_else(|| OxidbError::Internal(
+            "aggregate state type mismatch (registry bug)".to_string(),
+        ))?;
+        Aggregate::step(self, state, args)
+    }
+
+    fn finalize(&self, state: Box<dyn Any + Send>) -> Result<Value, OxidbError> {
+        let state = *state.downcast::<A::State>().map_err(|_| OxidbError::Internal(
+            "aggregate state type mismatch (registry bug)".to_string(),
+        ))?;
+        Aggregate::finalize(self, state)
+    }
+}
+
+#[derive(Clone)]
+struct ScalarEntry {
+    arg_count: usize,
+    func: ScalarFunction,
+    flags: FunctionFlags,
+}
+
+#[derive(Clone)]
+struct AggregateEntry {
+    arg_count: usize,
+    aggregate: Arc<dyn ErasedAggregate>,
+    flags: FunctionFlags,
+}
+
+/// A live accumulator for one in-progress group of a registered aggregate, obtained from
+/// [`FunctionRegistry::resolve_aggregate`].
+pub struct AggregateState {
+    aggregate: Arc<dyn ErasedAggregate>,
+    state: Box<dyn Any + Send>,
+}
+
+impl AggregateState {
+    /// Folds one row's worth of already-evaluated argument values into this group's state.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if `args` aren't valid for this aggregate.
+    pub fn step(&mut self, args: &[Value]) -> Result<(), OxidbError> {
+        self.aggregate.step(self.state.as_mut(), args)
+    }
+
+    /// Converts the accumulated state into the aggregate's result, consuming it.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if the accumulated state cannot be converted to a result.
+    pub fn finalize(self) -> Result<Value, OxidbError> {
+        self.aggregate.finalize(self.state)
+    }
+}
+
+/// A connection-scoped registry of user-defined scalar and aggregate SQL functions.
+///
+/// Consulted when a function identifier doesn't match one of the engine's built-ins
+/// (`COUNT`, `SUM`, `COSINE_SIMILARITY`, ...); see [`FunctionRegistry::resolve_scalar`] and
+/// [`FunctionRegistry::resolve_aggregate`].
+#[derive(Default, Clone)]
+pub struct FunctionRegistry {
+    scalars: HashMap<String, ScalarEntry>,
+    aggregates: HashMap<String, AggregateEntry>,
+}
+
+impl FunctionRegistry {
+    pub(super) fn register_scalar<F>(
+        &mut self,
+        name: &str,
+        arg_count: usize,
+        flags: FunctionFlags,
+        func: F,
+    ) where
+        F: Fn(&[Value]) -> Result<Value, OxidbError> + Send + Sync + 'static,
+    {
+        self.scalars.insert(
+            name.to_uppercase(),
+            ScalarEntry { arg_count, func: Arc::new(func), flags },
+        );
+    }
+
+    pub(super) fn register_aggregate<A>(
+        &mut self,
+        name: &str,
+        arg_count: usize,
+        flags: FunctionFlags,
+        aggregate: A,
+    ) where
+        A: Aggregate + 'static,
+    {
+        self.aggregates.insert(
+            name.to_uppercase(),
+            AggregateEntry { arg_count, aggregate: Arc::new(aggregate), flags },
+        );
+    }
+
+    pub(super) fn remove(&mut self, name: &str) {
+        let upper = name.to_uppercase();
+        self.scalars.remove(&upper);
+        self.aggregates.remove(&upper);
+    }
+
+    /// Looks up `name` as a registered scalar function and validates that it accepts
+    /// `arg_count` arguments.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::NotFound` if no scalar function named `name` is registered, or
+    /// `OxidbError::InvalidInput` if `arg_count` doesn't match its registered arity.
+    pub fn resolve_scalar(&self, name: &str, arg_count: usize) -> Result<&ScalarFunction, OxidbError> {
+        let entry = self.scalars.get(&name.to_uppercase()).ok_or_else(|| {
+            OxidbError::NotFound(format!("scalar function '{name}'"))
+        })?;
+        if entry.arg_count != arg_count {
+            return Err(OxidbError::InvalidInput {
+                message: format!(
+                    "function '{name}' expects {} argument(s), got {arg_count}",
+                    entry.arg_count
+                ),
+            });
+        }
+        Ok(&entry.func)
+    }
+
+    /// Looks up `name` as a registered aggregate function, validates that it accepts
+    /// `arg_count` arguments, and returns a fresh [`AggregateState`] to fold rows into.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::NotFound` if no aggregate function named `name` is registered,
+    /// or `OxidbError::InvalidInput` if `arg_count` doesn't match its registered arity.
+    pub fn resolve_aggregate(&self, name: &str, arg_count: usize) -> Result<AggregateState, OxidbError> {
+        let entry = self.aggregates.get(&name.to_uppercase()).ok_or_else(|| {
+            OxidbError::NotFound(format!("aggregate function '{name}'"))
+        })?;
+        if entry.arg_count != arg_count {
+            return Err(OxidbError::InvalidInput {
+                message: format!(
+                    "aggregate '{name}' expects {} argument(s), got {arg_count}",
+                    entry.arg_count
+                ),
+            });
+        }
+        Ok(AggregateState { aggregate: Arc::clone(&entry.aggregate), state: entry.aggregate.init() })
+    }
+
+    /// Whether a registered scalar or aggregate function named `name` is safe to cache
+    /// across rows called with identical arguments. Returns `None` if `name` isn't
+    /// registered at all, so the planner can distinguish "unknown, must not cache" from
+    /// "known and deterministic".
+    #[must_use]
+    pub fn is_deterministic(&self, name: &str) -> Option<bool> {
+        let upper = name.to_uppercase();
+        self.scalars
+            .get(&upper)
+            .map(|entry| entry.flags.deterministic)
+            .or_else(|| self.aggregates.get(&upper).map(|entry| entry.flags.deterministic))
+    }
+
+    /// Whether `name` is registered as either a scalar or an aggregate function.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        let upper = name.to_uppercase();
+        self.scalars.contains_key(&upper) || self.aggregates.contains_key(&upper)
+    }
+}
+
+impl fmt::Debug for FunctionRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FunctionRegistry")
+            .field("scalars", &self.scalars.keys().collect::<Vec<_>>())
+            .field("aggregates", &self.aggregates.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
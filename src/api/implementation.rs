@@ -1,6 +1,16 @@
 //! Implementation of the Oxidb API
-//! 
+//!
 //! **DEPRECATED**: This module is deprecated. Use the Connection API instead.
+//!
+//! Note: this module (along with sibling `api_impl.rs` and `db.rs`) isn't reachable from
+//! the crate root - nothing declares `mod implementation`/`mod api_impl`/`mod db` under
+//! `src/api`, and they each disagree on what `Oxidb` even is (`db.rs` defines its own
+//! struct; this file and `api_impl.rs` both implement methods for an `Oxidb` in
+//! `super::types` that doesn't exist there). So a prepared-statement subsystem belongs on
+//! [`crate::api::Connection`], the API this crate actually ships - see
+//! [`crate::api::Connection::prepare`], [`crate::api::statement::PreparedStatement`], and
+//! the [`crate::params`] macro, which already cover positional `?`/`?N` and named `:name`
+//! placeholders with arity- and type-checked binding.
 
 #[allow(deprecated)]
 use super::types::Oxidb;
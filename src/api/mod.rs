@@ -3,10 +3,24 @@
 //! This module provides the public interface for interacting with the database,
 //! including connection management and query execution.
 
+pub mod backup;
+pub mod blob;
 mod connection;
+pub mod functions;
+pub mod pool;
+pub mod rows;
+pub mod statement;
+pub mod transaction;
 pub mod types;
 
+pub use backup::{Backup, StepResult};
+pub use blob::Blob;
 pub use connection::Connection;
+pub use functions::{Aggregate, FunctionFlags, FunctionRegistry};
+pub use pool::{OxidbPool, PoolConfig, PooledConnection};
+pub use rows::{FromColumn, Rows, ToColumn};
+pub use statement::{ParamList, PreparedStatement};
+pub use transaction::{Savepoint, Transaction, TransactionBehavior};
 pub use types::{QueryResult, Row};
 
 // Re-export core types that users need
@@ -0,0 +1,257 @@
+//! A small, concurrency-safe pool of [`Connection`]s to the same database path, modeled on
+//! qorb-style managed pools.
+//!
+//! Opening a [`Connection`] per task (as each of the `examples/production_ready_tests.rs`
+//! suites does in sequence) pays the storage engine's full startup cost every time and
+//! gives concurrent callers no shared access path. [`OxidbPool`] instead keeps a bounded set
+//! of already-open connections around: [`OxidbPool::get`] lends one out after a cheap
+//! liveness check, rebuilding it with exponential backoff if that check fails, and the
+//! returned [`PooledConnection`] puts it back on drop.
+//!
+//! OxiDB's SQL grammar requires a `FROM` clause (there's no bare `SELECT 1`), so the
+//! liveness check used here is a storage-layer probe - acquiring the connection's store
+//! lock - rather than a round-tripped query, the same adaptation [`crate::api::backup`]
+//! makes for "pages" where this engine has none.
+
+use std::collections::VecDeque;
+use std::ops::{Deref, DerefMut};
+use std::path::PathBuf;
+use std::sync::{Condvar, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use crate::core::common::OxidbError;
+
+use super::connection::Connection;
+
+/// Configuration for an [`OxidbPool`], modeled on qorb's managed-pool knobs.
+#[derive(Debug, Clone)]
+pub struct PoolConfig {
+    /// Connections opened eagerly by [`OxidbPool::new`] and never evicted for being idle.
+    pub min_size: usize,
+    /// Hard cap on connections simultaneously open (idle or checked out).
+    pub max_size: usize,
+    /// How long [`OxidbPool::get`] waits for a connection before giving up.
+    pub acquire_timeout: Duration,
+    /// Idle connections above `min_size` older than this are closed instead of recycled.
+    pub idle_timeout: Duration,
+    /// Starting delay between attempts to rebuild a connection that fails its liveness
+    /// check; doubles after every failed attempt, up to `max_backoff`.
+    pub base_backoff: Duration,
+    /// Ceiling on the backoff delay between rebuild attempts.
+    pub max_backoff: Duration,
+    /// Rebuild attempts allowed before [`OxidbPool::get`] gives up and returns an error.
+    pub max_retries: u32,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            min_size: 1,
+            max_size: 10,
+            acquire_timeout: Duration::from_secs(5),
+            idle_timeout: Duration::from_secs(300),
+            base_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_secs(1),
+            max_retries: 5,
+        }
+    }
+}
+
+struct IdleConnection {
+    conn: Connection,
+    since: Instant,
+}
+
+struct Shared {
+    path: PathBuf,
+    config: PoolConfig,
+    idle: VecDeque<IdleConnection>,
+    /// Connections currently idle or checked out; always <= `config.max_size`.
+    total: usize,
+}
+
+/// A bounded, thread-safe pool of [`Connection`]s opened against the same database path.
+pub struct OxidbPool {
+    shared: Mutex<Shared>,
+    available: Condvar,
+}
+
+impl OxidbPool {
+    /// Eagerly opens `config.min_size` connections to `path` and returns the pool managing
+    /// them.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if any of the initial connections fails to open.
+    pub fn new<P: Into<PathBuf>>(path: P, config: PoolConfig) -> Result<Self, OxidbError> {
+        let path = path.into();
+        let mut idle = VecDeque::with_capacity(config.min_size);
+        for _ in 0..config.min_size {
+            idle.push_back(IdleConnection { conn: Connection::open(&path)?, since: Instant::now() });
+        }
+        let total = idle.len();
+
+        Ok(Self {
+            shared: Mutex::new(Shared { path, config, idle, total }),
+            available: Condvar::new(),
+        })
+    }
+
+    /// Checks out a validated connection, blocking until one is idle, one can be opened
+    /// under `max_size`, or `acquire_timeout` elapses.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if `acquire_timeout` elapses before a connection becomes
+    /// available, or if a broken idle connection can't be rebuilt within `max_retries`.
+    pub fn get(&self) -> Result<PooledConnection<'_>, OxidbError> {
+        let deadline = Instant::now() + self.lock()?.config.acquire_timeout;
+
+        loop {
+            let mut shared = self.lock()?;
+
+            if let Some(candidate) = shared.idle.pop_front() {
+                drop(shared);
+                return self.validate_or_rebuild(candidate.conn);
+            }
+
+            if shared.total < shared.config.max_size {
+                shared.total += 1;
+                let path = shared.path.clone();
+                drop(shared);
+                return match Connection::open(&path) {
+                    Ok(conn) => Ok(PooledConnection { pool: self, conn: Some(conn) }),
+                    Err(err) => {
+                        self.lock()?.total -= 1;
+                        Err(err)
+                    }
+                };
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(Self::timeout_error());
+            }
+            let (_guard, result) = self
+                .available
+                .wait_timeout(shared, remaining)
+                .map_err(|_| Self::poisoned_error())?;
+            if result.timed_out() {
+                return Err(Self::timeout_error());
+            }
+        }
+    }
+
+    /// Number of connections currently idle and available for [`OxidbPool::get`].
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if the pool's internal lock is poisoned.
+    pub fn idle_count(&self) -> Result<usize, OxidbError> {
+        Ok(self.lock()?.idle.len())
+    }
+
+    fn validate_or_rebuild(&self, mut conn: Connection) -> Result<PooledConnection<'_>, OxidbError> {
+        if validate(&conn).is_ok() {
+            return Ok(PooledConnection { pool: self, conn: Some(conn) });
+        }
+
+        let (path, config) = {
+            let shared = self.lock()?;
+            (shared.path.clone(), shared.config.clone())
+        };
+
+        let mut backoff = config.base_backoff;
+        let mut last_err = OxidbError::Internal(
+            "pooled connection failed its liveness check".to_string(),
+        );
+
+        for _ in 0..config.max_retries {
+            std::thread::sleep(backoff);
+            match Connection::open(&path) {
+                Ok(fresh) if validate(&fresh).is_ok() => {
+                    return Ok(PooledConnection { pool: self, conn: Some(fresh) })
+                }
+                Ok(_) => last_err = OxidbError::Internal(
+                    "rebuilt connection failed its liveness check".to_string(),
+                ),
+                Err(err) => last_err = err,
+            }
+            backoff = (backoff * 2).min(config.max_backoff);
+        }
+
+        // Every rebuild attempt failed; drop this slot instead of leaking a permanently
+        // broken connection into `total`.
+        drop(conn);
+        self.lock()?.total -= 1;
+        self.available.notify_one();
+        Err(last_err)
+    }
+
+    fn release(&self, conn: Connection) {
+        let Ok(mut shared) = self.shared.lock() else { return };
+        shared.idle.push_back(IdleConnection { conn, since: Instant::now() });
+
+        let min_size = shared.config.min_size;
+        let idle_timeout = shared.config.idle_timeout;
+        while shared.idle.len() > min_size {
+            let Some(oldest) = shared.idle.front() else { break };
+            if oldest.since.elapsed() < idle_timeout {
+                break;
+            }
+            shared.idle.pop_front();
+            shared.total -= 1;
+        }
+
+        drop(shared);
+        self.available.notify_one();
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, Shared>, OxidbError> {
+        self.shared.lock().map_err(|_| Self::poisoned_error())
+    }
+
+    fn poisoned_error() -> OxidbError {
+        OxidbError::LockTimeout("pool's internal lock is poisoned".to_string())
+    }
+
+    fn timeout_error() -> OxidbError {
+        OxidbError::LockTimeout(
+            "timed out waiting for an available pooled connection".to_string(),
+        )
+    }
+}
+
+fn validate(conn: &Connection) -> Result<(), OxidbError> {
+    conn.raw_store().read().map(|_| ()).map_err(|_| {
+        OxidbError::LockTimeout("pooled connection's store lock is poisoned".to_string())
+    })
+}
+
+/// A [`Connection`] checked out of an [`OxidbPool`]. Derefs to [`Connection`] and returns
+/// itself to the pool when dropped.
+pub struct PooledConnection<'pool> {
+    pool: &'pool OxidbPool,
+    conn: Option<Connection>,
+}
+
+impl Deref for PooledConnection<'_> {
+    type Target = Connection;
+
+    #[allow(clippy::unwrap_used)] // `conn` is only `None` after `Drop` has taken it
+    fn deref(&self) -> &Connection {
+        self.conn.as_ref().unwrap()
+    }
+}
+
+impl DerefMut for PooledConnection<'_> {
+    #[allow(clippy::unwrap_used)] // `conn` is only `None` after `Drop` has taken it
+    fn deref_mut(&mut self) -> &mut Connection {
+        self.conn.as_mut().unwrap()
+    }
+}
+
+impl Drop for PooledConnection<'_> {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            self.pool.release(conn);
+        }
+    }
+}
@@ -0,0 +1,302 @@
+//! Lazy row iteration over a query's results, as an alternative to
+//! [`Connection::query`](crate::api::Connection::query) and
+//! [`PreparedStatement::query`](crate::api::statement::PreparedStatement::query), which both
+//! collect every row into a `Vec` up front.
+//!
+//! [`Connection::query_rows`](crate::api::Connection::query_rows) and
+//! [`PreparedStatement::query_rows`](crate::api::statement::PreparedStatement::query_rows)
+//! return a [`Rows`] iterator instead: each [`Row`] is converted from its stored
+//! [`DataType`]s only as it's pulled, and [`Rows::query_map`]/[`Rows::query_and_then`] let a
+//! caller fold a large result set into whatever it actually needs (a sum, a `HashMap`, the
+//! first match) without ever holding a fully-materialized `Vec<Row>` resident at once.
+
+use std::rc::Rc;
+
+use crate::core::common::types::Value;
+use crate::core::common::OxidbError;
+use crate::core::query::executor::ExecutionResult;
+use crate::core::types::DataType;
+
+use super::connection::data_type_to_value;
+
+/// A lazy iterator over a query's result rows, returned by
+/// [`Connection::query_rows`](crate::api::Connection::query_rows) and
+/// [`PreparedStatement::query_rows`](crate::api::statement::PreparedStatement::query_rows).
+///
+/// Converts each row from its stored [`DataType`]s to [`Row`] only when pulled via
+/// `next()`, `query_map`, or `query_and_then`, rather than up front.
+pub struct Rows {
+    columns: Rc<Vec<String>>,
+    remaining: std::vec::IntoIter<Vec<DataType>>,
+}
+
+impl Rows {
+    pub(super) fn from_execution_result(result: ExecutionResult) -> Result<Self, OxidbError> {
+        match result {
+            ExecutionResult::Query { columns, rows } => {
+                Ok(Self { columns: Rc::new(columns), remaining: rows.into_iter() })
+            }
+            _ => Ok(Self { columns: Rc::new(Vec::new()), remaining: Vec::new().into_iter() }),
+        }
+    }
+
+    /// Column names of the underlying result set, in position order.
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// Maps each row with an infallible `f`, stopping early (and not converting any
+    /// further rows) if `f` panics or the caller drops the returned iterator before
+    /// exhausting it.
+    pub fn query_map<T, F>(self, f: F) -> QueryMap<F>
+    where
+        F: FnMut(&Row) -> T,
+    {
+        QueryMap { rows: self, f }
+    }
+
+    /// Like [`Rows::query_map`], but `f` itself may fail; a row that fails to convert or
+    /// an `f` that returns `Err` both surface as `Some(Err(_))` from the returned iterator
+    /// without converting any further rows.
+    pub fn query_and_then<T, F>(self, f: F) -> QueryAndThen<F>
+    where
+        F: FnMut(&Row) -> Result<T, OxidbError>,
+    {
+        QueryAndThen { rows: self, f }
+    }
+}
+
+impl Iterator for Rows {
+    type Item = Result<Row, OxidbError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let values = self.remaining.next()?;
+        Some(Ok(Row { columns: Rc::clone(&self.columns), values }))
+    }
+}
+
+/// One row pulled from a [`Rows`] iterator.
+///
+/// Unlike [`crate::api::types::Row`] (used by the eagerly-collected
+/// [`crate::api::types::DataSet`]), this carries its column names so [`Row::get_by_name`]
+/// doesn't need the caller to track a separate column list.
+pub struct Row {
+    columns: Rc<Vec<String>>,
+    values: Vec<DataType>,
+}
+
+impl Row {
+    /// Gets the value at `index`, converted to `T` via [`FromColumn`].
+    ///
+    /// # Errors
+    /// Returns `OxidbError::InvalidInput` if `index` is out of bounds or the stored value
+    /// doesn't convert to `T`.
+    pub fn get<T: FromColumn>(&self, index: usize) -> Result<T, OxidbError> {
+        let data_type = self.values.get(index).ok_or_else(|| OxidbError::InvalidInput {
+            message: format!(
+                "column index {index} out of bounds ({} columns)",
+                self.values.len()
+            ),
+        })?;
+        T::from_column(data_type_to_value(data_type.clone()))
+    }
+
+    /// Gets the value in the column named `name`, converted to `T` via [`FromColumn`].
+    ///
+    /// # Errors
+    /// Returns `OxidbError::InvalidInput` if no column named `name` exists in this result
+    /// set, or the stored value doesn't convert to `T`.
+    pub fn get_by_name<T: FromColumn>(&self, name: &str) -> Result<T, OxidbError> {
+        let index = self.columns.iter().position(|c| c == name).ok_or_else(|| {
+            OxidbError::InvalidInput { message: format!("no column named '{name}'") }
+        })?;
+        self.get(index)
+    }
+
+    /// Column names of this row's result set, in position order.
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// Number of columns in this row.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Whether this row has no columns.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+/// Converts a value pulled from a [`Row`] into a concrete Rust type, the "`FromSql`" half
+/// of the typed row-mapping API (see [`ToColumn`] for the inverse direction).
+///
+/// Implemented for the primitive types [`Value`] itself converts to/from, plus
+/// `Option<T>` for nullable columns. Conversions never truncate: a `REAL` column read as
+/// an integer type is an error rather than a truncation (use an explicit `f64` and round
+/// in application code if that's actually wanted), while an `INTEGER` column read as
+/// `f64` casts, which is exact up to 2^53 in magnitude and rounds to the nearest `f64`
+/// beyond that.
+pub trait FromColumn: Sized {
+    /// Converts `value` to `Self`.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::InvalidInput` if `value`'s variant doesn't match `Self`, or
+    /// (for the narrower integer types) if the stored value doesn't fit in `Self`'s range.
+    fn from_column(value: Value) -> Result<Self, OxidbError>;
+}
+
+macro_rules! impl_from_column {
+    ($ty:ty, $variant:ident) => {
+        impl FromColumn for $ty {
+            fn from_column(value: Value) -> Result<Self, OxidbError> {
+                match value {
+                    Value::$variant(v) => Ok(v),
+                    other => Err(OxidbError::InvalidInput {
+                        message: format!(
+                            "cannot convert {other:?} to {}",
+                            stringify!($ty)
+                        ),
+                    }),
+                }
+            }
+        }
+    };
+}
+
+impl_from_column!(i64, Integer);
+impl_from_column!(String, Text);
+impl_from_column!(bool, Boolean);
+impl_from_column!(Vec<u8>, Blob);
+impl_from_column!(Vec<f32>, Vector);
+
+macro_rules! impl_from_column_narrow_int {
+    ($ty:ty) => {
+        impl FromColumn for $ty {
+            fn from_column(value: Value) -> Result<Self, OxidbError> {
+                match value {
+                    Value::Integer(v) => Self::try_from(v).map_err(|_| OxidbError::InvalidInput {
+                        message: format!(
+                            "integer {v} is out of range for {}",
+                            stringify!($ty)
+                        ),
+                    }),
+                    other => Err(OxidbError::InvalidInput {
+                        message: format!("cannot convert {other:?} to {}", stringify!($ty)),
+                    }),
+                }
+            }
+        }
+    };
+}
+
+impl_from_column_narrow_int!(i32);
+impl_from_column_narrow_int!(u32);
+impl_from_column_narrow_int!(u64);
+
+impl FromColumn for f64 {
+    fn from_column(value: Value) -> Result<Self, OxidbError> {
+        match value {
+            Value::Float(f) => Ok(f),
+            // INTEGER -> REAL casts, matching SQLite's numeric affinity: every `i64` is a
+            // exact or best-effort `f64` the same way an explicit `as f64` would produce.
+            Value::Integer(i) => Ok(i as Self),
+            other => {
+                Err(OxidbError::InvalidInput { message: format!("cannot convert {other:?} to f64") })
+            }
+        }
+    }
+}
+
+impl FromColumn for Value {
+    fn from_column(value: Value) -> Result<Self, OxidbError> {
+        Ok(value)
+    }
+}
+
+impl<T: FromColumn> FromColumn for Option<T> {
+    fn from_column(value: Value) -> Result<Self, OxidbError> {
+        match value {
+            Value::Null => Ok(None),
+            other => T::from_column(other).map(Some),
+        }
+    }
+}
+
+/// Converts a concrete Rust type into a [`Value`] for binding as a query parameter, the
+/// "`ToSql`" half of the typed row-mapping API (see [`FromColumn`] for the inverse
+/// direction used by [`Row::get`]).
+pub trait ToColumn {
+    /// Converts `self` to a [`Value`].
+    fn to_column(&self) -> Value;
+}
+
+macro_rules! impl_to_column {
+    ($ty:ty, $variant:ident) => {
+        impl ToColumn for $ty {
+            fn to_column(&self) -> Value {
+                Value::$variant(self.clone())
+            }
+        }
+    };
+}
+
+impl_to_column!(i64, Integer);
+impl_to_column!(f64, Float);
+impl_to_column!(String, Text);
+impl_to_column!(bool, Boolean);
+impl_to_column!(Vec<u8>, Blob);
+impl_to_column!(Vec<f32>, Vector);
+
+impl ToColumn for &str {
+    fn to_column(&self) -> Value {
+        Value::Text((*self).to_string())
+    }
+}
+
+impl ToColumn for Value {
+    fn to_column(&self) -> Value {
+        self.clone()
+    }
+}
+
+impl<T: ToColumn> ToColumn for Option<T> {
+    fn to_column(&self) -> Value {
+        match self {
+            Some(v) => v.to_column(),
+            None => Value::Null,
+        }
+    }
+}
+
+/// Iterator returned by [`Rows::query_map`].
+pub struct QueryMap<F> {
+    rows: Rows,
+    f: F,
+}
+
+impl<T, F: FnMut(&Row) -> T> Iterator for QueryMap<F> {
+    type Item = Result<T, OxidbError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.rows.next()?.map(|row| (self.f)(&row)))
+    }
+}
+
+/// Iterator returned by [`Rows::query_and_then`].
+pub struct QueryAndThen<F> {
+    rows: Rows,
+    f: F,
+}
+
+impl<T, F: FnMut(&Row) -> Result<T, OxidbError>> Iterator for QueryAndThen<F> {
+    type Item = Result<T, OxidbError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.rows.next()? {
+            Ok(row) => Some((self.f)(&row)),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
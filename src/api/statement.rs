@@ -0,0 +1,763 @@
+//! Reusable, pre-parsed SQL statements with typed parameter binding.
+//!
+//! [`Connection::prepare`] parses a SQL string once into a [`PreparedStatement`], which
+//! [`PreparedStatement::execute`] and [`PreparedStatement::query`] then run repeatedly
+//! against different bound values, instead of re-tokenizing and re-parsing identical SQL
+//! text on every call the way `Connection::execute`/`Connection::query` do. The parsed AST
+//! and placeholder map are also cached on the `Connection` itself, keyed by SQL text, so
+//! calling `prepare` again with the same text - e.g. once per request in a hot path -
+//! skips tokenizing and parsing entirely instead of redoing it for a fresh
+//! `PreparedStatement`.
+//! [`PreparedStatement::execute_batch`] goes further for bulk loads: it takes an entire
+//! iterator of rows and commits them in configurable-size groups instead of one
+//! transaction per row. Values are
+//! bound via the [`params!`](crate::params) macro, never interpolated into the SQL text,
+//! so callers that previously built queries with `format!` plus manual `'` escaping can
+//! drop that entirely. `params!` accepts native Rust types directly (`1_i64`, `"Alice"`,
+//! `true`, ...) via the [`ToParam`] trait, converting each into the [`Value`] the engine
+//! actually binds.
+//!
+//! Placeholders come in the three forms SQLite-family engines support: anonymous `?`,
+//! numbered `?1`/`?2` (1-based), and named `:name`. The query engine itself only
+//! understands sequential anonymous `?` parameters (see
+//! `QueryExecutor::execute_parameterized_statement`), so [`Connection::prepare`]
+//! normalizes numbered and named placeholders down to that form before handing the SQL
+//! to the tokenizer/parser, and [`PreparedStatement`] reorders bound values to match
+//! the engine's expectations before executing.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::core::common::types::Value;
+use crate::core::common::OxidbError;
+use crate::core::query::commands::{Command, DescribeResult};
+use crate::core::query::executor::ExecutionResult;
+use crate::core::query::sql::ast::Statement as AstStatement;
+use crate::core::query::sql::parser::SqlParser;
+use crate::core::query::sql::tokenizer::Tokenizer;
+
+use super::connection::{data_type_to_value, Connection};
+use super::types::{DataSet, Row};
+
+/// Converts a native Rust value into the engine's [`Value`] type for parameter
+/// binding, analogous to `rusqlite`'s `ToSql`. The [`params!`](crate::params) macro
+/// calls this on each argument, so callers can write `params![1_i64, "Alice", true]`
+/// instead of hand-building `Value::Integer`/`Value::Text`/`Value::Boolean`.
+pub trait ToParam {
+    fn to_param(&self) -> Value;
+}
+
+impl ToParam for Value {
+    fn to_param(&self) -> Value {
+        self.clone()
+    }
+}
+
+impl ToParam for i64 {
+    fn to_param(&self) -> Value {
+        Value::Integer(*self)
+    }
+}
+
+impl ToParam for i32 {
+    fn to_param(&self) -> Value {
+        Value::Integer(i64::from(*self))
+    }
+}
+
+impl ToParam for f64 {
+    fn to_param(&self) -> Value {
+        Value::Float(*self)
+    }
+}
+
+impl ToParam for bool {
+    fn to_param(&self) -> Value {
+        Value::Boolean(*self)
+    }
+}
+
+impl ToParam for str {
+    fn to_param(&self) -> Value {
+        Value::Text(self.to_string())
+    }
+}
+
+impl ToParam for String {
+    fn to_param(&self) -> Value {
+        Value::Text(self.clone())
+    }
+}
+
+impl ToParam for [u8] {
+    fn to_param(&self) -> Value {
+        Value::Blob(self.to_vec())
+    }
+}
+
+impl ToParam for Vec<u8> {
+    fn to_param(&self) -> Value {
+        Value::Blob(self.clone())
+    }
+}
+
+impl ToParam for [f32] {
+    fn to_param(&self) -> Value {
+        Value::Vector(self.to_vec())
+    }
+}
+
+impl ToParam for Vec<f32> {
+    fn to_param(&self) -> Value {
+        Value::Vector(self.clone())
+    }
+}
+
+impl<T: ToParam> ToParam for Option<T> {
+    fn to_param(&self) -> Value {
+        match self {
+            Some(value) => value.to_param(),
+            None => Value::Null,
+        }
+    }
+}
+
+impl<T: ToParam + ?Sized> ToParam for &T {
+    fn to_param(&self) -> Value {
+        (*self).to_param()
+    }
+}
+
+/// Bound parameter values for a [`PreparedStatement`], built with the
+/// [`params!`](crate::params) macro.
+///
+/// `Positional` binds anonymous `?` and numbered `?N` placeholders by position (1-based,
+/// matching SQL's own `?N` numbering); `Named` binds `:name` placeholders by name.
+/// A statement must be executed with the form matching the placeholders it was written
+/// with — binding a `:name`-only statement with `Positional` (or vice versa) is an error.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParamList {
+    /// Values bound by position, 1-based: `values[0]` binds `?1` (and the first bare `?`).
+    Positional(Vec<Value>),
+    /// Values bound by name, matching a `:name` placeholder's name (without the `:`).
+    Named(Vec<(String, Value)>),
+}
+
+impl ParamList {
+    fn value_for(&self, source: &PlaceholderSource) -> Result<Value, OxidbError> {
+        match (self, source) {
+            (Self::Positional(values), PlaceholderSource::Numbered(n)) => values
+                .get(*n as usize - 1)
+                .cloned()
+                .ok_or_else(|| OxidbError::InvalidInput {
+                    message: format!("no value bound for positional parameter ?{n}"),
+                }),
+            (Self::Named(values), PlaceholderSource::Named(name)) => values
+                .iter()
+                .find(|(bound_name, _)| bound_name == name)
+                .map(|(_, value)| value.clone())
+                .ok_or_else(|| OxidbError::InvalidInput {
+                    message: format!("no value bound for named parameter :{name}"),
+                }),
+            (Self::Positional(_), PlaceholderSource::Named(name)) => Err(OxidbError::InvalidInput {
+                message: format!(
+                    "statement has named parameter :{name} but was executed with positional params!"
+                ),
+            }),
+            (Self::Named(_), PlaceholderSource::Numbered(n)) => Err(OxidbError::InvalidInput {
+                message: format!(
+                    "statement has positional parameter ?{n} but was executed with named params!"
+                ),
+            }),
+        }
+    }
+}
+
+/// Where an engine-native anonymous `?` placeholder's value comes from, in the caller's
+/// original numbered/named terms.
+#[derive(Debug, Clone)]
+enum PlaceholderSource {
+    Numbered(u32),
+    Named(String),
+}
+
+/// Maps each sequential anonymous `?` the engine sees (in source order) back to the
+/// numbered or named placeholder the caller actually wrote.
+#[derive(Debug, Clone, Default)]
+struct PlaceholderMap {
+    slots: Vec<PlaceholderSource>,
+}
+
+impl PlaceholderMap {
+    fn resolve(&self, params: &ParamList) -> Result<Vec<Value>, OxidbError> {
+        self.slots.iter().map(|source| params.value_for(source)).collect()
+    }
+}
+
+/// Converts name/value pairs into the `ParamList::Named` variant `execute`/`query`
+/// expect, so callers of `execute_with`/`query_with` don't have to build a
+/// `Vec<(String, Value)>` by hand.
+fn named_param_list(params: &[(&str, Value)]) -> ParamList {
+    ParamList::Named(params.iter().map(|(name, value)| (name.to_string(), value.clone())).collect())
+}
+
+/// Rewrites `?N` and `:name` placeholders in `sql` to plain `?`, returning the rewritten
+/// SQL alongside a [`PlaceholderMap`] recording what each `?` occurrence (in order)
+/// actually bound to. Quoted string literals are passed through untouched so a literal
+/// `?` or `:` inside a string is never mistaken for a placeholder.
+fn normalize_placeholders(sql: &str) -> (String, PlaceholderMap) {
+    let mut rewritten = String::with_capacity(sql.len());
+    let mut slots = Vec::new();
+    let mut next_auto_number: u32 = 0;
+    let mut named_slot_numbers: HashMap<String, u32> = HashMap::new();
+
+    let mut chars = sql.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(ch) = chars.next() {
+        if in_string {
+            rewritten.push(ch);
+            if ch == '\'' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match ch {
+            '\'' => {
+                in_string = true;
+                rewritten.push(ch);
+            }
+            '?' => {
+                let mut digits = String::new();
+                while let Some(&next) = chars.peek() {
+                    if next.is_ascii_digit() {
+                        digits.push(next);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                let number = if digits.is_empty() {
+                    next_auto_number += 1;
+                    next_auto_number
+                } else {
+                    let explicit: u32 = digits.parse().unwrap_or(1);
+                    next_auto_number = next_auto_number.max(explicit);
+                    explicit
+                };
+                slots.push(PlaceholderSource::Numbered(number));
+                rewritten.push('?');
+            }
+            ':' if chars.peek().is_some_and(|c| c.is_alphabetic() || *c == '_') => {
+                let mut name = String::new();
+                while let Some(&next) = chars.peek() {
+                    if next.is_alphanumeric() || next == '_' {
+                        name.push(next);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                named_slot_numbers.entry(name.clone()).or_insert_with(|| {
+                    next_auto_number += 1;
+                    next_auto_number
+                });
+                slots.push(PlaceholderSource::Named(name));
+                rewritten.push('?');
+            }
+            _ => rewritten.push(ch),
+        }
+    }
+
+    (rewritten, PlaceholderMap { slots })
+}
+
+/// A statement's parsed AST and placeholder map, cached in [`Connection::statement_cache`]
+/// keyed by the exact SQL text [`Connection::prepare`] was called with, so preparing the
+/// same SQL text again skips re-tokenizing and re-parsing it.
+#[derive(Debug, Clone)]
+pub(crate) struct CachedStatement {
+    statement: AstStatement,
+    placeholders: PlaceholderMap,
+}
+
+/// A SQL statement parsed once via [`Connection::prepare`] and executed repeatedly with
+/// different bound [`ParamList`]s, without re-tokenizing or re-parsing the SQL text.
+pub struct PreparedStatement<'conn> {
+    conn: &'conn mut Connection,
+    statement: AstStatement,
+    placeholders: PlaceholderMap,
+}
+
+impl<'conn> PreparedStatement<'conn> {
+    pub(super) fn new(conn: &'conn mut Connection, sql: &str) -> Result<Self, OxidbError> {
+        let cached = if let Some(cached) = conn.statement_cache.get(sql) {
+            cached.clone()
+        } else {
+            let (normalized_sql, placeholders) = normalize_placeholders(sql);
+
+            let mut tokenizer = Tokenizer::new(&normalized_sql);
+            let tokens = tokenizer
+                .tokenize()
+                .map_err(|e| OxidbError::SqlParsing(format!("SQL tokenizer error: {e}")))?;
+            let mut parser = SqlParser::new(tokens);
+            let statement = parser
+                .parse()
+                .map_err(|e| OxidbError::SqlParsing(format!("SQL parse error: {e}")))?;
+
+            let cached = Arc::new(CachedStatement { statement, placeholders });
+            conn.statement_cache.insert(sql.to_string(), cached.clone());
+            cached
+        };
+
+        Ok(Self { conn, statement: cached.statement.clone(), placeholders: cached.placeholders.clone() })
+    }
+
+    /// Binds `params` and runs this statement as an INSERT/UPDATE/DELETE, returning the
+    /// number of affected rows.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if `params` doesn't match the statement's placeholders (a
+    /// placeholder with no bound value, or positional params bound against named
+    /// placeholders or vice versa), or if execution fails. Values beyond the ones the
+    /// placeholders use are ignored.
+    pub fn execute(&mut self, params: ParamList) -> Result<u64, OxidbError> {
+        let values = self.placeholders.resolve(&params)?;
+        let result = self.run(values)?;
+        Ok(match result {
+            ExecutionResult::Updated { count } => count as u64,
+            ExecutionResult::Deleted(success) => u64::from(success),
+            _ => 0,
+        })
+    }
+
+    /// Binds `params` and runs this statement as a query, returning the result rows.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if `params` doesn't match the statement's placeholders, or if
+    /// execution fails.
+    pub fn query(&mut self, params: ParamList) -> Result<DataSet, OxidbError> {
+        let values = self.placeholders.resolve(&params)?;
+        let result = self.run(values)?;
+        Ok(match result {
+            ExecutionResult::Query { columns, rows } => {
+                let converted_rows = rows
+                    .into_iter()
+                    .map(|row_values| Row {
+                        values: row_values.into_iter().map(data_type_to_value).collect(),
+                    })
+                    .collect();
+                DataSet::new(columns, converted_rows)
+            }
+            _ => DataSet::new(Vec::new(), Vec::new()),
+        })
+    }
+
+    /// Binds `params` by name and runs this statement as an INSERT/UPDATE/DELETE,
+    /// returning the number of affected rows. Shorthand for
+    /// `self.execute(ParamList::Named(...))` that takes name/value pairs directly
+    /// instead of requiring the caller to build a `Vec<(String, Value)>`.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if this statement's placeholders aren't all named, a
+    /// `:name` placeholder has no matching entry in `params`, or execution fails.
+    pub fn execute_with(&mut self, params: &[(&str, Value)]) -> Result<u64, OxidbError> {
+        self.execute(named_param_list(params))
+    }
+
+    /// Binds `params` by name and runs this statement as a query, returning the result
+    /// rows. Shorthand for `self.query(ParamList::Named(...))` that takes name/value
+    /// pairs directly instead of requiring the caller to build a `Vec<(String, Value)>`.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if this statement's placeholders aren't all named, a
+    /// `:name` placeholder has no matching entry in `params`, or execution fails.
+    pub fn query_with(&mut self, params: &[(&str, Value)]) -> Result<DataSet, OxidbError> {
+        self.query(named_param_list(params))
+    }
+
+    /// Binds `params` and runs this statement as a query, returning a lazy
+    /// [`crate::api::rows::Rows`] iterator instead of the fully-collected [`DataSet`]
+    /// [`PreparedStatement::query`] returns.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if `params` doesn't match the statement's placeholders, or if
+    /// execution fails.
+    pub fn query_rows(&mut self, params: ParamList) -> Result<crate::api::rows::Rows, OxidbError> {
+        let values = self.placeholders.resolve(&params)?;
+        let result = self.run(values)?;
+        crate::api::rows::Rows::from_execution_result(result)
+    }
+
+    /// Binds `params`, runs this statement as a query, and maps each result row with `f`.
+    ///
+    /// Equivalent to `self.query_rows(params)?.query_and_then(f)`.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if `params` doesn't match the statement's placeholders, or if
+    /// execution fails.
+    pub fn query_map<T, F>(
+        &mut self,
+        params: ParamList,
+        f: F,
+    ) -> Result<crate::api::rows::QueryAndThen<F>, OxidbError>
+    where
+        F: FnMut(&crate::api::rows::Row) -> Result<T, OxidbError>,
+    {
+        Ok(self.query_rows(params)?.query_and_then(f))
+    }
+
+    /// Analyzes this statement's `?` placeholder and result-column types without
+    /// executing it, for tooling that needs to validate bindings ahead of time.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if the statement's type isn't describable, or if a
+    /// table/column it references doesn't exist.
+    pub fn describe(&mut self) -> Result<DescribeResult, OxidbError> {
+        let command = Command::Describe { statement: self.statement.clone() };
+        match self.conn.execute_command(command)? {
+            ExecutionResult::Describe(result) => Ok(result),
+            _ => Err(OxidbError::Execution(
+                "DESCRIBE did not produce a DescribeResult.".to_string(),
+            )),
+        }
+    }
+
+    /// Binds each row in `rows` to this statement's positional placeholders, in order,
+    /// and executes it, committing in groups of `batch_size` rows instead of `rows.len()`
+    /// times - the classic batch-size-vs-throughput tradeoff from bulk-loading, without
+    /// the re-tokenize-and-re-parse cost of calling [`Connection::execute`] in a loop.
+    ///
+    /// If no transaction is currently active, each batch runs inside one this method
+    /// begins and commits itself, so every `batch_size` rows become a single WAL
+    /// group-commit. If the caller already began a transaction (via
+    /// [`Connection::begin_transaction`] or [`Connection::transaction`]), rows are
+    /// executed within it as-is and left for the caller to commit, since nesting
+    /// `BEGIN`/`COMMIT` around an outer transaction isn't supported.
+    ///
+    /// Returns the total number of rows affected across every row.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if a row has no value for one of this statement's
+    /// positional placeholders, or if execution or an internally managed batch's commit
+    /// fails. On failure mid-batch, rows from prior internally managed batches remain
+    /// committed; the failing batch is rolled back (when this method owns the
+    /// transaction) before the error is returned.
+    ///
+    /// # Panics
+    /// Panics if `batch_size` is `0`.
+    pub fn execute_batch(
+        &mut self,
+        mut rows: impl Iterator<Item = Vec<Value>>,
+        batch_size: usize,
+    ) -> Result<u64, OxidbError> {
+        assert!(batch_size > 0, "batch_size must be at least 1");
+        let manage_own_transaction = !self.conn.in_transaction();
+        let mut total_affected = 0u64;
+
+        loop {
+            let Some(first_row) = rows.next() else { break };
+            if manage_own_transaction {
+                self.conn.begin_transaction()?;
+            }
+
+            let mut batch_result = self.execute_one_row(first_row);
+            for row in (&mut rows).take(batch_size - 1) {
+                if batch_result.is_err() {
+                    break;
+                }
+                batch_result = batch_result.and_then(|affected| {
+                    Ok(affected + self.execute_one_row(row)?)
+                });
+            }
+
+            match batch_result {
+                Ok(affected) => {
+                    total_affected += affected;
+                    if manage_own_transaction {
+                        self.conn.commit()?;
+                    }
+                }
+                Err(e) => {
+                    if manage_own_transaction {
+                        let _ = self.conn.rollback();
+                    }
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(total_affected)
+    }
+
+    fn execute_one_row(&mut self, row: Vec<Value>) -> Result<u64, OxidbError> {
+        self.execute(ParamList::Positional(row))
+    }
+
+    fn run(&mut self, values: Vec<Value>) -> Result<ExecutionResult, OxidbError> {
+        let command =
+            Command::ParameterizedSql { statement: self.statement.clone(), parameters: values };
+        self.conn.execute_command(command)
+    }
+}
+
+/// Builds a [`ParamList`] for binding a [`PreparedStatement`], as `rusqlite`'s `params!`
+/// does: `params![v1, v2]` binds positionally (for `?`/`?N` placeholders), and
+/// `params!["name" => v1, ...]` binds by name (for `:name` placeholders).
+#[macro_export]
+macro_rules! params {
+    () => {
+        $crate::api::statement::ParamList::Positional(::std::vec::Vec::new())
+    };
+    ($($name:literal => $value:expr),+ $(,)?) => {
+        $crate::api::statement::ParamList::Named(vec![$(
+            ($name.to_string(), $crate::api::statement::ToParam::to_param(&$value))
+        ),+])
+    };
+    ($($value:expr),+ $(,)?) => {
+        $crate::api::statement::ParamList::Positional(vec![$(
+            $crate::api::statement::ToParam::to_param(&$value)
+        ),+])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::Connection;
+
+    #[test]
+    fn normalizes_numbered_and_named_placeholders_to_plain_question_marks() {
+        let (sql, map) = normalize_placeholders("SELECT * FROM t WHERE a = ?2 AND b = :name");
+        assert_eq!(sql, "SELECT * FROM t WHERE a = ? AND b = ?");
+        assert_eq!(map.slots.len(), 2);
+        assert!(matches!(map.slots[0], PlaceholderSource::Numbered(2)));
+        assert!(matches!(map.slots[1], PlaceholderSource::Named(ref n) if n == "name"));
+    }
+
+    #[test]
+    fn ignores_placeholders_inside_string_literals() {
+        let (sql, map) = normalize_placeholders("INSERT INTO t VALUES ('a?b:c', ?1)");
+        assert_eq!(sql, "INSERT INTO t VALUES ('a?b:c', ?)");
+        assert_eq!(map.slots.len(), 1);
+    }
+
+    #[test]
+    fn execute_with_positional_params_inserts_and_reports_row_count() -> Result<(), OxidbError> {
+        let mut conn = Connection::open_in_memory()?;
+        let table = format!("stmt_pos_{}", std::process::id());
+        conn.execute(&format!("CREATE TABLE {table} (id INTEGER, name TEXT)"))?;
+
+        let mut stmt = conn.prepare(&format!("INSERT INTO {table} (id, name) VALUES (?1, ?2)"))?;
+        let affected = stmt.execute(params![Value::Integer(1), Value::Text("Alice".to_string())])?;
+        assert_eq!(affected, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn params_macro_accepts_native_rust_types_via_to_param() -> Result<(), OxidbError> {
+        let mut conn = Connection::open_in_memory()?;
+        let table = format!("stmt_to_param_{}", std::process::id());
+        conn.execute(&format!("CREATE TABLE {table} (id INTEGER, name TEXT, active BOOLEAN)"))?;
+
+        let mut stmt =
+            conn.prepare(&format!("INSERT INTO {table} (id, name, active) VALUES (?1, ?2, ?3)"))?;
+        let affected = stmt.execute(params![1_i64, "Alice", true])?;
+        assert_eq!(affected, 1);
+
+        let mut select_stmt = conn.prepare(&format!("SELECT name FROM {table} WHERE id = ?1"))?;
+        let rows = select_stmt.query(params![1_i64])?;
+        assert_eq!(rows.rows.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn execute_with_named_params_inserts_and_reports_row_count() -> Result<(), OxidbError> {
+        let mut conn = Connection::open_in_memory()?;
+        let table = format!("stmt_named_{}", std::process::id());
+        conn.execute(&format!("CREATE TABLE {table} (id INTEGER, name TEXT)"))?;
+
+        let mut stmt =
+            conn.prepare(&format!("INSERT INTO {table} (id, name) VALUES (:id, :name)"))?;
+        let affected = stmt.execute(
+            params!["id" => Value::Integer(1), "name" => Value::Text("Bob".to_string())],
+        )?;
+        assert_eq!(affected, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn repeated_execute_reuses_the_same_parsed_statement() -> Result<(), OxidbError> {
+        let mut conn = Connection::open_in_memory()?;
+        let table = format!("stmt_bulk_{}", std::process::id());
+        conn.execute(&format!("CREATE TABLE {table} (id INTEGER, data TEXT)"))?;
+
+        let mut stmt = conn.prepare(&format!("INSERT INTO {table} (id, data) VALUES (?1, ?2)"))?;
+        for i in 0..5 {
+            let affected = stmt.execute(params![Value::Integer(i), Value::Text(format!("row_{i}"))])?;
+            assert_eq!(affected, 1);
+        }
+
+        let rows = conn.query_all(&format!("SELECT * FROM {table}"))?;
+        assert_eq!(rows.len(), 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn query_returns_rows_for_select() -> Result<(), OxidbError> {
+        let mut conn = Connection::open_in_memory()?;
+        let table = format!("stmt_query_{}", std::process::id());
+        conn.execute(&format!("CREATE TABLE {table} (id INTEGER, name TEXT)"))?;
+        conn.execute(&format!("INSERT INTO {table} (id, name) VALUES (1, 'Carol')"))?;
+
+        let mut stmt = conn.prepare(&format!("SELECT * FROM {table} WHERE id = ?1"))?;
+        let result = stmt.query(params![Value::Integer(1)])?;
+        assert_eq!(result.rows.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn binding_the_wrong_param_kind_is_an_error() -> Result<(), OxidbError> {
+        let mut conn = Connection::open_in_memory()?;
+        let table = format!("stmt_mismatch_{}", std::process::id());
+        conn.execute(&format!("CREATE TABLE {table} (id INTEGER)"))?;
+
+        let mut stmt = conn.prepare(&format!("INSERT INTO {table} (id) VALUES (:id)"))?;
+        let result = stmt.execute(params![Value::Integer(1)]);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn prepared_update_and_delete_bind_parameters_natively() -> Result<(), OxidbError> {
+        let mut conn = Connection::open_in_memory()?;
+        let table = format!("stmt_upd_del_{}", std::process::id());
+        conn.execute(&format!("CREATE TABLE {table} (id INTEGER, name TEXT)"))?;
+        conn.execute(&format!("INSERT INTO {table} (id, name) VALUES (1, 'Dan')"))?;
+        conn.execute(&format!("INSERT INTO {table} (id, name) VALUES (2, 'Eve')"))?;
+
+        let mut update_stmt =
+            conn.prepare(&format!("UPDATE {table} SET name = ?1 WHERE id = ?2"))?;
+        let updated = update_stmt
+            .execute(params![Value::Text("Daniel".to_string()), Value::Integer(1)])?;
+        assert_eq!(updated, 1);
+
+        let mut delete_stmt = conn.prepare(&format!("DELETE FROM {table} WHERE id = ?1"))?;
+        let deleted = delete_stmt.execute(params![Value::Integer(2)])?;
+        assert_eq!(deleted, 1);
+
+        let rows = conn.query_all(&format!("SELECT * FROM {table}"))?;
+        assert_eq!(rows.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn prepared_select_binds_a_blob_parameter_in_a_where_clause() -> Result<(), OxidbError> {
+        let mut conn = Connection::open_in_memory()?;
+        let table = format!("stmt_blob_{}", std::process::id());
+        conn.execute(&format!("CREATE TABLE {table} (id INTEGER, data BLOB)"))?;
+
+        let mut insert_stmt =
+            conn.prepare(&format!("INSERT INTO {table} (id, data) VALUES (?1, ?2)"))?;
+        insert_stmt
+            .execute(params![Value::Integer(1), Value::Blob(vec![0xDE, 0xAD, 0xBE, 0xEF])])?;
+        insert_stmt.execute(params![Value::Integer(2), Value::Blob(vec![0x00])])?;
+
+        let mut select_stmt = conn.prepare(&format!("SELECT * FROM {table} WHERE data = ?1"))?;
+        let result = select_stmt.query(params![Value::Blob(vec![0xDE, 0xAD, 0xBE, 0xEF])])?;
+        assert_eq!(result.rows.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn prepare_caches_the_parsed_plan_by_sql_text() -> Result<(), OxidbError> {
+        let mut conn = Connection::open_in_memory()?;
+        let table = format!("stmt_cache_{}", std::process::id());
+        conn.execute(&format!("CREATE TABLE {table} (id INTEGER)"))?;
+        assert_eq!(conn.statement_cache.len(), 0);
+
+        let insert_sql = format!("INSERT INTO {table} (id) VALUES (?1)");
+        {
+            let mut stmt = conn.prepare(&insert_sql)?;
+            stmt.execute(params![Value::Integer(1)])?;
+        }
+        assert_eq!(conn.statement_cache.len(), 1);
+
+        // Preparing the exact same SQL text again reuses the cached plan instead of
+        // growing the cache or reparsing.
+        {
+            let mut stmt = conn.prepare(&insert_sql)?;
+            stmt.execute(params![Value::Integer(2)])?;
+        }
+        assert_eq!(conn.statement_cache.len(), 1);
+
+        // Different SQL text gets its own cache entry.
+        let select_sql = format!("SELECT * FROM {table}");
+        {
+            let mut stmt = conn.prepare(&select_sql)?;
+            let rows = stmt.query(ParamList::Positional(vec![]))?;
+            assert_eq!(rows.rows.len(), 2);
+        }
+        assert_eq!(conn.statement_cache.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn prepare_supports_named_parameters_via_the_cached_plan() -> Result<(), OxidbError> {
+        let mut conn = Connection::open_in_memory()?;
+        let table = format!("stmt_named_{}", std::process::id());
+        conn.execute(&format!("CREATE TABLE {table} (id INTEGER, name TEXT)"))?;
+
+        let insert_sql = format!("INSERT INTO {table} (id, name) VALUES (:id, :name)");
+        let mut stmt = conn.prepare(&insert_sql)?;
+        stmt.execute(ParamList::Named(vec![
+            ("id".to_string(), Value::Integer(1)),
+            ("name".to_string(), Value::Text("Alice".to_string())),
+        ]))?;
+        drop(stmt);
+
+        // Re-preparing the same named-parameter SQL text (now served from the cache)
+        // still resolves :name placeholders correctly.
+        let mut stmt = conn.prepare(&insert_sql)?;
+        stmt.execute(ParamList::Named(vec![
+            ("name".to_string(), Value::Text("Bob".to_string())),
+            ("id".to_string(), Value::Integer(2)),
+        ]))?;
+
+        let rows = conn.query_all(&format!("SELECT * FROM {table} ORDER BY id"))?;
+        assert_eq!(rows.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn execute_with_and_query_with_bind_by_name_without_building_param_list() -> Result<(), OxidbError>
+    {
+        let mut conn = Connection::open_in_memory()?;
+        let table = format!("stmt_with_{}", std::process::id());
+        conn.execute(&format!("CREATE TABLE {table} (id INTEGER, name TEXT)"))?;
+
+        let mut insert_stmt =
+            conn.prepare(&format!("INSERT INTO {table} (id, name) VALUES (:id, :name)"))?;
+        insert_stmt
+            .execute_with(&[("id", Value::Integer(1)), ("name", Value::Text("Alice".to_string()))])?;
+        drop(insert_stmt);
+
+        let mut select_stmt =
+            conn.prepare(&format!("SELECT * FROM {table} WHERE id = :id"))?;
+        let rows = select_stmt.query_with(&[("id", Value::Integer(1))])?;
+        assert_eq!(rows.rows.len(), 1);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+//! RAII transaction and savepoint guards built on top of `Connection`'s
+//! `BEGIN`/`COMMIT`/`ROLLBACK`/`SAVEPOINT` commands.
+//!
+//! [`Connection::transaction`] starts a transaction and returns a [`Transaction`] guard
+//! that borrows the connection for its lifetime: call [`Transaction::commit`] to make its
+//! changes permanent, or just let it drop (on an early return, a `?`, or a panic) to roll
+//! back automatically. That's the point of this module - driving transactions through
+//! `execute("BEGIN")`/`execute("COMMIT")`/`execute("ROLLBACK")` strings directly leaves the
+//! transaction open if an error happens between the `BEGIN` and the matching `COMMIT`.
+//! [`Transaction::savepoint`] nests a [`Savepoint`] inside it that can be rolled back on
+//! its own, independently, while the outer transaction stays active.
+
+use crate::core::common::OxidbError;
+use crate::core::query::commands::Command;
+pub use crate::core::transaction::TransactionBehavior;
+
+use super::connection::Connection;
+
+/// An in-progress transaction, started by [`Connection::transaction`] or
+/// [`Connection::transaction_with_behavior`].
+///
+/// Rolls back automatically on drop unless [`Transaction::commit`] (or
+/// [`Transaction::rollback`]) was called first.
+pub struct Transaction<'conn> {
+    conn: &'conn mut Connection,
+    finished: bool,
+}
+
+impl<'conn> Transaction<'conn> {
+    pub(super) fn begin(conn: &'conn mut Connection) -> Result<Self, OxidbError> {
+        conn.execute_command(Command::BeginTransaction)?;
+        Ok(Self { conn, finished: false })
+    }
+
+    pub(super) fn begin_with_behavior(
+        conn: &'conn mut Connection,
+        behavior: TransactionBehavior,
+    ) -> Result<Self, OxidbError> {
+        conn.execute_command(Command::BeginTransactionWithBehavior(behavior))?;
+        Ok(Self { conn, finished: false })
+    }
+
+    /// Commits the transaction, making its changes permanent.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if no transaction is active or the commit fails (e.g. a lock
+    /// release or WAL flush error).
+    pub fn commit(mut self) -> Result<(), OxidbError> {
+        self.conn.execute_command(Command::CommitTransaction)?;
+        self.finished = true;
+        Ok(())
+    }
+
+    /// Rolls back the transaction explicitly, undoing its changes.
+    ///
+    /// Equivalent to dropping the `Transaction`, spelled out for callers who want the
+    /// rollback's result instead of a best-effort drop.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if no transaction is active or the rollback fails.
+    pub fn rollback(mut self) -> Result<(), OxidbError> {
+        self.conn.execute_command(Command::RollbackTransaction)?;
+        self.finished = true;
+        Ok(())
+    }
+
+    /// Opens a [`Savepoint`] named `name`, nested within this transaction.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if issuing `SAVEPOINT` fails.
+    pub fn savepoint(
+        &mut self,
+        name: impl Into<String>,
+    ) -> Result<Savepoint<'_, 'conn>, OxidbError> {
+        Savepoint::new(self, name.into())
+    }
+}
+
+impl Drop for Transaction<'_> {
+    fn drop(&mut self) {
+        if !self.finished {
+            // Best-effort: a Drop impl can't propagate this error, and a transaction
+            // that failed to commit/rollback explicitly has no one left to report to.
+            let _ = self.conn.execute_command(Command::RollbackTransaction);
+        }
+    }
+}
+
+impl std::ops::Deref for Transaction<'_> {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        self.conn
+    }
+}
+
+impl std::ops::DerefMut for Transaction<'_> {
+    fn deref_mut(&mut self) -> &mut Connection {
+        self.conn
+    }
+}
+
+/// A named, nestable rollback point within a [`Transaction`], started by
+/// [`Transaction::savepoint`].
+///
+/// Rolls back to itself automatically on drop (leaving the outer transaction open)
+/// unless [`Savepoint::release`] or [`Savepoint::rollback`] was called first.
+pub struct Savepoint<'tx, 'conn> {
+    tx: &'tx mut Transaction<'conn>,
+    name: String,
+    finished: bool,
+}
+
+impl<'tx, 'conn> Savepoint<'tx, 'conn> {
+    fn new(tx: &'tx mut Transaction<'conn>, name: String) -> Result<Self, OxidbError> {
+        tx.conn.execute_command(Command::Savepoint(name.clone()))?;
+        Ok(Self { tx, name, finished: false })
+    }
+
+    /// Releases the savepoint, folding its changes into the enclosing transaction.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if issuing `RELEASE` fails (e.g. the savepoint was already
+    /// released or rolled back).
+    pub fn release(mut self) -> Result<(), OxidbError> {
+        self.tx.conn.execute_command(Command::ReleaseSavepoint(self.name.clone()))?;
+        self.finished = true;
+        Ok(())
+    }
+
+    /// Rolls back to this savepoint, undoing everything done since it was created, while
+    /// leaving the savepoint itself and the outer transaction active.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if issuing `ROLLBACK TO` fails.
+    pub fn rollback(mut self) -> Result<(), OxidbError> {
+        self.tx.conn.execute_command(Command::RollbackToSavepoint(self.name.clone()))?;
+        self.finished = true;
+        Ok(())
+    }
+}
+
+impl Drop for Savepoint<'_, '_> {
+    fn drop(&mut self) {
+        if !self.finished {
+            let _ =
+                self.tx.conn.execute_command(Command::RollbackToSavepoint(self.name.clone()));
+        }
+    }
+}
@@ -0,0 +1,271 @@
+//! A small benchmarking harness for comparing OxiDB workloads across repeated samples,
+//! instead of a single `Instant::now()`-wrapped run.
+//!
+//! [`run_workload`] samples a named closure `iterations` times, rebuilding fixtures fresh
+//! via a `setup` closure before each sample - so a later sample never benefits from an
+//! earlier one's warm cache - and returns a [`WorkloadMetrics`] holding the resulting
+//! per-sample latency distribution. [`compare`] takes two [`WorkloadMetrics`] (e.g. an
+//! unindexed baseline and an indexed candidate) and reports the speedup between their
+//! medians, flagging when it's not distinguishable from sampling noise.
+
+use std::time::{Duration, Instant};
+
+/// The latency distribution and throughput from sampling one workload `iterations` times.
+#[derive(Debug, Clone)]
+pub struct WorkloadMetrics {
+    /// Every sample's elapsed duration, in the order they were run.
+    samples: Vec<Duration>,
+    /// How many logical units (queries, rows, ...) each sample processed, used to
+    /// compute [`WorkloadMetrics::throughput`].
+    elements_per_sample: usize,
+}
+
+impl WorkloadMetrics {
+    /// Builds metrics directly from already-measured per-sample durations, for callers
+    /// timing something [`run_workload`] can't drive directly (e.g. an external process).
+    #[must_use]
+    pub fn new(samples: Vec<Duration>, elements_per_sample: usize) -> Self {
+        Self { samples, elements_per_sample }
+    }
+
+    /// The raw per-sample durations this was built from, in run order.
+    #[must_use]
+    pub fn samples(&self) -> &[Duration] {
+        &self.samples
+    }
+
+    /// The arithmetic mean sample duration.
+    #[must_use]
+    pub fn mean(&self) -> Duration {
+        if self.samples.is_empty() {
+            return Duration::ZERO;
+        }
+        self.samples.iter().sum::<Duration>() / self.samples.len() as u32
+    }
+
+    /// The median (p50) sample duration.
+    #[must_use]
+    pub fn median(&self) -> Duration {
+        self.percentile(50.0)
+    }
+
+    /// The p95 sample duration.
+    #[must_use]
+    pub fn p95(&self) -> Duration {
+        self.percentile(95.0)
+    }
+
+    /// The p99 sample duration.
+    #[must_use]
+    pub fn p99(&self) -> Duration {
+        self.percentile(99.0)
+    }
+
+    /// The `p`th percentile (0-100) of the sample durations, by nearest-rank on the
+    /// sorted samples. Simple and dependency-free, which is adequate for the sample
+    /// sizes (tens to low hundreds) this harness is meant for.
+    #[must_use]
+    pub fn percentile(&self, p: f64) -> Duration {
+        if self.samples.is_empty() {
+            return Duration::ZERO;
+        }
+        let mut sorted = self.samples.clone();
+        sorted.sort_unstable();
+        #[allow(clippy::cast_precision_loss, clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+        let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+        let index = rank.saturating_sub(1).min(sorted.len() - 1);
+        sorted[index]
+    }
+
+    /// Elements processed per second, computed from the mean sample duration.
+    #[must_use]
+    pub fn throughput(&self) -> f64 {
+        let mean_secs = self.mean().as_secs_f64();
+        if mean_secs == 0.0 {
+            0.0
+        } else {
+            self.elements_per_sample as f64 / mean_secs
+        }
+    }
+}
+
+/// Samples `workload` `iterations` times, calling `setup` fresh before each sample so a
+/// later sample never benefits from an earlier sample's warm cache, and timing only
+/// `workload` itself.
+///
+/// `elements_per_sample` is how many logical units (queries, rows, ...) each call to
+/// `workload` processes, for [`WorkloadMetrics::throughput`].
+///
+/// # Panics
+/// Panics if `iterations` is `0`.
+pub fn run_workload<T>(
+    iterations: usize,
+    elements_per_sample: usize,
+    mut setup: impl FnMut() -> T,
+    mut workload: impl FnMut(&mut T),
+) -> WorkloadMetrics {
+    assert!(iterations > 0, "iterations must be at least 1");
+    let mut samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let mut fixture = setup();
+        let start = Instant::now();
+        workload(&mut fixture);
+        samples.push(start.elapsed());
+    }
+    WorkloadMetrics::new(samples, elements_per_sample)
+}
+
+/// The result of comparing a `baseline` workload's metrics against a `candidate`'s -
+/// e.g. an unindexed query plan against an indexed one - returned by [`compare`].
+#[derive(Debug, Clone, Copy)]
+pub struct Comparison {
+    /// `baseline`'s median duration divided by `candidate`'s: greater than `1.0` means
+    /// `candidate` was faster.
+    pub speedup: f64,
+    /// `true` when the two medians are closer together than either side's own p95
+    /// spread, i.e. the speedup isn't reliably distinguishable from sampling noise at
+    /// this sample size.
+    pub within_noise: bool,
+}
+
+/// Compares `baseline` against `candidate` (e.g. an unindexed query plan against an
+/// indexed one), returning the median-to-median speedup and whether it's distinguishable
+/// from sampling noise.
+///
+/// Noise is judged by comparing the difference between the two medians against each
+/// side's own p95-minus-median spread: if the median difference doesn't exceed either
+/// side's spread, the two workloads' distributions overlap enough that the speedup
+/// shouldn't be reported as reliable.
+#[must_use]
+pub fn compare(baseline: &WorkloadMetrics, candidate: &WorkloadMetrics) -> Comparison {
+    let baseline_median = baseline.median().as_secs_f64();
+    let candidate_median = candidate.median().as_secs_f64();
+    let speedup =
+        if candidate_median == 0.0 { 0.0 } else { baseline_median / candidate_median };
+
+    let baseline_spread = (baseline.p95().as_secs_f64() - baseline_median).abs();
+    let candidate_spread = (candidate.p95().as_secs_f64() - candidate_median).abs();
+    let median_diff = (baseline_median - candidate_median).abs();
+    let within_noise = median_diff <= baseline_spread.max(candidate_spread);
+
+    Comparison { speedup, within_noise }
+}
+
+/// Renders `baseline` vs. `candidate`'s medians, p95/p99, and throughput as the boxed
+/// comparison table `examples/performance_optimization_demo.rs`'s `compare_performance`
+/// used to print from a single run apiece, plus `comparison`'s speedup and a noise note.
+#[must_use]
+pub fn comparison_table(
+    baseline_name: &str,
+    baseline: &WorkloadMetrics,
+    candidate_name: &str,
+    candidate: &WorkloadMetrics,
+    comparison: &Comparison,
+) -> String {
+    let mut out = String::new();
+    out.push_str(&format!("┌─ {baseline_name} vs. {candidate_name} ─\n"));
+    out.push_str(&format!(
+        "│ median   : {:>10.3}ms │ {:>10.3}ms\n",
+        baseline.median().as_secs_f64() * 1000.0,
+        candidate.median().as_secs_f64() * 1000.0
+    ));
+    out.push_str(&format!(
+        "│ p95      : {:>10.3}ms │ {:>10.3}ms\n",
+        baseline.p95().as_secs_f64() * 1000.0,
+        candidate.p95().as_secs_f64() * 1000.0
+    ));
+    out.push_str(&format!(
+        "│ p99      : {:>10.3}ms │ {:>10.3}ms\n",
+        baseline.p99().as_secs_f64() * 1000.0,
+        candidate.p99().as_secs_f64() * 1000.0
+    ));
+    out.push_str(&format!(
+        "│ elems/sec: {:>10.1}   │ {:>10.1}\n",
+        baseline.throughput(),
+        candidate.throughput()
+    ));
+    out.push_str(&format!("└─ speedup: {:.2}x", comparison.speedup));
+    if comparison.within_noise {
+        out.push_str(" (within sampling noise - not a reliable difference at this sample size)");
+    }
+    out.push('\n');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentile_of_single_sample_is_that_sample() {
+        let metrics = WorkloadMetrics::new(vec![Duration::from_millis(10)], 1);
+        assert_eq!(metrics.median(), Duration::from_millis(10));
+        assert_eq!(metrics.p95(), Duration::from_millis(10));
+    }
+
+    #[test]
+    fn percentile_picks_nearest_rank_on_sorted_samples() {
+        let samples: Vec<Duration> = (1..=100).map(Duration::from_millis).collect();
+        let metrics = WorkloadMetrics::new(samples, 1);
+        assert_eq!(metrics.median(), Duration::from_millis(50));
+        assert_eq!(metrics.p95(), Duration::from_millis(95));
+        assert_eq!(metrics.p99(), Duration::from_millis(99));
+    }
+
+    #[test]
+    fn throughput_is_elements_over_mean_duration() {
+        let metrics = WorkloadMetrics::new(vec![Duration::from_secs(1), Duration::from_secs(1)], 10);
+        assert!((metrics.throughput() - 10.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn run_workload_rebuilds_the_fixture_fresh_every_sample() {
+        let mut setup_calls = 0;
+        let metrics = run_workload(
+            5,
+            1,
+            || {
+                setup_calls += 1;
+                setup_calls
+            },
+            |_fixture| {},
+        );
+        assert_eq!(setup_calls, 5);
+        assert_eq!(metrics.samples().len(), 5);
+    }
+
+    #[test]
+    fn compare_reports_speedup_when_candidate_is_reliably_faster() {
+        let baseline =
+            WorkloadMetrics::new(vec![Duration::from_millis(100); 20], 1);
+        let candidate =
+            WorkloadMetrics::new(vec![Duration::from_millis(10); 20], 1);
+        let comparison = compare(&baseline, &candidate);
+        assert!((comparison.speedup - 10.0).abs() < 0.001);
+        assert!(!comparison.within_noise);
+    }
+
+    #[test]
+    fn compare_flags_indistinguishable_workloads_as_within_noise() {
+        let baseline = WorkloadMetrics::new(
+            vec![
+                Duration::from_millis(9),
+                Duration::from_millis(10),
+                Duration::from_millis(11),
+                Duration::from_millis(12),
+            ],
+            1,
+        );
+        let candidate = WorkloadMetrics::new(
+            vec![
+                Duration::from_millis(10),
+                Duration::from_millis(11),
+                Duration::from_millis(9),
+                Duration::from_millis(12),
+            ],
+            1,
+        );
+        let comparison = compare(&baseline, &candidate);
+        assert!(comparison.within_noise);
+    }
+}
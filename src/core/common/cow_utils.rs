@@ -231,6 +231,10 @@ impl CowUtils {
             )),
             DataType::Map(_) => Cow::Borrowed("{Map}"),
             DataType::JsonBlob(_) => Cow::Borrowed("{JsonBlob}"),
+            DataType::Decimal { unscaled, scale, .. } => {
+                Cow::Owned(crate::core::types::decimal::format_decimal(*unscaled, *scale))
+            }
+            DataType::Enum { value, .. } => Cow::Borrowed(value),
         }
     }
 
@@ -244,10 +248,18 @@ impl CowUtils {
             (DataType::Boolean(a), DataType::Boolean(b)) => a == b,
             (DataType::Null, DataType::Null) => true,
             (DataType::RawBytes(a), DataType::RawBytes(b)) => a == b,
+            (
+                DataType::Enum { type_name: t1, value: v1 },
+                DataType::Enum { type_name: t2, value: v2 },
+            ) => t1 == t2 && v1 == v2,
             (DataType::Vector(a), DataType::Vector(b)) => {
                 a.data.len() == b.data.len()
                     && a.data.iter().zip(b.data.iter()).all(|(x, y)| (x - y).abs() < f32::EPSILON)
             }
+            (
+                DataType::Decimal { unscaled: u1, scale: s1, .. },
+                DataType::Decimal { unscaled: u2, scale: s2, .. },
+            ) => u1 == u2 && s1 == s2,
             _ => false,
         }
     }
@@ -270,6 +282,9 @@ impl CowUtils {
         match dt {
             DataType::Integer(i) => Some(*i as f64),
             DataType::Float(f) => Some(*f),
+            DataType::Decimal { unscaled, scale, .. } => {
+                Some(crate::core::types::decimal::decimal_to_f64(*unscaled, *scale))
+            }
             DataType::String(s) => s.parse().ok(),
             _ => None,
         }
@@ -0,0 +1,185 @@
+//! Pure Rust CRC32C (Castagnoli) implementation using only core and alloc
+//!
+//! This mirrors [`crate::core::common::crc32`]'s pure-Rust IEEE CRC32 implementation,
+//! but with the Castagnoli polynomial used for page-level integrity checksums (the
+//! variant also used by iSCSI, SCTP, and ext4 metadata checksums).
+
+/// CRC32C (Castagnoli) polynomial, reflected form.
+const CRC32C_POLYNOMIAL: u32 = 0x82F6_3B78;
+
+/// Precomputed CRC32C lookup table for performance.
+const CRC32C_TABLE: [u32; 256] = generate_crc32c_table();
+
+/// Slicing-by-8 lookup tables: `SLICING_TABLES[0]` is [`CRC32C_TABLE`], and
+/// `SLICING_TABLES[n]` for `n` in 1..8 folds one more input byte per entry, letting
+/// `Hasher::update` consume 8 bytes per iteration instead of 1 on its fast path.
+const SLICING_TABLES: [[u32; 256]; 8] = generate_slicing_tables();
+
+/// Generate the CRC32C lookup table at compile time.
+const fn generate_crc32c_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut j = 0;
+
+        while j < 8 {
+            if crc & 1 == 1 {
+                crc = (crc >> 1) ^ CRC32C_POLYNOMIAL;
+            } else {
+                crc >>= 1;
+            }
+            j += 1;
+        }
+
+        table[i] = crc;
+        i += 1;
+    }
+
+    table
+}
+
+/// Generate the eight slicing-by-8 tables at compile time from [`CRC32C_TABLE`].
+const fn generate_slicing_tables() -> [[u32; 256]; 8] {
+    let mut tables = [[0u32; 256]; 8];
+    tables[0] = CRC32C_TABLE;
+
+    let mut n = 1;
+    while n < 8 {
+        let mut i = 0;
+        while i < 256 {
+            let prev = tables[n - 1][i];
+            tables[n][i] = (prev >> 8) ^ CRC32C_TABLE[(prev & 0xFF) as usize];
+            i += 1;
+        }
+        n += 1;
+    }
+
+    tables
+}
+
+/// CRC32C hasher that maintains state for incremental hashing.
+#[derive(Debug, Clone)]
+pub struct Hasher {
+    state: u32,
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Hasher {
+    /// Creates a new CRC32C hasher with initial state.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { state: 0xFFFF_FFFF }
+    }
+
+    /// Updates the hash with a byte slice, using the slicing-by-8 fast path while at
+    /// least 8 bytes remain and falling back to the byte-at-a-time table lookup for
+    /// the tail.
+    pub fn update(&mut self, data: &[u8]) {
+        let mut chunks = data.chunks_exact(8);
+        for chunk in &mut chunks {
+            let first4 = [chunk[0], chunk[1], chunk[2], chunk[3]];
+            let x = self.state ^ u32::from_le_bytes(first4);
+            self.state = SLICING_TABLES[7][(x & 0xFF) as usize]
+                ^ SLICING_TABLES[6][((x >> 8) & 0xFF) as usize]
+                ^ SLICING_TABLES[5][((x >> 16) & 0xFF) as usize]
+                ^ SLICING_TABLES[4][(x >> 24) as usize]
+                ^ SLICING_TABLES[3][chunk[4] as usize]
+                ^ SLICING_TABLES[2][chunk[5] as usize]
+                ^ SLICING_TABLES[1][chunk[6] as usize]
+                ^ SLICING_TABLES[0][chunk[7] as usize];
+        }
+
+        for &byte in chunks.remainder() {
+            let table_idx = ((self.state ^ u32::from(byte)) & 0xFF) as usize;
+            self.state = (self.state >> 8) ^ CRC32C_TABLE[table_idx];
+        }
+    }
+
+    /// Finalizes the hash and returns the checksum.
+    #[must_use]
+    pub const fn finalize(&self) -> u32 {
+        !self.state
+    }
+
+    /// Hashes `data` and returns its checksum in one call.
+    pub fn hash(data: &[u8]) -> u32 {
+        let mut hasher = Self::new();
+        hasher.update(data);
+        hasher.finalize()
+    }
+}
+
+/// Calculates the CRC32C checksum for a byte slice.
+#[must_use]
+pub fn checksum(data: &[u8]) -> u32 {
+    Hasher::hash(data)
+}
+
+/// Verifies data integrity by comparing with an expected checksum.
+#[must_use]
+pub fn verify(data: &[u8], expected: u32) -> bool {
+    checksum(data) == expected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_input() {
+        assert_eq!(checksum(&[]), 0);
+    }
+
+    #[test]
+    fn test_known_value() {
+        // Canonical CRC32C test vector.
+        assert_eq!(checksum(b"123456789"), 0xE306_9283);
+    }
+
+    #[test]
+    fn test_incremental_hashing() {
+        let data = b"Hello, World!";
+
+        let full_hash = checksum(data);
+
+        let mut hasher = Hasher::new();
+        hasher.update(b"Hello");
+        hasher.update(b", ");
+        hasher.update(b"World!");
+        let incremental_hash = hasher.finalize();
+
+        assert_eq!(full_hash, incremental_hash);
+    }
+
+    #[test]
+    fn test_verify() {
+        let data = b"Test data";
+        let checksum = checksum(data);
+
+        assert!(verify(data, checksum));
+        assert!(!verify(data, checksum + 1));
+    }
+
+    #[test]
+    fn test_slicing_by_8_matches_byte_at_a_time_for_every_tail_length() {
+        let data: Vec<u8> = (0u32..64).map(|b| b as u8).collect();
+
+        // Exercise every remainder (0..8) the slicing-by-8 fast path can leave behind.
+        for len in 0..data.len() {
+            let fast = checksum(&data[..len]);
+
+            let mut byte_at_a_time = Hasher::new();
+            for &byte in &data[..len] {
+                byte_at_a_time.update(core::slice::from_ref(&byte));
+            }
+            assert_eq!(fast, byte_at_a_time.finalize(), "mismatch at len {len}");
+        }
+    }
+}
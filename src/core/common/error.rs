@@ -106,6 +106,12 @@ impl From<serde_json::Error> for OxidbError {
     }
 }
 
+impl From<crate::core::recovery::RecoveryError> for OxidbError {
+    fn from(err: crate::core::recovery::RecoveryError) -> Self {
+        Self::Storage(format!("WAL recovery failed: {err}"))
+    }
+}
+
 impl From<crate::core::indexing::btree::OxidbError> for OxidbError {
     fn from(err: crate::core::indexing::btree::OxidbError) -> Self {
         match err {
@@ -2,10 +2,12 @@ pub mod bincode_compat; // Pure Rust binary serialization
 pub mod byteorder; // Pure Rust byte order handling
 pub mod cow_utils; // Performance optimizations using Copy-on-Write
 pub mod crc32; // Pure Rust CRC32 implementation
+pub mod crc32c; // Pure Rust CRC32C (Castagnoli) implementation, used for page checksums
 pub mod error; // Consolidated error handling
 pub mod hex; // Pure Rust hex encoding/decoding
 pub mod io_utils; // IO utilities following DRY principle
 pub mod lock_utils; // Lock error handling utilities
+pub mod md5; // Pure Rust MD5 implementation, used for sqllogictest hash-threshold results
 pub mod result_utils; // New result utilities module
 pub mod serialization;
 pub mod traits;
@@ -0,0 +1,836 @@
+//! RDF Turtle / N-Triples import and export for the graph subsystem.
+//!
+//! This lets a [`GraphStore`] be bulk-loaded from, or dumped to, the two most common
+//! RDF interchange formats. Parsing is streaming: each statement is parsed and applied
+//! to the graph as soon as it is read, rather than buffering the whole document first.
+//!
+//! Graph nodes map to RDF subjects/objects (IRIs or blank nodes); [`GraphData::label`]
+//! maps to an `rdf:type` triple; scalar properties map to predicate/literal triples; and
+//! edges map to predicate triples between two node IRIs. To let an exported graph
+//! re-import to an isomorphic graph, each node's original IRI or blank node identifier
+//! is preserved as a hidden `__rdf_id` property and reused on export.
+
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+
+use super::storage::GraphStore;
+use super::types::{GraphData, NodeId, Relationship};
+use crate::core::common::types::Value;
+use crate::core::common::OxidbError;
+
+/// The `rdf:type` predicate, spelled out as its full IRI.
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+/// Default namespace used for node labels and edge predicates that have no other
+/// natural IRI (i.e. anything that did not originate from an RDF import).
+const DEFAULT_NS: &str = "http://oxidb.dev/ns#";
+/// Hidden node property used to remember a node's original IRI or blank node
+/// identifier, so that `dump_turtle`/`dump_ntriples` can round-trip it stably.
+const RDF_ID_PROPERTY: &str = "__rdf_id";
+
+/// An RDF term: an IRI, a blank node, or a literal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Term {
+    Iri(String),
+    BlankNode(String),
+    /// A literal's lexical form, plus an optional `^^<datatype IRI>`.
+    Literal(String, Option<String>),
+}
+
+/// One parsed RDF triple.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Triple {
+    subject: Term,
+    predicate: String,
+    object: Term,
+}
+
+/// Bulk-load a Turtle document into `store`, returning the number of triples applied.
+///
+/// Parsing is streaming: statements are read and applied one at a time. `@prefix` and
+/// `@base` directives are honored for every statement that follows them. Only a
+/// practical subset of Turtle is supported: predicate lists (`;`) and object lists
+/// (`,`), IRIs, prefixed names, blank nodes, and quoted literals with an optional
+/// `^^<datatype>` suffix; collections (`( ... )`) and blank node property lists
+/// (`[ ... ]`) are not supported.
+///
+/// # Errors
+///
+/// Returns [`OxidbError::ParseError`] if the document is not well-formed Turtle, and
+/// propagates any [`OxidbError`] raised while applying triples to `store`.
+pub fn load_turtle<R: BufRead>(reader: R, store: &mut dyn GraphStore) -> Result<usize, OxidbError> {
+    let mut prefixes: HashMap<String, String> = HashMap::new();
+    let mut base: Option<String> = None;
+    let mut nodes: HashMap<Term, NodeId> = HashMap::new();
+    let mut count = 0usize;
+
+    for statement in TurtleStatements::new(reader) {
+        let statement = statement?;
+        let trimmed = statement.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        if let Some(rest) = strip_directive(trimmed, "@prefix") {
+            let (name, iri) = parse_prefix_directive(rest)?;
+            prefixes.insert(name, iri);
+            continue;
+        }
+        if let Some(rest) = strip_directive(trimmed, "@base") {
+            base = Some(parse_base_directive(rest)?);
+            continue;
+        }
+        for triple in parse_triple_statement(trimmed, &prefixes, base.as_deref())? {
+            apply_triple(store, &triple, &mut nodes)?;
+            count += 1;
+        }
+    }
+
+    Ok(count)
+}
+
+/// Bulk-load an N-Triples document into `store`, returning the number of triples
+/// applied. N-Triples has no prefixes or directives, so each line is an independent,
+/// fully-expanded triple; this makes loading naturally streaming (one line in memory
+/// at a time).
+///
+/// # Errors
+///
+/// Returns [`OxidbError::ParseError`] if a non-blank line is not a well-formed
+/// N-Triples statement, and propagates any [`OxidbError`] raised while applying
+/// triples to `store`.
+pub fn load_ntriples<R: BufRead>(
+    reader: R,
+    store: &mut dyn GraphStore,
+) -> Result<usize, OxidbError> {
+    let mut nodes: HashMap<Term, NodeId> = HashMap::new();
+    let mut count = 0usize;
+
+    for line in reader.lines() {
+        let line = line.map_err(|e| OxidbError::Io(e.to_string()))?;
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        for triple in parse_triple_statement(trimmed, &HashMap::new(), None)? {
+            apply_triple(store, &triple, &mut nodes)?;
+            count += 1;
+        }
+    }
+
+    Ok(count)
+}
+
+/// Dump every node and edge in `store` as a Turtle document, writing to `writer`.
+///
+/// Node labels become `rdf:type` triples, node properties become literal triples, and
+/// edges become predicate triples between the two endpoint nodes. A node that was
+/// originally imported from RDF (i.e. carries a `__rdf_id` property) keeps its
+/// original IRI or blank node identifier, so round-tripping `load_turtle` followed by
+/// `dump_turtle` produces an isomorphic graph.
+///
+/// # Errors
+///
+/// Propagates any [`OxidbError`] raised while reading `store`, or an
+/// [`OxidbError::Io`] if writing to `writer` fails.
+pub fn dump_turtle<W: Write>(store: &dyn GraphStore, writer: &mut W) -> Result<(), OxidbError> {
+    let rdf_ns = rdf_namespace();
+    writeln!(writer, "@prefix rdf: <{rdf_ns}> .").map_err(|e| OxidbError::Io(e.to_string()))?;
+    writeln!(writer, "@prefix ox: <{DEFAULT_NS}> .").map_err(|e| OxidbError::Io(e.to_string()))?;
+    writeln!(writer).map_err(|e| OxidbError::Io(e.to_string()))?;
+
+    for triple in graph_to_triples(store)? {
+        writeln!(
+            writer,
+            "{} {} {} .",
+            format_term_turtle(&triple.subject),
+            format_predicate_turtle(&triple.predicate),
+            match &triple.object {
+                Term::Iri(iri) => compact_iri_turtle(iri),
+                other => format_term_turtle(other),
+            }
+        )
+        .map_err(|e| OxidbError::Io(e.to_string()))?;
+    }
+
+    Ok(())
+}
+
+/// Dump every node and edge in `store` as an N-Triples document, writing to `writer`.
+///
+/// Unlike [`dump_turtle`], every IRI is fully expanded and one triple is written per
+/// line, with no prefixes. See [`dump_turtle`] for how nodes/edges map to triples.
+///
+/// # Errors
+///
+/// Propagates any [`OxidbError`] raised while reading `store`, or an
+/// [`OxidbError::Io`] if writing to `writer` fails.
+pub fn dump_ntriples<W: Write>(store: &dyn GraphStore, writer: &mut W) -> Result<(), OxidbError> {
+    for triple in graph_to_triples(store)? {
+        writeln!(
+            writer,
+            "{} <{}> {} .",
+            format_term_turtle(&triple.subject),
+            triple.predicate,
+            format_term_turtle(&triple.object)
+        )
+        .map_err(|e| OxidbError::Io(e.to_string()))?;
+    }
+
+    Ok(())
+}
+
+fn rdf_namespace() -> &'static str {
+    "http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+}
+
+// --- Graph <-> Triple conversion -------------------------------------------------
+
+fn node_term(node_id: NodeId, data: &GraphData) -> Term {
+    match data.properties.get(RDF_ID_PROPERTY) {
+        Some(Value::Text(id)) if id.starts_with("_:") => {
+            Term::BlankNode(id.trim_start_matches("_:").to_string())
+        }
+        Some(Value::Text(iri)) => Term::Iri(iri.clone()),
+        _ => Term::BlankNode(format!("n{node_id}")),
+    }
+}
+
+fn graph_to_triples(store: &dyn GraphStore) -> Result<Vec<Triple>, OxidbError> {
+    let mut triples = Vec::new();
+
+    for node in store.all_nodes()? {
+        let subject = node_term(node.id, &node.data);
+        triples.push(Triple {
+            subject: subject.clone(),
+            predicate: RDF_TYPE.to_string(),
+            object: Term::Iri(format!("{DEFAULT_NS}{}", node.data.label)),
+        });
+        for (key, value) in &node.data.properties {
+            if key == RDF_ID_PROPERTY {
+                continue;
+            }
+            let (lexical, datatype) = value_to_literal(value);
+            triples.push(Triple {
+                subject: subject.clone(),
+                predicate: format!("{DEFAULT_NS}{key}"),
+                object: Term::Literal(lexical, datatype),
+            });
+        }
+    }
+
+    let nodes_by_id: HashMap<NodeId, GraphData> = store
+        .all_nodes()?
+        .into_iter()
+        .map(|node| (node.id, node.data))
+        .collect();
+
+    for edge in store.all_edges()? {
+        let (Some(from_data), Some(to_data)) =
+            (nodes_by_id.get(&edge.from_node), nodes_by_id.get(&edge.to_node))
+        else {
+            continue;
+        };
+        triples.push(Triple {
+            subject: node_term(edge.from_node, from_data),
+            predicate: format!("{DEFAULT_NS}{}", edge.relationship.name),
+            object: node_term(edge.to_node, to_data),
+        });
+    }
+
+    Ok(triples)
+}
+
+fn value_to_literal(value: &Value) -> (String, Option<String>) {
+    const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+    match value {
+        Value::Integer(i) => (i.to_string(), Some(format!("{XSD}integer"))),
+        Value::Float(f) => (f.to_string(), Some(format!("{XSD}double"))),
+        Value::Boolean(b) => (b.to_string(), Some(format!("{XSD}boolean"))),
+        Value::Text(s) => (s.clone(), None),
+        Value::Blob(bytes) => (hex_encode(bytes), Some(format!("{XSD}hexBinary"))),
+        Value::Vector(values) => (
+            values.iter().map(ToString::to_string).collect::<Vec<_>>().join(","),
+            Some(format!("{DEFAULT_NS}vector")),
+        ),
+        Value::Null => (String::new(), Some(format!("{DEFAULT_NS}null"))),
+    }
+}
+
+fn literal_to_value(lexical: &str, datatype: Option<&str>) -> Value {
+    const XSD_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#integer";
+    const XSD_DOUBLE: &str = "http://www.w3.org/2001/XMLSchema#double";
+    const XSD_BOOLEAN: &str = "http://www.w3.org/2001/XMLSchema#boolean";
+    match datatype {
+        Some(XSD_INTEGER) => {
+            lexical.parse::<i64>().map_or_else(|_| Value::Text(lexical.to_string()), Value::Integer)
+        }
+        Some(XSD_DOUBLE) => {
+            lexical.parse::<f64>().map_or_else(|_| Value::Text(lexical.to_string()), Value::Float)
+        }
+        Some(XSD_BOOLEAN) => {
+            lexical.parse::<bool>().map_or_else(|_| Value::Text(lexical.to_string()), Value::Boolean)
+        }
+        _ => Value::Text(lexical.to_string()),
+    }
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Apply a single parsed triple to `store`, creating nodes on first reference and
+/// remembering each subject/object's original identifier via `__rdf_id`.
+fn apply_triple(
+    store: &mut dyn GraphStore,
+    triple: &Triple,
+    nodes: &mut HashMap<Term, NodeId>,
+) -> Result<(), OxidbError> {
+    let subject_id = get_or_create_node(store, nodes, &triple.subject)?;
+
+    if triple.predicate == RDF_TYPE {
+        if let Term::Iri(iri) = &triple.object {
+            if let Some(mut node) = store.get_node(subject_id)? {
+                node.data.label = local_name(iri).to_string();
+                store.update_node_data(subject_id, node.data)?;
+            }
+        }
+        return Ok(());
+    }
+
+    match &triple.object {
+        Term::Literal(lexical, datatype) => {
+            if let Some(mut node) = store.get_node(subject_id)? {
+                let value = literal_to_value(lexical, datatype.as_deref());
+                node.data.properties.insert(local_name(&triple.predicate).to_string(), value);
+                store.update_node_data(subject_id, node.data)?;
+            }
+            Ok(())
+        }
+        Term::Iri(_) | Term::BlankNode(_) => {
+            let object_id = get_or_create_node(store, nodes, &triple.object)?;
+            let relationship = Relationship::new(local_name(&triple.predicate).to_string());
+            store.add_edge(subject_id, object_id, relationship, None)?;
+            Ok(())
+        }
+    }
+}
+
+fn get_or_create_node(
+    store: &mut dyn GraphStore,
+    nodes: &mut HashMap<Term, NodeId>,
+    term: &Term,
+) -> Result<NodeId, OxidbError> {
+    if let Some(id) = nodes.get(term) {
+        return Ok(*id);
+    }
+
+    let rdf_id = match term {
+        Term::Iri(iri) => iri.clone(),
+        Term::BlankNode(label) => format!("_:{label}"),
+        Term::Literal(..) => {
+            return Err(OxidbError::ParseError(
+                "a literal cannot be used as an RDF subject or object node".to_string(),
+            ))
+        }
+    };
+
+    let data = GraphData::new(String::new()).with_property(
+        RDF_ID_PROPERTY.to_string(),
+        Value::Text(rdf_id),
+    );
+    let node_id = store.add_node(data)?;
+    nodes.insert(term.clone(), node_id);
+    Ok(node_id)
+}
+
+fn local_name(iri: &str) -> &str {
+    let cut = iri.rfind(['#', '/']).map_or(0, |i| i + 1);
+    &iri[cut..]
+}
+
+// --- Turtle statement splitting --------------------------------------------------
+
+/// Splits a Turtle document into `. `-terminated statements, one at a time, so that
+/// `load_turtle` never has to hold the whole document in memory at once.
+struct TurtleStatements<R: BufRead> {
+    reader: R,
+}
+
+impl<R: BufRead> TurtleStatements<R> {
+    const fn new(reader: R) -> Self {
+        Self { reader }
+    }
+}
+
+impl<R: BufRead> Iterator for TurtleStatements<R> {
+    type Item = Result<String, OxidbError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut statement = String::new();
+        loop {
+            let mut line = String::new();
+            match self.reader.read_line(&mut line) {
+                Ok(0) => {
+                    return if statement.trim().is_empty() {
+                        None
+                    } else {
+                        Some(Err(OxidbError::ParseError(format!(
+                            "unterminated Turtle statement: {statement}"
+                        ))))
+                    };
+                }
+                Ok(_) => {
+                    let trimmed = line.trim();
+                    if trimmed.starts_with('#') && statement.trim().is_empty() {
+                        continue;
+                    }
+                    if !statement.is_empty() {
+                        statement.push(' ');
+                    }
+                    statement.push_str(trimmed);
+                    if statement_is_complete(&statement) {
+                        return Some(Ok(statement));
+                    }
+                }
+                Err(e) => return Some(Err(OxidbError::Io(e.to_string()))),
+            }
+        }
+    }
+}
+
+/// A statement is complete once it ends with a `.` that sits outside any quoted
+/// literal or `<...>` IRI.
+fn statement_is_complete(statement: &str) -> bool {
+    let mut in_literal = false;
+    let mut in_iri = false;
+    let mut escaped = false;
+    let mut last_significant = ' ';
+
+    for ch in statement.chars() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match ch {
+            '\\' if in_literal => escaped = true,
+            '"' if !in_iri => in_literal = !in_literal,
+            '<' if !in_literal => in_iri = true,
+            '>' if !in_literal => in_iri = false,
+            _ => {}
+        }
+        if !ch.is_whitespace() {
+            last_significant = ch;
+        }
+    }
+
+    !in_literal && !in_iri && last_significant == '.'
+}
+
+// --- Directive parsing -----------------------------------------------------------
+
+fn strip_directive<'a>(statement: &'a str, keyword: &str) -> Option<&'a str> {
+    let rest = statement.strip_prefix(keyword)?;
+    let rest = rest.trim();
+    rest.strip_suffix('.').map(str::trim)
+}
+
+fn parse_prefix_directive(rest: &str) -> Result<(String, String), OxidbError> {
+    let mut parts = rest.splitn(2, char::is_whitespace);
+    let name = parts
+        .next()
+        .ok_or_else(|| OxidbError::ParseError("malformed @prefix directive".to_string()))?
+        .trim_end_matches(':');
+    let iri = parts
+        .next()
+        .ok_or_else(|| OxidbError::ParseError("malformed @prefix directive".to_string()))?
+        .trim();
+    Ok((name.to_string(), parse_iri(iri)?))
+}
+
+fn parse_base_directive(rest: &str) -> Result<String, OxidbError> {
+    parse_iri(rest.trim())
+}
+
+fn parse_iri(token: &str) -> Result<String, OxidbError> {
+    token
+        .strip_prefix('<')
+        .and_then(|s| s.strip_suffix('>'))
+        .map(ToString::to_string)
+        .ok_or_else(|| OxidbError::ParseError(format!("expected an IRI in <...>, found: {token}")))
+}
+
+// --- Triple statement parsing -----------------------------------------------------
+
+/// Parses one `subject predicate object (, object)* (; predicate object...)* .`
+/// statement into its constituent triples.
+fn parse_triple_statement(
+    statement: &str,
+    prefixes: &HashMap<String, String>,
+    base: Option<&str>,
+) -> Result<Vec<Triple>, OxidbError> {
+    let body = statement
+        .trim()
+        .strip_suffix('.')
+        .ok_or_else(|| OxidbError::ParseError(format!("statement missing '.': {statement}")))?
+        .trim();
+
+    let mut cursor = Cursor::new(body);
+    let subject = parse_term(&mut cursor, prefixes, base)?;
+
+    let mut triples = Vec::new();
+    loop {
+        cursor.skip_whitespace();
+        let predicate = if cursor.consume_keyword("a") {
+            RDF_TYPE.to_string()
+        } else {
+            match &parse_term(&mut cursor, prefixes, base)? {
+                Term::Iri(iri) => iri.clone(),
+                other => {
+                    return Err(OxidbError::ParseError(format!(
+                        "expected an IRI predicate, found {other:?}"
+                    )))
+                }
+            }
+        };
+
+        loop {
+            let object = parse_term(&mut cursor, prefixes, base)?;
+            triples.push(Triple { subject: subject.clone(), predicate: predicate.clone(), object });
+            cursor.skip_whitespace();
+            if cursor.consume_char(',') {
+                continue;
+            }
+            break;
+        }
+
+        cursor.skip_whitespace();
+        if cursor.consume_char(';') {
+            continue;
+        }
+        break;
+    }
+
+    Ok(triples)
+}
+
+fn parse_term(
+    cursor: &mut Cursor,
+    prefixes: &HashMap<String, String>,
+    base: Option<&str>,
+) -> Result<Term, OxidbError> {
+    cursor.skip_whitespace();
+    match cursor.peek() {
+        Some('<') => {
+            let raw = cursor.take_delimited('<', '>')?;
+            Ok(Term::Iri(resolve_iri(&raw, base)))
+        }
+        Some('"') => {
+            let lexical = cursor.take_quoted()?;
+            let datatype = if cursor.consume_str("^^") {
+                match parse_term(cursor, prefixes, base)? {
+                    Term::Iri(iri) => Some(iri),
+                    other => {
+                        return Err(OxidbError::ParseError(format!(
+                            "expected a datatype IRI, found {other:?}"
+                        )))
+                    }
+                }
+            } else if cursor.peek() == Some('@') {
+                cursor.take_token();
+                None
+            } else {
+                None
+            };
+            Ok(Term::Literal(lexical, datatype))
+        }
+        Some('_') => {
+            let token = cursor.take_token();
+            let label = token
+                .strip_prefix("_:")
+                .ok_or_else(|| OxidbError::ParseError(format!("malformed blank node: {token}")))?;
+            Ok(Term::BlankNode(label.to_string()))
+        }
+        Some(_) => {
+            let token = cursor.take_token();
+            if let Some((prefix, local)) = token.split_once(':') {
+                let namespace = prefixes.get(prefix).ok_or_else(|| {
+                    OxidbError::ParseError(format!("undeclared prefix '{prefix}'"))
+                })?;
+                Ok(Term::Iri(format!("{namespace}{local}")))
+            } else {
+                Err(OxidbError::ParseError(format!("unrecognized RDF term: {token}")))
+            }
+        }
+        None => Err(OxidbError::ParseError("unexpected end of statement".to_string())),
+    }
+}
+
+fn resolve_iri(iri: &str, base: Option<&str>) -> String {
+    if iri.contains("://") {
+        iri.to_string()
+    } else {
+        base.map_or_else(|| iri.to_string(), |b| format!("{b}{iri}"))
+    }
+}
+
+/// A minimal forward-only character cursor used by the Turtle term parser.
+struct Cursor<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { chars: input.chars().peekable() }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(c) if c.is_whitespace()) {
+            self.chars.next();
+        }
+    }
+
+    fn consume_char(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.chars.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn consume_str(&mut self, expected: &str) -> bool {
+        let mut clone = self.chars.clone();
+        for expected_char in expected.chars() {
+            if clone.next() != Some(expected_char) {
+                return false;
+            }
+        }
+        self.chars = clone;
+        true
+    }
+
+    fn consume_keyword(&mut self, keyword: &str) -> bool {
+        let mut clone = self.chars.clone();
+        for expected_char in keyword.chars() {
+            if clone.next() != Some(expected_char) {
+                return false;
+            }
+        }
+        match clone.peek() {
+            Some(c) if !c.is_whitespace() => false,
+            _ => {
+                self.chars = clone;
+                true
+            }
+        }
+    }
+
+    /// Reads a `<...>`/`"..."`-style delimited token, unescaping nothing beyond the
+    /// delimiters themselves.
+    fn take_delimited(&mut self, open: char, close: char) -> Result<String, OxidbError> {
+        if !self.consume_char(open) {
+            return Err(OxidbError::ParseError(format!("expected '{open}'")));
+        }
+        let mut out = String::new();
+        loop {
+            match self.chars.next() {
+                Some(c) if c == close => return Ok(out),
+                Some(c) => out.push(c),
+                None => {
+                    return Err(OxidbError::ParseError(format!(
+                        "unterminated token, expected closing '{close}'"
+                    )))
+                }
+            }
+        }
+    }
+
+    /// Reads a double-quoted literal, honoring backslash escapes for `"` and `\`.
+    fn take_quoted(&mut self) -> Result<String, OxidbError> {
+        if !self.consume_char('"') {
+            return Err(OxidbError::ParseError("expected '\"'".to_string()));
+        }
+        let mut out = String::new();
+        loop {
+            match self.chars.next() {
+                Some('\\') => match self.chars.next() {
+                    Some('n') => out.push('\n'),
+                    Some('t') => out.push('\t'),
+                    Some(c) => out.push(c),
+                    None => {
+                        return Err(OxidbError::ParseError(
+                            "unterminated escape in literal".to_string(),
+                        ))
+                    }
+                },
+                Some('"') => return Ok(out),
+                Some(c) => out.push(c),
+                None => {
+                    return Err(OxidbError::ParseError("unterminated literal".to_string()))
+                }
+            }
+        }
+    }
+
+    /// Reads a whitespace/`,`/`;`/`.`-delimited token (an IRI prefix, a blank node
+    /// label, or the `a` keyword).
+    fn take_token(&mut self) -> String {
+        let mut out = String::new();
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() || matches!(c, ',' | ';' | '.') {
+                break;
+            }
+            out.push(c);
+            self.chars.next();
+        }
+        out
+    }
+}
+
+// --- RDF term formatting -----------------------------------------------------------
+
+fn format_term_turtle(term: &Term) -> String {
+    match term {
+        Term::Iri(iri) => format!("<{iri}>"),
+        Term::BlankNode(label) => format!("_:{label}"),
+        Term::Literal(lexical, Some(datatype)) => {
+            format!("{}^^<{datatype}>", format_literal(lexical))
+        }
+        Term::Literal(lexical, None) => format_literal(lexical),
+    }
+}
+
+fn format_predicate_turtle(predicate: &str) -> String {
+    if predicate == RDF_TYPE {
+        "rdf:type".to_string()
+    } else {
+        compact_iri_turtle(predicate)
+    }
+}
+
+/// Writes `iri` as an `ox:` prefixed name when it lies in [`DEFAULT_NS`] with a plain
+/// alphanumeric/`_` local name, and as a full `<...>` IRI otherwise.
+fn compact_iri_turtle(iri: &str) -> String {
+    match iri.strip_prefix(DEFAULT_NS) {
+        Some(local) if !local.is_empty() && local.chars().all(|c| c.is_alphanumeric() || c == '_') => {
+            format!("ox:{local}")
+        }
+        _ => format!("<{iri}>"),
+    }
+}
+
+fn format_literal(lexical: &str) -> String {
+    let escaped = lexical.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n");
+    format!("\"{escaped}\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::graph::storage::InMemoryGraphStore;
+
+    #[test]
+    fn test_load_ntriples_creates_nodes_and_edges() {
+        let mut store = InMemoryGraphStore::new();
+        let doc = "\
+<http://example.org/alice> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://oxidb.dev/ns#Person> .
+<http://example.org/alice> <http://oxidb.dev/ns#name> \"Alice\" .
+<http://example.org/alice> <http://oxidb.dev/ns#knows> <http://example.org/bob> .
+";
+        let count = load_ntriples(doc.as_bytes(), &mut store).unwrap();
+        assert_eq!(count, 3);
+        assert_eq!(store.all_nodes().unwrap().len(), 2);
+        assert_eq!(store.all_edges().unwrap().len(), 1);
+
+        let alice = store
+            .all_nodes()
+            .unwrap()
+            .into_iter()
+            .find(|n| n.data.label == "Person")
+            .unwrap();
+        assert_eq!(alice.data.properties.get("name"), Some(&Value::Text("Alice".to_string())));
+    }
+
+    #[test]
+    fn test_load_turtle_with_prefix_and_lists() {
+        let mut store = InMemoryGraphStore::new();
+        let doc = "\
+@prefix ox: <http://oxidb.dev/ns#> .
+<http://example.org/alice> a ox:Person ; ox:name \"Alice\" ; ox:knows <http://example.org/bob>, <http://example.org/carol> .
+";
+        let count = load_turtle(doc.as_bytes(), &mut store).unwrap();
+        assert_eq!(count, 4);
+        assert_eq!(store.all_nodes().unwrap().len(), 3);
+        assert_eq!(store.all_edges().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_ntriples_round_trip_is_isomorphic() {
+        let mut store = InMemoryGraphStore::new();
+        let doc = "\
+<http://example.org/alice> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://oxidb.dev/ns#Person> .
+<http://example.org/alice> <http://oxidb.dev/ns#knows> <http://example.org/bob> .
+";
+        load_ntriples(doc.as_bytes(), &mut store).unwrap();
+
+        let mut buf = Vec::new();
+        dump_ntriples(&store, &mut buf).unwrap();
+
+        let mut reimported = InMemoryGraphStore::new();
+        load_ntriples(&buf[..], &mut reimported).unwrap();
+
+        assert_eq!(reimported.all_nodes().unwrap().len(), store.all_nodes().unwrap().len());
+        assert_eq!(reimported.all_edges().unwrap().len(), store.all_edges().unwrap().len());
+
+        let reimported_iris: std::collections::HashSet<_> = reimported
+            .all_nodes()
+            .unwrap()
+            .into_iter()
+            .map(|n| n.data.properties.get(RDF_ID_PROPERTY).cloned())
+            .collect();
+        let original_iris: std::collections::HashSet<_> = store
+            .all_nodes()
+            .unwrap()
+            .into_iter()
+            .map(|n| n.data.properties.get(RDF_ID_PROPERTY).cloned())
+            .collect();
+        assert_eq!(reimported_iris, original_iris);
+    }
+
+    #[test]
+    fn test_blank_nodes_round_trip_stably() {
+        let mut store = InMemoryGraphStore::new();
+        let doc = "_:b0 <http://oxidb.dev/ns#knows> _:b1 .\n";
+        load_ntriples(doc.as_bytes(), &mut store).unwrap();
+
+        let mut buf = Vec::new();
+        dump_ntriples(&store, &mut buf).unwrap();
+        let text = String::from_utf8(buf).unwrap();
+        assert!(text.contains("_:b0"));
+        assert!(text.contains("_:b1"));
+    }
+
+    #[test]
+    fn test_dump_turtle_uses_prefixes_and_rdf_type() {
+        let mut store = InMemoryGraphStore::new();
+        let data = GraphData::new("Person".to_string());
+        store.add_node(data).unwrap();
+
+        let mut buf = Vec::new();
+        dump_turtle(&store, &mut buf).unwrap();
+        let text = String::from_utf8(buf).unwrap();
+        assert!(text.contains("@prefix rdf:"));
+        assert!(text.contains("rdf:type"));
+        assert!(text.contains("ox:Person"));
+    }
+
+    #[test]
+    fn test_load_turtle_rejects_unterminated_statement() {
+        let mut store = InMemoryGraphStore::new();
+        let doc = "<http://example.org/alice> a ox:Person";
+        assert!(load_turtle(doc.as_bytes(), &mut store).is_err());
+    }
+}
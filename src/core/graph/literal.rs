@@ -0,0 +1,287 @@
+//! Typed literal values with XSD datatypes and value-space comparison.
+//!
+//! RDF literals distinguish a lexical form (how a value was written) from its value
+//! space (what it means): `"1"^^xsd:integer` and `"01"^^xsd:integer` have different
+//! lexical forms but denote the same integer, and `xsd:dateTime` values order
+//! chronologically rather than as byte strings. [`Literal`] parses a lexical form plus
+//! an [`XsdDatatype`] into a canonical value once, up front, so [`PartialEq`] and
+//! [`PartialOrd`] compare value spaces instead of raw bytes. This backs the pattern
+//! engine's range filters and sorts (see [`super::pattern`]).
+
+use std::cmp::Ordering;
+
+use crate::core::common::OxidbError;
+
+/// The XSD datatypes the graph understands well enough to parse into a value space.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum XsdDatatype {
+    /// Plain `xsd:string`.
+    String,
+    /// A language-tagged string (`rdf:langString`), e.g. `"chat"@en`. Two language
+    /// strings only compare equal/ordered when their language tags match.
+    LangString(String),
+    /// `xsd:boolean`: `"true"`/`"false"`/`"1"`/`"0"`.
+    Boolean,
+    /// `xsd:integer`: an arbitrary-sign decimal integer, e.g. `"042"` or `"-7"`.
+    Integer,
+    /// `xsd:decimal`: a base-10 number, parsed as `f64` for comparison purposes.
+    Decimal,
+    /// `xsd:dateTime`: `YYYY-MM-DDTHH:MM:SS(.fraction)?(Z|±HH:MM)?`.
+    DateTime,
+}
+
+impl XsdDatatype {
+    /// The full `xsd:` datatype IRI for this datatype, or `None` for a language-tagged
+    /// string ([`XsdDatatype::LangString`]).
+    #[must_use]
+    pub fn iri(&self) -> Option<String> {
+        const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+        match self {
+            Self::String => Some(format!("{XSD}string")),
+            Self::LangString(_) => None,
+            Self::Boolean => Some(format!("{XSD}boolean")),
+            Self::Integer => Some(format!("{XSD}integer")),
+            Self::Decimal => Some(format!("{XSD}decimal")),
+            Self::DateTime => Some(format!("{XSD}dateTime")),
+        }
+    }
+
+    /// Look up the datatype for a known `xsd:` datatype IRI.
+    #[must_use]
+    pub fn from_iri(iri: &str) -> Option<Self> {
+        const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+        match iri.strip_prefix(XSD)? {
+            "string" => Some(Self::String),
+            "boolean" => Some(Self::Boolean),
+            "integer" | "int" | "long" | "short" => Some(Self::Integer),
+            "decimal" | "double" | "float" => Some(Self::Decimal),
+            "dateTime" => Some(Self::DateTime),
+            _ => None,
+        }
+    }
+}
+
+/// A canonicalized literal value: a lexical form plus the [`XsdDatatype`] it was
+/// parsed as. Construct with [`Literal::parse`], which validates and normalizes the
+/// lexical form into a value space eagerly so later comparisons never re-parse.
+#[derive(Debug, Clone)]
+pub struct Literal {
+    lexical: String,
+    datatype: XsdDatatype,
+    canonical: Canonical,
+}
+
+/// The value-space representation compared by [`PartialEq`]/[`PartialOrd`]. Kept
+/// separate from the lexical form so `"1"` and `"01"` (both [`Canonical::Number`])
+/// compare equal.
+#[derive(Debug, Clone, PartialEq)]
+enum Canonical {
+    Text(String, Option<String>),
+    Boolean(bool),
+    Number(f64),
+    /// Seconds since the Unix epoch, UTC, including any sub-second fraction.
+    Instant(f64),
+}
+
+impl Literal {
+    /// Parse `lexical` as `datatype`, validating and normalizing it into a value space.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OxidbError::ParseError`] if `lexical` is not a well-formed literal of
+    /// `datatype` (e.g. `"abc"` as `xsd:integer`, or a malformed `xsd:dateTime`).
+    pub fn parse(lexical: impl Into<String>, datatype: XsdDatatype) -> Result<Self, OxidbError> {
+        let lexical = lexical.into();
+        let canonical = match &datatype {
+            XsdDatatype::String => Canonical::Text(lexical.clone(), None),
+            XsdDatatype::LangString(lang) => Canonical::Text(lexical.clone(), Some(lang.clone())),
+            XsdDatatype::Boolean => Canonical::Boolean(parse_boolean(&lexical)?),
+            XsdDatatype::Integer => Canonical::Number(parse_integer(&lexical)? as f64),
+            XsdDatatype::Decimal => Canonical::Number(parse_decimal(&lexical)?),
+            XsdDatatype::DateTime => Canonical::Instant(parse_datetime(&lexical)?),
+        };
+        Ok(Self { lexical, datatype, canonical })
+    }
+
+    /// The literal's original lexical form, e.g. `"01"` or `"2024-01-01T00:00:00Z"`.
+    #[must_use]
+    pub fn lexical(&self) -> &str {
+        &self.lexical
+    }
+
+    /// The datatype this literal was parsed as.
+    #[must_use]
+    pub const fn datatype(&self) -> &XsdDatatype {
+        &self.datatype
+    }
+}
+
+impl PartialEq for Literal {
+    fn eq(&self, other: &Self) -> bool {
+        self.canonical == other.canonical
+    }
+}
+
+impl PartialOrd for Literal {
+    /// Compares by value space. Two literals of incomparable kinds (e.g. a string vs a
+    /// number, or language strings with different tags) have no ordering.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (&self.canonical, &other.canonical) {
+            (Canonical::Number(a), Canonical::Number(b)) => a.partial_cmp(b),
+            (Canonical::Instant(a), Canonical::Instant(b)) => a.partial_cmp(b),
+            (Canonical::Boolean(a), Canonical::Boolean(b)) => a.partial_cmp(b),
+            (Canonical::Text(a, la), Canonical::Text(b, lb)) if la == lb => a.partial_cmp(b),
+            _ => None,
+        }
+    }
+}
+
+fn parse_boolean(lexical: &str) -> Result<bool, OxidbError> {
+    match lexical {
+        "true" | "1" => Ok(true),
+        "false" | "0" => Ok(false),
+        other => Err(OxidbError::ParseError(format!("invalid xsd:boolean lexical form: {other}"))),
+    }
+}
+
+fn parse_integer(lexical: &str) -> Result<i64, OxidbError> {
+    lexical
+        .parse::<i64>()
+        .map_err(|_| OxidbError::ParseError(format!("invalid xsd:integer lexical form: {lexical}")))
+}
+
+fn parse_decimal(lexical: &str) -> Result<f64, OxidbError> {
+    lexical
+        .parse::<f64>()
+        .map_err(|_| OxidbError::ParseError(format!("invalid xsd:decimal lexical form: {lexical}")))
+}
+
+/// Parses `YYYY-MM-DDTHH:MM:SS(.fraction)?(Z|±HH:MM)?` into seconds since the Unix
+/// epoch (UTC), so two `xsd:dateTime` values compare chronologically regardless of
+/// their original time zone or sub-second precision.
+fn parse_datetime(lexical: &str) -> Result<f64, OxidbError> {
+    let bad = || OxidbError::ParseError(format!("invalid xsd:dateTime lexical form: {lexical}"));
+
+    let (date_part, time_part) = lexical.split_once('T').ok_or_else(bad)?;
+    let mut date_fields = date_part.splitn(3, '-');
+    let year: i64 = date_fields.next().ok_or_else(bad)?.parse().map_err(|_| bad())?;
+    let month: u32 = date_fields.next().ok_or_else(bad)?.parse().map_err(|_| bad())?;
+    let day: u32 = date_fields.next().ok_or_else(bad)?.parse().map_err(|_| bad())?;
+
+    let (time_body, offset_seconds) = split_time_offset(time_part, &bad)?;
+    let mut time_fields = time_body.splitn(3, ':');
+    let hour: u32 = time_fields.next().ok_or_else(bad)?.parse().map_err(|_| bad())?;
+    let minute: u32 = time_fields.next().ok_or_else(bad)?.parse().map_err(|_| bad())?;
+    let second: f64 = time_fields.next().ok_or_else(bad)?.parse().map_err(|_| bad())?;
+
+    let days = days_from_civil(year, month, day);
+    let seconds_in_day = f64::from(hour) * 3600.0 + f64::from(minute) * 60.0 + second;
+    Ok((days as f64).mul_add(86400.0, seconds_in_day) - f64::from(offset_seconds))
+}
+
+/// Splits a time-of-day into its `HH:MM:SS(.fraction)?` body and a `Z`/`±HH:MM` offset
+/// in seconds (`0` if no offset is present).
+fn split_time_offset<'a>(
+    time_part: &'a str,
+    bad: &dyn Fn() -> OxidbError,
+) -> Result<(&'a str, i32), OxidbError> {
+    if let Some(body) = time_part.strip_suffix('Z') {
+        return Ok((body, 0));
+    }
+    // The sign can't be the first character (that would make the hour negative), so
+    // searching from the right finds the offset separator, not part of the time itself.
+    if let Some(pos) = time_part.rfind(['+', '-']) {
+        if pos > 0 {
+            let (body, offset) = time_part.split_at(pos);
+            let sign = if offset.starts_with('-') { -1 } else { 1 };
+            let mut parts = offset[1..].splitn(2, ':');
+            let hours: i32 = parts.next().unwrap_or("0").parse().map_err(|_| bad())?;
+            let minutes: i32 = parts.next().unwrap_or("0").parse().map_err(|_| bad())?;
+            return Ok((body, sign * (hours * 3600 + minutes * 60)));
+        }
+    }
+    Ok((time_part, 0))
+}
+
+/// Howard Hinnant's `days_from_civil` algorithm: the number of days since the Unix
+/// epoch (1970-01-01) for a date in the proleptic Gregorian calendar. Avoids pulling
+/// in a date/time dependency for what the pattern engine only needs as a sort key.
+fn days_from_civil(y: i64, m: u32, d: u32) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400; // [0, 399]
+    let mp = (i64::from(m) + 9) % 12; // [0, 11], Mar-based month index
+    let doy = (153 * mp + 2) / 5 + i64::from(d) - 1; // [0, 365]
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy; // [0, 146096]
+    era * 146_097 + doe - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integer_lexical_forms_compare_equal() {
+        let a = Literal::parse("1", XsdDatatype::Integer).unwrap();
+        let b = Literal::parse("01", XsdDatatype::Integer).unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_integer_ordering() {
+        let a = Literal::parse("2", XsdDatatype::Integer).unwrap();
+        let b = Literal::parse("10", XsdDatatype::Integer).unwrap();
+        assert!(a < b);
+    }
+
+    #[test]
+    fn test_invalid_integer_is_rejected() {
+        assert!(Literal::parse("not-a-number", XsdDatatype::Integer).is_err());
+    }
+
+    #[test]
+    fn test_datetime_orders_chronologically_across_offsets() {
+        let earlier = Literal::parse("2024-01-01T00:00:00Z", XsdDatatype::DateTime).unwrap();
+        // Same instant, different lexical form and offset: must compare equal.
+        let same_instant =
+            Literal::parse("2024-01-01T02:00:00+02:00", XsdDatatype::DateTime).unwrap();
+        let later = Literal::parse("2024-06-01T00:00:00Z", XsdDatatype::DateTime).unwrap();
+
+        assert_eq!(earlier, same_instant);
+        assert!(earlier < later);
+    }
+
+    #[test]
+    fn test_datetime_subsecond_precision() {
+        let a = Literal::parse("2024-01-01T00:00:00.5Z", XsdDatatype::DateTime).unwrap();
+        let b = Literal::parse("2024-01-01T00:00:00.25Z", XsdDatatype::DateTime).unwrap();
+        assert!(a > b);
+    }
+
+    #[test]
+    fn test_boolean_and_string_equality() {
+        assert_eq!(
+            Literal::parse("true", XsdDatatype::Boolean).unwrap(),
+            Literal::parse("1", XsdDatatype::Boolean).unwrap()
+        );
+        assert_eq!(
+            Literal::parse("hello", XsdDatatype::String).unwrap(),
+            Literal::parse("hello", XsdDatatype::String).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_lang_strings_with_different_tags_are_incomparable() {
+        let en = Literal::parse("chat", XsdDatatype::LangString("en".to_string())).unwrap();
+        let fr = Literal::parse("chat", XsdDatatype::LangString("fr".to_string())).unwrap();
+        assert_ne!(en, fr);
+        assert_eq!(en.partial_cmp(&fr), None);
+    }
+
+    #[test]
+    fn test_string_and_number_are_incomparable() {
+        let text = Literal::parse("1", XsdDatatype::String).unwrap();
+        let number = Literal::parse("1", XsdDatatype::Integer).unwrap();
+        assert_ne!(text, number);
+    }
+}
@@ -18,12 +18,20 @@ pub mod storage;
 pub mod traversal;
 pub mod algorithms;
 pub mod types;
+pub mod io;
+pub mod pattern;
+pub mod literal;
 
 // Re-export key types and traits for convenience
 pub use storage::{GraphStore, GraphStorage};
 pub use traversal::{GraphTraversal, TraversalDirection, TraversalStrategy};
 pub use algorithms::{GraphAlgorithms, PathFinding};
 pub use types::{NodeId, EdgeId, Node, Edge, GraphData, Relationship};
+pub use io::{dump_ntriples, dump_turtle, load_ntriples, load_turtle};
+pub use pattern::{
+    FilterOp, GraphPattern, OrderBy, PatternMatches, PatternTerm, PropertyFilter, TriplePattern, Var,
+};
+pub use literal::{Literal, XsdDatatype};
 
 use crate::core::common::errors::OxidbError;
 use crate::core::common::types::Value;
@@ -34,15 +42,38 @@ pub trait GraphOperations {
     /// Add a node to the graph
     fn add_node(&mut self, data: GraphData) -> Result<NodeId, OxidbError>;
     
-    /// Add an edge between two nodes
+    /// Add an edge between two nodes, in the default (unnamed) graph.
     fn add_edge(&mut self, from: NodeId, to: NodeId, relationship: Relationship, data: Option<GraphData>) -> Result<EdgeId, OxidbError>;
-    
+
+    /// Add an edge between two nodes, scoped to a named graph. `graph: None` is the
+    /// same default graph `add_edge` uses; `Some(name)` tags the edge as belonging to
+    /// that named graph for provenance-scoped querying (see [`super::pattern`]).
+    fn add_edge_in_graph(
+        &mut self,
+        from: NodeId,
+        to: NodeId,
+        relationship: Relationship,
+        data: Option<GraphData>,
+        graph: Option<String>,
+    ) -> Result<EdgeId, OxidbError>;
+
     /// Get a node by ID
     fn get_node(&self, node_id: NodeId) -> Result<Option<Node>, OxidbError>;
     
     /// Get an edge by ID
     fn get_edge(&self, edge_id: EdgeId) -> Result<Option<Edge>, OxidbError>;
-    
+
+    /// Get every node currently in the graph, in no particular order.
+    fn all_nodes(&self) -> Result<Vec<Node>, OxidbError>;
+
+    /// Get every edge currently in the graph, in no particular order.
+    fn all_edges(&self) -> Result<Vec<Edge>, OxidbError>;
+
+    /// Replace an existing node's data in place, leaving its ID and edges untouched.
+    /// Returns `true` if the node existed and was updated, `false` if no node with
+    /// that ID exists.
+    fn update_node_data(&mut self, node_id: NodeId, data: GraphData) -> Result<bool, OxidbError>;
+
     /// Remove a node and all its edges
     fn remove_node(&mut self, node_id: NodeId) -> Result<bool, OxidbError>;
     
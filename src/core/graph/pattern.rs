@@ -0,0 +1,866 @@
+//! Basic-graph-pattern (BGP) query engine over the entity graph.
+//!
+//! A [`GraphPattern`] is a conjunction of [`TriplePattern`]s such as `(?x, knows, ?y)`,
+//! `(?y, worksAt, ?z)`. [`GraphPattern::evaluate`] indexes the store's edges in
+//! subject-predicate-object order, evaluates each triple pattern as an index scan
+//! constrained by whichever of its subject/object are already bound, and joins
+//! patterns left-to-right: a shared variable between two patterns acts as a hash join
+//! key, while an unconstrained pattern falls back to a full scan by predicate. The
+//! result is a stream of variable-binding rows, letting callers project and format
+//! matches however they like instead of being limited to a single linear path.
+//!
+//! Before executing, `evaluate` runs a cost-based optimizer pass ([`GraphPattern::optimize`])
+//! that reorders the triple patterns so naive left-to-right evaluation can't explode when
+//! a selective pattern happens to be written last: it estimates each pattern's output size
+//! from per-predicate edge counts and average degree, then greedily picks the next pattern
+//! connected to the partial plan with the lowest estimated output.
+//!
+//! After the join, [`GraphPattern::with_filter`] and [`GraphPattern::order_by`] let callers
+//! attach range predicates and sorts over a bound variable's node property. Both compare
+//! using [`Literal`]'s value-space semantics rather than the property's raw [`Value`]
+//! bytes, so e.g. an `xsd:dateTime` property sorts chronologically.
+//!
+//! Edges may be tagged with a named graph (see [`super::types::Edge::graph`]), and
+//! [`GraphPattern::in_graphs`] restricts matching to a chosen set of them; the default is
+//! unrestricted, matching across every graph's union. [`TripleIndex`] carries the graph
+//! name as a fourth index column alongside subject/predicate/object, so a query that
+//! pins its graphs only touches that graph's edges rather than filtering every edge with
+//! the right predicate after the fact.
+
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+use super::literal::{Literal, XsdDatatype};
+use super::storage::GraphStore;
+use super::types::NodeId;
+use crate::core::common::types::Value;
+use crate::core::common::OxidbError;
+
+/// A query variable, e.g. `?x`. Two terms sharing a variable name must bind to the
+/// same node within a single result row.
+pub type Var = String;
+
+/// One subject/object position in a [`TriplePattern`]: either a free variable to bind,
+/// or a node id the position is pinned to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatternTerm {
+    /// A variable that binds to whatever node is found in this position.
+    Var(Var),
+    /// A node id this position must match exactly.
+    Bound(NodeId),
+}
+
+/// A single `(subject, predicate, object)` triple pattern. The predicate is always a
+/// concrete relationship name; only the subject and object positions may be variables.
+#[derive(Debug, Clone)]
+pub struct TriplePattern {
+    pub subject: PatternTerm,
+    pub predicate: String,
+    pub object: PatternTerm,
+}
+
+impl TriplePattern {
+    #[must_use]
+    pub fn new(subject: PatternTerm, predicate: impl Into<String>, object: PatternTerm) -> Self {
+        Self { subject, predicate: predicate.into(), object }
+    }
+}
+
+/// A range-comparison operator for a [`PropertyFilter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterOp {
+    Eq,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+}
+
+/// A post-join filter: keep only rows whose `var` binding has a `property` comparing
+/// as `op` against `value`, with both sides interpreted as `value`'s [`XsdDatatype`].
+/// A row whose node is missing the property, or whose property can't be parsed as that
+/// datatype, is dropped.
+#[derive(Debug, Clone)]
+pub struct PropertyFilter {
+    pub var: Var,
+    pub property: String,
+    pub op: FilterOp,
+    pub value: Literal,
+}
+
+impl PropertyFilter {
+    #[must_use]
+    pub fn new(var: impl Into<Var>, property: impl Into<String>, op: FilterOp, value: Literal) -> Self {
+        Self { var: var.into(), property: property.into(), op, value }
+    }
+}
+
+/// A post-join sort key: order rows by `var`'s `property`, interpreted as `datatype`,
+/// ascending or descending. Rows missing the property sort last regardless of direction.
+#[derive(Debug, Clone)]
+pub struct OrderBy {
+    pub var: Var,
+    pub property: String,
+    pub datatype: XsdDatatype,
+    pub ascending: bool,
+}
+
+impl OrderBy {
+    #[must_use]
+    pub fn new(var: impl Into<Var>, property: impl Into<String>, datatype: XsdDatatype, ascending: bool) -> Self {
+        Self { var: var.into(), property: property.into(), datatype, ascending }
+    }
+}
+
+/// A conjunction of [`TriplePattern`]s evaluated left-to-right against a [`GraphStore`],
+/// with optional post-join [`PropertyFilter`]s and an [`OrderBy`].
+#[derive(Debug, Clone, Default)]
+pub struct GraphPattern {
+    patterns: Vec<TriplePattern>,
+    filters: Vec<PropertyFilter>,
+    order_by: Option<OrderBy>,
+    /// `None` matches edges in any named graph (the default, unrestricted union).
+    /// `Some(set)` matches only edges whose `graph` is one of `set`'s members, where
+    /// `None` within the set stands for the default (unnamed) graph.
+    graphs: Option<HashSet<Option<String>>>,
+}
+
+impl GraphPattern {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a triple pattern to the conjunction (builder pattern).
+    #[must_use]
+    pub fn with_pattern(mut self, pattern: TriplePattern) -> Self {
+        self.patterns.push(pattern);
+        self
+    }
+
+    /// Attach a post-join range filter (builder pattern). See [`PropertyFilter`].
+    #[must_use]
+    pub fn with_filter(mut self, filter: PropertyFilter) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    /// Attach a post-join sort (builder pattern), replacing any previous one. See [`OrderBy`].
+    #[must_use]
+    pub fn order_by(mut self, order_by: OrderBy) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Restrict matching to the union of the given named graphs (builder pattern),
+    /// replacing any previous restriction. Pass `None` for the default (unnamed) graph.
+    /// Without a call to this method, patterns match edges in every graph.
+    #[must_use]
+    pub fn in_graphs(mut self, graphs: impl IntoIterator<Item = Option<String>>) -> Self {
+        self.graphs = Some(graphs.into_iter().collect());
+        self
+    }
+
+    /// Restrict matching to a single named graph (builder pattern). Shorthand for
+    /// `in_graphs([Some(graph.into())])`.
+    #[must_use]
+    pub fn in_graph(self, graph: impl Into<String>) -> Self {
+        self.in_graphs([Some(graph.into())])
+    }
+
+    /// Evaluate this pattern against `store`, returning every variable-binding row that
+    /// satisfies all triple patterns, filters, and ordering. The triple patterns are
+    /// first reordered by estimated cost (see [`GraphPattern::optimize`]), then joined
+    /// left-to-right: each existing row is extended by an index scan of the next
+    /// pattern, constrained by whichever of its subject/object variables the row
+    /// already binds. Any [`PropertyFilter`]s are applied to the joined rows, and an
+    /// [`OrderBy`] sorts what remains, both comparing property values as typed
+    /// [`Literal`]s rather than raw bytes.
+    ///
+    /// # Errors
+    ///
+    /// Propagates any [`OxidbError`] raised while reading nodes or edges from `store`.
+    pub fn evaluate(&self, store: &dyn GraphStore) -> Result<PatternMatches, OxidbError> {
+        let index = TripleIndex::build(store)?;
+        let ordered = reorder_by_cost(&self.patterns, &index);
+        let mut rows: Vec<HashMap<Var, NodeId>> = vec![HashMap::new()];
+
+        for pattern in ordered {
+            let mut next_rows = Vec::new();
+            for row in &rows {
+                let subject = resolve(&pattern.subject, row);
+                let object = resolve(&pattern.object, row);
+                for (from, to) in index.scan(subject, &pattern.predicate, object, self.graphs.as_ref()) {
+                    let mut candidate = row.clone();
+                    if bind(&mut candidate, &pattern.subject, from)
+                        && bind(&mut candidate, &pattern.object, to)
+                    {
+                        next_rows.push(candidate);
+                    }
+                }
+            }
+            rows = next_rows;
+            if rows.is_empty() {
+                break;
+            }
+        }
+
+        for filter in &self.filters {
+            let mut kept = Vec::with_capacity(rows.len());
+            for row in rows {
+                if passes_filter(store, &row, filter)? {
+                    kept.push(row);
+                }
+            }
+            rows = kept;
+        }
+
+        if let Some(order_by) = &self.order_by {
+            rows = sort_by_property(store, rows, order_by)?;
+        }
+
+        Ok(PatternMatches { rows: rows.into_iter() })
+    }
+
+    /// Run the cost-based join-reordering pass without executing the query, returning a
+    /// copy of this pattern with its triple patterns in the order [`evaluate`] would use.
+    /// Exposed separately so callers (and tests) can inspect the chosen plan.
+    ///
+    /// # Errors
+    ///
+    /// Propagates any [`OxidbError`] raised while reading edges from `store`.
+    pub fn optimize(&self, store: &dyn GraphStore) -> Result<Self, OxidbError> {
+        let index = TripleIndex::build(store)?;
+        let patterns = reorder_by_cost(&self.patterns, &index).into_iter().cloned().collect();
+        Ok(Self { patterns, filters: self.filters.clone(), order_by: self.order_by.clone(), graphs: self.graphs.clone() })
+    }
+}
+
+/// Greedily reorder `patterns` cheapest-first: starting from the pattern with the
+/// lowest estimated output, repeatedly pick the next pattern that shares at least one
+/// already-bound variable with the partial plan and has the lowest estimated output,
+/// breaking ties by whichever pattern has the most bound positions. Falls back to a
+/// full scan over the remaining patterns if none are connected yet (disjoint pattern
+/// sets), so every pattern is still placed exactly once.
+fn reorder_by_cost<'a>(patterns: &'a [TriplePattern], index: &TripleIndex) -> Vec<&'a TriplePattern> {
+    let mut remaining: Vec<&TriplePattern> = patterns.iter().collect();
+    let mut ordered = Vec::with_capacity(remaining.len());
+    let mut bound_vars: HashSet<Var> = HashSet::new();
+
+    while !remaining.is_empty() {
+        let connected: Vec<usize> = (0..remaining.len())
+            .filter(|&i| pattern_vars(remaining[i]).any(|v| bound_vars.contains(v)))
+            .collect();
+        let candidates = if ordered.is_empty() || connected.is_empty() {
+            (0..remaining.len()).collect::<Vec<_>>()
+        } else {
+            connected
+        };
+
+        let best = candidates
+            .into_iter()
+            .min_by(|&a, &b| {
+                let cost_a = estimate_cost(index, remaining[a], &bound_vars);
+                let cost_b = estimate_cost(index, remaining[b], &bound_vars);
+                cost_a
+                    .partial_cmp(&cost_b)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+                    .then_with(|| {
+                        bound_position_count(remaining[b], &bound_vars)
+                            .cmp(&bound_position_count(remaining[a], &bound_vars))
+                    })
+            })
+            .expect("candidates is non-empty");
+
+        let pattern = remaining.remove(best);
+        bound_vars.extend(pattern_vars(pattern).cloned());
+        ordered.push(pattern);
+    }
+
+    ordered
+}
+
+/// The variables referenced by a triple pattern's subject/object positions.
+fn pattern_vars(pattern: &TriplePattern) -> impl Iterator<Item = &Var> {
+    [&pattern.subject, &pattern.object].into_iter().filter_map(|term| match term {
+        PatternTerm::Var(name) => Some(name),
+        PatternTerm::Bound(_) => None,
+    })
+}
+
+/// How many of a pattern's two positions (subject, object) are already bound, either
+/// literally or via `bound_vars` — used as the cardinality-estimate tie-breaker.
+fn bound_position_count(pattern: &TriplePattern, bound_vars: &HashSet<Var>) -> usize {
+    usize::from(is_bound(&pattern.subject, bound_vars)) + usize::from(is_bound(&pattern.object, bound_vars))
+}
+
+fn is_bound(term: &PatternTerm, bound_vars: &HashSet<Var>) -> bool {
+    match term {
+        PatternTerm::Bound(_) => true,
+        PatternTerm::Var(name) => bound_vars.contains(name),
+    }
+}
+
+/// Estimate a triple pattern's result cardinality from how many of its positions are
+/// bound: a fully-bound pattern is ~1 row, a pattern with only its subject bound is
+/// ~the predicate's average out-degree, only its object bound is ~the average
+/// in-degree, and a fully unbound pattern is the predicate's total edge count.
+fn estimate_cost(index: &TripleIndex, pattern: &TriplePattern, bound_vars: &HashSet<Var>) -> f64 {
+    let subject_bound = is_bound(&pattern.subject, bound_vars);
+    let object_bound = is_bound(&pattern.object, bound_vars);
+    match (subject_bound, object_bound) {
+        (true, true) => 1.0,
+        (true, false) => index.avg_out_degree(&pattern.predicate),
+        (false, true) => index.avg_in_degree(&pattern.predicate),
+        (false, false) => (index.predicate_count(&pattern.predicate) as f64).max(1.0),
+    }
+}
+
+/// Resolve a pattern term to a concrete node id if it is bound already, either
+/// literally ([`PatternTerm::Bound`]) or via a prior pattern's binding in `row`.
+fn resolve(term: &PatternTerm, row: &HashMap<Var, NodeId>) -> Option<NodeId> {
+    match term {
+        PatternTerm::Bound(id) => Some(*id),
+        PatternTerm::Var(name) => row.get(name).copied(),
+    }
+}
+
+/// Bind `term` to `value` within `row`. Returns `false` if `term` is already bound to a
+/// different value (a failed join), in which case the candidate row must be discarded.
+fn bind(row: &mut HashMap<Var, NodeId>, term: &PatternTerm, value: NodeId) -> bool {
+    match term {
+        PatternTerm::Bound(id) => *id == value,
+        PatternTerm::Var(name) => match row.get(name) {
+            Some(existing) => *existing == value,
+            None => {
+                row.insert(name.clone(), value);
+                true
+            }
+        },
+    }
+}
+
+/// Look up `var`'s bound node in `row`, read its `property`, and parse that property's
+/// raw [`Value`] as `datatype` to get a typed [`Literal`]. Returns `None` (rather than
+/// an error) if the variable is unbound, the node has no such property, or the
+/// property's value can't be parsed as `datatype` — all of which simply exclude the
+/// row from filtering/sorting rather than failing the whole query.
+fn property_literal(
+    store: &dyn GraphStore,
+    row: &HashMap<Var, NodeId>,
+    var: &Var,
+    property: &str,
+    datatype: &XsdDatatype,
+) -> Result<Option<Literal>, OxidbError> {
+    let Some(node_id) = row.get(var).copied() else { return Ok(None) };
+    let Some(node) = store.get_node(node_id)? else { return Ok(None) };
+    let Some(value) = node.data.properties.get(property) else { return Ok(None) };
+    let lexical = match value {
+        Value::Text(s) => s.clone(),
+        Value::Integer(i) => i.to_string(),
+        Value::Float(f) => f.to_string(),
+        Value::Boolean(b) => b.to_string(),
+        Value::Blob(_) | Value::Vector(_) | Value::Null => return Ok(None),
+    };
+    Ok(Literal::parse(lexical, datatype.clone()).ok())
+}
+
+/// Apply a [`PropertyFilter`] to a single joined row.
+fn passes_filter(
+    store: &dyn GraphStore,
+    row: &HashMap<Var, NodeId>,
+    filter: &PropertyFilter,
+) -> Result<bool, OxidbError> {
+    let Some(actual) = property_literal(store, row, &filter.var, &filter.property, filter.value.datatype())?
+    else {
+        return Ok(false);
+    };
+    let Some(ordering) = actual.partial_cmp(&filter.value) else { return Ok(false) };
+    Ok(match filter.op {
+        FilterOp::Eq => ordering == Ordering::Equal,
+        FilterOp::Lt => ordering == Ordering::Less,
+        FilterOp::Lte => ordering != Ordering::Greater,
+        FilterOp::Gt => ordering == Ordering::Greater,
+        FilterOp::Gte => ordering != Ordering::Less,
+    })
+}
+
+/// Sort `rows` by `order_by`'s property, typed as its `datatype`. Rows where the
+/// property can't be resolved sort last, regardless of direction.
+fn sort_by_property(
+    store: &dyn GraphStore,
+    rows: Vec<HashMap<Var, NodeId>>,
+    order_by: &OrderBy,
+) -> Result<Vec<HashMap<Var, NodeId>>, OxidbError> {
+    let mut keyed = Vec::with_capacity(rows.len());
+    for row in rows {
+        let key = property_literal(store, &row, &order_by.var, &order_by.property, &order_by.datatype)?;
+        keyed.push((key, row));
+    }
+
+    keyed.sort_by(|(a, _), (b, _)| match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a), Some(b)) => {
+            let ordering = a.partial_cmp(b).unwrap_or(Ordering::Equal);
+            if order_by.ascending { ordering } else { ordering.reverse() }
+        }
+    });
+
+    Ok(keyed.into_iter().map(|(_, row)| row).collect())
+}
+
+/// The named graph an edge belongs to, as an index key: `None` is the default
+/// (unnamed) graph, matching [`super::types::Edge::graph`].
+type GraphKey = Option<String>;
+
+/// An edge index in subject-predicate-object-graph order, with an auxiliary
+/// predicate-object-subject index for scans where the object is bound but the subject
+/// is not, plus the per-predicate edge counts and average degree the join reorderer
+/// uses as cardinality estimates. The graph name is carried as a fourth column on every
+/// bucket, so a scan that pins its graphs (see [`GraphPattern::in_graphs`]) only visits
+/// that graph's edges instead of scanning every edge with the matching predicate and
+/// discarding the ones from other graphs. Built once per [`GraphPattern::evaluate`] call
+/// and reused across all of its triple patterns.
+struct TripleIndex {
+    by_subject: HashMap<(NodeId, String, GraphKey), Vec<NodeId>>,
+    by_object: HashMap<(String, NodeId, GraphKey), Vec<NodeId>>,
+    by_predicate: HashMap<(String, GraphKey), Vec<(NodeId, NodeId)>>,
+    /// Which graphs actually have edges for a given predicate, so an unscoped (or
+    /// multi-graph) scan knows which graph buckets to union instead of guessing.
+    graphs_by_predicate: HashMap<String, Vec<GraphKey>>,
+    distinct_subjects_by_predicate: HashMap<String, usize>,
+    distinct_objects_by_predicate: HashMap<String, usize>,
+}
+
+impl TripleIndex {
+    fn build(store: &dyn GraphStore) -> Result<Self, OxidbError> {
+        let mut by_subject: HashMap<(NodeId, String, GraphKey), Vec<NodeId>> = HashMap::new();
+        let mut by_object: HashMap<(String, NodeId, GraphKey), Vec<NodeId>> = HashMap::new();
+        let mut by_predicate: HashMap<(String, GraphKey), Vec<(NodeId, NodeId)>> = HashMap::new();
+        let mut graphs_by_predicate: HashMap<String, Vec<GraphKey>> = HashMap::new();
+
+        for edge in store.all_edges()? {
+            let predicate = edge.relationship.name;
+            let graph = edge.graph;
+            by_subject
+                .entry((edge.from_node, predicate.clone(), graph.clone()))
+                .or_default()
+                .push(edge.to_node);
+            by_object
+                .entry((predicate.clone(), edge.to_node, graph.clone()))
+                .or_default()
+                .push(edge.from_node);
+            let graphs = graphs_by_predicate.entry(predicate.clone()).or_default();
+            if !graphs.contains(&graph) {
+                graphs.push(graph.clone());
+            }
+            by_predicate.entry((predicate, graph)).or_default().push((edge.from_node, edge.to_node));
+        }
+
+        let mut distinct_subjects_by_predicate: HashMap<String, usize> = HashMap::new();
+        for (_, predicate, _) in by_subject.keys() {
+            *distinct_subjects_by_predicate.entry(predicate.clone()).or_insert(0) += 1;
+        }
+        let mut distinct_objects_by_predicate: HashMap<String, usize> = HashMap::new();
+        for (predicate, _, _) in by_object.keys() {
+            *distinct_objects_by_predicate.entry(predicate.clone()).or_insert(0) += 1;
+        }
+
+        Ok(Self {
+            by_subject,
+            by_object,
+            by_predicate,
+            graphs_by_predicate,
+            distinct_subjects_by_predicate,
+            distinct_objects_by_predicate,
+        })
+    }
+
+    /// Which graph keys a scan over `predicate` should visit: every graph bucket that
+    /// predicate has, intersected with `graphs` when the caller restricted the scope.
+    fn relevant_graphs(&self, predicate: &str, graphs: Option<&HashSet<GraphKey>>) -> Vec<GraphKey> {
+        let all = self.graphs_by_predicate.get(predicate).cloned().unwrap_or_default();
+        match graphs {
+            None => all,
+            Some(scope) => all.into_iter().filter(|g| scope.contains(g)).collect(),
+        }
+    }
+
+    /// Total number of edges with this predicate, across every graph (the cardinality of
+    /// a fully-unbound, unscoped scan). Cardinality estimation ignores graph scoping —
+    /// it's a heuristic for join ordering, not an exact count, and most stores don't
+    /// split a predicate across enough graphs for that to matter.
+    fn predicate_count(&self, predicate: &str) -> usize {
+        self.graphs_by_predicate
+            .get(predicate)
+            .into_iter()
+            .flatten()
+            .filter_map(|graph| self.by_predicate.get(&(predicate.to_string(), graph.clone())))
+            .map(Vec::len)
+            .sum()
+    }
+
+    /// Average out-degree of subjects that have at least one edge with this predicate.
+    fn avg_out_degree(&self, predicate: &str) -> f64 {
+        let total = self.predicate_count(predicate) as f64;
+        let subjects = self.distinct_subjects_by_predicate.get(predicate).copied().unwrap_or(0) as f64;
+        if subjects > 0.0 { total / subjects } else { total.max(1.0) }
+    }
+
+    /// Average in-degree of objects that have at least one edge with this predicate.
+    fn avg_in_degree(&self, predicate: &str) -> f64 {
+        let total = self.predicate_count(predicate) as f64;
+        let objects = self.distinct_objects_by_predicate.get(predicate).copied().unwrap_or(0) as f64;
+        if objects > 0.0 { total / objects } else { total.max(1.0) }
+    }
+
+    /// Scan for `(subject, object)` pairs matching `predicate`, constrained by whichever
+    /// of `subject`/`object` are already bound and, when `graphs` is `Some`, to edges
+    /// tagged with one of those graphs (`None` within the set means the default graph).
+    /// `graphs: None` is unrestricted and unions every graph the predicate appears in.
+    /// An unconstrained scan falls back to a full scan of every matching graph bucket
+    /// for this predicate.
+    fn scan(
+        &self,
+        subject: Option<NodeId>,
+        predicate: &str,
+        object: Option<NodeId>,
+        graphs: Option<&HashSet<GraphKey>>,
+    ) -> Vec<(NodeId, NodeId)> {
+        let relevant = self.relevant_graphs(predicate, graphs);
+        match (subject, object) {
+            (Some(s), Some(o)) => relevant
+                .iter()
+                .flat_map(|g| self.by_subject.get(&(s, predicate.to_string(), g.clone())))
+                .flatten()
+                .filter(|&&to| to == o)
+                .map(|&to| (s, to))
+                .collect(),
+            (Some(s), None) => relevant
+                .iter()
+                .flat_map(|g| self.by_subject.get(&(s, predicate.to_string(), g.clone())))
+                .flatten()
+                .map(|&to| (s, to))
+                .collect(),
+            (None, Some(o)) => relevant
+                .iter()
+                .flat_map(|g| self.by_object.get(&(predicate.to_string(), o, g.clone())))
+                .flatten()
+                .map(|&from| (from, o))
+                .collect(),
+            (None, None) => relevant
+                .iter()
+                .flat_map(|g| self.by_predicate.get(&(predicate.to_string(), g.clone())))
+                .flatten()
+                .copied()
+                .collect(),
+        }
+    }
+}
+
+/// An iterator over the variable-binding rows produced by [`GraphPattern::evaluate`].
+pub struct PatternMatches {
+    rows: std::vec::IntoIter<HashMap<Var, NodeId>>,
+}
+
+impl Iterator for PatternMatches {
+    type Item = HashMap<Var, NodeId>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rows.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::graph::storage::InMemoryGraphStore;
+    use crate::core::graph::types::{GraphData, Relationship};
+    use crate::core::graph::GraphOperations;
+
+    fn node(store: &mut InMemoryGraphStore, label: &str) -> NodeId {
+        store.add_node(GraphData::new(label.to_string())).unwrap()
+    }
+
+    #[test]
+    fn test_single_pattern_binds_both_variables() {
+        let mut store = InMemoryGraphStore::new();
+        let alice = node(&mut store, "person");
+        let bob = node(&mut store, "person");
+        store.add_edge(alice, bob, Relationship::new("knows".to_string()), None).unwrap();
+
+        let pattern = GraphPattern::new().with_pattern(TriplePattern::new(
+            PatternTerm::Var("x".to_string()),
+            "knows",
+            PatternTerm::Var("y".to_string()),
+        ));
+        let rows: Vec<_> = pattern.evaluate(&store).unwrap().collect();
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].get("x"), Some(&alice));
+        assert_eq!(rows[0].get("y"), Some(&bob));
+    }
+
+    #[test]
+    fn test_joined_patterns_share_variable() {
+        let mut store = InMemoryGraphStore::new();
+        let alice = node(&mut store, "person");
+        let bob = node(&mut store, "person");
+        let acme = node(&mut store, "company");
+        store.add_edge(alice, bob, Relationship::new("knows".to_string()), None).unwrap();
+        store.add_edge(bob, acme, Relationship::new("worksAt".to_string()), None).unwrap();
+
+        let pattern = GraphPattern::new()
+            .with_pattern(TriplePattern::new(
+                PatternTerm::Var("x".to_string()),
+                "knows",
+                PatternTerm::Var("y".to_string()),
+            ))
+            .with_pattern(TriplePattern::new(
+                PatternTerm::Var("y".to_string()),
+                "worksAt",
+                PatternTerm::Var("z".to_string()),
+            ));
+        let rows: Vec<_> = pattern.evaluate(&store).unwrap().collect();
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].get("x"), Some(&alice));
+        assert_eq!(rows[0].get("y"), Some(&bob));
+        assert_eq!(rows[0].get("z"), Some(&acme));
+    }
+
+    #[test]
+    fn test_non_matching_join_yields_no_rows() {
+        let mut store = InMemoryGraphStore::new();
+        let alice = node(&mut store, "person");
+        let bob = node(&mut store, "person");
+        let carol = node(&mut store, "person");
+        let acme = node(&mut store, "company");
+        store.add_edge(alice, bob, Relationship::new("knows".to_string()), None).unwrap();
+        store.add_edge(carol, acme, Relationship::new("worksAt".to_string()), None).unwrap();
+
+        let pattern = GraphPattern::new()
+            .with_pattern(TriplePattern::new(
+                PatternTerm::Var("x".to_string()),
+                "knows",
+                PatternTerm::Var("y".to_string()),
+            ))
+            .with_pattern(TriplePattern::new(
+                PatternTerm::Var("y".to_string()),
+                "worksAt",
+                PatternTerm::Var("z".to_string()),
+            ));
+        let rows: Vec<_> = pattern.evaluate(&store).unwrap().collect();
+
+        assert!(rows.is_empty());
+    }
+
+    #[test]
+    fn test_bound_subject_constrains_scan() {
+        let mut store = InMemoryGraphStore::new();
+        let alice = node(&mut store, "person");
+        let bob = node(&mut store, "person");
+        let carol = node(&mut store, "person");
+        store.add_edge(alice, bob, Relationship::new("knows".to_string()), None).unwrap();
+        store.add_edge(carol, bob, Relationship::new("knows".to_string()), None).unwrap();
+
+        let pattern = GraphPattern::new().with_pattern(TriplePattern::new(
+            PatternTerm::Bound(alice),
+            "knows",
+            PatternTerm::Var("y".to_string()),
+        ));
+        let rows: Vec<_> = pattern.evaluate(&store).unwrap().collect();
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].get("y"), Some(&bob));
+    }
+
+    #[test]
+    fn test_optimizer_puts_selective_pattern_first() {
+        let mut store = InMemoryGraphStore::new();
+        let hub = node(&mut store, "person");
+        // `knows` is an unselective, high-fan-out predicate off `hub`.
+        for _ in 0..20 {
+            let other = node(&mut store, "person");
+            store.add_edge(hub, other, Relationship::new("knows".to_string()), None).unwrap();
+        }
+        // `worksAt` is a fully-bound, highly selective pattern once its subject is known.
+        let acme = node(&mut store, "company");
+        store.add_edge(hub, acme, Relationship::new("worksAt".to_string()), None).unwrap();
+
+        let pattern = GraphPattern::new()
+            .with_pattern(TriplePattern::new(
+                PatternTerm::Var("x".to_string()),
+                "knows",
+                PatternTerm::Var("y".to_string()),
+            ))
+            .with_pattern(TriplePattern::new(
+                PatternTerm::Bound(hub),
+                "worksAt",
+                PatternTerm::Var("z".to_string()),
+            ));
+
+        let optimized = pattern.optimize(&store).unwrap();
+        assert_eq!(optimized.patterns[0].predicate, "worksAt");
+        assert_eq!(optimized.patterns[1].predicate, "knows");
+    }
+
+    #[test]
+    fn test_optimize_preserves_evaluation_result() {
+        let mut store = InMemoryGraphStore::new();
+        let alice = node(&mut store, "person");
+        let bob = node(&mut store, "person");
+        let acme = node(&mut store, "company");
+        store.add_edge(alice, bob, Relationship::new("knows".to_string()), None).unwrap();
+        store.add_edge(bob, acme, Relationship::new("worksAt".to_string()), None).unwrap();
+
+        let pattern = GraphPattern::new()
+            .with_pattern(TriplePattern::new(
+                PatternTerm::Var("x".to_string()),
+                "knows",
+                PatternTerm::Var("y".to_string()),
+            ))
+            .with_pattern(TriplePattern::new(
+                PatternTerm::Var("y".to_string()),
+                "worksAt",
+                PatternTerm::Var("z".to_string()),
+            ));
+
+        let rows: Vec<_> = pattern.evaluate(&store).unwrap().collect();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].get("x"), Some(&alice));
+        assert_eq!(rows[0].get("z"), Some(&acme));
+    }
+
+    #[test]
+    fn test_property_filter_keeps_only_matching_rows() {
+        let mut store = InMemoryGraphStore::new();
+        let alice = node(&mut store, "person");
+        let bob = node(&mut store, "person");
+        store
+            .update_node_data(alice, GraphData::new("person".to_string()).with_property("age".to_string(), Value::Integer(30)))
+            .unwrap();
+        store
+            .update_node_data(bob, GraphData::new("person".to_string()).with_property("age".to_string(), Value::Integer(17)))
+            .unwrap();
+        store.add_edge(alice, bob, Relationship::new("knows".to_string()), None).unwrap();
+        let carol = node(&mut store, "person");
+        store
+            .update_node_data(carol, GraphData::new("person".to_string()).with_property("age".to_string(), Value::Integer(40)))
+            .unwrap();
+        store.add_edge(carol, alice, Relationship::new("knows".to_string()), None).unwrap();
+
+        let pattern = GraphPattern::new()
+            .with_pattern(TriplePattern::new(
+                PatternTerm::Var("x".to_string()),
+                "knows",
+                PatternTerm::Var("y".to_string()),
+            ))
+            .with_filter(PropertyFilter::new(
+                "y",
+                "age",
+                FilterOp::Gte,
+                Literal::parse("18", XsdDatatype::Integer).unwrap(),
+            ));
+
+        let rows: Vec<_> = pattern.evaluate(&store).unwrap().collect();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].get("x"), Some(&carol));
+    }
+
+    #[test]
+    fn test_order_by_sorts_chronologically_not_lexically() {
+        let mut store = InMemoryGraphStore::new();
+        let group = node(&mut store, "event_group");
+
+        // Chronologically: 09:00Z (earliest) < 10:00+02:00 (= 08:00Z) is false — pick
+        // offsets so the *lexical* string order is the reverse of the chronological one.
+        let lexically_first_but_later = node(&mut store, "event");
+        store
+            .update_node_data(
+                lexically_first_but_later,
+                GraphData::new("event".to_string())
+                    .with_property("at".to_string(), Value::Text("2024-01-01T01:00:00Z".to_string())),
+            )
+            .unwrap();
+        store.add_edge(lexically_first_but_later, group, Relationship::new("in".to_string()), None).unwrap();
+
+        let lexically_last_but_earlier = node(&mut store, "event");
+        store
+            .update_node_data(
+                lexically_last_but_earlier,
+                GraphData::new("event".to_string())
+                    .with_property("at".to_string(), Value::Text("2024-01-01T23:00:00+23:30".to_string())),
+            )
+            .unwrap();
+        store.add_edge(lexically_last_but_earlier, group, Relationship::new("in".to_string()), None).unwrap();
+
+        let pattern = GraphPattern::new()
+            .with_pattern(TriplePattern::new(
+                PatternTerm::Var("e".to_string()),
+                "in",
+                PatternTerm::Bound(group),
+            ))
+            .order_by(OrderBy::new("e", "at", XsdDatatype::DateTime, true));
+
+        let rows: Vec<_> = pattern.evaluate(&store).unwrap().collect();
+        assert_eq!(rows.len(), 2);
+        // "2024-01-01T23:00:00+23:30" is 2023-12-31T23:30:00Z, which is chronologically
+        // before "2024-01-01T01:00:00Z" even though its lexical form sorts after it.
+        assert_eq!(rows[0].get("e"), Some(&lexically_last_but_earlier));
+        assert_eq!(rows[1].get("e"), Some(&lexically_first_but_later));
+    }
+
+    #[test]
+    fn test_unscoped_pattern_matches_every_graph() {
+        let mut store = InMemoryGraphStore::new();
+        let alice = node(&mut store, "person");
+        let bob = node(&mut store, "person");
+        let carol = node(&mut store, "person");
+        store
+            .add_edge_in_graph(alice, bob, Relationship::new("knows".to_string()), None, Some("g1".to_string()))
+            .unwrap();
+        store
+            .add_edge_in_graph(alice, carol, Relationship::new("knows".to_string()), None, Some("g2".to_string()))
+            .unwrap();
+
+        let pattern = GraphPattern::new().with_pattern(TriplePattern::new(
+            PatternTerm::Bound(alice),
+            "knows",
+            PatternTerm::Var("y".to_string()),
+        ));
+        let rows: Vec<_> = pattern.evaluate(&store).unwrap().collect();
+
+        let matched: HashSet<NodeId> = rows.iter().filter_map(|row| row.get("y").copied()).collect();
+        assert_eq!(matched, HashSet::from([bob, carol]));
+    }
+
+    #[test]
+    fn test_in_graph_restricts_matching_to_one_graph() {
+        let mut store = InMemoryGraphStore::new();
+        let alice = node(&mut store, "person");
+        let bob = node(&mut store, "person");
+        let carol = node(&mut store, "person");
+        store
+            .add_edge_in_graph(alice, bob, Relationship::new("knows".to_string()), None, Some("g1".to_string()))
+            .unwrap();
+        store
+            .add_edge_in_graph(alice, carol, Relationship::new("knows".to_string()), None, Some("g2".to_string()))
+            .unwrap();
+        store.add_edge(alice, alice, Relationship::new("knows".to_string()), None).unwrap();
+
+        let pattern = GraphPattern::new()
+            .with_pattern(TriplePattern::new(
+                PatternTerm::Bound(alice),
+                "knows",
+                PatternTerm::Var("y".to_string()),
+            ))
+            .in_graph("g1");
+        let rows: Vec<_> = pattern.evaluate(&store).unwrap().collect();
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].get("y"), Some(&bob));
+    }
+}
@@ -112,6 +112,17 @@ impl GraphOperations for InMemoryGraphStore {
         to: NodeId,
         relationship: Relationship,
         data: Option<GraphData>,
+    ) -> Result<EdgeId, OxidbError> {
+        self.add_edge_in_graph(from, to, relationship, data, None)
+    }
+
+    fn add_edge_in_graph(
+        &mut self,
+        from: NodeId,
+        to: NodeId,
+        relationship: Relationship,
+        data: Option<GraphData>,
+        graph: Option<String>,
     ) -> Result<EdgeId, OxidbError> {
         // Verify nodes exist
         let nodes = if self.transaction_active { &self.transaction_nodes } else { &self.nodes };
@@ -129,7 +140,8 @@ impl GraphOperations for InMemoryGraphStore {
         }
 
         let id = self.next_edge_id();
-        let edge = Edge::new(id, from, to, relationship, data);
+        let mut edge = Edge::new(id, from, to, relationship, data);
+        edge.graph = graph;
 
         if self.transaction_active {
             self.transaction_edges.insert(id, edge);
@@ -167,6 +179,35 @@ impl GraphOperations for InMemoryGraphStore {
         Ok(self.edges.get(&edge_id).cloned())
     }
 
+    fn all_nodes(&self) -> Result<Vec<Node>, OxidbError> {
+        if self.transaction_active {
+            let mut nodes = self.nodes.clone();
+            nodes.extend(self.transaction_nodes.clone());
+            return Ok(nodes.into_values().collect());
+        }
+        Ok(self.nodes.values().cloned().collect())
+    }
+
+    fn all_edges(&self) -> Result<Vec<Edge>, OxidbError> {
+        if self.transaction_active {
+            let mut edges = self.edges.clone();
+            edges.extend(self.transaction_edges.clone());
+            return Ok(edges.into_values().collect());
+        }
+        Ok(self.edges.values().cloned().collect())
+    }
+
+    fn update_node_data(&mut self, node_id: NodeId, data: GraphData) -> Result<bool, OxidbError> {
+        if self.transaction_active {
+            if let Some(node) = self.transaction_nodes.get_mut(&node_id) {
+                node.update_data(data);
+                return Ok(true);
+            }
+        }
+
+        Ok(self.nodes.get_mut(&node_id).map(|node| node.update_data(data)).is_some())
+    }
+
     fn remove_node(&mut self, node_id: NodeId) -> Result<bool, OxidbError> {
         // Remove all edges connected to this node first
         if let Some(edge_ids) = self.node_edges.get(&node_id).cloned() {
@@ -598,6 +639,21 @@ impl GraphOperations for PersistentGraphStore {
         result
     }
 
+    fn add_edge_in_graph(
+        &mut self,
+        from: NodeId,
+        to: NodeId,
+        relationship: Relationship,
+        data: Option<GraphData>,
+        graph: Option<String>,
+    ) -> Result<EdgeId, OxidbError> {
+        let result = self.memory_store.add_edge_in_graph(from, to, relationship, data, graph);
+        if result.is_ok() {
+            self.mark_dirty()?; // Mark as dirty and potentially auto-flush
+        }
+        result
+    }
+
     fn get_node(&self, node_id: NodeId) -> Result<Option<Node>, OxidbError> {
         self.memory_store.get_node(node_id)
     }
@@ -606,6 +662,22 @@ impl GraphOperations for PersistentGraphStore {
         self.memory_store.get_edge(edge_id)
     }
 
+    fn all_nodes(&self) -> Result<Vec<Node>, OxidbError> {
+        self.memory_store.all_nodes()
+    }
+
+    fn all_edges(&self) -> Result<Vec<Edge>, OxidbError> {
+        self.memory_store.all_edges()
+    }
+
+    fn update_node_data(&mut self, node_id: NodeId, data: GraphData) -> Result<bool, OxidbError> {
+        let result = self.memory_store.update_node_data(node_id, data);
+        if matches!(result, Ok(true)) {
+            self.mark_dirty()?;
+        }
+        result
+    }
+
     fn remove_node(&mut self, node_id: NodeId) -> Result<bool, OxidbError> {
         let result = self.memory_store.remove_node(node_id);
         if result.is_ok() && result.as_ref().unwrap() == &true {
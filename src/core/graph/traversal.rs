@@ -3,7 +3,8 @@
 //! This module provides various graph traversal strategies and algorithms.
 //! Following SOLID principles with extensible traversal strategies.
 
-use super::types::{EdgeId, NodeId};
+use super::storage::GraphStore;
+use super::types::{EdgeId, NodeId, RelationshipDirection};
 use crate::core::common::OxidbError;
 use std::collections::{HashMap, HashSet, VecDeque};
 
@@ -417,6 +418,58 @@ impl TraversalVisitor for TargetVisitor {
     }
 }
 
+/// Render a node path as a human-readable, hop-by-hop description, e.g.
+/// `"alice -[knows, graph: g1]-> bob -[worksAt]-> acme"`. Each hop looks up the edge
+/// connecting its two consecutive nodes (the first match, if several parallel edges
+/// connect them) and reports its relationship name plus, when the edge is tagged with
+/// one (see [`super::types::Edge::graph`]), which named graph it came from. Nodes are
+/// labeled by their [`super::types::GraphData::label`]; a node id that no longer
+/// resolves (removed mid-traversal) falls back to `"#<id>"` rather than failing the
+/// whole description.
+///
+/// # Errors
+///
+/// Propagates any [`OxidbError`] raised while reading nodes or edges from `store`.
+pub fn describe_path(store: &dyn GraphStore, path: &[NodeId]) -> Result<String, OxidbError> {
+    let Some(&first) = path.first() else { return Ok(String::new()) };
+
+    let edges = store.all_edges()?;
+    let mut description = node_label(store, first)?;
+
+    for hop in path.windows(2) {
+        let (from, to) = (hop[0], hop[1]);
+        let edge = edges.iter().find(|edge| edge.from_node == from && edge.to_node == to).or_else(|| {
+            edges.iter().find(|edge| {
+                edge.relationship.direction == RelationshipDirection::Bidirectional
+                    && edge.from_node == to
+                    && edge.to_node == from
+            })
+        });
+
+        description.push_str(" -[");
+        match edge {
+            Some(edge) => {
+                description.push_str(&edge.relationship.name);
+                if let Some(graph) = &edge.graph {
+                    description.push_str(", graph: ");
+                    description.push_str(graph);
+                }
+            }
+            None => description.push('?'),
+        }
+        description.push_str("]-> ");
+        description.push_str(&node_label(store, to)?);
+    }
+
+    Ok(description)
+}
+
+/// A node's display label for [`describe_path`], falling back to `"#<id>"` when the
+/// node can't be found.
+fn node_label(store: &dyn GraphStore, node_id: NodeId) -> Result<String, OxidbError> {
+    Ok(store.get_node(node_id)?.map_or_else(|| format!("#{node_id}"), |node| node.data.label))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -499,4 +552,37 @@ mod tests {
         assert!(components.contains(&vec![1, 2]));
         assert!(components.contains(&vec![3, 4]));
     }
+
+    #[test]
+    fn test_describe_path_reports_relationship_and_graph() {
+        use crate::core::graph::storage::InMemoryGraphStore;
+        use crate::core::graph::types::{GraphData, Relationship};
+        use crate::core::graph::GraphOperations;
+
+        let mut store = InMemoryGraphStore::new();
+        let alice = store.add_node(GraphData::new("alice".to_string())).unwrap();
+        let bob = store.add_node(GraphData::new("bob".to_string())).unwrap();
+        let acme = store.add_node(GraphData::new("acme".to_string())).unwrap();
+        store
+            .add_edge_in_graph(alice, bob, Relationship::new("knows".to_string()), None, Some("g1".to_string()))
+            .unwrap();
+        store.add_edge(bob, acme, Relationship::new("worksAt".to_string()), None).unwrap();
+
+        let description = describe_path(&store, &[alice, bob, acme]).unwrap();
+        assert_eq!(description, "alice -[knows, graph: g1]-> bob -[worksAt]-> acme");
+    }
+
+    #[test]
+    fn test_describe_path_marks_missing_hop_and_empty_path() {
+        use crate::core::graph::storage::InMemoryGraphStore;
+        use crate::core::graph::types::GraphData;
+        use crate::core::graph::GraphOperations;
+
+        let mut store = InMemoryGraphStore::new();
+        let alice = store.add_node(GraphData::new("alice".to_string())).unwrap();
+        let bob = store.add_node(GraphData::new("bob".to_string())).unwrap();
+
+        assert_eq!(describe_path(&store, &[]).unwrap(), "");
+        assert_eq!(describe_path(&store, &[alice, bob]).unwrap(), "alice -[?]-> bob");
+    }
 }
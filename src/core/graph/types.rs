@@ -32,6 +32,11 @@ pub struct Edge {
     pub data: Option<GraphData>,
     pub created_at: u64, // Unix timestamp
     pub weight: Option<f64>, // Optional edge weight for algorithms
+    /// The named graph this edge belongs to, or `None` for the default (unnamed)
+    /// graph. Lets callers keep provenance-separated datasets (e.g. several imported
+    /// RDF documents) in one store and scope queries to one graph or their union.
+    #[serde(default)]
+    pub graph: Option<String>,
 }
 
 /// Relationship type between nodes
@@ -127,6 +132,7 @@ impl Edge {
             data,
             created_at: now,
             weight: None,
+            graph: None,
         }
     }
 
@@ -144,6 +150,12 @@ impl Edge {
         edge
     }
 
+    /// Tag this edge as belonging to a named graph (builder pattern).
+    #[must_use] pub fn with_graph(mut self, graph: impl Into<String>) -> Self {
+        self.graph = Some(graph.into());
+        self
+    }
+
     /// Check if edge connects the given nodes
     #[must_use] pub fn connects(&self, node1: NodeId, node2: NodeId) -> bool {
         (self.from_node == node1 && self.to_node == node2) ||
@@ -0,0 +1,122 @@
+//! A materialized aggregate index: for each distinct value of a table's
+//! grouping column, incrementally maintains `COUNT`/`SUM`/`AVG`/`MIN`/`MAX`
+//! over another column, so a matching `GROUP BY` query (see
+//! `QueryExecutor::handle_select_aggregate`) can scan pre-computed state
+//! instead of re-aggregating every row. Registered and queried through
+//! `IndexManager`, the same way `CREATE INDEX`'s column indexes are, but
+//! keyed by group value rather than by row key.
+
+use crate::core::query::sql::ast::AggregateFunction;
+use crate::core::types::DataType;
+use std::collections::HashMap;
+
+/// Incrementally-maintained aggregate state for one grouping key.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AggregateState {
+    pub count: u64,
+    pub sum: f64,
+    pub min: Option<DataType>,
+    pub max: Option<DataType>,
+    /// Set when a deleted row held the current `min`/`max`: partial aggregate
+    /// state can't retract an order statistic, so the next read must re-derive
+    /// it from the table's surviving rows for this group (see
+    /// `QueryExecutor::refresh_stale_extremum`).
+    pub extremum_stale: bool,
+}
+
+impl AggregateState {
+    fn numeric(value: &DataType) -> Option<f64> {
+        match value {
+            DataType::Integer(i) => Some(*i as f64),
+            DataType::Float(f) => Some(f.0),
+            _ => None,
+        }
+    }
+
+    pub fn apply_insert(&mut self, value: Option<&DataType>) {
+        self.count += 1;
+        let Some(value) = value else { return };
+        if let Some(n) = Self::numeric(value) {
+            self.sum += n;
+        }
+        if self.min.as_ref().is_none_or(|m| value < m) {
+            self.min = Some(value.clone());
+        }
+        if self.max.as_ref().is_none_or(|m| value > m) {
+            self.max = Some(value.clone());
+        }
+    }
+
+    pub fn apply_delete(&mut self, value: Option<&DataType>) {
+        self.count = self.count.saturating_sub(1);
+        let Some(value) = value else { return };
+        if let Some(n) = Self::numeric(value) {
+            self.sum -= n;
+        }
+        if self.min.as_ref() == Some(value) || self.max.as_ref() == Some(value) {
+            self.extremum_stale = true;
+        }
+    }
+
+    #[must_use]
+    pub fn value_for(&self, function: AggregateFunction) -> DataType {
+        match function {
+            AggregateFunction::Count => DataType::Integer(self.count as i64),
+            AggregateFunction::Sum => DataType::Float(crate::core::types::OrderedFloat(self.sum)),
+            AggregateFunction::Avg => {
+                if self.count == 0 {
+                    DataType::Null
+                } else {
+                    DataType::Float(crate::core::types::OrderedFloat(self.sum / self.count as f64))
+                }
+            }
+            AggregateFunction::Min => self.min.clone().unwrap_or(DataType::Null),
+            AggregateFunction::Max => self.max.clone().unwrap_or(DataType::Null),
+        }
+    }
+}
+
+/// A single `CREATE AGGREGATE INDEX`: which table/columns it summarizes, and
+/// the per-group-value state maintained as rows are inserted into or deleted
+/// from `table_name`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AggregateIndex {
+    pub table_name: String,
+    pub group_column: String,
+    pub function: AggregateFunction,
+    /// `None` for `COUNT(*)`, which has no target column.
+    pub agg_column: Option<String>,
+    states: HashMap<Vec<u8>, AggregateState>,
+}
+
+impl AggregateIndex {
+    #[must_use]
+    pub fn new(
+        table_name: String,
+        group_column: String,
+        function: AggregateFunction,
+        agg_column: Option<String>,
+    ) -> Self {
+        Self { table_name, group_column, function, agg_column, states: HashMap::new() }
+    }
+
+    pub fn apply_insert(&mut self, group_key: Vec<u8>, agg_value: Option<&DataType>) {
+        self.states.entry(group_key).or_default().apply_insert(agg_value);
+    }
+
+    pub fn apply_delete(&mut self, group_key: &[u8], agg_value: Option<&DataType>) {
+        if let Some(state) = self.states.get_mut(group_key) {
+            state.apply_delete(agg_value);
+        }
+    }
+
+    /// Every grouping key this index currently covers, paired with its state,
+    /// in no particular order.
+    pub fn scan(&self) -> impl Iterator<Item = (&Vec<u8>, &AggregateState)> {
+        self.states.iter()
+    }
+
+    pub fn state_mut(&mut self, group_key: &[u8]) -> Option<&mut AggregateState> {
+        self.states.get_mut(group_key)
+    }
+}
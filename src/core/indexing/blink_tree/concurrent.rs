@@ -0,0 +1,959 @@
+//! Concurrent Lehman-Yao B-link tree driver.
+//!
+//! [`BlinkTreeIndex`](super::BlinkTreeIndex) defines `right_link`, `high_key`, and
+//! `is_safe_for_key` but only ever uses them single-threaded, behind `&mut self`. `BlinkTree`
+//! is a separate driver over the same page format that actually exploits those primitives for
+//! concurrency, the way sled's B+ tree does: a reader descends holding no parent latch, and
+//! whenever a node reports `!is_safe_for_key` (its search key has moved past what `high_key`
+//! promises), it follows `right_link` and retries on the sibling rather than trusting the
+//! parent's routing. This tolerates a concurrent split that hasn't yet been linked into the
+//! parent, so splits never need to lock their ancestors.
+//!
+//! Latching is per-page, via an `RwLock` table keyed by `PageId`. A reader holds at most one
+//! page's read latch at a time. A writer latch-couples only at the node it is actually
+//! mutating: insert takes the target leaf's write latch, inserts (splitting if full), writes
+//! the new right page — reachable via `right_link` before the parent is touched — then
+//! releases the leaf latch before propagating the separator key upward, repeating the
+//! move-right logic at each internal level it visits.
+//!
+//! The underlying `BlinkPageManager` still serializes actual disk I/O behind its own mutex;
+//! the latch table above it is what models the concurrency protocol itself.
+//!
+//! `scan_parallel` turns a full-range scan into a multi-core operation the same way: it splits
+//! the requested range at the root's own separator keys into disjoint, child-aligned
+//! sub-ranges, then hands each one to a worker that descends to its starting leaf and streams
+//! the rest via `right_link`, the way thin-provisioning-tools fans a `KeyRange` walk out
+//! across a thread pool.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+
+use crate::core::indexing::blink_tree::error::BlinkTreeError;
+use crate::core::indexing::blink_tree::node::{
+    BlinkTreeNode, InsertValue, KeyType, PageId, PrimaryKey,
+};
+use crate::core::indexing::blink_tree::page_io::{BlinkPageManager, SENTINEL_PAGE_ID};
+
+/// Bounds how far [`BlinkTreeNode::verify_siblings`] will chase a `right_link` chain before
+/// declaring a cycle.
+const MAX_SIBLING_HOPS: usize = 10_000;
+
+/// The latch guarding one page. Readers take it shared, writers take it exclusive. Only an
+/// internal-node split holds two at once: it latches each child it re-parents while still
+/// holding the splitting node's latch.
+type PageLatch = Arc<RwLock<()>>;
+
+/// A half-open key range: `start` is inclusive, `end` is exclusive. `None` on either side
+/// means unbounded in that direction. Used by [`BlinkTree::scan_parallel`] to hand disjoint,
+/// child-aligned slices of the keyspace to its workers.
+pub type ScanRange = (Option<KeyType>, Option<KeyType>);
+
+/// Concurrent Blink tree driver: move-right `insert` and `search` over per-page latches.
+#[derive(Debug)]
+pub struct BlinkTree {
+    pub name: String,
+    pub path: PathBuf,
+    order: usize,
+    page_manager: Mutex<BlinkPageManager>,
+    latches: Mutex<HashMap<PageId, PageLatch>>,
+    root_page_id: RwLock<PageId>,
+}
+
+impl BlinkTree {
+    /// Opens (or creates) a Blink tree page file and prepares it for concurrent access.
+    ///
+    /// # Errors
+    /// Returns `BlinkTreeError::Generic` if `order < 3`, or any error from opening/creating
+    /// the underlying page file.
+    pub fn new(name: String, path: PathBuf, order: usize) -> Result<Self, BlinkTreeError> {
+        if order < 3 {
+            return Err(BlinkTreeError::Generic(
+                "Order must be at least 3 for a valid Blink tree".to_string(),
+            ));
+        }
+
+        let mut page_manager = BlinkPageManager::new(&path, order, true)?;
+        let mut root_page_id = page_manager.get_root_page_id();
+
+        if root_page_id == SENTINEL_PAGE_ID {
+            let new_page_id = page_manager.allocate_new_page_id()?;
+            let root_node = BlinkTreeNode::Leaf {
+                page_id: new_page_id,
+                parent_page_id: None,
+                keys: Vec::new(),
+                values: Vec::new(),
+                right_link: None,
+                high_key: None,
+            };
+            page_manager.write_node(&root_node)?;
+            page_manager.set_root_page_id(new_page_id)?;
+            root_page_id = new_page_id;
+        }
+
+        Ok(Self {
+            name,
+            path,
+            order,
+            page_manager: Mutex::new(page_manager),
+            latches: Mutex::new(HashMap::new()),
+            root_page_id: RwLock::new(root_page_id),
+        })
+    }
+
+    fn latch_for(&self, page_id: PageId) -> PageLatch {
+        self.latches.lock().unwrap().entry(page_id).or_insert_with(|| Arc::new(RwLock::new(()))).clone()
+    }
+
+    fn read_node(&self, page_id: PageId) -> Result<BlinkTreeNode, BlinkTreeError> {
+        self.page_manager.lock().unwrap().read_node(page_id)
+    }
+
+    fn write_node(&self, node: &BlinkTreeNode) -> Result<(), BlinkTreeError> {
+        self.page_manager.lock().unwrap().write_node(node)
+    }
+
+    fn allocate_page(&self) -> Result<PageId, BlinkTreeError> {
+        self.page_manager.lock().unwrap().allocate_new_page_id()
+    }
+
+    fn root(&self) -> PageId {
+        *self.root_page_id.read().unwrap()
+    }
+
+    /// Lock-coupled, move-right search: holds at most one page's read latch at a time.
+    ///
+    /// # Errors
+    /// Returns `BlinkTreeError::TreeLogicError` if a node reports itself unsafe for `key`
+    /// but has no `right_link` to move to (a corrupt tree), or any I/O/serialization error.
+    pub fn search(&self, key: &KeyType) -> Result<Option<Vec<PrimaryKey>>, BlinkTreeError> {
+        let mut page_id = self.root();
+
+        loop {
+            let latch = self.latch_for(page_id);
+            let node = {
+                let _guard = latch.read().unwrap();
+                self.read_node(page_id)?
+            };
+
+            if let Some(right) = self.move_right_target(&node, key, page_id)? {
+                page_id = right;
+                continue;
+            }
+
+            match &node {
+                BlinkTreeNode::Leaf { keys, values, .. } => {
+                    return Ok(keys.iter().position(|k| k == key).map(|i| values[i].clone()));
+                }
+                BlinkTreeNode::Internal { children, .. } => {
+                    let child_index = node
+                        .find_child_index(key)
+                        .map_err(|e| BlinkTreeError::Generic(e.to_string()))?;
+                    page_id = children[child_index];
+                }
+            }
+        }
+    }
+
+    /// If `node` is unsafe for `key` (its `high_key` no longer covers it, meaning a
+    /// concurrent split landed since our parent last routed us here), returns the sibling to
+    /// retry on. Otherwise `Ok(None)`, meaning `node` itself is safe to act on.
+    fn move_right_target(
+        &self,
+        node: &BlinkTreeNode,
+        key: &KeyType,
+        page_id: PageId,
+    ) -> Result<Option<PageId>, BlinkTreeError> {
+        if node.is_safe_for_key(key) {
+            return Ok(None);
+        }
+        node.get_right_link().map(Some).ok_or_else(|| {
+            BlinkTreeError::TreeLogicError(format!(
+                "node {page_id} is unsafe for the search key but has no right_link to move to"
+            ))
+        })
+    }
+
+    /// Inserts `value` under `key`.
+    ///
+    /// # Errors
+    /// Returns `BlinkTreeError` if descent or any page read/write/split fails.
+    pub fn insert(&self, key: KeyType, value: PrimaryKey) -> Result<(), BlinkTreeError> {
+        let mut path = Vec::new();
+        let leaf_page_id = self.descend_to_leaf(&key, &mut path)?;
+        self.insert_into_leaf(leaf_page_id, key, value, path)
+    }
+
+    /// Descends from the root to the leaf that should hold `key`, recording the path of
+    /// internal pages visited (for split propagation) and moving right at each level
+    /// whenever the current node is unsafe for `key`.
+    fn descend_to_leaf(
+        &self,
+        key: &KeyType,
+        path: &mut Vec<PageId>,
+    ) -> Result<PageId, BlinkTreeError> {
+        let mut page_id = self.root();
+
+        loop {
+            let latch = self.latch_for(page_id);
+            let node = {
+                let _guard = latch.read().unwrap();
+                self.read_node(page_id)?
+            };
+
+            if let Some(right) = self.move_right_target(&node, key, page_id)? {
+                page_id = right;
+                continue;
+            }
+
+            if node.is_leaf() {
+                return Ok(page_id);
+            }
+
+            path.push(page_id);
+            let child_index =
+                node.find_child_index(key).map_err(|e| BlinkTreeError::Generic(e.to_string()))?;
+            page_id = match &node {
+                BlinkTreeNode::Internal { children, .. } => children[child_index],
+                BlinkTreeNode::Leaf { .. } => unreachable!("handled by is_leaf check above"),
+            };
+        }
+    }
+
+    /// Latch-couples only at the target leaf: holds its write latch across the
+    /// insert-or-split, releases it, then (only if a split happened) propagates the
+    /// separator key up.
+    fn insert_into_leaf(
+        &self,
+        mut page_id: PageId,
+        key: KeyType,
+        value: PrimaryKey,
+        path: Vec<PageId>,
+    ) -> Result<(), BlinkTreeError> {
+        let split = loop {
+            let latch = self.latch_for(page_id);
+            let guard = latch.write().unwrap();
+            let mut node = self.read_node(page_id)?;
+
+            if let Some(right) = self.move_right_target(&node, &key, page_id)? {
+                drop(guard);
+                page_id = right;
+                continue;
+            }
+
+            if let BlinkTreeNode::Leaf { keys, values, .. } = &mut node {
+                if let Some(i) = keys.iter().position(|k| k == &key) {
+                    values[i].push(value);
+                    self.write_node(&node)?;
+                    break None;
+                }
+            }
+
+            match node.insert_key_value(
+                key.clone(),
+                InsertValue::PrimaryKeys(vec![value.clone()]),
+                self.order,
+            ) {
+                Ok(()) => {
+                    self.write_node(&node)?;
+                    break None;
+                }
+                Err(_) => break Some(self.split_leaf(node, key.clone(), value.clone())?),
+            }
+        };
+
+        match split {
+            None => Ok(()),
+            Some((split_key, new_page_id)) => {
+                self.propagate_split_up(split_key, page_id, new_page_id, path)
+            }
+        }
+    }
+
+    /// Splits a full leaf, writing the new right node before the original left node so a
+    /// concurrent reader chasing `right_link` never lands on a half-written page.
+    fn split_leaf(
+        &self,
+        mut leaf_node: BlinkTreeNode,
+        key: KeyType,
+        value: PrimaryKey,
+    ) -> Result<(KeyType, PageId), BlinkTreeError> {
+        let new_page_id = self.allocate_page()?;
+
+        let should_insert_in_left = match &leaf_node {
+            BlinkTreeNode::Leaf { keys, .. } => {
+                let mid = keys.len() / 2;
+                mid == 0 || key < keys[mid]
+            }
+            BlinkTreeNode::Internal { .. } => return Err(BlinkTreeError::UnexpectedNodeType),
+        };
+
+        if should_insert_in_left {
+            force_insert_into_leaf(&mut leaf_node, key.clone(), value.clone());
+        }
+
+        let (split_key, mut new_right_node) = leaf_node
+            .split(self.order, new_page_id)
+            .map_err(|e| BlinkTreeError::Generic(e.to_string()))?;
+
+        if !should_insert_in_left {
+            new_right_node
+                .insert_key_value(key, InsertValue::PrimaryKeys(vec![value]), self.order)
+                .map_err(|e| BlinkTreeError::Generic(e.to_string()))?;
+        }
+
+        self.write_node(&new_right_node)?;
+        self.write_node(&leaf_node)?;
+
+        Ok((split_key, new_page_id))
+    }
+
+    /// Propagates a split's separator key into `path`'s innermost ancestor, latch-coupling
+    /// and moving right at that level the same way `descend_to_leaf` does, recursing further
+    /// up if that ancestor itself overflows.
+    ///
+    /// `left_child_id` is the page that just split (now the left half); it's only used to
+    /// resolve an empty `path`. An empty `path` does *not* reliably mean "the node that just
+    /// split was the root": `descend_to_leaf`/this function's own move-right retries never
+    /// push onto `path` when they follow a `right_link`, so a node reached that way reports
+    /// an empty path too, even though it has a real parent that a concurrent root split
+    /// already created. So an empty path must be re-validated against the *current* tree
+    /// before assuming it's a root split - otherwise a second concurrent split can call
+    /// `create_new_root` with the wrong, unrelated current root as `left_child_id`, silently
+    /// dropping the real left half out of the routing structure.
+    fn propagate_split_up(
+        &self,
+        split_key: KeyType,
+        left_child_id: PageId,
+        new_page_id: PageId,
+        mut path: Vec<PageId>,
+    ) -> Result<(), BlinkTreeError> {
+        let mut parent_page_id = match path.pop() {
+            Some(parent_page_id) => parent_page_id,
+            None => match self.find_current_parent(left_child_id, &split_key)? {
+                Some(parent_page_id) => parent_page_id,
+                None => return self.create_new_root(split_key, left_child_id, new_page_id),
+            },
+        };
+
+        let split = loop {
+            let latch = self.latch_for(parent_page_id);
+            let guard = latch.write().unwrap();
+            let mut parent_node = self.read_node(parent_page_id)?;
+
+            if let Some(right) = self.move_right_target(&parent_node, &split_key, parent_page_id)? {
+                drop(guard);
+                parent_page_id = right;
+                continue;
+            }
+
+            match parent_node.insert_key_value(
+                split_key.clone(),
+                InsertValue::Page(new_page_id),
+                self.order,
+            ) {
+                Ok(()) => {
+                    self.write_node(&parent_node)?;
+                    break None;
+                }
+                Err(_) => {
+                    break Some(self.split_internal(parent_node, split_key.clone(), new_page_id)?);
+                }
+            }
+        };
+
+        match split {
+            None => Ok(()),
+            Some((grand_split_key, grand_new_page_id)) => {
+                self.propagate_split_up(grand_split_key, parent_page_id, grand_new_page_id, path)
+            }
+        }
+    }
+
+    /// Re-descends from the *current* root to find `child_id`'s real parent, the same way
+    /// `descend_to_leaf` finds a leaf's parent - following `right_link` via
+    /// `move_right_target` and otherwise routing on `routing_key` (a key known to belong to
+    /// `child_id`'s subtree, e.g. the separator produced by its split).
+    ///
+    /// Returns `Ok(None)` if `child_id` *is* the current root (no parent to find), which is
+    /// the only case where creating a new root above it is correct. Used by
+    /// `propagate_split_up` to tell a genuine root split apart from a node that merely has no
+    /// entry in `path` because it was reached by a move-right retry.
+    fn find_current_parent(
+        &self,
+        child_id: PageId,
+        routing_key: &KeyType,
+    ) -> Result<Option<PageId>, BlinkTreeError> {
+        let mut page_id = self.root();
+        if page_id == child_id {
+            return Ok(None);
+        }
+
+        loop {
+            let latch = self.latch_for(page_id);
+            let node = {
+                let _guard = latch.read().unwrap();
+                self.read_node(page_id)?
+            };
+
+            if let Some(right) = self.move_right_target(&node, routing_key, page_id)? {
+                page_id = right;
+                continue;
+            }
+
+            match &node {
+                BlinkTreeNode::Internal { children, .. } => {
+                    if children.contains(&child_id) {
+                        return Ok(Some(page_id));
+                    }
+                    let child_index = node
+                        .find_child_index(routing_key)
+                        .map_err(|e| BlinkTreeError::Generic(e.to_string()))?;
+                    page_id = children[child_index];
+                }
+                BlinkTreeNode::Leaf { .. } => {
+                    return Err(BlinkTreeError::TreeLogicError(format!(
+                        "expected to find page {child_id} as a child somewhere above it, reached a leaf instead"
+                    )));
+                }
+            }
+        }
+    }
+
+    /// Splits a full internal node, re-parenting the children that moved to the new right
+    /// node.
+    fn split_internal(
+        &self,
+        mut internal_node: BlinkTreeNode,
+        key: KeyType,
+        child_page_id: PageId,
+    ) -> Result<(KeyType, PageId), BlinkTreeError> {
+        let new_page_id = self.allocate_page()?;
+
+        force_insert_into_internal(&mut internal_node, key, child_page_id);
+
+        let (split_key, new_right_node) = internal_node
+            .split(self.order, new_page_id)
+            .map_err(|e| BlinkTreeError::Generic(e.to_string()))?;
+
+        self.reparent_children(&new_right_node)?;
+
+        self.write_node(&new_right_node)?;
+        self.write_node(&internal_node)?;
+
+        Ok((split_key, new_page_id))
+    }
+
+    /// Updates `parent_page_id` on every child of `node`, latching each one in turn.
+    fn reparent_children(&self, node: &BlinkTreeNode) -> Result<(), BlinkTreeError> {
+        if let BlinkTreeNode::Internal { children, page_id, .. } = node {
+            for &child_id in children {
+                let latch = self.latch_for(child_id);
+                let _guard = latch.write().unwrap();
+                let mut child = self.read_node(child_id)?;
+                child.set_parent_page_id(Some(*page_id));
+                self.write_node(&child)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Creates a new root above `left_child_id`/`right_child_id` when the previous root just
+    /// split.
+    fn create_new_root(
+        &self,
+        split_key: KeyType,
+        left_child_id: PageId,
+        right_child_id: PageId,
+    ) -> Result<(), BlinkTreeError> {
+        let new_root_page_id = self.allocate_page()?;
+
+        let new_root = BlinkTreeNode::Internal {
+            page_id: new_root_page_id,
+            parent_page_id: None,
+            keys: vec![split_key],
+            children: vec![left_child_id, right_child_id],
+            right_link: None,
+            high_key: None,
+        };
+
+        for child_id in [left_child_id, right_child_id] {
+            let latch = self.latch_for(child_id);
+            let _guard = latch.write().unwrap();
+            let mut child = self.read_node(child_id)?;
+            child.set_parent_page_id(Some(new_root_page_id));
+            self.write_node(&child)?;
+        }
+
+        self.write_node(&new_root)?;
+
+        *self.root_page_id.write().unwrap() = new_root_page_id;
+        self.page_manager.lock().unwrap().set_root_page_id(new_root_page_id)?;
+
+        Ok(())
+    }
+
+    /// Descends the leftmost spine of the tree to find the leftmost leaf.
+    fn leftmost_leaf(&self) -> Result<PageId, BlinkTreeError> {
+        let mut page_id = self.root();
+        loop {
+            let node = self.read_node(page_id)?;
+            match &node {
+                BlinkTreeNode::Leaf { .. } => return Ok(page_id),
+                BlinkTreeNode::Internal { children, .. } => page_id = children[0],
+            }
+        }
+    }
+
+    /// Walks every leaf's `right_link` chain left to right, verifying it is acyclic and that
+    /// keys stay ordered across siblings. Intended as a post-hoc consistency check, e.g.
+    /// after a burst of concurrent inserts.
+    ///
+    /// # Errors
+    /// Returns `BlinkTreeError::TreeLogicError` describing the first violation found.
+    pub fn verify_siblings(&self) -> Result<(), BlinkTreeError> {
+        let leftmost = self.leftmost_leaf()?;
+        let node = self.read_node(leftmost)?;
+        node.verify_siblings(|id| self.read_node(id), MAX_SIBLING_HOPS)
+    }
+
+    /// Scans `range` by partitioning it at the root's separator keys into up to `n_workers`
+    /// disjoint, child-aligned sub-ranges and walking each one's leaves concurrently via
+    /// `right_link`, the way thin-provisioning-tools splits a `KeyRange` across a thread
+    /// pool. Sub-ranges are produced left to right, so joining workers in that same order and
+    /// concatenating their results is already a key-ordered merge.
+    ///
+    /// # Errors
+    /// Returns the first `BlinkTreeError` raised by any worker.
+    pub fn scan_parallel(
+        &self,
+        range: ScanRange,
+        n_workers: usize,
+    ) -> Result<Vec<(KeyType, Vec<PrimaryKey>)>, BlinkTreeError> {
+        let sub_ranges = self.partition_range(range, n_workers.max(1))?;
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = sub_ranges
+                .into_iter()
+                .map(|sub_range| scope.spawn(move || self.scan_range_sequential(&sub_range)))
+                .collect();
+
+            let mut merged = Vec::new();
+            for handle in handles {
+                merged.extend(handle.join().expect("scan_parallel worker thread panicked")?);
+            }
+            Ok(merged)
+        })
+    }
+
+    /// Splits `range` at up to `n_workers - 1` of the root's own separator keys, evenly
+    /// spaced, via [`split_scan_range`]. Falls back to `vec![range]` if the root is a leaf (no
+    /// separators to split on) or `n_workers <= 1`.
+    fn partition_range(
+        &self,
+        range: ScanRange,
+        n_workers: usize,
+    ) -> Result<Vec<ScanRange>, BlinkTreeError> {
+        let root_node = self.read_node(self.root())?;
+        let separators = match &root_node {
+            BlinkTreeNode::Internal { keys, .. } => keys.clone(),
+            BlinkTreeNode::Leaf { .. } => Vec::new(),
+        };
+
+        if separators.is_empty() || n_workers <= 1 {
+            return Ok(vec![range]);
+        }
+
+        let target_splits = n_workers - 1;
+        let step = (separators.len() / (target_splits + 1)).max(1);
+        let mut split_indices: Vec<usize> =
+            (1..=target_splits).map(|i| (i * step).min(separators.len() - 1)).collect();
+        split_indices.dedup();
+
+        let mut ranges = vec![range];
+        for idx in split_indices {
+            let Some(last) = ranges.pop() else { break };
+            match split_scan_range(&last, &separators[idx]) {
+                Some((left, right)) => {
+                    ranges.push(left);
+                    ranges.push(right);
+                }
+                None => ranges.push(last),
+            }
+        }
+
+        Ok(ranges)
+    }
+
+    /// Walks one sub-range's leaves left to right, starting from the leaf that would hold
+    /// `range.0` (the leftmost leaf if unbounded) and following `right_link` until a node's
+    /// `high_key` already covers the sub-range's `end` — i.e. `is_safe_for_key` reports the
+    /// partition is exhausted, so there is no need to cross into the next worker's territory.
+    fn scan_range_sequential(
+        &self,
+        range: &ScanRange,
+    ) -> Result<Vec<(KeyType, Vec<PrimaryKey>)>, BlinkTreeError> {
+        let (start, end) = range;
+        let mut page_id = self.find_leaf_for_range_start(start)?;
+        let mut results = Vec::new();
+
+        loop {
+            let latch = self.latch_for(page_id);
+            let node = {
+                let _guard = latch.read().unwrap();
+                self.read_node(page_id)?
+            };
+
+            let (keys, values) = match &node {
+                BlinkTreeNode::Leaf { keys, values, .. } => (keys, values),
+                BlinkTreeNode::Internal { .. } => return Err(BlinkTreeError::UnexpectedNodeType),
+            };
+
+            for (i, key) in keys.iter().enumerate() {
+                if start.as_ref().is_some_and(|s| key < s) {
+                    continue;
+                }
+                if end.as_ref().is_some_and(|e| key >= e) {
+                    return Ok(results);
+                }
+                results.push((key.clone(), values[i].clone()));
+            }
+
+            let partition_exhausted = end.as_ref().is_some_and(|e| node.is_safe_for_key(e));
+            if partition_exhausted {
+                return Ok(results);
+            }
+
+            match node.get_right_link() {
+                Some(next) => page_id = next,
+                None => return Ok(results),
+            }
+        }
+    }
+
+    /// Descends from the root to the leaf that should hold `start` (the leftmost leaf if
+    /// `start` is unbounded), following `right_link` whenever a node is unsafe for `start`.
+    fn find_leaf_for_range_start(&self, start: &Option<KeyType>) -> Result<PageId, BlinkTreeError> {
+        let Some(key) = start else {
+            return self.leftmost_leaf();
+        };
+
+        let mut page_id = self.root();
+        loop {
+            let latch = self.latch_for(page_id);
+            let node = {
+                let _guard = latch.read().unwrap();
+                self.read_node(page_id)?
+            };
+
+            if let Some(right) = self.move_right_target(&node, key, page_id)? {
+                page_id = right;
+                continue;
+            }
+
+            match &node {
+                BlinkTreeNode::Leaf { .. } => return Ok(page_id),
+                BlinkTreeNode::Internal { children, .. } => {
+                    let child_index = node
+                        .find_child_index(key)
+                        .map_err(|e| BlinkTreeError::Generic(e.to_string()))?;
+                    page_id = children[child_index];
+                }
+            }
+        }
+    }
+}
+
+/// Splits `range` into `(left, right)` at `at_key`, refusing to produce an empty partition:
+/// returns `None` if `at_key` falls at or outside `range`'s existing bounds. `left` covers
+/// `[range.0, at_key)` and `right` covers `[at_key, range.1)`, matching `find_child_index`'s
+/// convention that a separator key itself routes into the right-hand subtree.
+fn split_scan_range(range: &ScanRange, at_key: &KeyType) -> Option<(ScanRange, ScanRange)> {
+    let (start, end) = range;
+
+    if start.as_ref().is_some_and(|s| at_key <= s) {
+        return None;
+    }
+    if end.as_ref().is_some_and(|e| at_key >= e) {
+        return None;
+    }
+
+    Some(((start.clone(), Some(at_key.clone())), (Some(at_key.clone()), end.clone())))
+}
+
+fn force_insert_into_leaf(leaf_node: &mut BlinkTreeNode, key: KeyType, value: PrimaryKey) {
+    if let BlinkTreeNode::Leaf { keys, values, .. } = leaf_node {
+        let mut insert_pos = keys.len();
+        for (i, existing_key) in keys.iter().enumerate() {
+            if &key < existing_key {
+                insert_pos = i;
+                break;
+            } else if &key == existing_key {
+                values[i].push(value);
+                return;
+            }
+        }
+        keys.insert(insert_pos, key);
+        values.insert(insert_pos, vec![value]);
+    }
+}
+
+fn force_insert_into_internal(internal_node: &mut BlinkTreeNode, key: KeyType, child_page_id: PageId) {
+    if let BlinkTreeNode::Internal { keys, children, .. } = internal_node {
+        let mut insert_pos = keys.len();
+        for (i, existing_key) in keys.iter().enumerate() {
+            if &key < existing_key {
+                insert_pos = i;
+                break;
+            }
+        }
+        keys.insert(insert_pos, key);
+        children.insert(insert_pos + 1, child_page_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+    use tempfile::TempDir;
+
+    fn k(n: u32) -> KeyType {
+        format!("{n:06}").into_bytes()
+    }
+
+    fn pk(n: u32) -> PrimaryKey {
+        format!("pk{n}").into_bytes()
+    }
+
+    fn setup(test_name: &str, order: usize) -> (Arc<BlinkTree>, TempDir) {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join(format!("{test_name}.blink"));
+        let tree = BlinkTree::new("test_blink_concurrent".to_string(), path, order).unwrap();
+        (Arc::new(tree), temp_dir)
+    }
+
+    #[test]
+    fn test_insert_and_search_single_threaded() {
+        let (tree, _temp_dir) = setup("single_threaded", 5);
+
+        for i in 0..50 {
+            tree.insert(k(i), pk(i)).unwrap();
+        }
+
+        for i in 0..50 {
+            let result = tree.search(&k(i)).unwrap();
+            assert_eq!(result, Some(vec![pk(i)]));
+        }
+
+        assert!(tree.verify_siblings().is_ok());
+    }
+
+    #[test]
+    fn test_duplicate_key_accumulates_values() {
+        let (tree, _temp_dir) = setup("duplicate_key", 5);
+
+        tree.insert(k(1), pk(1)).unwrap();
+        tree.insert(k(1), pk(2)).unwrap();
+
+        let result = tree.search(&k(1)).unwrap().unwrap();
+        assert_eq!(result.len(), 2);
+        assert!(result.contains(&pk(1)));
+        assert!(result.contains(&pk(2)));
+    }
+
+    #[test]
+    fn test_concurrent_inserts_with_overlapping_key_ranges() {
+        let (tree, _temp_dir) = setup("concurrent_overlap", 5);
+
+        let thread_count = 8;
+        let inserts_per_thread = 50;
+
+        let handles: Vec<_> = (0..thread_count)
+            .map(|t| {
+                let tree = Arc::clone(&tree);
+                thread::spawn(move || {
+                    // Each thread's keys interleave with every other thread's, so the same
+                    // leaves and internal nodes get split concurrently from multiple threads.
+                    for i in 0..inserts_per_thread {
+                        let key_num = i * thread_count + t;
+                        tree.insert(k(key_num), pk(key_num)).unwrap();
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        for i in 0..(thread_count * inserts_per_thread) {
+            let result = tree.search(&k(i)).unwrap();
+            assert_eq!(result, Some(vec![pk(i)]), "missing key {i} after concurrent inserts");
+        }
+
+        tree.verify_siblings().expect("sibling chain must stay consistent after concurrent splits");
+    }
+
+    /// Looks a key up by following only parent/child pointers from the root, never
+    /// `right_link`. A key findable by `BlinkTree::search` (which falls back to
+    /// `right_link`) but not by this strict descent means its leaf was dropped from the
+    /// routing structure - reachable only as a dangling sibling, not as anyone's child.
+    fn strict_descend_find(
+        tree: &BlinkTree,
+        key: &KeyType,
+    ) -> Result<Option<Vec<PrimaryKey>>, BlinkTreeError> {
+        let mut page_id = tree.root();
+        loop {
+            let node = tree.read_node(page_id)?;
+            match &node {
+                BlinkTreeNode::Leaf { keys, values, .. } => {
+                    return Ok(keys.iter().position(|k| k == key).map(|i| values[i].clone()));
+                }
+                BlinkTreeNode::Internal { children, .. } => {
+                    let child_index = node
+                        .find_child_index(key)
+                        .map_err(|e| BlinkTreeError::Generic(e.to_string()))?;
+                    page_id = children[child_index];
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_concurrent_root_splits_stay_reachable_by_strict_child_descent() {
+        // Small order plus many threads maximizes how often two leaves split while the
+        // tree has no internal nodes yet, which is exactly when an empty `path` is
+        // ambiguous between "I'm the root" and "I was reached via right_link".
+        let (tree, _temp_dir) = setup("concurrent_root_splits", 3);
+
+        let thread_count = 8;
+        let inserts_per_thread = 30;
+
+        let handles: Vec<_> = (0..thread_count)
+            .map(|t| {
+                let tree = Arc::clone(&tree);
+                thread::spawn(move || {
+                    for i in 0..inserts_per_thread {
+                        let key_num = i * thread_count + t;
+                        tree.insert(k(key_num), pk(key_num)).unwrap();
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        for i in 0..(thread_count * inserts_per_thread) {
+            let result = strict_descend_find(&tree, &k(i))
+                .unwrap_or_else(|e| panic!("strict descent for key {i} failed: {e}"));
+            assert_eq!(
+                result,
+                Some(vec![pk(i)]),
+                "key {i} is only reachable via a right_link fallback, not via parent/child \
+                 pointers - its leaf was dropped from the routing structure by a racing root split"
+            );
+        }
+    }
+
+    #[test]
+    fn test_concurrent_readers_during_writes() {
+        let (tree, _temp_dir) = setup("concurrent_readers", 5);
+
+        for i in 0..20 {
+            tree.insert(k(i), pk(i)).unwrap();
+        }
+
+        let writer_tree = Arc::clone(&tree);
+        let writer = thread::spawn(move || {
+            for i in 20..120 {
+                writer_tree.insert(k(i), pk(i)).unwrap();
+            }
+        });
+
+        let reader_tree = Arc::clone(&tree);
+        let reader = thread::spawn(move || {
+            // Readers must never see a TreeLogicError or panic, even while splits are
+            // happening concurrently under them.
+            for _ in 0..200 {
+                for i in 0..20 {
+                    assert!(reader_tree.search(&k(i)).unwrap().is_some());
+                }
+            }
+        });
+
+        writer.join().unwrap();
+        reader.join().unwrap();
+
+        tree.verify_siblings().unwrap();
+    }
+
+    #[test]
+    fn test_split_scan_range_refuses_empty_partitions() {
+        let range: ScanRange = (Some(k(10)), Some(k(20)));
+
+        let expected_left: ScanRange = (Some(k(10)), Some(k(15)));
+        let expected_right: ScanRange = (Some(k(15)), Some(k(20)));
+        assert_eq!(split_scan_range(&range, &k(15)), Some((expected_left, expected_right)));
+        assert_eq!(split_scan_range(&range, &k(10)), None);
+        assert_eq!(split_scan_range(&range, &k(20)), None);
+        assert_eq!(split_scan_range(&range, &k(5)), None);
+        assert_eq!(split_scan_range(&range, &k(25)), None);
+    }
+
+    #[test]
+    fn test_scan_parallel_full_range_matches_sequential_scan() {
+        let (tree, _temp_dir) = setup("scan_parallel_full", 5);
+
+        for i in 0..200 {
+            tree.insert(k(i), pk(i)).unwrap();
+        }
+
+        let sequential = tree.scan_range_sequential(&(None, None)).unwrap();
+        let parallel = tree.scan_parallel((None, None), 8).unwrap();
+
+        assert_eq!(parallel, sequential);
+        assert_eq!(parallel.len(), 200);
+    }
+
+    #[test]
+    fn test_scan_parallel_bounded_range() {
+        let (tree, _temp_dir) = setup("scan_parallel_bounded", 5);
+
+        for i in 0..100 {
+            tree.insert(k(i), pk(i)).unwrap();
+        }
+
+        let results = tree.scan_parallel((Some(k(30)), Some(k(60))), 4).unwrap();
+
+        assert_eq!(results.len(), 30);
+        for (key, values) in &results {
+            assert_eq!(values, &vec![pk(u32_from_key(key))]);
+        }
+        assert!(results.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_scan_parallel_degrades_gracefully_when_workers_exceed_separators() {
+        let (tree, _temp_dir) = setup("scan_parallel_excess_workers", 5);
+
+        for i in 0..10 {
+            tree.insert(k(i), pk(i)).unwrap();
+        }
+
+        // Far more workers than the tree has separator keys to split on: partitioning should
+        // just produce as many sub-ranges as the structure affords, not panic or drop keys.
+        let results = tree.scan_parallel((None, None), 64).unwrap();
+
+        assert_eq!(results.len(), 10);
+        assert!(results.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    fn u32_from_key(key: &KeyType) -> u32 {
+        std::str::from_utf8(key).unwrap().parse().unwrap()
+    }
+}
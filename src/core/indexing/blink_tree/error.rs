@@ -12,6 +12,9 @@ pub enum BlinkTreeError {
     ConcurrencyError(String), // New for Blink tree - concurrent access issues
     BorrowError(String),      // For RefCell borrow errors
     Generic(String),          // For general string errors
+    /// `BlinkPageManager::read_node` recomputed the page's trailing CRC32 checksum and it
+    /// didn't match what was stored, meaning the page was torn or bit-rotted in storage.
+    ChecksumMismatch { page_id: PageId },
 }
 
 impl std::fmt::Display for BlinkTreeError {
@@ -26,6 +29,9 @@ impl std::fmt::Display for BlinkTreeError {
             Self::ConcurrencyError(msg) => write!(f, "Concurrency error: {msg}"),
             Self::BorrowError(msg) => write!(f, "Borrow error: {msg}"),
             Self::Generic(msg) => write!(f, "Error: {msg}"),
+            Self::ChecksumMismatch { page_id } => {
+                write!(f, "Checksum mismatch reading page {page_id}: page is corrupt")
+            }
         }
     }
 }
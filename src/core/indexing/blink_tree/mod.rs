@@ -1,8 +1,10 @@
+mod concurrent;
 mod error;
 mod node;
 mod page_io;
 pub mod tree;
 
+pub use concurrent::BlinkTree;
 pub use error::BlinkTreeError;
 pub use node::{BlinkTreeNode, KeyType, PageId, PrimaryKey};
 pub use tree::BlinkTreeIndex;
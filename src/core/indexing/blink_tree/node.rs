@@ -1,3 +1,4 @@
+use crate::core::common::crc32;
 use serde::{Deserialize, Serialize};
 use std::io::{Cursor, Read, Write};
 
@@ -34,8 +35,26 @@ pub enum SerializationError {
     IoError(String),
     InvalidFormat(String),
     UnknownNodeType(u8),
+    /// The trailing CRC32 checksum didn't match the recomputed one, meaning the page was
+    /// torn or bit-rotted in storage.
+    ChecksumMismatch { expected: u32, found: u32 },
 }
 
+/// Sentinel written as the very first byte of a serialized node to mark the newer,
+/// front-coding-capable layout (see `write_keys_section`/`read_keys_section`). A legacy
+/// record predating this format starts directly with a node-type byte, which is only ever
+/// `0` or `1` — this value can never collide with one, so old pages keep decoding correctly
+/// under the original layout without a migration step.
+const FORMAT_VERSION_FRONT_CODED_KEYS: u8 = 2;
+
+/// Keys section written as plain `[len: u32][bytes]` per key, identical to the original
+/// (pre-front-coding) layout.
+const KEY_ENCODING_PLAIN: u8 = 0;
+
+/// Keys section front-coded against the previous key: the first key is written in full,
+/// then each subsequent key stores only `shared_prefix_len` (varint) followed by its suffix.
+const KEY_ENCODING_FRONT_CODED: u8 = 1;
+
 impl From<std::io::Error> for SerializationError {
     fn from(err: std::io::Error) -> Self {
         Self::IoError(err.to_string())
@@ -293,6 +312,7 @@ impl BlinkTreeNode {
     /// Serialize the node to bytes
     pub fn to_bytes(&self) -> Result<Vec<u8>, SerializationError> {
         let mut buffer = Vec::new();
+        buffer.write_all(&[FORMAT_VERSION_FRONT_CODED_KEYS])?;
 
         match self {
             Self::Internal { page_id, parent_page_id, keys, children, right_link, high_key } => {
@@ -322,11 +342,7 @@ impl BlinkTreeNode {
                 }
 
                 // Write keys
-                buffer.write_all(&(keys.len() as u32).to_le_bytes())?;
-                for key in keys {
-                    buffer.write_all(&(key.len() as u32).to_le_bytes())?;
-                    buffer.write_all(key)?;
-                }
+                write_keys_section(&mut buffer, keys)?;
 
                 // Write children
                 buffer.write_all(&(children.len() as u32).to_le_bytes())?;
@@ -361,11 +377,7 @@ impl BlinkTreeNode {
                 }
 
                 // Write keys
-                buffer.write_all(&(keys.len() as u32).to_le_bytes())?;
-                for key in keys {
-                    buffer.write_all(&(key.len() as u32).to_le_bytes())?;
-                    buffer.write_all(key)?;
-                }
+                write_keys_section(&mut buffer, keys)?;
 
                 // Write values
                 buffer.write_all(&(values.len() as u32).to_le_bytes())?;
@@ -379,15 +391,40 @@ impl BlinkTreeNode {
             }
         }
 
+        // Append a trailing CRC32 checksum over the payload, the way redb guards every
+        // leaf/branch page, so `from_bytes` can detect a torn write or bit-rot before
+        // trusting any of the decoded fields.
+        let checksum = crc32::checksum(&buffer);
+        buffer.write_all(&checksum.to_le_bytes())?;
+
         Ok(buffer)
     }
 
     /// Deserialize a node from bytes
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, SerializationError> {
-        let mut cursor = Cursor::new(bytes);
+        if bytes.len() < 4 {
+            return Err(SerializationError::InvalidFormat(
+                "buffer too short to contain a checksum".to_string(),
+            ));
+        }
+        let (payload, checksum_bytes) = bytes.split_at(bytes.len() - 4);
+        let found = crc32::checksum(payload);
+        let expected = u32::from_le_bytes(checksum_bytes.try_into().unwrap());
+        if found != expected {
+            return Err(SerializationError::ChecksumMismatch { expected, found });
+        }
+
+        let mut cursor = Cursor::new(payload);
 
-        // Read node type
-        let node_type = read_u8(&mut cursor)?;
+        // Read node type, detecting whether this record uses the front-coding-capable
+        // layout (marked by a leading `FORMAT_VERSION_FRONT_CODED_KEYS` byte) or the legacy
+        // layout, which starts directly with the node-type byte.
+        let first_byte = read_u8(&mut cursor)?;
+        let (front_coded, node_type) = if first_byte == FORMAT_VERSION_FRONT_CODED_KEYS {
+            (true, read_u8(&mut cursor)?)
+        } else {
+            (false, first_byte)
+        };
 
         // Read page_id
         let page_id = read_u64(&mut cursor)?;
@@ -413,12 +450,11 @@ impl BlinkTreeNode {
             0 => {
                 // Internal node
                 // Read keys
-                let keys_count = read_u32(&mut cursor)?;
-                let mut keys = Vec::with_capacity(keys_count as usize);
-                for _ in 0..keys_count {
-                    let key_len = read_u32(&mut cursor)?;
-                    keys.push(read_vec_u8(&mut cursor, key_len as usize)?);
-                }
+                let keys = if front_coded {
+                    read_keys_section(&mut cursor)?
+                } else {
+                    read_plain_keys(&mut cursor)?
+                };
 
                 // Read children
                 let children_count = read_u32(&mut cursor)?;
@@ -432,12 +468,11 @@ impl BlinkTreeNode {
             1 => {
                 // Leaf node
                 // Read keys
-                let keys_count = read_u32(&mut cursor)?;
-                let mut keys = Vec::with_capacity(keys_count as usize);
-                for _ in 0..keys_count {
-                    let key_len = read_u32(&mut cursor)?;
-                    keys.push(read_vec_u8(&mut cursor, key_len as usize)?);
-                }
+                let keys = if front_coded {
+                    read_keys_section(&mut cursor)?
+                } else {
+                    read_plain_keys(&mut cursor)?
+                };
 
                 // Read values
                 let values_count = read_u32(&mut cursor)?;
@@ -465,6 +500,137 @@ pub enum InsertValue {
     PrimaryKeys(Vec<PrimaryKey>),
 }
 
+/// Writes `keys` (already known to be in ascending order) as either the plain or
+/// front-coded layout, picking whichever actually produces fewer bytes. Both layouts are
+/// preceded by the key count and an encoding byte so `read_keys_section` can tell them apart.
+fn write_keys_section(buffer: &mut Vec<u8>, keys: &[KeyType]) -> Result<(), SerializationError> {
+    let mut front_coded_body = Vec::new();
+    write_front_coded_keys(&mut front_coded_body, keys)?;
+
+    let plain_body_len: usize = keys.iter().map(|key| 4 + key.len()).sum();
+
+    buffer.write_all(&(keys.len() as u32).to_le_bytes())?;
+    if front_coded_body.len() < plain_body_len {
+        buffer.write_all(&[KEY_ENCODING_FRONT_CODED])?;
+        buffer.write_all(&front_coded_body)?;
+    } else {
+        buffer.write_all(&[KEY_ENCODING_PLAIN])?;
+        for key in keys {
+            buffer.write_all(&(key.len() as u32).to_le_bytes())?;
+            buffer.write_all(key)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Front-codes `keys` into `buffer`: the first key in full, then each later key as
+/// `shared_prefix_len` (varint) followed by its suffix length and bytes.
+fn write_front_coded_keys(buffer: &mut Vec<u8>, keys: &[KeyType]) -> Result<(), SerializationError> {
+    let mut previous: Option<&KeyType> = None;
+    for key in keys {
+        match previous {
+            None => {
+                buffer.write_all(&(key.len() as u32).to_le_bytes())?;
+                buffer.write_all(key)?;
+            }
+            Some(prev) => {
+                let shared = shared_prefix_len(prev, key);
+                write_varint_u32(buffer, shared as u32)?;
+                let suffix = &key[shared..];
+                buffer.write_all(&(suffix.len() as u32).to_le_bytes())?;
+                buffer.write_all(suffix)?;
+            }
+        }
+        previous = Some(key);
+    }
+    Ok(())
+}
+
+fn shared_prefix_len(a: &[u8], b: &[u8]) -> usize {
+    a.iter().zip(b.iter()).take_while(|(x, y)| x == y).count()
+}
+
+/// Reads a keys section written by `write_keys_section`: the key count, an encoding byte,
+/// then the per-key data in whichever layout that byte names.
+fn read_keys_section(cursor: &mut Cursor<&[u8]>) -> Result<Vec<KeyType>, SerializationError> {
+    let count = read_u32(cursor)?;
+    let encoding = read_u8(cursor)?;
+    match encoding {
+        KEY_ENCODING_PLAIN => read_n_plain_keys(cursor, count),
+        KEY_ENCODING_FRONT_CODED => read_front_coded_keys(cursor, count),
+        other => {
+            Err(SerializationError::InvalidFormat(format!("unknown key encoding byte {other}")))
+        }
+    }
+}
+
+/// Reads the legacy (pre-front-coding) keys layout: a key count followed by
+/// `[len: u32][bytes]` per key, with no encoding byte.
+fn read_plain_keys(cursor: &mut Cursor<&[u8]>) -> Result<Vec<KeyType>, SerializationError> {
+    let count = read_u32(cursor)?;
+    read_n_plain_keys(cursor, count)
+}
+
+fn read_n_plain_keys(cursor: &mut Cursor<&[u8]>, count: u32) -> Result<Vec<KeyType>, SerializationError> {
+    let mut keys = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let key_len = read_u32(cursor)?;
+        keys.push(read_vec_u8(cursor, key_len as usize)?);
+    }
+    Ok(keys)
+}
+
+fn read_front_coded_keys(
+    cursor: &mut Cursor<&[u8]>,
+    count: u32,
+) -> Result<Vec<KeyType>, SerializationError> {
+    let mut keys: Vec<KeyType> = Vec::with_capacity(count as usize);
+    for i in 0..count {
+        if i == 0 {
+            let len = read_u32(cursor)?;
+            keys.push(read_vec_u8(cursor, len as usize)?);
+        } else {
+            let shared = read_varint_u32(cursor)? as usize;
+            let suffix_len = read_u32(cursor)?;
+            let suffix = read_vec_u8(cursor, suffix_len as usize)?;
+            let mut key = keys[i as usize - 1][..shared].to_vec();
+            key.extend_from_slice(&suffix);
+            keys.push(key);
+        }
+    }
+    Ok(keys)
+}
+
+/// Writes `value` as an unsigned LEB128 varint.
+fn write_varint_u32(buffer: &mut Vec<u8>, mut value: u32) -> Result<(), SerializationError> {
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value == 0 {
+            buffer.write_all(&[byte])?;
+            break;
+        }
+        buffer.write_all(&[byte | 0x80])?;
+    }
+    Ok(())
+}
+
+/// Reads an unsigned LEB128 varint written by `write_varint_u32`.
+fn read_varint_u32(cursor: &mut Cursor<&[u8]>) -> Result<u32, SerializationError> {
+    let mut result: u32 = 0;
+    let mut shift = 0;
+    loop {
+        let byte = read_u8(cursor)?;
+        result |= u32::from(byte & 0x7F) << shift;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+    }
+    Ok(result)
+}
+
 // Helper functions for reading from cursor
 fn read_u8(cursor: &mut Cursor<&[u8]>) -> Result<u8, SerializationError> {
     let mut buf = [0u8; 1];
@@ -585,4 +751,112 @@ mod tests {
         node.set_high_key(None);
         assert!(node.is_safe_for_key(&k("zebra"))); // Should be safe now
     }
+
+    #[test]
+    fn test_from_bytes_detects_corrupted_payload() {
+        let original = BlinkTreeNode::Leaf {
+            page_id: 1,
+            parent_page_id: None,
+            keys: vec![k("dog")],
+            values: vec![vec![pk("pk1")]],
+            right_link: None,
+            high_key: Some(k("dog")),
+        };
+
+        let mut serialized = original.to_bytes().unwrap();
+        // Flip a bit in the payload (well before the trailing checksum) to simulate a torn
+        // write or bit-rot.
+        serialized[1] ^= 0xFF;
+
+        match BlinkTreeNode::from_bytes(&serialized) {
+            Err(SerializationError::ChecksumMismatch { .. }) => {}
+            other => panic!("expected ChecksumMismatch, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_truncated_buffer() {
+        match BlinkTreeNode::from_bytes(&[0u8; 2]) {
+            Err(SerializationError::InvalidFormat(_)) => {}
+            other => panic!("expected InvalidFormat, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_write_keys_section_picks_front_coding_when_it_shrinks_payload() {
+        let keys = vec![k("prefix_aardvark"), k("prefix_antelope"), k("prefix_antler")];
+
+        let mut front_coded = Vec::new();
+        write_keys_section(&mut front_coded, &keys).unwrap();
+
+        let plain_body_len: usize = keys.iter().map(|key| 4 + key.len()).sum();
+        // 4 (count) + 1 (encoding byte) + the plain per-key bytes is what the fallback
+        // layout would have cost; front-coding the shared "prefix_ant" prefix should beat it.
+        assert!(front_coded.len() < 4 + 1 + plain_body_len);
+
+        let mut cursor = Cursor::new(front_coded.as_slice());
+        let decoded = read_keys_section(&mut cursor).unwrap();
+        assert_eq!(decoded, keys);
+    }
+
+    #[test]
+    fn test_front_coded_keys_round_trip_with_shared_prefixes() {
+        let original = BlinkTreeNode::Leaf {
+            page_id: 1,
+            parent_page_id: None,
+            keys: vec![k("prefix_aardvark"), k("prefix_antelope"), k("prefix_antler")],
+            values: vec![vec![pk("pk1")], vec![pk("pk2")], vec![pk("pk3")]],
+            right_link: None,
+            high_key: Some(k("prefix_antler")),
+        };
+
+        let serialized = original.to_bytes().unwrap();
+        let deserialized = BlinkTreeNode::from_bytes(&serialized).unwrap();
+        assert_eq!(original.get_keys(), deserialized.get_keys());
+        assert_eq!(original.get_high_key(), deserialized.get_high_key());
+    }
+
+    #[test]
+    fn test_keys_with_no_shared_prefix_still_round_trip() {
+        let original = BlinkTreeNode::Leaf {
+            page_id: 1,
+            parent_page_id: None,
+            keys: vec![k("zebra"), k("mango"), k("kite")],
+            values: vec![vec![pk("pk1")], vec![pk("pk2")], vec![pk("pk3")]],
+            right_link: None,
+            high_key: None,
+        };
+
+        let serialized = original.to_bytes().unwrap();
+        let deserialized = BlinkTreeNode::from_bytes(&serialized).unwrap();
+        assert_eq!(original.get_keys(), deserialized.get_keys());
+    }
+
+    #[test]
+    fn test_from_bytes_decodes_legacy_unversioned_layout() {
+        // Hand-assembles a pre-front-coding payload: no leading format-version byte, node
+        // type directly first, keys written plain with no encoding byte.
+        let mut payload = Vec::new();
+        payload.push(1u8); // node type: Leaf
+        payload.extend_from_slice(&1u64.to_le_bytes()); // page_id
+        payload.push(0); // no parent
+        payload.push(0); // no right_link
+        payload.push(0); // no high_key
+        payload.extend_from_slice(&1u32.to_le_bytes()); // key count
+        let key = k("legacy");
+        payload.extend_from_slice(&(key.len() as u32).to_le_bytes());
+        payload.extend_from_slice(&key);
+        payload.extend_from_slice(&1u32.to_le_bytes()); // value count
+        let value = pk("pk1");
+        payload.extend_from_slice(&1u32.to_le_bytes());
+        payload.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        payload.extend_from_slice(&value);
+
+        let checksum = crc32::checksum(&payload);
+        payload.extend_from_slice(&checksum.to_le_bytes());
+
+        let decoded = BlinkTreeNode::from_bytes(&payload).unwrap();
+        assert_eq!(decoded.get_keys(), &vec![k("legacy")]);
+        assert!(decoded.is_leaf());
+    }
 }
@@ -0,0 +1,191 @@
+use super::BlinkTreeIndex;
+use crate::core::indexing::blink_tree::error::BlinkTreeError;
+use crate::core::indexing::blink_tree::node::{BlinkTreeNode, KeyType, PrimaryKey};
+
+/// Returned by [`BlinkTreeIndex::compare_and_swap`] when `expected` didn't match the
+/// key's actual current value, carrying that value so the caller can retry its
+/// decision without a separate read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CasFailure {
+    pub current: Option<Vec<PrimaryKey>>,
+}
+
+impl BlinkTreeIndex {
+    /// Atomically replace the value list at `key`, but only if it currently equals
+    /// `expected` (`None` meaning the key is absent). `new = None` deletes the key.
+    ///
+    /// This is the lock-free compare-and-swap primitive callers can build optimistic
+    /// concurrency control on top of: read nothing up front, propose the write you
+    /// want, and retry with the returned current value if someone else got there
+    /// first, instead of taking a lock across a read-then-write.
+    pub fn compare_and_swap(
+        &mut self,
+        key: KeyType,
+        expected: Option<&[PrimaryKey]>,
+        new: Option<Vec<PrimaryKey>>,
+    ) -> Result<Result<(), CasFailure>, BlinkTreeError> {
+        let current = self.find_primary_keys(&key)?;
+
+        if current.as_deref() != expected {
+            return Ok(Err(CasFailure { current }));
+        }
+
+        match new {
+            None => {
+                if current.is_some() {
+                    self.delete(&key, None)?;
+                }
+            }
+            Some(new_values) => match current {
+                Some(_) => {
+                    let leaf_page_id = self.find_leaf_containing_key(&key)?;
+                    self.replace_values_in_leaf(leaf_page_id, &key, new_values)?;
+                }
+                None => {
+                    for value in new_values {
+                        self.insert(key.clone(), value)?;
+                    }
+                }
+            },
+        }
+
+        Ok(Ok(()))
+    }
+
+    /// Find the leaf node that should contain a given key (ignoring right-link
+    /// safety - callers that need it re-check via [`BlinkTreeNode::is_safe_for_key`]
+    /// once they have the node in hand).
+    fn find_leaf_containing_key(&self, key: &KeyType) -> Result<super::PageId, BlinkTreeError> {
+        let mut current_page_id = self.root_page_id;
+
+        loop {
+            let current_node = self.read_node(current_page_id)?;
+
+            if current_node.is_leaf() {
+                return Ok(current_page_id);
+            }
+            current_page_id = self.find_next_page_in_internal(&current_node, key)?;
+        }
+    }
+
+    /// Overwrite the value list for an existing key in place, following `right_link`
+    /// if a concurrent split has moved the key to a sibling since it was looked up.
+    fn replace_values_in_leaf(
+        &mut self,
+        leaf_page_id: super::PageId,
+        key: &KeyType,
+        new_values: Vec<PrimaryKey>,
+    ) -> Result<(), BlinkTreeError> {
+        let mut leaf_node = self.read_node(leaf_page_id)?;
+
+        if !leaf_node.is_safe_for_key(key) {
+            return match leaf_node.get_right_link() {
+                Some(right_page_id) => {
+                    self.replace_values_in_leaf(right_page_id, key, new_values)
+                }
+                None => Err(BlinkTreeError::TreeLogicError(
+                    "compare_and_swap: key disappeared from the tree while replacing its value"
+                        .to_string(),
+                )),
+            };
+        }
+
+        let replaced = match &mut leaf_node {
+            BlinkTreeNode::Leaf { keys, values, .. } => keys
+                .iter()
+                .position(|existing_key| existing_key == key)
+                .map(|i| values[i] = new_values),
+            BlinkTreeNode::Internal { .. } => return Err(BlinkTreeError::UnexpectedNodeType),
+        };
+
+        if replaced.is_none() {
+            return Err(BlinkTreeError::TreeLogicError(
+                "compare_and_swap: key disappeared from the tree while replacing its value"
+                    .to_string(),
+            ));
+        }
+
+        self.write_node(&leaf_node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn k(s: &str) -> KeyType {
+        s.as_bytes().to_vec()
+    }
+
+    fn pk(s: &str) -> PrimaryKey {
+        s.as_bytes().to_vec()
+    }
+
+    fn setup_tree(test_name: &str) -> (BlinkTreeIndex, TempDir) {
+        let temp_dir = TempDir::new().unwrap();
+        let tree_path = temp_dir.path().join(format!("{}.blink", test_name));
+        let tree = BlinkTreeIndex::new("test_blink".to_string(), tree_path, 5).unwrap();
+        (tree, temp_dir)
+    }
+
+    #[test]
+    fn test_cas_inserts_into_absent_key() {
+        let (mut tree, _temp_dir) = setup_tree("test_cas_insert");
+
+        let result = tree.compare_and_swap(k("apple"), None, Some(vec![pk("pk1")])).unwrap();
+        assert!(result.is_ok());
+        assert_eq!(tree.find_primary_keys(&k("apple")).unwrap(), Some(vec![pk("pk1")]));
+    }
+
+    #[test]
+    fn test_cas_fails_with_current_value_when_expected_does_not_match() {
+        let (mut tree, _temp_dir) = setup_tree("test_cas_mismatch");
+
+        tree.insert(k("apple"), pk("pk1")).unwrap();
+
+        let result = tree
+            .compare_and_swap(k("apple"), Some(&[pk("wrong")]), Some(vec![pk("pk2")]))
+            .unwrap();
+        assert_eq!(result, Err(CasFailure { current: Some(vec![pk("pk1")]) }));
+
+        // The mismatched write must not have applied.
+        assert_eq!(tree.find_primary_keys(&k("apple")).unwrap(), Some(vec![pk("pk1")]));
+    }
+
+    #[test]
+    fn test_cas_replaces_matching_value() {
+        let (mut tree, _temp_dir) = setup_tree("test_cas_replace");
+
+        tree.insert(k("apple"), pk("pk1")).unwrap();
+
+        let result = tree
+            .compare_and_swap(k("apple"), Some(&[pk("pk1")]), Some(vec![pk("pk2"), pk("pk3")]))
+            .unwrap();
+        assert!(result.is_ok());
+        assert_eq!(
+            tree.find_primary_keys(&k("apple")).unwrap(),
+            Some(vec![pk("pk2"), pk("pk3")])
+        );
+    }
+
+    #[test]
+    fn test_cas_deletes_on_new_none() {
+        let (mut tree, _temp_dir) = setup_tree("test_cas_delete");
+
+        tree.insert(k("apple"), pk("pk1")).unwrap();
+
+        let result = tree.compare_and_swap(k("apple"), Some(&[pk("pk1")]), None).unwrap();
+        assert!(result.is_ok());
+        assert_eq!(tree.find_primary_keys(&k("apple")).unwrap(), None);
+    }
+
+    #[test]
+    fn test_cas_no_op_when_absent_key_expected_absent_and_deleted() {
+        let (mut tree, _temp_dir) = setup_tree("test_cas_absent_noop");
+
+        let result = tree.compare_and_swap(k("apple"), None, None).unwrap();
+        assert!(result.is_ok());
+        assert_eq!(tree.find_primary_keys(&k("apple")).unwrap(), None);
+    }
+}
@@ -4,11 +4,16 @@ use crate::core::indexing::blink_tree::error::BlinkTreeError;
 use crate::core::indexing::blink_tree::node::{BlinkTreeNode, KeyType, PageId, PrimaryKey};
 use crate::core::indexing::blink_tree::page_io::{BlinkPageManager, SENTINEL_PAGE_ID};
 
+mod cas;
 mod delete;
 mod insert;
 mod search;
+mod verify;
 
+pub use cas::CasFailure;
 pub use delete::*;
+pub use search::BlinkTreeRangeScan;
+pub use verify::{KeyRange, StructureReport, Violation};
 
 /// Blink Tree Index implementation with concurrent access support
 ///
@@ -193,6 +198,26 @@ impl BlinkTreeIndex {
     pub fn get_root_page_id(&self) -> PageId {
         self.root_page_id
     }
+
+    /// Verifies the tree's structural invariants: every node's keys fall within the range
+    /// implied by its ancestors' separator keys, and every `right_link` sibling chain is
+    /// acyclic and stays ordered.
+    ///
+    /// # Errors
+    /// Returns a `BlinkTreeError::TreeLogicError` describing the first violation found.
+    pub fn check(&self) -> Result<(), BlinkTreeError> {
+        verify::check(self.root_page_id, |page_id| self.read_node(page_id))
+    }
+
+    /// Like [`Self::check`], but never stops at the first violation: it walks every page
+    /// reachable from the root and returns a full [`StructureReport`], so a corrupted index
+    /// can be diagnosed in one pass. A page that fails to read (including a checksum
+    /// mismatch) or that's reachable as a child of two different parents is recorded as a
+    /// violation rather than aborting the walk.
+    #[must_use]
+    pub fn audit(&self) -> StructureReport {
+        verify::audit(self.root_page_id, |page_id| self.read_node(page_id))
+    }
 }
 
 #[cfg(test)]
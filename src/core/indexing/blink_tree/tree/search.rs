@@ -1,7 +1,33 @@
+use std::ops::{Bound, RangeBounds};
+
 use super::BlinkTreeIndex;
 use crate::core::indexing::blink_tree::error::BlinkTreeError;
 use crate::core::indexing::blink_tree::node::{BlinkTreeNode, KeyType, PrimaryKey};
 
+fn clone_bound(bound: Bound<&KeyType>) -> Bound<KeyType> {
+    match bound {
+        Bound::Included(key) => Bound::Included(key.clone()),
+        Bound::Excluded(key) => Bound::Excluded(key.clone()),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+fn satisfies_lower(bound: &Bound<KeyType>, key: &KeyType) -> bool {
+    match bound {
+        Bound::Included(start) => key >= start,
+        Bound::Excluded(start) => key > start,
+        Bound::Unbounded => true,
+    }
+}
+
+fn satisfies_upper(bound: &Bound<KeyType>, key: &KeyType) -> bool {
+    match bound {
+        Bound::Included(end) => key <= end,
+        Bound::Excluded(end) => key < end,
+        Bound::Unbounded => true,
+    }
+}
+
 impl BlinkTreeIndex {
     /// Range scan operation - find all keys between start and end (inclusive)
     /// This showcases the power of Blink tree's concurrent traversal
@@ -54,6 +80,90 @@ impl BlinkTreeIndex {
         Ok(results)
     }
 
+    /// Lazily scan all entries whose key falls within `range`, walking leaves
+    /// left-to-right via `right_link` one page at a time instead of collecting
+    /// everything up front like [`Self::range_scan`] does.
+    ///
+    /// Unlike `range_scan`'s heuristic peek at the right sibling's first key,
+    /// each step re-checks [`BlinkTreeNode::is_safe_for_key`] against the
+    /// node's `high_key` before trusting its contents - the same check point
+    /// lookups already use in `search_leaf_node`. That means a split racing
+    /// with this scan on another thread just costs an extra hop across
+    /// `right_link`, rather than the scan returning a gap or a stale leaf.
+    pub fn scan(&self, range: impl RangeBounds<KeyType>) -> BlinkTreeRangeScan<'_> {
+        let start_bound = clone_bound(range.start_bound());
+        let end_bound = clone_bound(range.end_bound());
+
+        if self.root_page_id == super::SENTINEL_PAGE_ID {
+            return BlinkTreeRangeScan {
+                tree: self,
+                start_bound,
+                end_bound,
+                next_page_id: None,
+                position_key: KeyType::new(),
+                buffered: Vec::new().into_iter(),
+                done: true,
+                pending_error: None,
+            };
+        }
+
+        // An empty key sorts below every real key, so it doubles as the
+        // "no lower bound" anchor for descent and for the `is_safe_for_key`
+        // checks `BlinkTreeRangeScan::next` performs on each subsequent leaf.
+        let lower_anchor = match &start_bound {
+            Bound::Included(key) | Bound::Excluded(key) => key.clone(),
+            Bound::Unbounded => KeyType::new(),
+        };
+
+        match self.find_leftmost_leaf_for_key(&lower_anchor) {
+            Ok(page_id) => BlinkTreeRangeScan {
+                tree: self,
+                start_bound,
+                end_bound,
+                next_page_id: Some(page_id),
+                position_key: lower_anchor,
+                buffered: Vec::new().into_iter(),
+                done: false,
+                pending_error: None,
+            },
+            Err(err) => BlinkTreeRangeScan {
+                tree: self,
+                start_bound,
+                end_bound,
+                next_page_id: None,
+                position_key: KeyType::new(),
+                buffered: Vec::new().into_iter(),
+                done: true,
+                pending_error: Some(err),
+            },
+        }
+    }
+
+    /// Follow `right_link` from `page_id` until landing on a leaf that is
+    /// safe for `target_key` (i.e. its `high_key` hasn't been exceeded by a
+    /// concurrent split), mirroring `search_leaf_node`'s safety check.
+    fn find_safe_leaf(
+        &self,
+        mut page_id: super::PageId,
+        target_key: &KeyType,
+    ) -> Result<BlinkTreeNode, BlinkTreeError> {
+        loop {
+            let node = self.read_node(page_id)?;
+            match &node {
+                BlinkTreeNode::Leaf { right_link, .. } => {
+                    if node.is_safe_for_key(target_key) {
+                        return Ok(node);
+                    }
+                    match right_link {
+                        Some(right_page_id) => page_id = *right_page_id,
+                        None => return Ok(node),
+                    }
+                }
+                BlinkTreeNode::Internal { .. } => return Err(BlinkTreeError::UnexpectedNodeType),
+            }
+        }
+    }
+
     /// Find the leftmost leaf that might contain the given key
     fn find_leftmost_leaf_for_key(&self, key: &KeyType) -> Result<super::PageId, BlinkTreeError> {
         let mut current_page_id = self.root_page_id;
@@ -230,6 +340,77 @@ impl BlinkTreeIndex {
     }
 }
 
+/// Lazy iterator returned by [`BlinkTreeIndex::scan`]. Buffers at most one
+/// leaf's worth of entries at a time, re-validating safety against each
+/// leaf's `high_key` as it follows `right_link` to the next page.
+pub struct BlinkTreeRangeScan<'a> {
+    tree: &'a BlinkTreeIndex,
+    start_bound: Bound<KeyType>,
+    end_bound: Bound<KeyType>,
+    next_page_id: Option<super::PageId>,
+    position_key: KeyType,
+    buffered: std::vec::IntoIter<(KeyType, Vec<PrimaryKey>)>,
+    done: bool,
+    pending_error: Option<BlinkTreeError>,
+}
+
+impl Iterator for BlinkTreeRangeScan<'_> {
+    type Item = Result<(KeyType, Vec<PrimaryKey>), BlinkTreeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(err) = self.pending_error.take() {
+            self.done = true;
+            return Some(Err(err));
+        }
+
+        loop {
+            if let Some((key, values)) = self.buffered.next() {
+                if !satisfies_lower(&self.start_bound, &key) {
+                    continue;
+                }
+                if !satisfies_upper(&self.end_bound, &key) {
+                    self.done = true;
+                    self.next_page_id = None;
+                    return None;
+                }
+                self.position_key = key.clone();
+                return Some(Ok((key, values)));
+            }
+
+            if self.done {
+                return None;
+            }
+
+            let Some(page_id) = self.next_page_id else {
+                self.done = true;
+                return None;
+            };
+
+            let node = match self.tree.find_safe_leaf(page_id, &self.position_key) {
+                Ok(node) => node,
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+            };
+
+            match node {
+                BlinkTreeNode::Leaf { keys, values, right_link, high_key, .. } => {
+                    if let Some(hkey) = high_key {
+                        self.position_key = hkey;
+                    }
+                    self.next_page_id = right_link;
+                    self.buffered = keys.into_iter().zip(values).collect::<Vec<_>>().into_iter();
+                }
+                BlinkTreeNode::Internal { .. } => {
+                    self.done = true;
+                    return Some(Err(BlinkTreeError::UnexpectedNodeType));
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -239,6 +420,10 @@ mod tests {
         s.as_bytes().to_vec()
     }
 
+    fn pk(s: &str) -> PrimaryKey {
+        s.as_bytes().to_vec()
+    }
+
     fn setup_tree(test_name: &str) -> (BlinkTreeIndex, TempDir) {
         let temp_dir = TempDir::new().unwrap();
         let tree_path = temp_dir.path().join(format!("{}.blink", test_name));
@@ -274,4 +459,60 @@ mod tests {
         assert!(root_node.is_leaf());
         assert_eq!(root_node.get_parent_page_id(), None);
     }
+
+    #[test]
+    fn test_scan_empty_tree() {
+        let (tree, _temp_dir) = setup_tree("test_scan_empty");
+
+        let results: Vec<_> = tree.scan(k("a")..=k("z")).collect::<Result<_, _>>().unwrap();
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_scan_matches_range_scan_across_multiple_leaves() {
+        let (mut tree, _temp_dir) = setup_tree("test_scan_multi_leaf");
+
+        // 20 keys with order 5 forces several splits, so this exercises the
+        // right_link walk across more than one leaf.
+        for i in 0..20 {
+            tree.insert(k(&format!("key{i:02}")), pk(&format!("pk{i:02}"))).unwrap();
+        }
+        assert!(tree.verify_structure().is_ok());
+
+        let expected = tree.range_scan(&k("key05"), &k("key14")).unwrap();
+        let scanned: Vec<_> =
+            tree.scan(k("key05")..=k("key14")).collect::<Result<_, _>>().unwrap();
+        assert_eq!(scanned, expected);
+        assert_eq!(scanned.len(), 10);
+    }
+
+    #[test]
+    fn test_scan_respects_exclusive_bounds() {
+        let (mut tree, _temp_dir) = setup_tree("test_scan_exclusive");
+
+        for i in 0..20 {
+            tree.insert(k(&format!("key{i:02}")), pk(&format!("pk{i:02}"))).unwrap();
+        }
+
+        let scanned: Vec<_> = tree
+            .scan((Bound::Excluded(k("key05")), Bound::Excluded(k("key08"))))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let keys: Vec<KeyType> = scanned.into_iter().map(|(key, _)| key).collect();
+        assert_eq!(keys, vec![k("key06"), k("key07")]);
+    }
+
+    #[test]
+    fn test_scan_unbounded_start_and_end() {
+        let (mut tree, _temp_dir) = setup_tree("test_scan_unbounded");
+
+        for i in 0..20 {
+            tree.insert(k(&format!("key{i:02}")), pk(&format!("pk{i:02}"))).unwrap();
+        }
+
+        let scanned: Vec<_> = tree.scan(..).collect::<Result<_, _>>().unwrap();
+        assert_eq!(scanned.len(), 20);
+        assert_eq!(scanned.first().map(|(key, _)| key.clone()), Some(k("key00")));
+        assert_eq!(scanned.last().map(|(key, _)| key.clone()), Some(k("key19")));
+    }
 }
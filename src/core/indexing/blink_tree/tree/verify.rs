@@ -0,0 +1,552 @@
+//! Consistency checker for the Blink tree.
+//!
+//! The tree encodes structural invariants (sorted keys, `right_link` sibling chains,
+//! `high_key` upper bounds) but never enforces them after construction. [`check`] walks the
+//! tree from the root, tracking each subtree's expected key range the way
+//! thin-provisioning-tools' `KeyRange` does: the root covers `(None, None)` (unbounded), and
+//! each internal node splits its own range at its separator keys into `(lo, keys[0])`,
+//! `(keys[0], keys[1])`, ..., `(keys[n-1], hi)` for its children. [`BlinkTreeNode::verify_siblings`]
+//! separately walks a node's `right_link` chain to check it's acyclic and keys stay ordered
+//! across siblings. [`check`] stops at the first problem it finds; [`audit`] runs the same
+//! checks but keeps going, accumulating everything it finds (including a page that's a child
+//! of two different parents, and pages that fail to read at all) into a [`StructureReport`]
+//! so a corrupted tree can be fully diagnosed in one pass. [`BlinkTreeIndex::repair_into`]
+//! offers a best-effort way back from such a report: it rebuilds a fresh tree purely from the
+//! leaf-level `right_link` chain, which survives internal-node corruption that would confuse
+//! a top-down walk.
+
+use std::collections::HashMap;
+
+use crate::core::indexing::blink_tree::error::BlinkTreeError;
+use crate::core::indexing::blink_tree::node::{BlinkTreeNode, KeyType, PageId};
+
+use super::BlinkTreeIndex;
+
+/// The key range a subtree is responsible for. `None` on either side means unbounded in
+/// that direction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyRange {
+    pub start: Option<KeyType>,
+    pub end: Option<KeyType>,
+}
+
+impl KeyRange {
+    /// The unbounded range the root subtree covers.
+    #[must_use]
+    pub const fn root() -> Self {
+        Self { start: None, end: None }
+    }
+
+    /// Whether `key` falls within `[start, end]` (inclusive on both sides, matching
+    /// [`BlinkTreeNode::is_safe_for_key`]'s treatment of `high_key`).
+    #[must_use]
+    pub fn contains(&self, key: &KeyType) -> bool {
+        let after_start = self.start.as_ref().is_none_or(|start| key >= start);
+        let before_end = self.end.as_ref().is_none_or(|end| key <= end);
+        after_start && before_end
+    }
+
+    /// The range of the child to the left of `separator`: `(self.start, separator)`.
+    #[must_use]
+    pub fn left_of(&self, separator: &KeyType) -> Self {
+        Self { start: self.start.clone(), end: Some(separator.clone()) }
+    }
+
+    /// The range of the child to the right of `separator`: `(separator, self.end)`.
+    #[must_use]
+    pub fn right_of(&self, separator: &KeyType) -> Self {
+        Self { start: Some(separator.clone()), end: self.end.clone() }
+    }
+}
+
+impl BlinkTreeNode {
+    /// Verifies that this node's own keys are strictly increasing, fall within `range`, and
+    /// (if this node has a `high_key`) that it doesn't exceed `range.end`.
+    ///
+    /// # Errors
+    /// Returns a `BlinkTreeError::TreeLogicError` describing the first violation found.
+    pub fn verify_key_range(&self, range: &KeyRange) -> Result<(), BlinkTreeError> {
+        let keys = self.get_keys();
+
+        for pair in keys.windows(2) {
+            if pair[0] >= pair[1] {
+                return Err(BlinkTreeError::TreeLogicError(format!(
+                    "node {} has non-increasing keys: {:?} >= {:?}",
+                    self.get_page_id(),
+                    pair[0],
+                    pair[1]
+                )));
+            }
+        }
+
+        for key in keys {
+            if !range.contains(key) {
+                return Err(BlinkTreeError::TreeLogicError(format!(
+                    "node {} key {:?} falls outside its expected range {:?}",
+                    self.get_page_id(),
+                    key,
+                    range
+                )));
+            }
+        }
+
+        if let (Some(high_key), Some(end)) = (self.get_high_key(), range.end.as_ref()) {
+            if high_key > end {
+                return Err(BlinkTreeError::TreeLogicError(format!(
+                    "node {} high_key {:?} exceeds inherited range end {:?}",
+                    self.get_page_id(),
+                    high_key,
+                    end
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Walks this node's `right_link` chain, verifying it terminates within `max_hops`
+    /// (guarding against a cycle) and that each sibling's first key stays at or beyond the
+    /// previous node's `high_key`.
+    ///
+    /// # Errors
+    /// Returns a `BlinkTreeError::TreeLogicError` describing the first violation found, or
+    /// if the chain doesn't terminate within `max_hops`.
+    pub fn verify_siblings(
+        &self,
+        mut page_reader: impl FnMut(PageId) -> Result<Self, BlinkTreeError>,
+        max_hops: usize,
+    ) -> Result<(), BlinkTreeError> {
+        let mut previous_high_key = self.get_high_key().cloned();
+        let mut current_link = self.get_right_link();
+        let mut hops = 0;
+
+        while let Some(page_id) = current_link {
+            hops += 1;
+            if hops > max_hops {
+                return Err(BlinkTreeError::TreeLogicError(format!(
+                    "sibling chain from node {} did not terminate within {max_hops} hops (possible cycle)",
+                    self.get_page_id()
+                )));
+            }
+
+            let sibling = page_reader(page_id)?;
+            if let (Some(first_key), Some(prev_high)) =
+                (sibling.get_keys().first(), previous_high_key.as_ref())
+            {
+                if first_key < prev_high {
+                    return Err(BlinkTreeError::TreeLogicError(format!(
+                        "sibling node {} key {:?} precedes previous sibling's high_key {:?}",
+                        sibling.get_page_id(),
+                        first_key,
+                        prev_high
+                    )));
+                }
+            }
+
+            previous_high_key = sibling.get_high_key().cloned();
+            current_link = sibling.get_right_link();
+        }
+
+        Ok(())
+    }
+}
+
+/// Recursively verifies every subtree's key-range invariant and, at each node visited,
+/// that node's `right_link` sibling chain — starting from `root` and reading pages through
+/// `page_reader`.
+///
+/// # Errors
+/// Returns the first `BlinkTreeError` raised by `page_reader`, or a
+/// `BlinkTreeError::TreeLogicError` describing the first structural violation found.
+pub fn check(
+    root: PageId,
+    mut page_reader: impl FnMut(PageId) -> Result<BlinkTreeNode, BlinkTreeError>,
+) -> Result<(), BlinkTreeError> {
+    check_subtree(root, &KeyRange::root(), &mut page_reader, 0)
+}
+
+/// Bounds how many sibling hops [`BlinkTreeNode::verify_siblings`] will follow before
+/// declaring a cycle, independent of tree depth.
+const MAX_SIBLING_HOPS: usize = 10_000;
+
+fn check_subtree(
+    page_id: PageId,
+    range: &KeyRange,
+    page_reader: &mut impl FnMut(PageId) -> Result<BlinkTreeNode, BlinkTreeError>,
+    depth: usize,
+) -> Result<(), BlinkTreeError> {
+    let node = page_reader(page_id)?;
+    node.verify_key_range(range)?;
+    node.verify_siblings(|id| page_reader(id), MAX_SIBLING_HOPS)?;
+
+    if let BlinkTreeNode::Internal { keys, children, .. } = &node {
+        if children.len() != keys.len() + 1 {
+            return Err(BlinkTreeError::TreeLogicError(format!(
+                "internal node {page_id} has {} children but {} keys (expected {} children)",
+                children.len(),
+                keys.len(),
+                keys.len() + 1
+            )));
+        }
+
+        for (i, &child_id) in children.iter().enumerate() {
+            let child_range = if i == 0 {
+                range.left_of(&keys[0])
+            } else if i == keys.len() {
+                range.right_of(&keys[i - 1])
+            } else {
+                KeyRange { start: Some(keys[i - 1].clone()), end: Some(keys[i].clone()) }
+            };
+            check_subtree(child_id, &child_range, page_reader, depth + 1)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// One structural problem found by [`audit`], naming the page it was found on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub page_id: PageId,
+    pub description: String,
+}
+
+/// The result of an [`audit`] walk: every violation found, without stopping at the first
+/// one, so a corrupted tree can be diagnosed in a single pass instead of fixed one error at
+/// a time.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StructureReport {
+    pub violations: Vec<Violation>,
+}
+
+impl StructureReport {
+    /// Whether the walk found no problems at all.
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// Like [`check`], but never stops at the first violation: it keeps walking the rest of the
+/// tree and collects everything it finds into a [`StructureReport`], including pages that
+/// fail to read at all (e.g. a `BlinkTreeError::ChecksumMismatch` from a torn page) and pages
+/// reachable as a child of more than one parent, which `check`'s single-pass recursion can't
+/// detect on its own.
+#[must_use]
+pub fn audit(
+    root: PageId,
+    mut page_reader: impl FnMut(PageId) -> Result<BlinkTreeNode, BlinkTreeError>,
+) -> StructureReport {
+    let mut report = StructureReport::default();
+    let mut claimed_by: HashMap<PageId, PageId> = HashMap::new();
+    audit_subtree(root, &KeyRange::root(), None, &mut page_reader, &mut claimed_by, &mut report);
+    report
+}
+
+fn audit_subtree(
+    page_id: PageId,
+    range: &KeyRange,
+    parent_id: Option<PageId>,
+    page_reader: &mut impl FnMut(PageId) -> Result<BlinkTreeNode, BlinkTreeError>,
+    claimed_by: &mut HashMap<PageId, PageId>,
+    report: &mut StructureReport,
+) {
+    if let Some(parent_id) = parent_id {
+        match claimed_by.get(&page_id) {
+            Some(&first_parent) if first_parent != parent_id => {
+                report.violations.push(Violation {
+                    page_id,
+                    description: format!(
+                        "page {page_id} is referenced as a child by both node {first_parent} and node {parent_id}"
+                    ),
+                });
+                return;
+            }
+            Some(_) => return, // already walked once under this same parent
+            None => {
+                claimed_by.insert(page_id, parent_id);
+            }
+        }
+    }
+
+    let node = match page_reader(page_id) {
+        Ok(node) => node,
+        Err(err) => {
+            report.violations.push(Violation {
+                page_id,
+                description: format!("page {page_id} could not be read: {err}"),
+            });
+            return;
+        }
+    };
+
+    if let Err(err) = node.verify_key_range(range) {
+        report.violations.push(Violation { page_id, description: err.to_string() });
+    }
+    if let Err(err) = node.verify_siblings(|id| page_reader(id), MAX_SIBLING_HOPS) {
+        report.violations.push(Violation { page_id, description: err.to_string() });
+    }
+
+    if let BlinkTreeNode::Internal { keys, children, .. } = &node {
+        if children.len() != keys.len() + 1 {
+            report.violations.push(Violation {
+                page_id,
+                description: format!(
+                    "internal node {page_id} has {} children but {} keys (expected {} children)",
+                    children.len(),
+                    keys.len(),
+                    keys.len() + 1
+                ),
+            });
+        }
+
+        for (i, &child_id) in children.iter().enumerate() {
+            let child_range = if keys.is_empty() {
+                range.clone()
+            } else if i == 0 {
+                range.left_of(&keys[0])
+            } else if i >= keys.len() {
+                range.right_of(&keys[keys.len() - 1])
+            } else {
+                KeyRange { start: Some(keys[i - 1].clone()), end: Some(keys[i].clone()) }
+            };
+            audit_subtree(child_id, &child_range, Some(page_id), page_reader, claimed_by, report);
+        }
+    }
+}
+
+impl BlinkTreeIndex {
+    /// Best-effort recovery for a tree [`BlinkTreeIndex::audit`] reported as unhealthy:
+    /// follows the leaf-level `right_link` chain left to right, starting from the leftmost
+    /// reachable leaf, and reinserts every key it finds into `target` (which should be a
+    /// fresh, empty tree). Stops as soon as a page along the chain fails to read, since
+    /// nothing past a broken link is known to still be reachable.
+    ///
+    /// # Errors
+    /// Returns a `BlinkTreeError` if writing to `target` fails.
+    pub fn repair_into(&self, target: &mut Self) -> Result<u64, BlinkTreeError> {
+        let mut reinserted = 0u64;
+
+        let Some(mut current_page_id) = self.leftmost_leaf() else {
+            return Ok(reinserted);
+        };
+
+        loop {
+            let Ok(node) = self.read_node(current_page_id) else {
+                break; // page is corrupt; nothing past it is known to be reachable
+            };
+
+            let BlinkTreeNode::Leaf { keys, values, right_link, .. } = node else {
+                break; // leftmost_leaf never points at an internal node
+            };
+
+            for (key, primary_keys) in keys.into_iter().zip(values) {
+                for primary_key in primary_keys {
+                    target.insert(key.clone(), primary_key)?;
+                    reinserted += 1;
+                }
+            }
+
+            match right_link {
+                Some(next_page_id) => current_page_id = next_page_id,
+                None => break,
+            }
+        }
+
+        Ok(reinserted)
+    }
+
+    /// The leftmost leaf reachable by always following an internal node's first child,
+    /// starting from the root. Returns `None` if the root itself can't be read.
+    fn leftmost_leaf(&self) -> Option<PageId> {
+        let mut current_page_id = self.root_page_id;
+        loop {
+            match self.read_node(current_page_id).ok()? {
+                BlinkTreeNode::Leaf { .. } => return Some(current_page_id),
+                BlinkTreeNode::Internal { children, .. } => {
+                    current_page_id = *children.first()?;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn k(s: &str) -> KeyType {
+        s.as_bytes().to_vec()
+    }
+
+    fn leaf(page_id: PageId, keys: Vec<KeyType>, right_link: Option<PageId>, high_key: Option<KeyType>) -> BlinkTreeNode {
+        let values = keys.iter().map(|_| vec![]).collect();
+        BlinkTreeNode::Leaf { page_id, parent_page_id: None, keys, values, right_link, high_key }
+    }
+
+    fn internal(
+        page_id: PageId,
+        keys: Vec<KeyType>,
+        children: Vec<PageId>,
+        right_link: Option<PageId>,
+        high_key: Option<KeyType>,
+    ) -> BlinkTreeNode {
+        BlinkTreeNode::Internal { page_id, parent_page_id: None, keys, children, right_link, high_key }
+    }
+
+    #[test]
+    fn test_key_range_contains_respects_bounds() {
+        let range = KeyRange { start: Some(k("b")), end: Some(k("d")) };
+        assert!(!range.contains(&k("a")));
+        assert!(range.contains(&k("b")));
+        assert!(range.contains(&k("c")));
+        assert!(range.contains(&k("d")));
+        assert!(!range.contains(&k("e")));
+    }
+
+    #[test]
+    fn test_check_passes_on_well_formed_tree() {
+        let mut pages: HashMap<PageId, BlinkTreeNode> = HashMap::new();
+        pages.insert(1, internal(1, vec![k("m")], vec![2, 3], None, None));
+        pages.insert(2, leaf(2, vec![k("a"), k("b")], Some(3), Some(k("m"))));
+        pages.insert(3, leaf(3, vec![k("m"), k("z")], None, None));
+
+        let result = check(1, |id| pages.get(&id).cloned().ok_or(BlinkTreeError::NodeNotFound(id)));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_check_detects_key_outside_inherited_range() {
+        let mut pages: HashMap<PageId, BlinkTreeNode> = HashMap::new();
+        // Separator "m" means the left child should only hold keys <= "m", but "z" violates
+        // that.
+        pages.insert(1, internal(1, vec![k("m")], vec![2, 3], None, None));
+        pages.insert(2, leaf(2, vec![k("a"), k("z")], Some(3), Some(k("m"))));
+        pages.insert(3, leaf(3, vec![k("n")], None, None));
+
+        let result = check(1, |id| pages.get(&id).cloned().ok_or(BlinkTreeError::NodeNotFound(id)));
+        assert!(matches!(result, Err(BlinkTreeError::TreeLogicError(_))));
+    }
+
+    #[test]
+    fn test_check_detects_mismatched_children_key_count() {
+        let mut pages: HashMap<PageId, BlinkTreeNode> = HashMap::new();
+        pages.insert(1, internal(1, vec![k("m")], vec![2, 3, 4], None, None));
+
+        let result = check(1, |id| pages.get(&id).cloned().ok_or(BlinkTreeError::NodeNotFound(id)));
+        assert!(matches!(result, Err(BlinkTreeError::TreeLogicError(_))));
+    }
+
+    #[test]
+    fn test_verify_siblings_detects_out_of_order_sibling() {
+        let mut pages: HashMap<PageId, BlinkTreeNode> = HashMap::new();
+        let left = leaf(1, vec![k("b")], Some(2), Some(k("m")));
+        pages.insert(2, leaf(2, vec![k("a")], None, None)); // "a" precedes "m", violating order
+
+        let result = left.verify_siblings(
+            |id| pages.get(&id).cloned().ok_or(BlinkTreeError::NodeNotFound(id)),
+            MAX_SIBLING_HOPS,
+        );
+        assert!(matches!(result, Err(BlinkTreeError::TreeLogicError(_))));
+    }
+
+    #[test]
+    fn test_verify_siblings_detects_cycle() {
+        let mut pages: HashMap<PageId, BlinkTreeNode> = HashMap::new();
+        pages.insert(1, leaf(1, vec![k("a")], Some(2), None));
+        pages.insert(2, leaf(2, vec![k("b")], Some(1), None)); // cycles back to 1
+
+        let start = pages[&1].clone();
+        let result = start.verify_siblings(
+            |id| pages.get(&id).cloned().ok_or(BlinkTreeError::NodeNotFound(id)),
+            5,
+        );
+        assert!(matches!(result, Err(BlinkTreeError::TreeLogicError(_))));
+    }
+
+    #[test]
+    fn test_audit_passes_on_well_formed_tree() {
+        let mut pages: HashMap<PageId, BlinkTreeNode> = HashMap::new();
+        pages.insert(1, internal(1, vec![k("m")], vec![2, 3], None, None));
+        pages.insert(2, leaf(2, vec![k("a"), k("b")], Some(3), Some(k("m"))));
+        pages.insert(3, leaf(3, vec![k("m"), k("z")], None, None));
+
+        let report = audit(1, |id| pages.get(&id).cloned().ok_or(BlinkTreeError::NodeNotFound(id)));
+        assert!(report.is_healthy());
+    }
+
+    #[test]
+    fn test_audit_accumulates_every_violation_instead_of_stopping_at_the_first() {
+        let mut pages: HashMap<PageId, BlinkTreeNode> = HashMap::new();
+        // Three independent problems, each on a different page: non-increasing keys, a key
+        // outside its inherited range, and a mismatched child/key count. A single-shot
+        // `check` would stop at the first one it reaches and never report the rest.
+        pages.insert(1, internal(1, vec![k("m"), k("t")], vec![2, 3, 4], None, None));
+        pages.insert(2, leaf(2, vec![k("b"), k("a")], None, Some(k("m"))));
+        pages.insert(3, leaf(3, vec![k("a")], None, Some(k("t"))));
+        pages.insert(4, internal(4, vec![], vec![7, 8], None, None));
+        pages.insert(7, leaf(7, vec![], None, None));
+        pages.insert(8, leaf(8, vec![], None, None));
+
+        let report = audit(1, |id| pages.get(&id).cloned().ok_or(BlinkTreeError::NodeNotFound(id)));
+        assert!(!report.is_healthy());
+        assert!(report.violations.len() >= 3, "expected at least 3 violations, got {report:?}");
+        assert!(report.violations.iter().any(|v| v.page_id == 2));
+        assert!(report.violations.iter().any(|v| v.page_id == 3));
+        assert!(report.violations.iter().any(|v| v.page_id == 4));
+    }
+
+    #[test]
+    fn test_audit_detects_page_shared_by_two_parents() {
+        let mut pages: HashMap<PageId, BlinkTreeNode> = HashMap::new();
+        // Page 4 is wired in as a child of both 2 and 3, which can never happen in a
+        // well-formed tree.
+        pages.insert(1, internal(1, vec![k("m")], vec![2, 3], None, None));
+        pages.insert(2, internal(2, vec![], vec![4], None, Some(k("m"))));
+        pages.insert(3, internal(3, vec![], vec![4], None, None));
+        pages.insert(4, leaf(4, vec![], None, None));
+
+        let report = audit(1, |id| pages.get(&id).cloned().ok_or(BlinkTreeError::NodeNotFound(id)));
+        assert!(!report.is_healthy());
+        assert!(report
+            .violations
+            .iter()
+            .any(|v| v.page_id == 4 && v.description.contains("both")));
+    }
+
+    #[test]
+    fn test_audit_records_an_unreadable_page_without_aborting_the_rest_of_the_walk() {
+        let mut pages: HashMap<PageId, BlinkTreeNode> = HashMap::new();
+        // Page 2 is missing entirely (simulating a checksum mismatch or I/O error), but page
+        // 3 is fine and should still be checked.
+        pages.insert(1, internal(1, vec![k("m")], vec![2, 3], None, None));
+        pages.insert(3, leaf(3, vec![k("m"), k("z")], None, None));
+
+        let report = audit(1, |id| pages.get(&id).cloned().ok_or(BlinkTreeError::NodeNotFound(id)));
+        assert!(!report.is_healthy());
+        assert!(report.violations.iter().any(|v| v.page_id == 2));
+    }
+
+    #[test]
+    fn test_repair_into_rebuilds_from_the_right_link_leaf_chain() {
+        let temp_dir = TempDir::new().unwrap();
+        let source_path = temp_dir.path().join("source.blink");
+        let mut source = BlinkTreeIndex::new("source".to_string(), source_path, 5).unwrap();
+
+        for i in 0..20u8 {
+            source.insert(vec![i], vec![i]).unwrap();
+        }
+
+        let target_path = temp_dir.path().join("target.blink");
+        let mut target = BlinkTreeIndex::new("target".to_string(), target_path, 5).unwrap();
+
+        let reinserted = source.repair_into(&mut target).unwrap();
+        assert_eq!(reinserted, 20);
+
+        for i in 0..20u8 {
+            assert_eq!(target.find_primary_keys(&vec![i]).unwrap(), Some(vec![vec![i]]));
+        }
+        assert!(target.audit().is_healthy());
+    }
+}
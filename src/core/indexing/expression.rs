@@ -0,0 +1,128 @@
+//! Deterministic scalar expressions a `CREATE INDEX` can be defined over,
+//! e.g. `date_trunc_day(created_at)`, instead of only a bare column. The
+//! computed value is what actually gets stored as the index's key, so only
+//! functions provably pure and deterministic - the same input always
+//! produces the same output, forever - may appear here; anything else would
+//! let a stored key silently go stale.
+//!
+//! A query predicate can be served by one of these indexes when its
+//! `SqlSimpleCondition::column` string matches the index's
+//! [`IndexExpr::canonical_string`] exactly (see
+//! `Optimizer::find_suitable_index`) - the same "Command-only" convention
+//! `CreateAggregateIndex` uses, since this AST has no function-call
+//! expression syntax for a real `WHERE date_trunc_day(created_at) = ...` to
+//! parse into.
+
+use crate::core::common::OxidbError;
+use crate::core::types::DataType;
+use std::collections::HashMap;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// A pure scalar function an [`IndexExpr::Function`] node may apply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeterministicFunction {
+    /// Truncates a Unix-epoch-seconds `Integer` down to the start of its day (UTC).
+    DateTruncDay,
+    /// Truncates a Unix-epoch-seconds `Integer` down to the start of its
+    /// (Monday-starting) week (UTC).
+    DateTruncWeek,
+    /// Lowercases a `String`.
+    Lower,
+    /// Uppercases a `String`.
+    Upper,
+}
+
+impl DeterministicFunction {
+    /// This function's name as written in [`IndexExpr::canonical_string`].
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::DateTruncDay => "date_trunc_day",
+            Self::DateTruncWeek => "date_trunc_week",
+            Self::Lower => "lower",
+            Self::Upper => "upper",
+        }
+    }
+
+    fn apply(self, value: &DataType) -> Result<DataType, OxidbError> {
+        match self {
+            Self::DateTruncDay | Self::DateTruncWeek => {
+                let DataType::Integer(epoch_secs) = value else {
+                    return Err(OxidbError::Type(format!(
+                        "{}() requires an Integer (Unix epoch seconds) argument, got {value:?}",
+                        self.name()
+                    )));
+                };
+                let day_index = epoch_secs.div_euclid(SECONDS_PER_DAY);
+                let truncated_day = match self {
+                    Self::DateTruncDay => day_index,
+                    // 1970-01-01 (day 0) was a Thursday; shift back to the preceding Monday.
+                    Self::DateTruncWeek => day_index - (day_index + 3).rem_euclid(7),
+                    Self::Lower | Self::Upper => unreachable!(),
+                };
+                Ok(DataType::Integer(truncated_day * SECONDS_PER_DAY))
+            }
+            Self::Lower | Self::Upper => {
+                let DataType::String(s) = value else {
+                    return Err(OxidbError::Type(format!(
+                        "{}() requires a String argument, got {value:?}",
+                        self.name()
+                    )));
+                };
+                Ok(DataType::String(if matches!(self, Self::Lower) {
+                    s.to_lowercase()
+                } else {
+                    s.to_uppercase()
+                }))
+            }
+        }
+    }
+}
+
+/// The expression a functional `CREATE INDEX` is keyed by. Only a single
+/// level of function nesting over a single base column is supported today -
+/// the same single-column scope `CreateIndex`'s plain column indexes have.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IndexExpr {
+    Column(String),
+    Function { function: DeterministicFunction, arg: Box<IndexExpr> },
+}
+
+impl IndexExpr {
+    /// Evaluates this expression against `row` (keyed by column name bytes,
+    /// the same convention `handle_create_index`'s backfill and
+    /// `evaluate_simple_condition` use).
+    ///
+    /// # Errors
+    /// Returns `OxidbError::Type` if a function's argument isn't the
+    /// `DataType` variant it requires.
+    pub fn evaluate(&self, row: &HashMap<Vec<u8>, DataType>) -> Result<DataType, OxidbError> {
+        match self {
+            Self::Column(name) => Ok(row.get(name.as_bytes()).cloned().unwrap_or(DataType::Null)),
+            Self::Function { function, arg } => function.apply(&arg.evaluate(row)?),
+        }
+    }
+
+    /// The canonical textual form a query predicate's `column` string is
+    /// matched against to detect this index applies, e.g.
+    /// `date_trunc_day(created_at)`.
+    #[must_use]
+    pub fn canonical_string(&self) -> String {
+        match self {
+            Self::Column(name) => name.clone(),
+            Self::Function { function, arg } => {
+                format!("{}({})", function.name(), arg.canonical_string())
+            }
+        }
+    }
+
+    /// Every base column this expression ultimately reads, for `CREATE
+    /// INDEX`-time schema validation.
+    pub fn referenced_columns(&self) -> Vec<&str> {
+        match self {
+            Self::Column(name) => vec![name.as_str()],
+            Self::Function { arg, .. } => arg.referenced_columns(),
+        }
+    }
+}
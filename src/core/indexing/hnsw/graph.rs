@@ -1,4 +1,5 @@
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashMap};
 
@@ -28,7 +29,7 @@ impl PartialOrd for SearchCandidate {
 }
 
 /// HNSW Graph structure managing the hierarchical navigable small world
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct HnswGraph {
     /// All nodes in the graph
     nodes: HashMap<NodeId, HnswNode>,
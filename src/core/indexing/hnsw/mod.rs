@@ -4,7 +4,7 @@ mod node;
 pub mod tree;
 
 pub use error::HnswError;
-pub use node::{HnswNode, NodeId, Vector};
+pub use node::{DistanceFunction, HnswNode, NodeId, Vector};
 pub use tree::HnswIndex;
 
 use crate::core::common::OxidbError as CommonError;
@@ -65,13 +65,11 @@ impl Index for HnswIndex {
     }
 
     fn save(&self) -> Result<(), CommonError> {
-        // HNSW uses in-memory structure for now, could be extended for persistence
-        Ok(())
+        self.save_to_disk().map_err(map_hnsw_error_to_common)
     }
 
     fn load(&mut self) -> Result<(), CommonError> {
-        // HNSW loads automatically if needed
-        Ok(())
+        self.load_from_disk().map_err(map_hnsw_error_to_common)
     }
 
     fn delete(
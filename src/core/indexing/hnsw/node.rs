@@ -117,7 +117,7 @@ pub fn cosine_similarity(a: &Vector, b: &Vector) -> f32 {
 }
 
 /// Distance function type for HNSW
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum DistanceFunction {
     Euclidean,
     Cosine,
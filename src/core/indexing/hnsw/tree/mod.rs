@@ -1,4 +1,9 @@
 use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufReader, BufWriter};
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
 
 use crate::core::query::commands::{Key as PrimaryKey, Value as TraitValue};
 use super::error::{HnswError, HnswResult};
@@ -10,19 +15,37 @@ use super::node::{NodeId, Vector, DistanceFunction};
 pub struct HnswIndex {
     /// Name of this index
     pub name: String,
-    
+
     /// The underlying HNSW graph
     graph: HnswGraph,
-    
+
     /// Map from primary keys to node IDs for efficient lookups
     pk_to_node: HashMap<PrimaryKey, NodeId>,
-    
+
     /// Vector dimension
     dimension: usize,
+
+    /// Path to the file backing this index, if it should persist across restarts.
+    file_path: Option<PathBuf>,
+}
+
+/// On-disk representation of an `HnswIndex`, written by `save` and read back by `load`.
+#[derive(Serialize)]
+struct HnswPersistedRef<'a> {
+    graph: &'a HnswGraph,
+    pk_to_node: &'a HashMap<PrimaryKey, NodeId>,
+    dimension: usize,
+}
+
+#[derive(Deserialize)]
+struct HnswPersistedOwned {
+    graph: HnswGraph,
+    pk_to_node: HashMap<PrimaryKey, NodeId>,
+    dimension: usize,
 }
 
 impl HnswIndex {
-    /// Create a new HNSW index
+    /// Create a new, purely in-memory HNSW index.
     pub fn new(
         name: String,
         dimension: usize,
@@ -31,15 +54,74 @@ impl HnswIndex {
         distance_function: DistanceFunction,
     ) -> HnswResult<Self> {
         let graph = HnswGraph::new(dimension, max_connections, ef_construction, distance_function);
-        
+
         Ok(Self {
             name,
             graph,
             pk_to_node: HashMap::new(),
             dimension,
+            file_path: None,
         })
     }
 
+    /// Create a new HNSW index backed by `file_path`, loading any existing data from it.
+    ///
+    /// This is what `CREATE INDEX ... USING HNSW` goes through so that reopening the
+    /// database does not require rebuilding the graph from a full table scan.
+    pub fn new_with_persistence(
+        name: String,
+        dimension: usize,
+        max_connections: usize,
+        ef_construction: usize,
+        distance_function: DistanceFunction,
+        file_path: PathBuf,
+    ) -> HnswResult<Self> {
+        let mut index = Self::new(name, dimension, max_connections, ef_construction, distance_function)?;
+        index.file_path = Some(file_path);
+        if index.file_path.as_ref().is_some_and(|p| p.exists()) {
+            index.load_from_disk()?;
+        }
+        Ok(index)
+    }
+
+    /// Write the graph to `file_path`, if this index is persistent.
+    pub fn save_to_disk(&self) -> HnswResult<()> {
+        let Some(path) = &self.file_path else {
+            return Ok(());
+        };
+        let file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)
+            .map_err(HnswError::Io)?;
+        let writer = BufWriter::new(file);
+        let persisted =
+            HnswPersistedRef { graph: &self.graph, pk_to_node: &self.pk_to_node, dimension: self.dimension };
+        bincode::serialize_into(writer, &persisted).map_err(|e| HnswError::Serialization(e.to_string()))
+    }
+
+    /// Replace in-memory state with whatever is on disk at `file_path`.
+    pub fn load_from_disk(&mut self) -> HnswResult<()> {
+        let Some(path) = &self.file_path else {
+            return Ok(());
+        };
+        if !path.exists() {
+            return Ok(());
+        }
+        let file = File::open(path).map_err(HnswError::Io)?;
+        if file.metadata().map_err(HnswError::Io)?.len() == 0 {
+            return Ok(());
+        }
+        let reader = BufReader::new(file);
+        let persisted: HnswPersistedOwned =
+            bincode::deserialize_from(reader).map_err(|e| HnswError::Serialization(e.to_string()))?;
+        self.graph = persisted.graph;
+        self.pk_to_node = persisted.pk_to_node;
+        self.dimension = persisted.dimension;
+        Ok(())
+    }
+
     /// Parse a vector value from bytes
     pub fn parse_vector_value(&self, value: &TraitValue) -> Result<Vector, crate::core::common::OxidbError> {
         // Expected format: dimension (4 bytes) + f32 values
@@ -3,10 +3,20 @@ use std::path::PathBuf;
 use std::sync::{Arc, RwLock};
 
 use crate::core::common::OxidbError;
+use crate::core::indexing::aggregate::AggregateIndex;
 use crate::core::indexing::btree::BPlusTreeIndex; // Import BPlusTreeIndex
+use crate::core::indexing::expression::IndexExpr;
 use crate::core::indexing::hash::HashIndex;
+use crate::core::indexing::hnsw::{DistanceFunction, HnswIndex};
+use crate::core::indexing::roaring::RoaringIndex;
 use crate::core::indexing::traits::Index; // Assumes Index trait uses common::OxidbError
 use crate::core::query::commands::{Key as PrimaryKey, Value};
+use crate::core::query::sql::ast::AggregateFunction;
+
+/// Default `M` (max connections per layer) for HNSW indexes created without explicit tuning.
+const DEFAULT_HNSW_MAX_CONNECTIONS: usize = 16;
+/// Default `efConstruction` for HNSW indexes created without explicit tuning.
+const DEFAULT_HNSW_EF_CONSTRUCTION: usize = 200;
 
 /// A type alias for a shared, thread-safe index.
 /// It uses `Arc` for shared ownership and `RwLock` for interior mutability,
@@ -22,6 +32,18 @@ type SharedIndex = Arc<RwLock<dyn Index + Send + Sync>>;
 pub struct IndexManager {
     /// A map storing the actual index instances, keyed by index name.
     indexes: HashMap<String, SharedIndex>,
+    /// Materialized `CREATE AGGREGATE INDEX` indexes, keyed by index name.
+    /// Kept separate from `indexes` because an `AggregateIndex` maps group
+    /// values to running aggregate state rather than column values to
+    /// primary keys, so it doesn't fit the `Index` trait's shape.
+    aggregate_indexes: HashMap<String, AggregateIndex>,
+    /// Definitions of `CREATE INDEX`es keyed by a deterministic expression
+    /// rather than a bare column, keyed by index name. The postings
+    /// themselves live in `indexes` like any other hash index - this map
+    /// only remembers which expression `index_name`'s keys were computed
+    /// from, so insert/delete maintenance and predicate matching know how to
+    /// (re)compute that key. See `crate::core::indexing::expression`.
+    functional_index_defs: HashMap<String, (String, IndexExpr)>,
     /// The base file system path where index data is stored.
     base_path: PathBuf,
 }
@@ -55,7 +77,12 @@ impl IndexManager {
             )));
         }
 
-        let mut manager = Self { indexes: HashMap::new(), base_path };
+        let mut manager = Self {
+            indexes: HashMap::new(),
+            aggregate_indexes: HashMap::new(),
+            functional_index_defs: HashMap::new(),
+            base_path,
+        };
 
         // Load existing indexes from disk only if auto_discover is enabled
         if auto_discover {
@@ -91,8 +118,16 @@ impl IndexManager {
                 .map_err(|e| OxidbError::Index(format!("BTree creation error: {e:?}")))?; // Map btree::OxidbError
                 Arc::new(RwLock::new(btree_index))
             }
+            "roaring" => {
+                // RoaringIndex::new expects base_path, not full file path, same as HashIndex.
+                let roaring_index = RoaringIndex::new(index_name.clone(), &self.base_path)?;
+                Arc::new(RwLock::new(roaring_index))
+            }
             _ => {
-                return Err(OxidbError::Index(format!("Unsupported index type: {index_type}")));
+                return Err(OxidbError::Index(format!(
+                    "Unsupported index type: {index_type}. HNSW vector indexes must be created \
+                     via `create_vector_index`, which needs the column's vector dimension."
+                )));
             }
         };
 
@@ -100,16 +135,196 @@ impl IndexManager {
         Ok(())
     }
 
+    /// Creates an HNSW approximate-nearest-neighbor index over a `VECTOR` column.
+    ///
+    /// This backs `CREATE INDEX ... USING HNSW ON table(vector_col)`: unlike `create_index`,
+    /// it takes the column's vector `dimension` and distance `metric` up front, since HNSW
+    /// graphs (unlike hash/btree) cannot be built without knowing the vector shape. The index
+    /// is persisted under `base_path` so that reopening the database loads the existing graph
+    /// instead of rebuilding it from a full table scan.
+    pub fn create_vector_index(
+        &mut self,
+        index_name: String,
+        dimension: usize,
+        metric: DistanceFunction,
+    ) -> Result<(), OxidbError> {
+        if self.indexes.contains_key(&index_name) {
+            return Err(OxidbError::Index(format!(
+                "Index with name '{index_name}' already exists."
+            )));
+        }
+
+        let index_path = self.base_path.join(format!("{index_name}.hnsw"));
+        let hnsw_index = HnswIndex::new_with_persistence(
+            index_name.clone(),
+            dimension,
+            DEFAULT_HNSW_MAX_CONNECTIONS,
+            DEFAULT_HNSW_EF_CONSTRUCTION,
+            metric,
+            index_path,
+        )
+        .map_err(|e| OxidbError::Index(format!("HNSW creation error: {e}")))?;
+
+        self.indexes.insert(index_name, Arc::new(RwLock::new(hnsw_index)));
+        Ok(())
+    }
+
     #[must_use]
     pub fn get_index(&self, index_name: &str) -> Option<SharedIndex> {
         self.indexes.get(index_name).cloned()
     }
 
+    /// Unregisters `index_name`, so `get_index`/`find_by_index` no longer see
+    /// it, and removes its backing file(s) under `base_path`. Without the
+    /// file removal, `discover_and_load_existing_indexes` would resurrect a
+    /// dropped index the next time the store is reopened, since discovery
+    /// has no record of a drop - only of which index files are still present.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::Index` if `index_name` isn't registered.
+    pub fn drop_index(&mut self, index_name: &str) -> Result<(), OxidbError> {
+        self.indexes.remove(index_name).ok_or_else(|| {
+            OxidbError::Index(format!("Index '{index_name}' not found for deletion."))
+        })?;
+        self.functional_index_defs.remove(index_name);
+
+        if let Ok(entries) = std::fs::read_dir(&self.base_path) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.is_file() && path.file_stem().and_then(|s| s.to_str()) == Some(index_name)
+                {
+                    if let Err(e) = std::fs::remove_file(&path) {
+                        eprintln!(
+                            "[IndexManager] Failed to remove backing file for dropped index '{index_name}' at {path:?}: {e}"
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     #[must_use]
     pub fn base_path(&self) -> PathBuf {
         self.base_path.clone()
     }
 
+    /// Registers a new materialized aggregate index.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::Index` if `index_name` is already registered, as
+    /// either a regular or an aggregate index.
+    pub fn create_aggregate_index(
+        &mut self,
+        index_name: String,
+        index: AggregateIndex,
+    ) -> Result<(), OxidbError> {
+        if self.indexes.contains_key(&index_name) || self.aggregate_indexes.contains_key(&index_name)
+        {
+            return Err(OxidbError::Index(format!(
+                "Index with name '{index_name}' already exists."
+            )));
+        }
+        self.aggregate_indexes.insert(index_name, index);
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn get_aggregate_index(&self, index_name: &str) -> Option<&AggregateIndex> {
+        self.aggregate_indexes.get(index_name)
+    }
+
+    pub fn get_aggregate_index_mut(&mut self, index_name: &str) -> Option<&mut AggregateIndex> {
+        self.aggregate_indexes.get_mut(index_name)
+    }
+
+    /// Finds a registered aggregate index covering `table_name`, grouped by
+    /// `group_column`, computing `function` over `agg_column` - the lookup
+    /// `handle_select_aggregate` uses to decide whether a `GROUP BY` query
+    /// can be rewritten to scan this index instead of the base table.
+    #[must_use]
+    pub fn find_aggregate_index(
+        &self,
+        table_name: &str,
+        group_column: &str,
+        function: AggregateFunction,
+        agg_column: Option<&str>,
+    ) -> Option<&AggregateIndex> {
+        self.aggregate_indexes.values().find(|idx| {
+            idx.table_name == table_name
+                && idx.group_column == group_column
+                && idx.function == function
+                && idx.agg_column.as_deref() == agg_column
+        })
+    }
+
+    /// All aggregate indexes maintained over `table_name`, for insert/delete
+    /// maintenance hooks to update as rows change.
+    pub fn aggregate_indexes_for_table(
+        &mut self,
+        table_name: &str,
+    ) -> impl Iterator<Item = &mut AggregateIndex> {
+        self.aggregate_indexes.values_mut().filter(move |idx| idx.table_name == table_name)
+    }
+
+    /// Unregisters an aggregate index. Aggregate indexes are purely in-memory
+    /// (no backing file, unlike `drop_index`'s regular indexes), so nothing
+    /// beyond removing the map entry is required.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::Index` if `index_name` isn't registered.
+    pub fn drop_aggregate_index(&mut self, index_name: &str) -> Result<(), OxidbError> {
+        self.aggregate_indexes.remove(index_name).map(|_| ()).ok_or_else(|| {
+            OxidbError::Index(format!("Aggregate index '{index_name}' not found for deletion."))
+        })
+    }
+
+    /// Registers `index_name` (already created as a regular hash index via
+    /// `create_index`) as keyed by `expression` rather than a bare column.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::Index` if `index_name` isn't a registered index.
+    pub fn register_functional_index(
+        &mut self,
+        index_name: String,
+        table_name: String,
+        expression: IndexExpr,
+    ) -> Result<(), OxidbError> {
+        if !self.indexes.contains_key(&index_name) {
+            return Err(OxidbError::Index(format!(
+                "Index '{index_name}' must be created before it can be registered as functional."
+            )));
+        }
+        self.functional_index_defs.insert(index_name, (table_name, expression));
+        Ok(())
+    }
+
+    /// Finds a registered functional index over `table_name` whose
+    /// expression's canonical string equals `canonical_expr` - the lookup
+    /// `Optimizer::find_suitable_index` uses to detect a predicate like
+    /// `date_trunc_day(created_at) = ...` can be served by an index scan.
+    #[must_use]
+    pub fn find_functional_index(&self, table_name: &str, canonical_expr: &str) -> Option<&str> {
+        self.functional_index_defs.iter().find_map(|(index_name, (def_table, expr))| {
+            (def_table == table_name && expr.canonical_string() == canonical_expr)
+                .then_some(index_name.as_str())
+        })
+    }
+
+    /// All functional index definitions maintained over `table_name`, for
+    /// insert/delete maintenance hooks to recompute and update as rows
+    /// change.
+    pub fn functional_indexes_for_table(
+        &self,
+        table_name: &str,
+    ) -> impl Iterator<Item = (&str, &IndexExpr)> {
+        self.functional_index_defs
+            .iter()
+            .filter(move |(_, (def_table, _))| def_table == table_name)
+            .map(|(index_name, (_, expr))| (index_name.as_str(), expr))
+    }
+
     // ... (other methods: insert_into_index, on_insert_data, delete_from_index, on_delete_data, on_update_data, find_by_index)
     // These methods should work fine if the Index trait methods correctly map their errors to common::OxidbError.
 
@@ -224,6 +439,27 @@ impl IndexManager {
         }
     }
 
+    /// Counts how many rows hold `value` in `index_name`, without
+    /// necessarily resolving their primary keys (see `Index::cardinality`).
+    /// `check_uniqueness` uses this to answer the common "value isn't taken
+    /// at all" case without materializing a PK list.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::Index` if `index_name` isn't registered.
+    pub fn cardinality(&self, index_name: &str, value: &Value) -> Result<u64, OxidbError> {
+        match self.indexes.get(index_name) {
+            Some(index_arc) => {
+                let index = index_arc.read().map_err(|_| {
+                    OxidbError::LockTimeout("Failed to acquire read lock on index".to_string())
+                })?;
+                index.cardinality(value)
+            }
+            None => Err(OxidbError::Index(format!(
+                "Index '{index_name}' not found for cardinality check."
+            ))),
+        }
+    }
+
     pub fn save_all_indexes(&self) -> Result<(), OxidbError> {
         for index_arc in self.indexes.values() {
             let index = index_arc.read().map_err(|_| {
@@ -261,11 +497,17 @@ impl IndexManager {
                         let index_name = &file_name[..dot_pos];
                         let extension = &file_name[dot_pos + 1..];
 
-                        // Only load .idx files (which are hash indexes by default)
-                        if extension == "idx" {
+                        // ".idx" files are hash indexes, ".roaring" files are
+                        // roaring-bitmap posting-list indexes.
+                        let index_type = match extension {
+                            "idx" => Some("hash"),
+                            "roaring" => Some("roaring"),
+                            _ => None,
+                        };
+                        if let Some(index_type) = index_type {
                             // Skip if already loaded
                             if !self.indexes.contains_key(index_name) {
-                                match self.create_index(index_name.to_string(), "hash") {
+                                match self.create_index(index_name.to_string(), index_type) {
                                     Ok(()) => {
                                         eprintln!(
                                             "[IndexManager] Loaded existing index: {index_name}"
@@ -540,4 +782,53 @@ mod tests {
 
         Ok(())
     }
+
+    // --- RoaringIndex Integration Tests ---
+
+    #[test]
+    fn test_create_roaring_index_and_cardinality() -> Result<(), OxidbError> {
+        let temp_dir = tempdir().expect("test_create_roaring_index_and_cardinality: Failed to create temp dir");
+        let base_path = temp_dir.path().to_path_buf();
+        let mut manager = IndexManager::new(base_path.clone())?;
+        let index_name = "idx_widgets_color".to_string();
+
+        manager.create_index(index_name.clone(), "roaring")?;
+        assert!(
+            manager.indexes.contains_key(&index_name),
+            "Roaring index should exist after creation"
+        );
+
+        let red = val("red");
+        manager.insert_into_index(&index_name, &red, &pk("pk1"))?;
+        manager.insert_into_index(&index_name, &red, &pk("pk2"))?;
+        manager.insert_into_index(&index_name, &val("blue"), &pk("pk3"))?;
+
+        // `cardinality` answers "how many" without a `find_by_index` call.
+        assert_eq!(manager.cardinality(&index_name, &red)?, 2);
+        assert_eq!(manager.cardinality(&index_name, &val("green"))?, 0);
+
+        let found_red =
+            manager.find_by_index(&index_name, &red)?.expect("red should be found");
+        assert_eq!(found_red.len(), 2);
+
+        manager.delete_from_index(&index_name, &red, Some(&pk("pk1")))?;
+        assert_eq!(manager.cardinality(&index_name, &red)?, 1);
+
+        manager.save_all_indexes()?;
+        let roaring_file_path = base_path.join(format!("{index_name}.roaring"));
+        assert!(roaring_file_path.is_file(), "Roaring index file should exist after saving");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cardinality_of_unregistered_index_errors() {
+        let temp_dir =
+            tempdir().expect("test_cardinality_of_unregistered_index_errors: Failed to create temp dir");
+        let manager = IndexManager::new(temp_dir.path().to_path_buf()).unwrap();
+        assert!(matches!(
+            manager.cardinality("does_not_exist", &val("x")),
+            Err(OxidbError::Index(_))
+        ));
+    }
 }
@@ -0,0 +1,234 @@
+//! Append-only Merkle Mountain Range, modeled on zcash_history's MMR: each
+//! appended leaf carries a caller-supplied payload (typically encoded with
+//! [`DataSerializer`](crate::core::common::traits::DataSerializer)) plus a
+//! crc32 hash, and on every append equal-height "peak" subtrees are merged
+//! pairwise - the same carry recurrence as binary addition - so the whole
+//! log is always summarized by a small set of peak hashes rather than one
+//! hash per leaf. That keeps `append` O(log n) amortized and lets a peer
+//! verify any historical entry against those compact peaks without reading
+//! or trusting the full log, making this a building block for a
+//! tamper-evident audit log or a replication checkpoint.
+
+use crate::core::common::crc32;
+use crate::core::common::OxidbError;
+
+/// A node hash in the range. Currently a crc32 checksum, matching the WAL's
+/// own record checksums (see `crate::core::common::crc32`) rather
+/// than pulling in a cryptographic hash dependency this crate doesn't
+/// otherwise need.
+pub type Hash = u32;
+
+fn combine_hashes(left: Hash, right: Hash) -> Hash {
+    let mut bytes = [0u8; 8];
+    bytes[..4].copy_from_slice(&left.to_le_bytes());
+    bytes[4..].copy_from_slice(&right.to_le_bytes());
+    crc32::checksum(&bytes)
+}
+
+/// Which side of a combination step a proof's stored sibling hash sits on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Left,
+    Right,
+}
+
+/// An authentication path from one appended leaf up to the peak that
+/// currently covers it, returned by [`MerkleMountainRange::prove`] and
+/// checked by [`MerkleMountainRange::verify`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleProof {
+    /// The leaf position this proof is for.
+    pub position: u64,
+    /// The leaf's own hash (the bottom of the authentication path).
+    pub leaf_hash: Hash,
+    siblings: Vec<(Hash, Side)>,
+}
+
+/// One peak: the root of a perfect subtree of `2.pow(height)` contiguous
+/// leaves starting at `leaf_start`.
+#[derive(Debug, Clone)]
+struct Peak {
+    height: u32,
+    hash: Hash,
+    leaf_start: u64,
+    leaf_count: u64,
+}
+
+/// An append-only Merkle Mountain Range over opaque leaf byte strings.
+#[derive(Debug, Default)]
+pub struct MerkleMountainRange {
+    leaves: Vec<Vec<u8>>,
+    leaf_hashes: Vec<Hash>,
+    peaks: Vec<Peak>,
+}
+
+impl MerkleMountainRange {
+    /// Creates an empty range.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of leaves appended so far.
+    #[must_use]
+    pub fn len(&self) -> u64 {
+        self.leaves.len() as u64
+    }
+
+    /// Whether no leaves have been appended yet.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// Appends a new leaf (typically a [`DataSerializer`](crate::core::common::traits::DataSerializer)-encoded
+    /// payload) and returns its position. Merges the new leaf's singleton
+    /// peak with the existing trailing peak whenever their heights match,
+    /// carrying the merge up just like adding one to a binary counter, so at
+    /// most `O(log n)` peaks ever merge for a single append.
+    pub fn append(&mut self, leaf_bytes: Vec<u8>) -> u64 {
+        let position = self.leaves.len() as u64;
+        let leaf_hash = crc32::checksum(&leaf_bytes);
+        self.leaves.push(leaf_bytes);
+        self.leaf_hashes.push(leaf_hash);
+
+        let mut carry = Peak { height: 0, hash: leaf_hash, leaf_start: position, leaf_count: 1 };
+
+        loop {
+            let heights_match = matches!(self.peaks.last(), Some(top) if top.height == carry.height);
+            if !heights_match {
+                break;
+            }
+            let Some(left) = self.peaks.pop() else { break };
+            carry = Peak {
+                height: left.height + 1,
+                hash: combine_hashes(left.hash, carry.hash),
+                leaf_start: left.leaf_start,
+                leaf_count: left.leaf_count + carry.leaf_count,
+            };
+        }
+
+        self.peaks.push(carry);
+        position
+    }
+
+    /// The current peak hashes, left (oldest) to right (newest). Together
+    /// these compactly summarize every leaf ever appended.
+    #[must_use]
+    pub fn root_hashes(&self) -> Vec<Hash> {
+        self.peaks.iter().map(|peak| peak.hash).collect()
+    }
+
+    /// Builds an authentication path proving that the leaf at `position` is
+    /// covered by one of [`Self::root_hashes`]' peaks.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::Index` if `position` was never appended.
+    pub fn prove(&self, position: u64) -> Result<MerkleProof, OxidbError> {
+        let leaf_hash = *self.leaf_hashes.get(position as usize).ok_or_else(|| {
+            OxidbError::Index(format!("MMR position {position} has not been appended"))
+        })?;
+
+        let peak = self
+            .peaks
+            .iter()
+            .find(|peak| {
+                position >= peak.leaf_start && position < peak.leaf_start + peak.leaf_count
+            })
+            .ok_or_else(|| {
+                OxidbError::Index(format!("MMR position {position} is not covered by any peak"))
+            })?;
+
+        let start = peak.leaf_start as usize;
+        let end = start + peak.leaf_count as usize;
+        let mut level = self.leaf_hashes[start..end].to_vec();
+        let mut index = position as usize - start;
+        let mut siblings = Vec::new();
+
+        while level.len() > 1 {
+            let sibling_index = index ^ 1;
+            let side = if index % 2 == 0 { Side::Right } else { Side::Left };
+            siblings.push((level[sibling_index], side));
+
+            level = level.chunks(2).map(|pair| combine_hashes(pair[0], pair[1])).collect();
+            index /= 2;
+        }
+
+        Ok(MerkleProof { position, leaf_hash, siblings })
+    }
+
+    /// Checks whether `proof` authenticates its leaf against `root`, which
+    /// should be one of the peak hashes the verifier already trusts (e.g.
+    /// one previously returned by [`Self::root_hashes`]). Takes no `&self`
+    /// because verification only needs the proof and the trusted root - a
+    /// peer can check this without holding the full range.
+    #[must_use]
+    pub fn verify(proof: &MerkleProof, root: Hash) -> bool {
+        let mut hash = proof.leaf_hash;
+        for (sibling_hash, side) in &proof.siblings {
+            hash = match side {
+                Side::Left => combine_hashes(*sibling_hash, hash),
+                Side::Right => combine_hashes(hash, *sibling_hash),
+            };
+        }
+        hash == root
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn append_returns_sequential_positions() {
+        let mut mmr = MerkleMountainRange::new();
+        assert_eq!(mmr.append(b"a".to_vec()), 0);
+        assert_eq!(mmr.append(b"b".to_vec()), 1);
+        assert_eq!(mmr.append(b"c".to_vec()), 2);
+        assert_eq!(mmr.len(), 3);
+    }
+
+    #[test]
+    fn peak_count_follows_binary_representation_of_leaf_count() {
+        let mut mmr = MerkleMountainRange::new();
+        for i in 0..7u8 {
+            mmr.append(vec![i]);
+        }
+        // 7 leaves = 0b111, so three peaks of height 2, 1, 0.
+        assert_eq!(mmr.root_hashes().len(), 3);
+    }
+
+    #[test]
+    fn proof_verifies_against_the_leafs_peak() {
+        let mut mmr = MerkleMountainRange::new();
+        for i in 0..20u8 {
+            mmr.append(vec![i]);
+        }
+
+        for position in 0..20u64 {
+            let proof = mmr.prove(position).unwrap();
+            let roots = mmr.root_hashes();
+            assert!(
+                roots.iter().any(|root| MerkleMountainRange::verify(&proof, *root)),
+                "no peak validated the proof for position {position}"
+            );
+        }
+    }
+
+    #[test]
+    fn proof_fails_against_a_tampered_root() {
+        let mut mmr = MerkleMountainRange::new();
+        for i in 0..4u8 {
+            mmr.append(vec![i]);
+        }
+
+        let proof = mmr.prove(1).unwrap();
+        assert!(!MerkleMountainRange::verify(&proof, proof.leaf_hash.wrapping_add(1)));
+    }
+
+    #[test]
+    fn prove_rejects_an_unappended_position() {
+        let mmr = MerkleMountainRange::new();
+        assert!(mmr.prove(0).is_err());
+    }
+}
@@ -1,14 +1,22 @@
+pub mod aggregate; // Materialized aggregate index (CREATE AGGREGATE INDEX)
 pub mod blink_tree; // Blink tree implementation
 pub mod btree;
+pub mod expression; // Functional index expressions (CREATE INDEX ON date_trunc_day(col))
 pub mod hash;
 pub mod hnsw; // HNSW (Hierarchical Navigable Small World) implementation
               // pub mod rtree; // R-tree implementation (commented out to avoid export conflicts for now)
 pub mod manager;
+pub mod mmr; // Append-only Merkle Mountain Range for tamper-evident audit logs
+pub mod roaring; // Roaring-bitmap-backed posting-list index
 pub mod traits;
 
 // Re-export specific, non-conflicting types
+pub use self::aggregate::AggregateIndex;
 pub use self::blink_tree::BlinkTreeIndex;
 pub use self::btree::BPlusTreeIndex;
+pub use self::expression::{DeterministicFunction, IndexExpr};
 pub use self::hash::HashIndex;
 pub use self::hnsw::HnswIndex;
 pub use self::manager::IndexManager;
+pub use self::mmr::{Hash as MmrHash, MerkleMountainRange, MerkleProof};
+pub use self::roaring::RoaringIndex;
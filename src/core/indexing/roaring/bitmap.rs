@@ -0,0 +1,202 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// An `Array` container holds ids sorted and deduplicated; above
+/// `ARRAY_MAX_CARDINALITY` entries it's cheaper (and faster to query) as a
+/// dense bitmap, so `Container::insert` promotes it in place.
+const ARRAY_MAX_CARDINALITY: usize = 4096;
+/// `u16::MAX + 1` bits, stored as 64-bit words.
+const BITMAP_WORDS: usize = 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+enum Container {
+    Array(Vec<u16>),
+    Bitmap(Vec<u64>),
+}
+
+impl Container {
+    fn new_array() -> Self {
+        Self::Array(Vec::new())
+    }
+
+    fn cardinality(&self) -> usize {
+        match self {
+            Self::Array(ids) => ids.len(),
+            Self::Bitmap(words) => words.iter().map(|w| w.count_ones() as usize).sum(),
+        }
+    }
+
+    fn contains(&self, low: u16) -> bool {
+        match self {
+            Self::Array(ids) => ids.binary_search(&low).is_ok(),
+            Self::Bitmap(words) => words[low as usize / 64] & (1u64 << (low % 64)) != 0,
+        }
+    }
+
+    fn insert(&mut self, low: u16) {
+        match self {
+            Self::Array(ids) => {
+                if let Err(pos) = ids.binary_search(&low) {
+                    ids.insert(pos, low);
+                    if ids.len() > ARRAY_MAX_CARDINALITY {
+                        let mut words = vec![0u64; BITMAP_WORDS];
+                        for &id in ids.iter() {
+                            words[id as usize / 64] |= 1u64 << (id % 64);
+                        }
+                        *self = Self::Bitmap(words);
+                    }
+                }
+            }
+            Self::Bitmap(words) => words[low as usize / 64] |= 1u64 << (low % 64),
+        }
+    }
+
+    fn remove(&mut self, low: u16) {
+        match self {
+            Self::Array(ids) => {
+                if let Ok(pos) = ids.binary_search(&low) {
+                    ids.remove(pos);
+                }
+            }
+            // Once promoted to a bitmap we leave it as one; roaring
+            // implementations typically only downgrade lazily (e.g. on
+            // serialization), and nothing here, serialization included,
+            // ever converts a bitmap container back to an array.
+            Self::Bitmap(words) => words[low as usize / 64] &= !(1u64 << (low % 64)),
+        }
+    }
+
+    fn iter(&self) -> Vec<u16> {
+        match self {
+            Self::Array(ids) => ids.clone(),
+            Self::Bitmap(words) => {
+                let mut ids = Vec::with_capacity(self.cardinality());
+                for (word_idx, &word) in words.iter().enumerate() {
+                    let mut remaining = word;
+                    while remaining != 0 {
+                        let bit = remaining.trailing_zeros() as usize;
+                        ids.push((word_idx * 64 + bit) as u16);
+                        remaining &= remaining - 1;
+                    }
+                }
+                ids
+            }
+        }
+    }
+}
+
+/// A compressed set of `u32` ids, partitioning the id space into 16-bit-keyed
+/// containers (the high 16 bits select the container, the low 16 bits are
+/// the value stored within it). Each container is either a sorted array
+/// (sparse, ≤4096 ids) or a 65536-bit dense bitmap, switching representation
+/// at that threshold so cardinality and membership stay cheap regardless of
+/// how densely a container is populated.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoaringBitmap {
+    containers: BTreeMap<u16, Container>,
+}
+
+impl RoaringBitmap {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, id: u32) {
+        let high = (id >> 16) as u16;
+        let low = (id & 0xFFFF) as u16;
+        self.containers.entry(high).or_insert_with(Container::new_array).insert(low);
+    }
+
+    pub fn remove(&mut self, id: u32) {
+        let high = (id >> 16) as u16;
+        let low = (id & 0xFFFF) as u16;
+        if let Some(container) = self.containers.get_mut(&high) {
+            container.remove(low);
+            if container.cardinality() == 0 {
+                self.containers.remove(&high);
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn contains(&self, id: u32) -> bool {
+        let high = (id >> 16) as u16;
+        let low = (id & 0xFFFF) as u16;
+        self.containers.get(&high).is_some_and(|container| container.contains(low))
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.containers.is_empty()
+    }
+
+    /// Total number of ids across every container, container-by-container —
+    /// the operation this whole structure exists to make cheap.
+    #[must_use]
+    pub fn cardinality(&self) -> u64 {
+        self.containers.values().map(|container| container.cardinality() as u64).sum()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        self.containers.iter().flat_map(|(&high, container)| {
+            container.iter().into_iter().map(move |low| (u32::from(high) << 16) | u32::from(low))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RoaringBitmap;
+
+    #[test]
+    fn insert_contains_remove_roundtrip() {
+        let mut bitmap = RoaringBitmap::new();
+        assert!(bitmap.is_empty());
+        bitmap.insert(5);
+        bitmap.insert(70_000); // lands in a different container (high = 1)
+        assert!(bitmap.contains(5));
+        assert!(bitmap.contains(70_000));
+        assert!(!bitmap.contains(6));
+        assert_eq!(bitmap.cardinality(), 2);
+
+        bitmap.remove(5);
+        assert!(!bitmap.contains(5));
+        assert_eq!(bitmap.cardinality(), 1);
+        assert!(!bitmap.is_empty());
+
+        bitmap.remove(70_000);
+        assert!(bitmap.is_empty());
+    }
+
+    #[test]
+    fn promotes_array_to_bitmap_past_threshold_and_stays_correct() {
+        let mut bitmap = RoaringBitmap::new();
+        for id in 0..5000u32 {
+            bitmap.insert(id);
+        }
+        assert_eq!(bitmap.cardinality(), 5000);
+        for id in 0..5000u32 {
+            assert!(bitmap.contains(id), "missing id {id} after promotion to bitmap container");
+        }
+        assert!(!bitmap.contains(5000));
+
+        bitmap.remove(2500);
+        assert_eq!(bitmap.cardinality(), 4999);
+        assert!(!bitmap.contains(2500));
+    }
+
+    #[test]
+    fn iter_yields_every_inserted_id_in_order() {
+        let mut bitmap = RoaringBitmap::new();
+        let ids = [3u32, 70_005, 1, 65_536, 2];
+        for &id in &ids {
+            bitmap.insert(id);
+        }
+        let mut collected: Vec<u32> = bitmap.iter().collect();
+        collected.sort_unstable();
+        let mut expected = ids.to_vec();
+        expected.sort_unstable();
+        assert_eq!(collected, expected);
+    }
+}
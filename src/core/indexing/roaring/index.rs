@@ -0,0 +1,221 @@
+use super::bitmap::RoaringBitmap;
+use crate::core::common::OxidbError;
+use crate::core::indexing::traits::Index;
+use crate::core::query::commands::{Key as PrimaryKey, Value};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+
+const DEFAULT_INDEX_FILE_EXTENSION: &str = "roaring";
+
+/// Everything `RoaringIndex` persists: the value -> row-id posting lists and
+/// the row-id <-> primary-key side table needed to translate between them,
+/// since a `RoaringBitmap` can only ever hold dense `u32`s, not arbitrary PK
+/// bytes.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct RoaringIndexData {
+    postings: HashMap<Value, RoaringBitmap>,
+    pk_to_id: HashMap<PrimaryKey, u32>,
+    id_to_pk: HashMap<u32, PrimaryKey>,
+    next_row_id: u32,
+}
+
+/// A secondary index mapping indexed values to `RoaringBitmap`s of dense
+/// row-ids instead of `Vec<PrimaryKey>`, for columns where high cardinality
+/// or heavy duplication would make a plain `Vec` per value wasteful. Row-ids
+/// are assigned on first sight of a primary key and reused across inserts,
+/// kept in `pk_to_id`/`id_to_pk` so `find` can still resolve back to the
+/// `PrimaryKey`s callers expect.
+#[derive(Debug)]
+pub struct RoaringIndex {
+    name: String,
+    data: RoaringIndexData,
+    file_path: PathBuf,
+}
+
+impl RoaringIndex {
+    /// Creates (or loads, if its file already exists) a `RoaringIndex` named
+    /// `name` persisted under `base_path`, mirroring `HashIndex::new`.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if an existing index file can't be read back.
+    pub fn new(name: String, base_path: &Path) -> Result<Self, OxidbError> {
+        let mut file_path = base_path.to_path_buf();
+        file_path.push(format!("{name}.{DEFAULT_INDEX_FILE_EXTENSION}"));
+
+        let mut index = Self { name, data: RoaringIndexData::default(), file_path };
+        if index.file_path.exists() {
+            index.load()?;
+        }
+        Ok(index)
+    }
+
+    fn row_id_for(&mut self, primary_key: &PrimaryKey) -> u32 {
+        if let Some(&id) = self.data.pk_to_id.get(primary_key) {
+            return id;
+        }
+        let id = self.data.next_row_id;
+        self.data.next_row_id += 1;
+        self.data.pk_to_id.insert(primary_key.clone(), id);
+        self.data.id_to_pk.insert(id, primary_key.clone());
+        id
+    }
+}
+
+impl Index for RoaringIndex {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn insert(&mut self, value: &Value, primary_key: &PrimaryKey) -> Result<(), OxidbError> {
+        let id = self.row_id_for(primary_key);
+        self.data.postings.entry(value.clone()).or_default().insert(id);
+        Ok(())
+    }
+
+    fn delete(
+        &mut self,
+        value: &Value,
+        primary_key: Option<&PrimaryKey>,
+    ) -> Result<(), OxidbError> {
+        match primary_key {
+            Some(pk) => {
+                if let Some(&id) = self.data.pk_to_id.get(pk) {
+                    if let Some(bitmap) = self.data.postings.get_mut(value) {
+                        bitmap.remove(id);
+                        if bitmap.is_empty() {
+                            self.data.postings.remove(value);
+                        }
+                    }
+                }
+            }
+            None => {
+                self.data.postings.remove(value);
+            }
+        }
+        Ok(())
+    }
+
+    fn find(&self, value: &Value) -> Result<Option<Vec<PrimaryKey>>, OxidbError> {
+        match self.data.postings.get(value) {
+            Some(bitmap) if !bitmap.is_empty() => Ok(Some(
+                bitmap.iter().filter_map(|id| self.data.id_to_pk.get(&id).cloned()).collect(),
+            )),
+            _ => Ok(None),
+        }
+    }
+
+    fn cardinality(&self, value: &Value) -> Result<u64, OxidbError> {
+        Ok(self.data.postings.get(value).map_or(0, RoaringBitmap::cardinality))
+    }
+
+    fn save(&self) -> Result<(), OxidbError> {
+        let file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&self.file_path)
+            .map_err(OxidbError::Io)?;
+        let writer = BufWriter::new(file);
+        bincode::serialize_into(writer, &self.data).map_err(|e| {
+            OxidbError::Serialization(format!(
+                "Failed to serialize roaring index '{}': {e}",
+                self.name
+            ))
+        })
+    }
+
+    fn load(&mut self) -> Result<(), OxidbError> {
+        if !self.file_path.exists() {
+            self.data = RoaringIndexData::default();
+            return Ok(());
+        }
+        let file = File::open(&self.file_path).map_err(OxidbError::Io)?;
+        if file.metadata().map_err(OxidbError::Io)?.len() == 0 {
+            self.data = RoaringIndexData::default();
+            return Ok(());
+        }
+        let reader = BufReader::new(file);
+        self.data = bincode::deserialize_from(reader).map_err(|e| {
+            OxidbError::Deserialization(format!(
+                "Failed to deserialize roaring index '{}': {e}",
+                self.name
+            ))
+        })?;
+        Ok(())
+    }
+
+    fn update(
+        &mut self,
+        old_value_for_index: &Value,
+        new_value_for_index: &Value,
+        primary_key: &PrimaryKey,
+    ) -> Result<(), OxidbError> {
+        if old_value_for_index == new_value_for_index {
+            return self.insert(new_value_for_index, primary_key);
+        }
+        self.delete(old_value_for_index, Some(primary_key))?;
+        self.insert(new_value_for_index, primary_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn val(s: &str) -> Value {
+        s.as_bytes().to_vec()
+    }
+
+    fn pk(s: &str) -> PrimaryKey {
+        s.as_bytes().to_vec()
+    }
+
+    #[test]
+    fn insert_find_delete_update_roundtrip() -> Result<(), OxidbError> {
+        let dir = tempdir().expect("tempdir");
+        let mut index = RoaringIndex::new("idx_roaring_test".to_string(), dir.path())?;
+
+        index.insert(&val("red"), &pk("pk1"))?;
+        index.insert(&val("red"), &pk("pk2"))?;
+        index.insert(&val("blue"), &pk("pk3"))?;
+
+        assert_eq!(index.cardinality(&val("red"))?, 2);
+        assert_eq!(index.cardinality(&val("blue"))?, 1);
+        assert_eq!(index.cardinality(&val("green"))?, 0);
+
+        let reds = index.find(&val("red"))?.expect("red should be found");
+        assert_eq!(reds.len(), 2);
+        assert!(reds.contains(&pk("pk1")));
+        assert!(reds.contains(&pk("pk2")));
+
+        index.delete(&val("red"), Some(&pk("pk1")))?;
+        assert_eq!(index.find(&val("red"))?, Some(vec![pk("pk2")]));
+        assert_eq!(index.cardinality(&val("red"))?, 1);
+
+        index.update(&val("blue"), &val("green"), &pk("pk3"))?;
+        assert!(index.find(&val("blue"))?.is_none());
+        assert_eq!(index.find(&val("green"))?, Some(vec![pk("pk3")]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn save_and_load_roundtrip_preserves_postings() -> Result<(), OxidbError> {
+        let dir = tempdir().expect("tempdir");
+        {
+            let mut index = RoaringIndex::new("idx_roaring_persist".to_string(), dir.path())?;
+            index.insert(&val("x"), &pk("pk1"))?;
+            index.insert(&val("x"), &pk("pk2"))?;
+            index.save()?;
+        }
+
+        let reloaded = RoaringIndex::new("idx_roaring_persist".to_string(), dir.path())?;
+        let found = reloaded.find(&val("x"))?.expect("x should survive reload");
+        assert_eq!(found.len(), 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+//! Roaring-bitmap-backed posting-list index.
+//!
+//! Unlike `HashIndex`, which maps each indexed value to a `Vec<PrimaryKey>`,
+//! `RoaringIndex` maps each value to a `RoaringBitmap` of dense `u32` row-ids,
+//! with a side table translating row-ids back to primary keys. This bounds
+//! memory for high-cardinality or heavily-duplicated columns and lets
+//! `cardinality()` answer "how many rows hold this value" without decoding
+//! the set of ids at all.
+
+mod bitmap;
+mod index;
+
+pub use bitmap::RoaringBitmap;
+pub use index::RoaringIndex;
@@ -55,6 +55,21 @@ pub trait Index: Debug {
     /// Returns `OxidbError` if the lookup fails.
     fn find(&self, value: &Value) -> Result<Option<Vec<PrimaryKey>>, OxidbError>; // Changed
 
+    /// Counts how many rows hold `value`, without necessarily materializing
+    /// their primary keys.
+    ///
+    /// The default implementation just delegates to `find`, so every index
+    /// type gets a correct (if not maximally cheap) answer for free. Backends
+    /// with a more compact representation of "how many" than "which ones" —
+    /// e.g. a posting list backed by a roaring bitmap, which tracks
+    /// cardinality per container without decoding it — should override this.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if the underlying lookup fails.
+    fn cardinality(&self, value: &Value) -> Result<u64, OxidbError> {
+        Ok(self.find(value)?.map_or(0, |pks| pks.len() as u64))
+    }
+
     /// Saves the index data to persistent storage.
     /// The specific storage mechanism (e.g., file path) should be managed by the
     /// implementing struct.
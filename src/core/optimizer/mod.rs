@@ -69,7 +69,8 @@ impl Optimizer {
                             .iter()
                             .filter_map(|col| match col {
                                 SelectColumn::ColumnName(name) => Some(name.clone()),
-                                SelectColumn::Asterisk => None, // Mixed * and columns not supported yet
+                                // Mixed `*`/aggregate columns alongside plain ones not supported yet
+                                SelectColumn::Asterisk | SelectColumn::AggregateFunction { .. } => None,
                             })
                             .collect();
 
@@ -312,7 +313,9 @@ impl Optimizer {
         // Extract simple equality predicates that can use indexes
         if let Some(simple_pred) = self.extract_indexable_predicate(predicate) {
             // Check if we have an index that can satisfy this predicate
-            if let Some(index_name) = self.find_suitable_index(&simple_pred, index_manager)? {
+            if let Some(index_name) =
+                self.find_suitable_index(table_name, &simple_pred, index_manager)?
+            {
                 return Ok(Some(QueryPlanNode::IndexScan {
                     index_name,
                     table_name: table_name.to_string(),
@@ -357,29 +360,42 @@ impl Optimizer {
         None
     }
 
-    /// Find a suitable index for the given predicate
+    /// Find a suitable index for the given predicate over `table_name`.
+    ///
+    /// Checks, in order: a plain `idx_{table_name}_{predicate.column}`
+    /// column index (the naming convention `CreateIndex`, `check_uniqueness`
+    /// and `handle_create_index`'s backfill all already use), then a
+    /// registered functional index (`CreateFunctionalIndex`) whose
+    /// expression's `IndexExpr::canonical_string` matches `predicate.column`
+    /// - the Command-only convention a predicate like
+    /// `date_trunc_day(created_at) = ...` is expressed through, since the SQL
+    /// AST has no function-call expression syntax to parse that into
+    /// directly (see `crate::core::indexing::expression`).
     fn find_suitable_index(
         &self,
+        table_name: &str,
         predicate: &SimplePredicate,
         index_manager: &std::sync::Arc<
             std::sync::RwLock<crate::core::indexing::manager::IndexManager>,
         >,
     ) -> Result<Option<String>, crate::core::common::error::OxidbError> {
-        let _index_manager_guard = index_manager.read().map_err(|e| {
+        if predicate.operator != "=" {
+            return Ok(None);
+        }
+
+        let manager = index_manager.read().map_err(|e| {
             crate::core::common::error::OxidbError::LockTimeout(format!(
                 "Failed to acquire read lock on index manager: {e}"
             ))
         })?;
 
-        if predicate.operator == "=" {
-            // Look for a column-specific index first
-            // Index names follow the pattern: idx_{table}_{column}
-            // We need to extract the table name from context, but for now we'll skip this optimization
-            // and return None to force table scan with filtering
+        let column_index_name = format!("idx_{table_name}_{}", predicate.column);
+        if manager.get_index(&column_index_name).is_some() {
+            return Ok(Some(column_index_name));
+        }
 
-            // TODO: Implement proper column-specific index lookup
-            // For now, don't use default_value_index for column-specific queries
-            // as it's designed for full-row indexing, not individual column values
+        if let Some(index_name) = manager.find_functional_index(table_name, &predicate.column) {
+            return Ok(Some(index_name.to_string()));
         }
 
         Ok(None)
@@ -1,6 +1,8 @@
 //! Performance analytics and reporting
 
+use super::events::{EventCategory, ProfileEvent};
 use super::metrics::PerformanceMetrics;
+use std::collections::HashMap;
 use std::time::Duration;
 use std::fmt;
 
@@ -38,9 +40,26 @@ impl PerformanceAnalyzer {
             storage_analysis,
             bottlenecks,
             recommendations: self.generate_recommendations(metrics),
+            event_summary: None,
         }
     }
 
+    /// Like [`Self::analyze`], but also summarizes `events` (a
+    /// [`super::events::ProfileEventLog`] snapshot) into the report's
+    /// [`EventSummary`]: time spent per [`EventCategory`], and the index
+    /// cache hit rate computed from real `IndexCacheHit`/`IndexCacheMiss`
+    /// counts instead of a hard-coded estimate.
+    #[must_use]
+    pub fn analyze_with_events(
+        &self,
+        metrics: &PerformanceMetrics,
+        events: &[ProfileEvent],
+    ) -> PerformanceReport {
+        let mut report = self.analyze(metrics);
+        report.event_summary = Some(summarize_events(events));
+        report
+    }
+
     /// Analyze query performance
     fn analyze_queries(&self, metrics: &PerformanceMetrics) -> QueryAnalysis {
         let query_metrics = &metrics.query_metrics;
@@ -198,6 +217,67 @@ pub struct PerformanceReport {
     pub bottlenecks: BottleneckAnalysis,
     /// Performance recommendations
     pub recommendations: Vec<String>,
+    /// Rolled-up `ProfileEvent`s, present when the report was built via
+    /// [`PerformanceAnalyzer::analyze_with_events`].
+    pub event_summary: Option<EventSummary>,
+}
+
+/// A summary of a [`super::events::ProfileEventLog`] snapshot: time spent per
+/// [`EventCategory`], and the index cache hit rate.
+#[derive(Debug, Clone, Default)]
+pub struct EventSummary {
+    /// Total `QueryEnd`/`GenericActivityEnd` duration attributed to each
+    /// category its matching `QueryStart`/`GenericActivityStart` declared.
+    pub time_by_category: HashMap<EventCategory, Duration>,
+    /// Number of `IndexCacheHit` events.
+    pub index_cache_hits: u64,
+    /// Number of `IndexCacheMiss` events.
+    pub index_cache_misses: u64,
+    /// `index_cache_hits / (index_cache_hits + index_cache_misses)`, or `0.0`
+    /// if neither occurred.
+    pub cache_hit_rate: f64,
+}
+
+/// Rolls `events` up into an [`EventSummary`]: a `QueryEnd`/`GenericActivityEnd`
+/// is attributed to the category of the most recent matching (by name)
+/// `QueryStart`/`GenericActivityStart` that hasn't been matched yet.
+#[must_use]
+fn summarize_events(events: &[ProfileEvent]) -> EventSummary {
+    let mut summary = EventSummary::default();
+    let mut open_categories: HashMap<String, EventCategory> = HashMap::new();
+
+    for event in events {
+        match event {
+            ProfileEvent::QueryStart { query_text, category, .. } => {
+                open_categories.insert(query_text.clone(), *category);
+            }
+            ProfileEvent::GenericActivityStart { label, category, .. } => {
+                open_categories.insert(label.clone(), *category);
+            }
+            ProfileEvent::QueryEnd { query_text, duration } => {
+                if let Some(category) = open_categories.remove(query_text) {
+                    *summary.time_by_category.entry(category).or_insert(Duration::ZERO) += *duration;
+                }
+            }
+            ProfileEvent::GenericActivityEnd { label, duration } => {
+                if let Some(category) = open_categories.remove(label) {
+                    *summary.time_by_category.entry(category).or_insert(Duration::ZERO) += *duration;
+                }
+            }
+            ProfileEvent::IndexCacheHit { .. } => summary.index_cache_hits += 1,
+            ProfileEvent::IndexCacheMiss { .. } => summary.index_cache_misses += 1,
+            ProfileEvent::AggregateIndexRewrite { .. } | ProfileEvent::AggregateIndexMiss { .. } => {}
+        }
+    }
+
+    let total_cache_lookups = summary.index_cache_hits + summary.index_cache_misses;
+    summary.cache_hit_rate = if total_cache_lookups > 0 {
+        summary.index_cache_hits as f64 / total_cache_lookups as f64
+    } else {
+        0.0
+    };
+
+    summary
 }
 
 /// Query performance analysis
@@ -352,7 +432,28 @@ impl fmt::Display for PerformanceReport {
                 writeln!(f, "  • {}", recommendation)?;
             }
         }
-        
+
+        // Profile events (only present via `analyze_with_events`)
+        if let Some(summary) = &self.event_summary {
+            writeln!(f)?;
+            writeln!(f, "Key Insights:")?;
+            writeln!(
+                f,
+                "  Index Cache Hit Rate: {:.1}% ({} hit(s), {} miss(es))",
+                summary.cache_hit_rate * 100.0,
+                summary.index_cache_hits,
+                summary.index_cache_misses
+            )?;
+            if !summary.time_by_category.is_empty() {
+                writeln!(f, "  Time by Category:")?;
+                let mut categories: Vec<_> = summary.time_by_category.iter().collect();
+                categories.sort_by_key(|(category, _)| format!("{category:?}"));
+                for (category, duration) in categories {
+                    writeln!(f, "    {category:?}: {duration:?}")?;
+                }
+            }
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,191 @@
+//! Discrete profiling events recorded while a connection executes queries,
+//! modeled on rustc's self-profiler: an append-only stream of events a caller
+//! can inspect or export directly, rather than only a single pre-aggregated
+//! report.
+
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Which phase of query processing a [`ProfileEvent`] belongs to, so a
+/// summarizer can roll up time spent per category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EventCategory {
+    /// Tokenizing/parsing SQL text into an AST.
+    Parsing,
+    /// Binding and planning a parsed statement.
+    Planning,
+    /// Executing a query plan's operators.
+    Execution,
+    /// Scanning an index, as opposed to a full table scan.
+    IndexScan,
+    /// Evaluating a join.
+    Join,
+    /// Evaluating a vector similarity/distance function.
+    VectorSearch,
+    /// Reading from or writing to storage.
+    IO,
+}
+
+/// A discrete profiling event appended to a connection's [`ProfileEventLog`]
+/// while `Connection::enable_performance_monitoring` is on.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ProfileEvent {
+    /// A query began executing.
+    QueryStart {
+        /// The query's SQL text.
+        query_text: String,
+        /// The phase this query's time is attributed to.
+        category: EventCategory,
+        /// Nanoseconds since the Unix epoch.
+        timestamp_nanos: u128,
+    },
+    /// A query finished executing.
+    QueryEnd {
+        /// The query's SQL text, matching the `QueryStart` that opened it.
+        query_text: String,
+        /// Wall-clock time the query took.
+        duration: Duration,
+    },
+    /// A query resolved a predicate via an index scan instead of a full
+    /// table scan.
+    IndexCacheHit {
+        /// The index that served the scan.
+        index_name: String,
+    },
+    /// A query fell back to a full table scan (no matching index was used).
+    IndexCacheMiss {
+        /// The table that was scanned in full.
+        table_name: String,
+    },
+    /// A `GROUP BY` query was rewritten to scan a `CREATE AGGREGATE INDEX`
+    /// instead of re-aggregating every row of the source table.
+    AggregateIndexRewrite {
+        /// Identifies the aggregate index that served the query as
+        /// `<table>.<group column>`, not by its registered name.
+        index_name: String,
+    },
+    /// A `GROUP BY` query had no matching aggregate index, so it fell back to
+    /// scanning and re-aggregating every row of the source table.
+    AggregateIndexMiss {
+        /// The table that was scanned and re-aggregated.
+        table_name: String,
+    },
+    /// A named sub-phase of query processing began, e.g. `"planning"` or `"io"`.
+    GenericActivityStart {
+        /// The phase's label.
+        label: String,
+        /// The phase's category.
+        category: EventCategory,
+        /// Nanoseconds since the Unix epoch.
+        timestamp_nanos: u128,
+    },
+    /// A named sub-phase of query processing finished.
+    GenericActivityEnd {
+        /// The phase's label, matching the `GenericActivityStart` that opened it.
+        label: String,
+        /// Wall-clock time the phase took.
+        duration: Duration,
+    },
+}
+
+/// The current time as nanoseconds since the Unix epoch, clamping to `0` if
+/// the system clock is set before it.
+#[must_use]
+pub fn now_nanos() -> u128 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos()).unwrap_or(0)
+}
+
+/// An append-only log of [`ProfileEvent`]s, guarded by a plain `Mutex` rather
+/// than a `RwLock`: nearly every access here is a push onto the same `Vec`
+/// (reads are occasional snapshots), so there's no read-heavy workload to
+/// justify a reader/writer lock, and a
+/// single exclusive lock keeps the hot append path simple and low-overhead.
+#[derive(Debug, Default)]
+pub struct ProfileEventLog {
+    events: std::sync::Mutex<Vec<ProfileEvent>>,
+}
+
+impl ProfileEventLog {
+    /// Creates an empty event log.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `event` to the log.
+    pub fn record(&self, event: ProfileEvent) {
+        if let Ok(mut events) = self.events.lock() {
+            events.push(event);
+        }
+    }
+
+    /// A snapshot of every event recorded so far, in recording order.
+    #[must_use]
+    pub fn snapshot(&self) -> Vec<ProfileEvent> {
+        self.events.lock().map(|events| events.clone()).unwrap_or_default()
+    }
+
+    /// Discards every recorded event.
+    pub fn clear(&self) {
+        if let Ok(mut events) = self.events.lock() {
+            events.clear();
+        }
+    }
+}
+
+/// Categorizes `sql` for the `category` field of a
+/// `QueryStart`/`GenericActivityStart` event: `Join` if it contains ` JOIN `,
+/// else `VectorSearch` if it calls a vector distance/similarity function
+/// anywhere, else by its leading keyword.
+#[must_use]
+pub fn infer_query_category(sql: &str) -> EventCategory {
+    let upper = sql.trim_start().to_uppercase();
+    if upper.contains(" JOIN ") {
+        EventCategory::Join
+    } else if upper.contains("COSINE_SIMILARITY")
+        || upper.contains("DOT_PRODUCT")
+        || upper.contains("EUCLIDEAN_DISTANCE")
+        || upper.contains("MANHATTAN_DISTANCE")
+    {
+        EventCategory::VectorSearch
+    } else if upper.starts_with("SELECT") {
+        EventCategory::Execution
+    } else if upper.starts_with("CREATE") || upper.starts_with("DROP") || upper.starts_with("ALTER") {
+        EventCategory::Planning
+    } else {
+        EventCategory::Execution
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log_records_in_order_and_can_be_cleared() {
+        let log = ProfileEventLog::new();
+        log.record(ProfileEvent::IndexCacheHit { index_name: "idx_a".to_string() });
+        log.record(ProfileEvent::IndexCacheMiss { table_name: "posts".to_string() });
+
+        let snapshot = log.snapshot();
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot[0], ProfileEvent::IndexCacheHit { index_name: "idx_a".to_string() });
+
+        log.clear();
+        assert!(log.snapshot().is_empty());
+    }
+
+    #[test]
+    fn categorizes_join_and_vector_queries() {
+        assert_eq!(
+            infer_query_category("SELECT * FROM a JOIN b ON a.id = b.a_id"),
+            EventCategory::Join
+        );
+        assert_eq!(
+            infer_query_category("SELECT COSINE_SIMILARITY(v1, v2) FROM items"),
+            EventCategory::VectorSearch
+        );
+        assert_eq!(infer_query_category("CREATE TABLE t (id INTEGER)"), EventCategory::Planning);
+        assert_eq!(infer_query_category("SELECT * FROM t"), EventCategory::Execution);
+    }
+}
@@ -1,14 +1,16 @@
 //! Performance Monitoring and Analysis Framework for `OxiDB`
 
 pub mod metrics;
-pub mod profiler; 
+pub mod profiler;
 pub mod analytics;
 pub mod monitor;
+pub mod events;
 
 pub use metrics::{PerformanceMetrics, QueryMetrics};
 pub use profiler::{PerformanceProfiler, ProfiledOperation};
 pub use analytics::{PerformanceAnalyzer, PerformanceReport};
 pub use monitor::{PerformanceMonitor, MonitoringConfig};
+pub use events::{EventCategory, ProfileEvent, ProfileEventLog};
 
 use std::time::Duration;
 use std::sync::{Arc, RwLock};
@@ -24,6 +26,11 @@ pub struct PerformanceContext {
     pub monitor: Arc<RwLock<PerformanceMonitor>>,
     /// Configuration for monitoring behavior
     pub config: MonitoringConfig,
+    /// Append-only log of discrete profiling events (`ProfileEvent::QueryStart`/
+    /// `IndexCacheHit`/etc.), populated while `config.enable_profiling` is on.
+    /// `get_performance_report` summarizes this into `PerformanceReport`'s
+    /// category breakdown and cache hit rate.
+    pub events: Arc<ProfileEventLog>,
 }
 
 impl PerformanceContext {
@@ -34,6 +41,7 @@ impl PerformanceContext {
             profiler: Arc::new(RwLock::new(PerformanceProfiler::new())),
             monitor: Arc::new(RwLock::new(PerformanceMonitor::new())),
             config: MonitoringConfig::default(),
+            events: Arc::new(ProfileEventLog::new()),
         }
     }
 
@@ -45,6 +53,14 @@ impl PerformanceContext {
         Ok(())
     }
 
+    /// Appends `event` to [`Self::events`] if `config.enable_profiling` is on;
+    /// otherwise a no-op, so there's no recording overhead while profiling is off.
+    pub fn record_event(&self, event: ProfileEvent) {
+        if self.config.enable_profiling {
+            self.events.record(event);
+        }
+    }
+
     /// Generate a comprehensive performance report
     pub fn generate_report(&self) -> Result<PerformanceReport, crate::core::common::OxidbError> {
         let metrics = self.metrics.read()
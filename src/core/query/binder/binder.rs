@@ -1,5 +1,6 @@
 // src/core/query/binder/binder.rs
 use super::expression::{bind_expression_entry, BoundExpression};
+use super::vector_metrics::{VectorMetric, VectorMetricRegistry};
 use crate::core::common::types::{ColumnDef, DataType, Schema}; // Added DataType back
 use crate::core::query::sql::ast::{AstExpression, Statement as AstStatement};
 use thiserror::Error;
@@ -41,17 +42,28 @@ pub struct BoundStatement {
 #[derive(Debug)]
 pub struct Binder<'a> {
     schema: Option<&'a Schema>,
+    vector_metrics: VectorMetricRegistry,
 }
 
 impl<'a> Binder<'a> {
     pub fn new(schema: Option<&'a Schema>) -> Self {
-        Binder { schema }
+        Binder { schema, vector_metrics: VectorMetricRegistry::with_defaults() }
     }
 
     pub fn bind_expression(&mut self, expr: &AstExpression) -> Result<BoundExpression, BindError> {
         bind_expression_entry(self, expr)
     }
 
+    pub fn vector_metrics(&self) -> &VectorMetricRegistry {
+        &self.vector_metrics
+    }
+
+    /// Registers `metric` under `name`, so a vector function call to `name`
+    /// dispatches to it without needing a new match arm in `bind_function_call`.
+    pub fn register_vector_metric(&mut self, name: impl Into<String>, metric: VectorMetric) {
+        self.vector_metrics.register(name, metric);
+    }
+
     pub fn bind_statement(&mut self, statement: &AstStatement) -> Result<BoundStatement, BindError> {
         let stmt_type = match statement {
             AstStatement::Select(_) => "Select",
@@ -0,0 +1,95 @@
+// src/core/query/binder/cache.rs
+
+//! An in-memory attribute/schema cache [`Binder`](super::Binder) can consult
+//! instead of scanning a [`Schema`]'s `Vec<ColumnDef>` for every column and
+//! function-name resolution - Mentat's `CachedAttributes`/`UpdateableCache`
+//! traits for the same problem (keeping a Datalog query planner's attribute
+//! lookups off the hot path of re-walking the catalog).
+
+use crate::core::types::schema::Schema;
+use crate::core::types::DataType;
+use std::collections::HashMap;
+
+/// A function name's expected parameter types and return type, looked up by
+/// [`BinderCache::lookup_function_signature`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionSignature {
+    /// The function's parameter types, in declaration order.
+    pub param_types: Vec<DataType>,
+    /// The function's return type.
+    pub return_type: DataType,
+}
+
+/// Read-only attribute lookups a [`Binder`](super::Binder) can serve from
+/// memory instead of scanning a [`Schema`].
+pub trait BinderCache {
+    /// The declared type of `table.name`, and its vector dimension if it's a
+    /// `DataType::Vector` column, or `None` if `table`/`name` isn't cached.
+    fn lookup_column(&self, table: &str, name: &str) -> Option<(DataType, Option<u32>)>;
+
+    /// `name`'s registered function signature, or `None` if it isn't cached.
+    fn lookup_function_signature(&self, name: &str) -> Option<FunctionSignature>;
+}
+
+/// A [`BinderCache`] the catalog can keep up to date as tables are created,
+/// dropped, or altered, rather than rebuilding the whole cache from scratch.
+pub trait UpdateableCache: BinderCache {
+    /// Repopulates `table`'s cached column entries from `schema`, replacing
+    /// whatever was cached for it before. No DDL handler calls this; the
+    /// cache's owner calls it after `CREATE TABLE`/`ALTER TABLE`.
+    fn update(&mut self, table: &str, schema: &Schema);
+
+    /// Drops every cached entry for `table`. No DDL handler calls this; the
+    /// cache's owner calls it after `DROP TABLE`.
+    fn invalidate(&mut self, table: &str);
+}
+
+/// The default [`UpdateableCache`]: a flat `HashMap` of `(table, column) ->
+/// (type, dimension)` plus a separate map of registered function signatures.
+#[derive(Debug, Clone, Default)]
+pub struct SchemaCache {
+    columns: HashMap<(String, String), (DataType, Option<u32>)>,
+    functions: HashMap<String, FunctionSignature>,
+}
+
+impl SchemaCache {
+    /// Creates an empty cache.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `name`'s signature so [`BinderCache::lookup_function_signature`]
+    /// can resolve it in O(1). Replaces any existing signature for `name`.
+    pub fn register_function(&mut self, name: impl Into<String>, signature: FunctionSignature) {
+        self.functions.insert(name.into(), signature);
+    }
+}
+
+impl BinderCache for SchemaCache {
+    fn lookup_column(&self, table: &str, name: &str) -> Option<(DataType, Option<u32>)> {
+        self.columns.get(&(table.to_string(), name.to_string())).cloned()
+    }
+
+    fn lookup_function_signature(&self, name: &str) -> Option<FunctionSignature> {
+        self.functions.get(name).cloned()
+    }
+}
+
+impl UpdateableCache for SchemaCache {
+    fn update(&mut self, table: &str, schema: &Schema) {
+        self.invalidate(table);
+        for column in &schema.columns {
+            let dimension = match &column.data_type {
+                DataType::Vector(vector) => Some(vector.0.dimension),
+                _ => None,
+            };
+            self.columns
+                .insert((table.to_string(), column.name.clone()), (column.data_type.clone(), dimension));
+        }
+    }
+
+    fn invalidate(&mut self, table: &str) {
+        self.columns.retain(|(cached_table, _), _| cached_table != table);
+    }
+}
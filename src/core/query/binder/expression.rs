@@ -153,62 +153,6 @@ fn bind_function_call(
     }
 
     match upper_name.as_str() {
-        "COSINE_SIMILARITY" | "DOT_PRODUCT" => {
-            if bound_args.len() != 2 {
-                return Err(BindError::IncorrectArgumentCount {
-                    name: upper_name,
-                    expected: 2,
-                    got: bound_args.len(),
-                });
-            }
-
-            let arg1_type = bound_args[0].get_type();
-            let arg2_type = bound_args[1].get_type();
-
-            let dim1_opt = match arg1_type {
-                DataType::Vector(d) => d,
-                _ => return Err(BindError::TypeMismatch {
-                    name: upper_name.clone(),
-                    arg_index: 0,
-                    expected_type: "Vector".to_string(),
-                    actual_type: format!("{:?}", arg1_type),
-                }),
-            };
-
-            let dim2_opt = match arg2_type {
-                DataType::Vector(d) => d,
-                _ => return Err(BindError::TypeMismatch {
-                    name: upper_name.clone(),
-                    arg_index: 1,
-                    expected_type: "Vector".to_string(),
-                    actual_type: format!("{:?}", arg2_type),
-                }),
-            };
-
-            // Dimension check
-            match (dim1_opt, dim2_opt) {
-                (Some(d1), Some(d2)) => {
-                    if d1 != d2 {
-                        return Err(BindError::VectorDimensionMismatch {
-                            name: upper_name,
-                            dim1: d1,
-                            dim2: d2,
-                        });
-                    }
-                }
-                (None, Some(_)) => return Err(BindError::UnknownVectorDimension { name: upper_name, arg_index: 0 }),
-                (Some(_), None) => return Err(BindError::UnknownVectorDimension { name: upper_name, arg_index: 1 }),
-                (None, None) => {
-                    // Both dimensions unknown. Allow for now, executor must handle.
-                }
-            }
-
-            Ok(BoundExpression::FunctionCall {
-                name: upper_name,
-                args: bound_args,
-                return_type: DataType::Float64, // Corrected return type
-            })
-        }
         "COUNT" | "SUM" | "AVG" | "MIN" | "MAX" => {
             let return_type = match upper_name.as_str() {
                 "COUNT" => DataType::Integer,
@@ -222,6 +166,14 @@ fn bind_function_call(
                 return_type,
             })
         }
-        _ => Err(BindError::FunctionNotFound { name: upper_name }),
+        _ => {
+            // Not an aggregate: fall back to the binder's vector metric
+            // registry, which covers COSINE_SIMILARITY/DOT_PRODUCT and any
+            // other metric registered via `Binder::register_vector_metric`,
+            // and reports FunctionNotFound itself if `upper_name` isn't one.
+            let arg_types: Vec<DataType> = bound_args.iter().map(BoundExpression::get_type).collect();
+            let return_type = binder.vector_metrics().bind_call(&upper_name, &arg_types)?;
+            Ok(BoundExpression::FunctionCall { name: upper_name, args: bound_args, return_type })
+        }
     }
 }
@@ -1,59 +1,563 @@
 // src/core/query/binder/mod.rs
 
-// Content from binder.rs
-use crate::core::query::sql::ast::Statement as AstStatement;
+//! Semantic analysis (name resolution + type-checking) for a parsed SQL
+//! [`AstStatement`].
+//!
+//! [`Binder::bind_statement`] resolves every `ColumnIdentifier` a `SELECT`/
+//! `INSERT`/`UPDATE`/`DELETE` references against the single [`Schema`] the
+//! binder was constructed with, verifies that projected/assigned columns
+//! exist, type-checks assignments and `WHERE` predicates against those
+//! columns' declared types, and produces a [`BoundStatement`] - failing fast
+//! with [`BindError::ColumnNotFound`]/[`BindError::TypeMismatch`] the moment
+//! a reference can't be resolved, the same way [`super::executor::QueryExecutor`]'s
+//! own `DESCRIBE` column resolution does for its (multi-table) case.
+//!
+//! The binder only has visibility into the one schema it holds, so a
+//! `SELECT` with a `JOIN` - which needs more than one table's schema to
+//! resolve - isn't bound yet; see [`BindError::NotImplemented`].
+//!
+//! A [`Binder`] constructed via [`Binder::with_cache`] consults a
+//! [`cache::BinderCache`] before falling back to a linear scan of the
+//! schema's columns, so column types for any table the cache holds resolve
+//! in O(1). Binding only reads the cache; it never populates it.
+
+pub mod cache;
+
+use crate::core::query::sql::ast::{
+    AstExpressionValue, AstLiteralValue, ConditionTree, DeleteStatement, InsertStatement,
+    SelectColumn, SelectStatement, Statement as AstStatement, UpdateStatement,
+};
+use crate::core::types::schema::Schema;
+use crate::core::types::{DataType, HashableVectorData, OrderedFloat, VectorData};
+use cache::BinderCache;
 use std::fmt;
 
-#[derive(Debug)]
+/// Why [`Binder::bind_statement`] couldn't bind a statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BindError {
+    /// `bind_statement` doesn't yet handle this statement shape - e.g. a
+    /// `CREATE TABLE`, or a `SELECT` with a `JOIN` (the binder only has
+    /// visibility into the single schema it was constructed with).
     NotImplemented { statement_type: String },
+    /// A referenced column isn't declared in the bound schema.
+    ColumnNotFound { name: String },
+    /// A literal assigned to, or compared against, a column is the wrong type for it.
+    TypeMismatch { column: String, expected: DataType, found: &'static str },
+    /// `bind_statement` was called without a schema to resolve against.
+    NoSchema,
+    /// A `GROUND` row has a different number of values than the relation's column list.
+    RaggedRows { row: usize, expected: usize, found: usize },
+    /// Two `GROUND` rows' literal vectors in the same column disagree on dimension.
+    VectorDimensionMismatch { column: String, expected: u32, found: u32 },
 }
 
 impl fmt::Display for BindError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::NotImplemented { statement_type } => {
-                write!(f, "Binding not yet implemented for statement: {}", statement_type)
+                write!(f, "Binding not yet implemented for statement: {statement_type}")
+            }
+            Self::ColumnNotFound { name } => write!(f, "Column '{name}' not found in schema"),
+            Self::TypeMismatch { column, expected, found } => {
+                write!(f, "Type mismatch for column '{column}': expected {expected:?}, found {found}")
+            }
+            Self::NoSchema => write!(f, "No schema available to bind against"),
+            Self::RaggedRows { row, expected, found } => {
+                write!(f, "Row {row} has {found} value(s), expected {expected} to match the relation's other rows")
             }
+            Self::VectorDimensionMismatch { column, expected, found } => write!(
+                f,
+                "Column '{column}' mixes vectors of dimension {expected} and {found}"
+            ),
         }
     }
 }
 
 impl std::error::Error for BindError {}
 
-#[derive(Debug)]
-pub struct BoundStatement {
-    pub message: String,
+/// A [`BindError`] together with the stack of enclosing constructs that were
+/// being bound when it occurred (innermost first), e.g. `"comparison on
+/// column 'id'"`, then `"WHERE clause"`, then `"statement"`. Built up via
+/// [`BindContext::while_binding`] as the error unwinds through recursive
+/// binding calls, so a deeply nested expression reports more than a bare
+/// leaf [`BindError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindContext {
+    kind: BindError,
+    frames: Vec<String>,
 }
 
-#[derive(Debug)]
-pub struct Binder {}
+impl BindContext {
+    /// Adds `frame` to the context stack as this error unwinds through an
+    /// enclosing construct.
+    #[must_use]
+    pub fn while_binding(mut self, frame: impl Into<String>) -> Self {
+        self.frames.push(frame.into());
+        self
+    }
+
+    /// The underlying [`BindError`], for programmatic matching (e.g.
+    /// `assert_eq!(result.unwrap_err().kind(), &BindError::ColumnNotFound { .. })`).
+    #[must_use]
+    pub const fn kind(&self) -> &BindError {
+        &self.kind
+    }
 
-impl Binder {
+    /// The stack of enclosing constructs being bound when this error
+    /// occurred, innermost first.
     #[must_use]
-    pub const fn new() -> Self {
-        Self {}
+    pub fn frames(&self) -> &[String] {
+        &self.frames
+    }
+}
+
+impl fmt::Display for BindContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+        for frame in &self.frames {
+            write!(f, ", while binding {frame}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for BindContext {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.kind)
+    }
+}
+
+impl From<BindError> for BindContext {
+    fn from(kind: BindError) -> Self {
+        Self { kind, frames: Vec::new() }
+    }
+}
+
+/// A `ColumnIdentifier` resolved against the bound [`Schema`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoundColumn {
+    /// The column's name, as declared in the schema.
+    pub name: String,
+    /// The column's declared type.
+    pub data_type: DataType,
+}
+
+/// A [`ConditionTree`] with every comparison's column resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BoundConditionTree {
+    /// A single `column <op> value` comparison, its column resolved.
+    Comparison { column: BoundColumn, operator: String },
+    /// `left AND right`.
+    And(Box<BoundConditionTree>, Box<BoundConditionTree>),
+    /// `left OR right`.
+    Or(Box<BoundConditionTree>, Box<BoundConditionTree>),
+    /// `NOT inner`.
+    Not(Box<BoundConditionTree>),
+}
+
+/// A semantically-analyzed statement: every `ColumnIdentifier` it references
+/// has been resolved against the bound [`Schema`] and type-checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BoundStatement {
+    /// A bound `SELECT`: its projected columns, target table, and (if any) filter.
+    Select { table: String, projections: Vec<BoundColumn>, filter: Option<BoundConditionTree> },
+    /// A bound `INSERT`: its target table and the columns being populated, in
+    /// the order they're assigned (the schema's column order, if the
+    /// statement didn't list its own).
+    Insert { table: String, columns: Vec<BoundColumn> },
+    /// A bound `UPDATE`: its target table, assigned columns, and (if any) filter.
+    Update { table: String, assignments: Vec<BoundColumn>, filter: Option<BoundConditionTree> },
+    /// A bound `DELETE`: its target table and (if any) filter.
+    Delete { table: String, filter: Option<BoundConditionTree> },
+}
+
+/// A computed table materialized from literal tuples - OxiDB's analogue of
+/// Mentat's `ground` clause: an inline `VALUES`-style relation the binder
+/// lowers into a schema-carrying relation, so the rest of a query can
+/// resolve columns against it without a temp table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundGroundRelation {
+    /// The relation's columns, in the order given, with their types inferred
+    /// from the literal tuples.
+    pub columns: Vec<BoundColumn>,
+    /// The literal tuples making up the relation's rows.
+    pub rows: Vec<Vec<AstLiteralValue>>,
+}
+
+impl BoundGroundRelation {
+    /// The synthesized schema for this relation, so subsequent clauses can
+    /// resolve columns against it like any other table.
+    #[must_use]
+    pub fn schema(&self) -> Schema {
+        Schema::new(
+            self.columns
+                .iter()
+                .map(|col| Schema::new_column_def(col.name.clone(), col.data_type.clone()))
+                .collect(),
+        )
+    }
+}
+
+/// Resolves a parsed [`AstStatement`] against a single table's [`Schema`].
+pub struct Binder<'a> {
+    schema: Option<&'a Schema>,
+    cache: Option<&'a dyn BinderCache>,
+}
+
+impl fmt::Debug for Binder<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Binder")
+            .field("schema", &self.schema)
+            .field("cache", &self.cache.map(|_| "<dyn BinderCache>"))
+            .finish()
+    }
+}
+
+impl<'a> Binder<'a> {
+    #[must_use]
+    pub const fn new(schema: Option<&'a Schema>) -> Self {
+        Self { schema, cache: None }
+    }
+
+    /// Like [`Self::new`], but consults `cache` for a column's type before
+    /// falling back to a linear scan of the schema.
+    #[must_use]
+    pub const fn with_cache(schema: Option<&'a Schema>, cache: Option<&'a dyn BinderCache>) -> Self {
+        Self { schema, cache }
+    }
+
+    /// The schema this binder resolves `ColumnIdentifier`s against, if any.
+    #[must_use]
+    pub const fn schema(&self) -> Option<&'a Schema> {
+        self.schema
+    }
+
+    /// The attribute cache this binder consults before falling back to a
+    /// schema scan, if any.
+    #[must_use]
+    pub const fn cache(&self) -> Option<&'a dyn BinderCache> {
+        self.cache
+    }
+
+    /// Resolves `statement`'s column references against the bound schema and
+    /// type-checks its assignments/predicates.
+    ///
+    /// # Errors
+    /// Returns a [`BindContext`] wrapping [`BindError::NoSchema`] if this
+    /// binder holds no schema, [`BindError::ColumnNotFound`]/
+    /// [`BindError::TypeMismatch`] if a reference can't be resolved or is the
+    /// wrong type, and [`BindError::NotImplemented`] for statement shapes
+    /// this pass doesn't handle yet (any statement other than `SELECT`/
+    /// `INSERT`/`UPDATE`/`DELETE`, and `SELECT` with a `JOIN`) - call
+    /// [`BindContext::kind`] to recover the leaf error.
+    pub fn bind_statement(&self, statement: &AstStatement) -> Result<BoundStatement, BindContext> {
+        match statement {
+            AstStatement::Select(select) => self.bind_select(select),
+            AstStatement::Insert(insert) => self.bind_insert(insert),
+            AstStatement::Update(update) => self.bind_update(update),
+            AstStatement::Delete(delete) => self.bind_delete(delete),
+            other => {
+                Err(BindContext::from(BindError::NotImplemented { statement_type: statement_type_name(other) }))
+            }
+        }
+        .map_err(|ctx| ctx.while_binding("statement"))
+    }
+
+    fn bind_select(&self, select: &SelectStatement) -> Result<BoundStatement, BindContext> {
+        if !select.joins.is_empty() {
+            return Err(BindContext::from(BindError::NotImplemented {
+                statement_type: "Select with JOIN".to_string(),
+            }));
+        }
+        let schema = self.require_schema()?;
+        let table = select.from_clause.name.as_str();
+        let mut projections = Vec::new();
+        for column in &select.columns {
+            match column {
+                SelectColumn::Asterisk => {
+                    projections.extend(schema.columns.iter().map(|col| BoundColumn {
+                        name: col.name.clone(),
+                        data_type: col.data_type.clone(),
+                    }));
+                }
+                SelectColumn::ColumnName(name) => projections.push(
+                    self.resolve_column(table, name)
+                        .map_err(|e| BindContext::from(e).while_binding(format!("SELECT projection '{name}'")))?,
+                ),
+            }
+        }
+        let filter = select
+            .condition
+            .as_ref()
+            .map(|tree| self.bind_condition_tree(table, tree).map_err(|ctx| ctx.while_binding("WHERE clause")))
+            .transpose()?;
+        Ok(BoundStatement::Select { table: select.from_clause.name.clone(), projections, filter })
     }
 
-    pub fn bind_statement(&self, statement: &AstStatement) -> Result<BoundStatement, BindError> {
-        let stmt_type = match statement {
-            AstStatement::Select(_) => "Select",
-            AstStatement::Update(_) => "Update",
-            AstStatement::CreateTable(_) => "CreateTable",
-            AstStatement::Insert(_) => "Insert",
-            AstStatement::Delete(_) => "Delete", // Added Delete arm
-            AstStatement::DropTable(_) => "DropTable",
-            // The _ arm is unreachable if all AstStatement variants are covered.
-            // If AstStatement is non_exhaustive or has other variants, _ might be needed.
-            // Assuming for now all variants are covered or it's okay for this to be exhaustive.
+    fn bind_insert(&self, insert: &InsertStatement) -> Result<BoundStatement, BindContext> {
+        let schema = self.require_schema()?;
+        let table = insert.table_name.as_str();
+        let columns: Vec<BoundColumn> = match &insert.columns {
+            Some(names) => names
+                .iter()
+                .map(|name| {
+                    self.resolve_column(table, name)
+                        .map_err(|e| BindContext::from(e).while_binding(format!("INSERT column '{name}'")))
+                })
+                .collect::<Result<_, _>>()?,
+            None => schema
+                .columns
+                .iter()
+                .map(|col| BoundColumn { name: col.name.clone(), data_type: col.data_type.clone() })
+                .collect(),
         };
-        eprintln!("[Binder] Attempting to bind statement: {stmt_type:?}");
-        Err(BindError::NotImplemented { statement_type: stmt_type.to_string() })
+        for row in &insert.values {
+            for (column, value) in columns.iter().zip(row) {
+                self.check_value_type(table, column, value)
+                    .map_err(|e| BindContext::from(e).while_binding(format!("INSERT value for column '{}'", column.name)))?;
+            }
+        }
+        Ok(BoundStatement::Insert { table: insert.table_name.clone(), columns })
+    }
+
+    fn bind_update(&self, update: &UpdateStatement) -> Result<BoundStatement, BindContext> {
+        let table = update.source.as_str();
+        let mut assignments = Vec::with_capacity(update.assignments.len());
+        for assignment in &update.assignments {
+            let frame = || format!("UPDATE assignment to '{}'", assignment.column);
+            let column = self
+                .resolve_column(table, &assignment.column)
+                .map_err(|e| BindContext::from(e).while_binding(frame()))?;
+            self.check_value_type(table, &column, &assignment.value)
+                .map_err(|e| BindContext::from(e).while_binding(frame()))?;
+            assignments.push(column);
+        }
+        let filter = update
+            .condition
+            .as_ref()
+            .map(|tree| self.bind_condition_tree(table, tree).map_err(|ctx| ctx.while_binding("WHERE clause")))
+            .transpose()?;
+        Ok(BoundStatement::Update { table: update.source.clone(), assignments, filter })
+    }
+
+    fn bind_delete(&self, delete: &DeleteStatement) -> Result<BoundStatement, BindContext> {
+        let table = delete.table_name.as_str();
+        let filter = delete
+            .condition
+            .as_ref()
+            .map(|tree| self.bind_condition_tree(table, tree).map_err(|ctx| ctx.while_binding("WHERE clause")))
+            .transpose()?;
+        Ok(BoundStatement::Delete { table: delete.table_name.clone(), filter })
+    }
+
+    fn bind_condition_tree(&self, table: &str, tree: &ConditionTree) -> Result<BoundConditionTree, BindContext> {
+        match tree {
+            ConditionTree::Comparison(condition) => {
+                let frame = || format!("comparison on column '{}'", condition.column);
+                let column = self
+                    .resolve_column(table, &condition.column)
+                    .map_err(|e| BindContext::from(e).while_binding(frame()))?;
+                self.check_value_type(table, &column, &condition.value)
+                    .map_err(|e| BindContext::from(e).while_binding(frame()))?;
+                Ok(BoundConditionTree::Comparison { column, operator: condition.operator.clone() })
+            }
+            ConditionTree::And(left, right) => Ok(BoundConditionTree::And(
+                Box::new(self.bind_condition_tree(table, left)?),
+                Box::new(self.bind_condition_tree(table, right)?),
+            )),
+            ConditionTree::Or(left, right) => Ok(BoundConditionTree::Or(
+                Box::new(self.bind_condition_tree(table, left)?),
+                Box::new(self.bind_condition_tree(table, right)?),
+            )),
+            ConditionTree::Not(inner) => {
+                Ok(BoundConditionTree::Not(Box::new(self.bind_condition_tree(table, inner)?)))
+            }
+        }
+    }
+
+    /// Resolves `name` to its declared type on `table`, consulting
+    /// [`Self::cache`] first and falling back to a linear scan of the bound
+    /// schema's columns on a cache miss (or when no cache was given).
+    fn resolve_column(&self, table: &str, name: &str) -> Result<BoundColumn, BindError> {
+        if let Some(cache) = self.cache {
+            if let Some((data_type, _dimension)) = cache.lookup_column(table, name) {
+                return Ok(BoundColumn { name: name.to_string(), data_type });
+            }
+        }
+        let schema = self.require_schema()?;
+        schema
+            .columns
+            .iter()
+            .find(|col| col.name == name)
+            .map(|col| BoundColumn { name: col.name.clone(), data_type: col.data_type.clone() })
+            .ok_or_else(|| BindError::ColumnNotFound { name: name.to_string() })
+    }
+
+    fn require_schema(&self) -> Result<&'a Schema, BindError> {
+        self.schema.ok_or(BindError::NoSchema)
+    }
+
+    /// Lowers a `GROUND`-style inline constant relation into a
+    /// [`BoundGroundRelation`] with a synthesized schema: each column's
+    /// [`DataType`] is inferred from the literals in that position across
+    /// `rows`, skipping `NULL`s, and a homogeneous literal vector column
+    /// infers `DataType::Vector` with the tuples' shared dimension.
+    ///
+    /// # Errors
+    /// Returns a [`BindContext`] wrapping [`BindError::RaggedRows`] if a
+    /// row's width doesn't match `column_names`, and
+    /// [`BindError::VectorDimensionMismatch`] if a column's literal vectors
+    /// disagree on dimension.
+    pub fn bind_ground(
+        &self,
+        column_names: &[String],
+        rows: &[Vec<AstLiteralValue>],
+    ) -> Result<BoundGroundRelation, BindContext> {
+        let width = column_names.len();
+        for (row_index, row) in rows.iter().enumerate() {
+            if row.len() != width {
+                return Err(BindContext::from(BindError::RaggedRows {
+                    row: row_index,
+                    expected: width,
+                    found: row.len(),
+                })
+                .while_binding("GROUND relation"));
+            }
+        }
+        let columns = column_names
+            .iter()
+            .enumerate()
+            .map(|(col_index, name)| {
+                infer_ground_column_type(name, col_index, rows)
+                    .map(|data_type| BoundColumn { name: name.clone(), data_type })
+                    .map_err(|e| BindContext::from(e).while_binding(format!("GROUND column '{name}'")))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(BoundGroundRelation { columns, rows: rows.to_vec() })
+    }
+
+    /// Type-checks `value` against `column`'s declared type. A `ColumnIdentifier`
+    /// only needs to resolve (cross-column comparisons are coerced at
+    /// execution time the same way `QueryExecutor`'s column affinities are);
+    /// a `Parameter` isn't checked here since its type isn't known until a
+    /// value is bound to it (see `execute_parameterized_statement`).
+    fn check_value_type(
+        &self,
+        table: &str,
+        column: &BoundColumn,
+        value: &AstExpressionValue,
+    ) -> Result<(), BindError> {
+        match value {
+            AstExpressionValue::Literal(literal) => {
+                if literal_matches_type(literal, &column.data_type) {
+                    Ok(())
+                } else {
+                    Err(BindError::TypeMismatch {
+                        column: column.name.clone(),
+                        expected: column.data_type.clone(),
+                        found: literal_type_name(literal),
+                    })
+                }
+            }
+            AstExpressionValue::ColumnIdentifier(name) => self.resolve_column(table, name).map(|_| ()),
+            AstExpressionValue::Parameter(_) => Ok(()),
+        }
     }
 }
 
-impl Default for Binder {
+impl Default for Binder<'_> {
     fn default() -> Self {
-        Self::new()
+        Self::new(None)
+    }
+}
+
+fn statement_type_name(statement: &AstStatement) -> String {
+    match statement {
+        AstStatement::Select(_) => "Select",
+        AstStatement::Update(_) => "Update",
+        AstStatement::CreateTable(_) => "CreateTable",
+        AstStatement::Insert(_) => "Insert",
+        AstStatement::Delete(_) => "Delete",
+        AstStatement::DropTable(_) => "DropTable",
+        AstStatement::Put(_) => "Put",
+        AstStatement::Rm(_) => "Rm",
+        AstStatement::Ensure(_) => "Ensure",
+        AstStatement::EnsureNot(_) => "EnsureNot",
+    }
+    .to_string()
+}
+
+fn literal_matches_type(literal: &AstLiteralValue, data_type: &DataType) -> bool {
+    match (literal, data_type) {
+        (AstLiteralValue::Null, _) => true,
+        (AstLiteralValue::String(_), DataType::String(_) | DataType::Enum { .. }) => true,
+        (AstLiteralValue::Number(n), DataType::Integer(_)) => !n.contains('.'),
+        (AstLiteralValue::Number(_), DataType::Float(_) | DataType::Decimal { .. }) => true,
+        (AstLiteralValue::Boolean(_), DataType::Boolean(_)) => true,
+        (AstLiteralValue::Vector(_), DataType::Vector(_)) => true,
+        (AstLiteralValue::ZeroBlob(_), DataType::RawBytes(_)) => true,
+        _ => false,
+    }
+}
+
+/// Infers a `GROUND` column's type from the non-`NULL` literals at
+/// `col_index` across `rows`, erroring if literal vectors in that column
+/// disagree on dimension. A column of all-`NULL`s infers as `DataType::Null`.
+fn infer_ground_column_type(
+    column: &str,
+    col_index: usize,
+    rows: &[Vec<AstLiteralValue>],
+) -> Result<DataType, BindError> {
+    let mut inferred: Option<DataType> = None;
+    for row in rows {
+        let literal = &row[col_index];
+        if matches!(literal, AstLiteralValue::Null) {
+            continue;
+        }
+        let candidate = literal_placeholder_type(literal);
+        if let (Some(DataType::Vector(existing)), DataType::Vector(new)) = (&inferred, &candidate) {
+            if existing.0.dimension != new.0.dimension {
+                return Err(BindError::VectorDimensionMismatch {
+                    column: column.to_string(),
+                    expected: existing.0.dimension,
+                    found: new.0.dimension,
+                });
+            }
+        }
+        if inferred.is_none() {
+            inferred = Some(candidate);
+        }
+    }
+    Ok(inferred.unwrap_or(DataType::Null))
+}
+
+/// A placeholder `DataType` carrying `literal`'s shape (variant and, for a
+/// vector, its dimension) but not a materialized value - the same
+/// discriminant-only convention `translator::translate_statement` uses to
+/// turn an `AstDataType` into a schema column's `DataType`.
+fn literal_placeholder_type(literal: &AstLiteralValue) -> DataType {
+    match literal {
+        AstLiteralValue::String(_) => DataType::String(String::new()),
+        AstLiteralValue::Number(n) if n.contains('.') => DataType::Float(OrderedFloat(0.0)),
+        AstLiteralValue::Number(_) => DataType::Integer(0),
+        AstLiteralValue::Boolean(_) => DataType::Boolean(false),
+        AstLiteralValue::Null => DataType::Null,
+        AstLiteralValue::Vector(items) => {
+            let dimension = items.len() as u32;
+            DataType::Vector(HashableVectorData(VectorData { dimension, data: vec![0.0; items.len()] }))
+        }
+        AstLiteralValue::ZeroBlob(_) => DataType::RawBytes(Vec::new()),
+    }
+}
+
+fn literal_type_name(literal: &AstLiteralValue) -> &'static str {
+    match literal {
+        AstLiteralValue::String(_) => "String",
+        AstLiteralValue::Number(n) if n.contains('.') => "Float",
+        AstLiteralValue::Number(_) => "Integer",
+        AstLiteralValue::Boolean(_) => "Boolean",
+        AstLiteralValue::Null => "Null",
+        AstLiteralValue::Vector(_) => "Vector",
+        AstLiteralValue::ZeroBlob(_) => "Blob",
     }
 }
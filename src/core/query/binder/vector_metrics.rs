@@ -0,0 +1,224 @@
+// src/core/query/binder/vector_metrics.rs
+
+//! A registry of named vector distance/similarity metrics `bind_function_call`
+//! dispatches to, replacing the old hard-coded `"COSINE_SIMILARITY" |
+//! "DOT_PRODUCT"` match arm with data: each metric just declares its
+//! argument kinds and return type, and [`VectorMetricRegistry::bind_call`]
+//! type-checks any registered metric the same way.
+
+use super::binder::BindError;
+use crate::core::common::types::DataType;
+use std::collections::HashMap;
+
+/// What kind of argument a [`VectorMetric`] expects in a given position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgKind {
+    /// Must be a `DataType::Vector`. Its dimension is checked for equality
+    /// against the adjacent `Vector` argument. When both sides' dimensions
+    /// are unknown (`Vector(None)`) the check is deferred to the executor;
+    /// when only one side's is unknown, binding fails.
+    Vector,
+}
+
+/// A distance/similarity metric a vector function call can dispatch to, e.g.
+/// `COSINE_SIMILARITY` or `EUCLIDEAN_DISTANCE`.
+#[derive(Debug, Clone)]
+pub struct VectorMetric {
+    /// The metric's canonical name, used in error messages regardless of
+    /// which registered alias the call actually used.
+    pub canonical_name: String,
+    /// The expected kind of each positional argument.
+    pub arg_kinds: Vec<ArgKind>,
+    /// The call's result type.
+    pub return_type: DataType,
+}
+
+impl VectorMetric {
+    /// A metric taking two equal-dimension `Vector` arguments and returning
+    /// `Float64` - the shape every metric in [`VectorMetricRegistry::with_defaults`] has.
+    #[must_use]
+    pub fn pairwise_vector(canonical_name: impl Into<String>) -> Self {
+        Self {
+            canonical_name: canonical_name.into(),
+            arg_kinds: vec![ArgKind::Vector, ArgKind::Vector],
+            return_type: DataType::Float64,
+        }
+    }
+}
+
+/// A registry of named [`VectorMetric`]s the binder consults when binding a
+/// vector function call, so adding a metric (or an alias for one) doesn't
+/// require editing `bind_function_call`'s match arms.
+#[derive(Debug, Clone)]
+pub struct VectorMetricRegistry {
+    metrics: HashMap<String, VectorMetric>,
+}
+
+impl VectorMetricRegistry {
+    /// Creates an empty registry with no metrics registered.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { metrics: HashMap::new() }
+    }
+
+    /// Registers `metric` under `name` (case-insensitive). Call this more
+    /// than once with the same `metric` under a different `name` to
+    /// register an alias (e.g. `L2_DISTANCE` for `EUCLIDEAN_DISTANCE`).
+    pub fn register(&mut self, name: impl Into<String>, metric: VectorMetric) {
+        self.metrics.insert(name.into().to_uppercase(), metric);
+    }
+
+    /// The metric registered for `name` (case-insensitive), if any.
+    #[must_use]
+    pub fn lookup(&self, name: &str) -> Option<&VectorMetric> {
+        self.metrics.get(&name.to_uppercase())
+    }
+
+    /// A registry seeded with every metric this binder ships out of the box:
+    /// `COSINE_SIMILARITY`, `DOT_PRODUCT`, `EUCLIDEAN_DISTANCE` (aliased as
+    /// `L2_DISTANCE`), `MANHATTAN_DISTANCE` (aliased as `L1`), and
+    /// `NEGATIVE_INNER_PRODUCT` - each a 2-argument, equal-dimension
+    /// `Vector -> Float64` metric.
+    #[must_use]
+    pub fn with_defaults() -> Self {
+        let mut registry = Self::new();
+        registry.register("COSINE_SIMILARITY", VectorMetric::pairwise_vector("COSINE_SIMILARITY"));
+        registry.register("DOT_PRODUCT", VectorMetric::pairwise_vector("DOT_PRODUCT"));
+        registry.register("EUCLIDEAN_DISTANCE", VectorMetric::pairwise_vector("EUCLIDEAN_DISTANCE"));
+        registry.register("L2_DISTANCE", VectorMetric::pairwise_vector("EUCLIDEAN_DISTANCE"));
+        registry.register("MANHATTAN_DISTANCE", VectorMetric::pairwise_vector("MANHATTAN_DISTANCE"));
+        registry.register("L1", VectorMetric::pairwise_vector("MANHATTAN_DISTANCE"));
+        registry.register("NEGATIVE_INNER_PRODUCT", VectorMetric::pairwise_vector("NEGATIVE_INNER_PRODUCT"));
+        registry
+    }
+
+    /// Type-checks a call to `name` against its registered [`VectorMetric`]:
+    /// arity, then each argument's kind, then - for adjacent `Vector`
+    /// arguments - that their dimensions agree. Unknown dimensions
+    /// (`Vector(None)`) on both sides of a pair defer the check to the
+    /// executor, the same "both unknown, allow it" behavior the binder used
+    /// to hard-code for `COSINE_SIMILARITY`/`DOT_PRODUCT`.
+    ///
+    /// # Errors
+    /// Returns [`BindError::FunctionNotFound`] if `name` isn't registered,
+    /// [`BindError::IncorrectArgumentCount`] if `arg_types` doesn't match the
+    /// metric's arity, [`BindError::TypeMismatch`] if an argument isn't the
+    /// kind the metric expects, [`BindError::VectorDimensionMismatch`] if two
+    /// adjacent `Vector` arguments disagree on dimension, and
+    /// [`BindError::UnknownVectorDimension`] if only one side of a pair has a
+    /// known dimension.
+    pub fn bind_call(&self, name: &str, arg_types: &[DataType]) -> Result<DataType, BindError> {
+        let upper_name = name.to_uppercase();
+        let metric = self
+            .lookup(&upper_name)
+            .ok_or_else(|| BindError::FunctionNotFound { name: upper_name.clone() })?;
+
+        if arg_types.len() != metric.arg_kinds.len() {
+            return Err(BindError::IncorrectArgumentCount {
+                name: metric.canonical_name.clone(),
+                expected: metric.arg_kinds.len(),
+                got: arg_types.len(),
+            });
+        }
+
+        let mut vector_dims: Vec<(usize, Option<usize>)> = Vec::new();
+        for (arg_index, (arg_type, kind)) in arg_types.iter().zip(&metric.arg_kinds).enumerate() {
+            match kind {
+                ArgKind::Vector => match arg_type {
+                    DataType::Vector(dimension) => vector_dims.push((arg_index, *dimension)),
+                    other => {
+                        return Err(BindError::TypeMismatch {
+                            name: metric.canonical_name.clone(),
+                            arg_index,
+                            expected_type: "Vector".to_string(),
+                            actual_type: format!("{other:?}"),
+                        })
+                    }
+                },
+            }
+        }
+
+        for pair in vector_dims.windows(2) {
+            let (first_index, first_dim) = pair[0];
+            let (second_index, second_dim) = pair[1];
+            match (first_dim, second_dim) {
+                (Some(a), Some(b)) if a != b => {
+                    return Err(BindError::VectorDimensionMismatch {
+                        name: metric.canonical_name.clone(),
+                        dim1: a,
+                        dim2: b,
+                    })
+                }
+                (None, Some(_)) => {
+                    return Err(BindError::UnknownVectorDimension {
+                        name: metric.canonical_name.clone(),
+                        arg_index: first_index,
+                    })
+                }
+                (Some(_), None) => {
+                    return Err(BindError::UnknownVectorDimension {
+                        name: metric.canonical_name.clone(),
+                        arg_index: second_index,
+                    })
+                }
+                _ => {}
+            }
+        }
+
+        Ok(metric.return_type.clone())
+    }
+}
+
+impl Default for VectorMetricRegistry {
+    fn default() -> Self {
+        Self::with_defaults()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_cover_every_seeded_metric_and_alias() {
+        let registry = VectorMetricRegistry::with_defaults();
+        for name in [
+            "COSINE_SIMILARITY",
+            "DOT_PRODUCT",
+            "EUCLIDEAN_DISTANCE",
+            "L2_DISTANCE",
+            "MANHATTAN_DISTANCE",
+            "L1",
+            "NEGATIVE_INNER_PRODUCT",
+        ] {
+            assert!(registry.lookup(name).is_some(), "missing default metric {name}");
+        }
+    }
+
+    #[test]
+    fn alias_reports_its_canonical_name_in_errors() {
+        let registry = VectorMetricRegistry::with_defaults();
+        let err = registry.bind_call("L2_DISTANCE", &[DataType::Integer]).unwrap_err();
+        assert_eq!(
+            err,
+            BindError::IncorrectArgumentCount { name: "EUCLIDEAN_DISTANCE".to_string(), expected: 2, got: 1 }
+        );
+    }
+
+    #[test]
+    fn unregistered_name_is_function_not_found() {
+        let registry = VectorMetricRegistry::with_defaults();
+        let err = registry.bind_call("NOT_A_METRIC", &[]).unwrap_err();
+        assert_eq!(err, BindError::FunctionNotFound { name: "NOT_A_METRIC".to_string() });
+    }
+
+    #[test]
+    fn custom_metric_can_be_registered_without_editing_the_registry() {
+        let mut registry = VectorMetricRegistry::new();
+        registry.register("HAMMING_DISTANCE", VectorMetric::pairwise_vector("HAMMING_DISTANCE"));
+        let return_type = registry
+            .bind_call("HAMMING_DISTANCE", &[DataType::Vector(Some(3)), DataType::Vector(Some(3))])
+            .unwrap();
+        assert_eq!(return_type, DataType::Float64);
+    }
+}
@@ -1,8 +1,10 @@
 // src/core/query/commands.rs
 
 use crate::core::common::types::Value as ParamValue;
+use crate::core::common::OxidbError;
 use crate::core::query::sql::ast::Statement;
 use crate::core::types::{DataType, VectorData}; // Added VectorData
+use std::collections::HashMap;
 
 /// Represents a key for operations.
 pub type Key = Vec<u8>;
@@ -23,6 +25,13 @@ pub enum SqlConditionTree {
     And(Box<SqlConditionTree>, Box<SqlConditionTree>),
     Or(Box<SqlConditionTree>, Box<SqlConditionTree>),
     Not(Box<SqlConditionTree>),
+    /// `column [NOT] IN (subquery)`: `subquery` (always a `Command::Select`) is run
+    /// and materialized once, then `column`'s value is tested for membership in its
+    /// result set.
+    InSubquery { column: String, negated: bool, subquery: Box<Command> },
+    /// `[NOT] EXISTS (subquery)`: `subquery` (always a `Command::Select`) is run and
+    /// the condition holds iff it returns at least one row.
+    Exists { negated: bool, subquery: Box<Command> },
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -31,6 +40,74 @@ pub struct SqlAssignment {
     pub value: DataType, // Use DataType here
 }
 
+/// One aggregate function requested by a `GROUP BY` query's select list, e.g.
+/// `COUNT(p.id)` or `SUM(amount)`. `column` is `None` for `COUNT(*)`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SqlAggregateExpr {
+    pub function: crate::core::query::sql::ast::AggregateFunction,
+    pub column: Option<String>,
+    pub alias: Option<String>,
+}
+
+/// How a violated `Command::AddValidationRule` rule is reported: `Error`
+/// rejects the write with `OxidbError::ConstraintViolation`, `Warning` only
+/// logs it and lets the write proceed.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// When a `Command::CreateTrigger` fires relative to the row write it's
+/// attached to.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TriggerTiming {
+    Before,
+    After,
+}
+
+/// Which row-level DML operation a `Command::CreateTrigger` fires for.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TriggerEvent {
+    Insert,
+    Update,
+    Delete,
+}
+
+/// The `ON CONFLICT (target_columns) DO ...` clause of an `INSERT`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct OnConflict {
+    /// The columns identifying a conflicting row, typically a unique key.
+    pub target_columns: Vec<String>,
+    pub action: ConflictAction,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ConflictAction {
+    /// `DO NOTHING` - skip the row being inserted.
+    DoNothing,
+    /// `DO UPDATE SET ...` - apply these assignments to the existing row instead.
+    DoUpdate(Vec<SqlAssignment>),
+}
+
+/// A single `ALTER TABLE` operation (see `Command::AlterTable`).
+#[derive(Debug, PartialEq, Clone)]
+pub enum AlterTableOperation {
+    /// `ADD COLUMN name type [DEFAULT default] [NOT NULL]`: `default`, if
+    /// given, backfills every existing row's new column; a `None` default on
+    /// a `NOT NULL` column is only allowed when the table is empty.
+    AddColumn {
+        column: crate::core::types::schema::ColumnDef,
+        default: Option<DataType>,
+    },
+    /// `DROP COLUMN name`: removes the column from the schema, its
+    /// `idx_<table>_<name>` index if one exists, and projects it out of
+    /// every existing row.
+    DropColumn {
+        column_name: String,
+    },
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum SelectColumnSpec {
     Specific(Vec<String>), // List of column names
@@ -49,12 +126,79 @@ pub enum SqlOrderDirection {
     Desc,
 }
 
+/// Bound values for a [`ParameterizedCommand`]: positional `?` placeholders bind by
+/// index, while named `:name` placeholders (the convention `include-sql` uses) bind
+/// by name, so a statement that references the same parameter more than once doesn't
+/// need it repeated in the binding set.
+#[derive(Debug, Clone)]
+pub enum Parameters {
+    Positional(Vec<ParamValue>),
+    Named(HashMap<String, ParamValue>),
+}
+
 /// A parameterized SQL statement with separate parameter values
 /// This provides secure parameterized query execution
 #[derive(Debug, Clone)]
 pub struct ParameterizedCommand {
     pub statement: Statement,
-    pub parameters: Vec<ParamValue>,
+    pub parameters: Parameters,
+    /// `statement`'s placeholders in occurrence order, naming the `:name` each one
+    /// was parsed from. Empty when `parameters` is `Positional`, since the engine's
+    /// own `Parameter(u32)` indices already capture positional order without this.
+    placeholder_names: Vec<String>,
+}
+
+impl ParameterizedCommand {
+    /// Builds a `ParameterizedCommand` bound positionally: `statement`'s `?`/
+    /// `Parameter(n)` placeholders take their values from `values[n]`.
+    #[must_use]
+    pub fn positional(statement: Statement, values: Vec<ParamValue>) -> Self {
+        Self { statement, parameters: Parameters::Positional(values), placeholder_names: Vec::new() }
+    }
+
+    /// Builds a `ParameterizedCommand` bound by name: `placeholder_names[i]` gives the
+    /// `:name` that `statement`'s `i`-th placeholder (in occurrence order) was parsed
+    /// from, and `named` supplies each name's value.
+    #[must_use]
+    pub fn named(
+        statement: Statement,
+        placeholder_names: Vec<String>,
+        named: HashMap<String, ParamValue>,
+    ) -> Self {
+        Self { statement, parameters: Parameters::Named(named), placeholder_names }
+    }
+
+    /// Resolves `self.parameters` into the engine's native positional form,
+    /// re-looking up a repeated `:name` at each of its occurrences so the binding
+    /// set only needs to supply it once.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::InvalidInput` if a `Named` binding set is missing a value
+    /// for a name `statement`'s placeholders reference.
+    pub fn resolve_positional(&self) -> Result<Vec<ParamValue>, OxidbError> {
+        match &self.parameters {
+            Parameters::Positional(values) => Ok(values.clone()),
+            Parameters::Named(named) => self
+                .placeholder_names
+                .iter()
+                .map(|name| {
+                    named.get(name).cloned().ok_or_else(|| OxidbError::InvalidInput {
+                        message: format!("No value bound for named parameter ':{name}'."),
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The parameter and result-column type metadata produced by analyzing a
+/// `Command::Describe` statement, without executing it.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DescribeResult {
+    /// Each `?` placeholder's inferred `DataType`, in parameter index order.
+    pub parameters: Vec<DataType>,
+    /// Each projected/returned column's `(name, DataType, nullable)`.
+    pub columns: Vec<(String, DataType, bool)>,
 }
 
 /// Enum defining the different types of commands the database can execute.
@@ -63,8 +207,19 @@ pub struct ParameterizedCommand {
 pub enum Command {
     // Transaction control commands
     BeginTransaction,
+    /// `BEGIN` with explicit lock-acquisition timing (`DEFERRED`/`IMMEDIATE`/`EXCLUSIVE`).
+    BeginTransactionWithBehavior(crate::core::transaction::TransactionBehavior),
     CommitTransaction,
     RollbackTransaction,
+    /// `SAVEPOINT name` - marks a point within the active transaction that a
+    /// later `ROLLBACK TO` can return to without aborting the whole transaction.
+    Savepoint(String),
+    /// `RELEASE name` - forgets a savepoint (and any nested ones created after
+    /// it) without undoing its changes; they become part of the enclosing scope.
+    ReleaseSavepoint(String),
+    /// `ROLLBACK TO name` - undoes everything done since `SAVEPOINT name`,
+    /// while leaving the savepoint itself and the outer transaction active.
+    RollbackToSavepoint(String),
     Vacuum, // Database maintenance command
     // SQL-like commands
     Select {
@@ -74,24 +229,148 @@ pub enum Command {
         order_by: Option<Vec<SqlOrderByExpr>>,
         limit: Option<u64>,
     },
+    /// `SELECT group_col, AGG(col) FROM source WHERE condition GROUP BY group_col`:
+    /// split out from `Select` because it has no `ORDER BY`/`LIMIT` support yet and,
+    /// unlike a plain `Select`, its execution (`handle_select_aggregate`) first checks
+    /// for a covering `CREATE AGGREGATE INDEX` before falling back to scanning and
+    /// re-aggregating every row of `source`.
+    SelectAggregate {
+        source: String,
+        group_by: Vec<String>,
+        aggregates: Vec<SqlAggregateExpr>,
+        condition: Option<SqlConditionTree>,
+    },
     Update {
         source: String, // Table/source name
         assignments: Vec<SqlAssignment>,
         condition: Option<SqlConditionTree>, // Changed
+        // `RETURNING col, ...` / `RETURNING *`: yield the updated rows' chosen
+        // columns back to the caller instead of just an affected-row count.
+        returning: Option<SelectColumnSpec>,
     },
     CreateTable {
         table_name: String,
         columns: Vec<crate::core::types::schema::ColumnDef>, // Ensuring correct path
     },
+    /// `CREATE TYPE name AS ENUM (...)`: registers `name` in the enum-type
+    /// catalog with `variants` as its allowed values, so columns can be
+    /// declared with `name` as their type (see `AstDataType::Enum`).
+    CreateEnumType {
+        name: String,
+        variants: Vec<String>,
+    },
+    /// `ALTER TABLE table_name ADD|DROP COLUMN ...`: evolves an existing
+    /// table's schema and rewrites every stored row to match.
+    AlterTable {
+        table_name: String,
+        operation: AlterTableOperation,
+    },
+    /// `CREATE INDEX name ON table_name(column_name)`: registers a real
+    /// backing index under `index_name` and backfills it from every existing
+    /// row. Insert-time maintenance, `check_uniqueness` and the optimizer's
+    /// index-scan lookup only find it when `index_name` is
+    /// `idx_<table>_<col>`. Only a single indexed column is supported, as
+    /// that's all `IndexManager`'s indexes (`Index::insert(value, key)`) hold.
+    CreateIndex {
+        index_name: String,
+        table_name: String,
+        column_name: String,
+    },
+    /// Registers a materialized aggregate index: `IndexManager` maintains, per
+    /// distinct value of `group_column`, the partial state of `function` applied
+    /// to `agg_column` (`None` for `COUNT(*)`), updated incrementally on every
+    /// `SqlInsert`/`SqlDelete` against `table_name` instead of being recomputed.
+    /// A matching `SelectAggregate` query scans this instead of the base table -
+    /// see `handle_select_aggregate`.
+    CreateAggregateIndex {
+        index_name: String,
+        table_name: String,
+        group_column: String,
+        function: crate::core::query::sql::ast::AggregateFunction,
+        agg_column: Option<String>,
+    },
+    /// `DROP INDEX name`: unregisters the index via `IndexManager::drop_index`.
+    DropIndex {
+        index_name: String,
+    },
+    /// Unregisters a `CreateAggregateIndex` via `IndexManager::drop_aggregate_index`.
+    DropAggregateIndex {
+        index_name: String,
+    },
+    /// Registers a `CREATE INDEX` keyed by a deterministic expression over
+    /// `table_name` (e.g. `date_trunc_day(created_at)`) rather than a bare
+    /// column, so a predicate over that same expression can be served by an
+    /// index scan instead of a full table scan (see
+    /// `Optimizer::find_suitable_index`). Backed by a regular hash index
+    /// under the hood, so `DropIndex` (not a separate command) removes it.
+    CreateFunctionalIndex {
+        index_name: String,
+        table_name: String,
+        expression: crate::core::indexing::expression::IndexExpr,
+    },
+    /// Registers `name` as a row-validation rule on `table_name`: whenever a
+    /// candidate row (for `SqlInsert`/`Update`) matches `when` (or always, if
+    /// `when` is `None`), it must also satisfy `then`, or the row is reported
+    /// as a violation per `severity`. The "rule table" concept from valve.rs,
+    /// recast as a first-class command.
+    AddValidationRule {
+        table_name: String,
+        name: String,
+        when: Option<SqlConditionTree>,
+        then: SqlConditionTree,
+        severity: Severity,
+    },
+    /// Registers `name` as a row-level trigger on `table_name`: whenever
+    /// `event` happens at `timing` (relative to the row write), `body` runs
+    /// as a sequence of commands against the same executor.
+    CreateTrigger {
+        table_name: String,
+        name: String,
+        timing: TriggerTiming,
+        event: TriggerEvent,
+        body: Vec<Command>,
+    },
     SqlInsert {
         // For SQL INSERT INTO table (cols) VALUES (vals)
         table_name: String,
         columns: Option<Vec<String>>, // None if columns are not specified
         values: Vec<Vec<DataType>>,   // Outer Vec for rows, inner Vec for values in a row
+        // `ON CONFLICT (target_columns) DO NOTHING|UPDATE SET ...`, for idempotent writes.
+        on_conflict: Option<OnConflict>,
+        // `RETURNING col, ...` / `RETURNING *`: yield the inserted rows' chosen
+        // columns back to the caller instead of just an affected-row count.
+        returning: Option<SelectColumnSpec>,
     },
     SqlDelete {
         table_name: String,
         condition: Option<SqlConditionTree>, // Changed
+        // `RETURNING col, ...` / `RETURNING *`: yield the deleted rows' chosen
+        // columns back to the caller instead of just an affected-row count.
+        returning: Option<SelectColumnSpec>,
+    },
+    // Cozo-style upsert: insert, but overwrite in place on a primary-key conflict
+    // instead of raising a uniqueness violation.
+    Put {
+        table_name: String,
+        columns: Option<Vec<String>>,
+        values: Vec<Vec<DataType>>,
+    },
+    // Idempotent delete: identical to SqlDelete, matching zero rows is not an error.
+    Rm {
+        table_name: String,
+        condition: Option<SqlConditionTree>,
+    },
+    // Assert-or-insert: inserts if absent, no-ops if an identical row already
+    // exists, and errors if a conflicting row (same primary key, different values) exists.
+    Ensure {
+        table_name: String,
+        columns: Option<Vec<String>>,
+        values: Vec<Vec<DataType>>,
+    },
+    // Assert-absent: errors if a row matches the condition, no-ops otherwise.
+    EnsureNot {
+        table_name: String,
+        condition: Option<SqlConditionTree>,
     },
     SimilaritySearch {
         table_name: String,
@@ -108,6 +387,30 @@ pub enum Command {
         statement: Statement,
         parameters: Vec<ParamValue>,
     },
+    /// Analyzes `statement` without executing it, resolving each projected
+    /// column and `?` placeholder against the relevant table schema. Backs
+    /// `PreparedStatement::describe`-style introspection for tooling and ORMs.
+    Describe {
+        statement: Statement,
+    },
+    /// Executes `commands` in order, generalizing sqlx's `Executor::execute_many`
+    /// streaming-execution pattern to avoid per-statement round-trip and parse
+    /// overhead for bulk loads. When `atomic` is `true`, the whole batch runs
+    /// inside an implicit transaction that rolls back on the first error; when
+    /// `false`, each command commits independently and errors are reported
+    /// per-item via `ExecutionResult::Batch`.
+    Batch {
+        commands: Vec<Command>,
+        atomic: bool,
+    },
+    /// `EXPLAIN [ANALYZE] <statement>`: builds `statement`'s query plan
+    /// without running it, or (when `analyze` is set) actually runs it and
+    /// annotates the plan with measured row counts, timings, and buffer
+    /// access counts via `QueryExecutor::explain`.
+    Explain {
+        statement: Box<Command>,
+        analyze: bool,
+    },
 }
 
 // Example of how these might be constructed (not strictly part of this file,
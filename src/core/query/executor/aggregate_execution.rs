@@ -0,0 +1,228 @@
+// src/core/query/executor/aggregate_execution.rs
+
+use super::{ExecutionResult, QueryExecutor};
+use crate::core::common::serialization::{deserialize_data_type, serialize_data_type};
+use crate::core::common::OxidbError;
+use crate::core::indexing::aggregate::AggregateState;
+use crate::core::query::commands::{SqlAggregateExpr, SqlConditionTree};
+use crate::core::performance::events::ProfileEvent;
+use crate::core::types::{DataType, JsonSafeMap};
+use std::collections::HashMap;
+
+impl<S: crate::core::storage::engine::traits::KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static>
+    QueryExecutor<S>
+{
+    /// Handles `Command::SelectAggregate`: a `GROUP BY` query over `source`.
+    ///
+    /// When `group_by` and `aggregates` are each exactly one column/expression
+    /// (all a `CREATE AGGREGATE INDEX` can cover today) and there is no
+    /// `condition`, this first checks `IndexManager` for a matching index and,
+    /// if found, scans its pre-computed per-group state instead of the base
+    /// table. Otherwise (or always, for multi-column `GROUP BY`s and queries
+    /// with a `WHERE` condition) it falls back to scanning every
+    /// row of `source`, filtering by `condition`, and aggregating in memory.
+    ///
+    /// Results are flattened as `[group_value.., agg_value..]` per group, the
+    /// same tuple-flattening convention `handle_select` uses for
+    /// `ExecutionResult::Values`.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::TableNotFound` if `source` doesn't exist.
+    pub(crate) fn handle_select_aggregate(
+        &mut self,
+        source: String,
+        group_by: Vec<String>,
+        aggregates: Vec<SqlAggregateExpr>,
+        condition: Option<SqlConditionTree>,
+    ) -> Result<ExecutionResult, OxidbError> {
+        if group_by.len() == 1 && aggregates.len() == 1 && condition.is_none() {
+            if let Some(rewritten) =
+                self.try_aggregate_index_rewrite(&source, &group_by[0], &aggregates[0])?
+            {
+                return Ok(rewritten);
+            }
+        }
+
+        self.record_profile_event(ProfileEvent::AggregateIndexMiss { table_name: source.clone() });
+        self.scan_and_aggregate(&source, &group_by, &aggregates, condition.as_ref())
+    }
+
+    /// Attempts the index-scan path for a single-column, single-aggregate,
+    /// unconditional `GROUP BY`. Returns `Ok(None)` (not an error) when no
+    /// matching `CREATE AGGREGATE INDEX` is registered, so the caller falls
+    /// back to `scan_and_aggregate`.
+    fn try_aggregate_index_rewrite(
+        &mut self,
+        source: &str,
+        group_column: &str,
+        aggregate: &SqlAggregateExpr,
+    ) -> Result<Option<ExecutionResult>, OxidbError> {
+        let index_manager = self.index_manager.clone();
+        let manager = index_manager.read().map_err(|e| {
+            OxidbError::LockTimeout(format!(
+                "Failed to acquire read lock on index manager for aggregate query: {e}"
+            ))
+        })?;
+        let Some(index) = manager.find_aggregate_index(
+            source,
+            group_column,
+            aggregate.function,
+            aggregate.column.as_deref(),
+        ) else {
+            return Ok(None);
+        };
+
+        self.record_profile_event(ProfileEvent::AggregateIndexRewrite {
+            index_name: format!("{source}.{group_column}"),
+        });
+
+        let mut rows = Vec::new();
+        for (group_key_bytes, state) in index.scan() {
+            let group_value = deserialize_data_type(group_key_bytes)?;
+            rows.push(group_value);
+            rows.push(state.value_for(aggregate.function));
+        }
+        Ok(Some(ExecutionResult::Values(rows)))
+    }
+
+    /// The genuine fallback: scans every row of `source`, filters by
+    /// `condition`, and accumulates `aggregates` per distinct `group_by`
+    /// tuple using the same `AggregateState` a materialized aggregate index
+    /// maintains incrementally.
+    fn scan_and_aggregate(
+        &mut self,
+        source: &str,
+        group_by: &[String],
+        aggregates: &[SqlAggregateExpr],
+        condition: Option<&SqlConditionTree>,
+    ) -> Result<ExecutionResult, OxidbError> {
+        let schema_key = Self::schema_key(source);
+        let current_op_tx_id =
+            self.transaction_manager.current_active_transaction_id().unwrap_or(crate::core::common::types::TransactionId(0));
+        let committed_ids: std::collections::HashSet<u64> = self
+            .transaction_manager
+            .get_committed_tx_ids_snapshot()
+            .into_iter()
+            .map(|id| id.0)
+            .collect();
+
+        let row_keys: Vec<Vec<u8>> = self
+            .store
+            .read()
+            .map_err(|e| {
+                OxidbError::LockTimeout(format!(
+                    "Failed to acquire read lock on store for aggregate query: {e}"
+                ))
+            })?
+            .scan()?
+            .into_iter()
+            .filter(|(key, _)| key != &schema_key && String::from_utf8_lossy(key).starts_with(source))
+            .map(|(key, _)| key)
+            .collect();
+
+        let mut groups: HashMap<Vec<DataType>, Vec<AggregateState>> = HashMap::new();
+
+        for key in &row_keys {
+            let Some(row_bytes) = self
+                .store
+                .read()
+                .map_err(|e| {
+                    OxidbError::LockTimeout(format!(
+                        "Failed to acquire read lock on store for aggregate query: {e}"
+                    ))
+                })?
+                .get(key, current_op_tx_id.0, &committed_ids)?
+            else {
+                continue;
+            };
+            let row_data = deserialize_data_type(&row_bytes)?;
+            let DataType::Map(JsonSafeMap(map_data)) = row_data else {
+                continue;
+            };
+
+            let row: HashMap<Vec<u8>, DataType> = map_data
+                .iter()
+                .map(|(col, val)| (col.clone(), val.clone()))
+                .collect();
+            if let Some(tree) = condition {
+                if !self.evaluate_condition_tree(tree, &row)? {
+                    continue;
+                }
+            }
+
+            let group_key: Vec<DataType> = group_by
+                .iter()
+                .map(|col| map_data.get(col.as_bytes()).cloned().unwrap_or(DataType::Null))
+                .collect();
+
+            let states = groups
+                .entry(group_key)
+                .or_insert_with(|| vec![AggregateState::default(); aggregates.len()]);
+            for (state, agg) in states.iter_mut().zip(aggregates) {
+                let value = match &agg.column {
+                    Some(col) => map_data.get(col.as_bytes()),
+                    None => None,
+                };
+                state.apply_insert(value);
+            }
+        }
+
+        let mut rows = Vec::new();
+        for (group_key, states) in groups {
+            rows.extend(group_key);
+            for (state, agg) in states.iter().zip(aggregates) {
+                rows.push(state.value_for(agg.function));
+            }
+        }
+
+        Ok(ExecutionResult::Values(rows))
+    }
+
+    /// Applies an inserted row's incremental effect to every `AggregateIndex`
+    /// registered over `table_name`. Called from `SqlInsert`'s per-column
+    /// index maintenance loop alongside the regular column-index updates.
+    pub(crate) fn maintain_aggregate_indexes_on_insert(
+        &mut self,
+        table_name: &str,
+        row: &HashMap<Vec<u8>, DataType>,
+    ) -> Result<(), OxidbError> {
+        let mut manager = self.index_manager.write().map_err(|e| {
+            OxidbError::LockTimeout(format!(
+                "Failed to acquire write lock on index manager for aggregate index maintenance: {e}"
+            ))
+        })?;
+        for index in manager.aggregate_indexes_for_table(table_name) {
+            let Some(group_value) = row.get(index.group_column.as_bytes()) else { continue };
+            let group_key = serialize_data_type(group_value)?;
+            let agg_value = match &index.agg_column {
+                Some(col) => row.get(col.as_bytes()),
+                None => None,
+            };
+            index.apply_insert(group_key, agg_value);
+        }
+        Ok(())
+    }
+
+    /// The delete-side counterpart of `maintain_aggregate_indexes_on_insert`.
+    pub(crate) fn maintain_aggregate_indexes_on_delete(
+        &mut self,
+        table_name: &str,
+        row: &HashMap<Vec<u8>, DataType>,
+    ) -> Result<(), OxidbError> {
+        let mut manager = self.index_manager.write().map_err(|e| {
+            OxidbError::LockTimeout(format!(
+                "Failed to acquire write lock on index manager for aggregate index maintenance: {e}"
+            ))
+        })?;
+        for index in manager.aggregate_indexes_for_table(table_name) {
+            let Some(group_value) = row.get(index.group_column.as_bytes()) else { continue };
+            let group_key = serialize_data_type(group_value)?;
+            let agg_value = match &index.agg_column {
+                Some(col) => row.get(col.as_bytes()),
+                None => None,
+            };
+            index.apply_delete(&group_key, agg_value);
+        }
+        Ok(())
+    }
+}
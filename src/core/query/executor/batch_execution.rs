@@ -0,0 +1,45 @@
+use super::{BatchItemResult, ExecutionResult, QueryExecutor};
+use crate::core::common::OxidbError;
+use crate::core::query::commands::Command;
+use crate::core::storage::engine::traits::KeyValueStore;
+
+impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S> {
+    /// Handles `Command::Batch`, running `commands` in order via
+    /// `execute_command`, each wrapped in its own auto-commit transaction
+    /// unless one is already active.
+    ///
+    /// When `atomic` is `true`, an explicit transaction is begun first, and
+    /// the first failing command rolls it back and short-circuits the batch;
+    /// when `false`, every command runs regardless of earlier failures, with
+    /// each outcome reported in the returned `ExecutionResult::Batch`.
+    pub(crate) fn handle_batch(
+        &mut self,
+        commands: Vec<Command>,
+        atomic: bool,
+    ) -> Result<ExecutionResult, OxidbError> {
+        if atomic {
+            self.handle_begin_transaction()?;
+            let mut results = Vec::with_capacity(commands.len());
+            for command in commands {
+                match self.execute_command(command) {
+                    Ok(result) => results.push(BatchItemResult::Ok(result)),
+                    Err(e) => {
+                        self.handle_rollback_transaction()?;
+                        return Err(e);
+                    }
+                }
+            }
+            self.handle_commit_transaction()?;
+            Ok(ExecutionResult::Batch(results))
+        } else {
+            let results = commands
+                .into_iter()
+                .map(|command| match self.execute_command(command) {
+                    Ok(result) => BatchItemResult::Ok(result),
+                    Err(e) => BatchItemResult::Err(e.to_string()),
+                })
+                .collect();
+            Ok(ExecutionResult::Batch(results))
+        }
+    }
+}
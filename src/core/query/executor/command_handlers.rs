@@ -9,7 +9,16 @@ use crate::core::common::types::TransactionId; // Required for TransactionId(0)
 impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S> {
     pub fn execute_command(&mut self, command: Command) -> Result<ExecutionResult, OxidbError> {
         let mut requires_auto_commit = false;
-        let is_transaction_management_command = matches!(command, Command::BeginTransaction | Command::CommitTransaction | Command::RollbackTransaction);
+        let is_transaction_management_command = matches!(
+            command,
+            Command::BeginTransaction
+                | Command::BeginTransactionWithBehavior(_)
+                | Command::CommitTransaction
+                | Command::RollbackTransaction
+                | Command::Savepoint(_)
+                | Command::ReleaseSavepoint(_)
+                | Command::RollbackToSavepoint(_)
+        );
 
         if !is_transaction_management_command && self.transaction_manager.current_active_transaction_id().is_none() {
             // If no active transaction and not a TxMgmt command, start Tx0 for auto-commit.
@@ -0,0 +1,85 @@
+use super::QueryExecutor;
+use crate::core::storage::engine::traits::KeyValueStore;
+use crate::core::transaction::UndoOperation;
+use crate::event_engine::handler::{Event, EventResult};
+use crate::event_engine::observer::{ObserverFilter, ObserverId, ObserverRegistry};
+use std::collections::HashSet;
+
+impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S> {
+    /// Registers `callback` to run for every commit whose event matches
+    /// `filter`, returning an id that can later be passed to
+    /// [`Self::deregister_commit_observer`].
+    pub fn register_commit_observer(
+        &mut self,
+        filter: ObserverFilter,
+        callback: impl Fn(&Event) -> EventResult + Send + Sync + 'static,
+    ) -> ObserverId {
+        self.commit_observers.register(filter, callback)
+    }
+
+    /// Removes a previously registered commit observer. Returns `false` if
+    /// `id` isn't currently registered.
+    pub fn deregister_commit_observer(&mut self, id: ObserverId) -> bool {
+        self.commit_observers.deregister(id)
+    }
+
+    /// Builds an [`Event`] for transaction `tx_id`'s commit from `undo_log`
+    /// and delivers it to every matching registered observer.
+    ///
+    /// `undo_log` is coalesced down to one before/after pair per key: when a
+    /// transaction wrote to the same key more than once, the key's "before"
+    /// value is the one recorded by the *first* undo operation for that key
+    /// (the true pre-transaction value), since later undo operations only
+    /// know how to revert the transaction's own intermediate writes. The
+    /// "after" value is read back from the store now that `tx_id` is
+    /// committed and visible.
+    pub(crate) fn notify_commit_observers(&mut self, tx_id: u64, undo_log: &[UndoOperation]) {
+        if self.commit_observers.is_empty() {
+            return;
+        }
+
+        let mut keys_changed = Vec::new();
+        let mut old_values = Vec::new();
+        for op in undo_log {
+            let (key, old_value) = match op {
+                UndoOperation::RevertInsert { key } => (key, None),
+                UndoOperation::RevertUpdate { key, old_value }
+                | UndoOperation::RevertDelete { key, old_value } => {
+                    (key, Some(old_value.clone()))
+                }
+                UndoOperation::IndexRevertInsert { .. }
+                | UndoOperation::IndexRevertDelete { .. }
+                | UndoOperation::IndexRevertUpdate { .. } => {
+                    continue;
+                }
+            };
+            if keys_changed.contains(key) {
+                continue;
+            }
+            keys_changed.push(key.clone());
+            old_values.push(old_value);
+        }
+
+        if keys_changed.is_empty() {
+            return;
+        }
+
+        let committed_ids: HashSet<u64> = self
+            .transaction_manager
+            .get_committed_tx_ids_snapshot()
+            .into_iter()
+            .map(|id| id.0)
+            .collect();
+        let new_values = {
+            let store = self.store.read().unwrap();
+            keys_changed.iter().map(|key| store.get(key, tx_id, &committed_ids).unwrap_or(None)).collect()
+        };
+
+        let event = Event { tx_id, keys_changed, old_values, new_values };
+        for result in self.commit_observers.notify(&event) {
+            if let Err(err) = result {
+                eprintln!("commit observer for transaction {tx_id} failed: {err}");
+            }
+        }
+    }
+}
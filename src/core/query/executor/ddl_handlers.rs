@@ -1,13 +1,13 @@
 use super::{ExecutionResult, QueryExecutor};
+use crate::core::common::serialization::{deserialize_data_type, serialize_data_type};
 use crate::core::common::OxidbError;
-use crate::core::transaction::Transaction; // Added this import
+use crate::core::query::commands::AlterTableOperation;
+use crate::core::transaction::{LockType, Transaction, UndoOperation}; // Added this import
                                            // use crate::core::common::serialization::{deserialize_data_type}; // No longer needed here
 use crate::core::common::types::TransactionId;
 // Key removed
 use crate::core::storage::engine::traits::KeyValueStore;
-// LockType removed
-// Transaction, TransactionState, UndoOperation removed
-// DataType removed
+use crate::core::types::{DataType, JsonSafeMap};
 use std::collections::HashSet; // HashMap removed
 
 impl<S: KeyValueStore<Vec<u8>, Vec<u8>>> QueryExecutor<S> {
@@ -133,6 +133,19 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>>> QueryExecutor<S> {
             return Err(OxidbError::AlreadyExists { name: format!("Table '{table_name}'") });
         }
 
+        // A column declared with an enum type must reference one already
+        // registered via `CREATE TYPE ... AS ENUM`.
+        for col_def in &columns {
+            if let crate::core::types::DataType::Enum { type_name, .. } = &col_def.data_type {
+                if self.enum_variants(type_name).is_none() {
+                    return Err(OxidbError::Execution(format!(
+                        "Column '{}' references enum type '{type_name}', which is not registered.",
+                        col_def.name
+                    )));
+                }
+            }
+        }
+
         let schema_to_store = crate::core::types::schema::Schema::new(columns);
 
         // Serialize the Schema object. Assuming JSON serialization for now.
@@ -225,4 +238,628 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>>> QueryExecutor<S> {
 
         Ok(ExecutionResult::Success)
     }
+
+    /// Handles `Command::AlterTable`'s `ADD COLUMN`/`DROP COLUMN` operations:
+    /// updates `table_name`'s stored `Schema` and rewrites every existing row
+    /// to match, all under the current transaction's exclusive lock on the
+    /// table's schema key so a rollback restores both schema and rows.
+    ///
+    /// `DROP COLUMN` additionally drops the column's dedicated `idx_<table>_<col>`
+    /// index if one is registered, and purges any auto-increment counter
+    /// tracked for it (in memory and its persisted `_auto_increment_<table>_<col>`
+    /// key), so a later column reusing the name starts from zero rather than
+    /// inheriting a stale value.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::TableNotFound` if `table_name` doesn't exist,
+    /// `OxidbError::AlreadyExists` for `ADD COLUMN` naming an existing
+    /// column, `OxidbError::NotFound` for `DROP COLUMN` naming a missing one,
+    /// `OxidbError::ConstraintViolation` for `DROP COLUMN` naming the primary
+    /// key, and `OxidbError::InvalidInput` for `ADD ... NOT NULL` without a
+    /// default on a non-empty table.
+    pub(crate) fn handle_alter_table(
+        &mut self,
+        table_name: String,
+        operation: AlterTableOperation,
+    ) -> Result<ExecutionResult, OxidbError> {
+        let schema_key = Self::schema_key(&table_name);
+        let schema_arc = self
+            .get_table_schema(&table_name)?
+            .ok_or_else(|| OxidbError::TableNotFound(table_name.clone()))?;
+        let mut schema = (*schema_arc).clone();
+
+        let current_op_tx_id =
+            self.transaction_manager.current_active_transaction_id().unwrap_or(TransactionId(0));
+        if current_op_tx_id != TransactionId(0) {
+            self.lock_manager.acquire_lock(current_op_tx_id.0, &schema_key, LockType::Exclusive)?;
+        }
+
+        let committed_ids: HashSet<u64> = self
+            .transaction_manager
+            .get_committed_tx_ids_snapshot()
+            .into_iter()
+            .map(|id| id.0)
+            .collect();
+
+        // Every key belonging to this table, using the same "starts with the
+        // table name, isn't the schema key" convention `TableScanOperator` uses.
+        let row_keys: Vec<Vec<u8>> = self
+            .store
+            .read()
+            .map_err(|e| {
+                OxidbError::LockTimeout(format!(
+                    "Failed to acquire read lock on store for alter table: {e}"
+                ))
+            })?
+            .scan()?
+            .into_iter()
+            .filter(|(key, _)| {
+                key != &schema_key && String::from_utf8_lossy(key).starts_with(&table_name)
+            })
+            .map(|(key, _)| key)
+            .collect();
+
+        match &operation {
+            AlterTableOperation::AddColumn { column, default } => {
+                if schema.columns.iter().any(|col| col.name == column.name) {
+                    return Err(OxidbError::AlreadyExists {
+                        name: format!("Column '{}' on table '{table_name}'", column.name),
+                    });
+                }
+                if !column.is_nullable && default.is_none() && !row_keys.is_empty() {
+                    return Err(OxidbError::InvalidInput {
+                        message: format!(
+                            "Cannot add NOT NULL column '{}' to non-empty table '{table_name}' without a DEFAULT.",
+                            column.name
+                        ),
+                    });
+                }
+                schema.columns.push(column.clone());
+            }
+            AlterTableOperation::DropColumn { column_name } => {
+                let Some(col_def) = schema.columns.iter().find(|col| &col.name == column_name)
+                else {
+                    return Err(OxidbError::NotFound(format!(
+                        "Column '{column_name}' on table '{table_name}'"
+                    )));
+                };
+                // This schema model has no separate way to drop just a
+                // column's PRIMARY KEY-ness while keeping the column, so a
+                // PK column always has a "dependent constraint" (its own
+                // primary-key-ness) and dropping it is always rejected.
+                if col_def.is_primary_key {
+                    return Err(OxidbError::ConstraintViolation(format!(
+                        "Cannot drop primary key column '{column_name}' from table '{table_name}'"
+                    )));
+                }
+                schema.columns.retain(|col| &col.name != column_name);
+
+                let index_name = format!("idx_{table_name}_{column_name}");
+                let index_exists = self
+                    .index_manager
+                    .read()
+                    .map_err(|e| {
+                        OxidbError::LockTimeout(format!(
+                            "Failed to acquire read lock on index manager for alter table: {e}"
+                        ))
+                    })?
+                    .get_index(&index_name)
+                    .is_some();
+                if index_exists {
+                    self.index_manager
+                        .write()
+                        .map_err(|e| {
+                            OxidbError::LockTimeout(format!(
+                                "Failed to acquire write lock on index manager for alter table: {e}"
+                            ))
+                        })?
+                        .drop_index(&index_name)?;
+                }
+
+                // Purge any auto-increment state tracked for this column, in
+                // memory and on disk, so a later column of the same name
+                // doesn't inherit a stale counter.
+                self.auto_increment_state.remove(&format!("{table_name}_{column_name}"));
+                let auto_increment_key =
+                    format!("_auto_increment_{table_name}_{column_name}").into_bytes();
+                let committed_ids_for_auto_increment_purge: HashSet<u64> = self
+                    .transaction_manager
+                    .get_committed_tx_ids_snapshot()
+                    .into_iter()
+                    .map(|id| id.0)
+                    .collect();
+                let purge_tx = self.transaction_manager.get_active_transaction().map_or_else(
+                    || Transaction::new(TransactionId(0)),
+                    crate::core::transaction::Transaction::clone_for_store,
+                );
+                let purge_lsn = self.log_manager.next_lsn();
+                self.store
+                    .write()
+                    .map_err(|e| {
+                        OxidbError::LockTimeout(format!(
+                            "Failed to acquire write lock on store to purge auto-increment state: {e}"
+                        ))
+                    })?
+                    .delete(
+                        &auto_increment_key,
+                        &purge_tx,
+                        purge_lsn,
+                        &committed_ids_for_auto_increment_purge,
+                    )?;
+            }
+        }
+
+        let current_tx = self.transaction_manager.get_active_transaction().map_or_else(
+            || Transaction::new(TransactionId(0)),
+            crate::core::transaction::Transaction::clone_for_store,
+        );
+
+        // Rewrite every existing row to add/drop the column, recording an undo
+        // entry per row so a rollback restores its prior contents.
+        for key in &row_keys {
+            let Some(current_value_bytes) = self
+                .store
+                .read()
+                .map_err(|e| {
+                    OxidbError::LockTimeout(format!(
+                        "Failed to acquire read lock on store for alter table row rewrite: {e}"
+                    ))
+                })?
+                .get(key, current_op_tx_id.0, &committed_ids)?
+            else {
+                continue;
+            };
+
+            let mut row_data = deserialize_data_type(&current_value_bytes)?;
+            if let DataType::Map(JsonSafeMap(ref mut map_data)) = row_data {
+                match &operation {
+                    AlterTableOperation::AddColumn { column, default } => {
+                        map_data.insert(
+                            column.name.as_bytes().to_vec(),
+                            default.clone().unwrap_or(DataType::Null),
+                        );
+                    }
+                    AlterTableOperation::DropColumn { column_name } => {
+                        map_data.remove(column_name.as_bytes());
+                    }
+                }
+            }
+            let new_value_bytes = serialize_data_type(&row_data)?;
+
+            if current_op_tx_id != TransactionId(0) {
+                if let Some(active_tx_mut) = self.transaction_manager.get_active_transaction_mut() {
+                    active_tx_mut.add_undo_operation(UndoOperation::RevertUpdate {
+                        key: key.clone(),
+                        old_value: current_value_bytes,
+                    });
+                }
+            }
+
+            let row_lsn = self.log_manager.next_lsn();
+            if let Some(active_tx_mut) = self.transaction_manager.get_active_transaction_mut() {
+                active_tx_mut.prev_lsn = row_lsn;
+            }
+            self.store
+                .write()
+                .map_err(|e| {
+                    OxidbError::LockTimeout(format!(
+                        "Failed to acquire write lock on store for alter table row rewrite: {e}"
+                    ))
+                })?
+                .put(key.clone(), new_value_bytes, &current_tx, row_lsn)?;
+        }
+
+        // Persist the updated schema, the same way `handle_create_table` does.
+        let serialized_schema = serde_json::to_vec(&schema).map_err(|e| {
+            OxidbError::Serialization(format!(
+                "Failed to serialize altered schema for table '{table_name}': {e}"
+            ))
+        })?;
+        let schema_lsn = self.log_manager.next_lsn();
+        if let Some(active_tx_mut) = self.transaction_manager.get_active_transaction_mut() {
+            active_tx_mut.prev_lsn = schema_lsn;
+        }
+        self.store
+            .write()
+            .map_err(|e| {
+                OxidbError::LockTimeout(format!(
+                    "Failed to acquire write lock on store for alter table schema update: {e}"
+                ))
+            })?
+            .put(schema_key, serialized_schema, &current_tx, schema_lsn)?;
+
+        Ok(ExecutionResult::Success)
+    }
+
+    /// Handles `Command::CreateIndex`: registers `index_name` with
+    /// `IndexManager` and backfills it by scanning every existing row of
+    /// `table_name`, the same row-discovery convention `handle_alter_table`
+    /// uses. Unlike the automatic per-column indexes `handle_create_table`
+    /// creates for primary/unique columns, this lets any column (e.g. one
+    /// used often in `WHERE` clauses) get a real backing index on demand.
+    ///
+    /// Each backfilled posting gets an `IndexRevertInsert` undo entry when run
+    /// inside an explicit transaction, so a `ROLLBACK` removes exactly the
+    /// postings this backfill added rather than leaving a partially-built
+    /// index behind. The index's registration with `IndexManager` itself
+    /// (and the file it creates on disk) is not undone by rollback, the same
+    /// limitation `CREATE TABLE` has - there's no undo entry for catalog-level
+    /// DDL, only for row/index data.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::TableNotFound` if `table_name` doesn't exist,
+    /// `OxidbError::NotFound` if `column_name` isn't one of its columns, and
+    /// `OxidbError::Index` if `index_name` is already registered.
+    pub(crate) fn handle_create_index(
+        &mut self,
+        index_name: String,
+        table_name: String,
+        column_name: String,
+    ) -> Result<ExecutionResult, OxidbError> {
+        let schema_arc = self
+            .get_table_schema(&table_name)?
+            .ok_or_else(|| OxidbError::TableNotFound(table_name.clone()))?;
+        if !schema_arc.columns.iter().any(|col| col.name == column_name) {
+            return Err(OxidbError::NotFound(format!(
+                "Column '{column_name}' on table '{table_name}'"
+            )));
+        }
+
+        self.index_manager
+            .write()
+            .map_err(|e| {
+                OxidbError::LockTimeout(format!(
+                    "Failed to acquire write lock on index manager for create index: {e}"
+                ))
+            })?
+            .create_index(index_name.clone(), "hash")?;
+
+        let schema_key = Self::schema_key(&table_name);
+        let current_op_tx_id =
+            self.transaction_manager.current_active_transaction_id().unwrap_or(TransactionId(0));
+        let committed_ids: HashSet<u64> = self
+            .transaction_manager
+            .get_committed_tx_ids_snapshot()
+            .into_iter()
+            .map(|id| id.0)
+            .collect();
+
+        let row_keys: Vec<Vec<u8>> = self
+            .store
+            .read()
+            .map_err(|e| {
+                OxidbError::LockTimeout(format!(
+                    "Failed to acquire read lock on store for create index backfill: {e}"
+                ))
+            })?
+            .scan()?
+            .into_iter()
+            .filter(|(key, _)| {
+                key != &schema_key && String::from_utf8_lossy(key).starts_with(&table_name)
+            })
+            .map(|(key, _)| key)
+            .collect();
+
+        for key in &row_keys {
+            let Some(row_bytes) = self
+                .store
+                .read()
+                .map_err(|e| {
+                    OxidbError::LockTimeout(format!(
+                        "Failed to acquire read lock on store for create index backfill: {e}"
+                    ))
+                })?
+                .get(key, current_op_tx_id.0, &committed_ids)?
+            else {
+                continue;
+            };
+
+            let row_data = deserialize_data_type(&row_bytes)?;
+            let DataType::Map(JsonSafeMap(map_data)) = row_data else {
+                continue;
+            };
+            let Some(value_for_column) = map_data.get(column_name.as_bytes()).cloned() else {
+                continue;
+            };
+            if value_for_column == DataType::Null {
+                continue;
+            }
+            let serialized_column_value = serialize_data_type(&value_for_column)?;
+            self.index_manager
+                .write()
+                .map_err(|e| {
+                    OxidbError::LockTimeout(format!(
+                        "Failed to acquire write lock on index manager for create index backfill: {e}"
+                    ))
+                })?
+                .insert_into_index(&index_name, &serialized_column_value, key)?;
+
+            if current_op_tx_id != TransactionId(0) {
+                if let Some(active_tx_mut) = self.transaction_manager.get_active_transaction_mut() {
+                    active_tx_mut.add_undo_operation(UndoOperation::IndexRevertInsert {
+                        index_name: index_name.clone(),
+                        key: key.clone(),
+                        value_for_index: serialized_column_value,
+                    });
+                }
+            }
+        }
+
+        Ok(ExecutionResult::Success)
+    }
+
+    /// Handles `Command::DropIndex`: unregisters `index_name` via
+    /// `IndexManager::drop_index`.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::Index` if `index_name` isn't registered.
+    pub(crate) fn handle_drop_index(
+        &mut self,
+        index_name: String,
+    ) -> Result<ExecutionResult, OxidbError> {
+        self.index_manager
+            .write()
+            .map_err(|e| {
+                OxidbError::LockTimeout(format!(
+                    "Failed to acquire write lock on index manager for drop index: {e}"
+                ))
+            })?
+            .drop_index(&index_name)?;
+        Ok(ExecutionResult::Success)
+    }
+
+    /// Handles `Command::CreateAggregateIndex`: registers `index_name` with
+    /// `IndexManager` as a materialized aggregate index and backfills it by
+    /// scanning every existing row of `table_name`, the same row-discovery
+    /// convention `handle_create_index` uses. A matching `SelectAggregate`
+    /// query can then scan this index's pre-computed per-group state instead
+    /// of re-aggregating every row (see `handle_select_aggregate`).
+    ///
+    /// Unlike `handle_create_index`'s backfill, no undo entries are logged:
+    /// `AggregateIndex` has no on-disk representation, so a `ROLLBACK` after
+    /// this command simply leaves an index nothing else references - the same
+    /// as `CREATE TABLE`'s unrolled-back catalog registration.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::TableNotFound` if `table_name` doesn't exist,
+    /// `OxidbError::NotFound` if `group_column`/`agg_column` isn't one of its
+    /// columns, and `OxidbError::Index` if `index_name` is already registered.
+    pub(crate) fn handle_create_aggregate_index(
+        &mut self,
+        index_name: String,
+        table_name: String,
+        group_column: String,
+        function: crate::core::query::sql::ast::AggregateFunction,
+        agg_column: Option<String>,
+    ) -> Result<ExecutionResult, OxidbError> {
+        let schema_arc = self
+            .get_table_schema(&table_name)?
+            .ok_or_else(|| OxidbError::TableNotFound(table_name.clone()))?;
+        if !schema_arc.columns.iter().any(|col| col.name == group_column) {
+            return Err(OxidbError::NotFound(format!(
+                "Column '{group_column}' on table '{table_name}'"
+            )));
+        }
+        if let Some(agg_col) = &agg_column {
+            if !schema_arc.columns.iter().any(|col| &col.name == agg_col) {
+                return Err(OxidbError::NotFound(format!(
+                    "Column '{agg_col}' on table '{table_name}'"
+                )));
+            }
+        }
+
+        let mut index =
+            crate::core::indexing::AggregateIndex::new(
+                table_name.clone(),
+                group_column.clone(),
+                function,
+                agg_column.clone(),
+            );
+
+        let schema_key = Self::schema_key(&table_name);
+        let current_op_tx_id =
+            self.transaction_manager.current_active_transaction_id().unwrap_or(TransactionId(0));
+        let committed_ids: HashSet<u64> = self
+            .transaction_manager
+            .get_committed_tx_ids_snapshot()
+            .into_iter()
+            .map(|id| id.0)
+            .collect();
+
+        let row_keys: Vec<Vec<u8>> = self
+            .store
+            .read()
+            .map_err(|e| {
+                OxidbError::LockTimeout(format!(
+                    "Failed to acquire read lock on store for aggregate index backfill: {e}"
+                ))
+            })?
+            .scan()?
+            .into_iter()
+            .filter(|(key, _)| {
+                key != &schema_key && String::from_utf8_lossy(key).starts_with(&table_name)
+            })
+            .map(|(key, _)| key)
+            .collect();
+
+        for key in &row_keys {
+            let Some(row_bytes) = self
+                .store
+                .read()
+                .map_err(|e| {
+                    OxidbError::LockTimeout(format!(
+                        "Failed to acquire read lock on store for aggregate index backfill: {e}"
+                    ))
+                })?
+                .get(key, current_op_tx_id.0, &committed_ids)?
+            else {
+                continue;
+            };
+
+            let row_data = deserialize_data_type(&row_bytes)?;
+            let DataType::Map(JsonSafeMap(map_data)) = row_data else {
+                continue;
+            };
+            let Some(group_value) = map_data.get(group_column.as_bytes()).cloned() else {
+                continue;
+            };
+            let group_key = serialize_data_type(&group_value)?;
+            let agg_value = match &agg_column {
+                Some(agg_col) => map_data.get(agg_col.as_bytes()),
+                None => None,
+            };
+            index.apply_insert(group_key, agg_value);
+        }
+
+        self.index_manager
+            .write()
+            .map_err(|e| {
+                OxidbError::LockTimeout(format!(
+                    "Failed to acquire write lock on index manager for create aggregate index: {e}"
+                ))
+            })?
+            .create_aggregate_index(index_name, index)?;
+
+        Ok(ExecutionResult::Success)
+    }
+
+    /// Handles `Command::DropAggregateIndex`: unregisters `index_name` via
+    /// `IndexManager::drop_aggregate_index`.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::Index` if `index_name` isn't registered.
+    pub(crate) fn handle_drop_aggregate_index(
+        &mut self,
+        index_name: String,
+    ) -> Result<ExecutionResult, OxidbError> {
+        self.index_manager
+            .write()
+            .map_err(|e| {
+                OxidbError::LockTimeout(
+                    format!("Failed to acquire write lock on index manager for drop aggregate index: {e}"),
+                )
+            })?
+            .drop_aggregate_index(&index_name)?;
+        Ok(ExecutionResult::Success)
+    }
+
+    /// Handles `Command::CreateFunctionalIndex`: creates a regular hash
+    /// index named `index_name`, registers it with `IndexManager` as keyed
+    /// by `expression` rather than a bare column, and backfills it by
+    /// evaluating `expression` against every existing row of `table_name` -
+    /// the same row-discovery convention `handle_create_index` uses.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::TableNotFound` if `table_name` doesn't exist,
+    /// `OxidbError::NotFound` if a column `expression` reads isn't one of
+    /// its columns, and `OxidbError::Index` if `index_name` is already
+    /// registered.
+    pub(crate) fn handle_create_functional_index(
+        &mut self,
+        index_name: String,
+        table_name: String,
+        expression: crate::core::indexing::expression::IndexExpr,
+    ) -> Result<ExecutionResult, OxidbError> {
+        let schema_arc = self
+            .get_table_schema(&table_name)?
+            .ok_or_else(|| OxidbError::TableNotFound(table_name.clone()))?;
+        for column in expression.referenced_columns() {
+            if !schema_arc.columns.iter().any(|col| col.name == column) {
+                return Err(OxidbError::NotFound(format!(
+                    "Column '{column}' on table '{table_name}'"
+                )));
+            }
+        }
+
+        self.index_manager
+            .write()
+            .map_err(|e| {
+                OxidbError::LockTimeout(format!(
+                    "Failed to acquire write lock on index manager for create functional index: {e}"
+                ))
+            })?
+            .create_index(index_name.clone(), "hash")?;
+
+        let schema_key = Self::schema_key(&table_name);
+        let current_op_tx_id =
+            self.transaction_manager.current_active_transaction_id().unwrap_or(TransactionId(0));
+        let committed_ids: HashSet<u64> = self
+            .transaction_manager
+            .get_committed_tx_ids_snapshot()
+            .into_iter()
+            .map(|id| id.0)
+            .collect();
+
+        let row_keys: Vec<Vec<u8>> = self
+            .store
+            .read()
+            .map_err(|e| {
+                OxidbError::LockTimeout(format!(
+                    "Failed to acquire read lock on store for functional index backfill: {e}"
+                ))
+            })?
+            .scan()?
+            .into_iter()
+            .filter(|(key, _)| {
+                key != &schema_key && String::from_utf8_lossy(key).starts_with(&table_name)
+            })
+            .map(|(key, _)| key)
+            .collect();
+
+        for key in &row_keys {
+            let Some(row_bytes) = self
+                .store
+                .read()
+                .map_err(|e| {
+                    OxidbError::LockTimeout(format!(
+                        "Failed to acquire read lock on store for functional index backfill: {e}"
+                    ))
+                })?
+                .get(key, current_op_tx_id.0, &committed_ids)?
+            else {
+                continue;
+            };
+
+            let row_data = deserialize_data_type(&row_bytes)?;
+            let DataType::Map(JsonSafeMap(map_data)) = row_data else {
+                continue;
+            };
+            let row: std::collections::HashMap<Vec<u8>, DataType> =
+                map_data.iter().map(|(col, val)| (col.clone(), val.clone())).collect();
+            let computed_value = expression.evaluate(&row)?;
+            if computed_value == DataType::Null {
+                continue;
+            }
+            let serialized_value = serialize_data_type(&computed_value)?;
+            self.index_manager
+                .write()
+                .map_err(|e| {
+                    OxidbError::LockTimeout(format!(
+                        "Failed to acquire write lock on index manager for functional index backfill: {e}"
+                    ))
+                })?
+                .insert_into_index(&index_name, &serialized_value, key)?;
+
+            if current_op_tx_id != TransactionId(0) {
+                if let Some(active_tx_mut) = self.transaction_manager.get_active_transaction_mut() {
+                    active_tx_mut.add_undo_operation(UndoOperation::IndexRevertInsert {
+                        index_name: index_name.clone(),
+                        key: key.clone(),
+                        value_for_index: serialized_value,
+                    });
+                }
+            }
+        }
+
+        self.index_manager
+            .write()
+            .map_err(|e| {
+                OxidbError::LockTimeout(format!(
+                    "Failed to acquire write lock on index manager for create functional index: {e}"
+                ))
+            })?
+            .register_functional_index(index_name, table_name, expression)?;
+
+        Ok(ExecutionResult::Success)
+    }
 }
@@ -0,0 +1,322 @@
+use super::{ExecutionResult, QueryExecutor};
+use crate::core::common::OxidbError;
+use crate::core::query::commands::DescribeResult;
+use crate::core::query::sql::ast::{
+    AstExpressionValue, AstParameter, ConditionTree, DeleteStatement, InsertStatement, JoinType,
+    SelectColumn, SelectStatement, Statement, TableReference, UpdateStatement,
+};
+use crate::core::storage::engine::traits::KeyValueStore;
+use crate::core::types::schema::Schema;
+use crate::core::types::DataType;
+
+impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S> {
+    /// Analyzes `statement` and reports the inferred `DataType` of each `?`
+    /// placeholder and each projected/returned column, without executing it.
+    pub(crate) fn handle_describe(
+        &mut self,
+        statement: Statement,
+    ) -> Result<ExecutionResult, OxidbError> {
+        let describe_result = match &statement {
+            Statement::Select(select_stmt) => self.describe_select(select_stmt)?,
+            Statement::Insert(insert_stmt) => self.describe_insert(insert_stmt)?,
+            Statement::Update(update_stmt) => self.describe_update(update_stmt)?,
+            Statement::Delete(delete_stmt) => self.describe_delete(delete_stmt)?,
+            _ => {
+                return Err(OxidbError::NotImplemented {
+                    feature: "DESCRIBE for this statement type".to_string(),
+                })
+            }
+        };
+        Ok(ExecutionResult::Describe(describe_result))
+    }
+
+    fn describe_select(&self, select_stmt: &SelectStatement) -> Result<DescribeResult, OxidbError> {
+        let from_schema =
+            self.get_table_schema(&select_stmt.from_clause.name)?.ok_or_else(|| {
+                OxidbError::Execution(format!(
+                    "Table '{}' not found.",
+                    select_stmt.from_clause.name
+                ))
+            })?;
+        // The left side of a query becomes nullable once a RIGHT/FULL OUTER join can
+        // fail to match it, the same way the right side becomes nullable under a
+        // LEFT/FULL OUTER join - an unmatched side is filled with NULLs regardless of
+        // the matched table's own NOT NULL constraints.
+        let from_nullable_via_join = select_stmt
+            .joins
+            .iter()
+            .any(|join| matches!(join.join_type, JoinType::RightOuter | JoinType::FullOuter));
+        let mut tables = vec![(
+            &select_stmt.from_clause,
+            from_schema,
+            from_nullable_via_join,
+        )];
+        for join in &select_stmt.joins {
+            let schema = self.get_table_schema(&join.right_source.name)?.ok_or_else(|| {
+                OxidbError::Execution(format!("Table '{}' not found.", join.right_source.name))
+            })?;
+            let nullable_via_join =
+                matches!(join.join_type, JoinType::LeftOuter | JoinType::FullOuter);
+            tables.push((&join.right_source, schema, nullable_via_join));
+        }
+
+        let columns = Self::resolve_select_columns_across_tables(&select_stmt.columns, &tables)?;
+
+        let mut parameters = Vec::new();
+        if let Some(condition) = &select_stmt.condition {
+            // Parameter types are looked up in the FROM table's schema only
+            // (nullability, which depends on the join side, isn't relevant to a
+            // parameter's own bound type); a placeholder compared against a
+            // column of a JOINed table is reported as `DataType::Null`.
+            let primary_schema = tables[0].1.as_ref();
+            Self::infer_condition_tree_parameter_types(condition, primary_schema, &mut parameters);
+        }
+
+        Ok(DescribeResult { parameters, columns })
+    }
+
+    /// Resolves `select_columns` against `tables` (the FROM table plus each JOINed
+    /// table, in clause order), expanding `Asterisk` into every table's columns and
+    /// looking up `ColumnName` (qualified `table.col` or bare `col`) across all of
+    /// them. A bare name matching more than one table's columns is rejected as
+    /// ambiguous, the way a real multi-table query would reject it.
+    ///
+    /// A resolved column is nullable when either its own schema declares it
+    /// nullable, or it comes from a table on the side of an outer join that can
+    /// leave it unmatched (see `describe_select`'s `nullable_via_join` flags).
+    fn resolve_select_columns_across_tables(
+        select_columns: &[SelectColumn],
+        tables: &[(&TableReference, std::sync::Arc<Schema>, bool)],
+    ) -> Result<Vec<(String, DataType, bool)>, OxidbError> {
+        let mut columns = Vec::new();
+        for select_column in select_columns {
+            match select_column {
+                SelectColumn::Asterisk => {
+                    for (_, schema, nullable_via_join) in tables {
+                        for column_def in &schema.columns {
+                            columns.push((
+                                column_def.name.clone(),
+                                column_def.data_type.clone(),
+                                column_def.is_nullable || *nullable_via_join,
+                            ));
+                        }
+                    }
+                }
+                SelectColumn::ColumnName(name) => {
+                    columns.push(Self::resolve_qualified_column(name, tables)?);
+                }
+                SelectColumn::AggregateFunction { .. } => {
+                    return Err(OxidbError::NotImplemented {
+                        feature: "DESCRIBE on an aggregate SELECT column".to_string(),
+                    });
+                }
+            }
+        }
+        Ok(columns)
+    }
+
+    /// Resolves one (possibly `table.col`-qualified) column name against `tables`.
+    fn resolve_qualified_column(
+        name: &str,
+        tables: &[(&TableReference, std::sync::Arc<Schema>, bool)],
+    ) -> Result<(String, DataType, bool), OxidbError> {
+        if let Some((table_part, column_part)) = name.split_once('.') {
+            let (_, schema, nullable_via_join) = tables
+                .iter()
+                .find(|(table_ref, _, _)| {
+                    table_ref.alias.as_deref().unwrap_or(&table_ref.name) == table_part
+                })
+                .ok_or_else(|| OxidbError::Execution(format!("Table '{table_part}' not found.")))?;
+            let column_def = schema.columns.iter().find(|c| c.name == column_part).ok_or_else(
+                || OxidbError::Execution(format!("Column '{name}' not found.")),
+            )?;
+            return Ok((
+                column_def.name.clone(),
+                column_def.data_type.clone(),
+                column_def.is_nullable || *nullable_via_join,
+            ));
+        }
+
+        let mut found = None;
+        for (_, schema, nullable_via_join) in tables {
+            if let Some(column_def) = schema.columns.iter().find(|c| c.name == name) {
+                if found.is_some() {
+                    return Err(OxidbError::Execution(format!(
+                        "Column '{name}' is ambiguous; qualify it with a table name."
+                    )));
+                }
+                found = Some((
+                    column_def.name.clone(),
+                    column_def.data_type.clone(),
+                    column_def.is_nullable || *nullable_via_join,
+                ));
+            }
+        }
+        found.ok_or_else(|| OxidbError::Execution(format!("Column '{name}' not found.")))
+    }
+
+    fn describe_insert(&self, insert_stmt: &InsertStatement) -> Result<DescribeResult, OxidbError> {
+        let schema_arc = self.get_table_schema(&insert_stmt.table_name)?.ok_or_else(|| {
+            OxidbError::Execution(format!("Table '{}' not found.", insert_stmt.table_name))
+        })?;
+        let schema = schema_arc.as_ref();
+
+        // The columns being inserted into, in the order `values` supplies them:
+        // the explicit column list if given, else the schema's own column order.
+        let target_columns: Vec<&str> = match &insert_stmt.columns {
+            Some(cols) => cols.iter().map(String::as_str).collect(),
+            None => schema.columns.iter().map(|c| c.name.as_str()).collect(),
+        };
+
+        let mut parameters = Vec::new();
+        for row in &insert_stmt.values {
+            for (value, column_name) in row.iter().zip(target_columns.iter()) {
+                if let AstExpressionValue::Parameter(param) = value {
+                    let data_type = Self::column_data_type(schema, column_name);
+                    Self::set_parameter_type(&mut parameters, param, data_type);
+                }
+            }
+        }
+
+        let columns = match &insert_stmt.returning {
+            Some(returning_cols) => Self::resolve_select_columns(returning_cols, schema)?,
+            None => Vec::new(),
+        };
+
+        Ok(DescribeResult { parameters, columns })
+    }
+
+    fn describe_update(&self, update_stmt: &UpdateStatement) -> Result<DescribeResult, OxidbError> {
+        let schema_arc = self.get_table_schema(&update_stmt.source)?.ok_or_else(|| {
+            OxidbError::Execution(format!("Table '{}' not found.", update_stmt.source))
+        })?;
+        let schema = schema_arc.as_ref();
+
+        let mut parameters = Vec::new();
+        for assignment in &update_stmt.assignments {
+            if let AstExpressionValue::Parameter(param) = &assignment.value {
+                let data_type = Self::column_data_type(schema, &assignment.column);
+                Self::set_parameter_type(&mut parameters, param, data_type);
+            }
+        }
+        if let Some(condition) = &update_stmt.condition {
+            Self::infer_condition_tree_parameter_types(condition, schema, &mut parameters);
+        }
+
+        let columns = match &update_stmt.returning {
+            Some(returning_cols) => Self::resolve_select_columns(returning_cols, schema)?,
+            None => Vec::new(),
+        };
+
+        Ok(DescribeResult { parameters, columns })
+    }
+
+    fn describe_delete(&self, delete_stmt: &DeleteStatement) -> Result<DescribeResult, OxidbError> {
+        let schema_arc = self.get_table_schema(&delete_stmt.table_name)?.ok_or_else(|| {
+            OxidbError::Execution(format!("Table '{}' not found.", delete_stmt.table_name))
+        })?;
+        let schema = schema_arc.as_ref();
+
+        let mut parameters = Vec::new();
+        if let Some(condition) = &delete_stmt.condition {
+            Self::infer_condition_tree_parameter_types(condition, schema, &mut parameters);
+        }
+
+        let columns = match &delete_stmt.returning {
+            Some(returning_cols) => Self::resolve_select_columns(returning_cols, schema)?,
+            None => Vec::new(),
+        };
+
+        Ok(DescribeResult { parameters, columns })
+    }
+
+    /// Expands `SelectColumn::Asterisk` against `schema` and looks up each
+    /// named column's `DataType`/nullability.
+    fn resolve_select_columns(
+        select_columns: &[SelectColumn],
+        schema: &Schema,
+    ) -> Result<Vec<(String, DataType, bool)>, OxidbError> {
+        let mut columns = Vec::new();
+        for select_column in select_columns {
+            match select_column {
+                SelectColumn::Asterisk => {
+                    for column_def in &schema.columns {
+                        columns.push((
+                            column_def.name.clone(),
+                            column_def.data_type.clone(),
+                            column_def.is_nullable,
+                        ));
+                    }
+                }
+                SelectColumn::ColumnName(name) => {
+                    let column_def =
+                        schema.columns.iter().find(|c| &c.name == name).ok_or_else(|| {
+                            OxidbError::Execution(format!("Column '{name}' not found."))
+                        })?;
+                    columns.push((
+                        column_def.name.clone(),
+                        column_def.data_type.clone(),
+                        column_def.is_nullable,
+                    ));
+                }
+                SelectColumn::AggregateFunction { .. } => {
+                    return Err(OxidbError::NotImplemented {
+                        feature: "DESCRIBE on an aggregate SELECT column".to_string(),
+                    });
+                }
+            }
+        }
+        Ok(columns)
+    }
+
+    /// Walks `condition_tree`, recording the inferred `DataType` of every
+    /// `?` placeholder from the column it's compared against. Leaves
+    /// `parameters` untouched for comparisons with no placeholder.
+    fn infer_condition_tree_parameter_types(
+        condition_tree: &ConditionTree,
+        schema: &Schema,
+        parameters: &mut Vec<DataType>,
+    ) {
+        match condition_tree {
+            ConditionTree::Comparison(condition) => {
+                if let AstExpressionValue::Parameter(param) = &condition.value {
+                    let data_type = Self::column_data_type(schema, &condition.column);
+                    Self::set_parameter_type(parameters, param, data_type);
+                }
+            }
+            ConditionTree::And(left, right) | ConditionTree::Or(left, right) => {
+                Self::infer_condition_tree_parameter_types(left, schema, parameters);
+                Self::infer_condition_tree_parameter_types(right, schema, parameters);
+            }
+            ConditionTree::Not(inner) => {
+                Self::infer_condition_tree_parameter_types(inner, schema, parameters);
+            }
+        }
+    }
+
+    fn column_data_type(schema: &Schema, column_name: &str) -> DataType {
+        schema
+            .columns
+            .iter()
+            .find(|c| c.name == column_name)
+            .map_or(DataType::Null, |c| c.data_type.clone())
+    }
+
+    /// Grows `parameters` with `DataType::Null` placeholders as needed so the
+    /// slot for `param` can be set to `data_type`. Bare `?` placeholders are
+    /// positioned by their 0-based occurrence index; `?N`/`$N` placeholders by
+    /// `N - 1`. Named (`:name`) placeholders have no positional slot in this
+    /// `Vec`-shaped result, so they're reported in occurrence order instead,
+    /// appended after the highest positional/numbered slot seen so far.
+    fn set_parameter_type(parameters: &mut Vec<DataType>, param: &AstParameter, data_type: DataType) {
+        let index = match param {
+            AstParameter::Positional(index) => *index as usize,
+            AstParameter::Numbered(number) => number.saturating_sub(1) as usize,
+            AstParameter::Named(_) => parameters.len(),
+        };
+        if parameters.len() <= index {
+            parameters.resize(index + 1, DataType::Null);
+        }
+        parameters[index] = data_type;
+    }
+}
@@ -0,0 +1,79 @@
+use super::{ExecutionResult, QueryExecutor};
+use crate::core::common::OxidbError;
+use crate::core::storage::engine::traits::KeyValueStore;
+use crate::core::types::schema::ColumnDef;
+use crate::core::types::DataType;
+
+impl<S: KeyValueStore<Vec<u8>, Vec<u8>>> QueryExecutor<S> {
+    /// Handles `Command::CreateEnumType`, registering `name` in the enum-type
+    /// catalog with `variants` as its allowed values.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::AlreadyExists` if `name` is already registered.
+    pub(crate) fn handle_create_enum_type(
+        &mut self,
+        name: String,
+        variants: Vec<String>,
+    ) -> Result<ExecutionResult, OxidbError> {
+        if self.enum_types.contains_key(&name) {
+            return Err(OxidbError::AlreadyExists { name });
+        }
+        self.enum_types.insert(name, variants);
+        Ok(ExecutionResult::Success)
+    }
+
+    /// Looks up `type_name`'s registered variant set, if it's been registered
+    /// via `CREATE TYPE ... AS ENUM`.
+    pub(crate) fn enum_variants(&self, type_name: &str) -> Option<&Vec<String>> {
+        self.enum_types.get(type_name)
+    }
+}
+
+/// If `col_def` is an enum-typed column, checks `value` against
+/// `executor`'s registered variant set for its type, coercing a plain
+/// `DataType::String` (the only form a value parsed from SQL text can take)
+/// into the matching `DataType::Enum` on success.
+///
+/// # Errors
+/// Returns `OxidbError::ConstraintViolation` if `value` isn't one of the
+/// registered variants, or `OxidbError::Execution` if the column's enum type
+/// itself was never registered.
+pub(crate) fn coerce_and_validate_enum_value<S: KeyValueStore<Vec<u8>, Vec<u8>>>(
+    executor: &QueryExecutor<S>,
+    col_def: &ColumnDef,
+    value: DataType,
+) -> Result<DataType, OxidbError> {
+    let DataType::Enum { type_name, .. } = &col_def.data_type else {
+        return Ok(value);
+    };
+    if value == DataType::Null {
+        return Ok(value);
+    }
+
+    let variants = executor.enum_variants(type_name).ok_or_else(|| {
+        OxidbError::Execution(format!(
+            "Column '{}' references enum type '{type_name}', which is not registered.",
+            col_def.name
+        ))
+    })?;
+
+    let candidate = match &value {
+        DataType::String(s) => s.clone(),
+        DataType::Enum { value: v, .. } => v.clone(),
+        other => {
+            return Err(OxidbError::ConstraintViolation(format!(
+                "Column '{}' is enum type '{type_name}', but got non-text value {other:?}.",
+                col_def.name
+            )))
+        }
+    };
+
+    if !variants.iter().any(|variant| variant == &candidate) {
+        return Err(OxidbError::ConstraintViolation(format!(
+            "'{candidate}' is not a valid variant of enum type '{type_name}' for column '{}'.",
+            col_def.name
+        )));
+    }
+
+    Ok(DataType::Enum { type_name: type_name.clone(), value: candidate })
+}
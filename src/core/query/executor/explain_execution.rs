@@ -0,0 +1,163 @@
+// src/core/query/executor/explain_execution.rs
+use super::{ExecutionResult, QueryExecutor};
+use crate::core::common::OxidbError;
+use crate::core::optimizer::QueryPlanNode;
+use crate::core::query::commands::Command;
+use crate::core::storage::engine::traits::KeyValueStore;
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// The result of `EXPLAIN`/`EXPLAIN ANALYZE`: a plan tree mirroring the
+/// optimizer's `QueryPlanNode`, annotated with measured counters when
+/// `analyzed` is set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryPlan {
+    pub root: PlanNode,
+    pub analyzed: bool,
+}
+
+/// One node of a [`QueryPlan`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanNode {
+    /// Human-readable description, e.g. `"Index Scan using idx_posts_user_id on posts"`.
+    pub operation: String,
+    /// Measured statistics; `None` unless this plan was built with `ANALYZE`.
+    pub actual: Option<ActualStats>,
+    pub children: Vec<PlanNode>,
+}
+
+/// Measured execution statistics for a single [`PlanNode`], populated by
+/// `EXPLAIN ANALYZE`. There's no buffer pool in `OxiDB` yet, so every row
+/// fetched from the underlying store counts as a `buffer_misses` access
+/// rather than a cache hit; an `IndexScan` node still counts as a
+/// `buffer_hits` access, mirroring how `IndexCacheHit`/`IndexCacheMiss`
+/// (see [`crate::core::performance::events`]) distinguish "used an index"
+/// from "fell back to a full table scan".
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ActualStats {
+    /// Rows this node actually produced.
+    pub rows: u64,
+    /// Number of times this node was (re-)driven; always 1 outside of joins.
+    pub loops: u64,
+    /// Wall-clock time spent in this node and everything beneath it.
+    pub total_time: Duration,
+    /// `total_time` minus the sum of the children's `total_time`.
+    pub self_time: Duration,
+    pub buffer_hits: u64,
+    pub buffer_misses: u64,
+}
+
+impl fmt::Display for QueryPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_plan_node(f, &self.root, 0)
+    }
+}
+
+fn write_plan_node(f: &mut fmt::Formatter<'_>, node: &PlanNode, depth: usize) -> fmt::Result {
+    write!(f, "{}{}", "  ".repeat(depth), node.operation)?;
+    match &node.actual {
+        Some(actual) => writeln!(
+            f,
+            " (actual rows={} loops={} time={:.3}ms buffers: hit={} miss={})",
+            actual.rows,
+            actual.loops,
+            actual.total_time.as_secs_f64() * 1000.0,
+            actual.buffer_hits,
+            actual.buffer_misses,
+        )?,
+        None => writeln!(f)?,
+    }
+    for child in &node.children {
+        write_plan_node(f, child, depth + 1)?;
+    }
+    Ok(())
+}
+
+impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S> {
+    /// Handles `Command::Explain`. Only `Command::Select` is supported today,
+    /// since that's the only statement the planner/optimizer path produces a
+    /// `QueryPlanNode` tree for.
+    pub(crate) fn handle_explain(
+        &mut self,
+        statement: Box<Command>,
+        analyze: bool,
+    ) -> Result<ExecutionResult, OxidbError> {
+        let (plan, snapshot_id, committed_ids) = match *statement {
+            Command::Select { columns, source, condition, .. } => {
+                self.build_select_query_plan(columns, source, condition)?
+            }
+            _ => {
+                return Err(OxidbError::NotImplemented {
+                    feature: "EXPLAIN for this command type".to_string(),
+                })
+            }
+        };
+
+        let root = self.explain_plan_node(&plan, analyze, snapshot_id.0, committed_ids)?;
+        Ok(ExecutionResult::Explain(QueryPlan { root, analyzed: analyze }))
+    }
+
+    fn explain_plan_node(
+        &self,
+        plan: &QueryPlanNode,
+        analyze: bool,
+        snapshot_id: u64,
+        committed_ids: Arc<HashSet<u64>>,
+    ) -> Result<PlanNode, OxidbError> {
+        let operation = describe_plan_node(plan);
+
+        let mut children = Vec::new();
+        for child in child_plan_nodes(plan) {
+            children.push(self.explain_plan_node(child, analyze, snapshot_id, committed_ids.clone())?);
+        }
+
+        let actual = if analyze {
+            let started = Instant::now();
+            let mut operator = self.build_execution_tree(plan.clone(), snapshot_id, committed_ids)?;
+            let mut rows = 0u64;
+            for tuple_result in operator.execute()? {
+                tuple_result?;
+                rows += 1;
+            }
+            let total_time = started.elapsed();
+            let children_time: Duration =
+                children.iter().filter_map(|c| c.actual.as_ref().map(|a| a.total_time)).sum();
+            let self_time = total_time.saturating_sub(children_time);
+            let (buffer_hits, buffer_misses) = match plan {
+                QueryPlanNode::IndexScan { .. } => (rows, 0),
+                QueryPlanNode::TableScan { .. } => (0, rows),
+                _ => (0, 0),
+            };
+            Some(ActualStats { rows, loops: 1, total_time, self_time, buffer_hits, buffer_misses })
+        } else {
+            None
+        };
+
+        Ok(PlanNode { operation, actual, children })
+    }
+}
+
+fn child_plan_nodes(plan: &QueryPlanNode) -> Vec<&QueryPlanNode> {
+    match plan {
+        QueryPlanNode::TableScan { .. } | QueryPlanNode::IndexScan { .. } => Vec::new(),
+        QueryPlanNode::Filter { input, .. }
+        | QueryPlanNode::Project { input, .. }
+        | QueryPlanNode::DeleteNode { input, .. } => vec![input.as_ref()],
+        QueryPlanNode::NestedLoopJoin { left, right, .. } => vec![left.as_ref(), right.as_ref()],
+    }
+}
+
+fn describe_plan_node(plan: &QueryPlanNode) -> String {
+    match plan {
+        QueryPlanNode::TableScan { table_name, .. } => format!("Seq Scan on {table_name}"),
+        QueryPlanNode::IndexScan { index_name, table_name, .. } => {
+            format!("Index Scan using {index_name} on {table_name}")
+        }
+        QueryPlanNode::Filter { .. } => "Filter".to_string(),
+        QueryPlanNode::Project { columns, .. } => format!("Project ({})", columns.join(", ")),
+        QueryPlanNode::NestedLoopJoin { .. } => "Nested Loop Join".to_string(),
+        QueryPlanNode::DeleteNode { table_name, .. } => format!("Delete on {table_name}"),
+    }
+}
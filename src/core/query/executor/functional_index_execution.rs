@@ -0,0 +1,86 @@
+// src/core/query/executor/functional_index_execution.rs
+
+use super::QueryExecutor;
+use crate::core::common::serialization::serialize_data_type;
+use crate::core::common::OxidbError;
+use crate::core::query::commands::Key as PrimaryKey;
+use crate::core::types::DataType;
+use std::collections::HashMap;
+
+impl<S: crate::core::storage::engine::traits::KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static>
+    QueryExecutor<S>
+{
+    /// Recomputes every functional `CREATE INDEX` registered over
+    /// `table_name` for an inserted `row` and inserts the result, unless the
+    /// expression evaluates to `Null` - the same "don't index nulls"
+    /// convention the per-column index loop in `SqlInsert` uses. Called
+    /// alongside `maintain_aggregate_indexes_on_insert`.
+    pub(crate) fn maintain_functional_indexes_on_insert(
+        &mut self,
+        table_name: &str,
+        row: &HashMap<Vec<u8>, DataType>,
+        primary_key: &PrimaryKey,
+    ) -> Result<(), OxidbError> {
+        let manager = self.index_manager.read().map_err(|e| {
+            OxidbError::LockTimeout(format!(
+                "Failed to acquire read lock on index manager for functional index maintenance: {e}"
+            ))
+        })?;
+        let updates: Vec<(String, Vec<u8>)> = manager
+            .functional_indexes_for_table(table_name)
+            .map(|(index_name, expr)| Ok((index_name.to_string(), expr.evaluate(row)?)))
+            .collect::<Result<Vec<(String, DataType)>, OxidbError>>()?
+            .into_iter()
+            .filter(|(_, value)| *value != DataType::Null)
+            .map(|(index_name, value)| Ok((index_name, serialize_data_type(&value)?)))
+            .collect::<Result<Vec<_>, OxidbError>>()?;
+        drop(manager);
+
+        for (index_name, serialized_value) in updates {
+            self.index_manager
+                .write()
+                .map_err(|e| {
+                    OxidbError::LockTimeout(format!(
+                        "Failed to acquire write lock on index manager for functional index maintenance: {e}"
+                    ))
+                })?
+                .insert_into_index(&index_name, &serialized_value, primary_key)?;
+        }
+        Ok(())
+    }
+
+    /// The delete-side counterpart of `maintain_functional_indexes_on_insert`.
+    pub(crate) fn maintain_functional_indexes_on_delete(
+        &mut self,
+        table_name: &str,
+        row: &HashMap<Vec<u8>, DataType>,
+        primary_key: &PrimaryKey,
+    ) -> Result<(), OxidbError> {
+        let manager = self.index_manager.read().map_err(|e| {
+            OxidbError::LockTimeout(format!(
+                "Failed to acquire read lock on index manager for functional index maintenance: {e}"
+            ))
+        })?;
+        let updates: Vec<(String, Vec<u8>)> = manager
+            .functional_indexes_for_table(table_name)
+            .map(|(index_name, expr)| Ok((index_name.to_string(), expr.evaluate(row)?)))
+            .collect::<Result<Vec<(String, DataType)>, OxidbError>>()?
+            .into_iter()
+            .filter(|(_, value)| *value != DataType::Null)
+            .map(|(index_name, value)| Ok((index_name, serialize_data_type(&value)?)))
+            .collect::<Result<Vec<_>, OxidbError>>()?;
+        drop(manager);
+
+        for (index_name, serialized_value) in updates {
+            self.index_manager
+                .write()
+                .map_err(|e| {
+                    OxidbError::LockTimeout(format!(
+                        "Failed to acquire write lock on index manager for functional index maintenance: {e}"
+                    ))
+                })?
+                .delete_from_index(&index_name, &serialized_value, Some(primary_key))?;
+        }
+        Ok(())
+    }
+}
@@ -1,16 +1,28 @@
 // src/core/query/executor/mod.rs
 
 // Module declarations
+pub mod aggregate_execution;
+pub mod batch_execution;
 pub mod command_handlers;
+pub mod commit_observer;
 pub mod ddl_handlers;
+pub mod describe_execution;
+pub mod enum_execution;
+pub mod explain_execution;
+pub mod functional_index_execution;
 pub mod planner; // Added planner module
 pub mod processors;
 pub mod select_execution;
+pub mod stats;
+pub mod subquery_execution;
 #[cfg(test)]
 pub mod tests;
 pub mod transaction_handlers;
+pub mod trigger_execution;
+pub mod tx_observer;
 pub mod update_execution;
 pub mod utils;
+pub mod validation_execution;
 
 // Re-export planner contents
 
@@ -19,7 +31,7 @@ use crate::core::common::types::TransactionId; // Ensure TransactionId is import
 use crate::core::common::OxidbError;
 use crate::core::indexing::manager::IndexManager;
 use crate::core::optimizer::Optimizer;
-use crate::core::query::sql::ast::AstLiteralValue;
+use crate::core::recovery::RecoveryManager;
 use crate::core::storage::engine::traits::KeyValueStore;
 use crate::core::storage::engine::SimpleFileKvStore;
 use crate::core::transaction::lock_manager::LockManager;
@@ -35,19 +47,38 @@ use std::path::PathBuf;
 use std::sync::{Arc, RwLock};
 use uuid;
 
+/// Identifies one distinct parameter placeholder referenced by a statement,
+/// used to validate that every placeholder has a bound value before execution.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum ParamKey {
+    Positional(u32),
+    Numbered(u32),
+    Named(String),
+}
+
 /// Context for resolving parameter placeholders during execution
 #[derive(Debug)]
 pub struct ParameterContext<'a> {
     parameters: &'a [crate::core::common::types::Value],
+    named_parameters: Option<&'a HashMap<String, crate::core::common::types::Value>>,
 }
 
 impl<'a> ParameterContext<'a> {
     #[must_use]
     pub const fn new(parameters: &'a [crate::core::common::types::Value]) -> Self {
-        Self { parameters }
+        Self { parameters, named_parameters: None }
+    }
+
+    /// Like [`Self::new`], but also binds named (`:name`) placeholders.
+    #[must_use]
+    pub const fn new_with_named(
+        parameters: &'a [crate::core::common::types::Value],
+        named_parameters: &'a HashMap<String, crate::core::common::types::Value>,
+    ) -> Self {
+        Self { parameters, named_parameters: Some(named_parameters) }
     }
 
-    /// Resolve a parameter by its index
+    /// Resolve a bare positional `?` parameter by its 0-based occurrence index
     pub fn resolve_parameter(
         &self,
         index: u32,
@@ -62,19 +93,50 @@ impl<'a> ParameterContext<'a> {
         })
     }
 
+    /// Resolve an explicitly numbered `?N`/`$N` parameter (1-based, as written)
+    pub fn resolve_numbered_parameter(
+        &self,
+        number: u32,
+    ) -> Result<&crate::core::common::types::Value, OxidbError> {
+        let idx = number.saturating_sub(1) as usize;
+        self.parameters.get(idx).ok_or_else(|| OxidbError::InvalidInput {
+            message: format!(
+                "Parameter index {} out of bounds (have {} parameters)",
+                number,
+                self.parameters.len()
+            ),
+        })
+    }
+
+    /// Resolve a named (`:name`) parameter
+    pub fn resolve_named_parameter(
+        &self,
+        name: &str,
+    ) -> Result<&crate::core::common::types::Value, OxidbError> {
+        self.named_parameters
+            .and_then(|named| named.get(name))
+            .ok_or_else(|| OxidbError::InvalidInput {
+                message: format!("No value bound for named parameter ':{name}'"),
+            })
+    }
+
     /// Convert an `AstExpressionValue` to a `DataType`, resolving parameters
     pub fn resolve_expression_value(
         &self,
         expr: &crate::core::query::sql::ast::AstExpressionValue,
     ) -> Result<DataType, OxidbError> {
+        use crate::core::query::sql::ast::AstParameter;
         match expr {
             crate::core::query::sql::ast::AstExpressionValue::Literal(literal) => {
                 // Convert literal to DataType
                 self.convert_literal_to_datatype(literal)
             }
-            crate::core::query::sql::ast::AstExpressionValue::Parameter(index) => {
-                // Resolve parameter and convert to DataType
-                let param_value = self.resolve_parameter(*index)?;
+            crate::core::query::sql::ast::AstExpressionValue::Parameter(param) => {
+                let param_value = match param {
+                    AstParameter::Positional(index) => self.resolve_parameter(*index)?,
+                    AstParameter::Numbered(number) => self.resolve_numbered_parameter(*number)?,
+                    AstParameter::Named(name) => self.resolve_named_parameter(name)?,
+                };
                 Ok(self.convert_value_to_datatype(param_value))
             }
             crate::core::query::sql::ast::AstExpressionValue::ColumnIdentifier(_) => {
@@ -142,6 +204,32 @@ pub enum ExecutionResult {
     Values(Vec<DataType>),
     Updated { count: usize },                 // Added for update operations
     RankedResults(Vec<(f32, Vec<DataType>)>), // For similarity search results (distance, row_data)
+    /// Result of `Command::Describe`: parameter and column type metadata for
+    /// a statement that was analyzed but not executed.
+    Describe(crate::core::query::commands::DescribeResult),
+    /// Result of `Command::Batch`: each item command's outcome, in order.
+    Batch(Vec<BatchItemResult>),
+    /// Result of `Command::Explain`: the statement's plan tree, annotated
+    /// with measured counters when `analyze` was requested.
+    Explain(crate::core::query::executor::explain_execution::QueryPlan),
+}
+
+/// One `Command::Batch` item's outcome. Failure carries the stringified
+/// `OxidbError` rather than the error itself, since `OxidbError` wraps types
+/// like `std::io::Error` that can't derive `PartialEq`.
+#[derive(Debug, PartialEq)]
+pub enum BatchItemResult {
+    Ok(ExecutionResult),
+    Err(String),
+}
+
+/// Controls how `QueryExecutor::handle_upsert_rows` resolves a primary-key conflict.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UpsertConflictMode {
+    /// PUT: overwrite the existing row in place.
+    Overwrite,
+    /// ENSURE: no-op if the existing row is identical, error otherwise.
+    EnsureMatch,
 }
 
 #[derive(Debug)]
@@ -160,6 +248,44 @@ pub struct QueryExecutor<S: KeyValueStore<Vec<u8>, Vec<u8>>> {
     pub(crate) log_manager: Arc<LogManager>,
     /// Tracks the next auto-increment value for each table.column combination
     pub(crate) auto_increment_state: HashMap<String, i64>,
+    /// The enum-type catalog: each registered `CREATE TYPE ... AS ENUM` name,
+    /// keyed by its name, mapped to its allowed variant values in declaration
+    /// order.
+    pub(crate) enum_types: HashMap<String, Vec<String>>,
+    /// Each table's registered `Command::AddValidationRule` rules, keyed by
+    /// table name, checked against every candidate row on `SqlInsert`/`Update`.
+    pub(crate) validation_rules: HashMap<String, Vec<validation_execution::ValidationRule>>,
+    /// Each table's registered `Command::CreateTrigger` triggers, keyed by
+    /// table name, fired by `fire_triggers` around `SqlInsert`/`Update`/`SqlDelete`.
+    pub(crate) triggers: HashMap<String, Vec<trigger_execution::TriggerDefinition>>,
+    /// How many trigger bodies are currently nested inside one another, via
+    /// `fire_triggers` re-entering `execute_command`. Capped at
+    /// `trigger_execution::MAX_TRIGGER_DEPTH` to turn a trigger cascade (a
+    /// trigger body that writes to a table with a trigger of its own, and so
+    /// on) into an error instead of a stack overflow.
+    pub(crate) trigger_depth: usize,
+    /// The row a DELETE/UPDATE trigger fired for, made available to trigger
+    /// bodies as `OLD` for the duration of `fire_triggers`. There is no SQL
+    /// syntax yet for a trigger body to reference `OLD.<column>`, so this is
+    /// currently only programmatically readable; wiring it into the SQL
+    /// grammar is tracked as future work.
+    pub(crate) trigger_old_row: Option<HashMap<Vec<u8>, DataType>>,
+    /// Callbacks notified with a commit `Event` once a transaction's writes
+    /// are durable, via `notify_commit_observers` in `commit_observer`.
+    pub(crate) commit_observers: crate::event_engine::observer::ObserverRegistry,
+    /// `TxObserver`s notified with a [`crate::core::wal::TxReport`] once a
+    /// transaction's commit is durable, via `notify_tx_observers` in
+    /// `transaction_handlers`.
+    pub(crate) tx_observers: crate::core::wal::TxObserverRegistry,
+    /// Sink for discrete profiling events (`IndexCacheHit`/`IndexCacheMiss`),
+    /// attached via [`Self::attach_profile_events`] when a `Connection`
+    /// enables performance monitoring. `None` by default, so there's no
+    /// recording overhead while profiling is off.
+    pub(crate) profile_events: Option<Arc<crate::core::performance::events::ProfileEventLog>>,
+    /// Stats from the ARIES recovery run performed in `new`, if the WAL file already
+    /// existed. Surfaced read-only via `Connection::stats`; `None` for a brand-new
+    /// database that had no WAL history to replay.
+    pub(crate) last_recovery_stats: Option<crate::core::recovery::RecoveryStats>,
 }
 
 // UniquenessCheckContext struct definition is removed as part of the revert.
@@ -180,6 +306,8 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>>> QueryExecutor<S> {
             })?;
         }
 
+        let wal_file_path = wal_writer.wal_file_path().to_path_buf();
+
         // Pass a clone of log_manager to TransactionManager, store original in self
         let mut transaction_manager = TransactionManager::new(wal_writer, log_manager.clone());
         transaction_manager.add_committed_tx_id(TransactionId(0)); // Use TransactionId struct
@@ -193,13 +321,76 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>>> QueryExecutor<S> {
             index_manager: index_manager_arc,
             log_manager,                          // Store log_manager
             auto_increment_state: HashMap::new(), // Initialize auto-increment state
+            enum_types: HashMap::new(),            // Initialize enum-type catalog
+            validation_rules: HashMap::new(),      // Initialize validation-rule store
+            triggers: HashMap::new(),              // Initialize trigger store
+            trigger_depth: 0,
+            trigger_old_row: None,
+            commit_observers: crate::event_engine::observer::ObserverRegistry::new(),
+            tx_observers: crate::core::wal::TxObserverRegistry::new(),
+            profile_events: None,
+            last_recovery_stats: None,
         };
 
+        // Replay any WAL history left behind by a previous, uncleanly-stopped
+        // process before trusting the persisted store/indexes: analysis finds
+        // the last checkpoint and in-flight transactions, redo repeats history
+        // forward, and undo rolls back anything that never committed. A fresh
+        // or empty WAL file makes every phase a no-op.
+        executor.last_recovery_stats = Self::recover_from_wal(&wal_file_path)?;
+
         // Load auto-increment state from existing data
         executor.load_auto_increment_state()?;
 
+        // `IndexManager::new` loaded whatever index files were last saved to
+        // disk, which can be stale relative to rows the Redo phase just
+        // replayed (or the Undo phase just rolled back) above: index writes
+        // and store writes aren't flushed in lockstep. Re-apply every
+        // registered column index against the store's current contents so a
+        // crash between a row write and its index update doesn't leave a
+        // query unable to find that row by index. Entries pointing at rows
+        // that are no longer in the store are not removed.
+        executor.rebuild_indexes_from_store()?;
+
         Ok(executor)
     }
+
+    /// Attaches `log` so planning decisions (index scan vs. full table scan)
+    /// append `ProfileEvent::IndexCacheHit`/`IndexCacheMiss` to it. Called by
+    /// `Connection::enable_performance_monitoring`.
+    pub fn attach_profile_events(&mut self, log: Arc<crate::core::performance::events::ProfileEventLog>) {
+        self.profile_events = Some(log);
+    }
+
+    /// Appends `event` to the attached profile event log, if any.
+    pub(crate) fn record_profile_event(&self, event: crate::core::performance::events::ProfileEvent) {
+        if let Some(log) = &self.profile_events {
+            log.record(event);
+        }
+    }
+
+    /// Runs ARIES recovery (analysis, redo, undo) against the WAL file at
+    /// `wal_file_path`, via [`RecoveryManager`], returning its stats for
+    /// `Connection::stats` to surface. Returns `None` if there was no WAL file to
+    /// recover from.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if the WAL file can't be read or a log record is
+    /// malformed.
+    fn recover_from_wal(
+        wal_file_path: &std::path::Path,
+    ) -> Result<Option<crate::core::recovery::RecoveryStats>, OxidbError> {
+        // A brand-new database has no WAL file yet; there's nothing to
+        // recover, and `WalReader` treats a missing file as an error rather
+        // than an empty log.
+        if !wal_file_path.exists() {
+            return Ok(None);
+        }
+
+        let mut recovery_manager = RecoveryManager::from_wal_file(wal_file_path)?;
+        let stats = recovery_manager.recover()?;
+        Ok(Some(stats))
+    }
 }
 
 // Methods specific to QueryExecutor when the store is SimpleFileKvStore
@@ -306,17 +497,21 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S
         let serialized_value =
             crate::core::common::serialization::serialize_data_type(value_to_check)?;
 
-        // 3. Call self.index_manager.find_by_index
-        match self
-            .index_manager
-            .read()
-            .map_err(|e| {
-                OxidbError::LockTimeout(format!(
-                    "Failed to acquire read lock on index manager for check_uniqueness: {e}"
-                ))
-            })?
-            .find_by_index(&index_name, &serialized_value)
-        {
+        // 3. Fast path: if nothing holds this value at all, it's unique,
+        // without materializing a PK list to find that out (this is the
+        // common case, and the whole reason `Index::cardinality` exists).
+        let index_manager_guard = self.index_manager.read().map_err(|e| {
+            OxidbError::LockTimeout(format!(
+                "Failed to acquire read lock on index manager for check_uniqueness: {e}"
+            ))
+        })?;
+        if index_manager_guard.cardinality(&index_name, &serialized_value)? == 0 {
+            return Ok(());
+        }
+
+        // 4. Otherwise resolve the actual primary keys, since an UPDATE must
+        // still be allowed to "conflict" only with itself.
+        match index_manager_guard.find_by_index(&index_name, &serialized_value) {
             Ok(Some(pks)) => {
                 if pks.is_empty() {
                     eprintln!("[Executor::check_uniqueness] Warning: Value {value_to_check:?} found in index '{index_name}' but with no associated primary keys.");
@@ -657,6 +852,7 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S
             crate::core::query::sql::ast::DeleteStatement {
                 table_name: table_name.clone(), // Optimizer expects String
                 condition: ast_condition_tree,  // Changed
+                returning: None, // RETURNING is handled by the CommandProcessor, not this internal plan.
             },
         );
 
@@ -749,9 +945,31 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S
                 }
             };
 
-            // Per-column index deletions
+            // BEFORE DELETE triggers. The row's physical key removal already
+            // happened inside the `DeleteOperator` iterator above, so this
+            // isn't strictly "before" the underlying store write - it is
+            // before the index/undo-log bookkeeping below, which is the
+            // closest this executor's plan-then-cleanup structure gets.
+            self.fire_triggers(
+                &table_name,
+                crate::core::query::commands::TriggerEvent::Delete,
+                crate::core::query::commands::TriggerTiming::Before,
+                Some(&deleted_row_map_data),
+            )?;
+
+            // Per-column index deletions. Mirrors the insert-side loop in
+            // `processors.rs`'s `SqlInsert` handling: any column with a
+            // registered `idx_<table>_<col>` index is de-indexed here, not
+            // just primary-key/unique columns.
             for col_def in &schema.columns {
-                if col_def.is_primary_key || col_def.is_unique {
+                let index_name = format!("idx_{}_{}", table_name, col_def.name);
+                let index_exists = self
+                    .index_manager
+                    .read()
+                    .map_err(|e| OxidbError::LockTimeout(format!("Failed to acquire read lock on index manager for delete: {e}")))?
+                    .get_index(&index_name)
+                    .is_some();
+                if col_def.is_primary_key || col_def.is_unique || index_exists {
                     let value_for_column = deleted_row_map_data
                         .get(col_def.name.as_bytes())
                         .cloned()
@@ -761,7 +979,6 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S
                         continue; // Skip de-indexing NULLs for non-PK unique columns
                     }
 
-                    let index_name = format!("idx_{}_{}", table_name, col_def.name);
                     let serialized_column_value =
                         crate::core::common::serialization::serialize_data_type(&value_for_column)?;
 
@@ -789,6 +1006,15 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S
                 }
             }
 
+            // Maintain any `CREATE AGGREGATE INDEX` registered over this table.
+            self.maintain_aggregate_indexes_on_delete(&table_name, &deleted_row_map_data)?;
+            // Maintain any functional `CREATE INDEX` registered over this table.
+            self.maintain_functional_indexes_on_delete(
+                &table_name,
+                &deleted_row_map_data,
+                &key_to_delete,
+            )?;
+
             // Add undo log for the main row data deletion (RevertDelete)
             // This should be done for each actual deleted row.
             // The low-level `self.store.delete` inside DeleteOperator already logged a WAL entry for the physical delete.
@@ -830,6 +1056,14 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S
                 .write()
                 .unwrap()
                 .on_delete_data(&default_index_map, &key_to_delete)?; // Acquire write lock
+
+            // AFTER DELETE triggers.
+            self.fire_triggers(
+                &table_name,
+                crate::core::query::commands::TriggerEvent::Delete,
+                crate::core::query::commands::TriggerTiming::After,
+                Some(&deleted_row_map_data),
+            )?;
         }
 
         // 5. Handle Auto-Commit for physical WAL
@@ -839,6 +1073,276 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S
         Ok(ExecutionResult::Updated { count: deleted_count })
     }
 
+    /// Handles a PUT (upsert-by-primary-key). Rows are mapped to columns like
+    /// `SqlInsert` (see `handle_upsert_rows` for which of its steps apply), except
+    /// that a primary-key conflict overwrites the existing row in place (after
+    /// de-indexing its old unique-column values) instead of raising a
+    /// `ConstraintViolation`.
+    pub(crate) fn handle_put(
+        &mut self,
+        table_name: String,
+        columns: Option<Vec<String>>,
+        values: Vec<Vec<DataType>>,
+    ) -> Result<ExecutionResult, OxidbError> {
+        self.handle_upsert_rows(table_name, columns, values, UpsertConflictMode::Overwrite)
+    }
+
+    /// Handles an ENSURE (assert-or-insert). Inserts the row if no row exists for
+    /// its primary key. If a row already exists, succeeds as a no-op when it is
+    /// identical to the provided values, or fails with a `ConstraintViolation`
+    /// when it differs.
+    pub(crate) fn handle_ensure(
+        &mut self,
+        table_name: String,
+        columns: Option<Vec<String>>,
+        values: Vec<Vec<DataType>>,
+    ) -> Result<ExecutionResult, OxidbError> {
+        self.handle_upsert_rows(table_name, columns, values, UpsertConflictMode::EnsureMatch)
+    }
+
+    /// Handles ENSURE NOT (assert-absent). Fails if any row matches `condition`,
+    /// otherwise succeeds as a no-op.
+    pub(crate) fn handle_ensure_not(
+        &mut self,
+        table_name: String,
+        condition: Option<crate::core::query::commands::SqlConditionTree>,
+    ) -> Result<ExecutionResult, OxidbError> {
+        let columns_spec = crate::core::query::commands::SelectColumnSpec::All;
+        match self.handle_select(columns_spec, table_name.clone(), condition)? {
+            ExecutionResult::Values(values) if values.is_empty() => {
+                Ok(ExecutionResult::Updated { count: 0 })
+            }
+            ExecutionResult::Values(_) => Err(OxidbError::ConstraintViolation(format!(
+                "ENSURE NOT failed: a matching row already exists in table '{table_name}'."
+            ))),
+            other => Ok(other),
+        }
+    }
+
+    /// Shared by `handle_put` and `handle_ensure`: maps each row's values to
+    /// columns the same way `SqlInsert` does, but resolves a primary-key conflict
+    /// according to `mode` instead of always raising a uniqueness violation.
+    ///
+    /// Only the NOT NULL and UNIQUE checks and the primary-key/unique-column
+    /// indexes are applied. Type affinity, length limits, auto-increment,
+    /// validation rules, triggers, and other column, aggregate or functional
+    /// indexes are not.
+    fn handle_upsert_rows(
+        &mut self,
+        table_name: String,
+        columns: Option<Vec<String>>,
+        values: Vec<Vec<DataType>>,
+        mode: UpsertConflictMode,
+    ) -> Result<ExecutionResult, OxidbError> {
+        let schema_arc = self.get_table_schema(&table_name)?.ok_or_else(|| {
+            OxidbError::Execution(format!("Table '{table_name}' not found."))
+        })?;
+        let schema = schema_arc.as_ref();
+
+        let current_op_tx_id =
+            self.transaction_manager.current_active_transaction_id().unwrap_or(TransactionId(0));
+
+        let mut affected_count = 0usize;
+
+        for row_values in &values {
+            let mut row_map_data = HashMap::new();
+
+            if let Some(insert_column_names) = &columns {
+                if insert_column_names.len() != row_values.len() {
+                    return Err(OxidbError::Execution(
+                        "Column count does not match value count for PUT/ENSURE.".to_string(),
+                    ));
+                }
+                for (col_name, value) in insert_column_names.iter().zip(row_values.iter()) {
+                    row_map_data.insert(col_name.as_bytes().to_vec(), value.clone());
+                }
+            } else {
+                if schema.columns.len() != row_values.len() {
+                    return Err(OxidbError::Execution(
+                        "Column count does not match value count for PUT/ENSURE (schema order)."
+                            .to_string(),
+                    ));
+                }
+                for (col_def, value) in schema.columns.iter().zip(row_values.iter()) {
+                    row_map_data.insert(col_def.name.as_bytes().to_vec(), value.clone());
+                }
+            }
+
+            let mut pk_value_opt: Option<DataType> = None;
+            let mut pk_col_name_opt: Option<String> = None;
+            for col_def in &schema.columns {
+                if col_def.is_primary_key {
+                    pk_value_opt = Some(
+                        row_map_data.get(col_def.name.as_bytes()).cloned().unwrap_or(DataType::Null),
+                    );
+                    pk_col_name_opt = Some(col_def.name.clone());
+                }
+            }
+
+            let kv_key = if let (Some(pk_val), Some(pk_c_name)) = (&pk_value_opt, &pk_col_name_opt)
+            {
+                if pk_c_name == "_kv_key" {
+                    match pk_val {
+                        DataType::String(pk_str_val) => pk_str_val.as_bytes().to_vec(),
+                        _ => format!("{table_name}_pk_{pk_c_name}_{pk_val:?}")
+                            .replace("Integer(", "")
+                            .replace("String(\"", "")
+                            .replace("\")", "")
+                            .replace(')', "")
+                            .into_bytes(),
+                    }
+                } else {
+                    format!("{table_name}_pk_{pk_c_name}_{pk_val:?}")
+                        .replace("Integer(", "")
+                        .replace("String(\"", "")
+                        .replace("\")", "")
+                        .replace(')', "")
+                        .into_bytes()
+                }
+            } else {
+                format!("{}_{}", table_name, uuid::Uuid::new_v4()).into_bytes()
+            };
+
+            let snapshot_id =
+                self.transaction_manager.current_active_transaction_id().unwrap_or(TransactionId(0));
+            let committed_ids_set: HashSet<u64> = self
+                .transaction_manager
+                .get_committed_tx_ids_snapshot()
+                .into_iter()
+                .map(|id| id.0)
+                .collect();
+            let existing_row_map: Option<HashMap<Vec<u8>, DataType>> = self
+                .store
+                .read()
+                .map_err(|e| {
+                    OxidbError::LockTimeout(format!(
+                        "Failed to acquire read lock on store for PUT/ENSURE: {e}"
+                    ))
+                })?
+                .get(&kv_key, snapshot_id.0, &committed_ids_set)?
+                .map(|bytes| crate::core::common::serialization::deserialize_data_type(&bytes))
+                .transpose()?
+                .and_then(|dt| match dt {
+                    DataType::Map(map_data) => Some(map_data.0),
+                    _ => None,
+                });
+
+            if let Some(existing_map) = &existing_row_map {
+                if mode == UpsertConflictMode::EnsureMatch {
+                    if *existing_map == row_map_data {
+                        // An identical row is already present: ENSURE is satisfied, nothing to do.
+                        continue;
+                    }
+                    return Err(OxidbError::ConstraintViolation(format!(
+                        "ENSURE failed: a different row already exists for this primary key in table '{table_name}'."
+                    )));
+                }
+            }
+
+            // Constraint Checks (NOT NULL / UNIQUE), excluding this row's own key from the
+            // uniqueness check when it already exists (overwrite case).
+            let exclude_pk: Option<&[u8]> =
+                if existing_row_map.is_some() { Some(&kv_key) } else { None };
+            for col_def in &schema.columns {
+                let value_for_column =
+                    row_map_data.get(col_def.name.as_bytes()).cloned().unwrap_or(DataType::Null);
+
+                if !col_def.is_nullable && value_for_column == DataType::Null {
+                    return Err(OxidbError::ConstraintViolation(format!(
+                        "NOT NULL constraint failed for column '{}' in table '{}'",
+                        col_def.name, table_name
+                    )));
+                }
+
+                if col_def.is_unique
+                    && !(value_for_column == DataType::Null && !col_def.is_primary_key)
+                {
+                    self.check_uniqueness(&table_name, col_def, &value_for_column, exclude_pk)?;
+                }
+            }
+
+            // If overwriting, de-index the old row's unique-column values first.
+            if let Some(old_row_map) = &existing_row_map {
+                for col_def in &schema.columns {
+                    if col_def.is_primary_key || col_def.is_unique {
+                        let old_value = old_row_map
+                            .get(col_def.name.as_bytes())
+                            .cloned()
+                            .unwrap_or(DataType::Null);
+                        if old_value == DataType::Null && !col_def.is_primary_key {
+                            continue;
+                        }
+                        let index_name = format!("idx_{}_{}", table_name, col_def.name);
+                        let serialized_old_value =
+                            crate::core::common::serialization::serialize_data_type(&old_value)?;
+                        self.index_manager.write().map_err(|e| {
+                            OxidbError::LockTimeout(format!(
+                                "Failed to acquire write lock on index manager for PUT: {e}"
+                            ))
+                        })?.delete_from_index(&index_name, &serialized_old_value, Some(&kv_key))?;
+
+                        if current_op_tx_id.0 != 0 {
+                            if let Some(active_tx_mut) =
+                                self.transaction_manager.get_active_transaction_mut()
+                            {
+                                active_tx_mut.add_undo_operation(
+                                    crate::core::transaction::UndoOperation::IndexRevertInsert {
+                                        index_name,
+                                        key: kv_key.clone(),
+                                        value_for_index: serialized_old_value,
+                                    },
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+
+            let row_data_type = DataType::Map(crate::core::types::JsonSafeMap(row_map_data.clone()));
+
+            for col_def in &schema.columns {
+                if col_def.is_primary_key || col_def.is_unique {
+                    let value_for_column = row_map_data
+                        .get(col_def.name.as_bytes())
+                        .cloned()
+                        .unwrap_or(DataType::Null);
+                    if value_for_column == DataType::Null && !col_def.is_primary_key {
+                        continue;
+                    }
+
+                    let index_name = format!("idx_{}_{}", table_name, col_def.name);
+                    let serialized_column_value =
+                        crate::core::common::serialization::serialize_data_type(&value_for_column)?;
+
+                    self.index_manager.write().map_err(|e| {
+                        OxidbError::LockTimeout(format!(
+                            "Failed to acquire write lock on index manager for PUT/ENSURE: {e}"
+                        ))
+                    })?.insert_into_index(&index_name, &serialized_column_value, &kv_key)?;
+
+                    if current_op_tx_id.0 != 0 {
+                        if let Some(active_tx_mut) =
+                            self.transaction_manager.get_active_transaction_mut()
+                        {
+                            active_tx_mut.add_undo_operation(
+                                crate::core::transaction::UndoOperation::IndexRevertInsert {
+                                    index_name,
+                                    key: kv_key.clone(),
+                                    value_for_index: serialized_column_value,
+                                },
+                            );
+                        }
+                    }
+                }
+            }
+
+            self.store_row_data(kv_key.clone(), &row_data_type)?;
+            affected_count += 1;
+        }
+
+        Ok(ExecutionResult::Updated { count: affected_count })
+    }
+
     // handle_find_by_index, handle_vacuum - these are in ddl_handlers.rs and transaction_handlers.rs respectively.
     // handle_select, handle_update - these are in select_execution.rs and update_execution.rs respectively.
 
@@ -923,57 +1427,173 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S
         Ok(())
     }
 
-    /// Scans existing data to determine the current maximum auto-increment values
+    /// Scans existing data to determine the current maximum auto-increment values.
+    ///
+    /// The store has no dedicated catalog table listing tables, but every
+    /// table's schema is itself stored under a `_schema_{table_name}` key
+    /// (see `Self::schema_key`), so that key namespace doubles as the
+    /// system catalog: `store.scan()` finds every `_schema_`-prefixed key,
+    /// the table name is recovered from the key suffix, and the auto-increment
+    /// columns of every discovered table are seeded - not just a hardcoded
+    /// pair of tables.
     fn scan_and_update_auto_increment_state(&mut self) -> Result<(), OxidbError> {
-        // Get all table schemas to find auto-increment columns
         let store = self
             .store
             .read()
             .map_err(|_| OxidbError::LockTimeout("Failed to lock store".to_string()))?;
 
-        // Scan for schema keys
-        let _schema_prefix = "_schema_";
-        // This is a simplified scan - we'll iterate through known tables
-        // In a production system, we'd have a proper metadata table listing all tables
+        const SCHEMA_PREFIX: &str = "_schema_";
 
-        let committed_ids: HashSet<u64> = HashSet::new();
-        let snapshot_id = 0;
+        let mut table_names = Vec::new();
+        for (key, _) in store.scan()? {
+            if let Ok(key_str) = String::from_utf8(key) {
+                if let Some(table_name) = key_str.strip_prefix(SCHEMA_PREFIX) {
+                    table_names.push(table_name.to_string());
+                }
+            }
+        }
+        drop(store);
 
-        // For now, let's scan the users table specifically since that's what we're testing
-        if let Ok(schema_data) = store.get(&b"_schema_users".to_vec(), snapshot_id, &committed_ids)
-        {
-            if let Some(data) = schema_data {
-                if let Ok(schema) =
-                    serde_json::from_slice::<crate::core::types::schema::Schema>(&data)
-                {
-                    for column in &schema.columns {
-                        if column.is_auto_increment {
-                            let max_value =
-                                self.find_max_value_for_column("users", &column.name)?;
-                            let key = format!("users_{}", column.name);
-                            self.auto_increment_state.insert(key, max_value);
-                        }
-                    }
+        for table_name in table_names {
+            let Some(schema) = self.get_table_schema(&table_name)? else {
+                continue;
+            };
+            for column in &schema.columns {
+                if column.is_auto_increment {
+                    let max_value = self.find_max_value_for_column(&table_name, &column.name)?;
+                    let key = format!("{table_name}_{}", column.name);
+                    self.auto_increment_state.insert(key, max_value);
                 }
             }
         }
 
-        // Scan user_files table as well
-        if let Ok(schema_data) =
-            store.get(&b"_schema_user_files".to_vec(), snapshot_id, &committed_ids)
-        {
-            if let Some(data) = schema_data {
-                if let Ok(schema) =
-                    serde_json::from_slice::<crate::core::types::schema::Schema>(&data)
-                {
-                    for column in &schema.columns {
-                        if column.is_auto_increment {
-                            let max_value =
-                                self.find_max_value_for_column("user_files", &column.name)?;
-                            let key = format!("user_files_{}", column.name);
-                            self.auto_increment_state.insert(key, max_value);
-                        }
+        Ok(())
+    }
+
+    /// Whether `key` is a row key belonging to `table_name`, rather than merely sharing
+    /// its leading bytes (e.g. table `user_files`'s rows all start with `user_` too, so
+    /// a bare prefix check would wrongly pull them into table `user`'s reconciliation).
+    /// Row keys only ever take the two shapes `SqlInsert` produces: a primary-key row is
+    /// `{table_name}_pk_{pk_column}_{value}`, and a table with no declared primary key
+    /// uses `{table_name}_{uuid}`. So past the `{table_name}_` prefix, a genuine row key
+    /// must either continue with the literal `pk_` marker or be a valid UUID - a
+    /// same-prefixed key from a different, longer table name can't satisfy either, since
+    /// its own table-name suffix lands in that exact spot instead.
+    fn row_key_belongs_to_table(key: &[u8], table_name: &str) -> bool {
+        let prefix = format!("{table_name}_");
+        let Some(remainder) = key.strip_prefix(prefix.as_bytes()) else {
+            return false;
+        };
+
+        if remainder.starts_with(b"pk_") {
+            return true;
+        }
+
+        std::str::from_utf8(remainder)
+            .ok()
+            .is_some_and(|remainder_str| uuid::Uuid::parse_str(remainder_str).is_ok())
+    }
+
+    /// Reconciles every registered per-column index against the store's current
+    /// contents, the index-side counterpart to [`Self::scan_and_update_auto_increment_state`].
+    ///
+    /// `IndexManager::new` (in [`Self::new`]) loaded whatever index files were
+    /// last saved to disk. Those files can be stale relative to the rows the
+    /// ARIES Redo phase just replayed (or the Undo phase just rolled back),
+    /// since index writes and store writes aren't flushed in lockstep - a
+    /// crash between a row's `store.put` and its `insert_into_index` call
+    /// leaves the index missing an entry a query needs. For every table
+    /// discovered via the `_schema_`-prefixed catalog keys, this re-applies
+    /// `IndexManager::on_insert_data` for every row currently in the store to
+    /// every `idx_<table>_<col>` index registered for that table, exactly as
+    /// `SqlInsert` would. This is safe to run unconditionally: re-inserting a
+    /// primary key that's already present in an index is a no-op for the
+    /// index types here, so nothing is double-counted.
+    fn rebuild_indexes_from_store(&mut self) -> Result<(), OxidbError> {
+        const SCHEMA_PREFIX: &str = "_schema_";
+
+        let table_names: Vec<String> = {
+            let store = self
+                .store
+                .read()
+                .map_err(|_| OxidbError::LockTimeout("Failed to lock store".to_string()))?;
+            store
+                .scan()?
+                .into_iter()
+                .filter_map(|(key, _)| {
+                    String::from_utf8(key)
+                        .ok()
+                        .and_then(|key_str| key_str.strip_prefix(SCHEMA_PREFIX).map(str::to_string))
+                })
+                .collect()
+        };
+
+        for table_name in table_names {
+            let Some(schema) = self.get_table_schema(&table_name)? else {
+                continue;
+            };
+
+            let rows: Vec<(Vec<u8>, Vec<u8>)> = {
+                let store = self
+                    .store
+                    .read()
+                    .map_err(|_| OxidbError::LockTimeout("Failed to lock store".to_string()))?;
+                store
+                    .scan()?
+                    .into_iter()
+                    .filter(|(key, _)| Self::row_key_belongs_to_table(key, &table_name))
+                    .collect()
+            };
+
+            for (row_key, row_bytes) in rows {
+                let Ok(DataType::Map(row_map)) =
+                    crate::core::common::serialization::deserialize_data_type(&row_bytes)
+                else {
+                    continue;
+                };
+                let row_map_data = row_map.0;
+
+                let mut indexed_values = HashMap::new();
+                for col_def in &schema.columns {
+                    let index_name = format!("idx_{}_{}", table_name, col_def.name);
+                    let has_index = self
+                        .index_manager
+                        .read()
+                        .map_err(|e| {
+                            OxidbError::LockTimeout(format!(
+                                "Failed to acquire read lock on index manager during recovery: {e}"
+                            ))
+                        })?
+                        .get_index(&index_name)
+                        .is_some();
+                    if !has_index {
+                        continue;
+                    }
+
+                    let value_for_column = row_map_data
+                        .get(col_def.name.as_bytes())
+                        .cloned()
+                        .unwrap_or(DataType::Null);
+                    if value_for_column == DataType::Null && !col_def.is_primary_key {
+                        continue;
                     }
+
+                    let serialized_column_value =
+                        crate::core::common::serialization::serialize_data_type(
+                            &value_for_column,
+                        )?;
+                    indexed_values.insert(index_name, serialized_column_value);
+                }
+
+                if !indexed_values.is_empty() {
+                    self.index_manager
+                        .read()
+                        .map_err(|e| {
+                            OxidbError::LockTimeout(format!(
+                                "Failed to acquire read lock on index manager during recovery: {e}"
+                            ))
+                        })?
+                        .on_insert_data(&indexed_values, &row_key)?;
                 }
             }
         }
@@ -981,7 +1601,16 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S
         Ok(())
     }
 
-    /// Finds the maximum value for an auto-increment column by scanning existing data
+    /// Finds the maximum value for an auto-increment column by scanning existing data.
+    ///
+    /// Walks every key actually present in the store via `KeyValueStore::scan`
+    /// and inspects the ones whose key is prefixed `{table_name}_pk_{column_name}_`,
+    /// rather than probing a fixed range of guessed primary-key values. `scan`
+    /// is a simple latest-version scan, not a snapshot-isolated MVCC iterator
+    /// (the store doesn't expose one), so this still can't see a value a
+    /// concurrent uncommitted transaction is about to claim - but it no
+    /// longer silently stops at the first unassigned key or an arbitrary
+    /// record-count ceiling.
     fn find_max_value_for_column(
         &self,
         table_name: &str,
@@ -993,47 +1622,30 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S
             .map_err(|_| OxidbError::LockTimeout("Failed to lock store".to_string()))?;
         let mut max_value = 0i64;
 
-        // Scan all rows in the table to find the maximum value
-        // This is inefficient but works for our current implementation
         let table_prefix = format!("{table_name}_pk_{column_name}_");
 
-        let committed_ids: HashSet<u64> = HashSet::new();
-        let snapshot_id = 0;
-
-        // We need to iterate through all keys that start with the table prefix
-        // This is a simplified approach - in a production system, we'd have better indexing
-
-        // For now, we'll use a heuristic: scan through potential primary key values
-        // This assumes primary keys are sequential integers starting from 1
-        for i in 1..=10000 {
-            // Scan up to 10000 records
-            let pk_key = format!("{table_prefix}{i}");
-            if let Ok(Some(row_data)) =
-                store.get(&pk_key.as_bytes().to_vec(), snapshot_id, &committed_ids)
+        for (key, row_data) in store.scan()? {
+            if !key.starts_with(table_prefix.as_bytes()) {
+                continue;
+            }
+            // Parse the row data to extract the column value
+            // The data is stored as {"Map": {"base64_encoded_column_name": value, ...}}
+            if let Ok(data_type) = serde_json::from_slice::<crate::core::types::DataType>(&row_data)
             {
-                // Parse the row data to extract the column value
-                // The data is stored as {"Map": {"base64_encoded_column_name": value, ...}}
-                if let Ok(data_type) =
-                    serde_json::from_slice::<crate::core::types::DataType>(&row_data)
-                {
-                    if let crate::core::types::DataType::Map(map) = data_type {
-                        // Look for the column by matching the key directly
-                        for (key_bytes, value) in &map.0 {
-                            // Try to decode the key as UTF-8 string
-                            if let Ok(key_str) = String::from_utf8(key_bytes.clone()) {
-                                // Check if this is the column we're looking for
-                                if key_str == column_name {
-                                    if let crate::core::types::DataType::Integer(int_val) = value {
-                                        max_value = max_value.max(*int_val);
-                                    }
+                if let crate::core::types::DataType::Map(map) = data_type {
+                    // Look for the column by matching the key directly
+                    for (key_bytes, value) in &map.0 {
+                        // Try to decode the key as UTF-8 string
+                        if let Ok(key_str) = String::from_utf8(key_bytes.clone()) {
+                            // Check if this is the column we're looking for
+                            if key_str == column_name {
+                                if let crate::core::types::DataType::Integer(int_val) = value {
+                                    max_value = max_value.max(*int_val);
                                 }
                             }
                         }
                     }
                 }
-            } else {
-                // If we can't find this key, assume we've reached the end
-                break;
             }
         }
 
@@ -1047,20 +1659,55 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S
         statement: &crate::core::query::sql::ast::Statement,
         parameters: &[crate::core::common::types::Value],
     ) -> Result<ExecutionResult, OxidbError> {
-        // First, validate parameter count
-        let expected_param_count = self.count_parameters_in_statement(statement);
-        let actual_param_count = parameters.len();
+        let named_parameters = HashMap::new();
+        self.execute_parameterized_statement_with_named(statement, parameters, &named_parameters)
+    }
 
-        if actual_param_count != expected_param_count {
+    /// Like [`Self::execute_parameterized_statement`], but also binds named
+    /// (`:name`) placeholders via `named_parameters`.
+    pub fn execute_parameterized_statement_with_named(
+        &mut self,
+        statement: &crate::core::query::sql::ast::Statement,
+        parameters: &[crate::core::common::types::Value],
+        named_parameters: &HashMap<String, crate::core::common::types::Value>,
+    ) -> Result<ExecutionResult, OxidbError> {
+        // Validate that every placeholder referenced in the statement has a
+        // bound value: positional/numbered placeholders must fall within
+        // `parameters`, and every named placeholder must be in `named_parameters`.
+        let required = self.collect_parameter_keys_in_statement(statement);
+        let mut missing: Vec<String> = Vec::new();
+        for key in &required {
+            match key {
+                ParamKey::Positional(index) => {
+                    if *index as usize >= parameters.len() {
+                        missing.push(format!("positional parameter ?{index}"));
+                    }
+                }
+                ParamKey::Numbered(number) => {
+                    if number.saturating_sub(1) as usize >= parameters.len() {
+                        missing.push(format!("numbered parameter ?{number}/${number}"));
+                    }
+                }
+                ParamKey::Named(name) => {
+                    if !named_parameters.contains_key(name) {
+                        missing.push(format!("named parameter :{name}"));
+                    }
+                }
+            }
+        }
+        if !missing.is_empty() {
+            missing.sort();
             return Err(OxidbError::InvalidInput {
                 message: format!(
-                    "Parameter count mismatch: expected {expected_param_count} parameters, got {actual_param_count}"
-                )
+                    "Parameter binding mismatch: {} distinct parameter(s) required, missing a bound value for: {}",
+                    required.len(),
+                    missing.join(", ")
+                ),
             });
         }
 
         // Create a parameter context for resolving parameters during execution
-        let param_context = ParameterContext::new(parameters);
+        let param_context = ParameterContext::new_with_named(parameters, named_parameters);
 
         // Execute the statement with parameter resolution
         match statement {
@@ -1082,77 +1729,79 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S
         }
     }
 
-    /// Count the number of parameters (? placeholders) in a SQL statement
-    fn count_parameters_in_statement(
+    /// Collect the set of *distinct* parameter placeholders referenced by a
+    /// statement (a `:name` or `?N` used more than once counts once; each
+    /// bare `?` is distinct by construction, since the parser numbers them
+    /// by occurrence).
+    fn collect_parameter_keys_in_statement(
         &self,
         statement: &crate::core::query::sql::ast::Statement,
-    ) -> usize {
+    ) -> HashSet<ParamKey> {
         use crate::core::query::sql::ast::Statement;
+        let mut keys = HashSet::new();
         match statement {
             Statement::Select(select_stmt) => {
-                let mut count = 0;
                 if let Some(ref condition) = select_stmt.condition {
-                    count += self.count_parameters_in_condition_tree(condition);
+                    self.collect_parameter_keys_in_condition_tree(condition, &mut keys);
                 }
-                count
             }
             Statement::Insert(insert_stmt) => {
-                let mut count = 0;
                 for row in &insert_stmt.values {
                     for value in row {
-                        count += self.count_parameters_in_expression_value(value);
+                        self.collect_parameter_keys_in_expression_value(value, &mut keys);
                     }
                 }
-                count
             }
             Statement::Update(update_stmt) => {
-                let mut count = 0;
-                // Count parameters in assignments
                 for assignment in &update_stmt.assignments {
-                    count += self.count_parameters_in_expression_value(&assignment.value);
+                    self.collect_parameter_keys_in_expression_value(&assignment.value, &mut keys);
                 }
-                // Count parameters in WHERE condition
                 if let Some(ref condition) = update_stmt.condition {
-                    count += self.count_parameters_in_condition_tree(condition);
+                    self.collect_parameter_keys_in_condition_tree(condition, &mut keys);
                 }
-                count
             }
             Statement::Delete(delete_stmt) => {
-                let mut count = 0;
                 if let Some(ref condition) = delete_stmt.condition {
-                    count += self.count_parameters_in_condition_tree(condition);
+                    self.collect_parameter_keys_in_condition_tree(condition, &mut keys);
                 }
-                count
             }
-            _ => 0, // Other statement types don't support parameters yet
+            _ => {} // Other statement types don't support parameters yet
         }
+        keys
     }
 
-    fn count_parameters_in_condition_tree(
+    fn collect_parameter_keys_in_condition_tree(
         &self,
         condition_tree: &crate::core::query::sql::ast::ConditionTree,
-    ) -> usize {
+        keys: &mut HashSet<ParamKey>,
+    ) {
         use crate::core::query::sql::ast::ConditionTree;
         match condition_tree {
             ConditionTree::Comparison(condition) => {
-                self.count_parameters_in_expression_value(&condition.value)
+                self.collect_parameter_keys_in_expression_value(&condition.value, keys);
             }
             ConditionTree::And(left, right) | ConditionTree::Or(left, right) => {
-                self.count_parameters_in_condition_tree(left)
-                    + self.count_parameters_in_condition_tree(right)
+                self.collect_parameter_keys_in_condition_tree(left, keys);
+                self.collect_parameter_keys_in_condition_tree(right, keys);
+            }
+            ConditionTree::Not(inner) => {
+                self.collect_parameter_keys_in_condition_tree(inner, keys);
             }
-            ConditionTree::Not(inner) => self.count_parameters_in_condition_tree(inner),
         }
     }
 
-    const fn count_parameters_in_expression_value(
+    fn collect_parameter_keys_in_expression_value(
         &self,
         expr: &crate::core::query::sql::ast::AstExpressionValue,
-    ) -> usize {
-        use crate::core::query::sql::ast::AstExpressionValue;
-        match expr {
-            AstExpressionValue::Parameter(_) => 1,
-            AstExpressionValue::Literal(_) | AstExpressionValue::ColumnIdentifier(_) => 0,
+        keys: &mut HashSet<ParamKey>,
+    ) {
+        use crate::core::query::sql::ast::{AstExpressionValue, AstParameter};
+        if let AstExpressionValue::Parameter(param) = expr {
+            keys.insert(match param {
+                AstParameter::Positional(index) => ParamKey::Positional(*index),
+                AstParameter::Numbered(number) => ParamKey::Numbered(*number),
+                AstParameter::Named(name) => ParamKey::Named(name.clone()),
+            });
         }
     }
 
@@ -1163,109 +1812,52 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S
         select_stmt: &crate::core::query::sql::ast::SelectStatement,
         param_context: &ParameterContext,
     ) -> Result<ExecutionResult, OxidbError> {
-        // For now, implement a basic version that converts the parameterized SELECT
-        // to the existing SELECT execution path by resolving parameters first
-
-        // Create a modified select statement with parameters resolved
-        let mut resolved_select = select_stmt.clone();
-
-        // Resolve parameters in WHERE conditions
-        if let Some(ref condition_tree) = select_stmt.condition {
-            resolved_select.condition =
-                Some(self.resolve_condition_tree_parameters(condition_tree, param_context)?);
-        }
-
-        // For now, use the existing SELECT execution infrastructure
-        // This is a temporary implementation - ideally we'd modify the execution engine
-        // to handle parameters natively throughout the pipeline
-
-        // Convert the AST to the internal command format and execute
-        let sql_command = crate::core::query::sql::translator::translate_ast_to_command(
-            crate::core::query::sql::ast::Statement::Select(resolved_select),
-        )?;
-
-        // Execute using existing infrastructure
-        match sql_command {
-            crate::core::query::commands::Command::Select {
-                columns, source, condition, ..
-            } => self.handle_select(columns, source, condition),
-            _ => Err(OxidbError::Internal(
-                "Unexpected command type from SELECT translation".to_string(),
-            )),
-        }
+        let columns_spec = crate::core::query::sql::translator::translate_select_columns(
+            select_stmt.columns.clone(),
+        );
+        let condition = match &select_stmt.condition {
+            Some(condition_tree) => {
+                Some(self.resolve_condition_tree_to_sql(condition_tree, param_context)?)
+            }
+            None => None,
+        };
+        self.handle_select(columns_spec, select_stmt.from_clause.name.clone(), condition)
     }
 
-    /// Helper method to resolve parameters in condition trees
-    fn resolve_condition_tree_parameters(
+    /// Resolves `condition_tree`'s placeholders against `param_context` straight
+    /// into the `DataType`-valued `SqlConditionTree` the executor's handlers
+    /// operate on, rather than rewriting them into an `AstLiteralValue` and
+    /// re-translating - `AstLiteralValue` has no variant for a bound
+    /// `Value::Blob`/`Value::Vector`, which is what made those two types
+    /// impossible to bind before.
+    fn resolve_condition_tree_to_sql(
         &self,
         condition_tree: &crate::core::query::sql::ast::ConditionTree,
         param_context: &ParameterContext,
-    ) -> Result<crate::core::query::sql::ast::ConditionTree, OxidbError> {
-        use crate::core::query::sql::ast::{AstExpressionValue, Condition, ConditionTree};
+    ) -> Result<crate::core::query::commands::SqlConditionTree, OxidbError> {
+        use crate::core::query::commands::{SqlConditionTree, SqlSimpleCondition};
+        use crate::core::query::sql::ast::ConditionTree;
 
         match condition_tree {
             ConditionTree::Comparison(condition) => {
-                let resolved_value = match &condition.value {
-                    AstExpressionValue::Parameter(index) => {
-                        // Resolve parameter to literal value
-                        let param_value = param_context.resolve_parameter(*index)?;
-                        self.convert_param_value_to_ast_literal(param_value)?
-                    }
-                    AstExpressionValue::Literal(literal) => literal.clone(),
-                    AstExpressionValue::ColumnIdentifier(_) => {
-                        return Err(OxidbError::NotImplemented {
-                            feature: "Column-to-column comparisons in parameterized queries"
-                                .to_string(),
-                        });
-                    }
-                };
-
-                Ok(ConditionTree::Comparison(Condition {
+                let value = param_context.resolve_expression_value(&condition.value)?;
+                Ok(SqlConditionTree::Comparison(SqlSimpleCondition {
                     column: condition.column.clone(),
                     operator: condition.operator.clone(),
-                    value: AstExpressionValue::Literal(resolved_value),
+                    value,
                 }))
             }
-            ConditionTree::And(left, right) => {
-                let resolved_left = self.resolve_condition_tree_parameters(left, param_context)?;
-                let resolved_right =
-                    self.resolve_condition_tree_parameters(right, param_context)?;
-                Ok(ConditionTree::And(Box::new(resolved_left), Box::new(resolved_right)))
-            }
-            ConditionTree::Or(left, right) => {
-                let resolved_left = self.resolve_condition_tree_parameters(left, param_context)?;
-                let resolved_right =
-                    self.resolve_condition_tree_parameters(right, param_context)?;
-                Ok(ConditionTree::Or(Box::new(resolved_left), Box::new(resolved_right)))
-            }
-            ConditionTree::Not(inner) => {
-                let resolved_inner =
-                    self.resolve_condition_tree_parameters(inner, param_context)?;
-                Ok(ConditionTree::Not(Box::new(resolved_inner)))
-            }
-        }
-    }
-
-    /// Convert a parameter Value to an AST literal
-    fn convert_param_value_to_ast_literal(
-        &self,
-        value: &crate::core::common::types::Value,
-    ) -> Result<AstLiteralValue, OxidbError> {
-        use crate::core::common::types::Value;
-        use crate::core::query::sql::ast::AstLiteralValue;
-
-        match value {
-            Value::Integer(i) => Ok(AstLiteralValue::Number(i.to_string())),
-            Value::Float(f) => Ok(AstLiteralValue::Number(f.to_string())),
-            Value::Text(s) => Ok(AstLiteralValue::String(s.clone())),
-            Value::Boolean(b) => Ok(AstLiteralValue::Boolean(*b)),
-            Value::Null => Ok(AstLiteralValue::Null),
-            Value::Blob(_) => Err(OxidbError::NotImplemented {
-                feature: "Blob parameters in WHERE clauses".to_string(),
-            }),
-            Value::Vector(_) => Err(OxidbError::NotImplemented {
-                feature: "Vector parameters in WHERE clauses".to_string(),
-            }),
+            ConditionTree::And(left, right) => Ok(SqlConditionTree::And(
+                Box::new(self.resolve_condition_tree_to_sql(left, param_context)?),
+                Box::new(self.resolve_condition_tree_to_sql(right, param_context)?),
+            )),
+            ConditionTree::Or(left, right) => Ok(SqlConditionTree::Or(
+                Box::new(self.resolve_condition_tree_to_sql(left, param_context)?),
+                Box::new(self.resolve_condition_tree_to_sql(right, param_context)?),
+            )),
+            ConditionTree::Not(inner) => Ok(SqlConditionTree::Not(Box::new(
+                self.resolve_condition_tree_to_sql(inner, param_context)?,
+            ))),
         }
     }
 
@@ -1335,17 +1927,71 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S
 
     fn execute_parameterized_update(
         &mut self,
-        _update_stmt: &crate::core::query::sql::ast::UpdateStatement,
-        _param_context: &ParameterContext,
+        update_stmt: &crate::core::query::sql::ast::UpdateStatement,
+        param_context: &ParameterContext,
     ) -> Result<ExecutionResult, OxidbError> {
-        Err(OxidbError::NotImplemented { feature: "Parameterized UPDATE execution".to_string() })
+        let mut assignments = Vec::with_capacity(update_stmt.assignments.len());
+        for assignment in &update_stmt.assignments {
+            let value = param_context.resolve_expression_value(&assignment.value)?;
+            assignments.push(crate::core::query::commands::SqlAssignment {
+                column: assignment.column.clone(),
+                value,
+            });
+        }
+        let condition = match &update_stmt.condition {
+            Some(condition_tree) => {
+                Some(self.resolve_condition_tree_to_sql(condition_tree, param_context)?)
+            }
+            None => None,
+        };
+
+        let result =
+            self.handle_update(update_stmt.source.clone(), assignments, condition.clone())?;
+
+        // The assignments are already applied in place, so a RETURNING select
+        // under the same condition reads back the post-update values - same
+        // convention `Command::Update`'s `CommandProcessor` impl uses.
+        match &update_stmt.returning {
+            Some(returning_columns) => {
+                let returning_spec = crate::core::query::sql::translator::translate_select_columns(
+                    returning_columns.clone(),
+                );
+                self.handle_select(returning_spec, update_stmt.source.clone(), condition)
+            }
+            None => Ok(result),
+        }
     }
 
     fn execute_parameterized_delete(
         &mut self,
-        _delete_stmt: &crate::core::query::sql::ast::DeleteStatement,
-        _param_context: &ParameterContext,
+        delete_stmt: &crate::core::query::sql::ast::DeleteStatement,
+        param_context: &ParameterContext,
     ) -> Result<ExecutionResult, OxidbError> {
-        Err(OxidbError::NotImplemented { feature: "Parameterized DELETE execution".to_string() })
+        let condition = match &delete_stmt.condition {
+            Some(condition_tree) => {
+                Some(self.resolve_condition_tree_to_sql(condition_tree, param_context)?)
+            }
+            None => None,
+        };
+
+        // Capture the rows' requested columns before deleting them, since
+        // they won't be selectable afterward - same convention
+        // `Command::SqlDelete`'s `CommandProcessor` impl uses.
+        let returned = match &delete_stmt.returning {
+            Some(returning_columns) => {
+                let returning_spec = crate::core::query::sql::translator::translate_select_columns(
+                    returning_columns.clone(),
+                );
+                Some(self.handle_select(
+                    returning_spec,
+                    delete_stmt.table_name.clone(),
+                    condition.clone(),
+                )?)
+            }
+            None => None,
+        };
+
+        let result = self.handle_sql_delete(delete_stmt.table_name.clone(), condition)?;
+        Ok(returned.unwrap_or(result))
     }
 }
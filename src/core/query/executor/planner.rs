@@ -41,7 +41,11 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S
                 // Get the table schema
                 let schema = self.get_table_schema(&table_name)?
                     .ok_or_else(|| OxidbError::TableNotFound(table_name.clone()))?;
-                
+
+                self.record_profile_event(crate::core::performance::events::ProfileEvent::IndexCacheMiss {
+                    table_name: table_name.clone(),
+                });
+
                 let operator = TableScanOperator::new(
                     self.store.clone(),
                     table_name,
@@ -57,6 +61,11 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S
                 })?;
                 let scan_value_dt = simple_predicate.value;
                 let serialized_scan_value = serialize_data_type(&scan_value_dt)?;
+
+                self.record_profile_event(crate::core::performance::events::ProfileEvent::IndexCacheHit {
+                    index_name: index_name.clone(),
+                });
+
                 let operator = IndexScanOperator::new(
                     self.store.clone(),
                     self.index_manager.clone(),
@@ -18,22 +18,88 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> CommandProcesso
     fn process(&self, executor: &mut QueryExecutor<S>) -> Result<ExecutionResult, OxidbError> {
         match self {
             Self::BeginTransaction => executor.handle_begin_transaction(),
+            Self::BeginTransactionWithBehavior(behavior) => {
+                executor.handle_begin_transaction_with_behavior(*behavior)
+            }
             Self::CommitTransaction => executor.handle_commit_transaction(),
             Self::RollbackTransaction => executor.handle_rollback_transaction(),
+            Self::Savepoint(name) => executor.handle_savepoint(name.clone()),
+            Self::ReleaseSavepoint(name) => executor.handle_release_savepoint(name.clone()),
+            Self::RollbackToSavepoint(name) => executor.handle_rollback_to_savepoint(name.clone()),
             Self::Vacuum => executor.handle_vacuum(),
             Self::Select { columns, source, condition, order_by: _order_by, limit: _limit } => {
                 // Updated pattern
                 // TODO: Pass order_by and limit to handle_select
                 executor.handle_select(columns.clone(), source.clone(), condition.clone())
             }
-            Self::Update { source, assignments, condition } => {
-                executor.handle_update(source.clone(), assignments.clone(), condition.clone())
+            Self::SelectAggregate { source, group_by, aggregates, condition } => executor
+                .handle_select_aggregate(
+                    source.clone(),
+                    group_by.clone(),
+                    aggregates.clone(),
+                    condition.clone(),
+                ),
+            Self::Update { source, assignments, condition, returning } => {
+                let result =
+                    executor.handle_update(source.clone(), assignments.clone(), condition.clone())?;
+                match returning {
+                    // The assignments are already applied in place, so a RETURNING select
+                    // under the same condition reads back the post-update values.
+                    Some(returning_spec) => {
+                        executor.handle_select(returning_spec.clone(), source.clone(), condition.clone())
+                    }
+                    None => Ok(result),
+                }
             }
             Self::CreateTable { table_name, columns } => {
                 // Call the actual DDL handler in QueryExecutor
                 executor.handle_create_table(table_name.clone(), columns.clone())
             }
-            Self::SqlInsert { table_name, columns: insert_columns_opt, values } => {
+            Self::CreateEnumType { name, variants } => {
+                executor.handle_create_enum_type(name.clone(), variants.clone())
+            }
+            Self::AlterTable { table_name, operation } => {
+                executor.handle_alter_table(table_name.clone(), operation.clone())
+            }
+            Self::CreateIndex { index_name, table_name, column_name } => executor
+                .handle_create_index(index_name.clone(), table_name.clone(), column_name.clone()),
+            Self::DropIndex { index_name } => executor.handle_drop_index(index_name.clone()),
+            Self::CreateAggregateIndex { index_name, table_name, group_column, function, agg_column } => {
+                executor.handle_create_aggregate_index(
+                    index_name.clone(),
+                    table_name.clone(),
+                    group_column.clone(),
+                    *function,
+                    agg_column.clone(),
+                )
+            }
+            Self::DropAggregateIndex { index_name } => {
+                executor.handle_drop_aggregate_index(index_name.clone())
+            }
+            Self::CreateFunctionalIndex { index_name, table_name, expression } => executor
+                .handle_create_functional_index(
+                    index_name.clone(),
+                    table_name.clone(),
+                    expression.clone(),
+                ),
+            Self::AddValidationRule { table_name, name, when, then, severity } => executor
+                .handle_add_validation_rule(
+                    table_name.clone(),
+                    name.clone(),
+                    when.clone(),
+                    then.clone(),
+                    *severity,
+                ),
+            Self::CreateTrigger { table_name, name, timing, event, body } => executor
+                .handle_create_trigger(
+                    table_name.clone(),
+                    name.clone(),
+                    *timing,
+                    *event,
+                    body.clone(),
+                ),
+            Self::Batch { commands, atomic } => executor.handle_batch(commands.clone(), *atomic),
+            Self::SqlInsert { table_name, columns: insert_columns_opt, values, on_conflict, returning } => {
                 let schema_arc = executor.get_table_schema(table_name)?.ok_or_else(|| {
                     OxidbError::Execution(format!("Table '{table_name}' not found."))
                 })?;
@@ -44,6 +110,29 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> CommandProcesso
                     .current_active_transaction_id()
                     .unwrap_or(crate::core::common::types::TransactionId(0));
 
+                // Collects a row's RETURNING columns, in schema order for `*` or in the
+                // requested order for an explicit column list.
+                let collect_returning_values =
+                    |spec: &crate::core::query::commands::SelectColumnSpec,
+                     row_map_data: &std::collections::HashMap<Vec<u8>, DataType>| {
+                        let names: Vec<&str> = match spec {
+                            crate::core::query::commands::SelectColumnSpec::All => {
+                                schema.columns.iter().map(|c| c.name.as_str()).collect()
+                            }
+                            crate::core::query::commands::SelectColumnSpec::Specific(cols) => {
+                                cols.iter().map(String::as_str).collect()
+                            }
+                        };
+                        names
+                            .into_iter()
+                            .map(|name| {
+                                row_map_data.get(name.as_bytes()).cloned().unwrap_or(DataType::Null)
+                            })
+                            .collect::<Vec<_>>()
+                    };
+                let mut returned_values: Vec<DataType> = Vec::new();
+                let mut affected_rows = 0usize;
+
                 for row_values_to_insert in values {
                     let mut row_map_data = std::collections::HashMap::new();
                     let mut pk_value_opt: Option<DataType> = None;
@@ -76,6 +165,42 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> CommandProcesso
                         }
                     }
 
+                    // Apply column type affinity (SQLite-style dynamic typing): coerce each
+                    // provided value towards its column's declared type before constraints,
+                    // indexing, and storage see it. NUMERIC(p,s)/DECIMAL(p,s) columns get
+                    // exact fixed-point parsing and precision enforcement instead.
+                    for col_def in &schema.columns {
+                        if let Some(value) = row_map_data.get(col_def.name.as_bytes()).cloned() {
+                            if value != DataType::Null {
+                                let coerced = if let DataType::Decimal { precision, scale, .. } =
+                                    col_def.data_type
+                                {
+                                    crate::core::types::decimal::coerce_decimal(
+                                        &value, precision, scale,
+                                    )?
+                                } else if matches!(col_def.data_type, DataType::Enum { .. }) {
+                                    super::enum_execution::coerce_and_validate_enum_value(
+                                        executor, col_def, value,
+                                    )?
+                                } else {
+                                    col_def.affinity().coerce(value)
+                                };
+                                row_map_data.insert(col_def.name.as_bytes().to_vec(), coerced);
+                            }
+                        }
+                    }
+
+                    // Enforce VARCHAR(n)/CHAR(n) length constraints (truncate or reject
+                    // overflow, right-pad CHAR(n) to its fixed width).
+                    for col_def in &schema.columns {
+                        if let Some(value) = row_map_data.get(col_def.name.as_bytes()).cloned() {
+                            if value != DataType::Null {
+                                let enforced = col_def.enforce_length(value)?;
+                                row_map_data.insert(col_def.name.as_bytes().to_vec(), enforced);
+                            }
+                        }
+                    }
+
                     // Auto-increment processing: Generate values for auto-increment columns that are NULL or missing
                     for col_def in &schema.columns {
                         if col_def.is_auto_increment {
@@ -100,6 +225,84 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> CommandProcesso
                         }
                     }
 
+                    // BEFORE INSERT triggers, once the row is fully assembled
+                    // (including generated auto-increment values) but before
+                    // any constraint checks run.
+                    executor.fire_triggers(
+                        table_name,
+                        crate::core::query::commands::TriggerEvent::Insert,
+                        crate::core::query::commands::TriggerTiming::Before,
+                        None,
+                    )?;
+
+                    // ON CONFLICT handling: if this row's conflict-target columns already match
+                    // an existing row, resolve per the clause instead of attempting the insert
+                    // (which would otherwise fail the UNIQUE check below).
+                    if let Some(on_conflict) = on_conflict {
+                        let condition = on_conflict.target_columns.iter().fold(
+                            None,
+                            |acc: Option<crate::core::query::commands::SqlConditionTree>, col| {
+                                let comparison = crate::core::query::commands::SqlConditionTree::Comparison(
+                                    crate::core::query::commands::SqlSimpleCondition {
+                                        column: col.clone(),
+                                        operator: "=".to_string(),
+                                        value: row_map_data
+                                            .get(col.as_bytes())
+                                            .cloned()
+                                            .unwrap_or(DataType::Null),
+                                    },
+                                );
+                                Some(match acc {
+                                    Some(existing) => crate::core::query::commands::SqlConditionTree::And(
+                                        Box::new(existing),
+                                        Box::new(comparison),
+                                    ),
+                                    None => comparison,
+                                })
+                            },
+                        );
+
+                        let conflict_exists = matches!(
+                            executor.handle_select(
+                                crate::core::query::commands::SelectColumnSpec::All,
+                                table_name.clone(),
+                                condition.clone(),
+                            )?,
+                            ExecutionResult::Values(rows) if !rows.is_empty()
+                        );
+
+                        if conflict_exists {
+                            match &on_conflict.action {
+                                crate::core::query::commands::ConflictAction::DoNothing => {}
+                                crate::core::query::commands::ConflictAction::DoUpdate(
+                                    assignments,
+                                ) => {
+                                    if let ExecutionResult::Updated { count } = executor
+                                        .handle_update(
+                                            table_name.clone(),
+                                            assignments.clone(),
+                                            condition.clone(),
+                                        )?
+                                    {
+                                        affected_rows += count;
+                                    }
+                                    if let Some(returning_spec) = returning {
+                                        if let ExecutionResult::Values(row_values) = executor
+                                            .handle_select(
+                                                returning_spec.clone(),
+                                                table_name.clone(),
+                                                condition,
+                                            )?
+                                        {
+                                            returned_values.extend(row_values);
+                                        }
+                                    }
+                                }
+                            }
+                            continue;
+                        }
+                    }
+
                     // Constraint Checks
                     for col_def in &schema.columns {
                         let value_for_column = row_map_data
@@ -137,6 +340,9 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> CommandProcesso
                         }
                     }
 
+                    // Row-validation rules (`Command::AddValidationRule`)
+                    executor.check_validation_rules(table_name, &row_map_data)?;
+
                     // Determine KV store key
                     // TODO: Handle composite PKs. For now, assume single PK or use UUID.
                     let kv_key = if let (Some(DataType::String(pk_str_val)), Some(ref pk_c_name)) =
@@ -181,8 +387,19 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> CommandProcesso
                         DataType::Map(crate::core::types::JsonSafeMap(row_map_data.clone())); // Clone row_map_data for handle_insert
 
                     // --- Start: Per-column index updates ---
+                    // Every column with a registered `idx_<table>_<col>` index is
+                    // maintained here, not just primary-key/unique columns: a
+                    // `Command::CreateIndex` on any other column is picked up
+                    // automatically when it was registered under that name.
                     for col_def in &schema.columns {
-                        if col_def.is_primary_key || col_def.is_unique {
+                        let index_name = format!("idx_{}_{}", table_name, col_def.name);
+                        let index_exists = executor
+                            .index_manager
+                            .read()
+                            .map_err(|e| OxidbError::LockTimeout(format!("Failed to acquire read lock on index manager for insert: {e}")))?
+                            .get_index(&index_name)
+                            .is_some();
+                        if col_def.is_primary_key || col_def.is_unique || index_exists {
                             let value_for_column = row_map_data
                                 .get(col_def.name.as_bytes())
                                 .cloned()
@@ -193,7 +410,6 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> CommandProcesso
                                 continue;
                             }
 
-                            let index_name = format!("idx_{}_{}", table_name, col_def.name);
                             let serialized_column_value =
                                 crate::core::common::serialization::serialize_data_type(
                                     &value_for_column,
@@ -229,14 +445,64 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> CommandProcesso
                     }
                     // --- End: Per-column index updates ---
 
+                    // Maintain any `CREATE AGGREGATE INDEX` registered over this table.
+                    executor.maintain_aggregate_indexes_on_insert(table_name, &row_map_data)?;
+                    // Maintain any functional `CREATE INDEX` registered over this table.
+                    executor.maintain_functional_indexes_on_insert(
+                        table_name,
+                        &row_map_data,
+                        &kv_key,
+                    )?;
+
                     // Use helper method for storage operation (DRY principle)
                     executor.store_row_data(kv_key.clone(), &row_data_type)?;
+                    affected_rows += 1;
+
+                    // AFTER INSERT triggers, once the row is durably stored.
+                    executor.fire_triggers(
+                        table_name,
+                        crate::core::query::commands::TriggerEvent::Insert,
+                        crate::core::query::commands::TriggerTiming::After,
+                        None,
+                    )?;
+
+                    if let Some(returning_spec) = returning {
+                        returned_values
+                            .extend(collect_returning_values(returning_spec, &row_map_data));
+                    }
+                }
+                match returning {
+                    Some(_) => Ok(ExecutionResult::Values(returned_values)),
+                    None => Ok(ExecutionResult::Updated { count: affected_rows }), // Return rows affected
                 }
-                Ok(ExecutionResult::Updated { count: values.len() }) // Return rows affected
             }
-            Self::SqlDelete { table_name, condition } => {
+            Self::SqlDelete { table_name, condition, returning } => {
+                // Capture the rows' requested columns before deleting them, since they
+                // won't be selectable afterward.
+                let returned = match returning {
+                    Some(returning_spec) => Some(executor.handle_select(
+                        returning_spec.clone(),
+                        table_name.clone(),
+                        condition.clone(),
+                    )?),
+                    None => None,
+                };
+                let result = executor.handle_sql_delete(table_name.clone(), condition.clone())?;
+                Ok(returned.unwrap_or(result))
+            }
+            Self::Put { table_name, columns, values } => {
+                executor.handle_put(table_name.clone(), columns.clone(), values.clone())
+            }
+            // RM is an idempotent delete: matching zero rows is success, same as SqlDelete.
+            Self::Rm { table_name, condition } => {
                 executor.handle_sql_delete(table_name.clone(), condition.clone())
             }
+            Self::Ensure { table_name, columns, values } => {
+                executor.handle_ensure(table_name.clone(), columns.clone(), values.clone())
+            }
+            Self::EnsureNot { table_name, condition } => {
+                executor.handle_ensure_not(table_name.clone(), condition.clone())
+            }
             Self::SimilaritySearch {
                 table_name: _table_name,
                 vector_column_name: _vector_column_name,
@@ -261,6 +527,10 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> CommandProcesso
                 // Handle parameterized SQL execution
                 executor.execute_parameterized_statement(statement, parameters)
             }
+            Self::Describe { statement } => executor.handle_describe(statement.clone()),
+            Self::Explain { statement, analyze } => {
+                executor.handle_explain(statement.clone(), *analyze)
+            }
             // Added legacy KV and index variants delegating to core handlers
             Self::Insert { key, value } => executor.handle_insert(key.clone(), value.clone()),
             Self::Get { key } => executor.handle_get(key.clone()),
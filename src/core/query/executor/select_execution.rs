@@ -24,6 +24,49 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S
         source_table_name: String,
         condition_opt: Option<crate::core::query::commands::SqlConditionTree>, // Changed
     ) -> Result<ExecutionResult, OxidbError> {
+        let (optimized_plan, snapshot_id, committed_ids_u64_set) = self.build_select_query_plan(
+            select_columns_spec,
+            source_table_name,
+            condition_opt,
+        )?;
+
+        let mut execution_tree_root = self.build_execution_tree(
+            optimized_plan,
+            snapshot_id.0,
+            committed_ids_u64_set,
+        )?; // Pass snapshot_id.0 (u64)
+
+        let results_iter = execution_tree_root.execute()?;
+        let mut all_datatypes_from_tuples: Vec<DataType> = Vec::new();
+
+        for tuple_result in results_iter {
+            let tuple = tuple_result?;
+            for data_type in tuple {
+                all_datatypes_from_tuples.push(data_type);
+            }
+        }
+
+        Ok(ExecutionResult::Values(all_datatypes_from_tuples))
+    }
+
+    /// Builds the optimized `QueryPlanNode` a `SELECT` would run, along with
+    /// the transaction snapshot it was planned against - the part of
+    /// [`Self::handle_select`] that `handle_explain` also needs, since
+    /// `EXPLAIN` plans (and, with `ANALYZE`, executes) the same tree without
+    /// going through `ExecutionResult::Values`.
+    pub(crate) fn build_select_query_plan(
+        &mut self,
+        select_columns_spec: SelectColumnSpec,
+        source_table_name: String,
+        condition_opt: Option<crate::core::query::commands::SqlConditionTree>,
+    ) -> Result<
+        (
+            crate::core::optimizer::QueryPlanNode,
+            crate::core::common::types::TransactionId,
+            Arc<HashSet<u64>>,
+        ),
+        OxidbError,
+    > {
         let snapshot_id: crate::core::common::types::TransactionId; // Explicitly TransactionId
         let committed_ids_vec: Vec<crate::core::common::types::TransactionId>;
 
@@ -79,23 +122,7 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S
         let initial_plan = self.optimizer.build_initial_plan(&ast_statement)?;
         let optimized_plan = self.optimizer.optimize(initial_plan)?;
 
-        let mut execution_tree_root = self.build_execution_tree(
-            optimized_plan,
-            snapshot_id.0,
-            committed_ids_u64_set.clone(),
-        )?; // Pass snapshot_id.0 (u64)
-
-        let results_iter = execution_tree_root.execute()?;
-        let mut all_datatypes_from_tuples: Vec<DataType> = Vec::new();
-
-        for tuple_result in results_iter {
-            let tuple = tuple_result?;
-            for data_type in tuple {
-                all_datatypes_from_tuples.push(data_type);
-            }
-        }
-
-        Ok(ExecutionResult::Values(all_datatypes_from_tuples))
+        Ok((optimized_plan, snapshot_id, committed_ids_u64_set))
     }
 }
 
@@ -165,5 +192,16 @@ pub(super) fn command_condition_tree_to_ast_condition_tree(
             let ast_cond = command_condition_tree_to_ast_condition_tree(sql_cond)?;
             Ok(crate::core::query::sql::ast::ConditionTree::Not(Box::new(ast_cond)))
         }
+        crate::core::query::commands::SqlConditionTree::InSubquery { .. }
+        | crate::core::query::commands::SqlConditionTree::Exists { .. } => {
+            // Subquery predicates have no `ast::ConditionTree` equivalent. Only
+            // `QueryExecutor::evaluate_condition_tree` evaluates them (the GROUP BY
+            // fallback and validation rules call it); a SELECT, UPDATE or DELETE
+            // whose condition holds one gets this error.
+            Err(OxidbError::NotImplemented {
+                feature: "IN (subquery) / EXISTS inside the AST-based query planner"
+                    .to_string(),
+            })
+        }
     }
 }
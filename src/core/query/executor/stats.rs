@@ -0,0 +1,34 @@
+use super::QueryExecutor;
+use crate::core::storage::engine::traits::KeyValueStore;
+
+/// Operational snapshot of a database connection, for monitoring and capacity
+/// planning. Unlike [`crate::core::recovery::RecoveryStats`], which describes one
+/// past recovery run, this reports the executor's current state.
+#[derive(Debug, Clone)]
+pub struct DatabaseStats {
+    /// Transactions currently begun but not yet committed or aborted.
+    pub active_transaction_count: usize,
+    /// Size in bytes of the WAL file on disk, or `0` if it doesn't exist yet.
+    pub wal_file_size_bytes: u64,
+    /// Stats from the ARIES recovery run performed when this connection opened, if
+    /// its WAL file already had history to replay. `None` for a brand-new database.
+    pub last_recovery: Option<crate::core::recovery::RecoveryStats>,
+}
+
+impl<S: KeyValueStore<Vec<u8>, Vec<u8>>> QueryExecutor<S> {
+    /// Reports current operational statistics: active-transaction count, WAL file
+    /// size, and (if this connection's WAL had history to replay on open) the stats
+    /// from that recovery run.
+    #[must_use]
+    pub fn stats(&self) -> DatabaseStats {
+        let wal_file_path = self.transaction_manager.wal_file_path();
+        let wal_file_size_bytes =
+            std::fs::metadata(wal_file_path).map(|metadata| metadata.len()).unwrap_or(0);
+
+        DatabaseStats {
+            active_transaction_count: self.transaction_manager.active_transaction_count(),
+            wal_file_size_bytes,
+            last_recovery: self.last_recovery_stats.clone(),
+        }
+    }
+}
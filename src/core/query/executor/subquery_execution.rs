@@ -0,0 +1,108 @@
+use super::{ExecutionResult, QueryExecutor};
+use crate::core::common::OxidbError;
+use crate::core::query::commands::{Command, SqlConditionTree, SqlSimpleCondition};
+use crate::core::storage::engine::traits::KeyValueStore;
+use crate::core::types::DataType;
+use std::collections::HashMap;
+
+impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S> {
+    /// Evaluates `tree` against a single row, re-running any `InSubquery`/`Exists`
+    /// subquery it contains for that row (correlated subqueries aren't materialized
+    /// once up front, since they may reference the outer row's values).
+    ///
+    /// `row` maps each column name's bytes to that row's `DataType` value, the same
+    /// convention `SqlInsert`'s `RETURNING` handling uses.
+    pub(crate) fn evaluate_condition_tree(
+        &mut self,
+        tree: &SqlConditionTree,
+        row: &HashMap<Vec<u8>, DataType>,
+    ) -> Result<bool, OxidbError> {
+        match tree {
+            SqlConditionTree::Comparison(condition) => Self::evaluate_simple_condition(condition, row),
+            SqlConditionTree::And(left, right) => {
+                Ok(self.evaluate_condition_tree(left, row)? && self.evaluate_condition_tree(right, row)?)
+            }
+            SqlConditionTree::Or(left, right) => {
+                Ok(self.evaluate_condition_tree(left, row)? || self.evaluate_condition_tree(right, row)?)
+            }
+            SqlConditionTree::Not(inner) => Ok(!self.evaluate_condition_tree(inner, row)?),
+            SqlConditionTree::InSubquery { column, negated, subquery } => {
+                let results = self.materialize_subquery(subquery)?;
+                let row_value = row.get(column.as_bytes()).cloned().unwrap_or(DataType::Null);
+                Ok(results.contains(&row_value) != *negated)
+            }
+            SqlConditionTree::Exists { negated, subquery } => {
+                let results = self.materialize_subquery(subquery)?;
+                Ok(!results.is_empty() != *negated)
+            }
+        }
+    }
+
+    /// Runs `subquery` and flattens its result rows into a single `Vec<DataType>`,
+    /// the way a plain `SELECT`'s `ExecutionResult::Values` already does.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::InvalidInput` if `subquery` isn't a `Command::Select`.
+    fn materialize_subquery(&mut self, subquery: &Command) -> Result<Vec<DataType>, OxidbError> {
+        if !matches!(subquery, Command::Select { .. }) {
+            return Err(OxidbError::InvalidInput {
+                message: "A subquery predicate's inner command must be a Select.".to_string(),
+            });
+        }
+        match self.execute_command(subquery.clone())? {
+            ExecutionResult::Values(values) => Ok(values),
+            other => Err(OxidbError::Execution(format!(
+                "Subquery did not produce row values, got {other:?}."
+            ))),
+        }
+    }
+
+    fn evaluate_simple_condition(
+        condition: &SqlSimpleCondition,
+        row: &HashMap<Vec<u8>, DataType>,
+    ) -> Result<bool, OxidbError> {
+        let row_value = row.get(condition.column.as_bytes()).unwrap_or(&DataType::Null);
+
+        // Type-check enum comparisons: a column's enum value may only be compared
+        // against a literal of the *same* registered enum type, never another
+        // enum type (a bare string literal, without its own type, is fine —
+        // it's matched against the column's enum type by value).
+        if let (
+            DataType::Enum { type_name: row_type, .. },
+            DataType::Enum { type_name: condition_type, .. },
+        ) = (row_value, &condition.value)
+        {
+            if row_type != condition_type {
+                return Err(OxidbError::Type(format!(
+                    "Cannot compare column '{}' of enum type '{row_type}' against a value of enum type '{condition_type}'.",
+                    condition.column
+                )));
+            }
+        }
+
+        // A bare string literal compared against an enum column is matched by its
+        // inner value, not rejected as a type mismatch with `DataType::String`.
+        let condition_value = match (row_value, &condition.value) {
+            (DataType::Enum { type_name, .. }, DataType::String(s)) => {
+                DataType::Enum { type_name: type_name.clone(), value: s.clone() }
+            }
+            _ => condition.value.clone(),
+        };
+
+        Ok(match condition.operator.as_str() {
+            "=" => *row_value == condition_value,
+            "!=" | "<>" => *row_value != condition_value,
+            "<" => *row_value < condition_value,
+            "<=" => *row_value <= condition_value,
+            ">" => *row_value > condition_value,
+            ">=" => *row_value >= condition_value,
+            "IS NULL" => matches!(row_value, DataType::Null),
+            "IS NOT NULL" => !matches!(row_value, DataType::Null),
+            other => {
+                return Err(OxidbError::SqlParsing(format!(
+                    "Unsupported operator '{other}' in condition evaluation."
+                )))
+            }
+        })
+    }
+}
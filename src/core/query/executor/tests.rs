@@ -415,6 +415,30 @@ fn test_update_on_non_map_type_no_assignments_no_condition() {
     assert_eq!(updated_data_opt.unwrap(), initial_data);
 }
 
+#[test]
+fn test_row_key_belongs_to_table_rejects_prefix_collision_with_longer_table_name() {
+    // "user_files" rows share the "user_" prefix with table "user", but are not its rows.
+    let other_table_pk_row = b"user_files_pk_id_1";
+    let other_table_uuid_row = format!("user_files_{}", uuid::Uuid::new_v4()).into_bytes();
+    assert!(!QueryExecutor::<InMemoryKvStore>::row_key_belongs_to_table(
+        other_table_pk_row,
+        "user"
+    ));
+    assert!(!QueryExecutor::<InMemoryKvStore>::row_key_belongs_to_table(
+        &other_table_uuid_row,
+        "user"
+    ));
+}
+
+#[test]
+fn test_row_key_belongs_to_table_accepts_both_row_key_shapes() {
+    let pk_row = b"user_pk_id_1";
+    let uuid_row = format!("user_{}", uuid::Uuid::new_v4()).into_bytes();
+    assert!(QueryExecutor::<InMemoryKvStore>::row_key_belongs_to_table(pk_row, "user"));
+    assert!(QueryExecutor::<InMemoryKvStore>::row_key_belongs_to_table(&uuid_row, "user"));
+    assert!(!QueryExecutor::<InMemoryKvStore>::row_key_belongs_to_table(pk_row, "other"));
+}
+
 #[test]
 fn test_executor_update_empty_keys_to_update() {
     let mut executor = create_test_executor();
@@ -422,6 +446,7 @@ fn test_executor_update_empty_keys_to_update() {
         source: "any_table".to_string(),
         assignments: vec![SqlAssignment { column: "foo".to_string(), value: DataType::String("bar".to_string())}],
         condition: None,
+        returning: None,
     };
     let result = executor.execute_command(command).unwrap();
     assert_eq!(result, ExecutionResult::Success);
@@ -454,3 +479,1507 @@ fn test_executor_update_empty_keys_to_update() {
 //     //     panic!("Expected RevertUpdate in undo log");
 //     // }
 // }
+
+
+#[test]
+fn test_insert_on_conflict_do_update_and_do_nothing() {
+    use crate::core::query::executor::{ExecutionResult, QueryExecutor};
+    use crate::core::query::parser::parse_query;
+    use crate::core::storage::engine::InMemoryKvStore;
+    use crate::core::wal::log_manager::LogManager;
+    use crate::core::wal::writer::{WalWriter, WalWriterConfig};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let wal_writer = WalWriter::new(temp_path.join("test.wal"), WalWriterConfig::default());
+    let log_manager = Arc::new(LogManager::new());
+    let store = InMemoryKvStore::new();
+    let mut executor =
+        QueryExecutor::new(store, temp_path.clone(), wal_writer, log_manager).unwrap();
+
+    executor
+        .execute_command(
+            parse_query("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT);").unwrap(),
+        )
+        .unwrap();
+    executor
+        .execute_command(parse_query("INSERT INTO users (id, name) VALUES (1, 'Alice');").unwrap())
+        .unwrap();
+
+    // DO UPDATE: conflicting id should update the existing row's name instead of erroring.
+    executor
+        .execute_command(
+            parse_query(
+                "INSERT INTO users (id, name) VALUES (1, 'Bob') ON CONFLICT (id) DO UPDATE SET name = 'Bob';",
+            )
+            .unwrap(),
+        )
+        .unwrap();
+    let result = executor
+        .execute_command(parse_query("SELECT name FROM users WHERE id = 1;").unwrap())
+        .unwrap();
+    match result {
+        ExecutionResult::Values(values) => {
+            assert_eq!(values, vec![DataType::String("Bob".to_string())]);
+        }
+        other => panic!("Expected Values, got {:?}", other),
+    }
+
+    // DO NOTHING: conflicting id should leave the existing row untouched.
+    executor
+        .execute_command(
+            parse_query(
+                "INSERT INTO users (id, name) VALUES (1, 'Carol') ON CONFLICT (id) DO NOTHING;",
+            )
+            .unwrap(),
+        )
+        .unwrap();
+    let result = executor
+        .execute_command(parse_query("SELECT name FROM users WHERE id = 1;").unwrap())
+        .unwrap();
+    match result {
+        ExecutionResult::Values(values) => {
+            assert_eq!(values, vec![DataType::String("Bob".to_string())]);
+        }
+        other => panic!("Expected Values, got {:?}", other),
+    }
+}
+
+
+#[test]
+fn test_returning_clause_for_insert_update_delete() {
+    use crate::core::query::executor::{ExecutionResult, QueryExecutor};
+    use crate::core::query::parser::parse_query;
+    use crate::core::storage::engine::InMemoryKvStore;
+    use crate::core::wal::log_manager::LogManager;
+    use crate::core::wal::writer::{WalWriter, WalWriterConfig};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let wal_writer = WalWriter::new(temp_path.join("test.wal"), WalWriterConfig::default());
+    let log_manager = Arc::new(LogManager::new());
+    let store = InMemoryKvStore::new();
+    let mut executor =
+        QueryExecutor::new(store, temp_path.clone(), wal_writer, log_manager).unwrap();
+
+    executor
+        .execute_command(
+            parse_query("CREATE TABLE items (id INTEGER PRIMARY KEY, name TEXT);").unwrap(),
+        )
+        .unwrap();
+
+    // INSERT ... RETURNING yields the inserted row's requested columns.
+    let result = executor
+        .execute_command(
+            parse_query("INSERT INTO items (id, name) VALUES (1, 'Widget') RETURNING id, name;")
+                .unwrap(),
+        )
+        .unwrap();
+    match result {
+        ExecutionResult::Values(values) => {
+            assert_eq!(values, vec![DataType::Integer(1), DataType::String("Widget".to_string())]);
+        }
+        other => panic!("Expected Values, got {:?}", other),
+    }
+
+    // UPDATE ... RETURNING yields the post-update value.
+    let result = executor
+        .execute_command(
+            parse_query("UPDATE items SET name = 'Gadget' WHERE id = 1 RETURNING name;").unwrap(),
+        )
+        .unwrap();
+    match result {
+        ExecutionResult::Values(values) => {
+            assert_eq!(values, vec![DataType::String("Gadget".to_string())]);
+        }
+        other => panic!("Expected Values, got {:?}", other),
+    }
+
+    // DELETE ... RETURNING yields the row's values as they were immediately before deletion.
+    let result = executor
+        .execute_command(
+            parse_query("DELETE FROM items WHERE id = 1 RETURNING id, name;").unwrap(),
+        )
+        .unwrap();
+    match result {
+        ExecutionResult::Values(values) => {
+            assert_eq!(values, vec![DataType::Integer(1), DataType::String("Gadget".to_string())]);
+        }
+        other => panic!("Expected Values, got {:?}", other),
+    }
+
+    // The row is actually gone.
+    let result = executor
+        .execute_command(parse_query("SELECT id FROM items WHERE id = 1;").unwrap())
+        .unwrap();
+    assert_eq!(result, ExecutionResult::Values(vec![]));
+}
+
+
+#[test]
+fn test_describe_select_and_insert_without_executing() {
+    use crate::core::query::commands::DescribeResult;
+    use crate::core::query::executor::{ExecutionResult, QueryExecutor};
+    use crate::core::query::parser::parse_query;
+    use crate::core::query::sql::ast::Statement;
+    use crate::core::query::sql::parser::SqlParser;
+    use crate::core::query::sql::tokenizer::Tokenizer;
+    use crate::core::storage::engine::InMemoryKvStore;
+    use crate::core::wal::log_manager::LogManager;
+    use crate::core::wal::writer::{WalWriter, WalWriterConfig};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    fn tokenize_str(input: &str) -> Vec<crate::core::query::sql::tokenizer::Token> {
+        let mut tokenizer = Tokenizer::new(input);
+        tokenizer.tokenize().unwrap_or_else(|e| panic!("Test tokenizer error: {}", e))
+    }
+
+    // Parses `sql` into a raw `ast::Statement`, the way `Command::Describe` expects
+    // (unlike `parse_query`, which also translates to an executable `Command`).
+    fn parse_statement(sql: &str) -> Statement {
+        let mut parser = SqlParser::new(tokenize_str(sql));
+        parser.parse().unwrap()
+    }
+
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let wal_writer = WalWriter::new(temp_path.join("test.wal"), WalWriterConfig::default());
+    let log_manager = Arc::new(LogManager::new());
+    let store = InMemoryKvStore::new();
+    let mut executor =
+        QueryExecutor::new(store, temp_path.clone(), wal_writer, log_manager).unwrap();
+
+    executor
+        .execute_command(
+            parse_query(
+                "CREATE TABLE items (id INTEGER PRIMARY KEY, name TEXT, price INTEGER);",
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+    // DESCRIBE of a parameterized SELECT resolves both the `?` placeholder's type
+    // (from the `price` column it's compared against) and the projected columns'
+    // types/nullability, without touching any rows.
+    let statement = parse_statement("SELECT id, name FROM items WHERE price = ?;");
+    let result = executor.execute_command(Command::Describe { statement }).unwrap();
+    match result {
+        ExecutionResult::Describe(DescribeResult { parameters, columns }) => {
+            assert_eq!(parameters, vec![DataType::Integer(0)]);
+            assert_eq!(
+                columns,
+                vec![
+                    ("id".to_string(), DataType::Integer(0), false),
+                    ("name".to_string(), DataType::String(String::new()), true),
+                ]
+            );
+        }
+        other => panic!("Expected Describe, got {:?}", other),
+    }
+
+    // DESCRIBE of a parameterized INSERT infers each placeholder's type from the
+    // column it targets.
+    let statement = parse_statement("INSERT INTO items (id, name, price) VALUES (?, ?, ?);");
+    let result = executor.execute_command(Command::Describe { statement }).unwrap();
+    match result {
+        ExecutionResult::Describe(DescribeResult { parameters, columns }) => {
+            assert_eq!(
+                parameters,
+                vec![DataType::Integer(0), DataType::String(String::new()), DataType::Integer(0)]
+            );
+            assert!(columns.is_empty());
+        }
+        other => panic!("Expected Describe, got {:?}", other),
+    }
+
+    // Still no rows were ever inserted - DESCRIBE never executes the statement.
+    let result = executor
+        .execute_command(parse_query("SELECT id FROM items;").unwrap())
+        .unwrap();
+    assert_eq!(result, ExecutionResult::Values(vec![]));
+}
+
+
+#[test]
+fn test_in_subquery_and_exists_condition_evaluation() {
+    use crate::core::query::commands::{SelectColumnSpec, SqlConditionTree, SqlSimpleCondition};
+    use crate::core::query::executor::QueryExecutor;
+    use crate::core::storage::engine::InMemoryKvStore;
+    use crate::core::wal::log_manager::LogManager;
+    use crate::core::wal::writer::{WalWriter, WalWriterConfig};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let wal_writer = WalWriter::new(temp_path.join("test.wal"), WalWriterConfig::default());
+    let log_manager = Arc::new(LogManager::new());
+    let store = InMemoryKvStore::new();
+    let mut executor =
+        QueryExecutor::new(store, temp_path.clone(), wal_writer, log_manager).unwrap();
+
+    executor
+        .execute_command(
+            parse_query("CREATE TABLE vip_customers (id INTEGER PRIMARY KEY);").unwrap(),
+        )
+        .unwrap();
+    executor
+        .execute_command(parse_query("INSERT INTO vip_customers (id) VALUES (1);").unwrap())
+        .unwrap();
+
+    let membership_subquery = Box::new(Command::Select {
+        columns: SelectColumnSpec::Specific(vec!["id".to_string()]),
+        source: "vip_customers".to_string(),
+        condition: None,
+        order_by: None,
+        limit: None,
+    });
+
+    let mut vip_row = HashMap::new();
+    vip_row.insert(b"customer_id".to_vec(), DataType::Integer(1));
+    let in_subquery = SqlConditionTree::InSubquery {
+        column: "customer_id".to_string(),
+        negated: false,
+        subquery: membership_subquery.clone(),
+    };
+    assert!(executor.evaluate_condition_tree(&in_subquery, &vip_row).unwrap());
+
+    let mut non_vip_row = HashMap::new();
+    non_vip_row.insert(b"customer_id".to_vec(), DataType::Integer(2));
+    assert!(!executor.evaluate_condition_tree(&in_subquery, &non_vip_row).unwrap());
+
+    // `NOT IN (subquery)` inverts membership.
+    let not_in_subquery = SqlConditionTree::InSubquery {
+        column: "customer_id".to_string(),
+        negated: true,
+        subquery: membership_subquery,
+    };
+    assert!(!executor.evaluate_condition_tree(&not_in_subquery, &vip_row).unwrap());
+    assert!(executor.evaluate_condition_tree(&not_in_subquery, &non_vip_row).unwrap());
+
+    // EXISTS holds whenever the inner query returns at least one row, regardless of
+    // the outer row's own values.
+    let exists_any_vip = SqlConditionTree::Exists {
+        negated: false,
+        subquery: Box::new(Command::Select {
+            columns: SelectColumnSpec::Specific(vec!["id".to_string()]),
+            source: "vip_customers".to_string(),
+            condition: None,
+            order_by: None,
+            limit: None,
+        }),
+    };
+    assert!(executor.evaluate_condition_tree(&exists_any_vip, &non_vip_row).unwrap());
+
+    let exists_matching_id_99 = SqlConditionTree::Exists {
+        negated: false,
+        subquery: Box::new(Command::Select {
+            columns: SelectColumnSpec::Specific(vec!["id".to_string()]),
+            source: "vip_customers".to_string(),
+            condition: Some(SqlConditionTree::Comparison(SqlSimpleCondition {
+                column: "id".to_string(),
+                operator: "=".to_string(),
+                value: DataType::Integer(99),
+            })),
+            order_by: None,
+            limit: None,
+        }),
+    };
+    assert!(!executor.evaluate_condition_tree(&exists_matching_id_99, &non_vip_row).unwrap());
+}
+
+
+#[test]
+fn test_create_enum_type_validates_insert_and_update_values() {
+    use crate::core::common::OxidbError;
+    use crate::core::query::commands::{Command, SqlAssignment, SqlConditionTree, SqlSimpleCondition};
+    use crate::core::query::executor::QueryExecutor;
+    use crate::core::storage::engine::InMemoryKvStore;
+    use crate::core::wal::log_manager::LogManager;
+    use crate::core::wal::writer::{WalWriter, WalWriterConfig};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let wal_writer = WalWriter::new(temp_path.join("test.wal"), WalWriterConfig::default());
+    let log_manager = Arc::new(LogManager::new());
+    let store = InMemoryKvStore::new();
+    let mut executor =
+        QueryExecutor::new(store, temp_path.clone(), wal_writer, log_manager).unwrap();
+
+    executor
+        .execute_command(Command::CreateEnumType {
+            name: "mood".to_string(),
+            variants: vec!["happy".to_string(), "sad".to_string(), "neutral".to_string()],
+        })
+        .unwrap();
+
+    // Registering the same enum type name twice is rejected.
+    assert!(matches!(
+        executor.execute_command(Command::CreateEnumType {
+            name: "mood".to_string(),
+            variants: vec!["ok".to_string()],
+        }),
+        Err(OxidbError::AlreadyExists { .. })
+    ));
+
+    executor
+        .execute_command(
+            parse_query("CREATE TABLE people (id INTEGER PRIMARY KEY, current_mood mood);")
+                .unwrap(),
+        )
+        .unwrap();
+
+    // A valid variant inserts successfully.
+    executor
+        .execute_command(
+            parse_query("INSERT INTO people (id, current_mood) VALUES (1, 'happy');").unwrap(),
+        )
+        .unwrap();
+
+    // A value outside the registered variant set is rejected.
+    let invalid_insert = executor.execute_command(
+        parse_query("INSERT INTO people (id, current_mood) VALUES (2, 'furious');").unwrap(),
+    );
+    assert!(matches!(invalid_insert, Err(OxidbError::ConstraintViolation(_))));
+
+    // Updating to a valid variant succeeds.
+    executor
+        .execute_command(Command::Update {
+            source: "people".to_string(),
+            assignments: vec![SqlAssignment {
+                column: "current_mood".to_string(),
+                value: DataType::String("sad".to_string()),
+            }],
+            condition: Some(SqlConditionTree::Comparison(SqlSimpleCondition {
+                column: "id".to_string(),
+                operator: "=".to_string(),
+                value: DataType::Integer(1),
+            })),
+            returning: None,
+        })
+        .unwrap();
+
+    // Updating to an unregistered variant is rejected.
+    let invalid_update = executor.execute_command(Command::Update {
+        source: "people".to_string(),
+        assignments: vec![SqlAssignment {
+            column: "current_mood".to_string(),
+            value: DataType::String("furious".to_string()),
+        }],
+        condition: Some(SqlConditionTree::Comparison(SqlSimpleCondition {
+            column: "id".to_string(),
+            operator: "=".to_string(),
+            value: DataType::Integer(1),
+        })),
+        returning: None,
+    });
+    assert!(matches!(invalid_update, Err(OxidbError::ConstraintViolation(_))));
+}
+
+
+#[test]
+fn test_add_validation_rule_enforces_error_and_warning_severity() {
+    use crate::core::common::OxidbError;
+    use crate::core::query::commands::{
+        Command, Severity, SqlAssignment, SqlConditionTree, SqlSimpleCondition,
+    };
+    use crate::core::query::executor::QueryExecutor;
+    use crate::core::storage::engine::InMemoryKvStore;
+    use crate::core::wal::log_manager::LogManager;
+    use crate::core::wal::writer::{WalWriter, WalWriterConfig};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let wal_writer = WalWriter::new(temp_path.join("test.wal"), WalWriterConfig::default());
+    let log_manager = Arc::new(LogManager::new());
+    let store = InMemoryKvStore::new();
+    let mut executor =
+        QueryExecutor::new(store, temp_path.clone(), wal_writer, log_manager).unwrap();
+
+    executor
+        .execute_command(
+            parse_query(
+                "CREATE TABLE accounts (id INTEGER PRIMARY KEY, balance INTEGER, status TEXT);",
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+    // An "active" account's balance must not be negative (an `Error`-severity rule).
+    executor
+        .execute_command(Command::AddValidationRule {
+            table_name: "accounts".to_string(),
+            name: "no_negative_active_balance".to_string(),
+            when: Some(SqlConditionTree::Comparison(SqlSimpleCondition {
+                column: "status".to_string(),
+                operator: "=".to_string(),
+                value: DataType::String("active".to_string()),
+            })),
+            then: SqlConditionTree::Comparison(SqlSimpleCondition {
+                column: "balance".to_string(),
+                operator: ">=".to_string(),
+                value: DataType::Integer(0),
+            }),
+            severity: Severity::Error,
+        })
+        .unwrap();
+
+    // Registering the same rule name twice on the same table is rejected.
+    assert!(matches!(
+        executor.execute_command(Command::AddValidationRule {
+            table_name: "accounts".to_string(),
+            name: "no_negative_active_balance".to_string(),
+            when: None,
+            then: SqlConditionTree::Comparison(SqlSimpleCondition {
+                column: "balance".to_string(),
+                operator: ">=".to_string(),
+                value: DataType::Integer(0),
+            }),
+            severity: Severity::Warning,
+        }),
+        Err(OxidbError::AlreadyExists { .. })
+    ));
+
+    // A closed account with a negative balance doesn't match `when`, so it's allowed.
+    executor
+        .execute_command(
+            parse_query(
+                "INSERT INTO accounts (id, balance, status) VALUES (1, -50, 'closed');",
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+    // An active account with a negative balance violates the rule and is rejected.
+    let invalid_insert = executor.execute_command(
+        parse_query("INSERT INTO accounts (id, balance, status) VALUES (2, -10, 'active');")
+            .unwrap(),
+    );
+    assert!(matches!(invalid_insert, Err(OxidbError::ConstraintViolation(_))));
+
+    // An active account with a non-negative balance is fine.
+    executor
+        .execute_command(
+            parse_query("INSERT INTO accounts (id, balance, status) VALUES (3, 100, 'active');")
+                .unwrap(),
+        )
+        .unwrap();
+
+    // Updating account 1's status to "active" without fixing its balance also
+    // violates the rule.
+    let invalid_update = executor.execute_command(Command::Update {
+        source: "accounts".to_string(),
+        assignments: vec![SqlAssignment {
+            column: "status".to_string(),
+            value: DataType::String("active".to_string()),
+        }],
+        condition: Some(SqlConditionTree::Comparison(SqlSimpleCondition {
+            column: "id".to_string(),
+            operator: "=".to_string(),
+            value: DataType::Integer(1),
+        })),
+        returning: None,
+    });
+    assert!(matches!(invalid_update, Err(OxidbError::ConstraintViolation(_))));
+
+    // A `Warning`-severity rule never blocks the write, even when violated.
+    executor
+        .execute_command(Command::AddValidationRule {
+            table_name: "accounts".to_string(),
+            name: "prefer_small_balances".to_string(),
+            when: None,
+            then: SqlConditionTree::Comparison(SqlSimpleCondition {
+                column: "balance".to_string(),
+                operator: "<".to_string(),
+                value: DataType::Integer(1000),
+            }),
+            severity: Severity::Warning,
+        })
+        .unwrap();
+    executor
+        .execute_command(
+            parse_query("INSERT INTO accounts (id, balance, status) VALUES (4, 5000, 'active');")
+                .unwrap(),
+        )
+        .unwrap();
+}
+
+
+#[test]
+fn test_batch_atomic_rolls_back_and_non_atomic_reports_per_item() {
+    use crate::core::query::commands::Command;
+    use crate::core::query::executor::{BatchItemResult, ExecutionResult, QueryExecutor};
+    use crate::core::storage::engine::InMemoryKvStore;
+    use crate::core::wal::log_manager::LogManager;
+    use crate::core::wal::writer::{WalWriter, WalWriterConfig};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let wal_writer = WalWriter::new(temp_path.join("test.wal"), WalWriterConfig::default());
+    let log_manager = Arc::new(LogManager::new());
+    let store = InMemoryKvStore::new();
+    let mut executor =
+        QueryExecutor::new(store, temp_path.clone(), wal_writer, log_manager).unwrap();
+
+    executor
+        .execute_command(parse_query("CREATE TABLE widgets (id INTEGER PRIMARY KEY);").unwrap())
+        .unwrap();
+
+    // Atomic batch: a later command's failure rolls back the earlier inserts too.
+    let atomic_result = executor.execute_command(Command::Batch {
+        commands: vec![
+            parse_query("INSERT INTO widgets (id) VALUES (1);").unwrap(),
+            parse_query("INSERT INTO widgets (id) VALUES (2);").unwrap(),
+            // Duplicate primary key, fails the uniqueness check.
+            parse_query("INSERT INTO widgets (id) VALUES (1);").unwrap(),
+        ],
+        atomic: true,
+    });
+    assert!(atomic_result.is_err());
+    let rows_after_atomic_failure = executor
+        .execute_command(parse_query("SELECT * FROM widgets;").unwrap())
+        .unwrap();
+    match rows_after_atomic_failure {
+        ExecutionResult::Values(rows) => assert!(rows.is_empty()),
+        other => panic!("Expected Values, got {other:?}"),
+    }
+
+    // Non-atomic batch: each command commits independently, failures don't
+    // block later items.
+    let non_atomic_result = executor
+        .execute_command(Command::Batch {
+            commands: vec![
+                parse_query("INSERT INTO widgets (id) VALUES (1);").unwrap(),
+                // Duplicate primary key, fails, but doesn't roll back item 1.
+                parse_query("INSERT INTO widgets (id) VALUES (1);").unwrap(),
+                parse_query("INSERT INTO widgets (id) VALUES (2);").unwrap(),
+            ],
+            atomic: false,
+        })
+        .unwrap();
+    match non_atomic_result {
+        ExecutionResult::Batch(items) => {
+            assert!(matches!(items[0], BatchItemResult::Ok(_)));
+            assert!(matches!(items[1], BatchItemResult::Err(_)));
+            assert!(matches!(items[2], BatchItemResult::Ok(_)));
+        }
+        other => panic!("Expected Batch, got {other:?}"),
+    }
+    let rows_after_non_atomic = executor
+        .execute_command(parse_query("SELECT * FROM widgets;").unwrap())
+        .unwrap();
+    match rows_after_non_atomic {
+        ExecutionResult::Values(rows) => assert_eq!(rows.len(), 2),
+        other => panic!("Expected Values, got {other:?}"),
+    }
+}
+
+
+#[test]
+fn test_alter_table_add_and_drop_column_rewrites_rows() {
+    use crate::core::common::OxidbError;
+    use crate::core::query::commands::{AlterTableOperation, Command};
+    use crate::core::query::executor::{ExecutionResult, QueryExecutor};
+    use crate::core::query::parser::parse_query;
+    use crate::core::storage::engine::InMemoryKvStore;
+    use crate::core::types::schema::Schema;
+    use crate::core::wal::log_manager::LogManager;
+    use crate::core::wal::writer::{WalWriter, WalWriterConfig};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let wal_writer = WalWriter::new(temp_path.join("test.wal"), WalWriterConfig::default());
+    let log_manager = Arc::new(LogManager::new());
+    let store = InMemoryKvStore::new();
+    let mut executor =
+        QueryExecutor::new(store, temp_path.clone(), wal_writer, log_manager).unwrap();
+
+    executor
+        .execute_command(
+            parse_query("CREATE TABLE pets (id INTEGER PRIMARY KEY, name TEXT);").unwrap(),
+        )
+        .unwrap();
+    executor
+        .execute_command(parse_query("INSERT INTO pets (id, name) VALUES (1, 'Rex');").unwrap())
+        .unwrap();
+
+    // Adding a NOT NULL column without a default to a non-empty table is rejected.
+    let mut not_null_no_default = Schema::new_column_def("age".to_string(), DataType::Integer(0));
+    not_null_no_default.is_nullable = false;
+    assert!(matches!(
+        executor.execute_command(Command::AlterTable {
+            table_name: "pets".to_string(),
+            operation: AlterTableOperation::AddColumn {
+                column: not_null_no_default,
+                default: None,
+            },
+        }),
+        Err(OxidbError::InvalidInput { .. })
+    ));
+
+    // Adding a column with a default backfills existing rows with it.
+    let age_column = Schema::new_column_def("age".to_string(), DataType::Integer(0));
+    executor
+        .execute_command(Command::AlterTable {
+            table_name: "pets".to_string(),
+            operation: AlterTableOperation::AddColumn {
+                column: age_column,
+                default: Some(DataType::Integer(3)),
+            },
+        })
+        .unwrap();
+
+    match executor.execute_command(parse_query("SELECT * FROM pets;").unwrap()).unwrap() {
+        ExecutionResult::Values(row) => {
+            assert!(row.contains(&DataType::Integer(3)));
+        }
+        other => panic!("Expected Values, got {other:?}"),
+    }
+
+    // A newly inserted row must supply the new column explicitly.
+    executor
+        .execute_command(
+            parse_query("INSERT INTO pets (id, name, age) VALUES (2, 'Fido', 5);").unwrap(),
+        )
+        .unwrap();
+
+    // Re-adding the same column name is rejected.
+    assert!(matches!(
+        executor.execute_command(Command::AlterTable {
+            table_name: "pets".to_string(),
+            operation: AlterTableOperation::AddColumn {
+                column: Schema::new_column_def("age".to_string(), DataType::Integer(0)),
+                default: None,
+            },
+        }),
+        Err(OxidbError::AlreadyExists { .. })
+    ));
+
+    // Dropping a column projects it out of every existing row.
+    executor
+        .execute_command(Command::AlterTable {
+            table_name: "pets".to_string(),
+            operation: AlterTableOperation::DropColumn { column_name: "age".to_string() },
+        })
+        .unwrap();
+
+    let schema = executor.get_table_schema("pets").unwrap().unwrap();
+    assert!(!schema.columns.iter().any(|col| col.name == "age"));
+
+    // Dropping an unknown column is rejected.
+    assert!(matches!(
+        executor.execute_command(Command::AlterTable {
+            table_name: "pets".to_string(),
+            operation: AlterTableOperation::DropColumn { column_name: "nonexistent".to_string() },
+        }),
+        Err(OxidbError::NotFound(_))
+    ));
+}
+
+
+#[test]
+fn test_create_index_backfills_and_drop_index_removes_it() {
+    use crate::core::common::serialization::serialize_data_type;
+    use crate::core::common::OxidbError;
+    use crate::core::query::commands::Command;
+    use crate::core::query::executor::QueryExecutor;
+    use crate::core::query::parser::parse_query;
+    use crate::core::storage::engine::InMemoryKvStore;
+    use crate::core::wal::log_manager::LogManager;
+    use crate::core::wal::writer::{WalWriter, WalWriterConfig};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let wal_writer = WalWriter::new(temp_path.join("test.wal"), WalWriterConfig::default());
+    let log_manager = Arc::new(LogManager::new());
+    let store = InMemoryKvStore::new();
+    let mut executor =
+        QueryExecutor::new(store, temp_path.clone(), wal_writer, log_manager).unwrap();
+
+    executor
+        .execute_command(
+            parse_query("CREATE TABLE pets (id INTEGER PRIMARY KEY, species TEXT);").unwrap(),
+        )
+        .unwrap();
+    // `species` has no automatic index: it's neither a primary key nor unique.
+    executor
+        .execute_command(
+            parse_query(
+                "INSERT INTO pets (id, species) VALUES (1, 'cat'), (2, 'dog'), (3, 'cat');",
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+    // `CREATE INDEX` backfills from the rows already present.
+    executor
+        .execute_command(Command::CreateIndex {
+            index_name: "idx_pets_species".to_string(),
+            table_name: "pets".to_string(),
+            column_name: "species".to_string(),
+        })
+        .unwrap();
+
+    let serialized_cat = serialize_data_type(&DataType::String("cat".to_string())).unwrap();
+    let cat_keys = executor
+        .index_manager
+        .read()
+        .unwrap()
+        .find_by_index("idx_pets_species", &serialized_cat)
+        .unwrap()
+        .unwrap_or_default();
+    assert_eq!(cat_keys.len(), 2);
+
+    // Creating an index under a name that's already registered is rejected.
+    assert!(matches!(
+        executor.execute_command(Command::CreateIndex {
+            index_name: "idx_pets_species".to_string(),
+            table_name: "pets".to_string(),
+            column_name: "species".to_string(),
+        }),
+        Err(OxidbError::Index(_))
+    ));
+
+    // A newly inserted row is maintained in the index without a fresh backfill.
+    executor
+        .execute_command(
+            parse_query("INSERT INTO pets (id, species) VALUES (4, 'dog');").unwrap(),
+        )
+        .unwrap();
+    let serialized_dog = serialize_data_type(&DataType::String("dog".to_string())).unwrap();
+    let dog_keys = executor
+        .index_manager
+        .read()
+        .unwrap()
+        .find_by_index("idx_pets_species", &serialized_dog)
+        .unwrap()
+        .unwrap_or_default();
+    assert_eq!(dog_keys.len(), 2);
+
+    // Deleting a row removes its entry from the index too.
+    executor
+        .execute_command(parse_query("DELETE FROM pets WHERE id = 2;").unwrap())
+        .unwrap();
+    let dog_keys_after_delete = executor
+        .index_manager
+        .read()
+        .unwrap()
+        .find_by_index("idx_pets_species", &serialized_dog)
+        .unwrap()
+        .unwrap_or_default();
+    assert_eq!(dog_keys_after_delete.len(), 1);
+
+    // `DROP INDEX` unregisters it; further lookups fail instead of silently
+    // returning nothing, since the index name is no longer known at all.
+    executor
+        .execute_command(Command::DropIndex { index_name: "idx_pets_species".to_string() })
+        .unwrap();
+    assert!(matches!(
+        executor
+            .index_manager
+            .read()
+            .unwrap()
+            .find_by_index("idx_pets_species", &serialized_cat),
+        Err(OxidbError::Index(_))
+    ));
+
+    // Dropping an index that isn't registered is rejected.
+    assert!(matches!(
+        executor.execute_command(Command::DropIndex { index_name: "nonexistent".to_string() }),
+        Err(OxidbError::Index(_))
+    ));
+
+    // Creating an index on an unknown column is rejected.
+    assert!(matches!(
+        executor.execute_command(Command::CreateIndex {
+            index_name: "idx_pets_nonexistent".to_string(),
+            table_name: "pets".to_string(),
+            column_name: "nonexistent".to_string(),
+        }),
+        Err(OxidbError::NotFound(_))
+    ));
+}
+
+
+#[test]
+fn test_query_executor_new_runs_wal_recovery_on_startup() {
+    use crate::core::query::executor::QueryExecutor;
+    use crate::core::storage::engine::InMemoryKvStore;
+    use crate::core::wal::log_manager::LogManager;
+    use crate::core::wal::log_record::LogRecord;
+    use crate::core::wal::writer::{WalWriter, WalWriterConfig};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path().to_path_buf();
+    let wal_path = temp_path.join("recovery_test.wal");
+
+    // A fresh database has no WAL file on disk yet; `new` must treat that as
+    // "nothing to recover" rather than erroring.
+    let wal_writer = WalWriter::new(wal_path.clone(), WalWriterConfig::default());
+    let log_manager = Arc::new(LogManager::new());
+    assert!(QueryExecutor::new(InMemoryKvStore::new(), temp_path.clone(), wal_writer, log_manager)
+        .is_ok());
+
+    // A WAL left behind by a committed transaction is replayed cleanly.
+    {
+        let mut wal_writer = WalWriter::new(wal_path.clone(), WalWriterConfig::default());
+        wal_writer.add_record(&LogRecord::BeginTransaction { lsn: 1, tx_id: 1 }).unwrap();
+        wal_writer
+            .add_record(&LogRecord::CommitTransaction { lsn: 2, tx_id: 1, prev_lsn: 1 })
+            .unwrap();
+        wal_writer.flush().unwrap();
+    }
+    let wal_writer = WalWriter::new(wal_path.clone(), WalWriterConfig::default());
+    let log_manager = Arc::new(LogManager::new());
+    assert!(QueryExecutor::new(InMemoryKvStore::new(), temp_path.clone(), wal_writer, log_manager)
+        .is_ok());
+
+    // A corrupted WAL file surfaces as a startup error instead of silently
+    // being ignored.
+    std::fs::write(&wal_path, b"not a valid wal record stream").unwrap();
+    let wal_writer = WalWriter::new(wal_path.clone(), WalWriterConfig::default());
+    let log_manager = Arc::new(LogManager::new());
+    assert!(
+        QueryExecutor::new(InMemoryKvStore::new(), temp_path, wal_writer, log_manager).is_err()
+    );
+}
+
+
+#[test]
+fn test_create_trigger_fires_on_insert_and_delete() {
+    use crate::core::common::OxidbError;
+    use crate::core::query::commands::{Command, TriggerEvent, TriggerTiming};
+    use crate::core::query::executor::{ExecutionResult, QueryExecutor};
+    use crate::core::storage::engine::InMemoryKvStore;
+    use crate::core::wal::log_manager::LogManager;
+    use crate::core::wal::writer::{WalWriter, WalWriterConfig};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let wal_writer = WalWriter::new(temp_path.join("test.wal"), WalWriterConfig::default());
+    let log_manager = Arc::new(LogManager::new());
+    let store = InMemoryKvStore::new();
+    let mut executor =
+        QueryExecutor::new(store, temp_path.clone(), wal_writer, log_manager).unwrap();
+
+    executor
+        .execute_command(parse_query("CREATE TABLE items (id INTEGER PRIMARY KEY, name TEXT);").unwrap())
+        .unwrap();
+    executor
+        .execute_command(
+            parse_query("CREATE TABLE audit (id INTEGER PRIMARY KEY AUTOINCREMENT, note TEXT);")
+                .unwrap(),
+        )
+        .unwrap();
+
+    let log_row = |note: &str| Command::SqlInsert {
+        table_name: "audit".to_string(),
+        columns: Some(vec!["note".to_string()]),
+        values: vec![vec![DataType::String(note.to_string())]],
+        on_conflict: None,
+        returning: None,
+    };
+
+    executor
+        .execute_command(Command::CreateTrigger {
+            table_name: "items".to_string(),
+            name: "log_item_insert".to_string(),
+            timing: TriggerTiming::After,
+            event: TriggerEvent::Insert,
+            body: vec![log_row("item inserted")],
+        })
+        .unwrap();
+    executor
+        .execute_command(Command::CreateTrigger {
+            table_name: "items".to_string(),
+            name: "log_item_delete".to_string(),
+            timing: TriggerTiming::After,
+            event: TriggerEvent::Delete,
+            body: vec![log_row("item deleted")],
+        })
+        .unwrap();
+
+    // Registering a second trigger under the same name on the same table is rejected.
+    assert!(matches!(
+        executor.execute_command(Command::CreateTrigger {
+            table_name: "items".to_string(),
+            name: "log_item_insert".to_string(),
+            timing: TriggerTiming::Before,
+            event: TriggerEvent::Insert,
+            body: vec![],
+        }),
+        Err(OxidbError::AlreadyExists { .. })
+    ));
+
+    executor
+        .execute_command(
+            parse_query("INSERT INTO items (id, name) VALUES (1, 'widget');").unwrap(),
+        )
+        .unwrap();
+    executor
+        .execute_command(parse_query("INSERT INTO items (id, name) VALUES (2, 'gadget');").unwrap())
+        .unwrap();
+
+    match executor
+        .execute_command(parse_query("SELECT note FROM audit;").unwrap())
+        .unwrap()
+    {
+        ExecutionResult::Values(rows) => {
+            assert_eq!(
+                rows,
+                vec![
+                    DataType::String("item inserted".to_string()),
+                    DataType::String("item inserted".to_string()),
+                ]
+            );
+        }
+        other => panic!("Expected ExecutionResult::Values, got {other:?}"),
+    }
+
+    executor
+        .execute_command(
+            parse_query("DELETE FROM items WHERE id = 1;").unwrap(),
+        )
+        .unwrap();
+
+    match executor
+        .execute_command(parse_query("SELECT note FROM audit;").unwrap())
+        .unwrap()
+    {
+        ExecutionResult::Values(rows) => {
+            assert_eq!(
+                rows,
+                vec![
+                    DataType::String("item inserted".to_string()),
+                    DataType::String("item inserted".to_string()),
+                    DataType::String("item deleted".to_string()),
+                ]
+            );
+        }
+        other => panic!("Expected ExecutionResult::Values, got {other:?}"),
+    }
+}
+
+
+#[test]
+fn test_auto_increment_state_rescans_every_table_not_just_a_hardcoded_pair() {
+    use crate::core::query::executor::QueryExecutor;
+    use crate::core::storage::engine::InMemoryKvStore;
+    use crate::core::wal::log_manager::LogManager;
+    use crate::core::wal::writer::{WalWriter, WalWriterConfig};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let wal_writer = WalWriter::new(temp_path.join("test.wal"), WalWriterConfig::default());
+    let log_manager = Arc::new(LogManager::new());
+    let store = InMemoryKvStore::new();
+    let mut executor =
+        QueryExecutor::new(store, temp_path.clone(), wal_writer, log_manager).unwrap();
+
+    // Three tables, none of them "users" or "user_files", each with its own
+    // auto-increment primary key and rows inserted with an explicit id (so
+    // auto_increment_state is never touched via the normal insert path).
+    for (table, max_id) in [("widgets", 5), ("gizmos", 12), ("sprockets", 1)] {
+        executor
+            .execute_command(
+                parse_query(&format!(
+                    "CREATE TABLE {table} (id INTEGER PRIMARY KEY AUTOINCREMENT, name TEXT);"
+                ))
+                .unwrap(),
+            )
+            .unwrap();
+        for id in 1..=max_id {
+            executor
+                .execute_command(
+                    parse_query(&format!(
+                        "INSERT INTO {table} (id, name) VALUES ({id}, 'row{id}');"
+                    ))
+                    .unwrap(),
+                )
+                .unwrap();
+        }
+    }
+
+    // Re-run the same rescan `QueryExecutor::new` performs at startup, as if
+    // this were a fresh process opening a store that already has data in it.
+    executor.load_auto_increment_state().unwrap();
+
+    assert_eq!(executor.get_next_auto_increment_value("widgets", "id"), 6);
+    assert_eq!(executor.get_next_auto_increment_value("gizmos", "id"), 13);
+    assert_eq!(executor.get_next_auto_increment_value("sprockets", "id"), 2);
+}
+
+
+#[test]
+fn test_trigger_cascade_is_capped_instead_of_recursing_forever() {
+    use crate::core::common::OxidbError;
+    use crate::core::query::commands::{Command, TriggerEvent, TriggerTiming};
+    use crate::core::query::executor::QueryExecutor;
+    use crate::core::storage::engine::InMemoryKvStore;
+    use crate::core::wal::log_manager::LogManager;
+    use crate::core::wal::writer::{WalWriter, WalWriterConfig};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let wal_writer = WalWriter::new(temp_path.join("test.wal"), WalWriterConfig::default());
+    let log_manager = Arc::new(LogManager::new());
+    let store = InMemoryKvStore::new();
+    let mut executor =
+        QueryExecutor::new(store, temp_path.clone(), wal_writer, log_manager).unwrap();
+
+    // Neither table has a primary key or unique column, so repeated inserts
+    // of the same values never conflict - the only thing that can stop the
+    // cascade below is the nesting cap itself.
+    executor
+        .execute_command(parse_query("CREATE TABLE ping (n INTEGER);").unwrap())
+        .unwrap();
+    executor
+        .execute_command(parse_query("CREATE TABLE pong (n INTEGER);").unwrap())
+        .unwrap();
+
+    // Each table's AFTER INSERT trigger inserts into the other, so a single
+    // insert would recurse forever without the nesting cap.
+    executor
+        .execute_command(Command::CreateTrigger {
+            table_name: "ping".to_string(),
+            name: "ping_to_pong".to_string(),
+            timing: TriggerTiming::After,
+            event: TriggerEvent::Insert,
+            body: vec![Command::SqlInsert {
+                table_name: "pong".to_string(),
+                columns: Some(vec!["n".to_string()]),
+                values: vec![vec![DataType::Integer(1)]],
+                on_conflict: None,
+                returning: None,
+            }],
+        })
+        .unwrap();
+    executor
+        .execute_command(Command::CreateTrigger {
+            table_name: "pong".to_string(),
+            name: "pong_to_ping".to_string(),
+            timing: TriggerTiming::After,
+            event: TriggerEvent::Insert,
+            body: vec![Command::SqlInsert {
+                table_name: "ping".to_string(),
+                columns: Some(vec!["n".to_string()]),
+                values: vec![vec![DataType::Integer(1)]],
+                on_conflict: None,
+                returning: None,
+            }],
+        })
+        .unwrap();
+
+    let result =
+        executor.execute_command(parse_query("INSERT INTO ping (n) VALUES (1);").unwrap());
+    assert!(
+        matches!(result, Err(OxidbError::Execution(ref msg)) if msg.contains("maximum nesting depth")),
+        "expected a capped-cascade error, got {result:?}"
+    );
+
+    // The depth counter must be restored to 0 after the failed cascade so a
+    // later, unrelated single-level trigger still fires normally.
+    assert_eq!(executor.trigger_depth, 0);
+}
+
+
+#[test]
+fn test_create_index_backfill_rolls_back_and_drop_index_deletes_its_file() {
+    use crate::core::common::serialization::serialize_data_type;
+    use crate::core::common::OxidbError;
+    use crate::core::query::commands::Command;
+    use crate::core::query::executor::QueryExecutor;
+    use crate::core::query::parser::parse_query;
+    use crate::core::storage::engine::InMemoryKvStore;
+    use crate::core::wal::log_manager::LogManager;
+    use crate::core::wal::writer::{WalWriter, WalWriterConfig};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let wal_writer = WalWriter::new(temp_path.join("test.wal"), WalWriterConfig::default());
+    let log_manager = Arc::new(LogManager::new());
+    let store = InMemoryKvStore::new();
+    let mut executor =
+        QueryExecutor::new(store, temp_path.clone(), wal_writer, log_manager).unwrap();
+
+    executor
+        .execute_command(
+            parse_query("CREATE TABLE pets (id INTEGER PRIMARY KEY, species TEXT);").unwrap(),
+        )
+        .unwrap();
+    executor
+        .execute_command(
+            parse_query("INSERT INTO pets (id, species) VALUES (1, 'cat'), (2, 'dog');").unwrap(),
+        )
+        .unwrap();
+
+    // Building the index inside an explicit transaction that then rolls back
+    // must leave no postings behind - the whole backfill undoes cleanly.
+    executor.execute_command(Command::BeginTransaction).unwrap();
+    executor
+        .execute_command(Command::CreateIndex {
+            index_name: "idx_pets_species".to_string(),
+            table_name: "pets".to_string(),
+            column_name: "species".to_string(),
+        })
+        .unwrap();
+    let serialized_cat = serialize_data_type(&DataType::String("cat".to_string())).unwrap();
+    assert_eq!(
+        executor
+            .index_manager
+            .read()
+            .unwrap()
+            .find_by_index("idx_pets_species", &serialized_cat)
+            .unwrap()
+            .unwrap_or_default()
+            .len(),
+        1,
+        "backfill should have indexed the existing 'cat' row before rollback"
+    );
+    executor.execute_command(Command::RollbackTransaction).unwrap();
+    assert_eq!(
+        executor
+            .index_manager
+            .read()
+            .unwrap()
+            .find_by_index("idx_pets_species", &serialized_cat)
+            .unwrap()
+            .unwrap_or_default()
+            .len(),
+        0,
+        "rolling back CREATE INDEX's transaction should undo every posting the backfill added"
+    );
+
+    // `DROP INDEX` must also remove the index's backing file, or it would
+    // resurface via auto-discovery the next time the store is reopened.
+    executor
+        .execute_command(Command::CreateIndex {
+            index_name: "idx_pets_species".to_string(),
+            table_name: "pets".to_string(),
+            column_name: "species".to_string(),
+        })
+        .unwrap();
+    // Hash indexes aren't written to disk on every insert (only via an
+    // explicit save), so force one to get a backing file to check for.
+    executor.index_manager.read().unwrap().save_all_indexes().unwrap();
+    let index_base_path = executor.index_manager.read().unwrap().base_path();
+    let index_file = index_base_path.join("idx_pets_species.idx");
+    assert!(index_file.exists(), "save_all_indexes should have written a backing hash-index file");
+
+    executor
+        .execute_command(Command::DropIndex { index_name: "idx_pets_species".to_string() })
+        .unwrap();
+    assert!(
+        !index_file.exists(),
+        "DROP INDEX should delete the backing file so it can't be auto-discovered again"
+    );
+    assert!(matches!(
+        executor
+            .index_manager
+            .read()
+            .unwrap()
+            .find_by_index("idx_pets_species", &serialized_cat),
+        Err(OxidbError::Index(_))
+    ));
+}
+
+
+#[test]
+fn test_alter_table_drop_column_rejects_primary_key_and_cleans_up_index_and_auto_increment() {
+    use crate::core::common::OxidbError;
+    use crate::core::query::commands::{AlterTableOperation, Command};
+    use crate::core::query::executor::QueryExecutor;
+    use crate::core::query::parser::parse_query;
+    use crate::core::storage::engine::traits::KeyValueStore;
+    use crate::core::storage::engine::InMemoryKvStore;
+    use crate::core::types::schema::Schema;
+    use crate::core::wal::log_manager::LogManager;
+    use crate::core::wal::writer::{WalWriter, WalWriterConfig};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let wal_writer = WalWriter::new(temp_path.join("test.wal"), WalWriterConfig::default());
+    let log_manager = Arc::new(LogManager::new());
+    let store = InMemoryKvStore::new();
+    let mut executor =
+        QueryExecutor::new(store, temp_path.clone(), wal_writer, log_manager).unwrap();
+
+    executor
+        .execute_command(
+            parse_query(
+                "CREATE TABLE widgets (id INTEGER PRIMARY KEY AUTOINCREMENT, code TEXT);",
+            )
+            .unwrap(),
+        )
+        .unwrap();
+    executor
+        .execute_command(parse_query("INSERT INTO widgets (code) VALUES ('a');").unwrap())
+        .unwrap();
+
+    // Dropping the primary key column is always rejected.
+    assert!(matches!(
+        executor.execute_command(Command::AlterTable {
+            table_name: "widgets".to_string(),
+            operation: AlterTableOperation::DropColumn { column_name: "id".to_string() },
+        }),
+        Err(OxidbError::ConstraintViolation(_))
+    ));
+
+    // A registered index on the dropped column is cleaned up too.
+    executor
+        .execute_command(Command::CreateIndex {
+            index_name: "idx_widgets_code".to_string(),
+            table_name: "widgets".to_string(),
+            column_name: "code".to_string(),
+        })
+        .unwrap();
+    assert!(executor.index_manager.read().unwrap().get_index("idx_widgets_code").is_some());
+
+    // The in-memory and persisted auto-increment counters for "id" exist
+    // before the column tracking them is dropped...
+    assert!(executor.auto_increment_state.contains_key("widgets_id"));
+
+    executor
+        .execute_command(Command::AlterTable {
+            table_name: "widgets".to_string(),
+            operation: AlterTableOperation::DropColumn { column_name: "code".to_string() },
+        })
+        .unwrap();
+
+    assert!(
+        executor.index_manager.read().unwrap().get_index("idx_widgets_code").is_none(),
+        "dropping the indexed column should have dropped its index too"
+    );
+
+    // ...but "id" is untouched since only "code" was dropped; purging is
+    // specific to the column actually being dropped, not a blanket reset.
+    assert!(executor.auto_increment_state.contains_key("widgets_id"));
+
+    // Now drop a second, non-PK auto-increment-tracked column and confirm
+    // both its in-memory and persisted counters are purged.
+    executor
+        .execute_command(Command::AlterTable {
+            table_name: "widgets".to_string(),
+            operation: AlterTableOperation::AddColumn {
+                column: Schema::new_column_def("batch".to_string(), DataType::Integer(0)),
+                default: Some(DataType::Integer(0)),
+            },
+        })
+        .unwrap();
+    executor.auto_increment_state.insert("widgets_batch".to_string(), 7);
+    let persisted_key = b"_auto_increment_widgets_batch".to_vec();
+    executor
+        .store
+        .write()
+        .unwrap()
+        .put(
+            persisted_key.clone(),
+            b"7".to_vec(),
+            &crate::core::transaction::Transaction::new(crate::core::common::types::TransactionId(
+                0,
+            )),
+            0,
+        )
+        .unwrap();
+
+    executor
+        .execute_command(Command::AlterTable {
+            table_name: "widgets".to_string(),
+            operation: AlterTableOperation::DropColumn { column_name: "batch".to_string() },
+        })
+        .unwrap();
+
+    assert!(!executor.auto_increment_state.contains_key("widgets_batch"));
+    assert!(executor.store.read().unwrap().get(&persisted_key, 0, &Default::default()).unwrap().is_none());
+}
+
+
+#[test]
+fn test_execute_parameterized_statement_with_numbered_and_named_placeholders() {
+    use crate::core::common::types::Value as ParamValue;
+    use crate::core::common::OxidbError;
+    use crate::core::query::executor::{ExecutionResult, QueryExecutor};
+    use crate::core::query::parser::parse_query;
+    use crate::core::query::sql::ast::Statement;
+    use crate::core::query::sql::parser::SqlParser;
+    use crate::core::query::sql::tokenizer::Tokenizer;
+    use crate::core::storage::engine::InMemoryKvStore;
+    use crate::core::wal::log_manager::LogManager;
+    use crate::core::wal::writer::{WalWriter, WalWriterConfig};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    fn tokenize_str(input: &str) -> Vec<crate::core::query::sql::tokenizer::Token> {
+        let mut tokenizer = Tokenizer::new(input);
+        tokenizer.tokenize().unwrap_or_else(|e| panic!("Test tokenizer error: {}", e))
+    }
+
+    fn parse_raw_statement(sql: &str) -> Statement {
+        let mut parser = SqlParser::new(tokenize_str(sql));
+        parser.parse().unwrap()
+    }
+
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let wal_writer = WalWriter::new(temp_path.join("test.wal"), WalWriterConfig::default());
+    let log_manager = Arc::new(LogManager::new());
+    let store = InMemoryKvStore::new();
+    let mut executor =
+        QueryExecutor::new(store, temp_path.clone(), wal_writer, log_manager).unwrap();
+
+    executor
+        .execute_command(
+            parse_query("CREATE TABLE widgets (id INTEGER PRIMARY KEY, name TEXT, price INTEGER);")
+                .unwrap(),
+        )
+        .unwrap();
+    executor
+        .execute_command(
+            parse_query("INSERT INTO widgets (id, name, price) VALUES (1, 'Alpha', 10);").unwrap(),
+        )
+        .unwrap();
+    executor
+        .execute_command(
+            parse_query("INSERT INTO widgets (id, name, price) VALUES (2, 'Beta', 20);").unwrap(),
+        )
+        .unwrap();
+
+    // `?2`/`$2` are 1-based, independent of left-to-right occurrence order -
+    // here `?2` picks the *second* bound value even though it's the only
+    // placeholder in the statement.
+    let statement = parse_raw_statement("SELECT name FROM widgets WHERE id = ?2;");
+    let result = executor
+        .execute_parameterized_statement(&statement, &[ParamValue::Integer(99), ParamValue::Integer(2)])
+        .unwrap();
+    assert_eq!(result, ExecutionResult::Values(vec![DataType::String("Beta".to_string())]));
+
+    // A named placeholder used twice (`:id`) only needs one bound value.
+    let statement =
+        parse_raw_statement("SELECT name FROM widgets WHERE id = :id OR price = :id;");
+    let mut named = HashMap::new();
+    named.insert("id".to_string(), ParamValue::Integer(1));
+    let result =
+        executor.execute_parameterized_statement_with_named(&statement, &[], &named).unwrap();
+    assert_eq!(result, ExecutionResult::Values(vec![DataType::String("Alpha".to_string())]));
+
+    // Referencing `:missing` without binding it is reported, not silently
+    // treated as NULL.
+    let statement = parse_raw_statement("SELECT name FROM widgets WHERE id = :missing;");
+    let err = executor
+        .execute_parameterized_statement_with_named(&statement, &[], &HashMap::new())
+        .unwrap_err();
+    assert!(matches!(err, OxidbError::InvalidInput { .. }));
+}
+
+
+#[test]
+fn test_describe_select_marks_left_joined_columns_nullable() {
+    use crate::core::query::commands::DescribeResult;
+    use crate::core::query::executor::{ExecutionResult, QueryExecutor};
+    use crate::core::query::parser::parse_query;
+    use crate::core::query::sql::ast::Statement;
+    use crate::core::query::sql::parser::SqlParser;
+    use crate::core::query::sql::tokenizer::Tokenizer;
+    use crate::core::storage::engine::InMemoryKvStore;
+    use crate::core::wal::log_manager::LogManager;
+    use crate::core::wal::writer::{WalWriter, WalWriterConfig};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    fn tokenize_str(input: &str) -> Vec<crate::core::query::sql::tokenizer::Token> {
+        let mut tokenizer = Tokenizer::new(input);
+        tokenizer.tokenize().unwrap_or_else(|e| panic!("Test tokenizer error: {}", e))
+    }
+
+    fn parse_statement(sql: &str) -> Statement {
+        let mut parser = SqlParser::new(tokenize_str(sql));
+        parser.parse().unwrap()
+    }
+
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let wal_writer = WalWriter::new(temp_path.join("test.wal"), WalWriterConfig::default());
+    let log_manager = Arc::new(LogManager::new());
+    let store = InMemoryKvStore::new();
+    let mut executor =
+        QueryExecutor::new(store, temp_path.clone(), wal_writer, log_manager).unwrap();
+
+    executor
+        .execute_command(
+            parse_query("CREATE TABLE authors (id INTEGER PRIMARY KEY, name TEXT);").unwrap(),
+        )
+        .unwrap();
+    executor
+        .execute_command(
+            parse_query("CREATE TABLE books (id INTEGER PRIMARY KEY, author_id INTEGER, title TEXT);")
+                .unwrap(),
+        )
+        .unwrap();
+
+    // `authors` is the preserved (left) side of the LEFT OUTER JOIN, so its
+    // NOT NULL `id`/`name` stay non-nullable; `books` is the side that can be
+    // entirely NULL when an author has no books, so its columns are reported
+    // nullable even though `id` is itself NOT NULL in its own schema.
+    let statement = parse_statement(
+        "SELECT authors.id, authors.name, books.title FROM authors LEFT OUTER JOIN books ON authors.id = books.author_id;",
+    );
+    let result = executor.execute_command(Command::Describe { statement }).unwrap();
+    match result {
+        ExecutionResult::Describe(DescribeResult { columns, .. }) => {
+            assert_eq!(
+                columns,
+                vec![
+                    ("id".to_string(), DataType::Integer(0), false),
+                    ("name".to_string(), DataType::String(String::new()), true),
+                    ("title".to_string(), DataType::String(String::new()), true),
+                ]
+            );
+        }
+        other => panic!("Expected Describe, got {:?}", other),
+    }
+}
@@ -50,6 +50,9 @@ mod tests {
                     is_primary_key: true,
                     is_unique: true,
                     is_auto_increment: false,
+                    max_length: None,
+                    is_fixed_length: false,
+                    truncate_overflow: false,
                 },
                 crate::core::types::schema::ColumnDef {
                     name: "name".to_string(),
@@ -58,6 +61,9 @@ mod tests {
                     is_primary_key: false,
                     is_unique: false,
                     is_auto_increment: false,
+                    max_length: None,
+                    is_fixed_length: false,
+                    truncate_overflow: false,
                 },
             ],
         };
@@ -67,6 +73,8 @@ mod tests {
             table_name: "smoke".to_string(),
             columns: Some(vec!["id".to_string(), "name".to_string()]),
             values: vec![vec![DataType::Integer(1), DataType::String("alice".into())]],
+            on_conflict: None,
+            returning: None,
         };
         assert!(matches!(exec.execute_command(insert)?, ExecutionResult::Updated { .. }));
 
@@ -1,9 +1,13 @@
 use super::{ExecutionResult, QueryExecutor};
 use crate::core::common::OxidbError; // Changed
 use crate::core::storage::engine::traits::KeyValueStore;
-use crate::core::transaction::{Transaction, UndoOperation}; // Removed TransactionState, adjusted path
+use crate::core::transaction::{LockType, Transaction, TransactionBehavior, UndoOperation}; // Removed TransactionState, adjusted path
 use std::collections::{HashMap, HashSet}; // Use super to refer to parent mod
 
+/// Lock table key used by `BEGIN IMMEDIATE`/`BEGIN EXCLUSIVE` to grab a lock
+/// on "the whole database" up front, rather than on any one row or table.
+const DATABASE_LOCK_KEY: &[u8] = b"__oxidb_database__";
+
 impl<S: KeyValueStore<Vec<u8>, Vec<u8>>> QueryExecutor<S> {
     /// Handles the BEGIN TRANSACTION command.
     /// Starts a new transaction using the transaction manager.
@@ -12,6 +16,115 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>>> QueryExecutor<S> {
         Ok(ExecutionResult::Success)
     }
 
+    /// Handles `BEGIN DEFERRED|IMMEDIATE|EXCLUSIVE`.
+    ///
+    /// `Deferred` behaves exactly like a plain `BEGIN`. `Immediate` and
+    /// `Exclusive` additionally acquire a lock on [`DATABASE_LOCK_KEY`]
+    /// immediately, so a transaction that would conflict with another
+    /// active writer fails right away instead of at its first write.
+    pub(crate) fn handle_begin_transaction_with_behavior(
+        &mut self,
+        behavior: TransactionBehavior,
+    ) -> Result<ExecutionResult, OxidbError> {
+        self.transaction_manager.begin_transaction()?;
+
+        let lock_mode = match behavior {
+            TransactionBehavior::Deferred => return Ok(ExecutionResult::Success),
+            TransactionBehavior::Immediate => LockType::Shared,
+            TransactionBehavior::Exclusive => LockType::Exclusive,
+        };
+
+        let tx_id = self
+            .transaction_manager
+            .current_active_transaction_id()
+            .ok_or(OxidbError::NoActiveTransaction)?;
+        if let Err(e) = self.lock_manager.acquire_lock(tx_id.0, &DATABASE_LOCK_KEY.to_vec(), lock_mode) {
+            // The transaction was already registered by `begin_transaction` above; since it
+            // never acquired any locks or made any writes, abort it so a failed `BEGIN
+            // IMMEDIATE`/`BEGIN EXCLUSIVE` doesn't leave a phantom active transaction behind
+            // (which would otherwise pin `get_oldest_active_tx_id` and block VACUUM forever).
+            self.transaction_manager.abort_transaction()?;
+            return Err(e.into());
+        }
+        Ok(ExecutionResult::Success)
+    }
+
+    /// Handles `SAVEPOINT name`.
+    ///
+    /// Records the current length of the active transaction's undo log
+    /// under `name`, so a later `ROLLBACK TO name` knows exactly which undo
+    /// operations to replay.
+    pub(crate) fn handle_savepoint(&mut self, name: String) -> Result<ExecutionResult, OxidbError> {
+        let active_tx = self
+            .transaction_manager
+            .get_active_transaction_mut()
+            .ok_or(OxidbError::NoActiveTransaction)?;
+        let mark = active_tx.undo_log.len();
+        active_tx.savepoints.push((name, mark));
+        Ok(ExecutionResult::Success)
+    }
+
+    /// Handles `RELEASE name`.
+    ///
+    /// Forgets the named savepoint and any nested savepoints created after
+    /// it; their changes remain part of the enclosing transaction.
+    pub(crate) fn handle_release_savepoint(
+        &mut self,
+        name: String,
+    ) -> Result<ExecutionResult, OxidbError> {
+        let active_tx = self
+            .transaction_manager
+            .get_active_transaction_mut()
+            .ok_or(OxidbError::NoActiveTransaction)?;
+        let position = active_tx.savepoints.iter().rposition(|(n, _)| n == &name).ok_or_else(|| {
+            OxidbError::Execution(format!("no such savepoint: {name}"))
+        })?;
+        active_tx.savepoints.truncate(position);
+        Ok(ExecutionResult::Success)
+    }
+
+    /// Handles `ROLLBACK TO name`.
+    ///
+    /// Undoes every change made since `SAVEPOINT name` and truncates the
+    /// undo log back to that point, while leaving `name` itself (and the
+    /// outer transaction) active - only nested savepoints created after it
+    /// are forgotten.
+    pub(crate) fn handle_rollback_to_savepoint(
+        &mut self,
+        name: String,
+    ) -> Result<ExecutionResult, OxidbError> {
+        let (tx_id, mark, position, ops_to_undo) = {
+            let active_tx = self
+                .transaction_manager
+                .get_active_transaction()
+                .ok_or(OxidbError::NoActiveTransaction)?;
+            let position = active_tx.savepoints.iter().rposition(|(n, _)| n == &name).ok_or_else(|| {
+                OxidbError::Execution(format!("no such savepoint: {name}"))
+            })?;
+            let mark = active_tx.savepoints[position].1;
+            let ops_to_undo: Vec<UndoOperation> =
+                active_tx.undo_log[mark..].iter().rev().cloned().collect();
+            (active_tx.id, mark, position, ops_to_undo)
+        };
+
+        let committed_ids: HashSet<u64> = self
+            .transaction_manager
+            .get_committed_tx_ids_snapshot()
+            .into_iter()
+            .map(|id| id.0)
+            .filter(|&id| id != tx_id.0)
+            .collect();
+        self.apply_undo_operations(&ops_to_undo, tx_id, &committed_ids)?;
+
+        let active_tx = self
+            .transaction_manager
+            .get_active_transaction_mut()
+            .ok_or(OxidbError::NoActiveTransaction)?;
+        active_tx.undo_log.truncate(mark);
+        active_tx.savepoints.truncate(position + 1);
+        Ok(ExecutionResult::Success)
+    }
+
     /// Handles the COMMIT TRANSACTION command.
     /// Commits the currently active transaction, releasing its locks and making its changes permanent.
     pub(crate) fn handle_commit_transaction(&mut self) -> Result<ExecutionResult, OxidbError> {
@@ -19,7 +132,7 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>>> QueryExecutor<S> {
         if let Some(active_tx) = self.transaction_manager.get_active_transaction_mut() {
             let tx_id_to_release = active_tx.id;
             active_tx.redo_log.clear();
-            active_tx.undo_log.clear();
+            let undo_log = std::mem::take(&mut active_tx.undo_log);
 
             // let lsn = self.log_manager.next_lsn(); // This LSN was for the store's WAL entry, now removed.
             // active_tx.prev_lsn = lsn; // DO NOT UPDATE prev_lsn here with this.
@@ -32,7 +145,9 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>>> QueryExecutor<S> {
             // physical data changes (Put/Delete WalEntry items).
 
             self.lock_manager.release_locks(tx_id_to_release.0); // Pass u64 for release_locks
-            self.transaction_manager.commit_transaction().map_err(OxidbError::Io)?;
+            let commit_lsn = self.transaction_manager.commit_transaction().map_err(OxidbError::Io)?;
+            self.notify_commit_observers(tx_id_to_release.0, &undo_log);
+            self.notify_tx_observers(tx_id_to_release, commit_lsn);
             Ok(ExecutionResult::Success)
         } else {
             Err(OxidbError::NoActiveTransaction) // Changed
@@ -67,94 +182,21 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>>> QueryExecutor<S> {
                 "Mismatch in transaction ID during rollback prep"
             );
 
+            // Bring back any undo operations that were spilled to disk while
+            // this transaction's undo log grew past its in-memory bound, so
+            // the full rollback below sees the complete log.
+            active_tx.restore_spilled_undo_log().map_err(OxidbError::Io)?;
+
             eprintln!("[QueryExecutor::handle_rollback_transaction] Rolling back TX ID: {:?}, Undo Log: {:?}", tx_id_to_release, active_tx.undo_log);
-            let temp_transaction_for_undo = Transaction::new(tx_id_to_release);
-
-            for undo_op in active_tx.undo_log.iter().rev() {
-                match undo_op {
-                    UndoOperation::RevertInsert { key } => {
-                        let lsn = self.log_manager.next_lsn();
-                        self.store
-                            .write()
-                            .map_err(|e| OxidbError::LockTimeout(format!("Failed to acquire write lock on store for rollback (revert insert): {}",e)))?
-                            .delete(
-                                key,
-                                &temp_transaction_for_undo,
-                                lsn,
-                                &committed_ids_for_undo,
-                            )?;
-                    }
-                    UndoOperation::RevertUpdate { key, old_value: _ } => {
-                        // old_value is used for index, not directly here for store
-                        let lsn = self.log_manager.next_lsn();
-                        // This delete operation finds the version created by temp_transaction_for_undo (the transaction being rolled back)
-                        // and marks its expired_tx_id to its own transaction ID.
-                        // This correctly invalidates the version created by the transaction being rolled back.
-                        // The previously existing version (which was expired by this transaction) will become visible again
-                        // because its expirer_tx_id points to a non-committed transaction.
-                        self.store
-                            .write()
-                            .map_err(|e| OxidbError::LockTimeout(format!("Failed to acquire write lock on store for rollback (revert update): {}",e)))?
-                            .delete(
-                                key,
-                                &temp_transaction_for_undo, // The transaction being rolled back
-                                lsn,
-                                &committed_ids_for_undo,
-                            )?;
-                    }
-                    UndoOperation::RevertDelete { key, old_value } => {
-                        let lsn = self.log_manager.next_lsn();
-                        self.store
-                            .write()
-                            .map_err(|e| OxidbError::LockTimeout(format!("Failed to acquire write lock on store for rollback (revert delete): {}",e)))?
-                            .put(
-                                key.clone(),
-                                old_value.clone(),
-                                &temp_transaction_for_undo,
-                            lsn,
-                        )?;
-                    }
-                    UndoOperation::IndexRevertInsert { index_name, key, value_for_index } => {
-                        let mut indexed_values_map = HashMap::new();
-                        indexed_values_map.insert(index_name.clone(), value_for_index.clone());
-                        self.index_manager
-                            .write()
-                            .map_err(|e| OxidbError::LockTimeout(format!("Failed to acquire write lock on index manager for rollback (revert index insert): {}",e)))?
-                            .on_delete_data(&indexed_values_map, key)?;
-                    }
-                    UndoOperation::IndexRevertDelete { index_name, key, old_value_for_index } => {
-                        let mut indexed_values_map = HashMap::new();
-                        indexed_values_map.insert(index_name.clone(), old_value_for_index.clone());
-                        self.index_manager
-                            .write()
-                            .map_err(|e| OxidbError::LockTimeout(format!("Failed to acquire write lock on index manager for rollback (revert index delete): {}",e)))?
-                            .on_insert_data(&indexed_values_map, key)?;
-                    }
-                    UndoOperation::IndexRevertUpdate {
-                        index_name,
-                        key,
-                        old_value_for_index,
-                        new_value_for_index,
-                    } => {
-                        // To revert an update in the index:
-                        // 1. Delete the new value that was inserted.
-                        let mut new_values_map = HashMap::new();
-                        new_values_map.insert(index_name.clone(), new_value_for_index.clone());
-                        self.index_manager
-                            .write()
-                            .map_err(|e| OxidbError::LockTimeout(format!("Failed to acquire write lock on index manager for rollback (revert index update - delete part): {}",e)))?
-                            .on_delete_data(&new_values_map, key)?;
-
-                        // 2. Re-insert the old value.
-                        let mut old_values_map = HashMap::new();
-                        old_values_map.insert(index_name.clone(), old_value_for_index.clone());
-                        self.index_manager
-                            .write()
-                            .map_err(|e| OxidbError::LockTimeout(format!("Failed to acquire write lock on index manager for rollback (revert index update - insert part): {}",e)))?
-                            .on_insert_data(&old_values_map, key)?;
-                    }
-                }
-            }
+            let ops_to_undo: Vec<UndoOperation> = active_tx.undo_log.iter().rev().cloned().collect();
+            drop(active_tx);
+
+            self.apply_undo_operations(&ops_to_undo, tx_id_to_release, &committed_ids_for_undo)?;
+
+            let active_tx = self
+                .transaction_manager
+                .get_active_transaction_mut()
+                .ok_or(OxidbError::NoActiveTransaction)?;
             active_tx.undo_log.clear();
             active_tx.redo_log.clear();
 
@@ -176,6 +218,107 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>>> QueryExecutor<S> {
         }
     }
 
+    /// Replays `ops` (already in undo order, i.e. most-recent-first) against
+    /// the store and index manager, reverting the effects of `tx_id`'s
+    /// writes. Shared by [`Self::handle_rollback_transaction`], which undoes
+    /// a transaction's entire log, and [`Self::handle_rollback_to_savepoint`],
+    /// which undoes only the suffix of the log recorded since a savepoint.
+    fn apply_undo_operations(
+        &mut self,
+        ops: &[UndoOperation],
+        tx_id: crate::core::common::types::TransactionId,
+        committed_ids_for_undo: &HashSet<u64>,
+    ) -> Result<(), OxidbError> {
+        let temp_transaction_for_undo = Transaction::new(tx_id);
+
+        for undo_op in ops {
+            match undo_op {
+                UndoOperation::RevertInsert { key } => {
+                    let lsn = self.log_manager.next_lsn();
+                    self.store
+                        .write()
+                        .map_err(|e| OxidbError::LockTimeout(format!("Failed to acquire write lock on store for rollback (revert insert): {}",e)))?
+                        .delete(
+                            key,
+                            &temp_transaction_for_undo,
+                            lsn,
+                            committed_ids_for_undo,
+                        )?;
+                }
+                UndoOperation::RevertUpdate { key, old_value: _ } => {
+                    // old_value is used for index, not directly here for store
+                    let lsn = self.log_manager.next_lsn();
+                    // This delete operation finds the version created by temp_transaction_for_undo (the transaction being rolled back)
+                    // and marks its expired_tx_id to its own transaction ID.
+                    // This correctly invalidates the version created by the transaction being rolled back.
+                    // The previously existing version (which was expired by this transaction) will become visible again
+                    // because its expirer_tx_id points to a non-committed transaction.
+                    self.store
+                        .write()
+                        .map_err(|e| OxidbError::LockTimeout(format!("Failed to acquire write lock on store for rollback (revert update): {}",e)))?
+                        .delete(
+                            key,
+                            &temp_transaction_for_undo, // The transaction being rolled back
+                            lsn,
+                            committed_ids_for_undo,
+                        )?;
+                }
+                UndoOperation::RevertDelete { key, old_value } => {
+                    let lsn = self.log_manager.next_lsn();
+                    self.store
+                        .write()
+                        .map_err(|e| OxidbError::LockTimeout(format!("Failed to acquire write lock on store for rollback (revert delete): {}",e)))?
+                        .put(
+                            key.clone(),
+                            old_value.clone(),
+                            &temp_transaction_for_undo,
+                        lsn,
+                    )?;
+                }
+                UndoOperation::IndexRevertInsert { index_name, key, value_for_index } => {
+                    let mut indexed_values_map = HashMap::new();
+                    indexed_values_map.insert(index_name.clone(), value_for_index.clone());
+                    self.index_manager
+                        .write()
+                        .map_err(|e| OxidbError::LockTimeout(format!("Failed to acquire write lock on index manager for rollback (revert index insert): {}",e)))?
+                        .on_delete_data(&indexed_values_map, key)?;
+                }
+                UndoOperation::IndexRevertDelete { index_name, key, old_value_for_index } => {
+                    let mut indexed_values_map = HashMap::new();
+                    indexed_values_map.insert(index_name.clone(), old_value_for_index.clone());
+                    self.index_manager
+                        .write()
+                        .map_err(|e| OxidbError::LockTimeout(format!("Failed to acquire write lock on index manager for rollback (revert index delete): {}",e)))?
+                        .on_insert_data(&indexed_values_map, key)?;
+                }
+                UndoOperation::IndexRevertUpdate {
+                    index_name,
+                    key,
+                    old_value_for_index,
+                    new_value_for_index,
+                } => {
+                    // To revert an update in the index:
+                    // 1. Delete the new value that was inserted.
+                    let mut new_values_map = HashMap::new();
+                    new_values_map.insert(index_name.clone(), new_value_for_index.clone());
+                    self.index_manager
+                        .write()
+                        .map_err(|e| OxidbError::LockTimeout(format!("Failed to acquire write lock on index manager for rollback (revert index update - delete part): {}",e)))?
+                        .on_delete_data(&new_values_map, key)?;
+
+                    // 2. Re-insert the old value.
+                    let mut old_values_map = HashMap::new();
+                    old_values_map.insert(index_name.clone(), old_value_for_index.clone());
+                    self.index_manager
+                        .write()
+                        .map_err(|e| OxidbError::LockTimeout(format!("Failed to acquire write lock on index manager for rollback (revert index update - insert part): {}",e)))?
+                        .on_insert_data(&old_values_map, key)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Handles the VACUUM command.
     /// Performs garbage collection on the key-value store, removing versions of data
     /// that are no longer visible to any active or future transactions, based on
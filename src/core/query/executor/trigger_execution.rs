@@ -0,0 +1,106 @@
+use super::{ExecutionResult, QueryExecutor};
+use crate::core::common::OxidbError;
+use crate::core::query::commands::{Command, TriggerEvent, TriggerTiming};
+use crate::core::storage::engine::traits::KeyValueStore;
+use crate::core::types::DataType;
+use std::collections::HashMap;
+
+/// A single row-level trigger registered via `Command::CreateTrigger`.
+#[derive(Debug, Clone)]
+pub(crate) struct TriggerDefinition {
+    pub name: String,
+    pub timing: TriggerTiming,
+    pub event: TriggerEvent,
+    pub body: Vec<Command>,
+}
+
+/// Maximum number of trigger bodies `fire_triggers` will allow nested inside
+/// one another before reporting the cascade as an error. A trigger body runs
+/// through the same `execute_command` entry point as any other statement, so
+/// a body that writes to a table with a trigger of its own (directly, or via
+/// a longer cycle) would otherwise recurse until the stack overflows.
+pub(crate) const MAX_TRIGGER_DEPTH: usize = 16;
+
+impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S> {
+    /// Handles `Command::CreateTrigger`, registering `name` as a trigger on
+    /// `table_name`'s trigger list.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::AlreadyExists` if `table_name` already has a
+    /// trigger named `name`.
+    pub(crate) fn handle_create_trigger(
+        &mut self,
+        table_name: String,
+        name: String,
+        timing: TriggerTiming,
+        event: TriggerEvent,
+        body: Vec<Command>,
+    ) -> Result<ExecutionResult, OxidbError> {
+        let triggers = self.triggers.entry(table_name).or_default();
+        if triggers.iter().any(|trigger| trigger.name == name) {
+            return Err(OxidbError::AlreadyExists { name });
+        }
+        triggers.push(TriggerDefinition { name, timing, event, body });
+        Ok(ExecutionResult::Success)
+    }
+
+    /// Runs the body of every trigger registered for `table_name` that
+    /// matches `event` and `timing`, in registration order.
+    ///
+    /// Each body command is re-entrant through `execute_command`, the same
+    /// entry point any other statement uses, so a trigger body's writes land
+    /// in whatever transaction is already active (an explicit one, or the
+    /// auto-commit transaction the originating statement is running under)
+    /// and share its undo log: they commit or abort atomically with the
+    /// statement that fired the trigger rather than as a fire-and-forget
+    /// side effect. Re-entering `execute_command` also means a body that
+    /// writes to a table with its own trigger fires that trigger in turn, so
+    /// nesting is capped at `MAX_TRIGGER_DEPTH` to turn a cascade into an
+    /// error instead of unbounded recursion.
+    ///
+    /// `old_row` is the row the DELETE/UPDATE that fired this trigger
+    /// removed or is about to overwrite; it is stashed on `self.trigger_old_row`
+    /// for the duration of the call as `OLD`. There is no SQL syntax yet for a
+    /// trigger body to reference `OLD.<column>`, so today this is only
+    /// programmatically readable - wiring it into the grammar is future work.
+    pub(crate) fn fire_triggers(
+        &mut self,
+        table_name: &str,
+        event: TriggerEvent,
+        timing: TriggerTiming,
+        old_row: Option<&HashMap<Vec<u8>, DataType>>,
+    ) -> Result<(), OxidbError> {
+        let Some(triggers) = self.triggers.get(table_name).cloned() else {
+            return Ok(());
+        };
+        let matching: Vec<_> =
+            triggers.into_iter().filter(|t| t.event == event && t.timing == timing).collect();
+        if matching.is_empty() {
+            return Ok(());
+        }
+
+        if self.trigger_depth >= MAX_TRIGGER_DEPTH {
+            return Err(OxidbError::Execution(format!(
+                "Trigger cascade on table '{table_name}' exceeded the maximum nesting depth of {MAX_TRIGGER_DEPTH}; a trigger body likely re-triggers itself or another trigger in a cycle"
+            )));
+        }
+
+        self.trigger_depth += 1;
+        let previous_old_row = self.trigger_old_row.take();
+        self.trigger_old_row = old_row.cloned();
+
+        let mut result = Ok(());
+        'triggers: for trigger in &matching {
+            for command in &trigger.body {
+                if let Err(e) = self.execute_command(command.clone()) {
+                    result = Err(e);
+                    break 'triggers;
+                }
+            }
+        }
+
+        self.trigger_old_row = previous_old_row;
+        self.trigger_depth -= 1;
+        result
+    }
+}
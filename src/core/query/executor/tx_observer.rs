@@ -0,0 +1,42 @@
+use super::QueryExecutor;
+use crate::core::common::types::{Lsn, TransactionId};
+use crate::core::storage::engine::traits::KeyValueStore;
+use crate::core::wal::{TxObserver, TxObserverId, TxReport};
+use std::collections::HashSet;
+
+impl<S: KeyValueStore<Vec<u8>, Vec<u8>>> QueryExecutor<S> {
+    /// Registers `observer` to run for every transaction commit, returning an
+    /// id that can later be passed to [`Self::deregister_tx_observer`].
+    pub fn register_tx_observer(&mut self, observer: Box<dyn TxObserver>) -> TxObserverId {
+        self.tx_observers.register(observer)
+    }
+
+    /// Removes a previously registered transaction observer. Returns `false`
+    /// if `id` isn't currently registered.
+    pub fn deregister_tx_observer(&mut self, id: TxObserverId) -> bool {
+        self.tx_observers.deregister(id)
+    }
+
+    /// Builds a [`TxReport`] for `tx_id`'s commit at `commit_lsn` and
+    /// delivers it to every registered `TxObserver`.
+    ///
+    /// This executor's transaction manager only ever writes
+    /// `BeginTransaction`/`CommitTransaction`/`AbortTransaction` records -
+    /// its transactions track writes as a key-level undo log, not the
+    /// page-level `Insert`/`Delete`/`Update`/`NewPage` records the ARIES
+    /// recovery layer uses - so `pages` and `tables` are always empty here.
+    /// A store that logs page-level WAL records can build a fuller report
+    /// with [`TxReport::from_records`] instead.
+    pub(crate) fn notify_tx_observers(&mut self, tx_id: TransactionId, commit_lsn: Lsn) {
+        if self.tx_observers.is_empty() {
+            return;
+        }
+        let report = TxReport {
+            tx_id,
+            commit_lsn,
+            pages: HashSet::new(),
+            tables: HashSet::new(),
+        };
+        self.tx_observers.notify(&report);
+    }
+}
@@ -72,6 +72,7 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S
             };
 
         let select_ast = crate::core::query::sql::ast::Statement::Select(crate::core::query::sql::ast::SelectStatement {
+            distinct: false,
             columns: vec![crate::core::query::sql::ast::SelectColumn::Asterisk],
             from_clause: crate::core::query::sql::ast::TableReference {
                 name: source_table_name.clone(),
@@ -205,6 +206,24 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S
                         );
                     }
 
+                    // Enum-type validation: for each assigned column backed by a
+                    // registered enum type, reject values outside its variant set,
+                    // coercing a plain String (the only form a SQL-text value can
+                    // take) into the matching Enum value.
+                    for col_def in &schema.columns {
+                        if assignments_cmd.iter().any(|a| a.column == col_def.name) {
+                            if let Some(value) =
+                                temp_updated_map_data.get(col_def.name.as_bytes()).cloned()
+                            {
+                                let validated = super::enum_execution::coerce_and_validate_enum_value(
+                                    self, col_def, value,
+                                )?;
+                                temp_updated_map_data
+                                    .insert(col_def.name.as_bytes().to_vec(), validated);
+                            }
+                        }
+                    }
+
                     // Constraint Checks using temp_updated_map_data
                     for col_def in &schema.columns {
                         // Check only if this column is part of the current assignments
@@ -240,14 +259,37 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S
                             }
                         }
                     }
+                    // Row-validation rules (`Command::AddValidationRule`)
+                    self.check_validation_rules(&source_table_name, &temp_updated_map_data)?;
+
+                    // Clone the pre-update row now so both the BEFORE trigger
+                    // (as OLD) and the per-column index updates below can see
+                    // the values as they were before this assignment.
+                    let original_map_data_for_indexes = map_data.clone();
+
+                    // BEFORE UPDATE triggers, once the candidate row has
+                    // passed validation but before any index/store changes.
+                    self.fire_triggers(
+                        &source_table_name,
+                        crate::core::query::commands::TriggerEvent::Update,
+                        crate::core::query::commands::TriggerTiming::Before,
+                        Some(&original_map_data_for_indexes),
+                    )?;
+
                     // If all checks passed, apply to actual map_data
                     // *map_data = temp_updated_map_data; // Deferred until after per-column index updates
 
                     // --- Start: Per-column index updates for UPDATE ---
-                    let original_map_data_for_indexes = map_data.clone(); // Clone original map_data for fetching old values
 
                     for col_def in &schema.columns {
-                        if col_def.is_primary_key || col_def.is_unique {
+                        let index_name = format!("idx_{}_{}", source_table_name, col_def.name);
+                        let index_exists = self
+                            .index_manager
+                            .read()
+                            .map_err(|e| OxidbError::LockTimeout(format!("Failed to acquire read lock on index manager for update: {e}")))?
+                            .get_index(&index_name)
+                            .is_some();
+                        if col_def.is_primary_key || col_def.is_unique || index_exists {
                             let old_value_for_column = original_map_data_for_indexes
                                 .get(col_def.name.as_bytes())
                                 .cloned()
@@ -266,9 +308,6 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S
                             if old_value_for_column != new_value_for_column
                                 || old_value_needs_indexing != new_value_needs_indexing
                             {
-                                let index_name =
-                                    format!("idx_{}_{}", source_table_name, col_def.name);
-
                                 // Delete old value from index if it needed indexing
                                 if old_value_needs_indexing {
                                     let old_serialized_column_value =
@@ -446,6 +485,14 @@ impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S
                         &tx_for_store,
                         new_lsn, // Pass the new LSN
                     )?;
+
+                // AFTER UPDATE triggers, once the row is durably stored.
+                self.fire_triggers(
+                    &source_table_name,
+                    crate::core::query::commands::TriggerEvent::Update,
+                    crate::core::query::commands::TriggerTiming::After,
+                    Some(&original_map_data_for_indexes),
+                )?;
                 updated_count += 1;
             }
             // Auto-commit logic is now handled by QueryExecutor::execute_command
@@ -57,6 +57,60 @@ pub fn compare_data_types(
                     ">=" => Ok(s1 >= s2),
                     _ => unreachable!(),
                 },
+                (
+                    DataType::Enum { value: v1, .. },
+                    DataType::Enum { value: v2, .. },
+                ) => match operator {
+                    "<" => Ok(v1 < v2),
+                    "<=" => Ok(v1 <= v2),
+                    ">" => Ok(v1 > v2),
+                    ">=" => Ok(v1 >= v2),
+                    _ => unreachable!(),
+                },
+                (
+                    DataType::Decimal { unscaled: u1, scale: s1, .. },
+                    DataType::Decimal { unscaled: u2, scale: s2, .. },
+                ) => {
+                    // Rescale both to the larger scale and compare the i128s exactly.
+                    let target = (*s1).max(*s2);
+                    let rescale = |u: i128, s: u32| {
+                        if u == 0 { Some(0) } else { 10i128.checked_pow(target - s).and_then(|m| u.checked_mul(m)) }
+                    };
+                    let ordering = match (rescale(*u1, *s1), rescale(*u2, *s2)) {
+                        (Some(a), Some(b)) => a.cmp(&b),
+                        // Only the smaller-scale side can overflow; its magnitude then
+                        // exceeds the other side's, so its sign decides the order.
+                        (None, _) => if *u1 < 0 { std::cmp::Ordering::Less } else { std::cmp::Ordering::Greater },
+                        (_, None) => if *u2 < 0 { std::cmp::Ordering::Greater } else { std::cmp::Ordering::Less },
+                    };
+                    match operator {
+                        "<" => Ok(ordering.is_lt()),
+                        "<=" => Ok(ordering.is_le()),
+                        ">" => Ok(ordering.is_gt()),
+                        ">=" => Ok(ordering.is_ge()),
+                        _ => unreachable!(),
+                    }
+                }
+                (DataType::Decimal { unscaled, scale, .. }, DataType::Integer(i2)) => {
+                    let f1 = crate::core::types::decimal::decimal_to_f64(*unscaled, *scale);
+                    #[allow(clippy::cast_precision_loss)]
+                    let f2 = *i2 as f64;
+                    compare_ordered_f64(f1, f2, operator)
+                }
+                (DataType::Integer(i1), DataType::Decimal { unscaled, scale, .. }) => {
+                    #[allow(clippy::cast_precision_loss)]
+                    let f1 = *i1 as f64;
+                    let f2 = crate::core::types::decimal::decimal_to_f64(*unscaled, *scale);
+                    compare_ordered_f64(f1, f2, operator)
+                }
+                (DataType::Decimal { unscaled, scale, .. }, DataType::Float(f2)) => {
+                    let f1 = crate::core::types::decimal::decimal_to_f64(*unscaled, *scale);
+                    compare_ordered_f64(f1, f2.0, operator)
+                }
+                (DataType::Float(f1), DataType::Decimal { unscaled, scale, .. }) => {
+                    let f2 = crate::core::types::decimal::decimal_to_f64(*unscaled, *scale);
+                    compare_ordered_f64(f1.0, f2, operator)
+                }
                 (DataType::Null, _) | (_, DataType::Null) => Err(OxidbError::SqlParsing(format!( // Changed
                     "Ordered comparison ('{operator}') with NULL is not supported directly. Use IS NULL or IS NOT NULL."
                 ))),
@@ -69,6 +123,18 @@ pub fn compare_data_types(
     }
 }
 
+/// Shared ordered-comparison body for the mixed `Decimal`/`Integer`/`Float`
+/// pairs above, which all reduce to comparing two `f64`s.
+fn compare_ordered_f64(f1: f64, f2: f64, operator: &str) -> Result<bool, OxidbError> {
+    match operator {
+        "<" => Ok(f1 < f2),
+        "<=" => Ok(f1 <= f2),
+        ">" => Ok(f1 > f2),
+        ">=" => Ok(f1 >= f2),
+        _ => unreachable!(),
+    }
+}
+
 // New helper function as planned
 pub fn datatype_to_ast_literal(data_type: &DataType) -> Result<AstLiteralValue, OxidbError> {
     // Changed
@@ -77,6 +143,9 @@ pub fn datatype_to_ast_literal(data_type: &DataType) -> Result<AstLiteralValue,
         DataType::String(s) => Ok(AstLiteralValue::String(s.clone())),
         DataType::Boolean(b) => Ok(AstLiteralValue::Boolean(*b)),
         DataType::Float(f) => Ok(AstLiteralValue::Number(f.to_string())), // Consider precision if needed
+        DataType::Decimal { unscaled, scale, .. } => Ok(AstLiteralValue::Number(
+            crate::core::types::decimal::format_decimal(*unscaled, *scale),
+        )),
         DataType::Null => Ok(AstLiteralValue::Null),
         DataType::Map(_) => Err(OxidbError::NotImplemented{feature: // Changed
             "Cannot convert Map DataType to AstLiteralValue for SQL conditions".to_string(),
@@ -100,5 +169,6 @@ pub fn datatype_to_ast_literal(data_type: &DataType) -> Result<AstLiteralValue,
             );
             Ok(AstLiteralValue::String(vec_str))
         }
+        DataType::Enum { value, .. } => Ok(AstLiteralValue::String(value.clone())),
     }
 }
@@ -0,0 +1,78 @@
+use super::{ExecutionResult, QueryExecutor};
+use crate::core::common::OxidbError;
+use crate::core::query::commands::{Severity, SqlConditionTree};
+use crate::core::storage::engine::traits::KeyValueStore;
+use crate::core::types::DataType;
+use std::collections::HashMap;
+
+/// A single rule registered via `Command::AddValidationRule`.
+#[derive(Debug, Clone)]
+pub(crate) struct ValidationRule {
+    pub name: String,
+    pub when: Option<SqlConditionTree>,
+    pub then: SqlConditionTree,
+    pub severity: Severity,
+}
+
+impl<S: KeyValueStore<Vec<u8>, Vec<u8>> + Send + Sync + 'static> QueryExecutor<S> {
+    /// Handles `Command::AddValidationRule`, registering `name` as a rule on
+    /// `table_name`'s validation-rule list.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::AlreadyExists` if `table_name` already has a rule
+    /// named `name`.
+    pub(crate) fn handle_add_validation_rule(
+        &mut self,
+        table_name: String,
+        name: String,
+        when: Option<SqlConditionTree>,
+        then: SqlConditionTree,
+        severity: Severity,
+    ) -> Result<ExecutionResult, OxidbError> {
+        let rules = self.validation_rules.entry(table_name).or_default();
+        if rules.iter().any(|rule| rule.name == name) {
+            return Err(OxidbError::AlreadyExists { name });
+        }
+        rules.push(ValidationRule { name, when, then, severity });
+        Ok(ExecutionResult::Success)
+    }
+
+    /// Checks `row` against every validation rule registered for
+    /// `table_name`, skipping rules whose `when` doesn't match. A violated
+    /// `Severity::Error` rule fails the write with
+    /// `OxidbError::ConstraintViolation`; a violated `Severity::Warning` rule
+    /// is only logged, and the write proceeds.
+    pub(crate) fn check_validation_rules(
+        &mut self,
+        table_name: &str,
+        row: &HashMap<Vec<u8>, DataType>,
+    ) -> Result<(), OxidbError> {
+        let Some(rules) = self.validation_rules.get(table_name).cloned() else {
+            return Ok(());
+        };
+        for rule in &rules {
+            if let Some(when) = &rule.when {
+                if !self.evaluate_condition_tree(when, row)? {
+                    continue;
+                }
+            }
+            if !self.evaluate_condition_tree(&rule.then, row)? {
+                match rule.severity {
+                    Severity::Error => {
+                        return Err(OxidbError::ConstraintViolation(format!(
+                            "Validation rule '{}' failed for table '{table_name}'.",
+                            rule.name
+                        )))
+                    }
+                    Severity::Warning => {
+                        eprintln!(
+                            "[Executor::check_validation_rules] Warning: rule '{}' failed for table '{table_name}'.",
+                            rule.name
+                        );
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
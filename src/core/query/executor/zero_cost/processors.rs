@@ -2,7 +2,9 @@
 //!
 //! Each processor handles a specific type of query operation.
 
-use super::{QueryProcessor, QueryResult};
+use std::collections::HashSet;
+
+use super::{QueryMetadata, QueryProcessor, QueryResult, Row};
 use crate::core::common::OxidbError;
 use crate::core::types::DataType;
 
@@ -20,4 +22,141 @@ impl QueryProcessor for SelectProcessor {
         // TODO: Implement SELECT processing
         todo!("Implement SELECT processing")
     }
+}
+
+/// Evaluates a recursive rule (`WITH RECURSIVE`-style relations, graph reachability,
+/// hierarchy walks) to a fixpoint using semi-naive Datalog evaluation: each epoch joins
+/// only the *delta* produced by the previous epoch against the rule body, instead of
+/// rejoining the whole accumulated result, so work per epoch is proportional to what's
+/// actually new.
+///
+/// `rule` takes the current delta (rows newly derived in the previous epoch) and returns
+/// the candidate rows it derives from them; [`RecursiveProcessor::evaluate`] dedupes those
+/// candidates against everything accumulated so far and the survivors become the next
+/// delta. Evaluation terminates once an epoch's delta is empty.
+pub struct RecursiveProcessor<F>
+where
+    F: Fn(&[Vec<DataType>]) -> Result<Vec<Vec<DataType>>, OxidbError>,
+{
+    rule: F,
+    /// Upper bound on epochs, guarding against a rule that never reaches a fixpoint.
+    max_epochs: usize,
+}
+
+impl<F> RecursiveProcessor<F>
+where
+    F: Fn(&[Vec<DataType>]) -> Result<Vec<Vec<DataType>>, OxidbError>,
+{
+    #[must_use]
+    pub const fn new(rule: F, max_epochs: usize) -> Self {
+        Self { rule, max_epochs }
+    }
+
+    /// Runs the semi-naive fixpoint starting from `base_rows` and returns every row
+    /// accumulated (the base relation plus every row derived across all epochs).
+    ///
+    /// # Errors
+    /// Propagates any `OxidbError` raised by `rule`, and returns
+    /// `OxidbError::Other` if `max_epochs` is exhausted without reaching a fixpoint.
+    pub fn evaluate<'a>(
+        &self,
+        base_rows: Vec<Vec<DataType>>,
+    ) -> Result<QueryResult<'a>, OxidbError> {
+        let mut seen: HashSet<Vec<DataType>> = base_rows.iter().cloned().collect();
+        let mut accumulated: Vec<Vec<DataType>> = base_rows.clone();
+        let mut delta = base_rows;
+
+        let mut epoch = 0;
+        while !delta.is_empty() {
+            if epoch >= self.max_epochs {
+                return Err(OxidbError::Other(format!(
+                    "recursive query did not reach a fixpoint within {} epochs",
+                    self.max_epochs
+                )));
+            }
+
+            let candidates = (self.rule)(&delta)?;
+            let next_delta: Vec<Vec<DataType>> =
+                candidates.into_iter().filter(|row| seen.insert(row.clone())).collect();
+
+            accumulated.extend(next_delta.iter().cloned());
+            delta = next_delta;
+            epoch += 1;
+        }
+
+        let rows_affected = accumulated.len();
+        let rows = Box::new(accumulated.into_iter().map(Row::from_owned));
+        Ok(QueryResult {
+            columns: std::borrow::Cow::Owned(Vec::new()),
+            rows,
+            metadata: QueryMetadata { rows_affected, ..QueryMetadata::default() },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Graph reachability over edges 1->2, 2->3, 3->4: starting from base row [1], the rule
+    /// follows one edge hop per epoch, so the fixpoint should contain 1, 2, 3, 4.
+    #[test]
+    fn test_recursive_processor_computes_transitive_closure() {
+        let edges = [(1i64, 2i64), (2, 3), (3, 4)];
+
+        let processor = RecursiveProcessor::new(
+            |delta: &[Vec<DataType>]| {
+                let mut derived = Vec::new();
+                for row in delta {
+                    if let Some(DataType::Integer(node)) = row.first() {
+                        for &(from, to) in &edges {
+                            if from == *node {
+                                derived.push(vec![DataType::Integer(to)]);
+                            }
+                        }
+                    }
+                }
+                Ok(derived)
+            },
+            10,
+        );
+
+        let result = processor.evaluate(vec![vec![DataType::Integer(1)]]).unwrap();
+        let mut reached: Vec<i64> = result
+            .rows
+            .map(|row| match row.get(0) {
+                Some(DataType::Integer(n)) => *n,
+                _ => panic!("expected integer row"),
+            })
+            .collect();
+        reached.sort_unstable();
+
+        assert_eq!(reached, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_recursive_processor_terminates_on_empty_delta() {
+        let processor = RecursiveProcessor::new(|_delta: &[Vec<DataType>]| Ok(Vec::new()), 10);
+        let result = processor.evaluate(vec![vec![DataType::Integer(1)]]).unwrap();
+        assert_eq!(result.metadata.rows_affected, 1);
+    }
+
+    #[test]
+    fn test_recursive_processor_errors_past_max_epochs() {
+        // A rule that always derives a fresh, never-before-seen row never reaches a
+        // fixpoint, so this should hit the epoch guard instead of looping forever.
+        let processor = RecursiveProcessor::new(
+            |delta: &[Vec<DataType>]| {
+                let next = match delta.first().and_then(|row| row.first()) {
+                    Some(DataType::Integer(n)) => *n + 1,
+                    _ => 0,
+                };
+                Ok(vec![vec![DataType::Integer(next)]])
+            },
+            5,
+        );
+
+        let err = processor.evaluate(vec![vec![DataType::Integer(0)]]).unwrap_err();
+        assert!(matches!(err, OxidbError::Other(_)));
+    }
 }
\ No newline at end of file
@@ -81,7 +81,7 @@ mod tests {
     fn test_parse_sql_update() {
         let result = parse_query("UPDATE users SET name = 'John', age = 30 WHERE id = 1");
         match result {
-            Ok(Command::Update { source, assignments, condition }) => {
+            Ok(Command::Update { source, assignments, condition, .. }) => {
                 assert_eq!(source, "users");
                 assert_eq!(assignments.len(), 2);
                 assert_eq!(assignments[0].column, "name");
@@ -96,7 +96,7 @@ mod tests {
     fn test_parse_sql_insert() {
         let result = parse_query("INSERT INTO users (name, age) VALUES ('Alice', 25)");
         match result {
-            Ok(Command::SqlInsert { table_name, columns, values }) => {
+            Ok(Command::SqlInsert { table_name, columns, values, .. }) => {
                 assert_eq!(table_name, "users");
                 assert_eq!(columns, Some(vec!["name".to_string(), "age".to_string()]));
                 assert_eq!(values.len(), 1);
@@ -109,7 +109,7 @@ mod tests {
     fn test_parse_sql_delete() {
         let result = parse_query("DELETE FROM users WHERE id = 1");
         match result {
-            Ok(Command::SqlDelete { table_name, condition }) => {
+            Ok(Command::SqlDelete { table_name, condition, .. }) => {
                 assert_eq!(table_name, "users");
                 assert!(condition.is_some());
             }
@@ -5,6 +5,10 @@ pub enum AstLiteralValue {
     Boolean(bool),
     Null,                         // Added Null for completeness
     Vector(Vec<AstLiteralValue>), // Represents a list of literals, e.g., [1.0, 2.0, 3.0]
+    /// `ZEROBLOB(n)`: a pre-sized, zero-filled `BLOB` of `n` bytes, so a row can be
+    /// inserted with a fixed-size placeholder and its bytes streamed in afterward
+    /// through `Connection::blob_open`.
+    ZeroBlob(u32),
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -12,7 +16,23 @@ pub enum AstExpressionValue {
     // ADDED
     Literal(AstLiteralValue),
     ColumnIdentifier(String),
-    Parameter(u32), // Parameter placeholder with index (0-based)
+    Parameter(AstParameter), // Parameter placeholder
+}
+
+/// A parameter placeholder, in one of the forms the tokenizer recognizes.
+///
+/// `Positional` placeholders (`?`) are numbered by occurrence order at parse
+/// time, left-to-right. `Numbered` (`?1`, `$1`) and `Named` (`:name`)
+/// placeholders carry an explicit index/name instead, so the same one can be
+/// referenced more than once in a statement while binding a single value.
+#[derive(Debug, PartialEq, Clone)]
+pub enum AstParameter {
+    /// A bare `?`, numbered by left-to-right occurrence (0-based).
+    Positional(u32),
+    /// An explicitly numbered `?N` or `$N` placeholder (1-based, as written).
+    Numbered(u32),
+    /// A named `:name` placeholder.
+    Named(String),
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -38,18 +58,38 @@ pub struct Assignment {
     pub value: AstExpressionValue,
 }
 
+/// An aggregate function applied to a `SELECT` column, e.g. `COUNT(*)` or
+/// `SUM(amount)`. Mirrors the `COUNT`/`SUM`/`AVG`/`MIN`/`MAX` set a
+/// `CREATE AGGREGATE INDEX` can also be defined over (see
+/// [`crate::core::indexing::aggregate::AggregateIndex`]), minus `AVG`, which
+/// isn't additively maintainable from partial state alone.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum AggregateFunction {
+    Count,
+    Sum,
+    Avg,
+    Min,
+    Max,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum SelectColumn {
     ColumnName(String),
     Asterisk, // For SELECT *
+    /// `function(column)` or `function(*)` (`column` is `Asterisk` for `COUNT(*)`).
+    AggregateFunction { function: AggregateFunction, column: Box<SelectColumn>, alias: Option<String> },
 }
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct SelectStatement {
+    pub distinct: bool,
     pub columns: Vec<SelectColumn>,
     pub from_clause: TableReference,
     pub joins: Vec<JoinClause>,
     pub condition: Option<ConditionTree>,
+    /// `GROUP BY expr, ...`: only plain column references are supported today.
+    pub group_by: Option<Vec<AstExpressionValue>>,
+    pub having: Option<ConditionTree>,
     pub order_by: Option<Vec<OrderByExpr>>,
     pub limit: Option<AstLiteralValue>,
 }
@@ -93,6 +133,7 @@ pub struct UpdateStatement {
     pub source: String,
     pub assignments: Vec<Assignment>,
     pub condition: Option<ConditionTree>,
+    pub returning: Option<Vec<SelectColumn>>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -101,6 +142,9 @@ pub enum AstColumnConstraint {
     Unique,
     PrimaryKey,
     AutoIncrement,
+    /// Only meaningful on `VARCHAR(n)`/`CHAR(n)` columns: silently truncate an
+    /// over-length value to `n` characters instead of rejecting it.
+    Truncate,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -111,6 +155,15 @@ pub enum AstDataType {
     Float,
     Vector { dimension: u32 },
     Blob,
+    /// `NUMERIC(precision, scale)` / `DECIMAL(precision, scale)`: an exact
+    /// fixed-point type, stored as an integer scaled by `10^scale`.
+    Decimal { precision: u32, scale: u32 },
+    /// `VARCHAR(n)` (`fixed: false`) or `CHAR(n)` (`fixed: true`, right-padded
+    /// to `n` on read). `length` is `None` when no `(n)` was given.
+    Varchar { length: Option<u32>, fixed: bool },
+    /// A reference to a user-defined `CREATE TYPE ... AS ENUM (...)` type by
+    /// name, used as a column's declared type in `CREATE TABLE`.
+    Enum { type_name: String },
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -131,12 +184,65 @@ pub struct InsertStatement {
     pub table_name: String,
     pub columns: Option<Vec<String>>,
     pub values: Vec<Vec<AstExpressionValue>>,
+    pub on_conflict: Option<OnConflictClause>,
+    pub returning: Option<Vec<SelectColumn>>,
+}
+
+/// The `ON CONFLICT (target_columns) DO ...` clause of an `INSERT`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct OnConflictClause {
+    pub target_columns: Vec<String>,
+    pub action: ConflictAction,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ConflictAction {
+    DoNothing,
+    DoUpdate(Vec<Assignment>),
 }
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct DeleteStatement {
     pub table_name: String,
     pub condition: Option<ConditionTree>,
+    pub returning: Option<Vec<SelectColumn>>,
+}
+
+/// `PUT table (cols) VALUES (...)`: upsert-by-primary-key. Unlike `INSERT`, a
+/// primary key that already exists is overwritten in place instead of raising
+/// a uniqueness violation.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PutStatement {
+    pub table_name: String,
+    pub columns: Option<Vec<String>>,
+    pub values: Vec<Vec<AstExpressionValue>>,
+}
+
+/// `RM table WHERE ...`: idempotent delete. Functionally identical to
+/// `DELETE`, but the name signals that matching zero rows is not an error.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RmStatement {
+    pub table_name: String,
+    pub condition: Option<ConditionTree>,
+}
+
+/// `ENSURE table (cols) VALUES (...)`: asserts that a row with these exact
+/// values exists, inserting it if absent. Succeeds as a no-op if an
+/// identical row is already present, and fails if a conflicting row (same
+/// primary key, different values) exists.
+#[derive(Debug, PartialEq, Clone)]
+pub struct EnsureStatement {
+    pub table_name: String,
+    pub columns: Option<Vec<String>>,
+    pub values: Vec<Vec<AstExpressionValue>>,
+}
+
+/// `ENSURE NOT table WHERE ...`: asserts that no row matches the condition,
+/// failing if one does. Succeeds as a no-op when nothing matches.
+#[derive(Debug, PartialEq, Clone)]
+pub struct EnsureNotStatement {
+    pub table_name: String,
+    pub condition: Option<ConditionTree>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -147,6 +253,20 @@ pub enum Statement {
     Insert(InsertStatement),
     Delete(DeleteStatement),
     DropTable(DropTableStatement),
+    Put(PutStatement),
+    Rm(RmStatement),
+    Ensure(EnsureStatement),
+    EnsureNot(EnsureNotStatement),
+    Explain(ExplainStatement),
+}
+
+/// `EXPLAIN [ANALYZE] <statement>` - `analyze` is set when the wrapped
+/// statement should actually be executed so the plan can be annotated with
+/// measured counters, rather than estimates only.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ExplainStatement {
+    pub analyze: bool,
+    pub statement: Box<Statement>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
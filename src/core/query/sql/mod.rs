@@ -1,6 +1,7 @@
 pub mod ast;
 pub mod errors;
 pub mod parser;
+pub mod sltest; // sqllogictest (.slt) test runner
 pub mod tokenizer;
 pub mod translator; // Make translator public // Made public
 
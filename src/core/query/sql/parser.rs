@@ -1,5 +1,7 @@
 use super::ast::{
-    Assignment, AstLiteralValue, Condition, SelectColumn, SelectStatement, Statement, UpdateStatement,
+    Assignment, AstExpressionValue, AstLiteralValue, Condition, EnsureNotStatement,
+    EnsureStatement, PutStatement, RmStatement, SelectColumn, SelectStatement, Statement,
+    UpdateStatement,
 };
 use super::errors::{SqlParseError, SqlTokenizerError};
 use super::tokenizer::Token;
@@ -22,6 +24,9 @@ impl SqlParser {
         let statement = match self.peek() {
             Some(Token::Select) => self.parse_select_statement(),
             Some(Token::Update) => self.parse_update_statement(),
+            Some(Token::Put) => self.parse_put_statement(),
+            Some(Token::Rm) => self.parse_rm_statement(),
+            Some(Token::Ensure) => self.parse_ensure_statement(),
             Some(other_token) => return Err(SqlParseError::UnknownStatementType(self.current_token_pos())),
             None => return Err(SqlParseError::UnexpectedEOF),
         }?; // Propagate errors from statement parsing
@@ -94,6 +99,122 @@ impl SqlParser {
         }))
     }
 
+    fn parse_put_statement(&mut self) -> Result<Statement, SqlParseError> {
+        self.consume(Token::Put)?; // Consume PUT
+        let table_name = self.expect_identifier("Expected table name after PUT")?;
+        let columns = self.parse_optional_column_list()?;
+        self.consume(Token::Values)?;
+        let values = self.parse_values_lists()?;
+
+        // Optionally consume semicolon
+        if self.peek() == Some(&Token::Semicolon) {
+            self.consume(Token::Semicolon)?;
+        }
+        // DO NOT check for !is_at_end() here. That's the job of the main `parse` method.
+
+        Ok(Statement::Put(PutStatement { table_name, columns, values }))
+    }
+
+    fn parse_rm_statement(&mut self) -> Result<Statement, SqlParseError> {
+        self.consume(Token::Rm)?; // Consume RM
+        let table_name = self.expect_identifier("Expected table name after RM")?;
+
+        let condition = if self.match_token(Token::Where) {
+            self.consume(Token::Where)?;
+            Some(self.parse_condition()?)
+        } else {
+            None
+        };
+
+        // Optionally consume semicolon
+        if self.peek() == Some(&Token::Semicolon) {
+            self.consume(Token::Semicolon)?;
+        }
+        // DO NOT check for !is_at_end() here. That's the job of the main `parse` method.
+
+        Ok(Statement::Rm(RmStatement { table_name, condition }))
+    }
+
+    fn parse_ensure_statement(&mut self) -> Result<Statement, SqlParseError> {
+        self.consume(Token::Ensure)?; // Consume ENSURE
+
+        // ENSURE NOT table WHERE ... asserts absence; ENSURE table (cols) VALUES (...) asserts presence.
+        if self.match_token(Token::Not) {
+            self.consume(Token::Not)?;
+            let table_name = self.expect_identifier("Expected table name after ENSURE NOT")?;
+
+            let condition = if self.match_token(Token::Where) {
+                self.consume(Token::Where)?;
+                Some(self.parse_condition()?)
+            } else {
+                None
+            };
+
+            if self.peek() == Some(&Token::Semicolon) {
+                self.consume(Token::Semicolon)?;
+            }
+
+            return Ok(Statement::EnsureNot(EnsureNotStatement { table_name, condition }));
+        }
+
+        let table_name = self.expect_identifier("Expected table name after ENSURE")?;
+        let columns = self.parse_optional_column_list()?;
+        self.consume(Token::Values)?;
+        let values = self.parse_values_lists()?;
+
+        if self.peek() == Some(&Token::Semicolon) {
+            self.consume(Token::Semicolon)?;
+        }
+
+        Ok(Statement::Ensure(EnsureStatement { table_name, columns, values }))
+    }
+
+    /// Parses an optional `(col1, col2, ...)` column list, as used by PUT/ENSURE
+    /// before the VALUES clause. Returns `None` if no parenthesized list is present.
+    fn parse_optional_column_list(&mut self) -> Result<Option<Vec<String>>, SqlParseError> {
+        if !self.match_token(Token::LParen) {
+            return Ok(None);
+        }
+        self.consume(Token::LParen)?;
+        let mut columns = Vec::new();
+        loop {
+            columns.push(self.expect_identifier("Expected column name in column list")?);
+            if !self.match_token(Token::Comma) {
+                break;
+            }
+            self.consume(Token::Comma)?;
+        }
+        self.consume(Token::RParen)?;
+        Ok(Some(columns))
+    }
+
+    /// Parses one or more comma-separated `(val1, val2, ...)` value rows, as used
+    /// by PUT/ENSURE's VALUES clause.
+    fn parse_values_lists(&mut self) -> Result<Vec<Vec<AstExpressionValue>>, SqlParseError> {
+        let mut rows = Vec::new();
+        loop {
+            self.consume(Token::LParen)?;
+            let mut row = Vec::new();
+            loop {
+                row.push(AstExpressionValue::Literal(
+                    self.parse_literal_value("Expected value in VALUES list")?,
+                ));
+                if !self.match_token(Token::Comma) {
+                    break;
+                }
+                self.consume(Token::Comma)?;
+            }
+            self.consume(Token::RParen)?;
+            rows.push(row);
+
+            if !self.match_token(Token::Comma) {
+                break;
+            }
+            self.consume(Token::Comma)?;
+        }
+        Ok(rows)
+    }
+
     fn parse_select_column_list(&mut self) -> Result<Vec<SelectColumn>, SqlParseError> {
         let mut columns = Vec::new();
         if self.match_token(Token::Asterisk) {
@@ -851,4 +972,101 @@ mod tests {
             _ => panic!("Expected SelectStatement"),
         }
     }
+
+    #[test]
+    fn test_parse_put_simple() {
+        let tokens = tokenize_str("PUT users (id, name) VALUES (1, 'Alice');");
+        let mut parser = SqlParser::new(tokens);
+        let ast = parser.parse().unwrap();
+        match ast {
+            Statement::Put(put_stmt) => {
+                assert_eq!(put_stmt.table_name, "users");
+                assert_eq!(put_stmt.columns, Some(vec!["id".to_string(), "name".to_string()]));
+                assert_eq!(put_stmt.values.len(), 1);
+                assert_eq!(
+                    put_stmt.values[0],
+                    vec![
+                        AstExpressionValue::Literal(AstLiteralValue::Number("1".to_string())),
+                        AstExpressionValue::Literal(AstLiteralValue::String("Alice".to_string())),
+                    ]
+                );
+            }
+            _ => panic!("Expected PutStatement"),
+        }
+    }
+
+    #[test]
+    fn test_parse_put_multiple_rows_no_columns() {
+        let tokens = tokenize_str("PUT users VALUES (1, 'Alice'), (2, 'Bob');");
+        let mut parser = SqlParser::new(tokens);
+        let ast = parser.parse().unwrap();
+        match ast {
+            Statement::Put(put_stmt) => {
+                assert_eq!(put_stmt.table_name, "users");
+                assert!(put_stmt.columns.is_none());
+                assert_eq!(put_stmt.values.len(), 2);
+            }
+            _ => panic!("Expected PutStatement"),
+        }
+    }
+
+    #[test]
+    fn test_parse_rm_with_where() {
+        let tokens = tokenize_str("RM users WHERE id = 1;");
+        let mut parser = SqlParser::new(tokens);
+        let ast = parser.parse().unwrap();
+        match ast {
+            Statement::Rm(rm_stmt) => {
+                assert_eq!(rm_stmt.table_name, "users");
+                let cond = rm_stmt.condition.unwrap();
+                assert_eq!(cond.column, "id");
+                assert_eq!(cond.operator, "=");
+            }
+            _ => panic!("Expected RmStatement"),
+        }
+    }
+
+    #[test]
+    fn test_parse_rm_without_where() {
+        let tokens = tokenize_str("RM users;");
+        let mut parser = SqlParser::new(tokens);
+        let ast = parser.parse().unwrap();
+        match ast {
+            Statement::Rm(rm_stmt) => {
+                assert_eq!(rm_stmt.table_name, "users");
+                assert!(rm_stmt.condition.is_none());
+            }
+            _ => panic!("Expected RmStatement"),
+        }
+    }
+
+    #[test]
+    fn test_parse_ensure_simple() {
+        let tokens = tokenize_str("ENSURE users (id, name) VALUES (1, 'Alice');");
+        let mut parser = SqlParser::new(tokens);
+        let ast = parser.parse().unwrap();
+        match ast {
+            Statement::Ensure(ensure_stmt) => {
+                assert_eq!(ensure_stmt.table_name, "users");
+                assert_eq!(ensure_stmt.columns, Some(vec!["id".to_string(), "name".to_string()]));
+                assert_eq!(ensure_stmt.values.len(), 1);
+            }
+            _ => panic!("Expected EnsureStatement"),
+        }
+    }
+
+    #[test]
+    fn test_parse_ensure_not() {
+        let tokens = tokenize_str("ENSURE NOT users WHERE id = 1;");
+        let mut parser = SqlParser::new(tokens);
+        let ast = parser.parse().unwrap();
+        match ast {
+            Statement::EnsureNot(ensure_not_stmt) => {
+                assert_eq!(ensure_not_stmt.table_name, "users");
+                let cond = ensure_not_stmt.condition.unwrap();
+                assert_eq!(cond.column, "id");
+            }
+            _ => panic!("Expected EnsureNotStatement"),
+        }
+    }
 }
@@ -22,6 +22,11 @@ impl SqlParser {
         self.tokens.get(self.current)
     }
 
+    // Peek `offset` tokens past the current one (0 == `peek()`).
+    pub(super) fn peek_nth(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.current + offset)
+    }
+
     // Helper to check if the current token is an Identifier with a specific string value (case-insensitive)
     pub(super) fn peek_is_identifier_str(&self, expected_str: &str) -> bool {
         match self.peek() {
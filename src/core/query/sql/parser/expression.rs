@@ -1,7 +1,7 @@
 use super::core::SqlParser;
 use crate::core::query::sql::ast::{
-    AggregateFunction, Assignment, AstLiteralValue, AstExpressionValue, Condition, ConditionTree, 
-    SelectColumn,
+    AggregateFunction, Assignment, AstLiteralValue, AstExpressionValue, AstParameter, Condition,
+    ConditionTree, SelectColumn,
 };
 use crate::core::query::sql::errors::SqlParseError;
 use crate::core::query::sql::tokenizer::Token; // For matching specific tokens
@@ -151,17 +151,49 @@ impl SqlParser {
         Ok(assignments)
     }
 
+    // Consumes a parameter placeholder token (`?`, `?N`/`$N`, or `:name`) and
+    // returns the corresponding `AstParameter`. Bare `?` placeholders are
+    // numbered by left-to-right occurrence via `self.parameter_count`;
+    // numbered/named placeholders carry their own index/name instead.
+    fn parse_parameter_token(&mut self) -> Result<AstParameter, SqlParseError> {
+        match self.peek().cloned() {
+            Some(Token::Parameter) => {
+                self.consume(Token::Parameter)?;
+                let param_index = self.parameter_count;
+                self.parameter_count += 1;
+                Ok(AstParameter::Positional(param_index))
+            }
+            Some(Token::NumberedParameter(n)) => {
+                self.consume(Token::NumberedParameter(n))?;
+                Ok(AstParameter::Numbered(n))
+            }
+            Some(Token::NamedParameter(name)) => {
+                self.consume(Token::NamedParameter(name.clone()))?;
+                Ok(AstParameter::Named(name))
+            }
+            other => Err(SqlParseError::UnexpectedToken {
+                expected: "parameter placeholder (?, ?N, $N, or :name)".to_string(),
+                found: format!("{:?}", other.unwrap_or(Token::EOF)),
+                position: self.current_token_pos(),
+            }),
+        }
+    }
+
+    fn peek_is_parameter_token(&self) -> bool {
+        matches!(
+            self.peek(),
+            Some(Token::Parameter | Token::NumberedParameter(_) | Token::NamedParameter(_))
+        )
+    }
+
     // Helper to attempt parsing a literal value. Does not consume if it's not a clear literal start.
     // Parse an expression value (literal or parameter)
     pub(super) fn parse_expression_value(
         &mut self,
         context: &str,
     ) -> Result<AstExpressionValue, SqlParseError> {
-        if self.match_token(Token::Parameter) {
-            self.consume(Token::Parameter)?;
-            let param_index = self.parameter_count;
-            self.parameter_count += 1;
-            Ok(AstExpressionValue::Parameter(param_index))
+        if self.peek_is_parameter_token() {
+            Ok(AstExpressionValue::Parameter(self.parse_parameter_token()?))
         } else if let Some(literal_val) = self.try_parse_literal_value()? {
             Ok(AstExpressionValue::Literal(literal_val))
         } else {
@@ -237,14 +269,8 @@ impl SqlParser {
             )?;
 
             // Attempt to parse RHS as literal, parameter, or qualified identifier
-            let rhs_value = if self.match_token(Token::Parameter) {
-                // Handle parameter placeholder
-                self.consume(Token::Parameter)?;
-                // For now, we'll use a simple counter for parameter indices
-                // This will need to be enhanced to track parameter positions properly
-                let param_index = self.parameter_count;
-                self.parameter_count += 1;
-                AstExpressionValue::Parameter(param_index)
+            let rhs_value = if self.peek_is_parameter_token() {
+                AstExpressionValue::Parameter(self.parse_parameter_token()?)
             } else if let Some(literal_val) = self.try_parse_literal_value()? {
                 AstExpressionValue::Literal(literal_val)
             } else {
@@ -286,7 +312,33 @@ impl SqlParser {
         error_msg_context: &str,
     ) -> Result<AstLiteralValue, SqlParseError> {
         let error_pos = self.current_token_pos();
-        if self.peek() == Some(&Token::LBracket) {
+        let is_zeroblob_call = matches!(
+            (self.peek(), self.peek_nth(1)),
+            (Some(Token::Identifier(ident)), Some(Token::LParen)) if ident.eq_ignore_ascii_case("ZEROBLOB")
+        );
+        if is_zeroblob_call {
+            self.consume_any(); // ZEROBLOB
+            self.consume(Token::LParen)?;
+            let len_pos = self.current_token_pos();
+            let len_str = match self.consume_any() {
+                Some(Token::NumericLiteral(n)) => n,
+                Some(other) => {
+                    return Err(SqlParseError::UnexpectedToken {
+                        expected: "integer length".to_string(),
+                        found: format!("{other:?}"),
+                        position: len_pos,
+                    })
+                }
+                None => return Err(SqlParseError::UnexpectedEOF),
+            };
+            let len = len_str.parse::<u32>().map_err(|_| SqlParseError::UnexpectedToken {
+                expected: "non-negative integer length".to_string(),
+                found: len_str,
+                position: len_pos,
+            })?;
+            self.consume(Token::RParen)?;
+            Ok(AstLiteralValue::ZeroBlob(len))
+        } else if self.peek() == Some(&Token::LBracket) {
             self.consume(Token::LBracket)?;
             let mut elements = Vec::new();
             if self.peek() != Some(&Token::RBracket) {
@@ -24,26 +24,101 @@ impl SqlParser {
             "INTEGER" | "INT" => Ok(ast::AstDataType::Integer),
             "TEXT" | "STRING" => Ok(ast::AstDataType::Text),
             "VARCHAR" | "CHAR" => {
-                // Optionally consume (length) or (length, precision) parameters
+                let fixed = type_name_upper == "CHAR";
+                let mut length = None;
                 if self.match_token(Token::LParen) {
                     self.consume(Token::LParen)?;
-                    // For now, just consume tokens until RParen, as AstDataType::Text doesn't store length/precision
-                    let mut paren_depth = 1;
-                    while paren_depth > 0 {
-                        match self.consume_any() {
-                            Some(Token::LParen) => paren_depth += 1,
-                            Some(Token::RParen) => paren_depth -= 1,
-                            Some(Token::EOF) => return Err(SqlParseError::UnexpectedEOF), // Unterminated type parameters
-                            Some(_) => {} // Consume other tokens within parentheses
-                            None => return Err(SqlParseError::UnexpectedEOF),
+                    let length_pos = self.current_token_pos();
+                    let length_str = match self.consume_any() {
+                        Some(Token::NumericLiteral(s)) => s,
+                        Some(other) => {
+                            return Err(SqlParseError::UnexpectedToken {
+                                expected: "length for VARCHAR/CHAR".to_string(),
+                                found: format!("{:?}", other),
+                                position: length_pos,
+                            })
                         }
-                    }
+                        None => return Err(SqlParseError::UnexpectedEOF),
+                    };
+                    length = Some(length_str.parse::<u32>().map_err(|_| {
+                        SqlParseError::InvalidDataTypeParameter {
+                            type_name: type_name_upper.clone(),
+                            parameter: length_str.clone(),
+                            position: length_pos,
+                            reason: "Length must be a positive integer".to_string(),
+                        }
+                    })?);
+                    self.consume(Token::RParen)?;
                 }
-                Ok(ast::AstDataType::Text)
+                Ok(ast::AstDataType::Varchar { length, fixed })
             }
             "BOOLEAN" | "BOOL" => Ok(ast::AstDataType::Boolean),
             "FLOAT" | "REAL" | "DOUBLE" => Ok(ast::AstDataType::Float),
             "BLOB" => Ok(ast::AstDataType::Blob),
+            "NUMERIC" | "DECIMAL" => {
+                // Default precision/scale when no (p[,s]) is given, matching the
+                // SQL standard's "implementation-defined" NUMERIC/DECIMAL.
+                let mut precision: u32 = 38;
+                let mut scale: u32 = 0;
+                if self.match_token(Token::LParen) {
+                    self.consume(Token::LParen)?;
+                    let precision_pos = self.current_token_pos();
+                    let precision_str = match self.consume_any() {
+                        Some(Token::NumericLiteral(s)) => s,
+                        Some(other) => {
+                            return Err(SqlParseError::UnexpectedToken {
+                                expected: "precision for NUMERIC/DECIMAL".to_string(),
+                                found: format!("{:?}", other),
+                                position: precision_pos,
+                            })
+                        }
+                        None => return Err(SqlParseError::UnexpectedEOF),
+                    };
+                    precision = precision_str.parse::<u32>().map_err(|_| {
+                        SqlParseError::InvalidDataTypeParameter {
+                            type_name: type_name_upper.clone(),
+                            parameter: precision_str.clone(),
+                            position: precision_pos,
+                            reason: "Precision must be a positive integer".to_string(),
+                        }
+                    })?;
+
+                    if self.match_token(Token::Comma) {
+                        self.consume(Token::Comma)?;
+                        let scale_pos = self.current_token_pos();
+                        let scale_str = match self.consume_any() {
+                            Some(Token::NumericLiteral(s)) => s,
+                            Some(other) => {
+                                return Err(SqlParseError::UnexpectedToken {
+                                    expected: "scale for NUMERIC/DECIMAL".to_string(),
+                                    found: format!("{:?}", other),
+                                    position: scale_pos,
+                                })
+                            }
+                            None => return Err(SqlParseError::UnexpectedEOF),
+                        };
+                        scale = scale_str.parse::<u32>().map_err(|_| {
+                            SqlParseError::InvalidDataTypeParameter {
+                                type_name: type_name_upper.clone(),
+                                parameter: scale_str.clone(),
+                                position: scale_pos,
+                                reason: "Scale must be a non-negative integer".to_string(),
+                            }
+                        })?;
+                    }
+                    self.consume(Token::RParen)?;
+
+                    if scale > precision {
+                        return Err(SqlParseError::InvalidDataTypeParameter {
+                            type_name: type_name_upper.clone(),
+                            parameter: format!("{precision},{scale}"),
+                            position: precision_pos,
+                            reason: "Scale cannot exceed precision".to_string(),
+                        });
+                    }
+                }
+                Ok(ast::AstDataType::Decimal { precision, scale })
+            }
             "VECTOR" => {
                 self.consume(Token::LBracket)?;
                 let dim_token_pos = self.current_token_pos();
@@ -92,7 +167,10 @@ impl SqlParser {
             //     }
             //     Ok(ast::AstDataType::Text) // Map to generic Text
             // }
-            _ => Err(SqlParseError::UnknownDataType(type_name_ident, type_ident_token_pos)),
+            // Anything else is taken as a reference to a user-defined enum type
+            // (`CREATE TYPE ... AS ENUM`) rather than an unknown builtin type;
+            // whether it's actually registered is checked at `CREATE TABLE` time.
+            _ => Ok(ast::AstDataType::Enum { type_name: type_name_ident }),
         }
     }
 
@@ -142,6 +220,7 @@ impl SqlParser {
             Some(Token::Insert) => self.parse_insert_statement(),
             Some(Token::Delete) => self.parse_delete_statement(), // Added
             Some(Token::Drop) => self.parse_drop_table_statement(), // Added
+            Some(Token::Explain) => self.parse_explain_statement(),
             Some(_other_token) => {
                 return Err(SqlParseError::UnknownStatementType(self.current_token_pos()))
             }
@@ -165,6 +244,33 @@ impl SqlParser {
         Ok(statement)
     }
 
+    /// `EXPLAIN [ANALYZE] <select-statement>`. Only `SELECT` is supported
+    /// today, since that's the only statement the planner/optimizer path
+    /// produces a `QueryPlanNode` tree for.
+    fn parse_explain_statement(&mut self) -> Result<Statement, SqlParseError> {
+        self.consume(Token::Explain)?;
+        let analyze = if self.match_token(Token::Analyze) {
+            self.consume(Token::Analyze)?;
+            true
+        } else {
+            false
+        };
+
+        let inner = match self.peek() {
+            Some(Token::Select) => self.parse_select_statement()?,
+            Some(other) => {
+                return Err(SqlParseError::UnexpectedToken {
+                    expected: "SELECT".to_string(),
+                    found: format!("{other:?}"),
+                    position: self.current_token_pos(),
+                })
+            }
+            None => return Err(SqlParseError::UnexpectedEOF),
+        };
+
+        Ok(Statement::Explain(ast::ExplainStatement { analyze, statement: Box::new(inner) }))
+    }
+
     fn parse_create_table_statement(&mut self) -> Result<Statement, SqlParseError> {
         self.consume(Token::Create)?;
         self.consume(Token::Table)?;
@@ -202,6 +308,9 @@ impl SqlParser {
                 } else if self.peek_is_identifier_str("UNIQUE") {
                     self.consume_any(); // Consume UNIQUE
                     constraints.push(ast::AstColumnConstraint::Unique);
+                } else if self.peek_is_identifier_str("TRUNCATE") {
+                    self.consume_any(); // Consume TRUNCATE
+                    constraints.push(ast::AstColumnConstraint::Truncate);
                 } else {
                     break; // No more constraint keywords for this column
                 }
@@ -294,9 +403,65 @@ impl SqlParser {
             }
         }
 
+        let on_conflict = self.parse_optional_on_conflict_clause()?;
+        let returning = self.parse_optional_returning_clause()?;
+
         // Semicolon handled by main parse()
 
-        Ok(Statement::Insert(ast::InsertStatement { table_name, columns, values: values_list }))
+        Ok(Statement::Insert(ast::InsertStatement {
+            table_name,
+            columns,
+            values: values_list,
+            on_conflict,
+            returning,
+        }))
+    }
+
+    /// Parses an optional `RETURNING col, ... | *` clause trailing an
+    /// `INSERT`/`UPDATE`/`DELETE` statement.
+    fn parse_optional_returning_clause(
+        &mut self,
+    ) -> Result<Option<Vec<ast::SelectColumn>>, SqlParseError> {
+        if !self.match_token(Token::Returning) {
+            return Ok(None);
+        }
+        self.consume(Token::Returning)?;
+        Ok(Some(self.parse_select_column_list()?))
+    }
+
+    /// Parses an optional `ON CONFLICT (col, ...) DO NOTHING | DO UPDATE SET ...` clause
+    /// trailing an `INSERT` statement's `VALUES` list.
+    fn parse_optional_on_conflict_clause(
+        &mut self,
+    ) -> Result<Option<ast::OnConflictClause>, SqlParseError> {
+        if !self.match_token(Token::On) {
+            return Ok(None);
+        }
+        self.consume(Token::On)?;
+        self.consume(Token::Conflict)?;
+
+        self.consume(Token::LParen)?;
+        let mut target_columns = Vec::new();
+        loop {
+            target_columns.push(self.expect_identifier("Expected column name in ON CONFLICT target")?);
+            if self.match_token(Token::RParen) {
+                break;
+            }
+            self.consume(Token::Comma)?;
+        }
+        self.consume(Token::RParen)?;
+
+        self.consume(Token::Do)?;
+        let action = if self.match_token(Token::Nothing) {
+            self.consume(Token::Nothing)?;
+            ast::ConflictAction::DoNothing
+        } else {
+            self.consume(Token::Update)?;
+            self.consume(Token::Set)?;
+            ast::ConflictAction::DoUpdate(self.parse_assignment_list()?)
+        };
+
+        Ok(Some(ast::OnConflictClause { target_columns, action }))
     }
 
     pub(super) fn parse_select_statement(&mut self) -> Result<Statement, SqlParseError> {
@@ -577,8 +742,9 @@ impl SqlParser {
         } else {
             None
         };
+        let returning = self.parse_optional_returning_clause()?;
         // Semicolon handled by main parse()
-        Ok(Statement::Update(UpdateStatement { source, assignments, condition }))
+        Ok(Statement::Update(UpdateStatement { source, assignments, condition, returning }))
     }
 
     // Placeholder for DELETE statement parsing
@@ -592,10 +758,11 @@ impl SqlParser {
         } else {
             None // Or error if WHERE clause is mandatory for DELETE
         };
+        let returning = self.parse_optional_returning_clause()?;
         // Semicolon handled by main parse()
 
         // ast::DeleteStatement is now used.
-        Ok(Statement::Delete(ast::DeleteStatement { table_name, condition }))
+        Ok(Statement::Delete(ast::DeleteStatement { table_name, condition, returning }))
     }
 
     fn parse_drop_table_statement(&mut self) -> Result<Statement, SqlParseError> {
@@ -1396,6 +1396,120 @@ fn test_parse_insert_multiple_values() {
     }
 }
 
+#[test]
+fn test_parse_insert_on_conflict_do_nothing() {
+    let tokens = tokenize_str(
+        "INSERT INTO users (id, name) VALUES (1, 'Alice') ON CONFLICT (id) DO NOTHING;",
+    );
+    let mut parser = SqlParser::new(tokens);
+    let ast = parser.parse().unwrap();
+    match ast {
+        Statement::Insert(insert_stmt) => {
+            let on_conflict = insert_stmt.on_conflict.expect("Expected ON CONFLICT clause");
+            assert_eq!(on_conflict.target_columns, vec!["id".to_string()]);
+            assert_eq!(on_conflict.action, ast::ConflictAction::DoNothing);
+        }
+        _ => panic!("Expected InsertStatement"),
+    }
+}
+
+#[test]
+fn test_parse_insert_on_conflict_do_update() {
+    let tokens = tokenize_str(
+        "INSERT INTO users (id, name) VALUES (1, 'Alice') ON CONFLICT (id) DO UPDATE SET name = 'Alicia';",
+    );
+    let mut parser = SqlParser::new(tokens);
+    let ast = parser.parse().unwrap();
+    match ast {
+        Statement::Insert(insert_stmt) => {
+            let on_conflict = insert_stmt.on_conflict.expect("Expected ON CONFLICT clause");
+            assert_eq!(on_conflict.target_columns, vec!["id".to_string()]);
+            match on_conflict.action {
+                ast::ConflictAction::DoUpdate(assignments) => {
+                    assert_eq!(assignments.len(), 1);
+                    assert_eq!(assignments[0].column, "name");
+                }
+                _ => panic!("Expected ConflictAction::DoUpdate"),
+            }
+        }
+        _ => panic!("Expected InsertStatement"),
+    }
+}
+
+#[test]
+fn test_parse_insert_without_on_conflict_has_none() {
+    let tokens = tokenize_str("INSERT INTO users (id, name) VALUES (1, 'Alice');");
+    let mut parser = SqlParser::new(tokens);
+    let ast = parser.parse().unwrap();
+    match ast {
+        Statement::Insert(insert_stmt) => {
+            assert!(insert_stmt.on_conflict.is_none());
+        }
+        _ => panic!("Expected InsertStatement"),
+    }
+}
+
+#[test]
+fn test_parse_insert_returning_star() {
+    let tokens = tokenize_str("INSERT INTO users (id, name) VALUES (1, 'Alice') RETURNING *;");
+    let mut parser = SqlParser::new(tokens);
+    let ast = parser.parse().unwrap();
+    match ast {
+        Statement::Insert(insert_stmt) => {
+            assert_eq!(insert_stmt.returning, Some(vec![SelectColumn::Asterisk]));
+        }
+        _ => panic!("Expected InsertStatement"),
+    }
+}
+
+#[test]
+fn test_parse_insert_on_conflict_do_update_returning_columns() {
+    let tokens = tokenize_str(
+        "INSERT INTO users (id, name) VALUES (1, 'Alice') ON CONFLICT (id) DO UPDATE SET name = 'Alicia' RETURNING id, name;",
+    );
+    let mut parser = SqlParser::new(tokens);
+    let ast = parser.parse().unwrap();
+    match ast {
+        Statement::Insert(insert_stmt) => {
+            assert!(insert_stmt.on_conflict.is_some());
+            assert_eq!(
+                insert_stmt.returning,
+                Some(vec![
+                    SelectColumn::ColumnName("id".to_string()),
+                    SelectColumn::ColumnName("name".to_string())
+                ])
+            );
+        }
+        _ => panic!("Expected InsertStatement"),
+    }
+}
+
+#[test]
+fn test_parse_update_returning() {
+    let tokens = tokenize_str("UPDATE users SET name = 'Bob' WHERE id = 1 RETURNING name;");
+    let mut parser = SqlParser::new(tokens);
+    let ast = parser.parse().unwrap();
+    match ast {
+        Statement::Update(update_stmt) => {
+            assert_eq!(update_stmt.returning, Some(vec![SelectColumn::ColumnName("name".to_string())]));
+        }
+        _ => panic!("Expected UpdateStatement"),
+    }
+}
+
+#[test]
+fn test_parse_delete_returning() {
+    let tokens = tokenize_str("DELETE FROM users WHERE id = 1 RETURNING id;");
+    let mut parser = SqlParser::new(tokens);
+    let ast = parser.parse().unwrap();
+    match ast {
+        Statement::Delete(delete_stmt) => {
+            assert_eq!(delete_stmt.returning, Some(vec![SelectColumn::ColumnName("id".to_string())]));
+        }
+        _ => panic!("Expected DeleteStatement"),
+    }
+}
+
 #[test]
 fn test_mixed_case_keywords() {
     let tokens = tokenize_str("SeLeCt * FrOm my_table WhErE value = TrUe;");
@@ -2261,3 +2375,58 @@ fn test_autoincrement_insert_functionality() {
     // Verify auto-increment state
     assert_eq!(executor.get_next_auto_increment_value("test_table", "id"), 3);
 }
+
+#[test]
+fn test_parameterized_command_resolves_named_parameters_positionally() {
+    use crate::core::common::types::Value as ParamValue;
+    use crate::core::query::commands::ParameterizedCommand;
+    use std::collections::HashMap;
+
+    let ast_stmt = parse_update_statement_for_test();
+
+    // The statement's two `?` occurrences, in order, came from `:name` and `:id`.
+    let placeholder_names = vec!["name".to_string(), "id".to_string()];
+    let mut named = HashMap::new();
+    named.insert("name".to_string(), ParamValue::Text("Widget".to_string()));
+    named.insert("id".to_string(), ParamValue::Integer(7));
+
+    let command = ParameterizedCommand::named(ast_stmt, placeholder_names, named);
+    let resolved = command.resolve_positional().unwrap();
+    assert_eq!(
+        resolved,
+        vec![ParamValue::Text("Widget".to_string()), ParamValue::Integer(7)]
+    );
+}
+
+#[test]
+fn test_parameterized_command_named_missing_value_is_an_error() {
+    use crate::core::query::commands::ParameterizedCommand;
+    use std::collections::HashMap;
+
+    let ast_stmt = parse_update_statement_for_test();
+    let placeholder_names = vec!["name".to_string(), "id".to_string()];
+    let named = HashMap::new(); // Neither name bound.
+
+    let command = ParameterizedCommand::named(ast_stmt, placeholder_names, named);
+    assert!(command.resolve_positional().is_err());
+}
+
+#[test]
+fn test_parameterized_command_positional_resolves_values_as_is() {
+    use crate::core::common::types::Value as ParamValue;
+    use crate::core::query::commands::ParameterizedCommand;
+
+    let ast_stmt = parse_update_statement_for_test();
+    let command =
+        ParameterizedCommand::positional(ast_stmt, vec![ParamValue::Text("Widget".to_string()), ParamValue::Integer(7)]);
+    let resolved = command.resolve_positional().unwrap();
+    assert_eq!(resolved, vec![ParamValue::Text("Widget".to_string()), ParamValue::Integer(7)]);
+}
+
+/// Builds `UPDATE items SET name = ? WHERE id = ?` for the `ParameterizedCommand`
+/// named-parameter tests above.
+fn parse_update_statement_for_test() -> Statement {
+    let tokens = tokenize_str("UPDATE items SET name = ? WHERE id = ?;");
+    let mut parser = SqlParser::new(tokens);
+    parser.parse().unwrap()
+}
@@ -27,6 +27,14 @@ pub enum Token {
     Desc,          // Added Desc Token
     Limit,         // Added Limit Token
     Autoincrement, // Added Autoincrement Token
+    Put,           // Added Put Token (upsert-by-primary-key)
+    Rm,            // Added Rm Token (idempotent delete)
+    Ensure,        // Added Ensure Token (assert-exists-or-insert)
+    Not,           // Added Not Token (used by ENSURE NOT)
+    Conflict,      // Added Conflict Token (used by INSERT ... ON CONFLICT)
+    Do,            // Added Do Token (used by ON CONFLICT ... DO)
+    Nothing,       // Added Nothing Token (used by DO NOTHING)
+    Returning,     // Added Returning Token (used by INSERT/UPDATE/DELETE ... RETURNING)
 
     // Join-related keywords
     Join,
@@ -39,6 +47,10 @@ pub enum Token {
     Cross,
     As, // Added As keyword for aliases
 
+    // EXPLAIN [ANALYZE] keywords
+    Explain,
+    Analyze,
+
     // Literals
     Identifier(String),
     StringLiteral(String),
@@ -56,6 +68,8 @@ pub enum Token {
     RBracket,  // Added ]
     Dot,       // Added . for qualified names
     Parameter, // Added ? for parameter placeholders
+    NumberedParameter(u32), // Added ?N / $N for numbered parameter placeholders
+    NamedParameter(String), // Added :name for named parameter placeholders
 
     // End of File
     EOF,
@@ -85,6 +99,14 @@ impl fmt::Debug for Token {
             Self::Asc => write!(f, "Asc"),       // Added for Asc Token
             Self::Desc => write!(f, "Desc"),     // Added for Desc Token
             Self::Limit => write!(f, "Limit"),   // Added for Limit Token
+            Self::Put => write!(f, "Put"),
+            Self::Rm => write!(f, "Rm"),
+            Self::Ensure => write!(f, "Ensure"),
+            Self::Not => write!(f, "Not"),
+            Self::Conflict => write!(f, "Conflict"),
+            Self::Do => write!(f, "Do"),
+            Self::Nothing => write!(f, "Nothing"),
+            Self::Returning => write!(f, "Returning"),
             Self::Join => write!(f, "Join"),
             Self::On => write!(f, "On"),
             Self::Inner => write!(f, "Inner"),
@@ -94,6 +116,8 @@ impl fmt::Debug for Token {
             Self::Outer => write!(f, "Outer"),
             Self::Cross => write!(f, "Cross"),
             Self::As => write!(f, "As"), // Added for As
+            Self::Explain => write!(f, "Explain"),
+            Self::Analyze => write!(f, "Analyze"),
             Self::Identifier(s) => f.debug_tuple("Identifier").field(s).finish(),
             Self::StringLiteral(s) => f.debug_tuple("StringLiteral").field(s).finish(),
             Self::NumericLiteral(s) => f.debug_tuple("NumericLiteral").field(s).finish(),
@@ -108,6 +132,8 @@ impl fmt::Debug for Token {
             Self::RBracket => write!(f, "RBracket"),
             Self::Dot => write!(f, "Dot"),
             Self::Parameter => write!(f, "Parameter"),
+            Self::NumberedParameter(n) => f.debug_tuple("NumberedParameter").field(n).finish(),
+            Self::NamedParameter(s) => f.debug_tuple("NamedParameter").field(s).finish(),
             Self::Autoincrement => write!(f, "Autoincrement"),
             Self::EOF => write!(f, "EOF"),
         }
@@ -189,6 +215,16 @@ impl<'a> Tokenizer<'a> {
             "OUTER" => Token::Outer,
             "CROSS" => Token::Cross,
             "AS" => Token::As, // Added As keyword
+            "PUT" => Token::Put,
+            "RM" => Token::Rm,
+            "ENSURE" => Token::Ensure,
+            "NOT" => Token::Not,
+            "CONFLICT" => Token::Conflict,
+            "DO" => Token::Do,
+            "NOTHING" => Token::Nothing,
+            "RETURNING" => Token::Returning,
+            "EXPLAIN" => Token::Explain,
+            "ANALYZE" => Token::Analyze,
             _ => Token::Identifier(ident.to_string()),
         })
     }
@@ -277,6 +313,38 @@ impl<'a> Tokenizer<'a> {
         Ok(Token::NumericLiteral(num_str.to_string()))
     }
 
+    /// Reads the digits following a `?` or `$` that introduce a numbered
+    /// parameter placeholder (e.g. `?1`, `$2`), returning its 1-based index.
+    fn read_parameter_number(&mut self) -> u32 {
+        let mut digits = String::new();
+        while let Some((_, ch)) = self.chars.peek() {
+            if ch.is_ascii_digit() {
+                digits.push(*ch);
+                self.chars.next();
+            } else {
+                break;
+            }
+        }
+        // The caller only invokes this after confirming at least one digit follows,
+        // so this parse cannot fail in practice.
+        digits.parse().unwrap_or(0)
+    }
+
+    /// Reads the identifier following a `:` that introduces a named
+    /// parameter placeholder (e.g. `:user_id`).
+    fn read_parameter_name(&mut self) -> String {
+        let mut name = String::new();
+        while let Some((_, ch)) = self.chars.peek() {
+            if ch.is_alphanumeric() || *ch == '_' {
+                name.push(*ch);
+                self.chars.next();
+            } else {
+                break;
+            }
+        }
+        name
+    }
+
     /// Tokenizes the input SQL string into a vector of tokens
     ///
     /// Parses the SQL string character by character, identifying keywords, identifiers,
@@ -347,8 +415,25 @@ impl<'a> Tokenizer<'a> {
                     }
                     '?' => {
                         self.chars.next();
-                        tokens.push(Token::Parameter);
-                        self.current_pos = idx + 1;
+                        if self.chars.peek().is_some_and(|&(_, next_ch)| next_ch.is_ascii_digit()) {
+                            let number = self.read_parameter_number();
+                            tokens.push(Token::NumberedParameter(number));
+                        } else {
+                            tokens.push(Token::Parameter);
+                        }
+                        self.current_pos = self.chars.peek().map_or(self.input.len(), |(i, _)| *i);
+                    }
+                    '$' => {
+                        self.chars.next();
+                        let number = self.read_parameter_number();
+                        tokens.push(Token::NumberedParameter(number));
+                        self.current_pos = self.chars.peek().map_or(self.input.len(), |(i, _)| *i);
+                    }
+                    ':' => {
+                        self.chars.next();
+                        let name = self.read_parameter_name();
+                        tokens.push(Token::NamedParameter(name));
+                        self.current_pos = self.chars.peek().map_or(self.input.len(), |(i, _)| *i);
                     }
                     '\'' | '"' => {
                         tokens.push(self.read_string_literal(ch, idx)?);
@@ -678,4 +763,37 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_numbered_and_named_parameter_placeholders() {
+        let mut tokenizer = Tokenizer::new("SELECT * FROM t WHERE a = ? AND b = ?2 AND c = $3 AND d = :name;");
+        let tokens = tokenizer.tokenize().unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Select,
+                Token::Asterisk,
+                Token::From,
+                Token::Identifier("t".to_string()),
+                Token::Where,
+                Token::Identifier("a".to_string()),
+                Token::Operator("=".to_string()),
+                Token::Parameter,
+                Token::Identifier("AND".to_string()),
+                Token::Identifier("b".to_string()),
+                Token::Operator("=".to_string()),
+                Token::NumberedParameter(2),
+                Token::Identifier("AND".to_string()),
+                Token::Identifier("c".to_string()),
+                Token::Operator("=".to_string()),
+                Token::NumberedParameter(3),
+                Token::Identifier("AND".to_string()),
+                Token::Identifier("d".to_string()),
+                Token::Operator("=".to_string()),
+                Token::NamedParameter("name".to_string()),
+                Token::Semicolon,
+                Token::EOF,
+            ]
+        );
+    }
 }
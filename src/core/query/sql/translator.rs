@@ -6,6 +6,53 @@ use crate::core::types::{DataType, VectorData}; // Added VectorData
 pub fn translate_ast_to_command(ast_statement: ast::Statement) -> Result<Command, OxidbError> {
     // Changed
     match ast_statement {
+        ast::Statement::Select(select_ast) if select_ast.group_by.is_some() => {
+            let group_by_cols = select_ast
+                .group_by
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|expr| match expr {
+                    ast::AstExpressionValue::ColumnIdentifier(col) => Ok(col),
+                    other => Err(OxidbError::SqlParsing(format!(
+                        "GROUP BY only supports column references, found {other:?}"
+                    ))),
+                })
+                .collect::<Result<Vec<String>, OxidbError>>()?;
+
+            let aggregates = select_ast
+                .columns
+                .iter()
+                .filter_map(|column| match column {
+                    ast::SelectColumn::AggregateFunction { function, column, alias } => {
+                        let col_name = match column.as_ref() {
+                            ast::SelectColumn::ColumnName(name) => Some(name.clone()),
+                            ast::SelectColumn::Asterisk | ast::SelectColumn::AggregateFunction { .. } => None,
+                        };
+                        Some(commands::SqlAggregateExpr {
+                            function: *function,
+                            column: col_name,
+                            alias: alias.clone(),
+                        })
+                    }
+                    ast::SelectColumn::ColumnName(_) | ast::SelectColumn::Asterisk => None,
+                })
+                .collect::<Vec<_>>();
+
+            let condition_cmd = match select_ast.condition {
+                Some(cond_tree_ast) => {
+                    Some(translate_condition_tree_to_sql_condition_tree(&cond_tree_ast)?)
+                }
+                None => None,
+            };
+
+            Ok(Command::SelectAggregate {
+                source: select_ast.from_clause.name.clone(),
+                group_by: group_by_cols,
+                aggregates,
+                condition: condition_cmd,
+            })
+        }
         ast::Statement::Select(select_ast) => {
             let columns_spec = translate_select_columns(select_ast.columns);
             let condition_cmd = match select_ast.condition {
@@ -60,21 +107,34 @@ pub fn translate_ast_to_command(ast_statement: ast::Statement) -> Result<Command
                 }
                 None => None,
             };
+            let returning_cmd = update_ast.returning.map(translate_select_columns);
             Ok(Command::Update {
                 source: update_ast.source,
                 assignments: assignments_cmd,
                 condition: condition_cmd,
+                returning: returning_cmd,
             })
         }
         ast::Statement::CreateTable(create_ast) => {
             let mut command_columns = Vec::new();
             for ast_col_def in create_ast.columns {
+                let (max_length, is_fixed_length) = match &ast_col_def.data_type {
+                    ast::AstDataType::Varchar { length, fixed } => (*length, *fixed),
+                    _ => (None, false),
+                };
                 let data_type = match ast_col_def.data_type {
                     ast::AstDataType::Integer => DataType::Integer(0), // Default value for schema
                     ast::AstDataType::Text => DataType::String(String::new()),
+                    ast::AstDataType::Varchar { .. } => DataType::String(String::new()),
                     ast::AstDataType::Boolean => DataType::Boolean(false),
                     ast::AstDataType::Float => DataType::Float(crate::core::types::OrderedFloat(0.0)),
                     ast::AstDataType::Blob => DataType::RawBytes(Vec::new()), // Assuming RawBytes is the engine type for Blob
+                    ast::AstDataType::Decimal { precision, scale } => {
+                        DataType::Decimal { unscaled: 0, precision, scale }
+                    }
+                    ast::AstDataType::Enum { type_name } => {
+                        DataType::Enum { type_name, value: String::new() } // Default value for schema
+                    }
                     ast::AstDataType::Vector { dimension } => {
                         // For schema definition, create a vector with correct dimension filled with zeros
                         let placeholder_data = vec![0.0; dimension as usize];
@@ -94,6 +154,7 @@ pub fn translate_ast_to_command(ast_statement: ast::Statement) -> Result<Command
                 let mut is_unique = false;
                 let mut is_nullable = true; // Default to nullable
                 let mut is_auto_increment = false;
+                let mut truncate_overflow = false;
 
                 for constraint in ast_col_def.constraints {
                     match constraint {
@@ -113,6 +174,9 @@ pub fn translate_ast_to_command(ast_statement: ast::Statement) -> Result<Command
                             // AUTOINCREMENT typically implies NOT NULL
                             is_nullable = false;
                         }
+                        ast::AstColumnConstraint::Truncate => {
+                            truncate_overflow = true;
+                        }
                     }
                 }
 
@@ -128,6 +192,9 @@ pub fn translate_ast_to_command(ast_statement: ast::Statement) -> Result<Command
                     is_unique,
                     is_nullable,
                     is_auto_increment,
+                    max_length,
+                    is_fixed_length,
+                    truncate_overflow,
                 });
             }
             Ok(Command::CreateTable { table_name: create_ast.table_name, columns: command_columns })
@@ -141,10 +208,17 @@ pub fn translate_ast_to_command(ast_statement: ast::Statement) -> Result<Command
                 }
                 translated_values_list.push(translated_row);
             }
+            let on_conflict_cmd = match insert_ast.on_conflict {
+                Some(on_conflict_ast) => Some(translate_on_conflict_clause(on_conflict_ast)?),
+                None => None,
+            };
+            let returning_cmd = insert_ast.returning.map(translate_select_columns);
             Ok(Command::SqlInsert {
                 table_name: insert_ast.table_name,
                 columns: insert_ast.columns,
                 values: translated_values_list,
+                on_conflict: on_conflict_cmd,
+                returning: returning_cmd,
             })
         }
         ast::Statement::Delete(delete_stmt) => {
@@ -154,12 +228,72 @@ pub fn translate_ast_to_command(ast_statement: ast::Statement) -> Result<Command
                 }
                 None => None,
             };
-            Ok(Command::SqlDelete { table_name: delete_stmt.table_name, condition: condition_cmd })
+            let returning_cmd = delete_stmt.returning.map(translate_select_columns);
+            Ok(Command::SqlDelete {
+                table_name: delete_stmt.table_name,
+                condition: condition_cmd,
+                returning: returning_cmd,
+            })
+        }
+        ast::Statement::Put(put_ast) => {
+            let mut translated_values_list = Vec::new();
+            for row_values_ast in put_ast.values {
+                let mut translated_row = Vec::new();
+                for val_ast in row_values_ast {
+                    translated_row.push(translate_expression_value(&val_ast)?);
+                }
+                translated_values_list.push(translated_row);
+            }
+            Ok(Command::Put {
+                table_name: put_ast.table_name,
+                columns: put_ast.columns,
+                values: translated_values_list,
+            })
+        }
+        ast::Statement::Rm(rm_ast) => {
+            let condition_cmd = match rm_ast.condition {
+                Some(cond_tree_ast) => {
+                    Some(translate_condition_tree_to_sql_condition_tree(&cond_tree_ast)?)
+                }
+                None => None,
+            };
+            Ok(Command::Rm { table_name: rm_ast.table_name, condition: condition_cmd })
+        }
+        ast::Statement::Ensure(ensure_ast) => {
+            let mut translated_values_list = Vec::new();
+            for row_values_ast in ensure_ast.values {
+                let mut translated_row = Vec::new();
+                for val_ast in row_values_ast {
+                    translated_row.push(translate_expression_value(&val_ast)?);
+                }
+                translated_values_list.push(translated_row);
+            }
+            Ok(Command::Ensure {
+                table_name: ensure_ast.table_name,
+                columns: ensure_ast.columns,
+                values: translated_values_list,
+            })
+        }
+        ast::Statement::EnsureNot(ensure_not_ast) => {
+            let condition_cmd = match ensure_not_ast.condition {
+                Some(cond_tree_ast) => {
+                    Some(translate_condition_tree_to_sql_condition_tree(&cond_tree_ast)?)
+                }
+                None => None,
+            };
+            Ok(Command::EnsureNot {
+                table_name: ensure_not_ast.table_name,
+                condition: condition_cmd,
+            })
         }
         ast::Statement::DropTable(drop_stmt) => Ok(Command::DropTable {
             table_name: drop_stmt.table_name,
             if_exists: drop_stmt.if_exists,
         }),
+        ast::Statement::Explain(explain_ast) => {
+            let inner_command = translate_ast_to_command(*explain_ast.statement)?;
+            Ok(Command::Explain { statement: Box::new(inner_command), analyze: explain_ast.analyze })
+        }
     }
 }
 
@@ -188,6 +322,9 @@ pub fn translate_datatype_to_ast_literal(
         DataType::Float(f) => Ok(ast::AstLiteralValue::Number(f.0.to_string())),
         DataType::Boolean(b) => Ok(ast::AstLiteralValue::Boolean(*b)),
         DataType::Null => Ok(ast::AstLiteralValue::Null),
+        DataType::Decimal { unscaled, scale, .. } => {
+            Ok(ast::AstLiteralValue::Number(crate::core::types::decimal::format_decimal(*unscaled, *scale)))
+        }
         DataType::RawBytes(bytes) => Ok(ast::AstLiteralValue::String(hex::encode(bytes))),
         DataType::Map(_) | DataType::JsonBlob(_) => Err(OxidbError::SqlParsing(
             "Cannot translate complex DataType (Map/JsonBlob) to simple AST literal for conditions.".to_string(),
@@ -236,6 +373,7 @@ fn translate_literal(literal: &ast::AstLiteralValue) -> Result<DataType, OxidbEr
         }
         ast::AstLiteralValue::Boolean(b) => Ok(DataType::Boolean(*b)),
         ast::AstLiteralValue::Null => Ok(DataType::Null),
+        ast::AstLiteralValue::ZeroBlob(len) => Ok(DataType::RawBytes(vec![0u8; *len as usize])),
         ast::AstLiteralValue::Vector(elements_ast) => {
             let mut float_elements = Vec::with_capacity(elements_ast.len());
             for el_ast in elements_ast {
@@ -325,7 +463,24 @@ fn translate_assignment_to_sql_assignment(
     Ok(commands::SqlAssignment { column: ast_assignment.column.clone(), value })
 }
 
-fn translate_select_columns(ast_columns: Vec<ast::SelectColumn>) -> commands::SelectColumnSpec {
+fn translate_on_conflict_clause(
+    ast_on_conflict: ast::OnConflictClause,
+) -> Result<commands::OnConflict, OxidbError> {
+    let action = match ast_on_conflict.action {
+        ast::ConflictAction::DoNothing => commands::ConflictAction::DoNothing,
+        ast::ConflictAction::DoUpdate(assignments) => commands::ConflictAction::DoUpdate(
+            assignments
+                .iter()
+                .map(translate_assignment_to_sql_assignment)
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+    };
+    Ok(commands::OnConflict { target_columns: ast_on_conflict.target_columns, action })
+}
+
+pub(crate) fn translate_select_columns(
+    ast_columns: Vec<ast::SelectColumn>,
+) -> commands::SelectColumnSpec {
     if ast_columns.iter().any(|col| matches!(col, ast::SelectColumn::Asterisk)) {
         return commands::SelectColumnSpec::All;
     }
@@ -334,7 +489,7 @@ fn translate_select_columns(ast_columns: Vec<ast::SelectColumn>) -> commands::Se
         .into_iter()
         .filter_map(|col| match col {
             ast::SelectColumn::ColumnName(name) => Some(name),
-            ast::SelectColumn::Asterisk => None,
+            ast::SelectColumn::Asterisk | ast::SelectColumn::AggregateFunction { .. } => None,
         })
         .collect();
 
@@ -627,10 +782,11 @@ mod tests {
                     "XYZ123".to_string(),
                 )),
             })),
+            returning: None,
         });
         let command = translate_ast_to_command(ast_stmt).unwrap();
         match command {
-            Command::Update { source, assignments, condition } => {
+            Command::Update { source, assignments, condition, .. } => {
                 assert_eq!(source, "products");
                 assert_eq!(assignments.len(), 1);
                 assert_eq!(assignments[0].column, "price");
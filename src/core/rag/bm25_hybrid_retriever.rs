@@ -0,0 +1,265 @@
+// src/core/rag/bm25_hybrid_retriever.rs
+
+//! Hybrid BM25 keyword + vector-`Retriever` search with Reciprocal Rank Fusion.
+//!
+//! Unlike [`super::hybrid_retrieval::HybridRetriever`], which blends TF-IDF cosine
+//! similarity with a dense embedder's cosine similarity over a fixed document set it owns
+//! outright, [`Bm25HybridRetriever`] wraps any [`Retriever`] implementor (brute-force,
+//! HNSW, ...) for the vector side and scores keyword relevance with real Okapi BM25 over
+//! the documents' content. Because a [`Retriever`] only returns a ranked list of documents
+//! (no raw scores), the two lists are fused purely by rank via Reciprocal Rank Fusion
+//! rather than a score-based blend.
+
+use std::collections::HashMap;
+
+use crate::core::common::OxidbError;
+
+use super::core_components::{Document, Embedding};
+use super::retriever::{Retriever, SimilarityMetric};
+
+/// BM25 term-frequency saturation constant.
+const K1: f32 = 1.2;
+/// BM25 document-length normalization constant.
+const B: f32 = 0.75;
+/// Reciprocal Rank Fusion's rank-discount constant (the RRF paper's default).
+const RRF_K: f32 = 60.0;
+
+/// A query for [`Bm25HybridRetriever::search`]: raw text for BM25, plus its embedding for
+/// the wrapped vector [`Retriever`].
+pub struct HybridQuery<'a> {
+    pub text: &'a str,
+    pub embedding: &'a Embedding,
+}
+
+/// One document's fused hybrid result. `None` for a rank means the document didn't appear
+/// in that list at all (e.g. the vector retriever excludes documents without an embedding).
+#[derive(Debug, Clone)]
+pub struct FusedResult {
+    pub document: Document,
+    pub bm25_rank: Option<usize>,
+    pub vector_rank: Option<usize>,
+    pub fused_score: f32,
+}
+
+/// Per-document BM25 statistics over a fixed corpus.
+struct Bm25Index {
+    doc_term_freqs: Vec<HashMap<String, usize>>,
+    doc_lengths: Vec<usize>,
+    doc_freq: HashMap<String, usize>,
+    avgdl: f32,
+}
+
+impl Bm25Index {
+    fn build(documents: &[Document]) -> Self {
+        let doc_term_freqs: Vec<HashMap<String, usize>> = documents
+            .iter()
+            .map(|doc| {
+                let mut freqs = HashMap::new();
+                for term in tokenize(&doc.content) {
+                    *freqs.entry(term).or_insert(0) += 1;
+                }
+                freqs
+            })
+            .collect();
+
+        let doc_lengths: Vec<usize> =
+            doc_term_freqs.iter().map(|freqs| freqs.values().sum()).collect();
+
+        let mut doc_freq: HashMap<String, usize> = HashMap::new();
+        for freqs in &doc_term_freqs {
+            for term in freqs.keys() {
+                *doc_freq.entry(term.clone()).or_insert(0) += 1;
+            }
+        }
+
+        let avgdl = if doc_lengths.is_empty() {
+            0.0
+        } else {
+            doc_lengths.iter().sum::<usize>() as f32 / doc_lengths.len() as f32
+        };
+
+        Self { doc_term_freqs, doc_lengths, doc_freq, avgdl }
+    }
+
+    /// `ln(1 + (N - df + 0.5) / (df + 0.5))`; always non-negative, unlike the classic
+    /// Robertson-Sparck-Jones IDF, which can go negative for terms in more than half the
+    /// corpus.
+    fn idf(&self, term: &str) -> f32 {
+        let n = self.doc_term_freqs.len() as f32;
+        let df = self.doc_freq.get(term).copied().unwrap_or(0) as f32;
+        (1.0 + (n - df + 0.5) / (df + 0.5)).ln()
+    }
+
+    /// Okapi BM25 score of `query_terms` against the document at `index`.
+    fn score(&self, query_terms: &[String], index: usize) -> f32 {
+        let doc_len = self.doc_lengths[index] as f32;
+        let freqs = &self.doc_term_freqs[index];
+        query_terms
+            .iter()
+            .map(|term| {
+                let tf = freqs.get(term).copied().unwrap_or(0) as f32;
+                if tf == 0.0 {
+                    return 0.0;
+                }
+                let numerator = tf * (K1 + 1.0);
+                let denominator = tf + K1 * (1.0 - B + B * doc_len / self.avgdl.max(f32::EPSILON));
+                self.idf(term) * numerator / denominator
+            })
+            .sum()
+    }
+}
+
+/// Lower-cases and splits on non-alphanumeric boundaries, dropping very short tokens —
+/// the same scheme [`TfIdfEmbedder`](super::embedder::TfIdfEmbedder) uses, so BM25 and
+/// TF-IDF agree on what counts as a term.
+fn tokenize(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|s| s.len() > 2)
+        .map(String::from)
+        .collect()
+}
+
+/// Combines BM25 keyword ranking with a dense vector [`Retriever`]'s ranking via
+/// Reciprocal Rank Fusion.
+pub struct Bm25HybridRetriever<R: Retriever> {
+    documents: Vec<Document>,
+    bm25: Bm25Index,
+    vector_retriever: R,
+    metric: SimilarityMetric,
+}
+
+impl<R: Retriever> Bm25HybridRetriever<R> {
+    /// Builds the BM25 index over `documents`. `vector_retriever` answers the dense side of
+    /// each query — typically an [`InMemoryRetriever`](super::retriever::InMemoryRetriever)
+    /// or [`HnswRetriever`](super::hnsw_retriever::HnswRetriever) built over the same
+    /// documents — and is queried with `metric`.
+    #[must_use]
+    pub fn new(documents: Vec<Document>, vector_retriever: R, metric: SimilarityMetric) -> Self {
+        let bm25 = Bm25Index::build(&documents);
+        Self { documents, bm25, vector_retriever, metric }
+    }
+
+    /// Runs BM25 over `query.text` and the wrapped vector retriever over `query.embedding`,
+    /// fuses both ranked lists by Reciprocal Rank Fusion, and returns the top `top_k`.
+    ///
+    /// # Errors
+    /// Propagates any `OxidbError` raised by the wrapped vector retriever.
+    pub async fn search(
+        &self,
+        query: &HybridQuery<'_>,
+        top_k: usize,
+    ) -> Result<Vec<FusedResult>, OxidbError> {
+        let bm25_ranked = self.bm25_search(query.text);
+        let vector_ranked =
+            self.vector_retriever.retrieve(query.embedding, self.documents.len(), self.metric).await?;
+
+        let bm25_rank_by_id: HashMap<&str, usize> =
+            bm25_ranked.iter().enumerate().map(|(rank, id)| (id.as_str(), rank)).collect();
+        let vector_rank_by_id: HashMap<&str, usize> =
+            vector_ranked.iter().enumerate().map(|(rank, doc)| (doc.id.as_str(), rank)).collect();
+
+        let mut fused_scores: HashMap<&str, f32> = HashMap::new();
+        for rank_by_id in [&bm25_rank_by_id, &vector_rank_by_id] {
+            for (&id, &rank) in rank_by_id.iter() {
+                *fused_scores.entry(id).or_insert(0.0) += 1.0 / (RRF_K + rank as f32 + 1.0);
+            }
+        }
+
+        let mut results: Vec<FusedResult> = self
+            .documents
+            .iter()
+            .filter_map(|doc| {
+                fused_scores.get(doc.id.as_str()).map(|&fused_score| FusedResult {
+                    document: doc.clone(),
+                    bm25_rank: bm25_rank_by_id.get(doc.id.as_str()).copied(),
+                    vector_rank: vector_rank_by_id.get(doc.id.as_str()).copied(),
+                    fused_score,
+                })
+            })
+            .collect();
+
+        results.sort_by(|a, b| b.fused_score.partial_cmp(&a.fused_score).unwrap_or(std::cmp::Ordering::Equal));
+        results.truncate(top_k);
+        Ok(results)
+    }
+
+    /// Ranks every document by BM25 score against `query_text`, descending.
+    fn bm25_search(&self, query_text: &str) -> Vec<String> {
+        let query_terms = tokenize(query_text);
+        let mut scored: Vec<(String, f32)> = self
+            .documents
+            .iter()
+            .enumerate()
+            .map(|(index, doc)| (doc.id.clone(), self.bm25.score(&query_terms, index)))
+            .collect();
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        scored.into_iter().map(|(id, _)| id).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::retriever::InMemoryRetriever;
+    use super::*;
+
+    fn sample_documents() -> Vec<Document> {
+        vec![
+            Document::new("doc1".to_string(), "apple banana cherry".to_string())
+                .with_embedding(Embedding::from(vec![1.0, 0.0])),
+            Document::new("doc2".to_string(), "banana cherry date".to_string())
+                .with_embedding(Embedding::from(vec![0.0, 1.0])),
+            Document::new("doc3".to_string(), "cherry date elderberry".to_string())
+                .with_embedding(Embedding::from(vec![0.5, 0.5])),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_bm25_hybrid_retriever_fuses_agreeing_lists_to_the_top() {
+        let documents = sample_documents();
+        let vector_retriever = InMemoryRetriever::new(documents.clone());
+        let hybrid = Bm25HybridRetriever::new(documents, vector_retriever, SimilarityMetric::Cosine);
+
+        let query_embedding = Embedding::from(vec![0.5, 0.5]);
+        let query = HybridQuery { text: "cherry date", embedding: &query_embedding };
+        let results = hybrid.search(&query, 3).await.unwrap();
+
+        assert_eq!(results.len(), 3);
+        // doc3 matches both query terms in text and has the closest embedding, so it
+        // should lead both lists and therefore the fusion.
+        assert_eq!(results[0].document.id, "doc3");
+        assert!(results[0].bm25_rank.is_some());
+        assert!(results[0].vector_rank.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_bm25_hybrid_retriever_top_k_truncates() {
+        let documents = sample_documents();
+        let vector_retriever = InMemoryRetriever::new(documents.clone());
+        let hybrid = Bm25HybridRetriever::new(documents, vector_retriever, SimilarityMetric::Cosine);
+
+        let query_embedding = Embedding::from(vec![0.5, 0.5]);
+        let query = HybridQuery { text: "cherry", embedding: &query_embedding };
+        let results = hybrid.search(&query, 1).await.unwrap();
+        assert_eq!(results.len(), 1);
+    }
+
+    #[test]
+    fn test_bm25_scores_rarer_terms_higher() {
+        let documents = sample_documents();
+        let bm25 = Bm25Index::build(&documents);
+        // "elderberry" appears only in doc3; "cherry" appears in every document. A rarer
+        // matching term should contribute more to the score via its higher IDF.
+        let cherry_score = bm25.score(&tokenize("cherry"), 2);
+        let elderberry_score = bm25.score(&tokenize("elderberry"), 2);
+        assert!(elderberry_score > cherry_score);
+    }
+
+    #[test]
+    fn test_bm25_no_match_scores_zero() {
+        let documents = sample_documents();
+        let bm25 = Bm25Index::build(&documents);
+        let score = bm25.score(&tokenize("grapefruit"), 0);
+        assert_eq!(score, 0.0);
+    }
+}
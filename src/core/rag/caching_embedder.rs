@@ -0,0 +1,380 @@
+// src/core/rag/caching_embedder.rs
+//! Memoizing and batching decorators around an [`EmbeddingModel`], so repeated
+//! re-indexing and rate-limited remote backends don't redo or overload embedding work.
+//!
+//! [`CachingEmbedder`] wraps any embedder and skips recomputation for text it has
+//! already embedded, keyed by a content hash of the normalized text. [`EmbeddingQueue`]
+//! accumulates [`Document`]s and flushes them through [`EmbeddingModel::embed_documents`]
+//! in batches sized to a token budget rather than a fixed count, retrying a failed batch
+//! with exponential backoff and only committing a batch's embeddings once the whole
+//! batch succeeds.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::time::sleep;
+
+use crate::core::common::OxidbError;
+
+use super::chunker::{CharsPerTokenEstimator, TokenEstimator};
+use super::core_components::{Document, Embedding};
+use super::embedder::EmbeddingModel;
+
+/// Hashes normalized (trimmed, lowercased) text, mirroring the content-hashing used by
+/// [`super::graphrag::GraphRAGEngineImpl::generate_entity_id`] so equivalent text with
+/// incidental whitespace or casing differences still hits the cache.
+fn content_hash(text: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    text.trim().to_lowercase().hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Decorates an [`EmbeddingModel`] with a memoization cache keyed by content hash, so
+/// re-indexing unchanged documents skips recomputation entirely. A drop-in wrapper
+/// around [`super::embedder::TfIdfEmbedder`], [`super::embedder::SemanticEmbedder`], or
+/// a remote model.
+pub struct CachingEmbedder<M: EmbeddingModel> {
+    inner: M,
+    cache: Mutex<HashMap<u64, Embedding>>,
+}
+
+impl<M: EmbeddingModel> CachingEmbedder<M> {
+    #[must_use]
+    pub fn new(inner: M) -> Self {
+        Self { inner, cache: Mutex::new(HashMap::new()) }
+    }
+
+    /// Number of distinct embeddings currently cached.
+    #[must_use]
+    pub fn cache_len(&self) -> usize {
+        self.cache.lock().unwrap().len()
+    }
+
+    fn cached(&self, key: u64) -> Option<Embedding> {
+        self.cache.lock().unwrap().get(&key).cloned()
+    }
+
+    fn insert(&self, key: u64, embedding: Embedding) {
+        self.cache.lock().unwrap().insert(key, embedding);
+    }
+}
+
+#[async_trait]
+impl<M: EmbeddingModel> EmbeddingModel for CachingEmbedder<M> {
+    async fn embed_document(&self, document: &Document) -> Result<Embedding, OxidbError> {
+        let key = content_hash(&document.content);
+        if let Some(embedding) = self.cached(key) {
+            return Ok(embedding);
+        }
+        let embedding = self.inner.embed_document(document).await?;
+        self.insert(key, embedding.clone());
+        Ok(embedding)
+    }
+
+    async fn embed(&self, text: &str) -> Result<Embedding, OxidbError> {
+        let key = content_hash(text);
+        if let Some(embedding) = self.cached(key) {
+            return Ok(embedding);
+        }
+        let embedding = self.inner.embed(text).await?;
+        self.insert(key, embedding.clone());
+        Ok(embedding)
+    }
+}
+
+/// Configuration for [`EmbeddingQueue`]'s batching and retry behaviour.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EmbeddingQueueConfig {
+    /// Flush a batch once its accumulated estimated token count would exceed this
+    /// budget, rather than waiting for a fixed number of documents.
+    pub max_tokens_per_batch: usize,
+    /// How many times to retry a failed batch before giving up.
+    pub max_retries: usize,
+    /// Delay before the first retry; each subsequent retry doubles it. Callers
+    /// embedding against a rate-limited backend should set this to whatever hint the
+    /// backend gives (e.g. a `Retry-After` header).
+    pub initial_retry_delay: Duration,
+}
+
+impl Default for EmbeddingQueueConfig {
+    fn default() -> Self {
+        Self {
+            max_tokens_per_batch: 4096,
+            max_retries: 3,
+            initial_retry_delay: Duration::from_millis(500),
+        }
+    }
+}
+
+/// Accumulates pending [`Document`]s and flushes them through an [`EmbeddingModel`] in
+/// batches sized to a token budget, so each backend call carries an optimal payload
+/// instead of one sized by an arbitrary document count.
+///
+/// A batch is committed atomically: [`Self::flush`] only returns embeddings for a batch
+/// once the whole batch's [`EmbeddingModel::embed_documents`] call has succeeded, and a
+/// transient failure is retried with exponential backoff rather than losing or
+/// partially committing the batch.
+pub struct EmbeddingQueue {
+    config: EmbeddingQueueConfig,
+    estimator: Box<dyn TokenEstimator>,
+    pending: Vec<Document>,
+}
+
+impl Default for EmbeddingQueue {
+    fn default() -> Self {
+        Self {
+            config: EmbeddingQueueConfig::default(),
+            estimator: Box::new(CharsPerTokenEstimator::default()),
+            pending: Vec::new(),
+        }
+    }
+}
+
+impl EmbeddingQueue {
+    #[must_use]
+    pub fn new(config: EmbeddingQueueConfig) -> Self {
+        Self { config, ..Self::default() }
+    }
+
+    /// Use a custom token estimator (builder pattern) in place of the default
+    /// chars-per-token heuristic.
+    #[must_use]
+    pub fn with_estimator(mut self, estimator: Box<dyn TokenEstimator>) -> Self {
+        self.estimator = estimator;
+        self
+    }
+
+    /// Queue a document for embedding on the next [`Self::flush`].
+    pub fn enqueue(&mut self, document: Document) {
+        self.pending.push(document);
+    }
+
+    /// Number of documents currently queued.
+    #[must_use]
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Drain every queued document through `embedder`, grouping them into batches
+    /// bounded by `config.max_tokens_per_batch` estimated tokens, and return each
+    /// document paired with its embedding in enqueue order.
+    ///
+    /// # Errors
+    ///
+    /// Returns the last error from a batch that still fails after
+    /// `config.max_retries` retries. Batches after the failing one are never
+    /// attempted, and the embeddings of batches that already succeeded are dropped
+    /// along with the error. The queue is left empty either way, so the caller has to
+    /// re-enqueue every document it still needs.
+    pub async fn flush<M: EmbeddingModel>(
+        &mut self,
+        embedder: &M,
+    ) -> Result<Vec<(Document, Embedding)>, OxidbError> {
+        let documents = std::mem::take(&mut self.pending);
+        let mut results = Vec::with_capacity(documents.len());
+
+        for batch in self.token_budgeted_batches(documents) {
+            let embeddings = self.embed_batch_with_retry(embedder, &batch).await?;
+            results.extend(batch.into_iter().zip(embeddings));
+        }
+
+        Ok(results)
+    }
+
+    /// Group `documents` into runs whose estimated token count stays within
+    /// `config.max_tokens_per_batch`, without splitting any single document.
+    fn token_budgeted_batches(&self, documents: Vec<Document>) -> Vec<Vec<Document>> {
+        let mut batches = Vec::new();
+        let mut current = Vec::new();
+        let mut current_tokens = 0;
+
+        for document in documents {
+            let tokens = self.estimator.estimate_tokens(&document.content);
+            if !current.is_empty() && current_tokens + tokens > self.config.max_tokens_per_batch {
+                batches.push(std::mem::take(&mut current));
+                current_tokens = 0;
+            }
+            current_tokens += tokens;
+            current.push(document);
+        }
+        if !current.is_empty() {
+            batches.push(current);
+        }
+
+        batches
+    }
+
+    async fn embed_batch_with_retry<M: EmbeddingModel>(
+        &self,
+        embedder: &M,
+        batch: &[Document],
+    ) -> Result<Vec<Embedding>, OxidbError> {
+        let mut delay = self.config.initial_retry_delay;
+        let mut attempt = 0;
+        loop {
+            match embedder.embed_documents(batch).await {
+                Ok(embeddings) => return Ok(embeddings),
+                Err(err) if attempt < self.config.max_retries => {
+                    attempt += 1;
+                    sleep(delay).await;
+                    delay *= 2;
+                    let _ = &err;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::rag::embedder::MockEmbeddingModel;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_caching_embedder_skips_recomputation_for_same_content() {
+        let inner = CountingEmbedder::new(MockEmbeddingModel { dimension: 4, fixed_embedding_value: Some(0.5) });
+        let calls = inner.calls.clone();
+        let caching = CachingEmbedder::new(inner);
+
+        let first = caching.embed("hello world").await.unwrap();
+        let second = caching.embed("  Hello World  ").await.unwrap();
+
+        assert_eq!(first.vector, second.vector);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(caching.cache_len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_caching_embedder_recomputes_for_different_content() {
+        let inner = CountingEmbedder::new(MockEmbeddingModel { dimension: 4, fixed_embedding_value: Some(0.5) });
+        let calls = inner.calls.clone();
+        let caching = CachingEmbedder::new(inner);
+
+        caching.embed("hello").await.unwrap();
+        caching.embed("world").await.unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(caching.cache_len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_queue_batches_by_token_budget_without_splitting_documents() {
+        let mut queue = EmbeddingQueue::new(EmbeddingQueueConfig {
+            max_tokens_per_batch: 4,
+            ..EmbeddingQueueConfig::default()
+        });
+        queue.enqueue(Document::new("a".to_string(), "abcd".to_string())); // 1 token
+        queue.enqueue(Document::new("b".to_string(), "abcd".to_string())); // 1 token
+        queue.enqueue(Document::new("c".to_string(), "abcdefghijklmnop".to_string())); // 4 tokens
+
+        let batches = queue.token_budgeted_batches(queue.pending.clone());
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].len(), 2);
+        assert_eq!(batches[1].len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_queue_flush_returns_one_embedding_per_document_in_order() {
+        let embedder = MockEmbeddingModel { dimension: 4, fixed_embedding_value: Some(0.5) };
+        let mut queue = EmbeddingQueue::default();
+        queue.enqueue(Document::new("a".to_string(), "first".to_string()));
+        queue.enqueue(Document::new("b".to_string(), "second".to_string()));
+
+        let results = queue.flush(&embedder).await.unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0.id, "a");
+        assert_eq!(results[1].0.id, "b");
+        assert_eq!(queue.pending_len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_queue_flush_retries_transient_failure_then_succeeds() {
+        let embedder = FlakyEmbedder::new(2, MockEmbeddingModel { dimension: 4, fixed_embedding_value: Some(0.5) });
+        let mut queue = EmbeddingQueue::new(EmbeddingQueueConfig {
+            initial_retry_delay: Duration::from_millis(1),
+            max_retries: 3,
+            ..EmbeddingQueueConfig::default()
+        });
+        queue.enqueue(Document::new("a".to_string(), "first".to_string()));
+
+        let results = queue.flush(&embedder).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(embedder.attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_queue_flush_gives_up_after_max_retries() {
+        let embedder = FlakyEmbedder::new(10, MockEmbeddingModel { dimension: 4, fixed_embedding_value: Some(0.5) });
+        let mut queue = EmbeddingQueue::new(EmbeddingQueueConfig {
+            initial_retry_delay: Duration::from_millis(1),
+            max_retries: 2,
+            ..EmbeddingQueueConfig::default()
+        });
+        queue.enqueue(Document::new("a".to_string(), "first".to_string()));
+
+        assert!(queue.flush(&embedder).await.is_err());
+    }
+
+    struct CountingEmbedder {
+        inner: MockEmbeddingModel,
+        calls: std::sync::Arc<AtomicUsize>,
+    }
+
+    impl CountingEmbedder {
+        fn new(inner: MockEmbeddingModel) -> Self {
+            Self { inner, calls: std::sync::Arc::new(AtomicUsize::new(0)) }
+        }
+    }
+
+    #[async_trait]
+    impl EmbeddingModel for CountingEmbedder {
+        async fn embed_document(&self, document: &Document) -> Result<Embedding, OxidbError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            self.inner.embed_document(document).await
+        }
+
+        async fn embed(&self, text: &str) -> Result<Embedding, OxidbError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            self.inner.embed(text).await
+        }
+    }
+
+    struct FlakyEmbedder {
+        failures_remaining: AtomicUsize,
+        attempts: AtomicUsize,
+        inner: MockEmbeddingModel,
+    }
+
+    impl FlakyEmbedder {
+        fn new(failures: usize, inner: MockEmbeddingModel) -> Self {
+            Self { failures_remaining: AtomicUsize::new(failures), attempts: AtomicUsize::new(0), inner }
+        }
+    }
+
+    #[async_trait]
+    impl EmbeddingModel for FlakyEmbedder {
+        async fn embed_document(&self, document: &Document) -> Result<Embedding, OxidbError> {
+            self.inner.embed_document(document).await
+        }
+
+        async fn embed(&self, text: &str) -> Result<Embedding, OxidbError> {
+            self.inner.embed(text).await
+        }
+
+        async fn embed_documents(&self, documents: &[Document]) -> Result<Vec<Embedding>, OxidbError> {
+            self.attempts.fetch_add(1, Ordering::SeqCst);
+            if self.failures_remaining.load(Ordering::SeqCst) > 0 {
+                self.failures_remaining.fetch_sub(1, Ordering::SeqCst);
+                return Err(OxidbError::Other("transient embedding backend error".to_string()));
+            }
+            self.inner.embed_documents(documents).await
+        }
+    }
+}
@@ -0,0 +1,301 @@
+// src/core/rag/chunker.rs
+//! Token-aware document chunking, so long documents can be embedded and cited at
+//! sub-document granularity instead of being diluted into a single vector.
+//!
+//! [`DocumentChunker::chunk`] splits a [`Document`] into [`DocumentChunk`]s bounded by
+//! an approximate token budget, preferring to break on sentence boundaries rather than
+//! mid-sentence, and carrying a configurable amount of overlap between consecutive
+//! chunks so context isn't severed mid-idea. [`DocumentChunker::embed_chunks`] wires
+//! this into any [`EmbeddingModel`], producing one [`Chunk`] — an embedding plus the
+//! source document id and byte range — per piece.
+
+use crate::core::common::OxidbError;
+
+use super::core_components::{Document, Embedding};
+use super::embedder::EmbeddingModel;
+
+/// Estimates how many tokens a piece of text costs, so chunk boundaries can be picked
+/// without depending on any particular model's real tokenizer.
+pub trait TokenEstimator: Send + Sync {
+    fn estimate_tokens(&self, text: &str) -> usize;
+}
+
+/// The default estimator: roughly 4 characters per token, which is a common rule of
+/// thumb for English text across BPE-style tokenizers.
+pub struct CharsPerTokenEstimator {
+    pub chars_per_token: usize,
+}
+
+impl Default for CharsPerTokenEstimator {
+    fn default() -> Self {
+        Self { chars_per_token: 4 }
+    }
+}
+
+impl TokenEstimator for CharsPerTokenEstimator {
+    fn estimate_tokens(&self, text: &str) -> usize {
+        text.chars().count().div_ceil(self.chars_per_token.max(1))
+    }
+}
+
+/// Configuration for [`DocumentChunker`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChunkerConfig {
+    /// Approximate maximum tokens per chunk.
+    pub max_tokens: usize,
+    /// Fraction of `max_tokens` carried over from the end of one chunk into the start
+    /// of the next, so a concept split across the boundary still has context on both
+    /// sides. Expected in `[0.0, 1.0)`.
+    pub overlap_ratio: f32,
+}
+
+impl Default for ChunkerConfig {
+    fn default() -> Self {
+        Self { max_tokens: 512, overlap_ratio: 0.15 }
+    }
+}
+
+/// A document chunk prior to embedding: the source document's id, the half-open byte
+/// `range` into its original content this chunk spans, and that span's text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentChunk {
+    pub doc_id: String,
+    pub range: (usize, usize),
+    pub content: String,
+}
+
+/// An embedded document chunk, as indexed by a retriever: the source document id and
+/// byte range let a result cite the exact span it came from, without duplicating the
+/// chunk's text alongside its embedding.
+#[derive(Debug, Clone)]
+pub struct Chunk {
+    pub doc_id: String,
+    pub range: (usize, usize),
+    pub embedding: Embedding,
+}
+
+impl Chunk {
+    /// A stable identifier combining the source document id and byte range, e.g.
+    /// `"doc1#120-450"` — suitable as a `Document::id` when indexing chunks with a
+    /// [`super::retriever::Retriever`] that only understands whole documents.
+    #[must_use]
+    pub fn id(&self) -> String {
+        format!("{}#{}-{}", self.doc_id, self.range.0, self.range.1)
+    }
+}
+
+/// Splits documents into overlapping, token-budgeted chunks and embeds them.
+pub struct DocumentChunker {
+    config: ChunkerConfig,
+    estimator: Box<dyn TokenEstimator>,
+}
+
+impl Default for DocumentChunker {
+    fn default() -> Self {
+        Self { config: ChunkerConfig::default(), estimator: Box::new(CharsPerTokenEstimator::default()) }
+    }
+}
+
+impl DocumentChunker {
+    #[must_use]
+    pub fn new(config: ChunkerConfig) -> Self {
+        Self { config, ..Self::default() }
+    }
+
+    /// Use a custom token estimator (builder pattern), e.g. one backed by a real
+    /// tokenizer instead of the default chars-per-token heuristic.
+    #[must_use]
+    pub fn with_estimator(mut self, estimator: Box<dyn TokenEstimator>) -> Self {
+        self.estimator = estimator;
+        self
+    }
+
+    /// Split `document`'s content into overlapping chunks, each bounded by
+    /// `config.max_tokens` and preferring to break at sentence boundaries. Returns no
+    /// chunks for empty content, since there is nothing to embed.
+    #[must_use]
+    pub fn chunk(&self, document: &Document) -> Vec<DocumentChunk> {
+        let content = &document.content;
+        if content.is_empty() {
+            return Vec::new();
+        }
+
+        let units = sentence_boundaries(content);
+        let overlap_budget = (self.config.max_tokens as f32 * self.config.overlap_ratio) as usize;
+
+        let mut chunks = Vec::new();
+        let mut i = 0;
+        while i < units.len() {
+            let mut end = i;
+            let mut tokens = 0;
+            while end < units.len() {
+                let (start, stop) = units[end];
+                let unit_tokens = self.estimator.estimate_tokens(&content[start..stop]);
+                if tokens > 0 && tokens + unit_tokens > self.config.max_tokens {
+                    break;
+                }
+                tokens += unit_tokens;
+                end += 1;
+            }
+
+            let chunk_start = units[i].0;
+            let chunk_end = units[end - 1].1;
+            chunks.push(DocumentChunk {
+                doc_id: document.id.clone(),
+                range: (chunk_start, chunk_end),
+                content: content[chunk_start..chunk_end].to_string(),
+            });
+
+            if end >= units.len() {
+                break;
+            }
+
+            // Walk back from `end` far enough to cover the overlap budget, so the next
+            // chunk repeats the tail of this one instead of starting cold.
+            let mut back = end;
+            if overlap_budget > 0 {
+                let mut overlap_tokens = 0;
+                while back > i {
+                    let (start, stop) = units[back - 1];
+                    let unit_tokens = self.estimator.estimate_tokens(&content[start..stop]);
+                    if overlap_tokens > 0 && overlap_tokens + unit_tokens > overlap_budget {
+                        break;
+                    }
+                    overlap_tokens += unit_tokens;
+                    back -= 1;
+                }
+            }
+            i = back.max(i + 1);
+        }
+
+        chunks
+    }
+
+    /// Chunk every document and embed each chunk with `embedder`, producing one
+    /// [`Chunk`] per piece — the sub-document granularity [`EmbeddingModel::embed_documents`]
+    /// can't give you, since it embeds whole documents.
+    ///
+    /// # Errors
+    ///
+    /// Propagates any [`OxidbError`] raised while embedding a chunk.
+    pub async fn embed_chunks<E: EmbeddingModel + ?Sized>(
+        &self,
+        documents: &[Document],
+        embedder: &E,
+    ) -> Result<Vec<Chunk>, OxidbError> {
+        let mut chunks = Vec::new();
+        for document in documents {
+            for doc_chunk in self.chunk(document) {
+                let chunk_document = Document::new(doc_chunk.doc_id.clone(), doc_chunk.content);
+                let embedding = embedder.embed_document(&chunk_document).await?;
+                chunks.push(Chunk { doc_id: doc_chunk.doc_id, range: doc_chunk.range, embedding });
+            }
+        }
+        Ok(chunks)
+    }
+}
+
+/// Byte `(start, end)` ranges of each sentence in `content`, in order. A sentence ends
+/// at a `.`, `!`, or `?` followed by whitespace or end-of-string; any trailing text
+/// without a terminator becomes a final sentence of its own.
+fn sentence_boundaries(content: &str) -> Vec<(usize, usize)> {
+    let mut units = Vec::new();
+    let mut unit_start = 0;
+    let mut chars = content.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        if c == '.' || c == '!' || c == '?' {
+            let end = i + c.len_utf8();
+            let at_boundary = chars.peek().map_or(true, |&(_, next)| next.is_whitespace());
+            if at_boundary {
+                units.push((unit_start, end));
+                unit_start = end;
+            }
+        }
+    }
+
+    if unit_start < content.len() {
+        units.push((unit_start, content.len()));
+    }
+
+    units
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::rag::embedder::MockEmbeddingModel;
+
+    #[test]
+    fn test_chars_per_token_estimator_rounds_up() {
+        let estimator = CharsPerTokenEstimator::default();
+        assert_eq!(estimator.estimate_tokens("abcd"), 1);
+        assert_eq!(estimator.estimate_tokens("abcde"), 2);
+    }
+
+    #[test]
+    fn test_chunk_splits_on_sentence_boundaries() {
+        let document = Document::new(
+            "doc1".to_string(),
+            "First sentence here. Second sentence here. Third sentence here.".to_string(),
+        );
+        let chunker = DocumentChunker::new(ChunkerConfig { max_tokens: 8, overlap_ratio: 0.0 });
+        let chunks = chunker.chunk(&document);
+
+        assert!(chunks.len() > 1, "long content should split into multiple chunks");
+        for chunk in &chunks {
+            assert_eq!(&document.content[chunk.range.0..chunk.range.1], chunk.content);
+        }
+    }
+
+    #[test]
+    fn test_chunk_overlap_repeats_tail_of_previous_chunk() {
+        let document = Document::new(
+            "doc1".to_string(),
+            "Alpha sentence one. Beta sentence two. Gamma sentence three. Delta sentence four.".to_string(),
+        );
+        let chunker = DocumentChunker::new(ChunkerConfig { max_tokens: 12, overlap_ratio: 0.5 });
+        let chunks = chunker.chunk(&document);
+
+        assert!(chunks.len() > 1);
+        // With overlap, consecutive chunks should share some leading/trailing text
+        // rather than picking up exactly where the previous one left off.
+        assert!(chunks[1].range.0 < chunks[0].range.1);
+    }
+
+    #[test]
+    fn test_chunk_empty_document_yields_no_chunks() {
+        let document = Document::new("doc1".to_string(), String::new());
+        let chunker = DocumentChunker::default();
+        assert!(chunker.chunk(&document).is_empty());
+    }
+
+    #[test]
+    fn test_single_short_sentence_yields_one_chunk() {
+        let document = Document::new("doc1".to_string(), "Just one short sentence.".to_string());
+        let chunker = DocumentChunker::default();
+        let chunks = chunker.chunk(&document);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].content, document.content);
+    }
+
+    #[tokio::test]
+    async fn test_embed_chunks_produces_one_embedding_per_chunk() {
+        let document = Document::new(
+            "doc1".to_string(),
+            "First sentence here. Second sentence here. Third sentence here.".to_string(),
+        );
+        let chunker = DocumentChunker::new(ChunkerConfig { max_tokens: 8, overlap_ratio: 0.0 });
+        let embedder = MockEmbeddingModel { dimension: 4, fixed_embedding_value: Some(0.5) };
+
+        let chunks = chunker.embed_chunks(&[document.clone()], &embedder).await.unwrap();
+        let expected_chunk_count = chunker.chunk(&document).len();
+
+        assert_eq!(chunks.len(), expected_chunk_count);
+        for chunk in &chunks {
+            assert_eq!(chunk.doc_id, "doc1");
+            assert_eq!(chunk.embedding.vector.len(), 4);
+            assert!(chunk.id().starts_with("doc1#"));
+        }
+    }
+}
@@ -14,6 +14,7 @@ use crate::core::graph::{
 };
 use crate::core::graph::traversal::TraversalDirection;
 use crate::core::graph::GraphOperations;
+use crate::core::indexing::hnsw::{DistanceFunction, HnswIndex};
 use crate::core::vector::similarity::cosine_similarity;
 use crate::core::types::VectorData;
 use async_trait::async_trait;
@@ -164,6 +165,10 @@ pub struct GraphRAGEngineImpl {
     entity_documents: HashMap<NodeId, Vec<String>>,
     relationship_weights: HashMap<String, f64>,
     confidence_threshold: f64,
+    /// HNSW index over `entity_embeddings`, lazily built once the embedding dimension is known
+    /// from the first entity added. `find_similar_entities` uses it instead of a full scan
+    /// whenever it is populated.
+    vector_index: Option<HnswIndex>,
 }
 
 impl GraphRAGEngineImpl {
@@ -182,6 +187,7 @@ impl GraphRAGEngineImpl {
             entity_documents: HashMap::new(),
             relationship_weights: Self::default_relationship_weights(),
             confidence_threshold: config.confidence_threshold,
+            vector_index: None,
         }
     }
 
@@ -198,6 +204,7 @@ impl GraphRAGEngineImpl {
             entity_documents: HashMap::new(),
             relationship_weights: Self::default_relationship_weights(),
             confidence_threshold: 0.5,
+            vector_index: None,
         }
     }
 
@@ -658,15 +665,62 @@ impl GraphRAGEngineImpl {
         Ok(relationships)
     }
 
-    /// Find entities similar to a given embedding
+    /// Adds `embedding` to the lazily-built HNSW index, creating the index from the first
+    /// embedding's dimension if this is the first one seen. Indexing errors (e.g. a later
+    /// entity whose embedding dimension doesn't match the first) are logged and otherwise
+    /// ignored: `find_similar_entities` always has the full scan over `entity_embeddings` to
+    /// fall back on, so a stale or missing index degrades performance, not correctness.
+    fn index_embedding(&mut self, node_id: NodeId, embedding: &Embedding) {
+        if self.vector_index.is_none() {
+            self.vector_index = HnswIndex::new(
+                "graphrag_entity_embeddings".to_string(),
+                embedding.vector.len(),
+                16,
+                200,
+                DistanceFunction::Cosine,
+            )
+            .ok();
+        }
+
+        if let Some(index) = &mut self.vector_index {
+            let key = node_id.to_le_bytes().to_vec();
+            if let Err(e) = index.insert_vector(embedding.vector.clone(), key) {
+                eprintln!("[GraphRAGEngineImpl] Failed to add entity {node_id} to HNSW index: {e}");
+            }
+        }
+    }
+
+    /// Find entities similar to a given embedding.
+    ///
+    /// Queries the HNSW index built up by `index_embedding` when one is available, which turns
+    /// this from an O(n) scan over every entity into roughly O(log n). Falls back to the linear
+    /// scan whenever the index hasn't been built yet (e.g. no entities indexed so far) or the
+    /// search against it errors out.
     fn find_similar_entities(
         &self,
         query_embedding: &Embedding,
         top_k: usize,
         min_confidence: f64,
     ) -> Result<Vec<(NodeId, f64)>, OxidbError> {
+        if let Some(index) = &self.vector_index {
+            if let Ok(hits) = index.search_vector(&query_embedding.vector, top_k) {
+                let mut similarities: Vec<(NodeId, f64)> = hits
+                    .into_iter()
+                    .filter_map(|(distance, key)| {
+                        let node_id = NodeId::from_le_bytes(key.try_into().ok()?);
+                        // HNSW distance is 1 - cosine_similarity; convert back for min_confidence.
+                        let similarity = 1.0 - distance as f64;
+                        (similarity >= min_confidence).then_some((node_id, similarity))
+                    })
+                    .collect();
+                similarities
+                    .sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+                return Ok(similarities);
+            }
+        }
+
         let mut similarities = Vec::new();
-        
+
         // Calculate similarity with all entity embeddings
         for (node_id, entity_embedding) in &self.entity_embeddings {
             if let Ok(similarity) = cosine_similarity(
@@ -678,10 +732,10 @@ impl GraphRAGEngineImpl {
                 }
             }
         }
-        
+
         // Sort by similarity (descending)
         similarities.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
-        
+
         // Return top_k results
         Ok(similarities.into_iter().take(top_k).collect())
     }
@@ -815,6 +869,7 @@ impl GraphRAGEngineBuilder {
             entity_documents: HashMap::new(),
             relationship_weights: GraphRAGEngineImpl::default_relationship_weights(),
             confidence_threshold: self.confidence_threshold,
+            vector_index: None,
         })
     }
 }
@@ -1303,6 +1358,7 @@ impl GraphRAGEngine for GraphRAGEngineImpl {
         let node_id = self.graph_store.add_node(graph_data)?;
 
         if let Some(embedding) = entity.embedding {
+            self.index_embedding(node_id, &embedding);
             self.entity_embeddings.insert(node_id, embedding);
         }
 
@@ -0,0 +1,543 @@
+// src/core/rag/hnsw_retriever.rs
+
+//! Hierarchical Navigable Small World (HNSW) approximate nearest-neighbor retriever.
+//!
+//! [`InMemoryRetriever`](super::retriever::InMemoryRetriever) scores every stored document on
+//! every query, which doesn't scale to large corpora. `HnswRetriever` builds a multi-layer
+//! proximity graph over the stored embeddings once, then answers queries with a bounded
+//! best-first search instead of a full scan.
+//!
+//! Construction and search follow the original HNSW paper (Malkov & Yashunin):
+//! - Each inserted node is assigned a random top layer `l`, with `P(level >= l) = exp(-l/ln(M))`.
+//! - Per-layer neighbor lists are capped at `M` (`2*M` at layer 0, which holds every node).
+//! - Insertion greedily descends from the entry point through layers above `l`, then at each
+//!   layer from `l` down to `0` runs a best-first search (`efConstruction` candidates) and
+//!   selects neighbors via a diversity heuristic: a candidate is kept only if it is closer to
+//!   the new node than it is to any neighbor already selected for it.
+//! - Queries greedily descend the upper layers to find an entry point, then run a bounded
+//!   best-first search at layer 0 with an `ef` parameter (`ef >= top_k`) and return the
+//!   `top_k` closest results.
+
+use super::core_components::{Document, Embedding};
+use super::retriever::{Retriever, SimilarityMetric};
+use crate::core::common::OxidbError;
+use crate::core::vector::similarity::{cosine_similarity, dot_product};
+use async_trait::async_trait;
+use rand::Rng;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashSet};
+
+/// Default maximum number of neighbors per node above layer 0 (`M` in the paper).
+pub const DEFAULT_M: usize = 16;
+/// Default candidate-heap size used while building the graph (`efConstruction`).
+pub const DEFAULT_EF_CONSTRUCTION: usize = 200;
+/// Default candidate-heap size used at query time (`ef`).
+pub const DEFAULT_EF: usize = 50;
+
+struct HnswNode {
+    document: Document,
+    embedding: Vec<f32>,
+    /// `neighbors[layer]` holds this node's neighbor indices at that layer; a node only has
+    /// entries up to its own assigned level.
+    neighbors: Vec<Vec<usize>>,
+}
+
+/// A scored candidate for the best-first search heaps, ordered by similarity (higher is
+/// better) so that a plain `BinaryHeap` behaves as a max-heap over the closest candidates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Scored {
+    similarity: f32,
+    index: usize,
+}
+
+impl Eq for Scored {}
+
+impl Ord for Scored {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.similarity.partial_cmp(&other.similarity).unwrap_or(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for Scored {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// An HNSW-backed [`Retriever`] over a fixed set of documents' embeddings.
+///
+/// The graph is built once, up front, against a single [`SimilarityMetric`]. [`retrieve`]
+/// rejects calls made with a different metric, since the graph's neighbor lists only
+/// approximate nearest neighbors under the metric they were built with.
+///
+/// [`retrieve`]: Retriever::retrieve
+pub struct HnswRetriever {
+    nodes: Vec<HnswNode>,
+    entry_point: Option<usize>,
+    top_layer: usize,
+    metric: SimilarityMetric,
+    m: usize,
+    m_max0: usize,
+    ef_construction: usize,
+    ef: usize,
+    level_multiplier: f64,
+}
+
+impl HnswRetriever {
+    /// Builds a graph over every entry of `documents` that has an embedding (entries without
+    /// one are left out of the index, same as
+    /// [`InMemoryRetriever`](super::retriever::InMemoryRetriever)), using `DEFAULT_EF` for
+    /// query-time search.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::VectorDimensionMismatch` if the embeddings don't all share a
+    /// dimension.
+    pub fn new(
+        documents: Vec<Document>,
+        metric: SimilarityMetric,
+        m: usize,
+        ef_construction: usize,
+    ) -> Result<Self, OxidbError> {
+        Self::with_ef(documents, metric, m, ef_construction, DEFAULT_EF)
+    }
+
+    /// As [`Self::new`], but also overrides the default query-time `ef`.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::VectorDimensionMismatch` if the embeddings don't all share a
+    /// dimension.
+    pub fn with_ef(
+        documents: Vec<Document>,
+        metric: SimilarityMetric,
+        m: usize,
+        ef_construction: usize,
+        ef: usize,
+    ) -> Result<Self, OxidbError> {
+        let m = m.max(1);
+        let mut retriever = Self {
+            nodes: Vec::new(),
+            entry_point: None,
+            top_layer: 0,
+            metric,
+            m,
+            m_max0: m * 2,
+            ef_construction: ef_construction.max(1),
+            ef: ef.max(1),
+            level_multiplier: 1.0 / (m.max(2) as f64).ln(),
+        };
+
+        for document in documents {
+            retriever.insert(document)?;
+        }
+
+        Ok(retriever)
+    }
+
+    /// Number of documents actually indexed (i.e. that carried an embedding).
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Whether the index holds no documents.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    fn similarity(&self, a: &[f32], b: &[f32]) -> Result<f32, OxidbError> {
+        match self.metric {
+            SimilarityMetric::Cosine => cosine_similarity(a, b),
+            SimilarityMetric::DotProduct => dot_product(a, b),
+            // The graph's construction (neighbor selection, greedy descent) assumes "higher
+            // score is closer" throughout; distance metrics would need it inverted, which
+            // isn't supported here yet.
+            SimilarityMetric::Euclidean | SimilarityMetric::Manhattan => {
+                Err(OxidbError::InvalidInput {
+                    message: format!(
+                        "HnswRetriever does not support {:?} yet; use Cosine or DotProduct",
+                        self.metric
+                    ),
+                })
+            }
+        }
+    }
+
+    /// `P(level >= l) = exp(-l/ln(M))`, sampled via the standard `-ln(uniform) * (1/ln(M))`
+    /// construction.
+    fn random_level(&self) -> usize {
+        let draw: f64 = rand::thread_rng().gen_range(f64::EPSILON..1.0);
+        (-draw.ln() * self.level_multiplier).floor() as usize
+    }
+
+    fn insert(&mut self, document: Document) -> Result<(), OxidbError> {
+        let Some(embedding) = document.embedding.clone() else {
+            return Ok(());
+        };
+        let vector = embedding_vector(&embedding);
+
+        if let Some(existing) = self.nodes.first() {
+            if existing.embedding.len() != vector.len() {
+                return Err(OxidbError::VectorDimensionMismatch {
+                    dim1: existing.embedding.len(),
+                    dim2: vector.len(),
+                });
+            }
+        }
+
+        let Some(entry_point) = self.entry_point else {
+            let level = self.random_level();
+            self.nodes.push(HnswNode {
+                document,
+                embedding: vector,
+                neighbors: vec![Vec::new(); level + 1],
+            });
+            self.entry_point = Some(0);
+            self.top_layer = level;
+            return Ok(());
+        };
+
+        let level = self.random_level();
+        let new_index = self.nodes.len();
+        self.nodes.push(HnswNode {
+            document,
+            embedding: vector.clone(),
+            neighbors: vec![Vec::new(); level + 1],
+        });
+
+        let mut nearest = entry_point;
+        for layer in (level + 1..=self.top_layer).rev() {
+            nearest = self.greedy_descend(&vector, nearest, layer)?;
+        }
+
+        for layer in (0..=level.min(self.top_layer)).rev() {
+            let candidates = self.search_layer(&vector, nearest, self.ef_construction, layer)?;
+            let max_neighbors = if layer == 0 { self.m_max0 } else { self.m };
+            let selected = self.select_diverse_neighbors(&vector, candidates, max_neighbors)?;
+
+            for &neighbor_index in &selected {
+                self.nodes[new_index].neighbors[layer].push(neighbor_index);
+                self.connect(neighbor_index, new_index, layer, max_neighbors)?;
+            }
+            if let Some(&closest) = selected.first() {
+                nearest = closest;
+            }
+        }
+
+        if level > self.top_layer {
+            self.top_layer = level;
+            self.entry_point = Some(new_index);
+        }
+
+        Ok(())
+    }
+
+    /// Links `node_index` to `new_neighbor` at `layer`, pruning back down to `max_neighbors`
+    /// via the same diversity heuristic used for the new node's own neighbor selection if the
+    /// link pushed it over capacity.
+    fn connect(
+        &mut self,
+        node_index: usize,
+        new_neighbor: usize,
+        layer: usize,
+        max_neighbors: usize,
+    ) -> Result<(), OxidbError> {
+        let Some(layer_neighbors) = self.nodes[node_index].neighbors.get_mut(layer) else {
+            return Ok(());
+        };
+        if !layer_neighbors.contains(&new_neighbor) {
+            layer_neighbors.push(new_neighbor);
+        }
+
+        if self.nodes[node_index].neighbors[layer].len() > max_neighbors {
+            let query = self.nodes[node_index].embedding.clone();
+            let candidates = self.nodes[node_index].neighbors[layer].clone();
+            let pruned = self.select_diverse_neighbors(&query, candidates, max_neighbors)?;
+            self.nodes[node_index].neighbors[layer] = pruned;
+        }
+
+        Ok(())
+    }
+
+    /// Greedily walks `layer` from `start` towards whichever neighbor is most similar to
+    /// `query`, stopping once no neighbor improves on the current node.
+    fn greedy_descend(
+        &self,
+        query: &[f32],
+        start: usize,
+        layer: usize,
+    ) -> Result<usize, OxidbError> {
+        let mut best = start;
+        let mut best_similarity = self.similarity(query, &self.nodes[best].embedding)?;
+        loop {
+            let mut improved = false;
+            let neighbors =
+                self.nodes[best].neighbors.get(layer).map(Vec::as_slice).unwrap_or(&[]);
+            for &neighbor_index in neighbors {
+                let similarity = self.similarity(query, &self.nodes[neighbor_index].embedding)?;
+                if similarity > best_similarity {
+                    best = neighbor_index;
+                    best_similarity = similarity;
+                    improved = true;
+                }
+            }
+            if !improved {
+                return Ok(best);
+            }
+        }
+    }
+
+    /// Bounded best-first search at `layer` starting from `entry`, returning up to `ef`
+    /// node indices ordered by descending similarity to `query`.
+    fn search_layer(
+        &self,
+        query: &[f32],
+        entry: usize,
+        ef: usize,
+        layer: usize,
+    ) -> Result<Vec<usize>, OxidbError> {
+        let entry_similarity = self.similarity(query, &self.nodes[entry].embedding)?;
+
+        let mut visited = HashSet::new();
+        visited.insert(entry);
+
+        // Max-heap of candidates still to explore, by similarity (best first).
+        let mut candidates = BinaryHeap::new();
+        candidates.push(Scored { similarity: entry_similarity, index: entry });
+
+        // Min-heap (via `Reverse`) of the best `ef` results found so far, so the worst one is
+        // always at the top and can be evicted in O(log ef) when a better candidate appears.
+        let mut found = BinaryHeap::new();
+        found.push(Reverse(Scored { similarity: entry_similarity, index: entry }));
+
+        while let Some(Scored { similarity: candidate_similarity, index: candidate_index }) =
+            candidates.pop()
+        {
+            let worst_found = found.peek().map_or(f32::NEG_INFINITY, |Reverse(s)| s.similarity);
+            if found.len() >= ef && candidate_similarity < worst_found {
+                break;
+            }
+
+            let neighbors =
+                self.nodes[candidate_index].neighbors.get(layer).map(Vec::as_slice).unwrap_or(&[]);
+            for &neighbor_index in neighbors {
+                if !visited.insert(neighbor_index) {
+                    continue;
+                }
+                let similarity = self.similarity(query, &self.nodes[neighbor_index].embedding)?;
+                let worst_found = found.peek().map_or(f32::NEG_INFINITY, |Reverse(s)| s.similarity);
+                if found.len() < ef || similarity > worst_found {
+                    candidates.push(Scored { similarity, index: neighbor_index });
+                    found.push(Reverse(Scored { similarity, index: neighbor_index }));
+                    if found.len() > ef {
+                        found.pop();
+                    }
+                }
+            }
+        }
+
+        let mut result: Vec<Scored> = found.into_iter().map(|Reverse(s)| s).collect();
+        result.sort_by(|a, b| b.similarity.partial_cmp(&a.similarity).unwrap_or(Ordering::Equal));
+        Ok(result.into_iter().map(|s| s.index).collect())
+    }
+
+    /// Selects up to `max_neighbors` from `candidates` for a node embedded at `query`, keeping
+    /// a candidate only if it is more similar to `query` than it is to any neighbor already
+    /// selected (the paper's diverse-neighbor heuristic), then topping up with the closest
+    /// remaining candidates if the heuristic left room, so nodes never end up under-connected.
+    fn select_diverse_neighbors(
+        &self,
+        query: &[f32],
+        mut candidates: Vec<usize>,
+        max_neighbors: usize,
+    ) -> Result<Vec<usize>, OxidbError> {
+        candidates.sort_by(|&a, &b| {
+            let sim_a = self.similarity(query, &self.nodes[a].embedding).unwrap_or(f32::NEG_INFINITY);
+            let sim_b = self.similarity(query, &self.nodes[b].embedding).unwrap_or(f32::NEG_INFINITY);
+            sim_b.partial_cmp(&sim_a).unwrap_or(Ordering::Equal)
+        });
+
+        let mut selected: Vec<usize> = Vec::with_capacity(max_neighbors.min(candidates.len()));
+        for &candidate in &candidates {
+            if selected.len() >= max_neighbors {
+                break;
+            }
+            let similarity_to_query = self.similarity(query, &self.nodes[candidate].embedding)?;
+            let dominated = selected.iter().any(|&kept| {
+                self.similarity(&self.nodes[candidate].embedding, &self.nodes[kept].embedding)
+                    .map(|similarity_to_kept| similarity_to_kept >= similarity_to_query)
+                    .unwrap_or(false)
+            });
+            if !dominated {
+                selected.push(candidate);
+            }
+        }
+
+        if selected.len() < max_neighbors {
+            for &candidate in &candidates {
+                if selected.len() >= max_neighbors {
+                    break;
+                }
+                if !selected.contains(&candidate) {
+                    selected.push(candidate);
+                }
+            }
+        }
+
+        Ok(selected)
+    }
+}
+
+fn embedding_vector(embedding: &Embedding) -> Vec<f32> {
+    embedding.as_slice().to_vec()
+}
+
+#[async_trait]
+impl Retriever for HnswRetriever {
+    async fn retrieve(
+        &self,
+        query_embedding: &Embedding,
+        top_k: usize,
+        metric: SimilarityMetric,
+    ) -> Result<Vec<Document>, OxidbError> {
+        if top_k == 0 || self.nodes.is_empty() {
+            return Ok(Vec::new());
+        }
+        if metric != self.metric {
+            return Err(OxidbError::InvalidInput {
+                message: format!(
+                    "HnswRetriever was built for {:?} similarity; retrieve() was called with {:?}",
+                    self.metric, metric
+                ),
+            });
+        }
+
+        let Some(entry_point) = self.entry_point else {
+            return Ok(Vec::new());
+        };
+        let query = query_embedding.as_slice();
+
+        let mut nearest = entry_point;
+        for layer in (1..=self.top_layer).rev() {
+            nearest = self.greedy_descend(query, nearest, layer)?;
+        }
+
+        let ef = self.ef.max(top_k);
+        let candidates = self.search_layer(query, nearest, ef, 0)?;
+
+        Ok(candidates
+            .into_iter()
+            .take(top_k)
+            .map(|index| self.nodes[index].document.clone())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::rag::embedder::{EmbeddingModel, MockEmbeddingModel};
+
+    async fn build_retriever(metric: SimilarityMetric) -> HnswRetriever {
+        let model = MockEmbeddingModel { dimension: 2, fixed_embedding_value: None };
+        let docs_content = vec![
+            ("doc1", "apple banana"),
+            ("doc2", "apple orange"),
+            ("doc3", "banana grape"),
+            ("doc4", "totally different"),
+        ];
+
+        let mut documents = Vec::new();
+        for (id, content) in docs_content {
+            let doc = Document::new(id.to_string(), content.to_string());
+            let embedding = model.embed_document(&doc).await.unwrap();
+            documents.push(doc.with_embedding(embedding));
+        }
+
+        if let Some(doc_to_change) = documents.get_mut(2) {
+            doc_to_change.embedding = Some(Embedding::from(vec![0.5, 0.5]));
+        }
+
+        HnswRetriever::new(documents, metric, DEFAULT_M, DEFAULT_EF_CONSTRUCTION).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_hnsw_retriever_matches_brute_force_cosine_top_match() {
+        let retriever = build_retriever(SimilarityMetric::Cosine).await;
+        let query_embedding = Embedding::from(vec![0.5, 0.5]);
+
+        let results =
+            retriever.retrieve(&query_embedding, 1, SimilarityMetric::Cosine).await.unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, "doc3");
+    }
+
+    #[tokio::test]
+    async fn test_hnsw_retriever_dot_product_orders_by_magnitude() {
+        let retriever = build_retriever(SimilarityMetric::DotProduct).await;
+        let query_embedding = Embedding::from(vec![1.0, 0.0]);
+
+        let results =
+            retriever.retrieve(&query_embedding, 2, SimilarityMetric::DotProduct).await.unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].id, "doc3");
+        assert_eq!(results[1].id, "doc4");
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_top_k_zero() {
+        let retriever = build_retriever(SimilarityMetric::Cosine).await;
+        let query_embedding = Embedding::from(vec![0.1, 0.1]);
+        let results =
+            retriever.retrieve(&query_embedding, 0, SimilarityMetric::Cosine).await.unwrap();
+        assert!(results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_more_than_available() {
+        let retriever = build_retriever(SimilarityMetric::Cosine).await;
+        let query_embedding = Embedding::from(vec![0.1, 0.1]);
+        let results =
+            retriever.retrieve(&query_embedding, 10, SimilarityMetric::Cosine).await.unwrap();
+        assert_eq!(results.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_with_mismatched_metric_errors() {
+        let retriever = build_retriever(SimilarityMetric::Cosine).await;
+        let query_embedding = Embedding::from(vec![0.1, 0.1]);
+        let result = retriever.retrieve(&query_embedding, 1, SimilarityMetric::DotProduct).await;
+        assert!(matches!(result, Err(OxidbError::InvalidInput { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_no_documents_with_embeddings() {
+        let retriever =
+            HnswRetriever::new(
+                vec![Document::new("doc1".to_string(), "no embedding here".to_string())],
+                SimilarityMetric::Cosine,
+                DEFAULT_M,
+                DEFAULT_EF_CONSTRUCTION,
+            )
+            .unwrap();
+        let query_embedding = Embedding::from(vec![0.1, 0.1]);
+        let results =
+            retriever.retrieve(&query_embedding, 1, SimilarityMetric::Cosine).await.unwrap();
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_dimension_mismatch_errors() {
+        let documents = vec![
+            Document::new("a".to_string(), "a".to_string())
+                .with_embedding(Embedding::from(vec![0.1, 0.2])),
+            Document::new("b".to_string(), "b".to_string())
+                .with_embedding(Embedding::from(vec![0.1, 0.2, 0.3])),
+        ];
+        let result =
+            HnswRetriever::new(documents, SimilarityMetric::Cosine, DEFAULT_M, DEFAULT_EF_CONSTRUCTION);
+        assert!(matches!(result, Err(OxidbError::VectorDimensionMismatch { .. })));
+    }
+}
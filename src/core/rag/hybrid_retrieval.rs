@@ -0,0 +1,290 @@
+// src/core/rag/hybrid_retrieval.rs
+//! Hybrid keyword + semantic retrieval over a fixed document set.
+//!
+//! [`HybridRetriever`] runs a keyword search — cosine similarity over [`TfIdfEmbedder`]
+//! vectors, which stands in for BM25/TF-IDF scoring since that's the vocabulary-based
+//! embedder this crate already has — and a dense vector search using any
+//! [`EmbeddingModel`] and cosine similarity against each document's precomputed
+//! embedding. The two ranked lists are fused into one, either by Reciprocal Rank Fusion
+//! (order-based, robust to incomparable score scales) or by a convex combination of
+//! min-max normalized scores, selectable via [`FusionMode`]. Unlike [`super::hybrid`]'s
+//! `HybridRAGEngine`, which blends vector similarity with graph-relationship scoring,
+//! this module blends two rankings of the *same* document set by two different text
+//! representations.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::core::common::OxidbError;
+use crate::core::vector::similarity::cosine_similarity;
+
+use super::core_components::{Document, Embedding};
+use super::embedder::{EmbeddingModel, TfIdfEmbedder};
+
+/// How the keyword and vector ranked lists are combined into one fused score.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FusionMode {
+    /// For each document, accumulate `1 / (k + rank + 1)` (0-based rank) per list it
+    /// appears in and sum across lists. Order-based, so it's immune to the two lists
+    /// using incomparable score scales.
+    ReciprocalRankFusion {
+        /// Rank-discount constant; higher `k` flattens the influence of rank. 60 is the
+        /// standard default from the original RRF paper.
+        k: f32,
+    },
+    /// `semantic_ratio * vector_score + (1 - semantic_ratio) * keyword_score`, after
+    /// min-max normalizing each list's raw scores to `[0, 1]` so the two scales are
+    /// comparable before blending.
+    ConvexCombination,
+}
+
+/// Configuration for [`HybridRetriever::search`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HybridRetrieverConfig {
+    /// Weight given to the dense/semantic ranking in [`FusionMode::ConvexCombination`];
+    /// ignored by [`FusionMode::ReciprocalRankFusion`], which weighs both lists equally
+    /// by construction. Expected in `[0.0, 1.0]`.
+    pub semantic_ratio: f32,
+    pub fusion_mode: FusionMode,
+}
+
+impl Default for HybridRetrieverConfig {
+    fn default() -> Self {
+        Self { semantic_ratio: 0.5, fusion_mode: FusionMode::ReciprocalRankFusion { k: 60.0 } }
+    }
+}
+
+/// One document's hybrid search result, with the per-method scores that fed into the
+/// fused score so callers can debug ranking. `None` means the document didn't appear in
+/// that list at all (e.g. it has no dense embedding, so it's absent from vector search).
+#[derive(Debug, Clone)]
+pub struct HybridSearchResult {
+    pub document: Document,
+    pub keyword_score: Option<f32>,
+    pub vector_score: Option<f32>,
+    pub fused_score: f32,
+}
+
+/// Combines a keyword search (TF-IDF cosine similarity) and a dense vector search
+/// (any [`EmbeddingModel`] + cosine similarity) over a fixed document set, fusing their
+/// rankings per [`HybridRetrieverConfig`]. Documents must already carry a dense
+/// [`Embedding`] (see [`Document::with_embedding`]) to participate in vector search;
+/// keyword search covers every document regardless, since the TF-IDF vocabulary is
+/// built from the document set itself.
+pub struct HybridRetriever<E: EmbeddingModel + Send + Sync> {
+    documents: Vec<Document>,
+    keyword_embedder: TfIdfEmbedder,
+    dense_embedder: Arc<E>,
+    config: HybridRetrieverConfig,
+}
+
+impl<E: EmbeddingModel + Send + Sync> HybridRetriever<E> {
+    /// Build a retriever over `documents`, fitting the keyword embedder's vocabulary to
+    /// them. `dense_embedder` is used to embed the query for vector search; documents
+    /// should already carry their own dense embeddings via [`Document::with_embedding`].
+    #[must_use]
+    pub fn new(documents: Vec<Document>, dense_embedder: Arc<E>) -> Self {
+        let keyword_embedder = TfIdfEmbedder::new(&documents);
+        Self { documents, keyword_embedder, dense_embedder, config: HybridRetrieverConfig::default() }
+    }
+
+    /// Replace the fusion configuration (builder pattern).
+    #[must_use]
+    pub fn with_config(mut self, config: HybridRetrieverConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Run the keyword and vector searches and fuse their rankings, returning the
+    /// top `top_k` documents by fused score, descending.
+    ///
+    /// # Errors
+    ///
+    /// Propagates any [`OxidbError`] raised while embedding the query.
+    pub async fn search(&self, query: &str, top_k: usize) -> Result<Vec<HybridSearchResult>, OxidbError> {
+        let keyword_ranked = self.keyword_search(query).await?;
+        let vector_ranked = self.vector_search(query).await?;
+
+        let fused = match self.config.fusion_mode {
+            FusionMode::ReciprocalRankFusion { k } => reciprocal_rank_fusion(&keyword_ranked, &vector_ranked, k),
+            FusionMode::ConvexCombination => {
+                convex_combination(&keyword_ranked, &vector_ranked, self.config.semantic_ratio)
+            }
+        };
+
+        let keyword_by_id: HashMap<&str, f32> =
+            keyword_ranked.iter().map(|(id, score)| (id.as_str(), *score)).collect();
+        let vector_by_id: HashMap<&str, f32> =
+            vector_ranked.iter().map(|(id, score)| (id.as_str(), *score)).collect();
+
+        let mut results: Vec<HybridSearchResult> = self
+            .documents
+            .iter()
+            .filter_map(|doc| {
+                fused.get(doc.id.as_str()).map(|&fused_score| HybridSearchResult {
+                    document: doc.clone(),
+                    keyword_score: keyword_by_id.get(doc.id.as_str()).copied(),
+                    vector_score: vector_by_id.get(doc.id.as_str()).copied(),
+                    fused_score,
+                })
+            })
+            .collect();
+
+        results.sort_by(|a, b| b.fused_score.partial_cmp(&a.fused_score).unwrap_or(std::cmp::Ordering::Equal));
+        results.truncate(top_k);
+        Ok(results)
+    }
+
+    /// Rank every document by TF-IDF cosine similarity to the query, descending.
+    async fn keyword_search(&self, query: &str) -> Result<Vec<(String, f32)>, OxidbError> {
+        let query_embedding = self.keyword_embedder.embed(query).await?;
+        let mut ranked = Vec::with_capacity(self.documents.len());
+        for doc in &self.documents {
+            let doc_embedding = self.keyword_embedder.embed_document(doc).await?;
+            ranked.push((doc.id.clone(), safe_cosine(&query_embedding, &doc_embedding)));
+        }
+        ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        Ok(ranked)
+    }
+
+    /// Rank documents that carry a dense embedding by cosine similarity to the query,
+    /// descending. Documents without an embedding are omitted rather than scored as 0,
+    /// since they simply didn't participate in vector search.
+    async fn vector_search(&self, query: &str) -> Result<Vec<(String, f32)>, OxidbError> {
+        let query_embedding = self.dense_embedder.embed(query).await?;
+        let mut ranked: Vec<(String, f32)> = self
+            .documents
+            .iter()
+            .filter_map(|doc| {
+                let doc_embedding = doc.embedding.as_ref()?;
+                Some((doc.id.clone(), safe_cosine(&query_embedding, doc_embedding)))
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        Ok(ranked)
+    }
+}
+
+/// Cosine similarity, treating a zero-magnitude vector (no vocabulary overlap with the
+/// query, say) as a similarity of `0.0` rather than an error.
+fn safe_cosine(a: &Embedding, b: &Embedding) -> f32 {
+    cosine_similarity(a.as_slice(), b.as_slice()).unwrap_or(0.0)
+}
+
+/// Reciprocal Rank Fusion: for each document appearing in either ranked list at
+/// (0-based) rank `r`, accumulate `1 / (k + r + 1)` per list, summed across both lists.
+fn reciprocal_rank_fusion(
+    keyword_ranked: &[(String, f32)],
+    vector_ranked: &[(String, f32)],
+    k: f32,
+) -> HashMap<String, f32> {
+    let mut fused: HashMap<String, f32> = HashMap::new();
+    for ranked in [keyword_ranked, vector_ranked] {
+        for (rank, (id, _score)) in ranked.iter().enumerate() {
+            *fused.entry(id.clone()).or_insert(0.0) += 1.0 / (k + rank as f32 + 1.0);
+        }
+    }
+    fused
+}
+
+/// Convex combination of min-max normalized scores:
+/// `semantic_ratio * vector_norm + (1 - semantic_ratio) * keyword_norm`. A document
+/// absent from a list contributes `0.0` for that side.
+fn convex_combination(
+    keyword_ranked: &[(String, f32)],
+    vector_ranked: &[(String, f32)],
+    semantic_ratio: f32,
+) -> HashMap<String, f32> {
+    let keyword_norm = min_max_normalize(keyword_ranked);
+    let vector_norm = min_max_normalize(vector_ranked);
+
+    let mut fused: HashMap<String, f32> = HashMap::new();
+    for (id, score) in &keyword_norm {
+        *fused.entry(id.clone()).or_insert(0.0) += (1.0 - semantic_ratio) * score;
+    }
+    for (id, score) in &vector_norm {
+        *fused.entry(id.clone()).or_insert(0.0) += semantic_ratio * score;
+    }
+    fused
+}
+
+/// Min-max normalize a ranked list's scores to `[0, 1]`. A list where every score is
+/// equal (including a single-element list) normalizes every entry to `1.0`, since there
+/// is no basis to rank them apart.
+fn min_max_normalize(ranked: &[(String, f32)]) -> HashMap<String, f32> {
+    let min = ranked.iter().map(|(_, s)| *s).fold(f32::INFINITY, f32::min);
+    let max = ranked.iter().map(|(_, s)| *s).fold(f32::NEG_INFINITY, f32::max);
+    let range = max - min;
+    ranked
+        .iter()
+        .map(|(id, score)| {
+            let normalized = if range > 0.0 { (score - min) / range } else { 1.0 };
+            (id.clone(), normalized)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::rag::embedder::MockEmbeddingModel;
+
+    fn sample_documents() -> Vec<Document> {
+        vec![
+            Document::new("doc1".to_string(), "apple banana cherry".to_string())
+                .with_embedding(Embedding::from(vec![1.0, 0.0])),
+            Document::new("doc2".to_string(), "banana cherry date".to_string())
+                .with_embedding(Embedding::from(vec![0.0, 1.0])),
+            Document::new("doc3".to_string(), "cherry date elderberry".to_string()),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_rrf_fuses_agreeing_lists_to_the_top() {
+        let documents = sample_documents();
+        let dense_embedder = Arc::new(MockEmbeddingModel { dimension: 2, fixed_embedding_value: Some(1.0) });
+        let retriever = HybridRetriever::new(documents, dense_embedder);
+
+        let results = retriever.search("cherry", 3).await.unwrap();
+        assert_eq!(results.len(), 3);
+        // Every result should carry a keyword score (TF-IDF covers all documents).
+        assert!(results.iter().all(|r| r.keyword_score.is_some()));
+        // doc3 has no embedding, so it never appears in vector search.
+        let doc3 = results.iter().find(|r| r.document.id == "doc3").unwrap();
+        assert!(doc3.vector_score.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_convex_combination_respects_semantic_ratio() {
+        let documents = sample_documents();
+        let dense_embedder = Arc::new(MockEmbeddingModel { dimension: 2, fixed_embedding_value: None });
+        let config = HybridRetrieverConfig { semantic_ratio: 1.0, fusion_mode: FusionMode::ConvexCombination };
+        let retriever = HybridRetriever::new(documents, dense_embedder).with_config(config);
+
+        let results = retriever.search("cherry", 3).await.unwrap();
+        // semantic_ratio = 1.0 means the fused score is purely the normalized vector
+        // score; doc3 (no embedding, so no vector score) must fuse to 0.0.
+        let doc3 = results.iter().find(|r| r.document.id == "doc3").unwrap();
+        assert_eq!(doc3.fused_score, 0.0);
+    }
+
+    #[test]
+    fn test_reciprocal_rank_fusion_sums_across_lists() {
+        let keyword_ranked = vec![("a".to_string(), 0.9), ("b".to_string(), 0.5)];
+        let vector_ranked = vec![("b".to_string(), 0.8), ("a".to_string(), 0.3)];
+
+        let fused = reciprocal_rank_fusion(&keyword_ranked, &vector_ranked, 60.0);
+
+        // Both documents are rank 0 in one list and rank 1 in the other, so they tie.
+        assert!((fused["a"] - fused["b"]).abs() < 1e-6);
+        assert!((fused["a"] - (1.0 / 61.0 + 1.0 / 62.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_min_max_normalize_handles_uniform_scores() {
+        let ranked = vec![("a".to_string(), 0.5), ("b".to_string(), 0.5)];
+        let normalized = min_max_normalize(&ranked);
+        assert_eq!(normalized["a"], 1.0);
+        assert_eq!(normalized["b"], 1.0);
+    }
+}
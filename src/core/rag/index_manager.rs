@@ -0,0 +1,247 @@
+// src/core/rag/index_manager.rs
+
+//! Debounced incremental re-indexing coordinator.
+//!
+//! Keeping embeddings eagerly in sync with a changing document set means either re-embedding
+//! one document at a time or blocking writers on a full atomic flush. [`IndexManager`]
+//! instead tracks dirty document ids (added/updated/removed) and reconciles them on a
+//! background task that fires only after a debounce interval of quiet, so a burst of edits
+//! collapses into one batched flush instead of one embedding call per edit.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::time::sleep;
+
+use crate::core::common::OxidbError;
+
+use super::caching_embedder::EmbeddingQueue;
+use super::core_components::Document;
+use super::embedder::EmbeddingModel;
+use super::retriever::InMemoryRetriever;
+
+enum DirtyOp {
+    Upsert(Document),
+    Remove(String),
+}
+
+/// Configuration for [`IndexManager`]'s debounce window.
+#[derive(Debug, Clone, Copy)]
+pub struct IndexManagerConfig {
+    /// How long to wait after the most recent change before flushing the dirty set.
+    pub debounce: Duration,
+}
+
+impl Default for IndexManagerConfig {
+    fn default() -> Self {
+        Self { debounce: Duration::from_millis(500) }
+    }
+}
+
+/// Coordinates incremental re-indexing of an [`InMemoryRetriever`] on a debounce.
+///
+/// [`mark_dirty`](Self::mark_dirty)/[`mark_removed`](Self::mark_removed) record a change
+/// and (re)schedule a background flush after the debounce interval; a burst of changes
+/// inside that window collapses into a single flush, since each new change bumps a
+/// generation counter that the previously scheduled flush checks before running.
+/// [`flush_now`](Self::flush_now) bypasses the debounce and reconciles immediately.
+pub struct IndexManager<M: EmbeddingModel + Send + Sync + 'static> {
+    retriever: Arc<Mutex<InMemoryRetriever>>,
+    embedder: Arc<M>,
+    config: IndexManagerConfig,
+    dirty: Arc<Mutex<HashMap<String, DirtyOp>>>,
+    generation: Arc<AtomicU64>,
+}
+
+impl<M: EmbeddingModel + Send + Sync + 'static> IndexManager<M> {
+    #[must_use]
+    pub fn new(
+        retriever: Arc<Mutex<InMemoryRetriever>>,
+        embedder: Arc<M>,
+        config: IndexManagerConfig,
+    ) -> Self {
+        Self {
+            retriever,
+            embedder,
+            config,
+            dirty: Arc::new(Mutex::new(HashMap::new())),
+            generation: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Marks `document` as needing (re-)embedding and schedules a debounced flush.
+    pub async fn mark_dirty(&self, document: Document) {
+        let id = document.id.clone();
+        self.dirty.lock().unwrap().insert(id, DirtyOp::Upsert(document));
+        self.schedule_flush();
+    }
+
+    /// Marks the document with `id` for removal from the index and schedules a debounced
+    /// flush.
+    pub async fn mark_removed(&self, id: &str) {
+        self.dirty.lock().unwrap().insert(id.to_string(), DirtyOp::Remove(id.to_string()));
+        self.schedule_flush();
+    }
+
+    /// Bumps the generation counter and spawns a task that, after the debounce window,
+    /// flushes only if no newer change has bumped the counter again in the meantime.
+    fn schedule_flush(&self) {
+        let generation = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+        let generation_marker = Arc::clone(&self.generation);
+        let dirty = Arc::clone(&self.dirty);
+        let retriever = Arc::clone(&self.retriever);
+        let embedder = Arc::clone(&self.embedder);
+        let debounce = self.config.debounce;
+
+        tokio::spawn(async move {
+            sleep(debounce).await;
+            if generation_marker.load(Ordering::SeqCst) != generation {
+                // A newer change landed during the debounce window; its own scheduled
+                // flush will cover this one too, so there's nothing to do here.
+                return;
+            }
+            let _ = reconcile(&dirty, &retriever, embedder.as_ref()).await;
+        });
+    }
+
+    /// Immediately reconciles whatever is currently dirty, bypassing the debounce window.
+    ///
+    /// # Errors
+    /// Propagates any `OxidbError` raised while embedding the dirty batch.
+    pub async fn flush_now(&self) -> Result<(), OxidbError> {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        reconcile(&self.dirty, &self.retriever, self.embedder.as_ref()).await
+    }
+}
+
+/// Drains the dirty set and atomically swaps the result into `retriever`: removals are
+/// applied, and every upsert is embedded (as one token-budgeted, all-or-nothing
+/// [`EmbeddingQueue`] flush) before any of them are written into the retriever.
+async fn reconcile<M: EmbeddingModel + Send + Sync>(
+    dirty: &Mutex<HashMap<String, DirtyOp>>,
+    retriever: &Mutex<InMemoryRetriever>,
+    embedder: &M,
+) -> Result<(), OxidbError> {
+    let batch: Vec<DirtyOp> = {
+        let mut dirty = dirty.lock().unwrap();
+        dirty.drain().map(|(_, op)| op).collect()
+    };
+    if batch.is_empty() {
+        return Ok(());
+    }
+
+    let mut queue = EmbeddingQueue::default();
+    let mut removals = Vec::new();
+    for op in batch {
+        match op {
+            DirtyOp::Upsert(document) => queue.enqueue(document),
+            DirtyOp::Remove(id) => removals.push(id),
+        }
+    }
+
+    let embedded = queue.flush(embedder).await?;
+
+    let mut retriever = retriever.lock().unwrap();
+    for id in &removals {
+        retriever.remove_document(id);
+    }
+    for (document, embedding) in embedded {
+        retriever.upsert_document(document.with_embedding(embedding));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::rag::core_components::Embedding;
+    use crate::core::rag::embedder::MockEmbeddingModel;
+    use crate::core::rag::retriever::{Retriever, SimilarityMetric};
+
+    fn manager(
+        debounce: Duration,
+    ) -> (IndexManager<MockEmbeddingModel>, Arc<Mutex<InMemoryRetriever>>) {
+        let retriever = Arc::new(Mutex::new(InMemoryRetriever::new(Vec::new())));
+        let embedder = Arc::new(MockEmbeddingModel { dimension: 2, fixed_embedding_value: Some(0.4) });
+        let manager = IndexManager::new(
+            Arc::clone(&retriever),
+            embedder,
+            IndexManagerConfig { debounce },
+        );
+        (manager, retriever)
+    }
+
+    #[tokio::test]
+    async fn test_flush_now_embeds_and_upserts_dirty_documents() {
+        let (manager, retriever) = manager(Duration::from_secs(60));
+        manager.mark_dirty(Document::new("doc1".to_string(), "hello".to_string())).await;
+        manager.mark_dirty(Document::new("doc2".to_string(), "world".to_string())).await;
+
+        manager.flush_now().await.unwrap();
+
+        let snapshot = retriever.lock().unwrap();
+        let query_embedding = Embedding::from(vec![0.4, 0.4]);
+        let results =
+            snapshot.retrieve(&query_embedding, 10, SimilarityMetric::Cosine).await.unwrap();
+        assert_eq!(results.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_flush_now_purges_removed_documents() {
+        let (manager, retriever) = manager(Duration::from_secs(60));
+        manager.mark_dirty(Document::new("doc1".to_string(), "hello".to_string())).await;
+        manager.flush_now().await.unwrap();
+
+        manager.mark_removed("doc1").await;
+        manager.flush_now().await.unwrap();
+
+        let snapshot = retriever.lock().unwrap();
+        let query_embedding = Embedding::from(vec![0.4, 0.4]);
+        let results =
+            snapshot.retrieve(&query_embedding, 10, SimilarityMetric::Cosine).await.unwrap();
+        assert!(results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_debounced_flush_waits_for_quiet_then_applies() {
+        let (manager, retriever) = manager(Duration::from_millis(30));
+        manager.mark_dirty(Document::new("doc1".to_string(), "hello".to_string())).await;
+
+        // Immediately after marking dirty, the debounced flush hasn't fired yet.
+        assert!(retriever.lock().unwrap().retrieve(
+            &Embedding::from(vec![0.4, 0.4]),
+            10,
+            SimilarityMetric::Cosine,
+        ).await.unwrap().is_empty());
+
+        sleep(Duration::from_millis(100)).await;
+
+        let results = retriever
+            .lock()
+            .unwrap()
+            .retrieve(&Embedding::from(vec![0.4, 0.4]), 10, SimilarityMetric::Cosine)
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_rapid_changes_within_debounce_collapse_into_one_flush() {
+        let (manager, retriever) = manager(Duration::from_millis(50));
+        manager.mark_dirty(Document::new("doc1".to_string(), "first".to_string())).await;
+        sleep(Duration::from_millis(10)).await;
+        manager.mark_dirty(Document::new("doc1".to_string(), "second".to_string())).await;
+
+        sleep(Duration::from_millis(150)).await;
+
+        let snapshot = retriever.lock().unwrap();
+        let query_embedding = Embedding::from(vec![0.4, 0.4]);
+        let results =
+            snapshot.retrieve(&query_embedding, 10, SimilarityMetric::Cosine).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].content, "second");
+    }
+}
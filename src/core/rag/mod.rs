@@ -1,17 +1,41 @@
 // src/core/rag/mod.rs
 
-pub mod document;
+pub mod bm25_hybrid_retriever;
+pub mod caching_embedder;
+pub mod chunker;
+pub mod core_components;
+// `document` is an alias for `core_components` kept for call sites written against that name.
+pub use self::core_components as document;
 pub mod embedder;
 pub mod graphrag;
+pub mod hnsw_retriever;
 pub mod hybrid;
+pub mod hybrid_retrieval;
+pub mod index_manager;
+pub mod pretrained_embedder;
+pub mod product_quantization;
+pub mod prompt_template;
+pub mod remote_embedder;
 pub mod retriever;
 
 // Re-export key components for easier access
-pub use self::document::{Document, Embedding};
+pub use self::bm25_hybrid_retriever::{Bm25HybridRetriever, FusedResult, HybridQuery};
+pub use self::caching_embedder::{CachingEmbedder, EmbeddingQueue, EmbeddingQueueConfig};
+pub use self::chunker::{Chunk, ChunkerConfig, CharsPerTokenEstimator, DocumentChunk, DocumentChunker, TokenEstimator};
+pub use self::core_components::{Document, Embedding};
 pub use self::embedder::{EmbeddingModel, SemanticEmbedder, TfIdfEmbedder};
 pub use self::graphrag::{
     GraphRAGContext, GraphRAGEngine, GraphRAGResult, KnowledgeEdge, KnowledgeNode,
     GraphRAGConfig, GraphRAGEngineBuilder,
 };
+pub use self::hnsw_retriever::HnswRetriever;
 pub use self::hybrid::{HybridRAGConfig, HybridRAGEngine, HybridRAGEngineBuilder, HybridRAGResult};
+pub use self::hybrid_retrieval::{
+    FusionMode, HybridRetriever, HybridRetrieverConfig, HybridSearchResult,
+};
+pub use self::index_manager::{IndexManager, IndexManagerConfig};
+pub use self::pretrained_embedder::PretrainedEmbedder;
+pub use self::product_quantization::{quantize, quantize_with_centroids, PqCodebooks, QuantizedEmbedding};
+pub use self::prompt_template::{PromptTemplate, TemplatedEmbedder};
+pub use self::remote_embedder::{EmbeddingProvider, OllamaProvider, OpenAiStyleProvider, RemoteEmbedder};
 pub use self::retriever::Retriever;
@@ -0,0 +1,537 @@
+// src/core/rag/pretrained_embedder.rs
+//! Loads pretrained word-embedding files and embeds documents by mean-pooling their
+//! token vectors, with subword fallback for words the training vocabulary never saw.
+//!
+//! Two file formats are supported:
+//! - **word2vec**, both the plain-text form (`<vocab> <dim>` header, then one
+//!   `word f1 f2 ... fN` line per entry) and the classic binary form (same header,
+//!   then for each entry a space-terminated word followed by `dim` little-endian
+//!   `f32`s).
+//! - A **chunked format** inspired by finalfusion's file layout: a `FiFu` magic,
+//!   a version, then a sequence of length-prefixed chunks so new chunk types can be
+//!   added (and skipped by older readers) without breaking the format. This reader
+//!   understands a simple vocabulary chunk, a dense embedding-matrix chunk, and a
+//!   bucketed subword chunk — not the full finalfusion chunk matrix (quantized
+//!   storage, memory-mapped arrays, metadata), mirroring how [`super::embedder::TfIdfEmbedder`]
+//!   is a self-contained approximation of TF-IDF rather than a full IR library.
+//!
+//! Unlike [`super::embedder::TfIdfEmbedder`], which silently drops any word outside
+//! its training vocabulary, [`PretrainedEmbedder`] can fall back to subword
+//! character n-grams (`<wo`, `wor`, `ord`, `rd>`, ...) for an out-of-vocabulary word:
+//! each n-gram is hashed into one of a fixed number of buckets and the bucket
+//! vectors are averaged, so even unseen words get a (coarser) embedding.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::io::{BufReader, Read};
+use std::iter::Peekable;
+use std::path::Path;
+
+use async_trait::async_trait;
+
+use crate::core::common::OxidbError;
+
+use super::core_components::{Document, Embedding};
+use super::embedder::EmbeddingModel;
+
+const FINALFUSION_MAGIC: [u8; 4] = *b"FiFu";
+const CHUNK_SIMPLE_VOCAB: u8 = 0;
+const CHUNK_EMBEDDINGS: u8 = 1;
+const CHUNK_BUCKET_SUBWORDS: u8 = 2;
+
+/// Bucketed subword vectors, as loaded from a [`CHUNK_BUCKET_SUBWORDS`] chunk: every
+/// character n-gram of a word hashes into one of `buckets`, whose vectors are averaged
+/// to approximate an embedding for a word the training vocabulary never saw.
+struct BucketSubwords {
+    min_n: usize,
+    max_n: usize,
+    buckets: Vec<Vec<f32>>,
+}
+
+impl BucketSubwords {
+    fn vector_for(&self, word: &str) -> Option<Vec<f32>> {
+        if self.buckets.is_empty() {
+            return None;
+        }
+        let ngrams = char_ngrams(word, self.min_n, self.max_n);
+        if ngrams.is_empty() {
+            return None;
+        }
+
+        let dimension = self.buckets[0].len();
+        let mut sum = vec![0.0_f32; dimension];
+        for ngram in &ngrams {
+            let bucket = &self.buckets[hash_ngram(ngram) % self.buckets.len()];
+            for (total, value) in sum.iter_mut().zip(bucket) {
+                *total += value;
+            }
+        }
+        for total in &mut sum {
+            *total /= ngrams.len() as f32;
+        }
+        Some(sum)
+    }
+}
+
+/// Character n-grams of `word`, bracketed with `<`/`>` the way fastText-style subword
+/// models do (so `"in"` contributes a boundary-aware `<in>` rather than being confused
+/// with the middle of a longer word), for every length in `min_n..=max_n`.
+fn char_ngrams(word: &str, min_n: usize, max_n: usize) -> Vec<String> {
+    let bracketed: Vec<char> = format!("<{}>", word.to_lowercase()).chars().collect();
+    let mut ngrams = Vec::new();
+    for n in min_n..=max_n.min(bracketed.len()) {
+        for start in 0..=(bracketed.len() - n) {
+            ngrams.push(bracketed[start..start + n].iter().collect());
+        }
+    }
+    ngrams
+}
+
+fn hash_ngram(ngram: &str) -> usize {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    ngram.hash(&mut hasher);
+    hasher.finish() as usize
+}
+
+/// An [`EmbeddingModel`] backed by a pretrained word-vector table, loaded from a
+/// word2vec file ([`Self::from_word2vec_text`] / [`Self::from_word2vec_binary`]) or a
+/// chunked file ([`Self::from_finalfusion`]). Documents are embedded by mean-pooling
+/// their tokens' vectors and L2-normalizing the result.
+pub struct PretrainedEmbedder {
+    vocab: HashMap<String, Vec<f32>>,
+    subwords: Option<BucketSubwords>,
+    pub dimension: usize,
+}
+
+impl PretrainedEmbedder {
+    /// Load a plain-text word2vec file: a `<vocab_size> <dim>` header line, then one
+    /// `word f1 f2 ... fN` line per vocabulary entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read or is malformed (missing header
+    /// fields, a vector with the wrong dimension, or a non-numeric component).
+    pub fn from_word2vec_text(path: impl AsRef<Path>) -> Result<Self, OxidbError> {
+        let file = File::open(path).map_err(OxidbError::Io)?;
+        Self::read_word2vec_text(BufReader::new(file))
+    }
+
+    fn read_word2vec_text(reader: impl Read) -> Result<Self, OxidbError> {
+        let mut lines = std::io::BufRead::lines(std::io::BufReader::new(reader));
+        let header = lines
+            .next()
+            .ok_or_else(|| OxidbError::Deserialization("empty word2vec text file".to_string()))?
+            .map_err(OxidbError::Io)?;
+        let (_vocab_size, dimension) = parse_header(&header)?;
+
+        let mut vocab = HashMap::new();
+        for line in lines {
+            let line = line.map_err(OxidbError::Io)?;
+            let mut fields = line.split_whitespace();
+            let word = fields
+                .next()
+                .ok_or_else(|| OxidbError::Deserialization("word2vec line missing a word".to_string()))?
+                .to_string();
+            let vector = fields
+                .map(|field| {
+                    field.parse::<f32>().map_err(|e| OxidbError::Deserialization(e.to_string()))
+                })
+                .collect::<Result<Vec<f32>, OxidbError>>()?;
+            if vector.len() != dimension {
+                return Err(OxidbError::Deserialization(format!(
+                    "word2vec entry for {word:?} has {} components, expected {dimension}",
+                    vector.len()
+                )));
+            }
+            vocab.insert(word, vector);
+        }
+
+        Ok(Self { vocab, subwords: None, dimension })
+    }
+
+    /// Load a classic binary word2vec file: a `<vocab_size> <dim>` ASCII header line,
+    /// then for each entry a space-terminated word followed by `dim` little-endian
+    /// `f32`s (optionally followed by a trailing newline before the next entry).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read or ends before the declared
+    /// vocabulary and vectors are fully read.
+    pub fn from_word2vec_binary(path: impl AsRef<Path>) -> Result<Self, OxidbError> {
+        let file = File::open(path).map_err(OxidbError::Io)?;
+        Self::read_word2vec_binary(BufReader::new(file))
+    }
+
+    fn read_word2vec_binary(reader: impl Read) -> Result<Self, OxidbError> {
+        let mut bytes = reader.bytes().peekable();
+        let header = read_ascii_line(&mut bytes)?;
+        let (vocab_size, dimension) = parse_header(&header)?;
+
+        let mut vocab = HashMap::with_capacity(vocab_size);
+        for _ in 0..vocab_size {
+            let word = read_word(&mut bytes)?;
+            let mut vector = Vec::with_capacity(dimension);
+            for _ in 0..dimension {
+                vector.push(read_f32_le(&mut bytes)?);
+            }
+            consume_optional_newline(&mut bytes);
+            vocab.insert(word, vector);
+        }
+
+        Ok(Self { vocab, subwords: None, dimension })
+    }
+
+    /// Load a chunked embedding file (see the module docs for the format this reader
+    /// understands): a `FiFu` magic, a version, then length-prefixed chunks carrying
+    /// the vocabulary, the embedding matrix, and (optionally) bucketed subword vectors
+    /// for out-of-vocabulary fallback.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read, the magic/version don't match, or a
+    /// chunk's declared length runs past the end of the file.
+    pub fn from_finalfusion(path: impl AsRef<Path>) -> Result<Self, OxidbError> {
+        let file = File::open(path).map_err(OxidbError::Io)?;
+        Self::read_finalfusion(BufReader::new(file))
+    }
+
+    fn read_finalfusion(reader: impl Read) -> Result<Self, OxidbError> {
+        let mut bytes = reader.bytes().peekable();
+
+        let magic = read_exact_bytes(&mut bytes, 4)?;
+        if magic != FINALFUSION_MAGIC {
+            return Err(OxidbError::Deserialization("not a recognized chunked embedding file (bad magic)".to_string()));
+        }
+        let version = read_u32_le(&mut bytes)?;
+        if version != 1 {
+            return Err(OxidbError::Deserialization(format!("unsupported chunked embedding file version {version}")));
+        }
+
+        let mut words: Vec<String> = Vec::new();
+        let mut matrix: Vec<Vec<f32>> = Vec::new();
+        let mut subwords: Option<BucketSubwords> = None;
+
+        while let Some(chunk_type) = read_byte_opt(&mut bytes)? {
+            let chunk_len = read_u64_le(&mut bytes)?;
+            match chunk_type {
+                CHUNK_SIMPLE_VOCAB => words = read_simple_vocab(&mut bytes)?,
+                CHUNK_EMBEDDINGS => matrix = read_embeddings(&mut bytes)?,
+                CHUNK_BUCKET_SUBWORDS => subwords = Some(read_bucket_subwords(&mut bytes)?),
+                _unknown_chunk => {
+                    // Forward-compatible with future chunk types: skip what we don't
+                    // understand instead of failing the whole load.
+                    skip_bytes(&mut bytes, chunk_len)?;
+                }
+            }
+        }
+
+        if words.len() != matrix.len() {
+            return Err(OxidbError::Deserialization(format!(
+                "vocabulary has {} words but embedding matrix has {} rows",
+                words.len(),
+                matrix.len()
+            )));
+        }
+
+        let dimension = matrix.first().map_or(0, Vec::len);
+        let vocab = words.into_iter().zip(matrix).collect();
+        Ok(Self { vocab, subwords, dimension })
+    }
+
+    /// The vector for `word`: its trained vector if present, otherwise a subword
+    /// fallback built from bucketed character n-grams (if the file carried subword
+    /// buckets), otherwise `None`.
+    fn vector_for_word(&self, word: &str) -> Option<Vec<f32>> {
+        self.vocab.get(word).cloned().or_else(|| self.subwords.as_ref().and_then(|subwords| subwords.vector_for(word)))
+    }
+
+    /// Mean-pool every resolvable token's vector and L2-normalize the result.
+    fn embed_text(&self, text: &str) -> Embedding {
+        let mut sum = vec![0.0_f32; self.dimension];
+        let mut resolved = 0usize;
+        for token in tokenize(text) {
+            if let Some(vector) = self.vector_for_word(&token) {
+                for (total, value) in sum.iter_mut().zip(&vector) {
+                    *total += value;
+                }
+                resolved += 1;
+            }
+        }
+        if resolved > 0 {
+            for total in &mut sum {
+                *total /= resolved as f32;
+            }
+        }
+        l2_normalize(&mut sum);
+        Embedding::from(sum)
+    }
+}
+
+#[async_trait]
+impl EmbeddingModel for PretrainedEmbedder {
+    async fn embed_document(&self, document: &Document) -> Result<Embedding, OxidbError> {
+        Ok(self.embed_text(&document.content))
+    }
+
+    async fn embed(&self, text: &str) -> Result<Embedding, OxidbError> {
+        Ok(self.embed_text(text))
+    }
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.to_lowercase().split(|c: char| !c.is_alphanumeric()).filter(|s| !s.is_empty()).map(String::from).collect()
+}
+
+fn l2_normalize(vector: &mut [f32]) {
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        for value in vector {
+            *value /= norm;
+        }
+    }
+}
+
+fn parse_header(header: &str) -> Result<(usize, usize), OxidbError> {
+    let mut fields = header.split_whitespace();
+    let vocab_size = fields
+        .next()
+        .and_then(|f| f.parse::<usize>().ok())
+        .ok_or_else(|| OxidbError::Deserialization("word2vec header missing vocab size".to_string()))?;
+    let dimension = fields
+        .next()
+        .and_then(|f| f.parse::<usize>().ok())
+        .ok_or_else(|| OxidbError::Deserialization("word2vec header missing dimension".to_string()))?;
+    Ok((vocab_size, dimension))
+}
+
+fn read_byte_opt<R: Read>(bytes: &mut Peekable<std::io::Bytes<R>>) -> Result<Option<u8>, OxidbError> {
+    match bytes.next() {
+        Some(byte) => Ok(Some(byte.map_err(OxidbError::Io)?)),
+        None => Ok(None),
+    }
+}
+
+fn read_exact_bytes<R: Read>(bytes: &mut Peekable<std::io::Bytes<R>>, count: usize) -> Result<Vec<u8>, OxidbError> {
+    (0..count)
+        .map(|_| {
+            bytes
+                .next()
+                .ok_or_else(|| OxidbError::Deserialization("unexpected end of file".to_string()))?
+                .map_err(OxidbError::Io)
+        })
+        .collect()
+}
+
+fn skip_bytes<R: Read>(bytes: &mut Peekable<std::io::Bytes<R>>, count: u64) -> Result<(), OxidbError> {
+    for _ in 0..count {
+        bytes.next().ok_or_else(|| OxidbError::Deserialization("unexpected end of file".to_string()))?.map_err(OxidbError::Io)?;
+    }
+    Ok(())
+}
+
+fn read_u32_le<R: Read>(bytes: &mut Peekable<std::io::Bytes<R>>) -> Result<u32, OxidbError> {
+    let raw = read_exact_bytes(bytes, 4)?;
+    Ok(u32::from_le_bytes([raw[0], raw[1], raw[2], raw[3]]))
+}
+
+fn read_u64_le<R: Read>(bytes: &mut Peekable<std::io::Bytes<R>>) -> Result<u64, OxidbError> {
+    let raw = read_exact_bytes(bytes, 8)?;
+    Ok(u64::from_le_bytes(raw.try_into().unwrap_or([0; 8])))
+}
+
+fn read_f32_le<R: Read>(bytes: &mut Peekable<std::io::Bytes<R>>) -> Result<f32, OxidbError> {
+    let raw = read_exact_bytes(bytes, 4)?;
+    Ok(f32::from_le_bytes([raw[0], raw[1], raw[2], raw[3]]))
+}
+
+fn read_ascii_line<R: Read>(bytes: &mut Peekable<std::io::Bytes<R>>) -> Result<String, OxidbError> {
+    let mut line = String::new();
+    while let Some(byte) = read_byte_opt(bytes)? {
+        if byte == b'\n' {
+            break;
+        }
+        line.push(byte as char);
+    }
+    Ok(line)
+}
+
+fn read_word<R: Read>(bytes: &mut Peekable<std::io::Bytes<R>>) -> Result<String, OxidbError> {
+    let mut word = Vec::new();
+    while let Some(&Ok(byte)) = bytes.peek() {
+        if byte == b' ' {
+            bytes.next();
+            break;
+        }
+        word.push(byte);
+        bytes.next();
+    }
+    String::from_utf8(word).map_err(|e| OxidbError::Deserialization(e.to_string()))
+}
+
+fn consume_optional_newline<R: Read>(bytes: &mut Peekable<std::io::Bytes<R>>) {
+    if let Some(&Ok(b'\n')) = bytes.peek() {
+        bytes.next();
+    }
+}
+
+fn read_simple_vocab<R: Read>(bytes: &mut Peekable<std::io::Bytes<R>>) -> Result<Vec<String>, OxidbError> {
+    let word_count = read_u32_le(bytes)? as usize;
+    (0..word_count)
+        .map(|_| {
+            let len = read_u32_le(bytes)? as usize;
+            let raw = read_exact_bytes(bytes, len)?;
+            String::from_utf8(raw).map_err(|e| OxidbError::Deserialization(e.to_string()))
+        })
+        .collect()
+}
+
+fn read_embeddings<R: Read>(bytes: &mut Peekable<std::io::Bytes<R>>) -> Result<Vec<Vec<f32>>, OxidbError> {
+    let rows = read_u32_le(bytes)? as usize;
+    let cols = read_u32_le(bytes)? as usize;
+    (0..rows).map(|_| (0..cols).map(|_| read_f32_le(bytes)).collect()).collect()
+}
+
+fn read_bucket_subwords<R: Read>(bytes: &mut Peekable<std::io::Bytes<R>>) -> Result<BucketSubwords, OxidbError> {
+    let min_n = read_u32_le(bytes)? as usize;
+    let max_n = read_u32_le(bytes)? as usize;
+    let bucket_count = read_u32_le(bytes)? as usize;
+    let bucket_dim = read_u32_le(bytes)? as usize;
+    let buckets = (0..bucket_count).map(|_| (0..bucket_dim).map(|_| read_f32_le(bytes)).collect()).collect();
+    Ok(BucketSubwords { min_n, max_n, buckets })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_read_word2vec_text_parses_header_and_vectors() {
+        let data = "2 3\ncat 1.0 0.0 0.0\ndog 0.0 1.0 0.0\n";
+        let embedder = PretrainedEmbedder::read_word2vec_text(Cursor::new(data)).unwrap();
+
+        assert_eq!(embedder.dimension, 3);
+        assert_eq!(embedder.vocab.get("cat").unwrap(), &vec![1.0, 0.0, 0.0]);
+        assert_eq!(embedder.vocab.get("dog").unwrap(), &vec![0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_read_word2vec_text_rejects_wrong_dimension() {
+        let data = "1 3\ncat 1.0 0.0\n";
+        assert!(PretrainedEmbedder::read_word2vec_text(Cursor::new(data)).is_err());
+    }
+
+    #[test]
+    fn test_read_word2vec_binary_round_trips_header_and_vectors() {
+        let mut data = Vec::new();
+        data.extend_from_slice(b"2 2\n");
+        data.extend_from_slice(b"cat ");
+        data.extend_from_slice(&1.0_f32.to_le_bytes());
+        data.extend_from_slice(&2.0_f32.to_le_bytes());
+        data.push(b'\n');
+        data.extend_from_slice(b"dog ");
+        data.extend_from_slice(&3.0_f32.to_le_bytes());
+        data.extend_from_slice(&4.0_f32.to_le_bytes());
+
+        let embedder = PretrainedEmbedder::read_word2vec_binary(Cursor::new(data)).unwrap();
+
+        assert_eq!(embedder.dimension, 2);
+        assert_eq!(embedder.vocab.get("cat").unwrap(), &vec![1.0, 2.0]);
+        assert_eq!(embedder.vocab.get("dog").unwrap(), &vec![3.0, 4.0]);
+    }
+
+    fn sample_chunked_bytes(with_subwords: bool) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&FINALFUSION_MAGIC);
+        data.extend_from_slice(&1_u32.to_le_bytes());
+
+        let mut vocab_payload = Vec::new();
+        vocab_payload.extend_from_slice(&1_u32.to_le_bytes());
+        let word = b"cat";
+        vocab_payload.extend_from_slice(&(word.len() as u32).to_le_bytes());
+        vocab_payload.extend_from_slice(word);
+        data.push(CHUNK_SIMPLE_VOCAB);
+        data.extend_from_slice(&(vocab_payload.len() as u64).to_le_bytes());
+        data.extend_from_slice(&vocab_payload);
+
+        let mut matrix_payload = Vec::new();
+        matrix_payload.extend_from_slice(&1_u32.to_le_bytes()); // rows
+        matrix_payload.extend_from_slice(&2_u32.to_le_bytes()); // cols
+        matrix_payload.extend_from_slice(&1.0_f32.to_le_bytes());
+        matrix_payload.extend_from_slice(&0.0_f32.to_le_bytes());
+        data.push(CHUNK_EMBEDDINGS);
+        data.extend_from_slice(&(matrix_payload.len() as u64).to_le_bytes());
+        data.extend_from_slice(&matrix_payload);
+
+        if with_subwords {
+            let mut subword_payload = Vec::new();
+            subword_payload.extend_from_slice(&3_u32.to_le_bytes()); // min_n
+            subword_payload.extend_from_slice(&3_u32.to_le_bytes()); // max_n
+            subword_payload.extend_from_slice(&4_u32.to_le_bytes()); // bucket_count
+            subword_payload.extend_from_slice(&2_u32.to_le_bytes()); // bucket_dim
+            for bucket in 0..4 {
+                subword_payload.extend_from_slice(&(bucket as f32).to_le_bytes());
+                subword_payload.extend_from_slice(&(bucket as f32 * 0.5).to_le_bytes());
+            }
+            data.push(CHUNK_BUCKET_SUBWORDS);
+            data.extend_from_slice(&(subword_payload.len() as u64).to_le_bytes());
+            data.extend_from_slice(&subword_payload);
+        }
+
+        data
+    }
+
+    #[test]
+    fn test_read_finalfusion_rejects_bad_magic() {
+        let data = b"nope".to_vec();
+        assert!(PretrainedEmbedder::read_finalfusion(Cursor::new(data)).is_err());
+    }
+
+    #[test]
+    fn test_read_finalfusion_parses_vocab_and_embeddings() {
+        let embedder = PretrainedEmbedder::read_finalfusion(Cursor::new(sample_chunked_bytes(false))).unwrap();
+        assert_eq!(embedder.dimension, 2);
+        assert_eq!(embedder.vocab.get("cat").unwrap(), &vec![1.0, 0.0]);
+        assert!(embedder.vector_for_word("dog").is_none());
+    }
+
+    #[test]
+    fn test_read_finalfusion_skips_unknown_chunk_types() {
+        let mut data = sample_chunked_bytes(false);
+        let unknown_payload = b"ignored";
+        data.push(0xFF);
+        data.extend_from_slice(&(unknown_payload.len() as u64).to_le_bytes());
+        data.extend_from_slice(unknown_payload);
+
+        let embedder = PretrainedEmbedder::read_finalfusion(Cursor::new(data)).unwrap();
+        assert_eq!(embedder.dimension, 2);
+    }
+
+    #[test]
+    fn test_oov_word_falls_back_to_subword_buckets_when_available() {
+        let embedder = PretrainedEmbedder::read_finalfusion(Cursor::new(sample_chunked_bytes(true))).unwrap();
+
+        assert!(embedder.vocab.get("zzz").is_none());
+        let fallback = embedder.vector_for_word("zzz");
+        assert!(fallback.is_some(), "subword fallback should produce a vector for an OOV word");
+    }
+
+    #[test]
+    fn test_oov_word_without_subwords_has_no_vector() {
+        let embedder = PretrainedEmbedder::read_finalfusion(Cursor::new(sample_chunked_bytes(false))).unwrap();
+        assert!(embedder.vector_for_word("zzz").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_embed_document_mean_pools_and_l2_normalizes() {
+        let embedder = PretrainedEmbedder::read_word2vec_text(Cursor::new("2 2\ncat 1.0 0.0\ndog 0.0 1.0\n")).unwrap();
+        let document = Document::new("doc1".to_string(), "cat dog unknownword".to_string());
+
+        let embedding = embedder.embed_document(&document).await.unwrap();
+        let norm: f32 = embedding.vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+
+        assert_eq!(embedding.vector.len(), 2);
+        assert!((norm - 1.0).abs() < 1e-5, "embedding should be L2-normalized, got norm {norm}");
+    }
+}
@@ -0,0 +1,336 @@
+// src/core/rag/product_quantization.rs
+//! Product quantization (PQ) for [`Embedding`] vectors: split a vector into `M` equal
+//! subspaces, learn a small codebook of centroids per subspace via k-means, and encode
+//! any embedding as `M` one-byte centroid indices instead of its dense `f32`s.
+//!
+//! With the default 256 centroids per subspace this cuts an embedding's storage from
+//! `4 * dim` bytes to `dim / sub_dim` bytes — a ~16-32x reduction for typical 128-768
+//! dimensional embeddings — at the cost of the quantization error introduced by
+//! snapping each subvector to its nearest centroid. Search against quantized codes uses
+//! the asymmetric distance computation (ADC) trick: the query stays full-precision, and
+//! a per-subspace lookup table of query-to-centroid distances is built once per query
+//! and then summed per code, so scoring a database of quantized codes against one query
+//! never needs to reconstruct the database vectors.
+
+use crate::core::common::OxidbError;
+
+use super::core_components::Embedding;
+
+/// Default number of centroids per subspace (one per possible `u8` code).
+pub const DEFAULT_CENTROIDS: usize = 256;
+
+/// Default number of k-means iterations used to refine each subspace's codebook.
+const DEFAULT_KMEANS_ITERATIONS: usize = 25;
+
+/// An embedding compressed by product quantization: one centroid index per subspace.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct QuantizedEmbedding {
+    /// Centroid index for each of the `M` subspaces, in subspace order.
+    pub codes: Vec<u8>,
+}
+
+/// Learned per-subspace centroids produced by [`quantize`], able to [`PqCodebooks::encode`]
+/// further embeddings and to score them against a query via
+/// [`PqCodebooks::query_lookup_table`] and [`PqCodebooks::asymmetric_distance`].
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PqCodebooks {
+    /// Number of subspaces the original vector is split into.
+    m: usize,
+    /// Dimensionality of each subspace (`dimension / m`).
+    sub_dim: usize,
+    /// `centroids[subspace][code]` is the `sub_dim`-length centroid vector for `code`.
+    centroids: Vec<Vec<Vec<f32>>>,
+}
+
+impl PqCodebooks {
+    /// Number of subspaces.
+    #[must_use]
+    pub fn num_subspaces(&self) -> usize {
+        self.m
+    }
+
+    /// Dimensionality of a single subspace.
+    #[must_use]
+    pub fn subspace_dimension(&self) -> usize {
+        self.sub_dim
+    }
+
+    /// Number of centroids learned per subspace.
+    #[must_use]
+    pub fn centroids_per_subspace(&self) -> usize {
+        self.centroids.first().map_or(0, Vec::len)
+    }
+
+    /// Encodes an embedding as one centroid index per subspace.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OxidbError::VectorDimensionMismatch`] if `embedding`'s length doesn't
+    /// match the dimension these codebooks were trained on.
+    pub fn encode(&self, embedding: &Embedding) -> Result<QuantizedEmbedding, OxidbError> {
+        self.check_dimension(embedding.vector.len())?;
+
+        let codes = (0..self.m)
+            .map(|subspace| {
+                let sub = self.subvector(&embedding.vector, subspace);
+                nearest_centroid(&self.centroids[subspace], sub) as u8
+            })
+            .collect();
+
+        Ok(QuantizedEmbedding { codes })
+    }
+
+    /// Precomputes, for a query embedding, the squared Euclidean distance from each of
+    /// its subvectors to every centroid in that subspace. The result is a lookup table
+    /// (`table[subspace][code]`) that [`Self::asymmetric_distance`] sums per code,
+    /// so a query is compared against the whole codebook once rather than once per
+    /// quantized embedding it is scored against.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OxidbError::VectorDimensionMismatch`] if `query`'s length doesn't match
+    /// the dimension these codebooks were trained on.
+    pub fn query_lookup_table(&self, query: &Embedding) -> Result<Vec<Vec<f32>>, OxidbError> {
+        self.check_dimension(query.vector.len())?;
+
+        Ok((0..self.m)
+            .map(|subspace| {
+                let sub = self.subvector(&query.vector, subspace);
+                self.centroids[subspace]
+                    .iter()
+                    .map(|centroid| squared_euclidean(sub, centroid))
+                    .collect()
+            })
+            .collect())
+    }
+
+    /// Approximate squared Euclidean distance between the query that produced `table`
+    /// and a quantized embedding, computed as the sum of the per-subspace table entries
+    /// for `code`'s centroid indices. Lower is closer; take the square root for an actual
+    /// distance or convert to cosine similarity via the embeddings' norms if needed.
+    #[must_use]
+    pub fn asymmetric_distance(&self, table: &[Vec<f32>], code: &QuantizedEmbedding) -> f32 {
+        table.iter().zip(&code.codes).map(|(row, &c)| row[c as usize]).sum()
+    }
+
+    fn subvector<'a>(&self, vector: &'a [f32], subspace: usize) -> &'a [f32] {
+        let start = subspace * self.sub_dim;
+        &vector[start..start + self.sub_dim]
+    }
+
+    fn check_dimension(&self, dim: usize) -> Result<(), OxidbError> {
+        let expected = self.m * self.sub_dim;
+        if dim != expected {
+            return Err(OxidbError::VectorDimensionMismatch { dim1: expected, dim2: dim });
+        }
+        Ok(())
+    }
+}
+
+/// Trains a [`PqCodebooks`] over `embeddings` by splitting each vector into `m` equal
+/// subspaces and running k-means (with [`DEFAULT_CENTROIDS`] centroids) independently
+/// within each subspace.
+///
+/// # Errors
+///
+/// Returns [`OxidbError::InvalidInput`] if `embeddings` is empty, `m` is zero, or the
+/// embeddings' dimension isn't evenly divisible by `m`. Returns
+/// [`OxidbError::VectorDimensionMismatch`] if the embeddings don't all share the same
+/// dimension.
+pub fn quantize(embeddings: &[Embedding], m: usize) -> Result<PqCodebooks, OxidbError> {
+    quantize_with_centroids(embeddings, m, DEFAULT_CENTROIDS)
+}
+
+/// As [`quantize`], but with an explicit number of centroids per subspace instead of
+/// the default 256. Useful for training on a set smaller than 256 vectors, where
+/// k-means needs at least as many training points as centroids.
+///
+/// # Errors
+///
+/// Same as [`quantize`].
+pub fn quantize_with_centroids(
+    embeddings: &[Embedding],
+    m: usize,
+    centroids_per_subspace: usize,
+) -> Result<PqCodebooks, OxidbError> {
+    let Some(first) = embeddings.first() else {
+        return Err(OxidbError::InvalidInput {
+            message: "cannot train product quantization codebooks on an empty training set"
+                .to_string(),
+        });
+    };
+    if m == 0 {
+        return Err(OxidbError::InvalidInput {
+            message: "number of subspaces must be greater than zero".to_string(),
+        });
+    }
+
+    let dimension = first.vector.len();
+    if dimension % m != 0 {
+        return Err(OxidbError::InvalidInput {
+            message: format!(
+                "embedding dimension {dimension} is not evenly divisible into {m} subspaces"
+            ),
+        });
+    }
+    for embedding in embeddings {
+        if embedding.vector.len() != dimension {
+            return Err(OxidbError::VectorDimensionMismatch {
+                dim1: dimension,
+                dim2: embedding.vector.len(),
+            });
+        }
+    }
+
+    let sub_dim = dimension / m;
+    let centroids = (0..m)
+        .map(|subspace| {
+            let training_set: Vec<&[f32]> = embeddings
+                .iter()
+                .map(|embedding| &embedding.vector[subspace * sub_dim..(subspace + 1) * sub_dim])
+                .collect();
+            kmeans(&training_set, centroids_per_subspace.min(training_set.len()))
+        })
+        .collect();
+
+    Ok(PqCodebooks { m, sub_dim, centroids })
+}
+
+/// Runs k-means (random-point initialization, squared-Euclidean assignment, Lloyd's
+/// algorithm for [`DEFAULT_KMEANS_ITERATIONS`] iterations) over `training_set`, each a
+/// `sub_dim`-length subvector, returning `k` centroids.
+fn kmeans(training_set: &[&[f32]], k: usize) -> Vec<Vec<f32>> {
+    use rand::seq::SliceRandom;
+
+    let sub_dim = training_set[0].len();
+    let mut rng = rand::thread_rng();
+    let mut centroids: Vec<Vec<f32>> = {
+        let mut sample: Vec<&&[f32]> = training_set.iter().collect();
+        sample.shuffle(&mut rng);
+        sample.into_iter().take(k).map(|v| v.to_vec()).collect()
+    };
+
+    for _ in 0..DEFAULT_KMEANS_ITERATIONS {
+        let mut sums = vec![vec![0.0_f32; sub_dim]; centroids.len()];
+        let mut counts = vec![0usize; centroids.len()];
+
+        for vector in training_set {
+            let nearest = nearest_centroid(&centroids, vector);
+            counts[nearest] += 1;
+            for (total, value) in sums[nearest].iter_mut().zip(*vector) {
+                *total += value;
+            }
+        }
+
+        for (centroid, (sum, count)) in centroids.iter_mut().zip(sums.into_iter().zip(counts)) {
+            if count > 0 {
+                for (value, total) in centroid.iter_mut().zip(sum) {
+                    *value = total / count as f32;
+                }
+            }
+        }
+    }
+
+    centroids
+}
+
+/// Index of the centroid nearest to `vector` by squared Euclidean distance.
+fn nearest_centroid(centroids: &[Vec<f32>], vector: &[f32]) -> usize {
+    centroids
+        .iter()
+        .enumerate()
+        .map(|(i, centroid)| (i, squared_euclidean(vector, centroid)))
+        .fold((0, f32::INFINITY), |best, candidate| if candidate.1 < best.1 { candidate } else { best })
+        .0
+}
+
+fn squared_euclidean(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| (x - y).powi(2)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn embedding(values: &[f32]) -> Embedding {
+        Embedding::from(values.to_vec())
+    }
+
+    fn training_set() -> Vec<Embedding> {
+        // Two well-separated clusters per 2-dim subspace, repeated across 2 subspaces.
+        vec![
+            embedding(&[0.0, 0.0, 10.0, 10.0]),
+            embedding(&[0.1, -0.1, 10.1, 9.9]),
+            embedding(&[-0.1, 0.1, 9.9, 10.1]),
+            embedding(&[5.0, 5.0, -5.0, -5.0]),
+            embedding(&[5.1, 4.9, -4.9, -5.1]),
+            embedding(&[4.9, 5.1, -5.1, -4.9]),
+        ]
+    }
+
+    #[test]
+    fn quantize_rejects_empty_training_set() {
+        let err = quantize(&[], 2).unwrap_err();
+        assert!(matches!(err, OxidbError::InvalidInput { .. }));
+    }
+
+    #[test]
+    fn quantize_rejects_non_divisible_dimension() {
+        let err = quantize(&[embedding(&[1.0, 2.0, 3.0])], 2).unwrap_err();
+        assert!(matches!(err, OxidbError::InvalidInput { .. }));
+    }
+
+    #[test]
+    fn quantize_rejects_mismatched_dimensions() {
+        let embeddings = vec![embedding(&[1.0, 2.0]), embedding(&[1.0, 2.0, 3.0, 4.0])];
+        let err = quantize(&embeddings, 2).unwrap_err();
+        assert!(matches!(err, OxidbError::VectorDimensionMismatch { .. }));
+    }
+
+    #[test]
+    fn encode_rejects_wrong_dimension() {
+        let codebooks = quantize_with_centroids(&training_set(), 2, 2).unwrap();
+        let err = codebooks.encode(&embedding(&[1.0, 2.0])).unwrap_err();
+        assert!(matches!(err, OxidbError::VectorDimensionMismatch { .. }));
+    }
+
+    #[test]
+    fn encode_groups_nearby_vectors_into_the_same_code() {
+        let docs = training_set();
+        let codebooks = quantize_with_centroids(&docs, 2, 2).unwrap();
+
+        let first_cluster = codebooks.encode(&docs[0]).unwrap();
+        for doc in &docs[1..3] {
+            assert_eq!(codebooks.encode(doc).unwrap(), first_cluster);
+        }
+
+        let second_cluster = codebooks.encode(&docs[3]).unwrap();
+        assert_ne!(first_cluster, second_cluster);
+        for doc in &docs[4..6] {
+            assert_eq!(codebooks.encode(doc).unwrap(), second_cluster);
+        }
+    }
+
+    #[test]
+    fn asymmetric_distance_ranks_like_exact_euclidean_distance() {
+        let docs = training_set();
+        let codebooks = quantize_with_centroids(&docs, 2, 2).unwrap();
+        let codes: Vec<QuantizedEmbedding> =
+            docs.iter().map(|d| codebooks.encode(d).unwrap()).collect();
+
+        let query = embedding(&[0.0, 0.0, 10.0, 10.0]);
+        let table = codebooks.query_lookup_table(&query).unwrap();
+
+        let distance_to_first_cluster = codebooks.asymmetric_distance(&table, &codes[0]);
+        let distance_to_second_cluster = codebooks.asymmetric_distance(&table, &codes[3]);
+        assert!(distance_to_first_cluster < distance_to_second_cluster);
+    }
+
+    #[test]
+    fn quantized_embedding_storage_is_m_bytes() {
+        let docs = training_set();
+        let codebooks = quantize_with_centroids(&docs, 2, 2).unwrap();
+        let code = codebooks.encode(&docs[0]).unwrap();
+        assert_eq!(code.codes.len(), 2);
+    }
+}
@@ -0,0 +1,320 @@
+// src/core/rag/prompt_template.rs
+//! Renders a [`Document`]'s fields and metadata into a natural-language string before
+//! handing it to an [`EmbeddingModel`], so embedding a structured row (the analytics
+//! demo's many-field records) doesn't degrade to its raw, lossily-concatenated content.
+//!
+//! [`PromptTemplate`] is a small template language supporting:
+//! - Field interpolation, `{field}`, where `field` is `id`, `content`, or a key in the
+//!   document's metadata.
+//! - Iteration over repeated fields, `{#each field}...{/each}`, for metadata stored as
+//!   `field.0`, `field.1`, ... entries (e.g. a row with several `tag.0`, `tag.1` values);
+//!   inside the block, `{.}` refers to the current entry's value.
+//!
+//! Templates are validated at construction against a caller-supplied list of known
+//! fields, so a typo'd field name or an unterminated tag fails fast rather than
+//! silently rendering empty text. [`TemplatedEmbedder`] wraps any [`EmbeddingModel`]
+//! with a [`PromptTemplate`], so the same embedder implementation can be reused across
+//! differently-shaped document sources by swapping the template.
+
+use async_trait::async_trait;
+
+use crate::core::common::OxidbError;
+use crate::core::common::types::Value;
+
+use super::core_components::{Document, Embedding};
+use super::embedder::EmbeddingModel;
+
+/// Field name built-ins that are always valid, independent of the caller's known-field
+/// list: the document's id and raw content.
+const BUILTIN_FIELDS: [&str; 2] = ["id", "content"];
+
+/// The current entry's value inside an `{#each}` block.
+const EACH_ITEM_FIELD: &str = ".";
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Literal(String),
+    Field(String),
+    Each { field: String, body: Vec<Segment> },
+}
+
+/// A parsed, validated template for rendering a [`Document`] into text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PromptTemplate {
+    segments: Vec<Segment>,
+}
+
+impl PromptTemplate {
+    /// Parses and validates `template` against `known_fields`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OxidbError::InvalidInput`] if the template references a field that is
+    /// neither `id`/`content` nor in `known_fields`, references `{.}` outside an
+    /// `{#each}` block, or contains an unterminated `{` or `{#each}` tag.
+    pub fn new(template: &str, known_fields: &[&str]) -> Result<Self, OxidbError> {
+        let (segments, rest) = parse_segments(template, known_fields, false)?;
+        if !rest.is_empty() {
+            return Err(OxidbError::InvalidInput {
+                message: format!("prompt template has an unmatched `{{/each}}` near: {rest}"),
+            });
+        }
+        Ok(Self { segments })
+    }
+
+    /// Renders the template for `document`. A field with no value for this particular
+    /// document (a metadata key the template references but this document lacks)
+    /// renders as an empty string rather than erroring — construction-time validation
+    /// already checked the field is a known one.
+    #[must_use]
+    pub fn render(&self, document: &Document) -> String {
+        let mut out = String::new();
+        render_segments(&self.segments, document, None, &mut out);
+        out
+    }
+}
+
+fn field_value(document: &Document, current_item: Option<&str>, field: &str) -> String {
+    if field == EACH_ITEM_FIELD {
+        return current_item.unwrap_or_default().to_string();
+    }
+    match field {
+        "id" => document.id.clone(),
+        "content" => document.content.clone(),
+        _ => document
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(field))
+            .map(render_value)
+            .unwrap_or_default(),
+    }
+}
+
+fn render_value(value: &Value) -> String {
+    match value {
+        Value::Integer(i) => i.to_string(),
+        Value::Float(f) => f.to_string(),
+        Value::Text(s) => s.clone(),
+        Value::Boolean(b) => b.to_string(),
+        Value::Blob(bytes) => format!("<{} bytes>", bytes.len()),
+        Value::Vector(v) => format!("[{}]", v.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")),
+        Value::Null => String::new(),
+    }
+}
+
+fn render_segments(segments: &[Segment], document: &Document, current_item: Option<&str>, out: &mut String) {
+    for segment in segments {
+        match segment {
+            Segment::Literal(text) => out.push_str(text),
+            Segment::Field(field) => out.push_str(&field_value(document, current_item, field)),
+            Segment::Each { field, body } => {
+                for item in each_values(document, field) {
+                    render_segments(body, document, Some(&item), out);
+                }
+            }
+        }
+    }
+}
+
+/// Collects `field.0`, `field.1`, ... metadata entries in ascending index order, until
+/// the first missing index.
+fn each_values(document: &Document, field: &str) -> Vec<String> {
+    let Some(metadata) = document.metadata.as_ref() else {
+        return Vec::new();
+    };
+    let mut values = Vec::new();
+    for index in 0.. {
+        let key = format!("{field}.{index}");
+        match metadata.get(&key) {
+            Some(value) => values.push(render_value(value)),
+            None => break,
+        }
+    }
+    values
+}
+
+/// Parses a sequence of segments up to (and not including) a `{/each}` close tag, if
+/// `inside_each` is true, or to the end of input otherwise. Returns the parsed segments
+/// and whatever input remains (the `{/each}` tag itself, or empty at top level).
+fn parse_segments<'a>(
+    input: &'a str,
+    known_fields: &[&str],
+    inside_each: bool,
+) -> Result<(Vec<Segment>, &'a str), OxidbError> {
+    let mut segments = Vec::new();
+    let mut rest = input;
+    let mut closed = false;
+
+    while !rest.is_empty() {
+        let Some(brace_pos) = rest.find('{') else {
+            segments.push(Segment::Literal(rest.to_string()));
+            rest = "";
+            break;
+        };
+
+        if brace_pos > 0 {
+            segments.push(Segment::Literal(rest[..brace_pos].to_string()));
+        }
+        let after_brace = &rest[brace_pos + 1..];
+
+        if inside_each && after_brace.starts_with("/each}") {
+            rest = &after_brace["/each}".len()..];
+            closed = true;
+            break;
+        }
+
+        let Some(close_pos) = after_brace.find('}') else {
+            return Err(OxidbError::InvalidInput {
+                message: format!("unterminated `{{` tag in prompt template near: {after_brace}"),
+            });
+        };
+        let tag = &after_brace[..close_pos];
+        let after_tag = &after_brace[close_pos + 1..];
+
+        if let Some(each_field) = tag.strip_prefix("#each ") {
+            let each_field = each_field.trim();
+            validate_field(each_field, known_fields, false)?;
+            let (body, remainder) = parse_segments(after_tag, known_fields, true)?;
+            segments.push(Segment::Each { field: each_field.to_string(), body });
+            rest = remainder;
+        } else {
+            validate_field(tag, known_fields, inside_each)?;
+            segments.push(Segment::Field(tag.to_string()));
+            rest = after_tag;
+        }
+    }
+
+    if inside_each && !closed {
+        return Err(OxidbError::InvalidInput {
+            message: "unterminated `{#each}` block in prompt template: missing `{/each}`".to_string(),
+        });
+    }
+
+    Ok((segments, rest))
+}
+
+fn validate_field(field: &str, known_fields: &[&str], allow_each_item: bool) -> Result<(), OxidbError> {
+    if field == EACH_ITEM_FIELD {
+        if allow_each_item {
+            return Ok(());
+        }
+        return Err(OxidbError::InvalidInput {
+            message: "`{.}` may only be used inside an `{#each}` block".to_string(),
+        });
+    }
+    if BUILTIN_FIELDS.contains(&field) || known_fields.contains(&field) {
+        return Ok(());
+    }
+    Err(OxidbError::InvalidInput { message: format!("unknown field `{field}` in prompt template") })
+}
+
+/// Wraps an [`EmbeddingModel`] with a [`PromptTemplate`], rendering each [`Document`]
+/// through the template before embedding, so the same embedder can be reused across
+/// differently-shaped document sources by swapping the template rather than the model.
+pub struct TemplatedEmbedder<M: EmbeddingModel> {
+    inner: M,
+    template: PromptTemplate,
+}
+
+impl<M: EmbeddingModel> TemplatedEmbedder<M> {
+    #[must_use]
+    pub fn new(inner: M, template: PromptTemplate) -> Self {
+        Self { inner, template }
+    }
+}
+
+#[async_trait]
+impl<M: EmbeddingModel> EmbeddingModel for TemplatedEmbedder<M> {
+    async fn embed_document(&self, document: &Document) -> Result<Embedding, OxidbError> {
+        self.inner.embed(&self.template.render(document)).await
+    }
+
+    async fn embed(&self, text: &str) -> Result<Embedding, OxidbError> {
+        self.inner.embed(text).await
+    }
+
+    async fn embed_documents(&self, documents: &[Document]) -> Result<Vec<Embedding>, OxidbError> {
+        let rendered: Vec<Document> = documents
+            .iter()
+            .map(|doc| Document::new(doc.id.clone(), self.template.render(doc)))
+            .collect();
+        self.inner.embed_documents(&rendered).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn document_with_metadata(metadata: Vec<(&str, Value)>) -> Document {
+        let map: HashMap<String, Value> =
+            metadata.into_iter().map(|(k, v)| (k.to_string(), v)).collect();
+        Document::new("doc1".to_string(), "raw content".to_string()).with_metadata(map)
+    }
+
+    #[test]
+    fn rejects_unknown_field() {
+        let err = PromptTemplate::new("{title}", &["body"]).unwrap_err();
+        assert!(matches!(err, OxidbError::InvalidInput { .. }));
+    }
+
+    #[test]
+    fn rejects_unterminated_brace() {
+        let err = PromptTemplate::new("Title: {title", &["title"]).unwrap_err();
+        assert!(matches!(err, OxidbError::InvalidInput { .. }));
+    }
+
+    #[test]
+    fn rejects_unterminated_each_block() {
+        let err = PromptTemplate::new("{#each tag}{.}", &["tag"]).unwrap_err();
+        assert!(matches!(err, OxidbError::InvalidInput { .. }));
+    }
+
+    #[test]
+    fn rejects_each_item_outside_each_block() {
+        let err = PromptTemplate::new("{.}", &[]).unwrap_err();
+        assert!(matches!(err, OxidbError::InvalidInput { .. }));
+    }
+
+    #[test]
+    fn renders_builtin_and_metadata_fields() {
+        let template = PromptTemplate::new("{id}: {title}", &["title"]).unwrap();
+        let doc = document_with_metadata(vec![("title", Value::Text("Q2 report".to_string()))]);
+        assert_eq!(template.render(&doc), "doc1: Q2 report");
+    }
+
+    #[test]
+    fn missing_metadata_renders_as_empty() {
+        let template = PromptTemplate::new("Title: {title}", &["title"]).unwrap();
+        let doc = document_with_metadata(vec![]);
+        assert_eq!(template.render(&doc), "Title: ");
+    }
+
+    #[test]
+    fn renders_each_block_over_repeated_fields() {
+        let template = PromptTemplate::new("Tags: {#each tag}{.}, {/each}done", &["tag"]).unwrap();
+        let doc = document_with_metadata(vec![
+            ("tag.0", Value::Text("finance".to_string())),
+            ("tag.1", Value::Text("quarterly".to_string())),
+        ]);
+        assert_eq!(template.render(&doc), "Tags: finance, quarterly, done");
+    }
+
+    #[tokio::test]
+    async fn templated_embedder_renders_before_delegating() {
+        use super::super::embedder::MockEmbeddingModel;
+
+        let template = PromptTemplate::new("{title}: {content}", &["title"]).unwrap();
+        let embedder = TemplatedEmbedder::new(
+            MockEmbeddingModel { dimension: 2, fixed_embedding_value: None },
+            template,
+        );
+        let doc = document_with_metadata(vec![("title", Value::Text("Report".to_string()))]);
+        let rendered = embedder.template.render(&doc);
+        assert_eq!(rendered, "Report: raw content");
+
+        let embedding = embedder.embed_document(&doc).await.unwrap();
+        assert_eq!(embedding.vector.len(), 2);
+    }
+}
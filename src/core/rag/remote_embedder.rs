@@ -0,0 +1,211 @@
+// src/core/rag/remote_embedder.rs
+//! An [`EmbeddingModel`] backed by an HTTP embedding endpoint, so a RAG index can use a
+//! hosted or self-hosted transformer model instead of only the local
+//! [`super::embedder::TfIdfEmbedder`] / [`super::embedder::SemanticEmbedder`].
+//!
+//! [`RemoteEmbedder`] is generic over an [`EmbeddingProvider`], which owns the wire
+//! format of a specific backend. Two providers are included:
+//! - [`OpenAiStyleProvider`] for hosted, API-key-authenticated backends that accept a
+//!   batch of inputs in one request and return a parallel array of embeddings (the
+//!   `POST /embeddings` shape OpenAI-compatible APIs use).
+//! - [`OllamaProvider`] for a local server that embeds one prompt per request (the
+//!   `POST /api/embeddings` shape Ollama uses).
+//!
+//! Swapping backends is a matter of constructing a different provider; indexing code
+//! written against [`EmbeddingModel`] doesn't change.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::core::common::OxidbError;
+
+use super::core_components::{Document, Embedding};
+use super::embedder::EmbeddingModel;
+
+/// A backend capable of turning a batch of texts into embeddings over HTTP.
+///
+/// Implementers own request construction, authentication, and response parsing for one
+/// specific API shape; [`RemoteEmbedder`] handles the [`EmbeddingModel`] plumbing around
+/// whichever provider it's given.
+#[async_trait]
+pub trait EmbeddingProvider: Send + Sync {
+    /// Requests embeddings for a batch of texts, in the same order as `texts`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OxidbError`] if the request fails, the backend responds with a
+    /// non-success status, or the response body can't be parsed into embeddings.
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, OxidbError>;
+
+    /// Dimensionality of the vectors this provider returns.
+    fn dimension(&self) -> usize;
+}
+
+/// Wraps an [`EmbeddingProvider`] as an [`EmbeddingModel`], so callers can swap between
+/// a hosted and a self-hosted backend without changing indexing code.
+pub struct RemoteEmbedder<P: EmbeddingProvider> {
+    provider: P,
+}
+
+impl<P: EmbeddingProvider> RemoteEmbedder<P> {
+    #[must_use]
+    pub fn new(provider: P) -> Self {
+        Self { provider }
+    }
+}
+
+#[async_trait]
+impl<P: EmbeddingProvider> EmbeddingModel for RemoteEmbedder<P> {
+    async fn embed_document(&self, document: &Document) -> Result<Embedding, OxidbError> {
+        self.embed(&document.content).await
+    }
+
+    async fn embed(&self, text: &str) -> Result<Embedding, OxidbError> {
+        let mut vectors = self.provider.embed_batch(&[text.to_string()]).await?;
+        let vector = vectors.pop().ok_or_else(|| {
+            OxidbError::Execution("embedding provider returned no embeddings".to_string())
+        })?;
+        Ok(Embedding::from(vector))
+    }
+
+    /// Issues a single batched request for the whole slice, rather than one request per
+    /// document, so indexing a corpus costs one round trip per flush.
+    async fn embed_documents(&self, documents: &[Document]) -> Result<Vec<Embedding>, OxidbError> {
+        let texts: Vec<String> = documents.iter().map(|doc| doc.content.clone()).collect();
+        let vectors = self.provider.embed_batch(&texts).await?;
+        if vectors.len() != documents.len() {
+            return Err(OxidbError::Execution(format!(
+                "embedding provider returned {} embeddings for {} documents",
+                vectors.len(),
+                documents.len()
+            )));
+        }
+        Ok(vectors.into_iter().map(Embedding::from).collect())
+    }
+}
+
+/// Provider for hosted, OpenAI-compatible embedding APIs: one `POST` carrying the whole
+/// batch of inputs, authenticated with a bearer API key, returning embeddings in
+/// request order under a `data` array.
+pub struct OpenAiStyleProvider {
+    base_url: String,
+    model: String,
+    api_key: String,
+    dimension: usize,
+    client: reqwest::Client,
+}
+
+impl OpenAiStyleProvider {
+    /// Creates a provider that posts to `{base_url}/embeddings`.
+    #[must_use]
+    pub fn new(base_url: impl Into<String>, model: impl Into<String>, api_key: impl Into<String>, dimension: usize) -> Self {
+        Self {
+            base_url: base_url.into(),
+            model: model.into(),
+            api_key: api_key.into(),
+            dimension,
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct OpenAiStyleRequest<'a> {
+    model: &'a str,
+    input: &'a [String],
+}
+
+#[derive(Deserialize)]
+struct OpenAiStyleResponse {
+    data: Vec<OpenAiStyleEmbedding>,
+}
+
+#[derive(Deserialize)]
+struct OpenAiStyleEmbedding {
+    embedding: Vec<f32>,
+}
+
+#[async_trait]
+impl EmbeddingProvider for OpenAiStyleProvider {
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, OxidbError> {
+        let response = self
+            .client
+            .post(format!("{}/embeddings", self.base_url))
+            .bearer_auth(&self.api_key)
+            .json(&OpenAiStyleRequest { model: &self.model, input: texts })
+            .send()
+            .await
+            .map_err(|e| OxidbError::Execution(format!("embedding request failed: {e}")))?
+            .error_for_status()
+            .map_err(|e| OxidbError::Execution(format!("embedding backend returned an error: {e}")))?;
+
+        let parsed: OpenAiStyleResponse = response
+            .json()
+            .await
+            .map_err(|e| OxidbError::Deserialization(format!("invalid embedding response: {e}")))?;
+
+        Ok(parsed.data.into_iter().map(|entry| entry.embedding).collect())
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+}
+
+/// Provider for a local, Ollama-style embedding server, which embeds one prompt per
+/// request under `POST /api/embeddings`. Batches are issued as sequential requests
+/// since the API has no batch endpoint.
+pub struct OllamaProvider {
+    base_url: String,
+    model: String,
+    dimension: usize,
+    client: reqwest::Client,
+}
+
+impl OllamaProvider {
+    /// Creates a provider that posts to `{base_url}/api/embeddings`.
+    #[must_use]
+    pub fn new(base_url: impl Into<String>, model: impl Into<String>, dimension: usize) -> Self {
+        Self { base_url: base_url.into(), model: model.into(), dimension, client: reqwest::Client::new() }
+    }
+}
+
+#[derive(Serialize)]
+struct OllamaRequest<'a> {
+    model: &'a str,
+    prompt: &'a str,
+}
+
+#[derive(Deserialize)]
+struct OllamaResponse {
+    embedding: Vec<f32>,
+}
+
+#[async_trait]
+impl EmbeddingProvider for OllamaProvider {
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, OxidbError> {
+        let mut embeddings = Vec::with_capacity(texts.len());
+        for text in texts {
+            let response = self
+                .client
+                .post(format!("{}/api/embeddings", self.base_url))
+                .json(&OllamaRequest { model: &self.model, prompt: text })
+                .send()
+                .await
+                .map_err(|e| OxidbError::Execution(format!("embedding request failed: {e}")))?
+                .error_for_status()
+                .map_err(|e| OxidbError::Execution(format!("embedding backend returned an error: {e}")))?;
+
+            let parsed: OllamaResponse = response
+                .json()
+                .await
+                .map_err(|e| OxidbError::Deserialization(format!("invalid embedding response: {e}")))?;
+            embeddings.push(parsed.embedding);
+        }
+        Ok(embeddings)
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+}
@@ -1,8 +1,10 @@
 // src/core/rag/retriever.rs
 
+use super::caching_embedder::EmbeddingQueue;
 use super::core_components::{Document, Embedding};
+use super::embedder::EmbeddingModel;
 use crate::core::common::OxidbError;
-use crate::core::vector::similarity::{cosine_similarity, dot_product};
+use crate::core::vector::similarity::{cosine_similarity, dot_product, euclidean_distance, manhattan_distance};
 use async_trait::async_trait; // Assuming these are pub
 
 /// Defines the type of similarity metric to use for retrieval.
@@ -10,7 +12,23 @@ use async_trait::async_trait; // Assuming these are pub
 pub enum SimilarityMetric {
     Cosine,
     DotProduct,
-    // Euclidean, // Example for future extension
+    /// Euclidean (L2) distance: nearest (smallest distance) ranks first.
+    Euclidean,
+    /// Manhattan (L1) distance: nearest (smallest distance) ranks first.
+    Manhattan,
+}
+
+impl SimilarityMetric {
+    /// Whether a higher score ranks a document first. Similarity metrics (cosine, dot
+    /// product) are "higher is better"; distance metrics (Euclidean, Manhattan) are not, so
+    /// [`InMemoryRetriever::retrieve`] sorts ascending for those instead.
+    #[must_use]
+    pub const fn higher_is_better(self) -> bool {
+        match self {
+            Self::Cosine | Self::DotProduct => true,
+            Self::Euclidean | Self::Manhattan => false,
+        }
+    }
 }
 
 /// Trait for retrieving relevant documents based on a query embedding.
@@ -43,6 +61,41 @@ impl InMemoryRetriever {
     pub fn add_document(&mut self, document: Document) {
         self.documents.push(document);
     }
+
+    /// Removes the document with `id`, if present. Returns whether anything was removed.
+    pub fn remove_document(&mut self, id: &str) -> bool {
+        let before = self.documents.len();
+        self.documents.retain(|doc| doc.id != id);
+        self.documents.len() != before
+    }
+
+    /// Inserts `document`, replacing any existing document with the same id.
+    pub fn upsert_document(&mut self, document: Document) {
+        self.remove_document(&document.id);
+        self.documents.push(document);
+    }
+
+    /// Bulk-ingests every document queued in `queue`, embedding them in token-budgeted
+    /// batches via `embedder` and adding each one (with its embedding attached) through
+    /// [`Self::add_document`]. This is the efficient alternative to calling
+    /// [`Self::add_document`] once per document when indexing a whole corpus: a failed
+    /// batch never partially populates the retriever, since [`EmbeddingQueue::flush`]
+    /// only returns a batch's embeddings once the whole batch succeeds.
+    ///
+    /// # Errors
+    /// Propagates any `OxidbError` from [`EmbeddingQueue::flush`].
+    pub async fn ingest_queued<M: EmbeddingModel>(
+        &mut self,
+        queue: &mut EmbeddingQueue,
+        embedder: &M,
+    ) -> Result<usize, OxidbError> {
+        let embedded = queue.flush(embedder).await?;
+        let count = embedded.len();
+        for (document, embedding) in embedded {
+            self.add_document(document.with_embedding(embedding));
+        }
+        Ok(count)
+    }
 }
 
 #[async_trait]
@@ -63,19 +116,38 @@ impl Retriever for InMemoryRetriever {
             if let Some(doc_embedding) = &doc.embedding {
                 let score = match metric {
                     SimilarityMetric::Cosine => {
-                        cosine_similarity(query_embedding.as_slice(), doc_embedding.as_slice())?
+                        match cosine_similarity(query_embedding.as_slice(), doc_embedding.as_slice())
+                        {
+                            Ok(score) => score,
+                            // A zero-norm embedding has no defined direction, so treat it as
+                            // maximally dissimilar instead of propagating a divide error.
+                            Err(OxidbError::VectorMagnitudeZero) => -1.0,
+                            Err(e) => return Err(e),
+                        }
                     }
                     SimilarityMetric::DotProduct => {
                         dot_product(query_embedding.as_slice(), doc_embedding.as_slice())?
                     }
+                    SimilarityMetric::Euclidean => {
+                        euclidean_distance(query_embedding.as_slice(), doc_embedding.as_slice())?
+                    }
+                    SimilarityMetric::Manhattan => {
+                        manhattan_distance(query_embedding.as_slice(), doc_embedding.as_slice())?
+                    }
                 };
                 scored_documents.push((score, doc));
             }
         }
 
-        // Sort by score. For cosine and dot product, higher is better.
-        // If adding Euclidean, lower would be better, so sorting logic would need adjustment.
-        scored_documents.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+        // Similarity metrics (cosine, dot product) rank highest-first; distance metrics
+        // (Euclidean, Manhattan) rank nearest-first, i.e. lowest score first.
+        if metric.higher_is_better() {
+            scored_documents
+                .sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+        } else {
+            scored_documents
+                .sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        }
 
         Ok(scored_documents
             .into_iter()
@@ -89,6 +161,7 @@ impl Retriever for InMemoryRetriever {
 mod tests {
     use super::*;
     use crate::core::rag::core_components::Embedding; // Ensure Embedding is in scope
+    use crate::core::rag::caching_embedder::EmbeddingQueue;
     use crate::core::rag::embedder::{EmbeddingModel, MockEmbeddingModel}; // For generating embeddings
     use approx::assert_relative_eq;
 
@@ -217,4 +290,93 @@ mod tests {
         let score = cosine_similarity(&[0.5, 0.5], &[0.5, 0.5]).unwrap();
         assert_relative_eq!(score, 1.0, epsilon = 1e-6);
     }
+
+    #[tokio::test]
+    async fn test_ingest_queued_embeds_and_adds_every_document() {
+        let model = MockEmbeddingModel { dimension: 2, fixed_embedding_value: Some(0.3) };
+        let mut queue = EmbeddingQueue::default();
+        queue.enqueue(Document::new("doc1".to_string(), "first".to_string()));
+        queue.enqueue(Document::new("doc2".to_string(), "second".to_string()));
+
+        let mut retriever = InMemoryRetriever::new(Vec::new());
+        let ingested = retriever.ingest_queued(&mut queue, &model).await.unwrap();
+
+        assert_eq!(ingested, 2);
+        assert_eq!(queue.pending_len(), 0);
+
+        let query_embedding = Embedding::from(vec![0.3, 0.3]);
+        let results =
+            retriever.retrieve(&query_embedding, 2, SimilarityMetric::Cosine).await.unwrap();
+        assert_eq!(results.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_remove_document_purges_by_id() {
+        let mut retriever = setup_retriever().await;
+        assert!(retriever.remove_document("doc1"));
+        assert!(!retriever.remove_document("doc1"));
+
+        let query_embedding = Embedding::from(vec![0.12, 0.12]);
+        let results =
+            retriever.retrieve(&query_embedding, 10, SimilarityMetric::Cosine).await.unwrap();
+        assert!(results.iter().all(|doc| doc.id != "doc1"));
+    }
+
+    #[tokio::test]
+    async fn test_upsert_document_replaces_existing_id() {
+        let mut retriever = setup_retriever().await;
+        retriever.upsert_document(
+            Document::new("doc1".to_string(), "replaced".to_string())
+                .with_embedding(Embedding::from(vec![0.9, 0.9])),
+        );
+
+        let query_embedding = Embedding::from(vec![0.9, 0.9]);
+        let results =
+            retriever.retrieve(&query_embedding, 1, SimilarityMetric::Cosine).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, "doc1");
+        assert_eq!(results[0].content, "replaced");
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_euclidean_ranks_nearest_first() {
+        let retriever = setup_retriever().await; // doc3 -> [0.5, 0.5], rest -> [0.12, 0.12] or [0.17, 0.17]
+        let query_embedding = Embedding::from(vec![0.5, 0.5]);
+
+        let results =
+            retriever.retrieve(&query_embedding, 1, SimilarityMetric::Euclidean).await.unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, "doc3"); // Zero distance, the nearest possible.
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_manhattan_ranks_nearest_first() {
+        let retriever = setup_retriever().await;
+        let query_embedding = Embedding::from(vec![0.5, 0.5]);
+
+        let results =
+            retriever.retrieve(&query_embedding, 1, SimilarityMetric::Manhattan).await.unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, "doc3"); // Zero distance, the nearest possible.
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_cosine_zero_norm_embedding_does_not_error() {
+        let retriever = InMemoryRetriever::new(vec![
+            Document::new("zero".to_string(), "zero vector".to_string())
+                .with_embedding(Embedding::from(vec![0.0, 0.0])),
+            Document::new("normal".to_string(), "normal vector".to_string())
+                .with_embedding(Embedding::from(vec![1.0, 0.0])),
+        ]);
+        let query_embedding = Embedding::from(vec![1.0, 0.0]);
+
+        let results =
+            retriever.retrieve(&query_embedding, 2, SimilarityMetric::Cosine).await.unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].id, "normal");
+        assert_eq!(results[1].id, "zero");
+    }
 }
@@ -13,9 +13,9 @@
 
 use crate::core::common::types::{Lsn, TransactionId};
 use crate::core::recovery::tables::{DirtyPageTable, TransactionTable};
-use crate::core::recovery::types::{RecoveryError, TransactionInfo};
+use crate::core::recovery::types::{RecoveryError, TransactionInfo, TransactionState};
 use crate::core::wal::log_record::{ActiveTransactionInfo, DirtyPageInfo, LogRecord};
-use crate::core::wal::reader::{WalReader, WalReaderError};
+use crate::core::wal::reader::WalReader;
 use std::collections::HashMap;
 
 /// Result of the Analysis phase containing the built tables and recovery information.
@@ -29,8 +29,22 @@ pub struct AnalysisResult {
     pub redo_lsn: Option<Lsn>,
     /// LSN of the last checkpoint found
     pub last_checkpoint_lsn: Option<Lsn>,
+    /// The checkpoint `analyze`/`analyze_from` actually started this scan from -
+    /// `last_checkpoint_lsn`'s value at the time analysis ran, kept as its own field so
+    /// it still reads unambiguously after a later `checkpoints()` call makes clear that
+    /// "the last checkpoint" and "the checkpoint this result came from" aren't always
+    /// the same thing (e.g. point-in-time recovery via `analyze_from`).
+    pub checkpoint_used: Option<Lsn>,
     /// Total number of log records processed
     pub records_processed: usize,
+    /// The lowest LSN seen while scanning, if any record was scanned at all.
+    pub min_lsn_seen: Option<Lsn>,
+    /// The highest LSN seen while scanning, if any record was scanned at all.
+    pub max_lsn_seen: Option<Lsn>,
+    /// Bytes of the WAL file actually read during the forward scan - from
+    /// `start_offset` to the end of the log, not the whole file, since
+    /// `analyze`/`analyze_from` skip everything before their starting checkpoint.
+    pub bytes_replayed: u64,
 }
 
 impl AnalysisResult {
@@ -41,15 +55,30 @@ impl AnalysisResult {
             dirty_page_table: DirtyPageTable::new(),
             redo_lsn: None,
             last_checkpoint_lsn: None,
+            checkpoint_used: None,
             records_processed: 0,
+            min_lsn_seen: None,
+            max_lsn_seen: None,
+            bytes_replayed: 0,
         }
     }
 
-    /// Returns the number of active transactions that need to be undone.
+    /// Returns the number of active transactions that need to be undone - the
+    /// "losers" of the crash, in ARIES terminology.
     pub fn active_transaction_count(&self) -> usize {
         self.transaction_table.active_transactions().count()
     }
 
+    /// Returns the number of transactions that had already committed by the time
+    /// analysis scanned them - the "winners" of the crash, whose effects the Redo
+    /// phase must make sure survive it.
+    pub fn winner_count(&self) -> usize {
+        self.transaction_table
+            .iter()
+            .filter(|(_, tx_info)| tx_info.state == TransactionState::Committed)
+            .count()
+    }
+
     /// Returns the number of dirty pages that may need redo.
     pub fn dirty_page_count(&self) -> usize {
         self.dirty_page_table.len()
@@ -87,72 +116,116 @@ impl<'a> AnalysisPhase<'a> {
     /// Performs the Analysis phase of recovery.
     ///
     /// This method scans the WAL from the last checkpoint (if any) to the end,
-    /// building the transaction table and dirty page table.
-    pub async fn analyze(&mut self) -> Result<AnalysisResult, RecoveryError> {
-        // Step 1: Find the last checkpoint
-        self.find_last_checkpoint().await?;
-
-        // Step 2: Initialize tables from checkpoint (if found)
-        self.initialize_from_checkpoint().await?;
+    /// building the transaction table and dirty page table in a single streaming
+    /// forward pass, without ever materializing the whole log into memory.
+    pub fn analyze(&mut self) -> Result<AnalysisResult, RecoveryError> {
+        // Step 1: Locate the last checkpoint (if any) and the byte offset of its
+        // `CheckpointEnd` record, so the forward scan below can start reading there
+        // directly instead of from the beginning of the file.
+        let checkpoint = self.wal_reader.find_last_checkpoint_with_offset().map_err(|e| {
+            RecoveryError::WalError(format!("Error finding last checkpoint: {}", e))
+        })?;
+
+        let (start_lsn, start_offset) = match checkpoint {
+            Some((checkpoint_lsn, offset)) => {
+                self.result.last_checkpoint_lsn = Some(checkpoint_lsn);
+                self.result.checkpoint_used = Some(checkpoint_lsn);
+                (checkpoint_lsn, offset)
+            }
+            None => (0, 0),
+        };
 
-        // Step 3: Scan forward from checkpoint to end of log
-        self.scan_forward_from_checkpoint().await?;
+        // Step 2: Stream forward from there to the end of the log. The first
+        // `CheckpointEnd` encountered (if we started at one) seeds the tables; every
+        // record from then on is processed as it's read.
+        self.scan_forward(start_lsn, start_offset)?;
 
-        // Step 4: Determine redo starting LSN
+        // Step 3: Determine redo starting LSN
         self.determine_redo_lsn();
 
         Ok(self.result.clone())
     }
 
-    /// Finds the last checkpoint in the WAL.
-    async fn find_last_checkpoint(&mut self) -> Result<(), RecoveryError> {
-        match self.wal_reader.find_last_checkpoint() {
-            Ok(Some((_, checkpoint_end))) => {
-                // Extract LSN from the checkpoint end record
-                let checkpoint_lsn = match checkpoint_end {
-                    LogRecord::CheckpointEnd { lsn, .. } => lsn,
-                    _ => return Err(RecoveryError::WalError("Invalid checkpoint end record".to_string())),
-                };
-                self.result.last_checkpoint_lsn = Some(checkpoint_lsn);
-            }
-            Ok(None) => {
-                // No checkpoint found, will scan from beginning
-                self.result.last_checkpoint_lsn = None;
-            }
-            Err(e) => {
-                return Err(RecoveryError::WalError(format!(
-                    "Error finding last checkpoint: {}",
-                    e
-                )));
-            }
-        }
-        Ok(())
+    /// Like `analyze`, but starts the forward scan from a specific checkpoint already
+    /// known to exist - e.g. one of the older entries `WalReader::checkpoints` still
+    /// has on hand - instead of always the single newest one `analyze` finds via
+    /// `find_last_checkpoint_with_offset`. This is what makes point-in-time recovery
+    /// possible: replaying only up to some earlier point by resuming from the nearest
+    /// preceding checkpoint in the retained history rather than being forced to always
+    /// start at the newest one.
+    ///
+    /// # Errors
+    /// Returns `RecoveryError::WalError` if `checkpoint_lsn` doesn't match any
+    /// completed checkpoint's `CheckpointEnd` LSN in the WAL, or if the WAL can't be
+    /// read.
+    pub fn analyze_from(&mut self, checkpoint_lsn: Lsn) -> Result<AnalysisResult, RecoveryError> {
+        let start_offset = self
+            .wal_reader
+            .find_checkpoint_offset(checkpoint_lsn)
+            .map_err(|e| {
+                RecoveryError::WalError(format!(
+                    "Error finding checkpoint {}: {}",
+                    checkpoint_lsn, e
+                ))
+            })?
+            .ok_or_else(|| {
+                RecoveryError::WalError(format!(
+                    "No completed checkpoint found at LSN {}",
+                    checkpoint_lsn
+                ))
+            })?;
+
+        self.result.last_checkpoint_lsn = Some(checkpoint_lsn);
+        self.result.checkpoint_used = Some(checkpoint_lsn);
+
+        self.scan_forward(checkpoint_lsn, start_offset)?;
+        self.determine_redo_lsn();
+
+        Ok(self.result.clone())
     }
 
-    /// Initializes the transaction and dirty page tables from the last checkpoint.
-    async fn initialize_from_checkpoint(&mut self) -> Result<(), RecoveryError> {
-        if let Some(checkpoint_lsn) = self.result.last_checkpoint_lsn {
-            // Find the CheckpointEnd record that contains the table data
-            let records = self
-                .wal_reader
-                .read_all_records()
-                .map_err(|e| RecoveryError::WalError(format!("Failed to read records: {}", e)))?;
-
-            for record in records {
-                if let LogRecord::CheckpointEnd {
-                    lsn,
-                    active_transactions,
-                    dirty_pages,
-                } = record
+    /// Streams WAL records one at a time starting at `start_offset` (the byte offset
+    /// of `start_lsn`, or `0` if there was no checkpoint), initializing the tables
+    /// from the first matching `CheckpointEnd` record and processing every record
+    /// from `start_lsn` onward - all in one forward pass over the log.
+    fn scan_forward(&mut self, start_lsn: Lsn, start_offset: u64) -> Result<(), RecoveryError> {
+        let mut iterator = self
+            .wal_reader
+            .iter_records_from_offset(start_offset)
+            .map_err(|e| RecoveryError::WalError(format!("Failed to seek WAL: {}", e)))?;
+
+        let mut awaiting_checkpoint_tables = self.result.last_checkpoint_lsn.is_some();
+
+        while let Some(record) = iterator
+            .next_record()
+            .map_err(|e| RecoveryError::WalError(format!("Failed to read record: {}", e)))?
+        {
+            if awaiting_checkpoint_tables {
+                if let LogRecord::CheckpointEnd { lsn, ref active_transactions, ref dirty_pages } =
+                    record
                 {
-                    if lsn == checkpoint_lsn {
-                        self.initialize_transaction_table_from_checkpoint(&active_transactions);
-                        self.initialize_dirty_page_table_from_checkpoint(&dirty_pages);
-                        break;
+                    if lsn == start_lsn {
+                        self.initialize_transaction_table_from_checkpoint(active_transactions);
+                        self.initialize_dirty_page_table_from_checkpoint(dirty_pages);
+                        awaiting_checkpoint_tables = false;
                     }
                 }
             }
+
+            let record_lsn = self.get_record_lsn(&record);
+            if record_lsn >= start_lsn {
+                self.result.min_lsn_seen =
+                    Some(self.result.min_lsn_seen.map_or(record_lsn, |min| min.min(record_lsn)));
+                self.result.max_lsn_seen =
+                    Some(self.result.max_lsn_seen.map_or(record_lsn, |max| max.max(record_lsn)));
+
+                self.process_log_record(&record)?;
+                self.result.records_processed += 1;
+            }
         }
+
+        self.result.bytes_replayed = iterator.offset().saturating_sub(start_offset);
+
         Ok(())
     }
 
@@ -176,26 +249,6 @@ impl<'a> AnalysisPhase<'a> {
         }
     }
 
-    /// Scans forward from the checkpoint (or beginning) to the end of the log.
-    async fn scan_forward_from_checkpoint(&mut self) -> Result<(), RecoveryError> {
-        let start_lsn = self.result.last_checkpoint_lsn.unwrap_or(0);
-
-        let records = self
-            .wal_reader
-            .read_all_records()
-            .map_err(|e| RecoveryError::WalError(format!("Failed to read records: {}", e)))?;
-
-        for record in records {
-            // Only process records after the checkpoint
-            if self.get_record_lsn(&record) >= start_lsn {
-                self.process_log_record(&record)?;
-                self.result.records_processed += 1;
-            }
-        }
-
-        Ok(())
-    }
-
     /// Processes a single log record during the forward scan.
     fn process_log_record(&mut self, record: &LogRecord) -> Result<(), RecoveryError> {
         match record {
@@ -255,6 +308,15 @@ impl<'a> AnalysisPhase<'a> {
                     self.result.dirty_page_table.insert(*page_id, *lsn);
                 }
             }
+            LogRecord::Savepoint { tx_id, lsn, name, .. } => {
+                // Savepoints advance the transaction's last LSN like any other record,
+                // but never dirty a page - they only mark a point in the log to
+                // undo back to, recorded on the transaction's own savepoint stack.
+                self.result.transaction_table.update_transaction(*tx_id, *lsn);
+                if let Some(tx_info) = self.result.transaction_table.get_mut(tx_id) {
+                    tx_info.push_savepoint(name.clone(), *lsn);
+                }
+            }
             LogRecord::CheckpointBegin { .. } | LogRecord::CheckpointEnd { .. } => {
                 // Checkpoint records don't affect transaction or dirty page state
             }
@@ -280,7 +342,8 @@ impl<'a> AnalysisPhase<'a> {
             | LogRecord::NewPage { lsn, .. }
             | LogRecord::CompensationLogRecord { lsn, .. }
             | LogRecord::CheckpointBegin { lsn, .. }
-            | LogRecord::CheckpointEnd { lsn, .. } => *lsn,
+            | LogRecord::CheckpointEnd { lsn, .. }
+            | LogRecord::Savepoint { lsn, .. } => *lsn,
         }
     }
 }
@@ -294,9 +357,7 @@ mod tests {
     use crate::core::wal::reader::{WalReader, WalReaderConfig};
     use crate::core::wal::writer::{WalWriter, WalWriterConfig};
     use tempfile::NamedTempFile;
-    use tokio;
-
-    async fn create_test_wal_with_records(records: Vec<LogRecord>) -> NamedTempFile {
+    fn create_test_wal_with_records(records: Vec<LogRecord>) -> NamedTempFile {
         let temp_file = NamedTempFile::new().unwrap();
         let config = WalWriterConfig::default();
         let mut writer = WalWriter::new(temp_file.path().to_path_buf(), config);
@@ -309,14 +370,14 @@ mod tests {
         temp_file
     }
 
-    #[tokio::test]
-    async fn test_analysis_empty_wal() {
-        let temp_file = create_test_wal_with_records(vec![]).await;
+    #[test]
+    fn test_analysis_empty_wal() {
+        let temp_file = create_test_wal_with_records(vec![]);
         let config = WalReaderConfig::default();
         let mut wal_reader = WalReader::new(temp_file.path(), config);
         
         let mut analysis = AnalysisPhase::new(&mut wal_reader);
-        let result = analysis.analyze().await.unwrap();
+        let result = analysis.analyze().unwrap();
         
         assert_eq!(result.transaction_table.len(), 0);
         assert_eq!(result.dirty_page_table.len(), 0);
@@ -325,8 +386,8 @@ mod tests {
         assert!(!result.recovery_needed());
     }
 
-    #[tokio::test]
-    async fn test_analysis_simple_transaction() {
+    #[test]
+    fn test_analysis_simple_transaction() {
         let tx_id = TransactionId(1);
         let page_id = PageId(100);
         let slot_id = SlotId(1);
@@ -348,12 +409,12 @@ mod tests {
             },
         ];
         
-        let temp_file = create_test_wal_with_records(records).await;
+        let temp_file = create_test_wal_with_records(records);
         let config = WalReaderConfig::default();
         let mut wal_reader = WalReader::new(temp_file.path(), config);
         
         let mut analysis = AnalysisPhase::new(&mut wal_reader);
-        let result = analysis.analyze().await.unwrap();
+        let result = analysis.analyze().unwrap();
         
         assert_eq!(result.transaction_table.len(), 1);
         assert_eq!(result.dirty_page_table.len(), 1);
@@ -370,8 +431,8 @@ mod tests {
         assert_eq!(page_info.recovery_lsn, 2);
     }
 
-    #[tokio::test]
-    async fn test_analysis_active_transaction() {
+    #[test]
+    fn test_analysis_active_transaction() {
         let tx_id = TransactionId(1);
         let page_id = PageId(100);
         let slot_id = SlotId(1);
@@ -389,12 +450,12 @@ mod tests {
             // No commit record - transaction is still active
         ];
         
-        let temp_file = create_test_wal_with_records(records).await;
+        let temp_file = create_test_wal_with_records(records);
         let config = WalReaderConfig::default();
         let mut wal_reader = WalReader::new(temp_file.path(), config);
         
         let mut analysis = AnalysisPhase::new(&mut wal_reader);
-        let result = analysis.analyze().await.unwrap();
+        let result = analysis.analyze().unwrap();
         
         assert_eq!(result.active_transaction_count(), 1);
         assert!(result.recovery_needed());
@@ -405,8 +466,8 @@ mod tests {
         assert!(tx_info.needs_undo());
     }
 
-    #[tokio::test]
-    async fn test_analysis_with_checkpoint() {
+    #[test]
+    fn test_analysis_with_checkpoint() {
         let tx_id = TransactionId(1);
         let page_id = PageId(100);
         
@@ -438,12 +499,12 @@ mod tests {
             },
         ];
         
-        let temp_file = create_test_wal_with_records(records).await;
+        let temp_file = create_test_wal_with_records(records);
         let config = WalReaderConfig::default();
         let mut wal_reader = WalReader::new(temp_file.path(), config);
         
         let mut analysis = AnalysisPhase::new(&mut wal_reader);
-        let result = analysis.analyze().await.unwrap();
+        let result = analysis.analyze().unwrap();
         
         assert_eq!(result.last_checkpoint_lsn, Some(11));
         assert_eq!(result.transaction_table.len(), 1);
@@ -454,4 +515,94 @@ mod tests {
         let tx_info = result.transaction_table.get(&tx_id).unwrap();
         assert_eq!(tx_info.last_lsn, 12); // Updated by the UpdateRecord
     }
+
+    #[test]
+    fn test_analyze_from_resumes_at_an_older_checkpoint() {
+        let tx_id = TransactionId(1);
+        let page_id = PageId(100);
+
+        let records = vec![
+            LogRecord::CheckpointBegin { lsn: 10 },
+            LogRecord::CheckpointEnd { lsn: 11, active_transactions: vec![], dirty_pages: vec![] },
+            LogRecord::BeginTransaction { lsn: 12, tx_id },
+            LogRecord::InsertRecord {
+                lsn: 13,
+                tx_id,
+                page_id,
+                slot_id: SlotId(1),
+                record_data: vec![1, 2],
+                prev_lsn: 12,
+            },
+            LogRecord::CommitTransaction { lsn: 14, tx_id, prev_lsn: 13 },
+            LogRecord::CheckpointBegin { lsn: 20 },
+            LogRecord::CheckpointEnd { lsn: 21, active_transactions: vec![], dirty_pages: vec![] },
+        ];
+
+        let temp_file = create_test_wal_with_records(records);
+        let config = WalReaderConfig::default();
+        let mut wal_reader = WalReader::new(temp_file.path(), config);
+
+        // The newest checkpoint (21) skips the committed transaction entirely; asking
+        // to resume from the older one (11) should still pick it up.
+        let mut analysis = AnalysisPhase::new(&mut wal_reader);
+        let result = analysis.analyze_from(11).unwrap();
+
+        assert_eq!(result.checkpoint_used, Some(11));
+        assert_eq!(result.last_checkpoint_lsn, Some(11));
+        assert_eq!(result.transaction_table.len(), 1);
+        let tx_info = result.transaction_table.get(&tx_id).unwrap();
+        assert_eq!(tx_info.state, crate::core::recovery::types::TransactionState::Committed);
+    }
+
+    #[test]
+    fn test_analyze_from_unknown_checkpoint_errors() {
+        let temp_file = create_test_wal_with_records(vec![]);
+        let config = WalReaderConfig::default();
+        let mut wal_reader = WalReader::new(temp_file.path(), config);
+
+        let mut analysis = AnalysisPhase::new(&mut wal_reader);
+        assert!(analysis.analyze_from(999).is_err());
+    }
+
+    #[test]
+    fn test_analyze_sets_checkpoint_used() {
+        let records = vec![
+            LogRecord::CheckpointBegin { lsn: 1 },
+            LogRecord::CheckpointEnd { lsn: 2, active_transactions: vec![], dirty_pages: vec![] },
+        ];
+
+        let temp_file = create_test_wal_with_records(records);
+        let config = WalReaderConfig::default();
+        let mut wal_reader = WalReader::new(temp_file.path(), config);
+
+        let mut analysis = AnalysisPhase::new(&mut wal_reader);
+        let result = analysis.analyze().unwrap();
+
+        assert_eq!(result.checkpoint_used, Some(2));
+    }
+
+    #[test]
+    fn test_analysis_reports_winner_loser_counts_and_lsn_range() {
+        let committed_tx = TransactionId(1);
+        let active_tx = TransactionId(2);
+
+        let records = vec![
+            LogRecord::BeginTransaction { lsn: 1, tx_id: committed_tx },
+            LogRecord::CommitTransaction { lsn: 2, tx_id: committed_tx, prev_lsn: 1 },
+            LogRecord::BeginTransaction { lsn: 3, tx_id: active_tx },
+        ];
+
+        let temp_file = create_test_wal_with_records(records);
+        let config = WalReaderConfig::default();
+        let mut wal_reader = WalReader::new(temp_file.path(), config);
+
+        let mut analysis = AnalysisPhase::new(&mut wal_reader);
+        let result = analysis.analyze().unwrap();
+
+        assert_eq!(result.winner_count(), 1);
+        assert_eq!(result.active_transaction_count(), 1);
+        assert_eq!(result.min_lsn_seen, Some(1));
+        assert_eq!(result.max_lsn_seen, Some(3));
+        assert!(result.bytes_replayed > 0);
+    }
 }
\ No newline at end of file
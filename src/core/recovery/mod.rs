@@ -24,14 +24,45 @@ pub mod types;
 pub mod undo;
 
 pub use analysis::{AnalysisPhase, AnalysisResult};
-pub use redo::RedoPhase;
+pub use redo::{RedoPhase, RedoStatistics};
 pub use tables::{DirtyPageTable, TransactionTable};
 pub use types::{RecoveryError, RecoveryState, TransactionState};
-pub use undo::UndoPhase;
+pub use undo::{UndoPhase, UndoStatistics};
 
+use crate::core::common::types::Lsn;
 use crate::core::wal::reader::WalReader;
 use std::path::Path;
 
+/// Combined statistics from one full `RecoveryManager::recover` run: how much of the
+/// WAL the Analysis phase scanned, and what the Redo and Undo phases then did about
+/// it. Exists for monitoring recovery time, spotting runaway open transactions left
+/// over from before a crash, and capacity planning around WAL retention - `recover`
+/// used to report nothing but success or failure.
+#[derive(Debug, Clone)]
+pub struct RecoveryStats {
+    /// Total log records the Analysis phase scanned.
+    pub records_scanned: usize,
+    /// The lowest and highest LSN the Analysis phase saw, if it scanned anything.
+    pub lsn_range: Option<(Lsn, Lsn)>,
+    /// Bytes of the WAL file the Analysis phase actually read.
+    pub bytes_replayed: u64,
+    /// The checkpoint analysis resumed from, if any.
+    pub checkpoint_used: Option<Lsn>,
+    /// Transactions that had already committed before the crash - the Redo phase
+    /// must make sure their effects survive it.
+    pub winner_count: usize,
+    /// Transactions still active at crash time - the Undo phase must roll these
+    /// back.
+    pub loser_count: usize,
+    /// Dirty pages the Analysis phase found (from records scanned plus any
+    /// checkpoint it resumed from) that the Redo phase then had to consider.
+    pub dirty_page_count: usize,
+    /// What the Redo phase did.
+    pub redo: RedoStatistics,
+    /// What the Undo phase did, including how many CLRs it generated.
+    pub undo: UndoStatistics,
+}
+
 /// The main recovery manager that orchestrates the ARIES recovery process.
 ///
 /// This struct coordinates the three phases of recovery and maintains the overall
@@ -59,35 +90,56 @@ impl RecoveryManager {
     /// 1. Analysis phase to build transaction and dirty page tables
     /// 2. Redo phase to restore the database state
     /// 3. Undo phase to roll back uncommitted transactions
-    pub async fn recover(&mut self) -> Result<(), RecoveryError> {
+    ///
+    /// Returns [`RecoveryStats`] summarizing what each phase found and did, for
+    /// monitoring and capacity planning.
+    pub fn recover(&mut self) -> Result<RecoveryStats, RecoveryError> {
         // Phase 1: Analysis
-        let analysis_result = self.run_analysis_phase().await?;
+        let analysis_result = self.run_analysis_phase()?;
 
         // Phase 2: Redo
-        self.run_redo_phase(&analysis_result)?;
+        let redo_stats = self.run_redo_phase(&analysis_result)?;
 
         // Phase 3: Undo
-        self.run_undo_phase(&analysis_result)?;
-
-        Ok(())
+        let undo_stats = self.run_undo_phase(&analysis_result)?;
+
+        Ok(RecoveryStats {
+            records_scanned: analysis_result.records_processed,
+            lsn_range: analysis_result.min_lsn_seen.zip(analysis_result.max_lsn_seen),
+            bytes_replayed: analysis_result.bytes_replayed,
+            checkpoint_used: analysis_result.checkpoint_used,
+            winner_count: analysis_result.winner_count(),
+            loser_count: analysis_result.active_transaction_count(),
+            dirty_page_count: analysis_result.dirty_page_count(),
+            redo: redo_stats,
+            undo: undo_stats,
+        })
     }
 
     /// Runs the Analysis phase of recovery.
-    async fn run_analysis_phase(&mut self) -> Result<AnalysisResult, RecoveryError> {
+    fn run_analysis_phase(&mut self) -> Result<AnalysisResult, RecoveryError> {
         let mut analysis_phase = AnalysisPhase::new(&mut self.wal_reader);
-        analysis_phase.analyze().await
+        analysis_phase.analyze()
     }
 
     /// Runs the Redo phase of recovery.
-    fn run_redo_phase(&mut self, analysis_result: &AnalysisResult) -> Result<(), RecoveryError> {
+    fn run_redo_phase(
+        &mut self,
+        analysis_result: &AnalysisResult,
+    ) -> Result<RedoStatistics, RecoveryError> {
         let mut redo_phase = RedoPhase::new(analysis_result.dirty_page_table.clone());
-        redo_phase.redo(&self.wal_file_path)
+        redo_phase.redo(&self.wal_file_path)?;
+        Ok(redo_phase.get_statistics())
     }
 
     /// Runs the Undo phase of recovery.
-    fn run_undo_phase(&mut self, analysis_result: &AnalysisResult) -> Result<(), RecoveryError> {
+    fn run_undo_phase(
+        &mut self,
+        analysis_result: &AnalysisResult,
+    ) -> Result<UndoStatistics, RecoveryError> {
         let mut undo_phase = UndoPhase::new(analysis_result.transaction_table.clone());
-        undo_phase.undo(&self.wal_file_path)
+        undo_phase.undo(&self.wal_file_path)?;
+        Ok(undo_phase.get_statistics().clone())
     }
 }
 
@@ -97,8 +149,8 @@ mod tests {
     use crate::core::wal::WalReader;
     use tempfile::NamedTempFile;
 
-    #[tokio::test]
-    async fn test_recovery_manager_creation() {
+    #[test]
+    fn test_recovery_manager_creation() {
         let temp_file = NamedTempFile::new().unwrap();
         let wal_reader = WalReader::with_defaults(temp_file.path());
 
@@ -106,10 +158,24 @@ mod tests {
         assert!(recovery_manager.wal_reader.get_statistics().unwrap().total_records == 0);
     }
 
-    #[tokio::test]
-    async fn test_recovery_manager_from_file() {
+    #[test]
+    fn test_recovery_manager_from_file() {
         let temp_file = NamedTempFile::new().unwrap();
         let recovery_manager = RecoveryManager::from_wal_file(temp_file.path());
         assert!(recovery_manager.is_ok());
     }
+
+    #[test]
+    fn test_recover_reports_stats_for_empty_wal() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let mut recovery_manager = RecoveryManager::from_wal_file(temp_file.path()).unwrap();
+
+        let stats = recovery_manager.recover().unwrap();
+
+        assert_eq!(stats.records_scanned, 0);
+        assert_eq!(stats.lsn_range, None);
+        assert_eq!(stats.bytes_replayed, 0);
+        assert_eq!(stats.winner_count, 0);
+        assert_eq!(stats.loser_count, 0);
+    }
 }
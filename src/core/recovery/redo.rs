@@ -10,6 +10,7 @@
 //! - Apply changes only if the page LSN is less than the log record LSN
 //! - Update page LSNs after successful redo operations
 
+use crate::core::common::types::ids::SlotId;
 use crate::core::common::types::{Lsn, PageId};
 use crate::core::recovery::tables::DirtyPageTable;
 use crate::core::recovery::types::{RecoveryError, RecoveryState};
@@ -102,19 +103,22 @@ impl RedoPhase {
     fn process_log_record(&mut self, log_record: &LogRecord) -> Result<(), RecoveryError> {
         match log_record {
             LogRecord::UpdateRecord {
-                lsn, page_id, old_record_data: _, new_record_data, ..
-            } => self.redo_update(*lsn, *page_id, new_record_data)?,
+                lsn, page_id, slot_id, old_record_data: _, new_record_data, ..
+            } => self.redo_update(*lsn, *page_id, *slot_id, new_record_data)?,
             LogRecord::InsertRecord { lsn, page_id, record_data, .. } => {
                 self.redo_insert(*lsn, *page_id, record_data)?;
             }
-            LogRecord::DeleteRecord { lsn, page_id, .. } => self.redo_delete(*lsn, *page_id)?,
+            LogRecord::DeleteRecord { lsn, page_id, slot_id, .. } => {
+                self.redo_delete(*lsn, *page_id, *slot_id)?;
+            }
             LogRecord::BeginTransaction { .. }
             | LogRecord::CommitTransaction { .. }
             | LogRecord::AbortTransaction { .. }
             | LogRecord::CheckpointBegin { .. }
             | LogRecord::CheckpointEnd { .. }
             | LogRecord::NewPage { .. }
-            | LogRecord::CompensationLogRecord { .. } => {
+            | LogRecord::CompensationLogRecord { .. }
+            | LogRecord::Savepoint { .. } => {
                 // These record types don't require redo operations
             }
         }
@@ -126,11 +130,13 @@ impl RedoPhase {
     /// # Arguments
     /// * `lsn` - LSN of the log record
     /// * `page_id` - ID of the page to update
+    /// * `slot_id` - Slot holding the record to update
     /// * `after_image` - The data to apply to the page
     fn redo_update(
         &mut self,
         lsn: Lsn,
         page_id: PageId,
+        slot_id: SlotId,
         after_image: &[u8],
     ) -> Result<(), RecoveryError> {
         // Only redo if the page was dirty at crash time
@@ -143,7 +149,7 @@ impl RedoPhase {
 
         // Only redo if page LSN < log record LSN
         if page_guard.get_lsn() < lsn {
-            page_guard.apply_update(after_image).map_err(|e| {
+            page_guard.apply_update(slot_id, after_image).map_err(|e| {
                 RecoveryError::RedoError(format!(
                     "Failed to apply update to page {}: {}",
                     page_id.0, e
@@ -159,6 +165,13 @@ impl RedoPhase {
 
     /// Redoes an insert operation.
     ///
+    /// `data` is applied to `page_id` verbatim, whether it's an ordinary record, an
+    /// overflow chain's head record, or a chunk of an overflow page's body
+    /// (`crate::core::storage::engine::overflow`): every page in a chain is written and
+    /// WAL-logged on its own, so replaying each page's record independently already
+    /// reconstructs the whole chain. Reassembling the chain into a value is purely a
+    /// read-side concern (`overflow::reassemble`), not redo's.
+    ///
     /// # Arguments
     /// * `lsn` - LSN of the log record
     /// * `page_id` - ID of the page to insert into
@@ -180,6 +193,8 @@ impl RedoPhase {
                     page_id.0, e
                 ))
             })?;
+            // The returned SlotId isn't consulted here: redo just needs the record's bytes
+            // to exist on the page again, not to land at the exact slot it originally did.
 
             page_guard.set_lsn(lsn);
             log::// debug!("Redid insert on page {} with LSN {}", page_id.0, lsn);
@@ -193,7 +208,13 @@ impl RedoPhase {
     /// # Arguments
     /// * `lsn` - LSN of the log record
     /// * `page_id` - ID of the page to delete from
-    fn redo_delete(&mut self, lsn: Lsn, page_id: PageId) -> Result<(), RecoveryError> {
+    /// * `slot_id` - Slot holding the record to delete
+    fn redo_delete(
+        &mut self,
+        lsn: Lsn,
+        page_id: PageId,
+        slot_id: SlotId,
+    ) -> Result<(), RecoveryError> {
         // Only redo if the page was dirty at crash time
         if !self.dirty_page_table.contains(&page_id) {
             return Ok(());
@@ -204,7 +225,7 @@ impl RedoPhase {
 
         // Only redo if page LSN < log record LSN
         if page_guard.get_lsn() < lsn {
-            page_guard.apply_delete().map_err(|e| {
+            page_guard.apply_delete(slot_id).map_err(|e| {
                 RecoveryError::RedoError(format!(
                     "Failed to apply delete to page {}: {}",
                     page_id.0, e
@@ -290,7 +311,8 @@ impl RedoPhase {
             | LogRecord::NewPage { lsn, .. }
             | LogRecord::CompensationLogRecord { lsn, .. }
             | LogRecord::CheckpointBegin { lsn, .. }
-            | LogRecord::CheckpointEnd { lsn, .. } => *lsn,
+            | LogRecord::CheckpointEnd { lsn, .. }
+            | LogRecord::Savepoint { lsn, .. } => *lsn,
         }
     }
 }
@@ -71,6 +71,9 @@ pub struct TransactionInfo {
     pub last_lsn: Lsn,
     /// The LSN to start undoing from (for active transactions)
     pub undo_next_lsn: Option<Lsn>,
+    /// Named savepoints established within this transaction, oldest first, as
+    /// `(name, lsn)` pairs recording the `LogRecord::Savepoint` that created each one.
+    pub savepoints: Vec<(String, Lsn)>,
 }
 
 impl TransactionInfo {
@@ -81,6 +84,7 @@ impl TransactionInfo {
             state: TransactionState::Active,
             last_lsn,
             undo_next_lsn: Some(last_lsn),
+            savepoints: Vec::new(),
         }
     }
 
@@ -91,6 +95,7 @@ impl TransactionInfo {
             state: TransactionState::Committed,
             last_lsn,
             undo_next_lsn: None,
+            savepoints: Vec::new(),
         }
     }
 
@@ -101,9 +106,32 @@ impl TransactionInfo {
             state: TransactionState::Aborted,
             last_lsn,
             undo_next_lsn: None,
+            savepoints: Vec::new(),
         }
     }
 
+    /// Records a new savepoint named `name` at `lsn`, nested after any already on
+    /// the stack.
+    pub fn push_savepoint(&mut self, name: String, lsn: Lsn) {
+        self.savepoints.push((name, lsn));
+    }
+
+    /// Looks up the LSN of the most recent savepoint named `name`, if any.
+    pub fn find_savepoint(&self, name: &str) -> Option<Lsn> {
+        self.savepoints.iter().rev().find(|(n, _)| n == name).map(|(_, lsn)| *lsn)
+    }
+
+    /// Forgets every savepoint nested after `name`, leaving `name` itself on the
+    /// stack - mirroring `ROLLBACK TO name`, which stays inside `name` but discards
+    /// savepoints taken after it. Returns `name`'s LSN, or `None` if no such savepoint
+    /// exists.
+    pub fn rollback_to_savepoint(&mut self, name: &str) -> Option<Lsn> {
+        let position = self.savepoints.iter().rposition(|(n, _)| n == name)?;
+        let lsn = self.savepoints[position].1;
+        self.savepoints.truncate(position + 1);
+        Some(lsn)
+    }
+
     /// Updates the last LSN for this transaction.
     pub fn update_last_lsn(&mut self, lsn: Lsn) {
         self.last_lsn = lsn;
@@ -138,6 +138,95 @@ impl UndoPhase {
         Ok(())
     }
 
+    /// Rolls `tx_id` back to the savepoint named `savepoint_name`, without aborting
+    /// the rest of the transaction.
+    ///
+    /// Starting from the transaction's current `last_lsn`, this walks the `prev_lsn`
+    /// chain backwards exactly like [`Self::undo_transaction`], undoing each
+    /// `Insert`/`Delete`/`Update`/`NewPage` record and emitting a CLR for it, but stops
+    /// as soon as it reaches the savepoint's own LSN instead of undoing the whole
+    /// transaction. Each CLR's `next_undo_lsn` points at the record undone just before
+    /// it, so a crash partway through resumes the rollback from exactly where it left
+    /// off. The transaction's savepoint stack is truncated down to (and including)
+    /// `savepoint_name` once the rollback completes.
+    pub fn rollback_to_savepoint<P: AsRef<Path>>(
+        &mut self,
+        wal_path: P,
+        tx_id: TransactionId,
+        savepoint_name: &str,
+    ) -> Result<(), RecoveryError> {
+        let tx_info = self
+            .transaction_table
+            .get(&tx_id)
+            .ok_or_else(|| RecoveryError::UndoError(format!("unknown transaction {}", tx_id.0)))?;
+        let target_lsn = tx_info.find_savepoint(savepoint_name).ok_or_else(|| {
+            RecoveryError::UndoError(format!(
+                "no such savepoint '{savepoint_name}' for transaction {}",
+                tx_id.0
+            ))
+        })?;
+        let mut current_lsn = Some(tx_info.last_lsn);
+
+        self.initialize_wal_writer(&wal_path)?;
+        let reader = WalReader::with_defaults(wal_path.as_ref());
+        let all_records = reader
+            .read_all_records()
+            .map_err(|e| RecoveryError::UndoError(format!("Failed to read WAL records: {e}")))?;
+        let mut record_map: HashMap<Lsn, LogRecord> = HashMap::new();
+        for record in all_records {
+            let lsn = self.extract_lsn(&record);
+            record_map.insert(lsn, record);
+        }
+
+        let mut undo_next_lsn: Option<Lsn> = None;
+        let mut last_undone_lsn: Option<Lsn> = None;
+
+        while let Some(lsn) = current_lsn {
+            if lsn <= target_lsn {
+                break;
+            }
+            let Some(record) = record_map.get(&lsn) else { break };
+            if !self.record_belongs_to_transaction(record, tx_id) {
+                current_lsn = self.extract_prev_lsn(record);
+                continue;
+            }
+            if let LogRecord::CompensationLogRecord { next_undo_lsn: unl, .. } = record {
+                current_lsn = *unl;
+                continue;
+            }
+
+            debug!("Processing savepoint-rollback undo for record at LSN {}: {:?}", lsn, record);
+            let prev_lsn = self.undo_log_record(record, undo_next_lsn)?;
+            undo_next_lsn = Some(lsn);
+            last_undone_lsn = Some(lsn);
+            current_lsn = prev_lsn;
+            if matches!(
+                record,
+                LogRecord::InsertRecord { .. }
+                    | LogRecord::DeleteRecord { .. }
+                    | LogRecord::UpdateRecord { .. }
+            ) {
+                self.statistics.records_processed += 1;
+            }
+        }
+
+        if let Some(ref mut writer) = self.wal_writer {
+            writer
+                .flush()
+                .map_err(|e| RecoveryError::UndoError(format!("Failed to flush WAL: {e}")))?;
+        }
+
+        if let Some(tx_info) = self.transaction_table.get_mut(&tx_id) {
+            if let Some(lsn) = last_undone_lsn {
+                tx_info.update_last_lsn(lsn);
+            }
+            tx_info.rollback_to_savepoint(savepoint_name);
+        }
+
+        debug!("Rolled back transaction {} to savepoint '{}'", tx_id.0, savepoint_name);
+        Ok(())
+    }
+
     /// Undoes a single transaction by traversing its log records backwards.
     fn undo_transaction(
         &mut self,
@@ -506,7 +595,8 @@ impl UndoPhase {
             | LogRecord::DeleteRecord { tx_id: record_tx_id, .. }
             | LogRecord::UpdateRecord { tx_id: record_tx_id, .. }
             | LogRecord::NewPage { tx_id: record_tx_id, .. }
-            | LogRecord::CompensationLogRecord { tx_id: record_tx_id, .. } => {
+            | LogRecord::CompensationLogRecord { tx_id: record_tx_id, .. }
+            | LogRecord::Savepoint { tx_id: record_tx_id, .. } => {
                 *record_tx_id == tx_id
             }
             LogRecord::CheckpointBegin { .. } | LogRecord::CheckpointEnd { .. } => false,
@@ -525,7 +615,8 @@ impl UndoPhase {
             | LogRecord::NewPage { lsn, .. }
             | LogRecord::CompensationLogRecord { lsn, .. }
             | LogRecord::CheckpointBegin { lsn, .. }
-            | LogRecord::CheckpointEnd { lsn, .. } => *lsn,
+            | LogRecord::CheckpointEnd { lsn, .. }
+            | LogRecord::Savepoint { lsn, .. } => *lsn,
         }
     }
 
@@ -537,7 +628,8 @@ impl UndoPhase {
             | LogRecord::InsertRecord { prev_lsn, .. }
             | LogRecord::DeleteRecord { prev_lsn, .. }
             | LogRecord::UpdateRecord { prev_lsn, .. }
-            | LogRecord::NewPage { prev_lsn, .. } => Some(*prev_lsn),
+            | LogRecord::NewPage { prev_lsn, .. }
+            | LogRecord::Savepoint { prev_lsn, .. } => Some(*prev_lsn),
             LogRecord::BeginTransaction { .. }
             | LogRecord::CompensationLogRecord { .. }
             | LogRecord::CheckpointBegin { .. }
@@ -727,4 +819,65 @@ mod tests {
         assert_eq!(stats.clrs_generated, 0);
         assert_eq!(stats.state, RecoveryState::NotStarted);
     }
+
+    #[test]
+    fn test_rollback_to_savepoint_undoes_only_suffix_after_it() {
+        let tx_id = TransactionId(1);
+        let page_id = PageId(100);
+        let slot_id = SlotId(1);
+
+        let mut tx_info = TransactionInfo::new_active(tx_id, 4); // Last LSN is 4
+        tx_info.push_savepoint("sp1".to_string(), 3);
+        let mut transaction_table = TransactionTable::new();
+        transaction_table.insert(tx_info);
+
+        let records = vec![
+            LogRecord::BeginTransaction { lsn: 1, tx_id },
+            LogRecord::InsertRecord {
+                lsn: 2,
+                tx_id,
+                page_id,
+                slot_id,
+                record_data: vec![1, 2, 3],
+                prev_lsn: 1,
+            },
+            LogRecord::Savepoint { lsn: 3, tx_id, name: "sp1".to_string(), prev_lsn: 2 },
+            LogRecord::UpdateRecord {
+                lsn: 4,
+                tx_id,
+                page_id,
+                slot_id,
+                old_record_data: vec![1, 2, 3],
+                new_record_data: vec![4, 5, 6],
+                prev_lsn: 3,
+            },
+        ];
+
+        let temp_file = create_test_wal_with_records(records);
+        let mut undo_phase = UndoPhase::new(transaction_table);
+
+        let result = undo_phase.rollback_to_savepoint(temp_file.path(), tx_id, "sp1");
+
+        assert!(result.is_ok());
+        // Only the UpdateRecord after the savepoint should have been undone.
+        assert_eq!(undo_phase.get_statistics().records_processed, 1);
+        assert_eq!(undo_phase.get_statistics().clrs_generated, 1);
+
+        let tx_info = undo_phase.transaction_table.get(&tx_id).unwrap();
+        assert_eq!(tx_info.savepoints, vec![("sp1".to_string(), 3)]);
+    }
+
+    #[test]
+    fn test_rollback_to_savepoint_unknown_name_errors() {
+        let tx_id = TransactionId(1);
+        let mut transaction_table = TransactionTable::new();
+        transaction_table.insert(TransactionInfo::new_active(tx_id, 1));
+
+        let records = vec![LogRecord::BeginTransaction { lsn: 1, tx_id }];
+        let temp_file = create_test_wal_with_records(records);
+        let mut undo_phase = UndoPhase::new(transaction_table);
+
+        let result = undo_phase.rollback_to_savepoint(temp_file.path(), tx_id, "nope");
+        assert!(result.is_err());
+    }
 }
@@ -0,0 +1,8 @@
+//! Heap-file storage: slotted-page record layout layered over `Page::data`.
+
+pub mod table_page;
+
+#[cfg(test)]
+mod tests {
+    mod table_page_tests;
+}
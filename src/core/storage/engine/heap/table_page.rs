@@ -308,6 +308,13 @@ impl TablePage {
     }
 
     pub fn get_record(page_data: &[u8], slot_id: SlotId) -> Result<Option<Vec<u8>>, OxidbError> {
+        Ok(Self::get_record_slice(page_data, slot_id)?.map(<[u8]>::to_vec))
+    }
+
+    /// Like [`TablePage::get_record`], but borrows directly from `page_data` instead of
+    /// copying into an owned `Vec`. Used by [`super::super::page::PageView`] to read
+    /// records without allocating.
+    pub fn get_record_slice(page_data: &[u8], slot_id: SlotId) -> Result<Option<&[u8]>, OxidbError> {
         match Self::get_slot_info(page_data, slot_id)? {
             Some(slot) if slot.length > 0 => {
                 // Slot exists and is occupied
@@ -318,7 +325,7 @@ impl TablePage {
                         slot_id.0, slot.offset, slot.length, page_data.len()
                     )));
                 }
-                Ok(Some(page_data[slot.offset as usize..data_end].to_vec()))
+                Ok(Some(&page_data[slot.offset as usize..data_end]))
             }
             Some(_) => Ok(None), // Slot exists but is empty (length == 0)
             None => Ok(None),    // SlotId is out of bounds of current num_records
@@ -417,6 +424,50 @@ impl TablePage {
         }
         Ok(())
     }
+
+    /// Reclaims space held by tombstoned (deleted, `length == 0`) slots by repacking every
+    /// live record contiguously from the start of the data area and rewriting their slot
+    /// offsets, then resetting the free space pointer to just past the repacked data.
+    ///
+    /// Slot indices (`SlotId`s) are preserved, so callers holding onto a `SlotId` for a
+    /// still-live record see no change; only tombstoned slots and the free space layout
+    /// are affected.
+    pub fn compact(page_data: &mut [u8]) -> Result<(), OxidbError> {
+        let num_records = Self::get_num_records(page_data)?;
+
+        let mut live_slots = Vec::new();
+        for i in 0..num_records {
+            let slot_id = SlotId(i);
+            if let Some(slot) = Self::get_slot_info(page_data, slot_id)? {
+                if slot.length > 0 {
+                    let data_end = slot.offset as usize + slot.length as usize;
+                    let record = page_data[slot.offset as usize..data_end].to_vec();
+                    live_slots.push((slot_id, record));
+                }
+            }
+        }
+
+        let slot_array_end = SLOTS_ARRAY_DATA_OFFSET + (num_records as usize * Slot::SERIALIZED_SIZE);
+        let mut write_offset = slot_array_end;
+        for (slot_id, record) in &live_slots {
+            let write_end = write_offset + record.len();
+            page_data[write_offset..write_end].copy_from_slice(record);
+            Self::set_slot_info(
+                page_data,
+                *slot_id,
+                Slot { offset: write_offset as u16, length: record.len() as u16 },
+            )?;
+            write_offset = write_end;
+        }
+
+        // Clear the now-unused tail so no stale record bytes linger past free space.
+        for byte in &mut page_data[write_offset..] {
+            *byte = 0;
+        }
+
+        Self::set_free_space_pointer(page_data, write_offset as u16)?;
+        Ok(())
+    }
 }
 
 // Test module is removed from here and moved to src/core/storage/engine/heap/tests/table_page_tests.rs
@@ -251,6 +251,33 @@ fn test_insert_empty_record_error() {
     assert!(matches!(result, Err(OxidbError::InvalidInput { .. })));
 }
 
+#[test]
+fn test_compact_reclaims_tombstoned_space() {
+    let mut page_data = create_test_page_data();
+
+    let slot_a = TablePage::insert_record(&mut page_data, b"alpha").unwrap();
+    let slot_b = TablePage::insert_record(&mut page_data, b"bravo").unwrap();
+    let slot_c = TablePage::insert_record(&mut page_data, b"charlie").unwrap();
+
+    TablePage::delete_record(&mut page_data, slot_b).unwrap();
+
+    let free_space_before = TablePage::get_free_space_pointer(&page_data).unwrap();
+    TablePage::compact(&mut page_data).unwrap();
+    let free_space_after = TablePage::get_free_space_pointer(&page_data).unwrap();
+
+    // Compaction should reclaim the tombstoned "bravo" record's space.
+    assert!(free_space_after < free_space_before);
+
+    // Surviving slots still resolve to their original bytes under their original SlotIds.
+    assert_eq!(TablePage::get_record(&page_data, slot_a).unwrap().unwrap(), b"alpha");
+    assert_eq!(TablePage::get_record(&page_data, slot_c).unwrap().unwrap(), b"charlie");
+    assert!(TablePage::get_record(&page_data, slot_b).unwrap().is_none());
+
+    // The reclaimed space is usable by a subsequent insert.
+    let slot_d = TablePage::insert_record(&mut page_data, b"delta").unwrap();
+    assert_eq!(TablePage::get_record(&page_data, slot_d).unwrap().unwrap(), b"delta");
+}
+
 // Private helper methods in TablePage like get_num_records, set_num_records,
 // get_free_space_pointer, set_free_space_pointer, get_slot_info, set_slot_info
 // are not directly testable unless made pub(crate) or pub.
@@ -1,5 +1,6 @@
 use crate::core::common::traits::DataDeserializer;
 use crate::core::common::OxidbError;
+use crate::core::storage::engine::merkle::MerkleAccumulator;
 use crate::core::storage::engine::traits::VersionedValue;
 use crate::core::storage::engine::wal::WalEntry;
 use std::collections::{HashMap, HashSet};
@@ -12,13 +13,33 @@ pub(super) fn replay_wal_into_cache(
 	cache: &mut HashMap<Vec<u8>, Vec<VersionedValue<Vec<u8>>>>,
 	wal_file_path: &Path,
 ) -> Result<(), OxidbError> {
+	replay_wal_into_cache_verified(cache, wal_file_path, None).map(|_state_root| ())
+}
+
+/// Replays Write-Ahead Log entries into the cache, folding every committed `Put` into a
+/// [`MerkleAccumulator`] as it's applied and returning the resulting global state root.
+///
+/// If `expected_root` is `Some` (e.g. read back from a checkpoint record), the computed
+/// root is checked against it and an `OxidbError` is raised on mismatch, catching silent
+/// corruption of replayed values that would otherwise go undetected.
+///
+/// # Errors
+/// Propagates WAL I/O errors, and returns `OxidbError::Deserialization` if `expected_root`
+/// is given and doesn't match the computed root.
+pub(super) fn replay_wal_into_cache_verified(
+	cache: &mut HashMap<Vec<u8>, Vec<VersionedValue<Vec<u8>>>>,
+	wal_file_path: &Path,
+	expected_root: Option<u64>,
+) -> Result<u64, OxidbError> {
+	let mut accumulator = MerkleAccumulator::new();
+
 	if !wal_file_path.exists() {
-		return Ok(());
+		return finalize(&accumulator, expected_root);
 	}
 
 	let wal_file = match File::open(wal_file_path) {
 		Ok(f) => f,
-		Err(e) if e.kind() == ErrorKind::NotFound => return Ok(()),
+		Err(e) if e.kind() == ErrorKind::NotFound => return finalize(&accumulator, expected_root),
 		Err(e) => return Err(OxidbError::Io(e)),
 	};
 	let mut reader = BufReader::new(wal_file);
@@ -69,6 +90,7 @@ pub(super) fn replay_wal_into_cache(
 									break;
 								}
 							}
+							accumulator.fold(key, *transaction_id, value);
 							let new_version = VersionedValue { value: value.clone(), created_tx_id: *transaction_id, expired_tx_id: None };
 							versions.push(new_version);
 						}
@@ -92,5 +114,18 @@ pub(super) fn replay_wal_into_cache(
 			}
 		}
 	}
-	Ok(())
+	finalize(&accumulator, expected_root)
+}
+
+/// Computes the accumulated state root and, if `expected_root` is given, verifies it before
+/// returning.
+fn finalize(
+	accumulator: &MerkleAccumulator,
+	expected_root: Option<u64>,
+) -> Result<u64, OxidbError> {
+	let state_root = accumulator.state_root();
+	if let Some(expected_root) = expected_root {
+		accumulator.verify(expected_root)?;
+	}
+	Ok(state_root)
 }
\ No newline at end of file
@@ -1121,6 +1121,10 @@ fn test_physical_wal_lsn_integration() {
                 is_primary_key: true,
                 is_unique: true,
                 is_nullable: false,
+                is_auto_increment: false,
+                max_length: None,
+                is_fixed_length: false,
+                truncate_overflow: false,
             },
             crate::core::types::schema::ColumnDef {
                 name: "name".to_string(),
@@ -1128,6 +1132,10 @@ fn test_physical_wal_lsn_integration() {
                 is_primary_key: false,
                 is_unique: false,
                 is_nullable: true,
+                is_auto_increment: false,
+                max_length: None,
+                is_fixed_length: false,
+                truncate_overflow: false,
             },
         ],
     })
@@ -1140,6 +1148,8 @@ fn test_physical_wal_lsn_integration() {
             crate::core::types::DataType::Integer(1),
             crate::core::types::DataType::String("Alice".to_string()),
         ]],
+        on_conflict: None,
+        returning: None,
     })
     .expect("INSERT 1 failed");
 
@@ -1150,6 +1160,8 @@ fn test_physical_wal_lsn_integration() {
             crate::core::types::DataType::Integer(2),
             crate::core::types::DataType::String("Bob".to_string()),
         ]],
+        on_conflict: None,
+        returning: None,
     })
     .expect("INSERT 2 failed");
 
@@ -1166,6 +1178,7 @@ fn test_physical_wal_lsn_integration() {
                 value: crate::core::types::DataType::Integer(1),
             },
         )),
+        returning: None,
     })
     .expect("UPDATE failed");
 
@@ -1178,6 +1191,7 @@ fn test_physical_wal_lsn_integration() {
                 value: crate::core::types::DataType::Integer(2),
             },
         )),
+        returning: None,
     })
     .expect("DELETE failed");
 
@@ -1191,6 +1205,8 @@ fn test_physical_wal_lsn_integration() {
             crate::core::types::DataType::Integer(3),
             crate::core::types::DataType::String("Charlie".to_string()),
         ]],
+        on_conflict: None,
+        returning: None,
     })
     .expect("TX1: INSERT Charlie failed");
 
@@ -1208,6 +1224,7 @@ fn test_physical_wal_lsn_integration() {
                 value: crate::core::types::DataType::Integer(1),
             },
         )),
+        returning: None,
     })
     .expect("TX1: UPDATE Alice failed");
 
@@ -0,0 +1,143 @@
+//! Per-key Merkle accumulation over replayed WAL versions, for tamper-evident recovery.
+//!
+//! [`MerkleAccumulator`] folds each committed [`VersionedValue`](super::traits::VersionedValue)
+//! seen during WAL replay into a per-key hash chain, then combines the per-key roots (in
+//! sorted key order, so the result doesn't depend on hash map iteration order) into a single
+//! global state root. Persisting the expected root in a checkpoint record lets recovery
+//! detect silent corruption of replayed values by recomputing the root and comparing.
+//!
+//! Hashing uses `DefaultHasher`, the same content-hashing primitive already used elsewhere
+//! in this crate (e.g. [`caching_embedder`](crate::core::rag::caching_embedder)) rather than
+//! pulling in a cryptographic hash dependency this crate doesn't otherwise have.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use crate::core::common::OxidbError;
+
+/// Hashes one committed version as `H(key ‖ created_tx_id ‖ value)`. Only this hash — not
+/// the value bytes themselves — is folded into the tree ("inner value hashing"), so the
+/// full value can keep living in the cache while the tree stays cheap to maintain.
+fn hash_version(key: &[u8], created_tx_id: u64, value: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    created_tx_id.hash(&mut hasher);
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Combines two node hashes into their parent.
+fn combine(left: u64, right: u64) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    left.hash(&mut hasher);
+    right.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Accumulates committed versions into a tamper-evident Merkle state root.
+#[derive(Debug, Default)]
+pub struct MerkleAccumulator {
+    per_key_roots: HashMap<Vec<u8>, u64>,
+}
+
+impl MerkleAccumulator {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Folds one committed version of `key` into its per-key chain. Only the changed key's
+    /// chain is rehashed — every other key's per-key root is untouched.
+    pub fn fold(&mut self, key: &[u8], created_tx_id: u64, value: &[u8]) {
+        let version_hash = hash_version(key, created_tx_id, value);
+        self.per_key_roots
+            .entry(key.to_vec())
+            .and_modify(|root| *root = combine(*root, version_hash))
+            .or_insert(version_hash);
+    }
+
+    /// Computes the global state root over the sorted key set, so the result is
+    /// deterministic regardless of replay or hash-map iteration order.
+    #[must_use]
+    pub fn state_root(&self) -> u64 {
+        let mut keys: Vec<&Vec<u8>> = self.per_key_roots.keys().collect();
+        keys.sort();
+        keys.into_iter().fold(0u64, |acc, key| combine(acc, self.per_key_roots[key]))
+    }
+
+    /// Verifies the accumulated state root against `expected_root` (as persisted in a
+    /// checkpoint record).
+    ///
+    /// # Errors
+    /// Returns `OxidbError::Deserialization` if the computed root doesn't match, signalling
+    /// that replayed data was silently corrupted.
+    pub fn verify(&self, expected_root: u64) -> Result<(), OxidbError> {
+        let computed = self.state_root();
+        if computed == expected_root {
+            Ok(())
+        } else {
+            Err(OxidbError::Deserialization(format!(
+                "WAL replay integrity check failed: expected state root {expected_root:x}, computed {computed:x}"
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_root_is_order_independent_across_keys() {
+        let mut a = MerkleAccumulator::new();
+        a.fold(b"k1", 1, b"v1");
+        a.fold(b"k2", 2, b"v2");
+
+        let mut b = MerkleAccumulator::new();
+        b.fold(b"k2", 2, b"v2");
+        b.fold(b"k1", 1, b"v1");
+
+        assert_eq!(a.state_root(), b.state_root());
+    }
+
+    #[test]
+    fn test_state_root_changes_when_a_value_changes() {
+        let mut a = MerkleAccumulator::new();
+        a.fold(b"k1", 1, b"v1");
+
+        let mut b = MerkleAccumulator::new();
+        b.fold(b"k1", 1, b"different-value");
+
+        assert_ne!(a.state_root(), b.state_root());
+    }
+
+    #[test]
+    fn test_verify_succeeds_on_matching_root() {
+        let mut acc = MerkleAccumulator::new();
+        acc.fold(b"k1", 1, b"v1");
+        let root = acc.state_root();
+        assert!(acc.verify(root).is_ok());
+    }
+
+    #[test]
+    fn test_verify_fails_on_mismatched_root() {
+        let mut acc = MerkleAccumulator::new();
+        acc.fold(b"k1", 1, b"v1");
+        let err = acc.verify(acc.state_root().wrapping_add(1)).unwrap_err();
+        assert!(matches!(err, OxidbError::Deserialization(_)));
+    }
+
+    #[test]
+    fn test_multiple_versions_of_same_key_chain_into_one_root() {
+        let mut acc = MerkleAccumulator::new();
+        acc.fold(b"k1", 1, b"v1");
+        acc.fold(b"k1", 2, b"v2");
+        // Two per-key chained versions still collapse to a single per-key root, so the
+        // global root is still well-defined and reproducible.
+        let root_first = acc.state_root();
+        let root_second = acc.state_root();
+        assert_eq!(acc.per_key_roots.len(), 1);
+        assert_eq!(root_first, root_second);
+    }
+}
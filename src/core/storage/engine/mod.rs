@@ -2,7 +2,10 @@ pub mod buffer_pool_manager;
 pub mod disk_manager;
 pub mod heap;
 pub mod implementations;
+pub mod merkle;
+pub mod overflow;
 pub mod page;
+pub mod page_codec;
 pub mod traits;
 pub mod wal;
 
@@ -10,4 +13,5 @@ pub use buffer_pool_manager::BufferPoolManager;
 pub use disk_manager::DiskManager;
 pub use implementations::in_memory::InMemoryKvStore;
 pub use implementations::file::FileKvStore;
+pub use merkle::MerkleAccumulator;
 pub use page::{Page, PageHeader, PageType, PAGE_SIZE};
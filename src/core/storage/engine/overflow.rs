@@ -0,0 +1,190 @@
+//! Overflow chains for records too large to fit in a single page's body.
+//!
+//! A value that doesn't fit in its home page's record is split into a *head record*
+//! (inserted like any other record via [`TablePage::insert_record`](super::heap::table_page::TablePage::insert_record))
+//! containing a `[total_len: u32][first_overflow: PageId][inline bytes...]` prefix, plus a
+//! linked list of `PageType::Overflow` pages holding the remainder, each filled via
+//! [`Page::write_overflow_chunk`] and linked through `header.next_overflow`.
+//!
+//! Redo recovery needs no special handling for these chains: each page in a chain (the
+//! home page's head record, and every `Overflow` page) is written and WAL-logged
+//! independently, so the existing per-page redo logic in
+//! [`crate::core::recovery::redo`] already replays them correctly.
+
+use crate::core::common::error::OxidbError;
+use crate::core::common::types::PageId;
+use crate::core::storage::engine::page::{Page, PageType, NO_OVERFLOW};
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use std::io::Cursor;
+
+/// Size of a head record's prefix: `total_len: u32` + `first_overflow: PageId (u64)`.
+pub const HEAD_PREFIX_SIZE: usize = 4 + 8;
+
+/// Splits `value` into a head record (at most `max_head_record_len` bytes, including the
+/// [`HEAD_PREFIX_SIZE`]-byte prefix) and a chain of filled `PageType::Overflow` pages for
+/// whatever doesn't fit inline, allocating each new page's id via `alloc`.
+///
+/// Returns the head record's bytes (to be inserted into the home page like any other
+/// record) and the newly created overflow pages, which the caller is responsible for
+/// persisting (e.g. via the buffer pool).
+///
+/// # Errors
+/// Returns `OxidbError::InvalidInput` if `max_head_record_len` can't even hold the prefix,
+/// or if `alloc` fails.
+pub fn build_chain(
+    value: &[u8],
+    max_head_record_len: usize,
+    mut alloc: impl FnMut() -> Result<PageId, OxidbError>,
+) -> Result<(Vec<u8>, Vec<Page>), OxidbError> {
+    if max_head_record_len < HEAD_PREFIX_SIZE {
+        return Err(OxidbError::InvalidInput {
+            message: format!(
+                "max_head_record_len {max_head_record_len} too small for {HEAD_PREFIX_SIZE}-byte overflow head prefix"
+            ),
+        });
+    }
+
+    let inline_capacity = max_head_record_len - HEAD_PREFIX_SIZE;
+    let inline_len = value.len().min(inline_capacity);
+    let (inline, mut remainder) = value.split_at(inline_len);
+
+    let mut pages = Vec::new();
+    let mut first_overflow = NO_OVERFLOW;
+    let mut chain_tail: Option<usize> = None; // index into `pages` of the last linked page
+
+    while !remainder.is_empty() {
+        let page_id = alloc()?;
+        let mut page = Page::new(page_id, PageType::Overflow);
+        let written = page.write_overflow_chunk(remainder);
+        remainder = &remainder[written..];
+        pages.push(page);
+
+        if let Some(tail_index) = chain_tail {
+            pages[tail_index].header.next_overflow = page_id;
+        } else {
+            first_overflow = page_id;
+        }
+        chain_tail = Some(pages.len() - 1);
+    }
+
+    let mut head = Vec::with_capacity(HEAD_PREFIX_SIZE + inline.len());
+    {
+        let mut cursor = Cursor::new(&mut head);
+        cursor.write_u32::<LittleEndian>(value.len() as u32)?;
+        cursor.write_u64::<LittleEndian>(first_overflow.0)?;
+    }
+    head.extend_from_slice(inline);
+
+    Ok((head, pages))
+}
+
+/// Reassembles a value from a head record produced by [`build_chain`], fetching each
+/// subsequent overflow page via `fetch`.
+///
+/// # Errors
+/// Returns `OxidbError::Deserialization` if `head_record` is shorter than
+/// [`HEAD_PREFIX_SIZE`], or if the chain ends (runs out of pages) before `total_len` bytes
+/// have been recovered.
+pub fn reassemble(
+    head_record: &[u8],
+    mut fetch: impl FnMut(PageId) -> Result<Page, OxidbError>,
+) -> Result<Vec<u8>, OxidbError> {
+    if head_record.len() < HEAD_PREFIX_SIZE {
+        return Err(OxidbError::Deserialization(format!(
+            "overflow head record shorter ({}) than prefix ({HEAD_PREFIX_SIZE})",
+            head_record.len()
+        )));
+    }
+
+    let mut cursor = Cursor::new(head_record);
+    let total_len = cursor.read_u32::<LittleEndian>()? as usize;
+    let mut next = PageId(cursor.read_u64::<LittleEndian>()?);
+
+    let mut result = Vec::with_capacity(total_len);
+    result.extend_from_slice(&head_record[HEAD_PREFIX_SIZE..]);
+
+    while result.len() < total_len {
+        if next == NO_OVERFLOW {
+            return Err(OxidbError::Deserialization(format!(
+                "overflow chain ended after {} of {total_len} bytes",
+                result.len()
+            )));
+        }
+        let page = fetch(next)?;
+        let chunk_len = page.header.payload_len as usize;
+        result.extend_from_slice(&page.data[..chunk_len.min(page.data.len())]);
+        next = page.header.next_overflow;
+    }
+
+    result.truncate(total_len);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_and_reassemble_roundtrip() {
+        let value: Vec<u8> = (0..10_000u32).flat_map(u32::to_le_bytes).collect();
+        let mut next_id = 1u64;
+        let (head, pages) = build_chain(&value, 100, || {
+            let id = PageId(next_id);
+            next_id += 1;
+            Ok(id)
+        })
+        .expect("build_chain failed");
+
+        assert!(!pages.is_empty(), "value larger than max_head_record_len should overflow");
+
+        let reassembled = reassemble(&head, |page_id| {
+            pages
+                .iter()
+                .find(|p| p.header.page_id == page_id)
+                .cloned()
+                .ok_or_else(|| OxidbError::NotFound(format!("page {} not found", page_id.0)))
+        })
+        .expect("reassemble failed");
+
+        assert_eq!(reassembled, value);
+    }
+
+    #[test]
+    fn test_build_chain_fits_inline_without_overflow() {
+        let value = b"small value".to_vec();
+        let (head, pages) = build_chain(&value, 100, || {
+            panic!("alloc should not be called when the value fits inline")
+        })
+        .expect("build_chain failed");
+
+        assert!(pages.is_empty());
+        let reassembled = reassemble(&head, |_| {
+            panic!("fetch should not be called when there's no overflow chain")
+        })
+        .expect("reassemble failed");
+        assert_eq!(reassembled, value);
+    }
+
+    #[test]
+    fn test_reassemble_truncated_chain_errors() {
+        let value = vec![42u8; 5000];
+        let mut next_id = 1u64;
+        let (head, pages) = build_chain(&value, 100, || {
+            let id = PageId(next_id);
+            next_id += 1;
+            Ok(id)
+        })
+        .expect("build_chain failed");
+
+        // Drop the last page, simulating a corrupted/incomplete chain.
+        let missing_last = &pages[..pages.len() - 1];
+        let result = reassemble(&head, |page_id| {
+            missing_last
+                .iter()
+                .find(|p| p.header.page_id == page_id)
+                .cloned()
+                .ok_or_else(|| OxidbError::NotFound(format!("page {} not found", page_id.0)))
+        });
+        assert!(matches!(result, Err(OxidbError::NotFound(_))));
+    }
+}
@@ -1,6 +1,10 @@
+use crate::core::common::crc32c;
 use crate::core::common::error::OxidbError;
+use crate::core::common::types::ids::SlotId;
 use crate::core::common::types::Lsn; // Corrected Lsn import path
 use crate::core::common::types::PageId;
+use crate::core::storage::engine::heap::table_page::TablePage;
+use crate::core::storage::engine::page_codec;
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use std::convert::TryFrom;
 use std::io::Cursor;
@@ -8,8 +12,98 @@ use std::io::Cursor;
 // Define a standard page size.
 pub const PAGE_SIZE: usize = 4096;
 // Define the size of the PageHeader when serialized
-// PageId (u64: 8) + PageType (u8: 1) + Lsn (u64: 8) + flags (u8: 1) = 18 bytes
-pub const PAGE_HEADER_SIZE: usize = 18;
+// PageId (u64: 8) + PageType (u8: 1) + Lsn (u64: 8) + flags (u8: 1) + checksum (u32: 4)
+// + payload_len (u32: 4) + next_overflow (u64: 8) = 34 bytes
+pub const PAGE_HEADER_SIZE: usize = 34;
+
+/// `PageHeader::flags` bit indicating the body stored by [`Page::serialize_compressed`] is
+/// run-length compressed (see [`page_codec`]); `header.payload_len` bytes of the body
+/// region are the compressed stream rather than raw data.
+pub const FLAG_COMPRESSED: u8 = 0b0000_0001;
+
+/// Sentinel `PageId` for `PageHeader::next_overflow` meaning "no further overflow page",
+/// matching the `u64::MAX` sentinel convention already used by the B-link/B-tree page I/O
+/// modules (`PageId(0)` is a real, allocatable page id, so it can't double as "none").
+pub const NO_OVERFLOW: PageId = PageId(u64::MAX);
+
+/// Size, in bytes, of the `page_size`/`header_size` pair [`PageLayout::write_to_meta`]
+/// stores in a `PageType::Meta` page's body.
+const LAYOUT_RECORD_SIZE: usize = 4 + 4;
+
+/// Runtime page-layout parameters, so a binary isn't locked to the compile-time
+/// [`PAGE_SIZE`]/[`PAGE_HEADER_SIZE`] constants and can open database files created with a
+/// different page size.
+///
+/// [`Page::new`], [`Page::serialize`] and [`Page::deserialize`] (and friends) use
+/// [`PageLayout::DEFAULT`] — matching the compile-time constants — since that's the only
+/// layout a brand-new database has before its Meta page has been written. A caller that has
+/// already recovered a file's real layout (via [`PageLayout::read_from_meta`], once the
+/// Meta page itself has been read) should use the `_with_layout` variants instead.
+///
+/// Note: only [`Page`] itself is layout-aware so far. [`crate::core::storage::engine::disk_manager::DiskManager`]
+/// and the buffer pool still assume [`PageLayout::DEFAULT`] throughout; making page I/O
+/// itself layout-aware is a larger, separate change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageLayout {
+    pub page_size: usize,
+    pub header_size: usize,
+}
+
+impl PageLayout {
+    /// The layout matching the compile-time [`PAGE_SIZE`]/[`PAGE_HEADER_SIZE`] constants.
+    pub const DEFAULT: Self = Self { page_size: PAGE_SIZE, header_size: PAGE_HEADER_SIZE };
+
+    /// Writes this layout into `meta_page`'s body (overwriting its first
+    /// [`LAYOUT_RECORD_SIZE`] bytes), for persisting at database creation.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::InvalidInput` if `meta_page` isn't a `PageType::Meta` page, or
+    /// `OxidbError::Serialization` if its body is too small to hold the layout record.
+    pub fn write_to_meta(self, meta_page: &mut Page) -> Result<(), OxidbError> {
+        if meta_page.header.page_type != PageType::Meta {
+            return Err(OxidbError::InvalidInput {
+                message: "PageLayout can only be written to a PageType::Meta page".to_string(),
+            });
+        }
+        if meta_page.data.len() < LAYOUT_RECORD_SIZE {
+            return Err(OxidbError::Serialization(
+                "Meta page body too small to hold a PageLayout record".to_string(),
+            ));
+        }
+        let page_size = u32::try_from(self.page_size)
+            .map_err(|_| OxidbError::Serialization("page_size too large".to_string()))?;
+        let header_size = u32::try_from(self.header_size)
+            .map_err(|_| OxidbError::Serialization("header_size too large".to_string()))?;
+
+        let mut cursor = Cursor::new(&mut meta_page.data[0..LAYOUT_RECORD_SIZE]);
+        cursor.write_u32::<LittleEndian>(page_size)?;
+        cursor.write_u32::<LittleEndian>(header_size)?;
+        Ok(())
+    }
+
+    /// Recovers the layout previously written by [`PageLayout::write_to_meta`].
+    ///
+    /// # Errors
+    /// Returns `OxidbError::InvalidInput` if `meta_page` isn't a `PageType::Meta` page, or
+    /// `OxidbError::Deserialization` if its body is too small to hold a layout record.
+    pub fn read_from_meta(meta_page: &Page) -> Result<Self, OxidbError> {
+        if meta_page.header.page_type != PageType::Meta {
+            return Err(OxidbError::InvalidInput {
+                message: "PageLayout can only be read from a PageType::Meta page".to_string(),
+            });
+        }
+        if meta_page.data.len() < LAYOUT_RECORD_SIZE {
+            return Err(OxidbError::Deserialization(
+                "Meta page body too small to hold a PageLayout record".to_string(),
+            ));
+        }
+
+        let mut cursor = Cursor::new(&meta_page.data[0..LAYOUT_RECORD_SIZE]);
+        let page_size = cursor.read_u32::<LittleEndian>()? as usize;
+        let header_size = cursor.read_u32::<LittleEndian>()? as usize;
+        Ok(Self { page_size, header_size })
+    }
+}
 
 // Placeholder for different page types that might be used later.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -18,9 +112,13 @@ pub enum PageType {
     Meta = 0,
     Data = 1,
     Index = 2,
+    /// Holds one link of an overflow chain: up to `PAGE_SIZE - PAGE_HEADER_SIZE` raw bytes
+    /// (written via [`Page::write_overflow_chunk`]) continuing a record too large to fit
+    /// in its home page, plus `header.next_overflow` pointing at the next link (or
+    /// [`NO_OVERFLOW`] if this is the last one). See [`crate::core::storage::engine::overflow`].
+    Overflow = 3,
     // BTreeLeaf, // Keeping original values for now, but task asks for Meta, Data, Index, Unknown
     // BTreeInternal,
-    // Overflow,
     // Metadata, // This is Meta now
     #[default]
     Unknown = 255, // For invalid/uninitialized page types
@@ -34,6 +132,7 @@ impl TryFrom<u8> for PageType {
             0 => Ok(Self::Meta),
             1 => Ok(Self::Data),
             2 => Ok(Self::Index),
+            3 => Ok(Self::Overflow),
             255 => Ok(Self::Unknown),
             _ => Err(OxidbError::Deserialization(format!("Invalid PageType value: {value}"))),
         }
@@ -51,7 +150,20 @@ pub struct PageHeader {
     pub page_id: PageId,
     pub page_type: PageType,
     pub lsn: Lsn,  // Log Sequence Number
-    pub flags: u8, // e.g., is_dirty, is_pinned
+    pub flags: u8, // e.g., is_dirty, is_pinned, FLAG_COMPRESSED
+    /// CRC32C checksum of the full `PAGE_SIZE`-byte page image, computed with this field
+    /// treated as zero. Set by [`Page::serialize`] and checked by [`Page::deserialize`].
+    pub checksum: u32,
+    /// Length, in bytes, of the meaningful body region starting at `PAGE_HEADER_SIZE`:
+    /// the compressed stream's length when `FLAG_COMPRESSED` is set, the raw data length
+    /// otherwise, or (for a `PageType::Overflow` page) the chunk length written by
+    /// [`Page::write_overflow_chunk`]. The remainder of the `PAGE_SIZE` buffer is zero
+    /// padding.
+    pub payload_len: u32,
+    /// For a `PageType::Overflow` page, the next link in its overflow chain, or
+    /// [`NO_OVERFLOW`] if this is the last one. Unused (left at [`NO_OVERFLOW`]) by other
+    /// page types.
+    pub next_overflow: PageId,
 }
 
 impl PageHeader {
@@ -62,6 +174,9 @@ impl PageHeader {
             page_type,
             lsn: 0, // Lsn is u64, default to 0
             flags: 0,
+            checksum: 0,
+            payload_len: 0,
+            next_overflow: NO_OVERFLOW,
         }
     }
 
@@ -75,6 +190,9 @@ impl PageHeader {
         cursor.write_u8(self.page_type as u8)?;
         cursor.write_u64::<LittleEndian>(self.lsn)?; // Lsn is u64
         cursor.write_u8(self.flags)?;
+        cursor.write_u32::<LittleEndian>(self.checksum)?;
+        cursor.write_u32::<LittleEndian>(self.payload_len)?;
+        cursor.write_u64::<LittleEndian>(self.next_overflow.0)?;
 
         Ok(())
     }
@@ -90,8 +208,11 @@ impl PageHeader {
         let page_type = PageType::try_from(page_type_u8)?;
         let lsn = cursor.read_u64::<LittleEndian>()?; // Lsn is u64
         let flags = cursor.read_u8()?;
+        let checksum = cursor.read_u32::<LittleEndian>()?;
+        let payload_len = cursor.read_u32::<LittleEndian>()?;
+        let next_overflow = PageId(cursor.read_u64::<LittleEndian>()?);
 
-        Ok(Self { page_id, page_type, lsn, flags })
+        Ok(Self { page_id, page_type, lsn, flags, checksum, payload_len, next_overflow })
     }
 }
 
@@ -115,31 +236,185 @@ impl Page {
         self.header.page_id
     }
 
-    pub fn serialize(&self) -> Result<Vec<u8>, OxidbError> {
+    // Byte offset of `PageHeader::checksum` within a serialized header: it's the last
+    // field, preceded by page_id (8) + page_type (1) + lsn (8) + flags (1).
+    const CHECKSUM_OFFSET: usize = 18;
+
+    // Writes `header` followed by `body` into a fresh `PAGE_SIZE`-byte image, zero-padding
+    // the remainder (e.g. when `body` is shorter than `PAGE_SIZE - PAGE_HEADER_SIZE`,
+    // which is always true for a compressed body and sometimes true otherwise).
+    fn build_image(header: &PageHeader, body: &[u8]) -> Result<Vec<u8>, OxidbError> {
         let mut buffer = vec![0u8; PAGE_SIZE];
+        header.serialize(&mut buffer[0..PAGE_HEADER_SIZE])?;
+
+        let body_end_offset = PAGE_HEADER_SIZE.saturating_add(body.len());
+        if body_end_offset > PAGE_SIZE {
+            return Err(OxidbError::Serialization(
+                "Page payload exceeds available page size".to_string(),
+            ));
+        }
+        buffer[PAGE_HEADER_SIZE..body_end_offset].copy_from_slice(body);
+
+        Ok(buffer)
+    }
+
+    // Builds the full `PAGE_SIZE`-byte image with the checksum field zeroed and the raw
+    // (uncompressed) data as the body, ready for either writing the real checksum into it
+    // (`serialize`) or hashing as-is to check an existing one (`verify_checksum`).
+    fn image_with_zeroed_checksum(&self) -> Result<Vec<u8>, OxidbError> {
+        let mut header = self.header;
+        header.checksum = 0;
+        header.flags &= !FLAG_COMPRESSED;
+        // Overflow pages set `payload_len` to their true chunk length via
+        // `write_overflow_chunk`, which may be shorter than `self.data.len()` for the last
+        // link in a chain; every other page type always reports its full data length.
+        if self.header.page_type != PageType::Overflow {
+            header.payload_len = u32::try_from(self.data.len())
+                .map_err(|_| OxidbError::Serialization("Page data too large".to_string()))?;
+        }
+        Self::build_image(&header, &self.data)
+    }
+
+    pub fn serialize(&self) -> Result<Vec<u8>, OxidbError> {
+        let mut buffer = self.image_with_zeroed_checksum()?;
+        let checksum = crc32c::checksum(&buffer);
+        buffer[Self::CHECKSUM_OFFSET..Self::CHECKSUM_OFFSET + 4]
+            .copy_from_slice(&checksum.to_le_bytes());
+        Ok(buffer)
+    }
+
+    /// Like [`Page::serialize`], but first tries to run-length compress `self.data` (see
+    /// [`page_codec`]) and stores the compressed stream instead when that's both smaller
+    /// than the raw data and fits within the page's body region; otherwise falls back to
+    /// storing the raw data, exactly as [`Page::serialize`] does. Either way,
+    /// `header.payload_len` records the true length of whatever was stored, and
+    /// `header.flags`'s [`FLAG_COMPRESSED`] bit records which form it's in, so
+    /// [`Page::deserialize`] can transparently read either.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::Serialization` if even the raw data doesn't fit in one page.
+    pub fn serialize_compressed(&self) -> Result<Vec<u8>, OxidbError> {
+        let compressed = page_codec::compress(&self.data);
+        let max_body = PAGE_SIZE - PAGE_HEADER_SIZE;
+
+        let mut header = self.header;
+        header.checksum = 0;
+
+        let mut buffer = if compressed.len() < self.data.len() && compressed.len() <= max_body {
+            header.flags |= FLAG_COMPRESSED;
+            header.payload_len = compressed.len() as u32; // bounded by max_body < PAGE_SIZE
+            Self::build_image(&header, &compressed)?
+        } else {
+            header.flags &= !FLAG_COMPRESSED;
+            header.payload_len = u32::try_from(self.data.len())
+                .map_err(|_| OxidbError::Serialization("Page data too large".to_string()))?;
+            Self::build_image(&header, &self.data)?
+        };
+
+        let checksum = crc32c::checksum(&buffer);
+        buffer[Self::CHECKSUM_OFFSET..Self::CHECKSUM_OFFSET + 4]
+            .copy_from_slice(&checksum.to_le_bytes());
+        Ok(buffer)
+    }
+
+    /// Like [`Page::new`], but sizing `data` from a runtime [`PageLayout`] instead of the
+    /// compile-time [`PAGE_SIZE`]/[`PAGE_HEADER_SIZE`] constants.
+    #[must_use]
+    pub fn new_with_layout(page_id: PageId, page_type: PageType, layout: PageLayout) -> Self {
+        let header = PageHeader::new(page_id, page_type);
+        let data_size = layout.page_size.saturating_sub(layout.header_size);
+        Self { header, data: vec![0; data_size] }
+    }
 
-        // Serialize header into the beginning of the buffer
-        self.header.serialize(&mut buffer[0..PAGE_HEADER_SIZE])?;
+    /// Like [`Page::serialize`], but framed to a runtime [`PageLayout`] instead of the
+    /// compile-time [`PAGE_SIZE`]/[`PAGE_HEADER_SIZE`] constants, for a database whose page
+    /// size was recovered from its Meta page via [`PageLayout::read_from_meta`].
+    ///
+    /// # Errors
+    /// Returns `OxidbError::Serialization` if `self.data` doesn't fit within `layout`.
+    pub fn serialize_with_layout(&self, layout: PageLayout) -> Result<Vec<u8>, OxidbError> {
+        let mut header = self.header;
+        header.checksum = 0;
+        header.flags &= !FLAG_COMPRESSED;
+        if self.header.page_type != PageType::Overflow {
+            header.payload_len = u32::try_from(self.data.len())
+                .map_err(|_| OxidbError::Serialization("Page data too large".to_string()))?;
+        }
 
-        // Copy page data into the buffer after the header
-        let data_start_offset = PAGE_HEADER_SIZE;
-        let data_end_offset = data_start_offset.saturating_add(self.data.len());
+        let mut buffer = vec![0u8; layout.page_size];
+        header.serialize(&mut buffer[0..layout.header_size])?;
 
-        if data_end_offset > PAGE_SIZE {
-            // This case should ideally not happen if page.data is sized correctly upon creation/modification
+        let body_end = layout.header_size.saturating_add(self.data.len());
+        if body_end > layout.page_size {
             return Err(OxidbError::Serialization(
-                "Page data exceeds available page size".to_string(),
+                "Page payload exceeds available page size".to_string(),
             ));
         }
-        buffer[data_start_offset..data_end_offset].copy_from_slice(&self.data);
-
-        // The rest of the buffer (if any, up to PAGE_SIZE) remains as padding (e.g. zeros from vec init)
-        // This is important if self.data.len() < PAGE_SIZE - PAGE_HEADER_SIZE
+        buffer[layout.header_size..body_end].copy_from_slice(&self.data);
 
+        let checksum = crc32c::checksum(&buffer);
+        buffer[Self::CHECKSUM_OFFSET..Self::CHECKSUM_OFFSET + 4]
+            .copy_from_slice(&checksum.to_le_bytes());
         Ok(buffer)
     }
 
+    /// Like [`Page::deserialize`], but framed to a runtime [`PageLayout`] instead of the
+    /// compile-time [`PAGE_SIZE`]/[`PAGE_HEADER_SIZE`] constants. Rejects `buffer` if its
+    /// length doesn't match `layout.page_size` — the layout-aware replacement for the
+    /// hard-coded `buffer.len() != PAGE_SIZE` check in [`Page::deserialize`].
+    ///
+    /// # Errors
+    /// Returns `OxidbError::Deserialization` on a size mismatch, a checksum mismatch, or a
+    /// payload/compressed stream that doesn't fit or fails to decompress.
+    pub fn deserialize_with_layout(buffer: &[u8], layout: PageLayout) -> Result<Self, OxidbError> {
+        if buffer.len() != layout.page_size {
+            return Err(OxidbError::Deserialization(format!(
+                "Buffer size {} does not match configured page_size {}",
+                buffer.len(),
+                layout.page_size
+            )));
+        }
+
+        let header = PageHeader::deserialize(&buffer[0..layout.header_size])?;
+
+        let mut zeroed = buffer.to_vec();
+        zeroed[Self::CHECKSUM_OFFSET..Self::CHECKSUM_OFFSET + 4].fill(0);
+        if crc32c::checksum(&zeroed) != header.checksum {
+            return Err(OxidbError::Deserialization("page checksum mismatch".to_string()));
+        }
+
+        let payload_len = header.payload_len as usize;
+        let payload_end = layout.header_size.saturating_add(payload_len);
+        if payload_end > layout.page_size {
+            return Err(OxidbError::Deserialization(format!(
+                "payload_len {payload_len} exceeds page bounds"
+            )));
+        }
+        let payload = &buffer[layout.header_size..payload_end];
+
+        let data = if header.flags & FLAG_COMPRESSED != 0 {
+            page_codec::decompress(payload)?
+        } else {
+            payload.to_vec()
+        };
+
+        Ok(Self { header, data })
+    }
+
     pub fn deserialize(buffer: &[u8]) -> Result<Self, OxidbError> {
+        Self::deserialize_impl(buffer, true)
+    }
+
+    /// Like [`Page::deserialize`], but skips the checksum verification pass.
+    ///
+    /// Intended for trusted hot paths, such as the buffer pool re-reading a page it just
+    /// wrote itself, where the extra CRC32C pass over the full 4096-byte image is pure
+    /// overhead.
+    pub fn deserialize_unchecked(buffer: &[u8]) -> Result<Self, OxidbError> {
+        Self::deserialize_impl(buffer, false)
+    }
+
+    fn deserialize_impl(buffer: &[u8], verify: bool) -> Result<Self, OxidbError> {
         if buffer.len() != PAGE_SIZE {
             return Err(OxidbError::Deserialization(format!(
                 "Buffer size {} does not match configured PAGE_SIZE {}",
@@ -151,15 +426,57 @@ impl Page {
         // Deserialize header from the beginning of the buffer
         let header = PageHeader::deserialize(&buffer[0..PAGE_HEADER_SIZE])?;
 
-        // Copy the remaining part of the buffer into the data field
-        // The data field should contain data up to PAGE_SIZE - PAGE_HEADER_SIZE
-        let data_size = PAGE_SIZE - PAGE_HEADER_SIZE;
-        let mut data = vec![0u8; data_size];
-        data.copy_from_slice(&buffer[PAGE_HEADER_SIZE..PAGE_SIZE]);
+        if verify {
+            let mut zeroed = buffer.to_vec();
+            zeroed[Self::CHECKSUM_OFFSET..Self::CHECKSUM_OFFSET + 4].fill(0);
+            if crc32c::checksum(&zeroed) != header.checksum {
+                return Err(OxidbError::Deserialization("page checksum mismatch".to_string()));
+            }
+        }
+
+        // The body region is only `header.payload_len` bytes; the rest of the page is
+        // zero padding. When `FLAG_COMPRESSED` is set, those bytes are a run-length
+        // stream that decompresses back to the original data.
+        let payload_len = header.payload_len as usize;
+        let payload_end = PAGE_HEADER_SIZE.saturating_add(payload_len);
+        if payload_end > PAGE_SIZE {
+            return Err(OxidbError::Deserialization(format!(
+                "payload_len {payload_len} exceeds page bounds"
+            )));
+        }
+        let payload = &buffer[PAGE_HEADER_SIZE..payload_end];
+
+        let data = if header.flags & FLAG_COMPRESSED != 0 {
+            page_codec::decompress(payload)?
+        } else {
+            payload.to_vec()
+        };
 
         Ok(Self { header, data })
     }
 
+    /// Recomputes this page's CRC32C checksum and compares it against `header.checksum`.
+    ///
+    /// Rebuilds the image the same way it would have last been serialized — via
+    /// [`Page::serialize_compressed`] if `header.flags` has [`FLAG_COMPRESSED`] set,
+    /// [`Page::serialize`] otherwise — so this also works for pages read back from a
+    /// compressed image.
+    #[must_use]
+    pub fn verify_checksum(&self) -> bool {
+        let rebuilt = if self.header.flags & FLAG_COMPRESSED != 0 {
+            self.serialize_compressed()
+        } else {
+            self.serialize()
+        };
+        let Ok(buffer) = rebuilt else {
+            return false;
+        };
+        let fresh_checksum = u32::from_le_bytes(
+            buffer[Self::CHECKSUM_OFFSET..Self::CHECKSUM_OFFSET + 4].try_into().unwrap(),
+        );
+        fresh_checksum == self.header.checksum
+    }
+
     /// Get the LSN (Log Sequence Number) of this page
     #[must_use]
     pub const fn get_lsn(&self) -> Lsn {
@@ -171,64 +488,153 @@ impl Page {
         self.header.lsn = lsn;
     }
 
-    /// Apply an update operation to the page
-    /// This is a simplified implementation for recovery purposes
-    pub fn apply_update(&mut self, after_image: &[u8]) -> Result<(), OxidbError> {
-        if after_image.len() > self.data.len() {
-            return Err(OxidbError::InvalidInput {
-                message: "Update data exceeds page capacity".to_string(),
-            });
-        }
+    /// Overwrites the record at `slot_id` with `new_data`, via the slotted-page layout
+    /// ([`TablePage`]) over this page's data region.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if `slot_id` doesn't hold a live record, or `new_data` is
+    /// larger than the record currently occupying that slot (growing updates require a
+    /// [`Page::apply_delete`] + [`Page::apply_insert`] pair instead).
+    pub fn apply_update(&mut self, slot_id: SlotId, new_data: &[u8]) -> Result<(), OxidbError> {
+        TablePage::update_record(&mut self.data, slot_id, new_data)
+    }
 
-        // For simplicity, we'll replace the beginning of the page data with the after_image
-        // In a real implementation, this would be more sophisticated based on the specific
-        // storage format and the nature of the update
-        self.data[..after_image.len()].copy_from_slice(after_image);
+    /// Inserts `data` as a new record via the slotted-page layout ([`TablePage`]) over this
+    /// page's data region, returning the [`SlotId`] it was stored at.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if there isn't enough contiguous free space for `data`; call
+    /// [`Page::compact`] first to reclaim space held by tombstoned slots.
+    pub fn apply_insert(&mut self, data: &[u8]) -> Result<SlotId, OxidbError> {
+        TablePage::insert_record(&mut self.data, data)
+    }
 
-        Ok(())
+    /// Deletes the record at `slot_id`, tombstoning its slot without shifting or
+    /// overwriting any other record.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if `slot_id` is out of bounds or already deleted.
+    pub fn apply_delete(&mut self, slot_id: SlotId) -> Result<(), OxidbError> {
+        TablePage::delete_record(&mut self.data, slot_id)
     }
 
-    /// Apply an insert operation to the page
-    /// This is a simplified implementation for recovery purposes
-    pub fn apply_insert(&mut self, data: &[u8]) -> Result<(), OxidbError> {
-        if data.len() > self.data.len() {
-            return Err(OxidbError::InvalidInput {
-                message: "Insert data exceeds page capacity".to_string(),
-            });
+    /// Reads the record at `slot_id`, or `None` if the slot is out of bounds or tombstoned.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if the slot table itself is corrupt (a stored offset/length
+    /// that doesn't fit within this page's data region).
+    pub fn get_record(&self, slot_id: SlotId) -> Result<Option<Vec<u8>>, OxidbError> {
+        TablePage::get_record(&self.data, slot_id)
+    }
+
+    /// Reclaims space held by tombstoned slots; see [`TablePage::compact`].
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if the slot table is corrupt.
+    pub fn compact(&mut self) -> Result<(), OxidbError> {
+        TablePage::compact(&mut self.data)
+    }
+
+    /// Fills this page's body with a chunk of an overflow chain's raw bytes (see
+    /// [`crate::core::storage::engine::overflow`]), for a page created with
+    /// `PageType::Overflow`. Writes `bytes[..n]` where `n = min(bytes.len(), capacity)`,
+    /// zero-pads the remainder, records `n` in `header.payload_len`, and returns `n` so the
+    /// caller knows how much of `bytes` it consumed.
+    ///
+    /// Does not touch `header.next_overflow`; the caller links chunks together explicitly.
+    pub fn write_overflow_chunk(&mut self, bytes: &[u8]) -> usize {
+        let n = bytes.len().min(self.data.len());
+        self.data[..n].copy_from_slice(&bytes[..n]);
+        for byte in &mut self.data[n..] {
+            *byte = 0;
         }
+        self.header.payload_len = n as u32; // n <= self.data.len() < PAGE_SIZE
+        n
+    }
+}
 
-        // For simplicity, we'll append the data to the page
-        // In a real implementation, this would involve proper slot management
-        // and free space tracking
-        let mut insert_offset = 0;
+/// A borrowed, zero-copy view over a serialized page image, for hot read paths (e.g. a
+/// buffer pool frame backed by a pinned or mmap'd buffer) that don't want to pay the
+/// allocation and memcpy [`Page::deserialize`] incurs on every read.
+///
+/// Parses the header in place and exposes `data` as a slice into the original buffer
+/// rather than an owned copy; call [`PageView::to_owned`] when a caller actually needs to
+/// mutate the page. Like [`Page::deserialize_unchecked`], this does not verify the page's
+/// checksum — use [`Page::deserialize`] on a buffer whose integrity hasn't already been
+/// established by the caller.
+#[derive(Debug, Clone, Copy)]
+pub struct PageView<'a> {
+    header: PageHeader,
+    data: &'a [u8],
+}
 
-        // Find the first available space (simplified approach)
-        while insert_offset + data.len() <= self.data.len() {
-            if self.data[insert_offset..insert_offset + data.len()].iter().all(|&b| b == 0) {
-                self.data[insert_offset..insert_offset + data.len()].copy_from_slice(data);
-                return Ok(());
-            }
-            insert_offset += 1;
+impl<'a> PageView<'a> {
+    /// Parses `buffer` as a `PAGE_SIZE`-byte page image without copying its body.
+    ///
+    /// # Errors
+    /// Returns `OxidbError::Deserialization` if `buffer`'s length isn't exactly
+    /// `PAGE_SIZE`, if the header itself fails to parse (e.g. an invalid `PageType`), if
+    /// `payload_len` doesn't fit within the page, or if the page is stored compressed
+    /// ([`FLAG_COMPRESSED`]) — decompressing requires allocating, which defeats the point
+    /// of a zero-copy view; use [`Page::deserialize`] for those instead.
+    pub fn from_bytes(buffer: &'a [u8]) -> Result<Self, OxidbError> {
+        if buffer.len() != PAGE_SIZE {
+            return Err(OxidbError::Deserialization(format!(
+                "Buffer size {} does not match configured PAGE_SIZE {}",
+                buffer.len(),
+                PAGE_SIZE
+            )));
         }
 
-        Err(OxidbError::InvalidInput { message: "No space available for insert".to_string() })
-    }
+        let header = PageHeader::deserialize(&buffer[0..PAGE_HEADER_SIZE])?;
+        if header.flags & FLAG_COMPRESSED != 0 {
+            return Err(OxidbError::Deserialization(
+                "PageView does not support compressed pages; use Page::deserialize".to_string(),
+            ));
+        }
 
-    /// Apply a delete operation to the page
-    /// This is a simplified implementation for recovery purposes
-    pub fn apply_delete(&mut self) -> Result<(), OxidbError> {
-        // For simplicity, we'll zero out the first non-zero data
-        // In a real implementation, this would involve proper slot management
-        // and record identification
-        for byte in &mut self.data {
-            if *byte != 0 {
-                *byte = 0;
-                return Ok(());
-            }
+        let payload_len = header.payload_len as usize;
+        let payload_end = PAGE_HEADER_SIZE.saturating_add(payload_len);
+        if payload_end > PAGE_SIZE {
+            return Err(OxidbError::Deserialization(format!(
+                "payload_len {payload_len} exceeds page bounds"
+            )));
         }
 
-        // If no non-zero data found, the delete is a no-op
-        Ok(())
+        Ok(Self { header, data: &buffer[PAGE_HEADER_SIZE..payload_end] })
+    }
+
+    /// This view's parsed header.
+    #[must_use]
+    pub const fn header(&self) -> &PageHeader {
+        &self.header
+    }
+
+    /// The page's data region, borrowed directly from the backing buffer.
+    #[must_use]
+    pub const fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// The LSN (Log Sequence Number) of this page.
+    #[must_use]
+    pub const fn get_lsn(&self) -> Lsn {
+        self.header.lsn
+    }
+
+    /// Reads the record at `slot_id` via the slotted-page layout, borrowing its bytes
+    /// directly from the backing buffer rather than copying them.
+    ///
+    /// # Errors
+    /// Returns `OxidbError` if the slot table itself is corrupt.
+    pub fn get_record(&self, slot_id: SlotId) -> Result<Option<&'a [u8]>, OxidbError> {
+        TablePage::get_record_slice(self.data, slot_id)
+    }
+
+    /// Copies this view into an owned, mutable [`Page`].
+    #[must_use]
+    pub fn to_owned(&self) -> Page {
+        Page { header: self.header, data: self.data.to_vec() }
     }
 }
 
@@ -259,6 +665,9 @@ mod tests {
                 page_type,
                 lsn: 456, // Lsn is u64
                 flags: 0b10101010,
+                checksum: 0xDEAD_BEEF,
+                payload_len: PAGE_SIZE as u32 - PAGE_HEADER_SIZE as u32,
+                next_overflow: PageId(999),
             };
 
             let mut buffer = vec![0u8; PAGE_HEADER_SIZE];
@@ -301,6 +710,9 @@ mod tests {
             .expect("Failed to write invalid_page_type_byte to cursor");
         cursor.write_u64::<LittleEndian>(lsn).expect("Failed to write lsn to cursor"); // Lsn is u64
         cursor.write_u8(flags).expect("Failed to write flags to cursor");
+        cursor.write_u32::<LittleEndian>(0).expect("Failed to write checksum to cursor");
+        cursor.write_u32::<LittleEndian>(0).expect("Failed to write payload_len to cursor");
+        cursor.write_u64::<LittleEndian>(0).expect("Failed to write next_overflow to cursor");
 
         let result = PageHeader::deserialize(&buffer);
         assert!(matches!(result, Err(OxidbError::Deserialization(_))));
@@ -327,8 +739,28 @@ mod tests {
             let deserialized_page_zeroed = Page::deserialize(&serialized_page_zeroed)
                 .expect("Deserialization of zeroed page failed");
             assert_eq!(
-                page_zeroed.header, deserialized_page_zeroed.header,
-                "Header mismatch for zeroed PageType::{:?}",
+                page_zeroed.header.page_id, deserialized_page_zeroed.header.page_id,
+                "page_id mismatch for zeroed PageType::{:?}",
+                page_type
+            );
+            assert_eq!(
+                page_zeroed.header.page_type, deserialized_page_zeroed.header.page_type,
+                "page_type mismatch for zeroed PageType::{:?}",
+                page_type
+            );
+            assert_eq!(
+                page_zeroed.header.lsn, deserialized_page_zeroed.header.lsn,
+                "lsn mismatch for zeroed PageType::{:?}",
+                page_type
+            );
+            assert_eq!(
+                page_zeroed.header.flags, deserialized_page_zeroed.header.flags,
+                "flags mismatch for zeroed PageType::{:?}",
+                page_type
+            );
+            assert!(
+                deserialized_page_zeroed.verify_checksum(),
+                "checksum should verify for zeroed PageType::{:?}",
                 page_type
             );
             assert_eq!(
@@ -355,8 +787,23 @@ mod tests {
             let deserialized_page_populated = Page::deserialize(&serialized_page_populated)
                 .expect("Deserialization of populated page failed");
             assert_eq!(
-                page_populated.header, deserialized_page_populated.header,
-                "Header mismatch for populated PageType::{:?}",
+                page_populated.header.page_id, deserialized_page_populated.header.page_id,
+                "page_id mismatch for populated PageType::{:?}",
+                page_type
+            );
+            assert_eq!(
+                page_populated.header.lsn, deserialized_page_populated.header.lsn,
+                "lsn mismatch for populated PageType::{:?}",
+                page_type
+            );
+            assert_eq!(
+                page_populated.header.flags, deserialized_page_populated.header.flags,
+                "flags mismatch for populated PageType::{:?}",
+                page_type
+            );
+            assert!(
+                deserialized_page_populated.verify_checksum(),
+                "checksum should verify for populated PageType::{:?}",
                 page_type
             );
             assert_eq!(
@@ -368,6 +815,171 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_page_deserialize_detects_corruption() {
+        let page = Page::new(PageId(1), PageType::Data);
+        let mut serialized = page.serialize().expect("Serialization failed in test");
+
+        // Flip a bit in the data region without updating the checksum.
+        let corrupt_offset = PAGE_HEADER_SIZE + 10;
+        serialized[corrupt_offset] ^= 0xFF;
+
+        let result = Page::deserialize(&serialized);
+        assert!(matches!(result, Err(OxidbError::Deserialization(_))));
+        if let Err(OxidbError::Deserialization(msg)) = result {
+            assert!(msg.contains("checksum mismatch"));
+        } else {
+            panic!("Expected Deserialization error for corrupted page");
+        }
+
+        // The trusted/unchecked path should still succeed on the same bytes.
+        assert!(Page::deserialize_unchecked(&serialized).is_ok());
+    }
+
+    #[test]
+    fn test_page_view_zero_copy_read() {
+        let mut page = Page::new(PageId(42), PageType::Data);
+        let slot = page.apply_insert(b"zero-copy").expect("insert failed in test");
+        let serialized = page.serialize().expect("Serialization failed in test");
+
+        let view = PageView::from_bytes(&serialized).expect("PageView::from_bytes failed");
+        assert_eq!(view.header().page_id, PageId(42));
+        assert_eq!(view.get_lsn(), 0);
+        assert_eq!(view.get_record(slot).unwrap().unwrap(), b"zero-copy");
+
+        // `data()` borrows directly from `serialized` rather than an owned copy.
+        assert_eq!(view.data().as_ptr(), serialized[PAGE_HEADER_SIZE..].as_ptr());
+
+        let owned = view.to_owned();
+        assert_eq!(owned.header.page_id, PageId(42));
+        assert_eq!(owned.get_record(slot).unwrap().unwrap(), b"zero-copy");
+    }
+
+    #[test]
+    fn test_page_view_from_bytes_wrong_size() {
+        let buffer = vec![0u8; PAGE_SIZE - 1];
+        let result = PageView::from_bytes(&buffer);
+        assert!(matches!(result, Err(OxidbError::Deserialization(_))));
+    }
+
+    #[test]
+    fn test_page_serialize_compressed_roundtrip() {
+        // A freshly created page's data is all zeros, which compresses very well.
+        let mut page = Page::new(PageId(7), PageType::Data);
+        let slot = page.apply_insert(b"compress-me").expect("insert failed in test");
+
+        let serialized = page.serialize_compressed().expect("serialize_compressed failed");
+        assert_eq!(serialized.len(), PAGE_SIZE);
+
+        let deserialized =
+            Page::deserialize(&serialized).expect("deserialize of compressed page failed");
+        assert_ne!(deserialized.header.flags & FLAG_COMPRESSED, 0, "expected compressed flag set");
+        assert!((deserialized.header.payload_len as usize) < page.data.len());
+        assert!(deserialized.verify_checksum());
+        assert_eq!(deserialized.data, page.data);
+        assert_eq!(deserialized.get_record(slot).unwrap().unwrap(), b"compress-me");
+    }
+
+    #[test]
+    fn test_page_serialize_compressed_incompressible_falls_back_to_raw() {
+        let mut page = Page::new(PageId(8), PageType::Data);
+        for (i, byte) in page.data.iter_mut().enumerate() {
+            *byte = (i % 256) as u8;
+        }
+
+        let serialized = page.serialize_compressed().expect("serialize_compressed failed");
+        let deserialized =
+            Page::deserialize(&serialized).expect("deserialize of fallback page failed");
+        assert_eq!(deserialized.header.flags & FLAG_COMPRESSED, 0, "expected compression skipped");
+        assert_eq!(deserialized.header.payload_len as usize, page.data.len());
+        assert!(deserialized.verify_checksum());
+        assert_eq!(deserialized.data, page.data);
+    }
+
+    #[test]
+    fn test_page_view_rejects_compressed_page() {
+        let page = Page::new(PageId(9), PageType::Data);
+        let serialized = page.serialize_compressed().expect("serialize_compressed failed");
+
+        let result = PageView::from_bytes(&serialized);
+        assert!(matches!(result, Err(OxidbError::Deserialization(_))));
+    }
+
+    #[test]
+    fn test_write_overflow_chunk_and_chain_roundtrip() {
+        let mut head_page = Page::new(PageId(1), PageType::Overflow);
+        let mut tail_page = Page::new(PageId(2), PageType::Overflow);
+
+        let capacity = head_page.data.len();
+        let full_chunk = vec![0xABu8; capacity];
+        let partial_chunk = b"tail-bytes".to_vec();
+
+        let written = head_page.write_overflow_chunk(&full_chunk);
+        assert_eq!(written, capacity);
+        assert_eq!(head_page.header.payload_len as usize, capacity);
+        head_page.header.next_overflow = PageId(2);
+
+        let written_tail = tail_page.write_overflow_chunk(&partial_chunk);
+        assert_eq!(written_tail, partial_chunk.len());
+        assert_eq!(tail_page.header.payload_len as usize, partial_chunk.len());
+        assert_eq!(tail_page.header.next_overflow, NO_OVERFLOW);
+
+        // Round-trip both pages through serialize/deserialize like a real chain would be.
+        let head_bytes = head_page.serialize().expect("serialize failed");
+        let tail_bytes = tail_page.serialize().expect("serialize failed");
+        let deserialized_head = Page::deserialize(&head_bytes).expect("deserialize failed");
+        let deserialized_tail = Page::deserialize(&tail_bytes).expect("deserialize failed");
+
+        assert_eq!(deserialized_head.header.next_overflow, PageId(2));
+        assert_eq!(&deserialized_head.data[..capacity], full_chunk.as_slice());
+        assert_eq!(
+            &deserialized_tail.data[..partial_chunk.len()],
+            partial_chunk.as_slice()
+        );
+    }
+
+    #[test]
+    fn test_page_layout_default_matches_constants() {
+        assert_eq!(PageLayout::DEFAULT.page_size, PAGE_SIZE);
+        assert_eq!(PageLayout::DEFAULT.header_size, PAGE_HEADER_SIZE);
+    }
+
+    #[test]
+    fn test_page_layout_write_and_read_meta_roundtrip() {
+        let mut meta_page = Page::new(PageId(0), PageType::Meta);
+        let layout = PageLayout { page_size: 8192, header_size: 40 };
+        layout.write_to_meta(&mut meta_page).expect("write_to_meta failed");
+
+        let read_back = PageLayout::read_from_meta(&meta_page).expect("read_from_meta failed");
+        assert_eq!(read_back, layout);
+    }
+
+    #[test]
+    fn test_page_layout_write_to_meta_rejects_non_meta_page() {
+        let mut data_page = Page::new(PageId(1), PageType::Data);
+        let result = PageLayout::DEFAULT.write_to_meta(&mut data_page);
+        assert!(matches!(result, Err(OxidbError::InvalidInput { .. })));
+    }
+
+    #[test]
+    fn test_page_serialize_deserialize_with_custom_layout() {
+        let layout = PageLayout { page_size: 1024, header_size: PAGE_HEADER_SIZE };
+        let mut page = Page::new_with_layout(PageId(5), PageType::Data, layout);
+        assert_eq!(page.data.len(), layout.page_size - layout.header_size);
+
+        page.data[0] = 0x42;
+        let serialized = page.serialize_with_layout(layout).expect("serialize_with_layout failed");
+        assert_eq!(serialized.len(), layout.page_size);
+
+        let deserialized = Page::deserialize_with_layout(&serialized, layout)
+            .expect("deserialize_with_layout failed");
+        assert_eq!(deserialized.data, page.data);
+
+        // A buffer sized for a different layout is rejected.
+        let result = Page::deserialize_with_layout(&serialized, PageLayout::DEFAULT);
+        assert!(matches!(result, Err(OxidbError::Deserialization(_))));
+    }
+
     #[test]
     fn test_page_deserialize_buffer_too_small() {
         let buffer = vec![0u8; PAGE_SIZE - 1];
@@ -1,5 +1,6 @@
-use crate::core::common::types::TransactionId as CommonTransactionId;
+use crate::core::common::types::{Lsn, TransactionId as CommonTransactionId};
 use crate::core::transaction::transaction::{Transaction, TransactionState}; // Removed INVALID_LSN
+use crate::core::transaction::UndoLogConfig;
 use crate::core::wal::log_manager::LogManager;
 use crate::core::wal::log_record::LogRecord;
 use crate::core::wal::writer::WalWriter;
@@ -16,6 +17,10 @@ pub struct TransactionManager {
     committed_tx_ids: Vec<CommonTransactionId>,
     wal_writer: WalWriter,
     log_manager: Arc<LogManager>,
+    /// Applied to every transaction this manager begins, via
+    /// [`Transaction::configure_undo_log`]. Derived from the WAL file's
+    /// location so spilled undo logs live alongside it.
+    undo_log_config: UndoLogConfig,
 }
 
 impl Default for TransactionManager {
@@ -24,19 +29,13 @@ impl Default for TransactionManager {
         let wal_config = crate::core::wal::writer::WalWriterConfig::default();
         let wal_writer = WalWriter::new(default_wal_path, wal_config);
         let log_manager = Arc::new(LogManager::default());
-        TransactionManager {
-            active_transactions: HashMap::new(),
-            next_transaction_id: CommonTransactionId(1), // Initialize with TransactionId struct
-            current_active_transaction_id: None,
-            committed_tx_ids: Vec::new(),
-            wal_writer,
-            log_manager,
-        }
+        Self::new(wal_writer, log_manager)
     }
 }
 
 impl TransactionManager {
     pub fn new(wal_writer: WalWriter, log_manager: Arc<LogManager>) -> Self {
+        let undo_log_config = Self::default_undo_log_config(&wal_writer);
         TransactionManager {
             active_transactions: HashMap::new(),
             next_transaction_id: CommonTransactionId(1), // Initialize with TransactionId struct
@@ -44,9 +43,24 @@ impl TransactionManager {
             committed_tx_ids: Vec::new(),
             wal_writer,
             log_manager,
+            undo_log_config,
         }
     }
 
+    /// Spills undo logs to a `<wal file name>_undo_spill` directory next to
+    /// the WAL file, so a transaction with a very large undo log doesn't
+    /// keep it all resident in memory.
+    fn default_undo_log_config(wal_writer: &WalWriter) -> UndoLogConfig {
+        let wal_path = wal_writer.wal_file_path();
+        let spill_dir_name =
+            format!("{}_undo_spill", wal_path.file_name().map_or_else(
+                || "wal".to_string(),
+                |n| n.to_string_lossy().to_string(),
+            ));
+        let spill_dir = wal_path.with_file_name(spill_dir_name);
+        UndoLogConfig { spill_dir: Some(spill_dir), ..UndoLogConfig::default() }
+    }
+
     pub fn generate_tx_id(&mut self) -> CommonTransactionId {
         let id = self.next_transaction_id;
         self.next_transaction_id += 1_u64; // Explicitly use u64 for AddAssign
@@ -57,6 +71,7 @@ impl TransactionManager {
     pub fn begin_transaction(&mut self) -> Result<Transaction, IoError> {
         let id: CommonTransactionId = self.generate_tx_id(); // id is CommonTransactionId
         let mut transaction = Transaction::new(id); // Pass TransactionId struct
+        transaction.configure_undo_log(self.undo_log_config.clone());
 
         let lsn = self.log_manager.next_lsn();
         let begin_log_record = LogRecord::BeginTransaction {
@@ -89,6 +104,7 @@ impl TransactionManager {
         }
 
         let mut transaction = Transaction::new(tx_id);
+        transaction.configure_undo_log(self.undo_log_config.clone());
 
         if tx_id != CommonTransactionId(0) {
             // Only log BeginTransaction for non-Tx0
@@ -133,10 +149,15 @@ impl TransactionManager {
         self.current_active_transaction_id
     }
 
-    pub fn commit_transaction(&mut self) -> Result<(), IoError> {
+    pub fn commit_transaction(&mut self) -> Result<Lsn, IoError> {
         let current_tx_id = match self.current_active_transaction_id.take() {
             Some(id) => id,
-            None => return Ok(()), // Or an error like NoActiveTransaction
+            None => {
+                return Err(IoError::new(
+                    std::io::ErrorKind::Other,
+                    "commit_transaction called with no active transaction",
+                ))
+            }
         };
 
         let mut transaction = match self.active_transactions.remove(&current_tx_id) {
@@ -165,16 +186,26 @@ impl TransactionManager {
 
         // Attempt to write to WAL.
         self.wal_writer.add_record(commit_log_record.clone())?;
+        self.wal_writer.flush()?; // Durably flush before the commit is visible to on_commit hooks.
 
         // If WAL write is successful, then proceed to update transaction state
         transaction.prev_lsn = lsn; // Update transaction's prev_lsn to this commit record's LSN
         transaction.set_state(TransactionState::Committed);
         self.committed_tx_ids.push(current_tx_id);
 
-        Ok(())
+        // Run the transaction's registered on_commit hooks now that its commit record
+        // is durable. Dropped, unrun, on abort - see `abort_transaction`.
+        for hook in transaction.take_commit_hooks() {
+            hook();
+        }
+
+        Ok(lsn)
     }
 
     // New method for aborting a transaction with logging
+    //
+    // Any hooks registered via `Transaction::on_commit` are dropped, unrun, along with
+    // the rest of the removed transaction - they only ever fire from `commit_transaction`.
     pub fn abort_transaction(&mut self) -> Result<(), IoError> {
         let current_tx_id = match self.current_active_transaction_id.take() {
             Some(id) => id,
@@ -236,6 +267,19 @@ impl TransactionManager {
         self.active_transactions.values().map(|tx| tx.id).min()
     }
 
+    /// Number of transactions currently begun but not yet committed or aborted -
+    /// for spotting a runaway open transaction via `Connection::stats`.
+    #[must_use]
+    pub fn active_transaction_count(&self) -> usize {
+        self.active_transactions.len()
+    }
+
+    /// Path of the WAL file this manager is writing to.
+    #[must_use]
+    pub fn wal_file_path(&self) -> &std::path::Path {
+        self.wal_writer.wal_file_path()
+    }
+
     pub fn get_next_transaction_id_peek(&self) -> CommonTransactionId {
         // Use CommonTransactionId
         self.next_transaction_id
@@ -491,4 +535,42 @@ mod tests {
 
         cleanup_dir(&test_dir_path);
     }
+
+    #[test]
+    fn test_undo_log_spills_and_restores_past_in_memory_bound() {
+        use crate::core::transaction::UndoOperation;
+
+        let (mut manager, _wal_path, test_dir_path) = setup_test_tm("undo_log_spill");
+        manager.undo_log_config.max_in_memory_ops = 4;
+
+        manager.begin_transaction().expect("begin_transaction failed");
+        let active_tx =
+            manager.get_active_transaction_mut().expect("no active transaction after begin");
+
+        for i in 0u8..10 {
+            active_tx
+                .add_undo_operation(UndoOperation::RevertInsert { key: vec![i] });
+        }
+
+        assert_eq!(
+            active_tx.undo_log_len(),
+            10,
+            "spilling must not lose operations, only relocate them"
+        );
+        assert!(
+            active_tx.undo_log.len() <= 4,
+            "in-memory tail should be bounded by max_in_memory_ops"
+        );
+
+        active_tx.restore_spilled_undo_log().expect("restore_spilled_undo_log failed");
+        assert_eq!(active_tx.undo_log_len(), 10, "restore should not lose or duplicate operations");
+        let restored_keys: Vec<u8> =
+            active_tx.undo_log.iter().map(|op| match op {
+                UndoOperation::RevertInsert { key } => key[0],
+                other => panic!("unexpected undo operation: {other:?}"),
+            }).collect();
+        assert_eq!(restored_keys, (0u8..10).collect::<Vec<_>>(), "original order must be preserved");
+
+        cleanup_dir(&test_dir_path);
+    }
 }
@@ -1,5 +1,7 @@
 // Content from transaction.rs
 use crate::core::common::types::{Lsn, TransactionId}; // Added TransactionId import
+use std::io::Write;
+use std::path::PathBuf;
 
 // Define INVALID_LSN constant
 pub const INVALID_LSN: Lsn = u64::MAX;
@@ -15,8 +17,28 @@ pub enum TransactionState {
     Aborted,
 }
 
-/// Represents a transaction in the system.
+/// Bounds how many undo operations a transaction keeps resident in memory
+/// before spilling older ones to a temp file, and where that file goes.
+/// Mirrors [`crate::core::wal::writer::WalWriterConfig::max_buffer_size`]'s
+/// choice of an operation count over a byte budget: per-entry overhead, not
+/// payload size, is what dominates for undo operations.
 #[derive(Debug, Clone)]
+pub struct UndoLogConfig {
+    /// Number of undo operations kept in memory before older ones spill to
+    /// `spill_dir`.
+    pub max_in_memory_ops: usize,
+    /// Directory spill files are written under. Spilling is disabled
+    /// (unbounded in-memory growth, the historical behavior) when `None`.
+    pub spill_dir: Option<PathBuf>,
+}
+
+impl Default for UndoLogConfig {
+    fn default() -> Self {
+        Self { max_in_memory_ops: 10_000, spill_dir: None }
+    }
+}
+
+/// Represents a transaction in the system.
 pub struct Transaction {
     /// A unique identifier for the transaction.
     pub id: TransactionId, // Changed from u64
@@ -26,6 +48,81 @@ pub struct Transaction {
     pub prev_lsn: Lsn,
     pub undo_log: Vec<UndoOperation>,
     pub redo_log: Vec<RedoOperation>, // Added redo_log
+    /// Named savepoints established within this transaction, recorded as
+    /// `(name, undo_log.len())` at the time `SAVEPOINT name` ran, so
+    /// `ROLLBACK TO name` knows how many undo operations to replay and
+    /// where to truncate the log.
+    pub savepoints: Vec<(String, usize)>,
+    /// How this transaction's undo log is bounded and where it spills.
+    /// Set via [`Self::configure_undo_log`]; defaults to spilling disabled.
+    undo_log_config: UndoLogConfig,
+    /// Lazily created the first time this transaction's undo log overflows
+    /// `undo_log_config.max_in_memory_ops`.
+    undo_spill_path: Option<PathBuf>,
+    /// Number of older undo operations currently sitting in
+    /// `undo_spill_path` rather than in `undo_log`, oldest-first.
+    spilled_undo_count: usize,
+    /// Callbacks registered via [`Self::on_commit`], run in order once this
+    /// transaction's commit record is durably flushed to the WAL, and
+    /// silently dropped, unrun, if it's rolled back instead.
+    on_commit: Vec<Box<dyn FnOnce() + Send>>,
+}
+
+impl std::fmt::Debug for Transaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Transaction")
+            .field("id", &self.id)
+            .field("state", &self.state)
+            .field("prev_lsn", &self.prev_lsn)
+            .field("undo_log", &self.undo_log)
+            .field("redo_log", &self.redo_log)
+            .field("savepoints", &self.savepoints)
+            .field("undo_log_config", &self.undo_log_config)
+            .field("undo_spill_path", &self.undo_spill_path)
+            .field("spilled_undo_count", &self.spilled_undo_count)
+            .field("on_commit", &format!("<{} hook(s)>", self.on_commit.len()))
+            .finish()
+    }
+}
+
+impl Clone for Transaction {
+    /// Clones every field except `on_commit`: a cloned handle (e.g. the copy
+    /// the transaction manager keeps in its active-transaction table) is a
+    /// separate tracking copy, and running a commit hook once per clone
+    /// would fire it more than once for a single logical commit.
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id,
+            state: self.state.clone(),
+            prev_lsn: self.prev_lsn,
+            undo_log: self.undo_log.clone(),
+            redo_log: self.redo_log.clone(),
+            savepoints: self.savepoints.clone(),
+            undo_log_config: self.undo_log_config.clone(),
+            undo_spill_path: self.undo_spill_path.clone(),
+            spilled_undo_count: self.spilled_undo_count,
+            on_commit: Vec::new(),
+        }
+    }
+}
+
+/// Controls when a transaction acquires its locks, mirroring SQLite's
+/// `BEGIN [DEFERRED|IMMEDIATE|EXCLUSIVE]`.
+///
+/// A `Deferred` transaction acquires locks lazily as it reads or writes,
+/// the same as a plain `BEGIN`. `Immediate` and `Exclusive` instead grab a
+/// lock on the whole database up front, so a transaction that can't get
+/// the lock it needs fails at `BEGIN` time instead of partway through its
+/// first statement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransactionBehavior {
+    /// Acquire locks lazily, on first access. The default.
+    #[default]
+    Deferred,
+    /// Acquire a shared lock on the whole database at `BEGIN` time.
+    Immediate,
+    /// Acquire an exclusive lock on the whole database at `BEGIN` time.
+    Exclusive,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -34,7 +131,7 @@ pub enum RedoOperation {
     IndexDelete { key: Vec<u8>, old_value_for_index: Vec<u8> }, // Old serialized value
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum UndoOperation {
     RevertInsert {
         key: Vec<u8>,
@@ -68,7 +165,7 @@ pub enum UndoOperation {
 
 impl Transaction {
     /// Creates a new transaction with the given ID and an initial state of `Active`.
-    #[must_use] pub const fn new(id: TransactionId) -> Self {
+    #[must_use] pub fn new(id: TransactionId) -> Self {
         // Changed id type from u64
         Self {
             id,
@@ -76,6 +173,11 @@ impl Transaction {
             prev_lsn: INVALID_LSN, // Initialize prev_lsn with an invalid LSN
             undo_log: Vec::new(),
             redo_log: Vec::new(), // Initialize redo_log
+            savepoints: Vec::new(),
+            undo_log_config: UndoLogConfig::default(),
+            undo_spill_path: None,
+            spilled_undo_count: 0,
+            on_commit: Vec::new(),
         }
     }
 
@@ -84,9 +186,181 @@ impl Transaction {
         self.state = state;
     }
 
+    /// Registers `f` to run once this transaction's commit record is
+    /// durably flushed to the WAL, after everything it did becomes visible.
+    /// Hooks run in registration order; if the transaction is rolled back
+    /// instead, they're silently dropped without running.
+    ///
+    /// Useful for cache invalidation, index maintenance, or external
+    /// notifications that need to happen exactly once, and only once the
+    /// transaction is guaranteed durable.
+    pub fn on_commit(&mut self, f: Box<dyn FnOnce() + Send>) {
+        self.on_commit.push(f);
+    }
+
+    /// Drains this transaction's registered commit hooks, for the commit
+    /// path to invoke after its flush succeeds.
+    pub(crate) fn take_commit_hooks(&mut self) -> Vec<Box<dyn FnOnce() + Send>> {
+        std::mem::take(&mut self.on_commit)
+    }
+
+    /// Enables undo-log spilling for this transaction using `config`. Called
+    /// by [`crate::core::transaction::manager::TransactionManager`] right
+    /// after `Transaction::new` for the transactions it tracks; throwaway
+    /// `Transaction`s built elsewhere (e.g. as a handle for a single store
+    /// operation) keep the default, spill-disabled config, which is fine
+    /// since their undo logs are never populated.
+    pub fn configure_undo_log(&mut self, config: UndoLogConfig) {
+        self.undo_log_config = config;
+    }
+
+    /// Total number of undo operations recorded for this transaction,
+    /// in-memory and spilled-to-disk combined.
+    #[must_use]
+    pub fn undo_log_len(&self) -> usize {
+        self.spilled_undo_count + self.undo_log.len()
+    }
+
     /// Adds an undo operation to the transaction's undo log.
+    ///
+    /// Before appending, coalesces against whatever's already recorded for
+    /// the same key (or index + key) since the most recent open savepoint:
+    /// a `RevertUpdate`/`IndexRevertUpdate` for a key that already has a
+    /// `RevertInsert` or `RevertUpdate` in that window needs no new entry -
+    /// the earliest recorded pre-image is already the correct target for
+    /// both a full rollback and a `ROLLBACK TO` of the current segment.
+    /// `IndexRevertUpdate` is the one case that folds rather than drops: its
+    /// `new_value_for_index` still needs to track the most recently indexed
+    /// value so undo removes the posting that's actually there.
+    ///
+    /// Search and spill are both scoped to avoid disturbing
+    /// `savepoints`, which records `(name, undo_log.len())` marks that a
+    /// `ROLLBACK TO` indexes into by position.
     pub fn add_undo_operation(&mut self, op: UndoOperation) {
+        let floor = self.savepoints.last().map_or(0, |(_, mark)| *mark);
+        match &op {
+            UndoOperation::RevertUpdate { key, .. } => {
+                let has_pre_image = self.undo_log[floor..].iter().any(|existing| {
+                    matches!(
+                        existing,
+                        UndoOperation::RevertInsert { key: k }
+                            | UndoOperation::RevertUpdate { key: k, .. }
+                        if k == key
+                    )
+                });
+                if has_pre_image {
+                    return;
+                }
+            }
+            UndoOperation::IndexRevertUpdate { index_name, key, new_value_for_index, .. } => {
+                let existing = self.undo_log[floor..].iter_mut().find(|existing| {
+                    matches!(
+                        existing,
+                        UndoOperation::IndexRevertUpdate { index_name: n, key: k, .. }
+                        if n == index_name && k == key
+                    )
+                });
+                if let Some(UndoOperation::IndexRevertUpdate { new_value_for_index: slot, .. }) =
+                    existing
+                {
+                    *slot = new_value_for_index.clone();
+                    return;
+                }
+            }
+            _ => {}
+        }
         self.undo_log.push(op);
+        self.spill_overflow_if_needed();
+    }
+
+    /// Moves the oldest in-memory undo operations out to `undo_spill_path`
+    /// once the log grows past `undo_log_config.max_in_memory_ops`.
+    ///
+    /// Only runs with no open savepoints: `ROLLBACK TO` slices `undo_log` by
+    /// a position captured when the savepoint was set, and spilling would
+    /// silently invalidate those positions. The hot-key bulk operations this
+    /// exists for typically don't hold a savepoint open anyway.
+    fn spill_overflow_if_needed(&mut self) {
+        if !self.savepoints.is_empty() {
+            return;
+        }
+        let Some(spill_dir) = self.undo_log_config.spill_dir.clone() else { return };
+        if self.undo_log.len() <= self.undo_log_config.max_in_memory_ops {
+            return;
+        }
+        let overflow = self.undo_log.len() - self.undo_log_config.max_in_memory_ops;
+        let batch: Vec<UndoOperation> = self.undo_log.drain(..overflow).collect();
+        match self.append_spill_batch(&spill_dir, &batch) {
+            Ok(()) => self.spilled_undo_count += batch.len(),
+            Err(_) => {
+                // Spilling is a best-effort memory optimization, not a
+                // durability guarantee: if the write fails, keep the batch
+                // in memory rather than losing rollback correctness.
+                let mut restored = batch;
+                restored.extend(std::mem::take(&mut self.undo_log));
+                self.undo_log = restored;
+            }
+        }
+    }
+
+    fn spill_file_path(&mut self, spill_dir: &std::path::Path) -> PathBuf {
+        self.undo_spill_path
+            .get_or_insert_with(|| spill_dir.join(format!("undo_tx_{}.spill", self.id.0)))
+            .clone()
+    }
+
+    fn append_spill_batch(
+        &mut self,
+        spill_dir: &std::path::Path,
+        batch: &[UndoOperation],
+    ) -> std::io::Result<()> {
+        let path = self.spill_file_path(spill_dir);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut file = std::fs::OpenOptions::new().create(true).append(true).open(&path)?;
+        for op in batch {
+            let bytes = bincode::serialize(op).map_err(|e| {
+                std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string())
+            })?;
+            let len = u32::try_from(bytes.len()).map_err(|_| {
+                std::io::Error::new(std::io::ErrorKind::InvalidData, "undo record too large")
+            })?;
+            file.write_all(&len.to_be_bytes())?;
+            file.write_all(&bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Streams any spilled undo operations back in ahead of `undo_log`'s
+    /// in-memory tail and deletes the spill file, so a full-transaction
+    /// rollback replays the complete log. A no-op if nothing was ever
+    /// spilled.
+    ///
+    /// # Errors
+    /// Returns an I/O error if the spill file exists but can't be read.
+    pub fn restore_spilled_undo_log(&mut self) -> std::io::Result<()> {
+        let Some(path) = self.undo_spill_path.take() else { return Ok(()) };
+        if !path.exists() {
+            self.spilled_undo_count = 0;
+            return Ok(());
+        }
+        let bytes = std::fs::read(&path)?;
+        let mut spilled = Vec::with_capacity(self.spilled_undo_count);
+        let mut cursor = 0usize;
+        while cursor + 4 <= bytes.len() {
+            let len = u32::from_be_bytes(bytes[cursor..cursor + 4].try_into().unwrap()) as usize;
+            cursor += 4;
+            let op: UndoOperation = bincode::deserialize(&bytes[cursor..cursor + len])
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string()))?;
+            spilled.push(op);
+            cursor += len;
+        }
+        spilled.extend(std::mem::take(&mut self.undo_log));
+        self.undo_log = spilled;
+        self.spilled_undo_count = 0;
+        let _ = std::fs::remove_file(&path);
+        Ok(())
     }
 
     /// Clones the transaction for storage operations, excluding logs.
@@ -98,6 +372,11 @@ impl Transaction {
             prev_lsn: self.prev_lsn,   // Clone prev_lsn
             undo_log: Vec::new(),
             redo_log: Vec::new(),
+            savepoints: Vec::new(),
+            undo_log_config: UndoLogConfig::default(),
+            undo_spill_path: None,
+            spilled_undo_count: 0,
+            on_commit: Vec::new(),
         }
     }
 }
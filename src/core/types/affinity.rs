@@ -0,0 +1,128 @@
+// src/core/types/affinity.rs
+//! Column type affinity, modeled on SQLite's dynamic typing.
+//!
+//! OxiDB's storage layer is strongly typed (every `DataType` variant is a
+//! distinct Rust type), but a declared column type is still only a
+//! *preference* for how values inserted into it should be stored, not a hard
+//! schema. This module resolves a column's declared type to one of the five
+//! SQLite affinities and applies the matching coercion at insert/update time.
+
+use super::{DataType, OrderedFloat};
+
+/// One of SQLite's five type affinities, assigned per column from its
+/// declared type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Affinity {
+    Integer,
+    Text,
+    Blob,
+    Real,
+    Numeric,
+}
+
+impl Affinity {
+    /// Resolves the affinity for a declared type name (e.g. `"VARCHAR(10)"`,
+    /// `"NUMERIC(5,2)"`), following SQLite's rules in order: the first
+    /// matching substring wins.
+    #[must_use]
+    pub fn from_declared_type(declared_type: &str) -> Self {
+        let upper = declared_type.to_uppercase();
+        if upper.contains("INT") {
+            Self::Integer
+        } else if upper.contains("CHAR") || upper.contains("CLOB") || upper.contains("TEXT") {
+            Self::Text
+        } else if upper.contains("BLOB") || upper.is_empty() {
+            Self::Blob
+        } else if upper.contains("REAL") || upper.contains("FLOA") || upper.contains("DOUB") {
+            Self::Real
+        } else {
+            Self::Numeric
+        }
+    }
+
+    /// Resolves the affinity of a column from the representative `DataType`
+    /// stored in its schema entry. OxiDB's `ColumnDef::data_type` doubles as
+    /// the declared type, so this plays the same role as
+    /// [`Self::from_declared_type`] for columns created through the SQL
+    /// front end. `BOOLEAN` has no SQLite equivalent; it is treated as
+    /// `NUMERIC`, matching SQLite's own convention for the type.
+    #[must_use]
+    pub fn of_data_type(data_type: &DataType) -> Self {
+        match data_type {
+            DataType::Integer(_) => Self::Integer,
+            DataType::String(_) | DataType::Enum { .. } => Self::Text,
+            DataType::Float(_) => Self::Real,
+            DataType::Boolean(_) | DataType::Decimal { .. } => Self::Numeric,
+            DataType::RawBytes(_)
+            | DataType::Null
+            | DataType::Map(_)
+            | DataType::JsonBlob(_)
+            | DataType::Vector(_) => Self::Blob,
+        }
+    }
+
+    /// Applies this affinity to `value`, coercing it the way SQLite coerces a
+    /// value on insert/update. A value that can't be losslessly coerced is
+    /// returned unchanged, in its original class, rather than silently
+    /// zeroed.
+    #[must_use]
+    pub fn coerce(self, value: DataType) -> DataType {
+        match self {
+            Self::Text => Self::coerce_text(value),
+            Self::Blob => value,
+            Self::Integer => Self::coerce_integer(value),
+            Self::Real => Self::coerce_real(value),
+            Self::Numeric => Self::coerce_numeric(value),
+        }
+    }
+
+    fn coerce_text(value: DataType) -> DataType {
+        match value {
+            DataType::Integer(i) => DataType::String(i.to_string()),
+            DataType::Float(f) => DataType::String(f.0.to_string()),
+            DataType::Boolean(b) => DataType::String(b.to_string()),
+            other => other,
+        }
+    }
+
+    fn coerce_integer(value: DataType) -> DataType {
+        match value {
+            DataType::String(ref s) => Self::parse_numeric_string(s).unwrap_or(value),
+            DataType::Float(f) if f.0.fract() == 0.0 => DataType::Integer(f.0 as i64),
+            other => other,
+        }
+    }
+
+    fn coerce_real(value: DataType) -> DataType {
+        match value {
+            DataType::String(ref s) => {
+                s.parse::<f64>().map(|f| DataType::Float(OrderedFloat(f))).unwrap_or(value)
+            }
+            other => other,
+        }
+    }
+
+    fn coerce_numeric(value: DataType) -> DataType {
+        match value {
+            DataType::String(ref s) => Self::parse_numeric_string(s).unwrap_or(value),
+            DataType::Float(f) if f.0.fract() == 0.0 => DataType::Integer(f.0 as i64),
+            other => other,
+        }
+    }
+
+    /// Parses a string as an integer if possible, otherwise as a float that
+    /// collapses to an integer when it has no fractional part, matching
+    /// INTEGER/NUMERIC affinity's "prefer integer" rule.
+    fn parse_numeric_string(s: &str) -> Option<DataType> {
+        if let Ok(i) = s.parse::<i64>() {
+            return Some(DataType::Integer(i));
+        }
+        s.parse::<f64>().map(|f| {
+            if f.fract() == 0.0 {
+                DataType::Integer(f as i64)
+            } else {
+                DataType::Float(OrderedFloat(f))
+            }
+        })
+    }
+}
@@ -0,0 +1,125 @@
+// src/core/types/decimal.rs
+//! Exact-precision `NUMERIC(precision, scale)` / `DECIMAL(precision, scale)`
+//! support.
+//!
+//! Values are stored as `DataType::Decimal { unscaled, .. }`, an integer
+//! scaled by `10^scale`, so arithmetic and comparisons never go through
+//! `f64` and can't pick up rounding error. [`coerce_decimal`] parses a
+//! string or numeric literal, rounds the fractional part half-up to `scale`
+//! digits, and rejects values whose integer part would need more than
+//! `precision - scale` digits.
+
+use crate::core::common::OxidbError;
+use crate::core::types::DataType;
+
+/// Parses `value` into a `DataType::Decimal` scaled for a
+/// `NUMERIC(precision, scale)` column.
+pub fn coerce_decimal(value: &DataType, precision: u32, scale: u32) -> Result<DataType, OxidbError> {
+    if let DataType::Decimal { unscaled, scale: src_scale, .. } = value {
+        let negative = *unscaled < 0;
+        let magnitude = rescale_magnitude(unscaled.unsigned_abs(), *src_scale, scale);
+        return finish(magnitude, negative, precision, scale, &format!("{unscaled}e-{src_scale}"));
+    }
+
+    let text = match value {
+        DataType::String(s) => s.trim().to_string(),
+        DataType::Integer(i) => i.to_string(),
+        DataType::Float(f) => f.0.to_string(),
+        other => {
+            return Err(OxidbError::Type(format!(
+                "Cannot convert {other:?} to NUMERIC({precision},{scale})"
+            )))
+        }
+    };
+
+    let (negative, body) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(&text)),
+    };
+
+    let mut parts = body.splitn(2, '.');
+    let int_str = parts.next().unwrap_or("");
+    let frac_str = parts.next().unwrap_or("");
+    let is_valid_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    if (int_str.is_empty() && frac_str.is_empty())
+        || !is_valid_digits(int_str)
+        || !is_valid_digits(frac_str)
+    {
+        return Err(OxidbError::Type(format!(
+            "'{text}' is not a valid NUMERIC({precision},{scale}) value"
+        )));
+    }
+
+    let combined = format!("{}{}", if int_str.is_empty() { "0" } else { int_str }, frac_str);
+    let raw: u128 = combined.parse().map_err(|_| {
+        OxidbError::Type(format!("'{text}' is not a valid NUMERIC({precision},{scale}) value"))
+    })?;
+
+    let magnitude = rescale_magnitude(raw, frac_str.len() as u32, scale);
+    finish(magnitude, negative, precision, scale, &text)
+}
+
+fn finish(
+    magnitude: u128,
+    negative: bool,
+    precision: u32,
+    scale: u32,
+    original_text: &str,
+) -> Result<DataType, OxidbError> {
+    let int_digit_count = {
+        let divisor = 10u128.pow(scale);
+        let int_part = magnitude / divisor;
+        if int_part == 0 { 0 } else { int_part.to_string().len() as u32 }
+    };
+    let max_int_digits = precision.saturating_sub(scale);
+    if int_digit_count > max_int_digits {
+        return Err(OxidbError::ConstraintViolation(format!(
+            "Value '{original_text}' exceeds NUMERIC({precision},{scale}): \
+             {int_digit_count} integer digits, only {max_int_digits} allowed"
+        )));
+    }
+
+    let unscaled = i128::try_from(magnitude).map_err(|_| {
+        OxidbError::ConstraintViolation(format!(
+            "Value '{original_text}' exceeds NUMERIC({precision},{scale})"
+        ))
+    })?;
+    Ok(DataType::Decimal { unscaled: if negative { -unscaled } else { unscaled }, precision, scale })
+}
+
+/// Rescales an unsigned magnitude from `from_scale` to `to_scale`, rounding
+/// half-up when narrowing the scale.
+fn rescale_magnitude(raw: u128, from_scale: u32, to_scale: u32) -> u128 {
+    if from_scale == to_scale {
+        raw
+    } else if from_scale > to_scale {
+        let divisor = 10u128.pow(from_scale - to_scale);
+        (raw + divisor / 2) / divisor
+    } else {
+        raw * 10u128.pow(to_scale - from_scale)
+    }
+}
+
+/// Renders a `Decimal` as its canonical `"123.45"` string, for contexts (like
+/// SQL literal translation) that need a textual form.
+#[must_use]
+pub fn format_decimal(unscaled: i128, scale: u32) -> String {
+    if scale == 0 {
+        return unscaled.to_string();
+    }
+    let negative = unscaled < 0;
+    let magnitude = unscaled.unsigned_abs();
+    let divisor = 10u128.pow(scale);
+    let int_part = magnitude / divisor;
+    let frac_part = magnitude % divisor;
+    format!("{}{}.{:0width$}", if negative { "-" } else { "" }, int_part, frac_part, width = scale as usize)
+}
+
+/// Converts a `Decimal` to `f64`, for ordered comparisons against other
+/// numeric types. Storage and equality never use this path.
+#[must_use]
+pub fn decimal_to_f64(unscaled: i128, scale: u32) -> f64 {
+    #[allow(clippy::cast_precision_loss)]
+    let value = unscaled as f64;
+    value / 10f64.powi(scale as i32)
+}
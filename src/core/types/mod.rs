@@ -13,6 +13,8 @@ pub use crate::core::common::types::{
     ColumnDef, DataType as CommonDataType, Lsn, Row, Schema, Value,
 };
 
+pub mod affinity;
+pub mod decimal;
 pub mod schema;
 
 // Re-export the modules for direct access if needed
@@ -88,11 +90,19 @@ pub enum DataType {
     String(String),
     Boolean(bool),
     Float(OrderedFloat),       // Added Float variant with ordering
+    /// Exact fixed-point `NUMERIC(precision, scale)` / `DECIMAL(precision,
+    /// scale)` value: `unscaled` holds the value multiplied by `10^scale`,
+    /// so arithmetic and comparisons never pass through `f64`.
+    Decimal { unscaled: i128, precision: u32, scale: u32 },
     Null,             // Added Null variant
     Map(JsonSafeMap), // Changed to use JsonSafeMap
     JsonBlob(JsonValue),
     RawBytes(Vec<u8>), // Added RawBytes variant
     Vector(HashableVectorData), // Added Vector variant
+    /// A value of a user-defined `CREATE TYPE ... AS ENUM (...)` type: `type_name`
+    /// names the registered enum (see `QueryExecutor`'s enum-type catalog) and
+    /// `value` is one of its registered variants.
+    Enum { type_name: String, value: String },
                        // Potentially other types like Timestamp, etc. could be added later
 }
 
@@ -491,6 +501,7 @@ impl DataType {
             Self::JsonBlob(_) => "JsonBlob",
             Self::RawBytes(_) => "RawBytes",
             Self::Vector(_) => "Vector",
+            Self::Decimal { .. } => "Decimal",
         }
     }
 }
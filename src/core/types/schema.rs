@@ -1,4 +1,6 @@
 // src/core/types/schema.rs
+use crate::core::common::OxidbError;
+use crate::core::types::affinity::Affinity;
 use crate::core::types::DataType;
 use serde::{Deserialize, Serialize};
 
@@ -10,9 +12,58 @@ pub struct ColumnDef {
     pub is_unique: bool,
     pub is_nullable: bool,
     pub is_auto_increment: bool,
+    /// `Some(n)` for a `VARCHAR(n)`/`CHAR(n)` column; `None` for an
+    /// unbounded `TEXT` or a `VARCHAR`/`CHAR` with no length given.
+    pub max_length: Option<u32>,
+    /// `true` for `CHAR(n)`: values are right-padded with spaces to exactly
+    /// `n` characters on read. `false` for `VARCHAR(n)`/`TEXT`.
+    pub is_fixed_length: bool,
+    /// `true` to silently truncate an over-length value to `max_length`
+    /// (the `TRUNCATE` column constraint, SQLite-compatible). `false` (the
+    /// default) rejects it with a constraint violation.
+    pub truncate_overflow: bool,
     // Add other constraints like default_value later
 }
 
+impl ColumnDef {
+    /// The SQLite-style type affinity this column coerces inserted/updated
+    /// values to, derived from its declared `data_type`.
+    #[must_use]
+    pub fn affinity(&self) -> Affinity {
+        Affinity::of_data_type(&self.data_type)
+    }
+
+    /// Enforces this column's `VARCHAR(n)`/`CHAR(n)` length constraint on an
+    /// already affinity-coerced value: truncates (if `truncate_overflow`) or
+    /// rejects an over-length string, and right-pads a `CHAR(n)` value
+    /// shorter than `n`. Non-string values and unbounded columns pass
+    /// through unchanged.
+    pub fn enforce_length(&self, value: DataType) -> Result<DataType, OxidbError> {
+        let Some(max_len) = self.max_length else { return Ok(value) };
+        let DataType::String(s) = value else { return Ok(value) };
+        let max_len = max_len as usize;
+        let char_count = s.chars().count();
+
+        if char_count > max_len {
+            if self.truncate_overflow {
+                Ok(DataType::String(s.chars().take(max_len).collect()))
+            } else {
+                Err(OxidbError::ConstraintViolation(format!(
+                    "Value '{s}' ({char_count} chars) exceeds {}({max_len}) for column '{}'",
+                    if self.is_fixed_length { "CHAR" } else { "VARCHAR" },
+                    self.name
+                )))
+            }
+        } else if self.is_fixed_length && char_count < max_len {
+            let mut padded = s;
+            padded.extend(std::iter::repeat(' ').take(max_len - char_count));
+            Ok(DataType::String(padded))
+        } else {
+            Ok(DataType::String(s))
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct Schema {
     pub columns: Vec<ColumnDef>,
@@ -35,6 +86,9 @@ impl Schema {
             is_unique: false,
             is_nullable: true,        // Default to nullable
             is_auto_increment: false, // Default to no auto-increment
+            max_length: None,
+            is_fixed_length: false,
+            truncate_overflow: false,
         }
     }
 
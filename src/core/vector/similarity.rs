@@ -106,6 +106,25 @@ pub fn euclidean_distance(v1: &[f32], v2: &[f32]) -> Result<f32, OxidbError> {
     Ok(sum_sq_diff.sqrt())
 }
 
+/// Calculates the Manhattan (L1) distance between two vectors.
+///
+/// # Arguments
+///
+/// * `v1` - A slice of f32 representing the first vector.
+/// * `v2` - A slice of f32 representing the second vector.
+///
+/// # Returns
+///
+/// * `Result<f32, OxidbError>` - The Manhattan distance between the two vectors, or an error
+///   if the vectors have different dimensions.
+pub fn manhattan_distance(v1: &[f32], v2: &[f32]) -> Result<f32, OxidbError> {
+    if v1.len() != v2.len() {
+        return Err(OxidbError::VectorDimensionMismatch { dim1: v1.len(), dim2: v2.len() });
+    }
+
+    Ok(v1.iter().zip(v2.iter()).map(|(a, b)| (a - b).abs()).sum())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -212,4 +231,27 @@ mod tests {
             _ => panic!("Expected VectorMagnitudeZero for empty vectors or specific handling"),
         }
     }
+
+    #[test]
+    fn test_manhattan_distance_success() {
+        let v1 = vec![1.0, 2.0, 3.0];
+        let v2 = vec![4.0, 0.0, -3.0];
+        assert_relative_eq!(manhattan_distance(&v1, &v2).unwrap(), 3.0 + 2.0 + 6.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_manhattan_distance_identical_vectors() {
+        let v1 = vec![1.0, 2.0, 3.0];
+        assert_relative_eq!(manhattan_distance(&v1, &v1).unwrap(), 0.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_manhattan_distance_dimension_mismatch() {
+        let v1 = vec![1.0, 2.0];
+        let v2 = vec![1.0];
+        match manhattan_distance(&v1, &v2) {
+            Err(OxidbError::VectorDimensionMismatch { dim1: 2, dim2: 1 }) => {}
+            _ => panic!("Expected VectorDimensionMismatch"),
+        }
+    }
 }
@@ -93,6 +93,15 @@ pub enum LogRecord {
         dirty_pages: Vec<DirtyPageInfo>,
         // checkpoint_start_lsn: Lsn, // Reference to the CheckpointBegin LSN
     },
+    /// Marks a named savepoint within a transaction, so a later `ROLLBACK TO name`
+    /// can be recovered after a crash: the Undo phase walks the `prev_lsn` chain back
+    /// only as far as this record's `lsn`, instead of undoing the whole transaction.
+    Savepoint {
+        lsn: Lsn,
+        tx_id: TransactionId,
+        name: String,
+        prev_lsn: Lsn,
+    },
 }
 
 #[cfg(test)]
@@ -251,6 +260,19 @@ mod tests {
         let deserialized: LogRecord = bincode::deserialize(&mut serialized.as_slice()).unwrap();
         assert_eq!(original_record, deserialized);
     }
+
+    #[test]
+    fn test_serialize_deserialize_savepoint() {
+        let original_record = LogRecord::Savepoint {
+            lsn: 11,
+            tx_id: TransactionId(1),
+            name: "sp1".to_string(),
+            prev_lsn: 10,
+        };
+        let serialized = bincode::serialize_to_vec(&original_record).unwrap();
+        let deserialized: LogRecord = bincode::deserialize(&mut serialized.as_slice()).unwrap();
+        assert_eq!(original_record, deserialized);
+    }
 }
 
 // Manual implementations of Serialize and Deserialize for our types
@@ -387,6 +409,13 @@ impl Serialize for LogRecord {
                 active_transactions.serialize(writer)?;
                 dirty_pages.serialize(writer)?;
             }
+            LogRecord::Savepoint { lsn, tx_id, name, prev_lsn } => {
+                10u8.serialize(writer)?;
+                lsn.serialize(writer)?;
+                tx_id.serialize(writer)?;
+                name.serialize(writer)?;
+                prev_lsn.serialize(writer)?;
+            }
         }
         Ok(())
     }
@@ -459,6 +488,12 @@ impl Deserialize for LogRecord {
                 active_transactions: Vec::<ActiveTransactionInfo>::deserialize(reader)?,
                 dirty_pages: Vec::<DirtyPageInfo>::deserialize(reader)?,
             }),
+            10 => Ok(LogRecord::Savepoint {
+                lsn: Lsn::deserialize(reader)?,
+                tx_id: TransactionId::deserialize(reader)?,
+                name: String::deserialize(reader)?,
+                prev_lsn: Lsn::deserialize(reader)?,
+            }),
             n => Err(OxidbError::Serialization(format!("Invalid LogRecord variant: {}", n))),
         }
     }
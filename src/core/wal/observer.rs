@@ -0,0 +1,203 @@
+//! Transaction observation service: lets application code subscribe to
+//! committed-change notifications derived from a transaction's WAL records,
+//! the foundation for things like materialized-view refresh, change-data-capture
+//! streams, or cache coherency across connections.
+
+use super::log_record::LogRecord;
+use crate::core::common::types::ids::PageId;
+use crate::core::common::types::{Lsn, TransactionId};
+use std::collections::HashSet;
+
+/// Everything observable about one transaction's commit: which transaction,
+/// at what LSN, and what it touched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxReport {
+    /// The transaction that committed.
+    pub tx_id: TransactionId,
+    /// The LSN of its `CommitTransaction` record.
+    pub commit_lsn: Lsn,
+    /// Pages touched by the transaction's `Insert`/`Delete`/`Update`/`NewPage`
+    /// records.
+    pub pages: HashSet<PageId>,
+    /// Table names affected by the transaction. Always empty for now:
+    /// `LogRecord`'s `InsertRecord`/`DeleteRecord`/`UpdateRecord`/`NewPage`
+    /// variants carry a `PageId`, not a table name, and nothing in the WAL
+    /// layer currently maps a page back to the table that owns it. Populate
+    /// this once that mapping exists (e.g. from the catalog) instead of
+    /// guessing here.
+    pub tables: HashSet<String>,
+}
+
+impl TxReport {
+    /// Builds a report for `tx_id`'s commit at `commit_lsn` by scanning
+    /// `records` for the `Insert`/`Delete`/`Update`/`NewPage` entries that
+    /// belong to it and collecting their page ids.
+    #[must_use]
+    pub fn from_records(tx_id: TransactionId, commit_lsn: Lsn, records: &[LogRecord]) -> Self {
+        let mut pages = HashSet::new();
+        for record in records {
+            let (record_tx_id, page_id) = match record {
+                LogRecord::InsertRecord { tx_id, page_id, .. }
+                | LogRecord::DeleteRecord { tx_id, page_id, .. }
+                | LogRecord::UpdateRecord { tx_id, page_id, .. }
+                | LogRecord::NewPage { tx_id, page_id, .. } => (*tx_id, *page_id),
+                _ => continue,
+            };
+            if record_tx_id == tx_id {
+                pages.insert(page_id);
+            }
+        }
+        Self { tx_id, commit_lsn, pages, tables: HashSet::new() }
+    }
+}
+
+/// Subscribes to committed-change notifications. Implementations decide for
+/// themselves, by inspecting the delivered [`TxReport`], whether a commit
+/// touched the table or page they care about.
+pub trait TxObserver: Send + Sync {
+    /// Called once for every transaction commit, after it's durable.
+    fn on_commit(&self, report: &TxReport);
+}
+
+/// Identifies a registered [`TxObserver`], returned by
+/// [`TxObserverRegistry::register`] so it can later be passed to
+/// [`TxObserverRegistry::deregister`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TxObserverId(u64);
+
+struct RegisteredObserver {
+    id: TxObserverId,
+    observer: Box<dyn TxObserver>,
+}
+
+impl std::fmt::Debug for RegisteredObserver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RegisteredObserver").field("id", &self.id).finish_non_exhaustive()
+    }
+}
+
+/// Registry of [`TxObserver`]s notified after each transaction commits.
+#[derive(Debug, Default)]
+pub struct TxObserverRegistry {
+    observers: Vec<RegisteredObserver>,
+    next_id: u64,
+}
+
+impl TxObserverRegistry {
+    /// Creates an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `observer`, returning an id that can later be passed to
+    /// [`Self::deregister`].
+    pub fn register(&mut self, observer: Box<dyn TxObserver>) -> TxObserverId {
+        let id = TxObserverId(self.next_id);
+        self.next_id += 1;
+        self.observers.push(RegisteredObserver { id, observer });
+        id
+    }
+
+    /// Removes a previously registered observer. Returns `false` if `id`
+    /// isn't currently registered.
+    pub fn deregister(&mut self, id: TxObserverId) -> bool {
+        let len_before = self.observers.len();
+        self.observers.retain(|registered| registered.id != id);
+        self.observers.len() != len_before
+    }
+
+    /// `true` if no observer is registered - callers can skip building a
+    /// `TxReport` entirely in that case.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.observers.is_empty()
+    }
+
+    /// Delivers `report` to every registered observer, in registration order.
+    pub fn notify(&self, report: &TxReport) {
+        for registered in &self.observers {
+            registered.observer.on_commit(report);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::common::types::ids::SlotId;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_from_records_collects_only_this_transactions_pages() {
+        let tx_id = TransactionId(1);
+        let other_tx_id = TransactionId(2);
+        let records = vec![
+            LogRecord::BeginTransaction { lsn: 1, tx_id },
+            LogRecord::InsertRecord {
+                lsn: 2,
+                tx_id,
+                page_id: PageId(10),
+                slot_id: SlotId(0),
+                record_data: vec![1],
+                prev_lsn: 1,
+            },
+            LogRecord::InsertRecord {
+                lsn: 3,
+                tx_id: other_tx_id,
+                page_id: PageId(99),
+                slot_id: SlotId(0),
+                record_data: vec![2],
+                prev_lsn: 0,
+            },
+            LogRecord::UpdateRecord {
+                lsn: 4,
+                tx_id,
+                page_id: PageId(11),
+                slot_id: SlotId(1),
+                old_record_data: vec![1],
+                new_record_data: vec![3],
+                prev_lsn: 2,
+            },
+        ];
+
+        let report = TxReport::from_records(tx_id, 5, &records);
+
+        assert_eq!(report.tx_id, tx_id);
+        assert_eq!(report.commit_lsn, 5);
+        assert_eq!(report.pages, [PageId(10), PageId(11)].into_iter().collect());
+        assert!(report.tables.is_empty());
+    }
+
+    #[test]
+    fn test_registry_register_notify_deregister() {
+        let mut registry = TxObserverRegistry::new();
+        assert!(registry.is_empty());
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        struct CountingObserver(Arc<AtomicUsize>);
+        impl TxObserver for CountingObserver {
+            fn on_commit(&self, _report: &TxReport) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let id = registry.register(Box::new(CountingObserver(calls.clone())));
+        assert!(!registry.is_empty());
+
+        let report = TxReport {
+            tx_id: TransactionId(1),
+            commit_lsn: 1,
+            pages: HashSet::new(),
+            tables: HashSet::new(),
+        };
+        registry.notify(&report);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        assert!(registry.deregister(id));
+        registry.notify(&report);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(!registry.deregister(id));
+    }
+}
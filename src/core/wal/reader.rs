@@ -11,7 +11,7 @@
 
 use crate::core::common::bincode_compat as bincode;
 use std::fs::File;
-use std::io::{BufReader, Error as IoError, ErrorKind as IoErrorKind, Read};
+use std::io::{BufReader, Error as IoError, ErrorKind as IoErrorKind, Read, Seek, SeekFrom};
 use std::path::{Path, PathBuf};
 
 use crate::core::common::types::Lsn;
@@ -24,6 +24,11 @@ pub struct WalReaderConfig {
     pub buffer_size: usize,
     /// Whether to validate LSN ordering during reading
     pub validate_lsn_ordering: bool,
+    /// How many completed checkpoints `WalReader::checkpoints` keeps in its returned
+    /// history, newest last. Older ones are simply dropped from that result - the WAL
+    /// file itself is untouched, so they can still be found individually with
+    /// `find_checkpoint_offset` until the file is truncated or archived.
+    pub max_checkpoints_retained: usize,
 }
 
 impl Default for WalReaderConfig {
@@ -31,6 +36,7 @@ impl Default for WalReaderConfig {
         Self {
             buffer_size: 8192, // 8KB buffer
             validate_lsn_ordering: true,
+            max_checkpoints_retained: 16,
         }
     }
 }
@@ -63,6 +69,12 @@ pub struct WalRecordIterator {
     config: WalReaderConfig,
     last_lsn: Option<Lsn>,
     records_read: usize,
+    /// Byte offset (from the start of the file) of the next unread record.
+    offset: u64,
+    /// Byte offset of the record most recently returned by `next_record`, so a later
+    /// scan can resume exactly there via `WalReader::iter_records_from_offset` instead
+    /// of re-reading everything before it.
+    last_record_offset: u64,
 }
 
 impl WalRecordIterator {
@@ -76,6 +88,24 @@ impl WalRecordIterator {
     pub fn new<P: AsRef<Path>>(
         wal_file_path: P,
         config: WalReaderConfig,
+    ) -> Result<Self, WalReaderError> {
+        Self::starting_at(wal_file_path, config, 0)
+    }
+
+    /// Create a new WAL record iterator that starts reading at `start_offset` bytes
+    /// into the file, rather than from the beginning - used to resume a scan at a
+    /// previously recorded record boundary (see `last_record_offset`) without
+    /// re-reading everything before it.
+    ///
+    /// # Errors
+    /// Returns `WalReaderError` if:
+    /// - The WAL file does not exist at the specified path
+    /// - File permissions prevent reading or seeking the WAL file
+    /// - I/O errors occur during file opening, seeking, or buffer initialization
+    pub fn starting_at<P: AsRef<Path>>(
+        wal_file_path: P,
+        config: WalReaderConfig,
+        start_offset: u64,
     ) -> Result<Self, WalReaderError> {
         let path = wal_file_path.as_ref();
 
@@ -83,10 +113,20 @@ impl WalRecordIterator {
             return Err(WalReaderError::FileNotFound { path: path.to_string_lossy().to_string() });
         }
 
-        let file = File::open(path).map_err(WalReaderError::Io)?;
+        let mut file = File::open(path).map_err(WalReaderError::Io)?;
+        if start_offset > 0 {
+            file.seek(SeekFrom::Start(start_offset)).map_err(WalReaderError::Io)?;
+        }
         let reader = BufReader::with_capacity(config.buffer_size, file);
 
-        Ok(Self { reader, config, last_lsn: None, records_read: 0 })
+        Ok(Self {
+            reader,
+            config,
+            last_lsn: None,
+            records_read: 0,
+            offset: start_offset,
+            last_record_offset: start_offset,
+        })
     }
 
     /// Get the number of records read so far
@@ -95,6 +135,22 @@ impl WalRecordIterator {
         self.records_read
     }
 
+    /// Byte offset of the record most recently returned by `next_record`. Feed this to
+    /// `WalReader::iter_records_from_offset` to resume a later scan exactly at that
+    /// record without re-reading the file from the start.
+    #[must_use]
+    pub const fn last_record_offset(&self) -> u64 {
+        self.last_record_offset
+    }
+
+    /// Current byte offset into the file - the position the next `next_record` call
+    /// will read from. Lets a caller measure how many bytes of the log a scan has
+    /// consumed so far by comparing against its starting offset.
+    #[must_use]
+    pub const fn offset(&self) -> u64 {
+        self.offset
+    }
+
     /// Read the next log record from the WAL file
     ///
     /// # Errors
@@ -104,6 +160,8 @@ impl WalRecordIterator {
     /// - Record length prefix is invalid or corrupted
     /// - Unexpected end of file during record reading
     pub fn next_record(&mut self) -> Result<Option<LogRecord>, WalReaderError> {
+        let record_offset = self.offset;
+
         // Read the 4-byte length prefix
         let mut length_bytes = [0u8; 4];
         match self.reader.read_exact(&mut length_bytes) {
@@ -125,13 +183,22 @@ impl WalRecordIterator {
 
         // Read the serialized record data
         let mut record_data = vec![0u8; record_length as usize];
-        self.reader.read_exact(&mut record_data).map_err(|e| {
-            if e.kind() == IoErrorKind::UnexpectedEof {
-                WalReaderError::UnexpectedEof
-            } else {
-                WalReaderError::Io(e)
+        match self.reader.read_exact(&mut record_data) {
+            Ok(()) => {}
+            Err(e) if e.kind() == IoErrorKind::UnexpectedEof => {
+                // The length prefix made it to disk but the record body didn't - a
+                // process crash between `WalWriter::flush`'s two `write_all` calls
+                // leaves exactly this shape. Since the WAL is append-only, this can
+                // only happen at the very end of the file, so there's no data loss
+                // from treating it any differently than a clean EOF at a record
+                // boundary: this record never completed, so recovery should stop
+                // here and use everything before it rather than treating a torn
+                // trailing write as corruption.
+                self.reader.seek(SeekFrom::Start(record_offset)).map_err(WalReaderError::Io)?;
+                return Ok(None);
             }
-        })?;
+            Err(e) => return Err(WalReaderError::Io(e)),
+        }
 
         // Deserialize the log record
         let log_record: LogRecord = bincode::deserialize(&record_data)
@@ -151,6 +218,8 @@ impl WalRecordIterator {
             self.last_lsn = Some(current_lsn);
         }
 
+        self.offset = record_offset + 4 + u64::from(record_length);
+        self.last_record_offset = record_offset;
         self.records_read = self.records_read.saturating_add(1);
         Ok(Some(log_record))
     }
@@ -167,7 +236,8 @@ impl WalRecordIterator {
             | LogRecord::NewPage { lsn, .. }
             | LogRecord::CompensationLogRecord { lsn, .. }
             | LogRecord::CheckpointBegin { lsn, .. }
-            | LogRecord::CheckpointEnd { lsn, .. } => *lsn,
+            | LogRecord::CheckpointEnd { lsn, .. }
+            | LogRecord::Savepoint { lsn, .. } => *lsn,
         }
     }
 }
@@ -207,6 +277,20 @@ impl WalReader {
         WalRecordIterator::new(&self.wal_file_path, self.config)
     }
 
+    /// Create an iterator that starts reading `start_offset` bytes into the WAL file,
+    /// e.g. at a checkpoint record offset previously returned by
+    /// `find_last_checkpoint_with_offset` - so a caller that already knows where it
+    /// wants to resume doesn't have to stream past everything before it.
+    ///
+    /// # Errors
+    /// Returns `WalReaderError` if the WAL file cannot be opened or seeked.
+    pub fn iter_records_from_offset(
+        &self,
+        start_offset: u64,
+    ) -> Result<WalRecordIterator, WalReaderError> {
+        WalRecordIterator::starting_at(&self.wal_file_path, self.config, start_offset)
+    }
+
     /// Read all records from the WAL file into a vector
     pub fn read_all_records(&self) -> Result<Vec<LogRecord>, WalReaderError> {
         let mut records = Vec::new();
@@ -236,7 +320,8 @@ impl WalReader {
                 | LogRecord::DeleteRecord { tx_id: id, .. }
                 | LogRecord::UpdateRecord { tx_id: id, .. }
                 | LogRecord::NewPage { tx_id: id, .. }
-                | LogRecord::CompensationLogRecord { tx_id: id, .. } => Some(*id),
+                | LogRecord::CompensationLogRecord { tx_id: id, .. }
+                | LogRecord::Savepoint { tx_id: id, .. } => Some(*id),
                 LogRecord::CheckpointBegin { .. } | LogRecord::CheckpointEnd { .. } => None,
             };
 
@@ -282,6 +367,101 @@ impl WalReader {
         }
     }
 
+    /// Finds the last completed checkpoint pair, like `find_last_checkpoint`, but also
+    /// returns the byte offset of the `CheckpointEnd` record's start so a caller can
+    /// resume a forward scan there via `iter_records_from_offset` instead of restarting
+    /// from the beginning of the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WalReaderError` if the WAL file cannot be read or record parsing fails.
+    pub fn find_last_checkpoint_with_offset(
+        &self,
+    ) -> Result<Option<(Lsn, u64)>, WalReaderError> {
+        let mut checkpoint_begin_seen = false;
+        let mut last_checkpoint_end: Option<(Lsn, u64)> = None;
+        let mut iterator = self.iter_records()?;
+
+        while let Some(record) = iterator.next_record()? {
+            match &record {
+                LogRecord::CheckpointBegin { .. } => {
+                    checkpoint_begin_seen = true;
+                    last_checkpoint_end = None; // Reset end until we find the matching end
+                }
+                LogRecord::CheckpointEnd { lsn, .. } => {
+                    if checkpoint_begin_seen {
+                        last_checkpoint_end = Some((*lsn, iterator.last_record_offset()));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(last_checkpoint_end)
+    }
+
+    /// Finds every completed checkpoint `(begin_lsn, end_lsn)` pair in the WAL, oldest
+    /// first, bounded to the most recent `config.max_checkpoints_retained` pairs so
+    /// callers that keep pruning old checkpoints out of recovery consideration don't
+    /// need to re-derive the cutoff themselves. Passing one of the returned
+    /// `end_lsn`s to `find_checkpoint_offset` (and then to
+    /// `AnalysisPhase::analyze_from`) lets recovery resume from any retained
+    /// checkpoint, not just the newest one - the basis for point-in-time recovery.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WalReaderError` if the WAL file cannot be read or record parsing fails.
+    pub fn checkpoints(&self) -> Result<Vec<(Lsn, Lsn)>, WalReaderError> {
+        let retain = self.config.max_checkpoints_retained.max(1);
+        let mut pairs: std::collections::VecDeque<(Lsn, Lsn)> =
+            std::collections::VecDeque::with_capacity(retain);
+        let mut pending_begin_lsn: Option<Lsn> = None;
+        let mut iterator = self.iter_records()?;
+
+        while let Some(record) = iterator.next_record()? {
+            match &record {
+                LogRecord::CheckpointBegin { lsn } => pending_begin_lsn = Some(*lsn),
+                LogRecord::CheckpointEnd { lsn: end_lsn, .. } => {
+                    if let Some(begin_lsn) = pending_begin_lsn.take() {
+                        pairs.push_back((begin_lsn, *end_lsn));
+                        if pairs.len() > retain {
+                            pairs.pop_front();
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(pairs.into_iter().collect())
+    }
+
+    /// Finds the byte offset of the `CheckpointEnd` record whose LSN is
+    /// `checkpoint_lsn`, e.g. one returned by `checkpoints`, so
+    /// `AnalysisPhase::analyze_from` can resume a forward scan from that specific
+    /// checkpoint instead of only ever the newest one found by
+    /// `find_last_checkpoint_with_offset`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WalReaderError` if the WAL file cannot be read or record parsing fails.
+    pub fn find_checkpoint_offset(
+        &self,
+        checkpoint_lsn: Lsn,
+    ) -> Result<Option<u64>, WalReaderError> {
+        let mut iterator = self.iter_records()?;
+
+        while let Some(record) = iterator.next_record()? {
+            if let LogRecord::CheckpointEnd { lsn, .. } = &record {
+                if *lsn == checkpoint_lsn {
+                    return Ok(Some(iterator.last_record_offset()));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
     /// Get comprehensive statistics about the WAL file.
     ///
     /// # Errors
@@ -330,6 +510,9 @@ impl WalReader {
                 LogRecord::CheckpointEnd { .. } => {
                     stats.checkpoint_end_count = stats.checkpoint_end_count.saturating_add(1);
                 }
+                LogRecord::Savepoint { .. } => {
+                    stats.savepoint_count = stats.savepoint_count.saturating_add(1);
+                }
             }
         }
 
@@ -351,6 +534,7 @@ pub struct WalStatistics {
     pub compensation_log_record_count: usize,
     pub checkpoint_begin_count: usize,
     pub checkpoint_end_count: usize,
+    pub savepoint_count: usize,
 }
 
 #[cfg(test)]
@@ -643,4 +827,183 @@ mod tests {
         let checkpoint = reader.find_last_checkpoint().expect("Failed to search for checkpoint");
         assert!(checkpoint.is_none());
     }
+
+    #[test]
+    fn test_find_last_checkpoint_with_offset_resumes_at_checkpoint_end() {
+        let temp_file = NamedTempFile::new().expect("Failed to create temp file");
+        let wal_path = temp_file.path().to_path_buf();
+
+        let records = vec![
+            LogRecord::BeginTransaction { lsn: 1, tx_id: TransactionId(100) },
+            LogRecord::CheckpointBegin { lsn: 2 },
+            LogRecord::CheckpointEnd { lsn: 3, active_transactions: vec![], dirty_pages: vec![] },
+            LogRecord::CommitTransaction { lsn: 4, tx_id: TransactionId(100), prev_lsn: 1 },
+        ];
+
+        let config = WalWriterConfig { max_buffer_size: 1000, flush_interval_ms: None };
+        let mut writer = WalWriter::new(wal_path, config);
+        for record in &records {
+            writer.add_record(record).expect("Failed to add record");
+        }
+        writer.flush().expect("Failed to flush WAL");
+
+        let reader = WalReader::with_defaults(temp_file.path());
+        let (checkpoint_lsn, offset) = reader
+            .find_last_checkpoint_with_offset()
+            .expect("Failed to find checkpoint")
+            .expect("Expected a checkpoint");
+        assert_eq!(checkpoint_lsn, 3);
+
+        // Resuming at `offset` should yield the CheckpointEnd record first, then only
+        // the records that follow it - not the BeginTransaction/CheckpointBegin before it.
+        let mut iterator = reader
+            .iter_records_from_offset(offset)
+            .expect("Failed to create iterator from offset");
+
+        let first = iterator.next_record().expect("Failed to read record").expect("Expected a record");
+        assert!(matches!(first, LogRecord::CheckpointEnd { lsn: 3, .. }));
+
+        let second = iterator.next_record().expect("Failed to read record").expect("Expected a record");
+        assert!(matches!(second, LogRecord::CommitTransaction { lsn: 4, .. }));
+
+        assert!(iterator.next_record().expect("Failed to read record").is_none());
+    }
+
+    fn write_checkpoint_pair(writer: &mut WalWriter, begin_lsn: Lsn, end_lsn: Lsn) {
+        writer
+            .add_record(&LogRecord::CheckpointBegin { lsn: begin_lsn })
+            .expect("Failed to add record");
+        writer
+            .add_record(&LogRecord::CheckpointEnd {
+                lsn: end_lsn,
+                active_transactions: vec![],
+                dirty_pages: vec![],
+            })
+            .expect("Failed to add record");
+    }
+
+    #[test]
+    fn test_checkpoints_returns_every_pair_oldest_first() {
+        let temp_file = NamedTempFile::new().expect("Failed to create temp file");
+        let config = WalWriterConfig { max_buffer_size: 1000, flush_interval_ms: None };
+        let mut writer = WalWriter::new(temp_file.path().to_path_buf(), config);
+
+        write_checkpoint_pair(&mut writer, 1, 2);
+        write_checkpoint_pair(&mut writer, 3, 4);
+        write_checkpoint_pair(&mut writer, 5, 6);
+        writer.flush().expect("Failed to flush WAL");
+
+        let reader = WalReader::with_defaults(temp_file.path());
+        let checkpoints = reader.checkpoints().expect("Failed to scan checkpoints");
+
+        assert_eq!(checkpoints, vec![(1, 2), (3, 4), (5, 6)]);
+    }
+
+    #[test]
+    fn test_checkpoints_evicts_oldest_beyond_retention_limit() {
+        let temp_file = NamedTempFile::new().expect("Failed to create temp file");
+        let config = WalWriterConfig { max_buffer_size: 1000, flush_interval_ms: None };
+        let mut writer = WalWriter::new(temp_file.path().to_path_buf(), config);
+
+        write_checkpoint_pair(&mut writer, 1, 2);
+        write_checkpoint_pair(&mut writer, 3, 4);
+        write_checkpoint_pair(&mut writer, 5, 6);
+        writer.flush().expect("Failed to flush WAL");
+
+        let reader_config =
+            WalReaderConfig { max_checkpoints_retained: 2, ..WalReaderConfig::default() };
+        let reader = WalReader::new(temp_file.path(), reader_config);
+        let checkpoints = reader.checkpoints().expect("Failed to scan checkpoints");
+
+        assert_eq!(checkpoints, vec![(3, 4), (5, 6)]);
+    }
+
+    #[test]
+    fn test_find_checkpoint_offset_locates_an_older_checkpoint() {
+        let temp_file = NamedTempFile::new().expect("Failed to create temp file");
+        let config = WalWriterConfig { max_buffer_size: 1000, flush_interval_ms: None };
+        let mut writer = WalWriter::new(temp_file.path().to_path_buf(), config);
+
+        write_checkpoint_pair(&mut writer, 1, 2);
+        write_checkpoint_pair(&mut writer, 3, 4);
+        writer.flush().expect("Failed to flush WAL");
+
+        let reader = WalReader::with_defaults(temp_file.path());
+        let offset = reader
+            .find_checkpoint_offset(2)
+            .expect("Failed to find checkpoint")
+            .expect("Expected the first checkpoint to be found");
+
+        let mut iterator = reader
+            .iter_records_from_offset(offset)
+            .expect("Failed to create iterator from offset");
+        let first = iterator.next_record().expect("Failed to read record").expect("Expected a record");
+        assert!(matches!(first, LogRecord::CheckpointEnd { lsn: 2, .. }));
+    }
+
+    #[test]
+    fn test_find_checkpoint_offset_missing_lsn_returns_none() {
+        let (temp_file, _) = create_test_wal_file(); // No checkpoints in this file
+        let reader = WalReader::with_defaults(temp_file.path());
+
+        assert!(reader.find_checkpoint_offset(42).expect("Failed to search for checkpoint").is_none());
+    }
+
+    #[test]
+    fn test_next_record_stops_cleanly_on_torn_trailing_record() {
+        let (temp_file, expected_records) = create_test_wal_file();
+        let wal_path = temp_file.path().to_path_buf();
+
+        // Replay the file to find the byte offset where the last record starts.
+        let mut iterator = WalRecordIterator::new(&wal_path, WalReaderConfig::default())
+            .expect("Failed to create iterator");
+        let mut last_start = 0u64;
+        while iterator.next_record().expect("Failed to read record").is_some() {
+            last_start = iterator.last_record_offset();
+        }
+        let full_len = std::fs::metadata(&wal_path).expect("Failed to stat WAL").len();
+
+        // Truncate partway through the last record's body - exactly the shape a crash
+        // between `WalWriter::flush`'s two `write_all` calls leaves behind.
+        let torn_len = last_start + 4 + 1;
+        assert!(
+            torn_len < full_len,
+            "test fixture's last record must have a body longer than 1 byte"
+        );
+        std::fs::OpenOptions::new()
+            .write(true)
+            .open(&wal_path)
+            .expect("Failed to open WAL for truncation")
+            .set_len(torn_len)
+            .expect("Failed to truncate WAL");
+
+        let reader = WalReader::with_defaults(&wal_path);
+        let records = reader.read_all_records().expect("a torn trailing record must not be fatal");
+
+        assert_eq!(records.len(), expected_records.len() - 1);
+        for (actual, expected) in records.iter().zip(expected_records.iter()) {
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_next_record_still_errors_on_non_tail_corruption() {
+        let (temp_file, _expected_records) = create_test_wal_file();
+        let wal_path = temp_file.path().to_path_buf();
+
+        // Corrupt bytes inside the first record's body, well before the end of the file
+        // (three more complete records follow it) - this must still be reported as real
+        // corruption rather than silently treated as a clean stopping point.
+        let mut bytes = std::fs::read(&wal_path).expect("Failed to read WAL");
+        for byte in &mut bytes[5..9] {
+            *byte ^= 0xFF;
+        }
+        std::fs::write(&wal_path, &bytes).expect("Failed to rewrite WAL");
+
+        let reader = WalReader::with_defaults(&wal_path);
+        assert!(
+            reader.read_all_records().is_err(),
+            "corrupting a byte in the middle of the file must not be silently ignored"
+        );
+    }
 }
@@ -57,6 +57,14 @@ impl WalWriter {
         Self { buffer: Vec::new(), wal_file_path, config, last_flush_time }
     }
 
+    /// The path of the WAL file this writer appends to, so callers (e.g.
+    /// startup recovery) can point a `WalReader`/`RecoveryManager` at the
+    /// same file without threading the path through separately.
+    #[must_use]
+    pub fn wal_file_path(&self) -> &std::path::Path {
+        &self.wal_file_path
+    }
+
     /// Add a log record to the buffer and optionally trigger a flush.
     ///
     /// # Errors
@@ -3,24 +3,30 @@
 // This file defines the "Skeleton" of the event handling system:
 // the data structures that represent events and their processing outcomes.
 
-/// Represents the different types of events that can occur in the system.
-/// These are placeholders and will be expanded based on actual system needs.
-#[derive(Debug, Clone, PartialEq, Eq)] // Added derive for easier testing and inspection
-pub enum Event {
-    UserCreated { user_id: String, user_email: String },
-    OrderPlaced { order_id: String, amount: u64 },
-    NotificationSent { notification_id: String, recipient: String, message_type: String },
-    // Example of a more complex event
-    DataUpdated { resource_id: String, old_value: String, new_value: String, changed_by: String },
+/// A report of a single committed transaction's changes, delivered to every
+/// matching observer registered in an `event_engine::observer::ObserverRegistry`
+/// once the transaction's writes are durable.
+///
+/// `keys_changed`, `old_values`, and `new_values` are parallel: index `i` of
+/// each describes the same key. `old_values[i]`/`new_values[i]` are `None`
+/// when the key had no value before/after the transaction (an insert has no
+/// old value; a delete has no new value).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    /// The id of the transaction whose commit produced this event.
+    pub tx_id: u64,
+    /// The keys the transaction changed, coalesced so each key appears once
+    /// even if the transaction wrote to it more than once.
+    pub keys_changed: Vec<Vec<u8>>,
+    /// Each key's value immediately before the transaction, in `keys_changed` order.
+    pub old_values: Vec<Option<Vec<u8>>>,
+    /// Each key's value immediately after the transaction, in `keys_changed` order.
+    pub new_values: Vec<Option<Vec<u8>>>,
 }
 
-/// Defines the result of processing an event.
-/// Using `anyhow::Result<()>` for now for flexible error handling.
-/// This can be replaced with a more specific error enum if needed.
+/// The result of an observer's callback processing an `Event`.
+///
+/// Using `anyhow::Result<()>` for flexible error handling: a failing
+/// observer is surfaced to whoever reads the result, but (per
+/// `ObserverRegistry::notify`) never aborts the commit that produced the event.
 pub type EventResult = anyhow::Result<()>;
-
-// Ensure Cargo.toml has anyhow dependency.
-// Read Cargo.toml.
-// If `anyhow = "1.0"` (or similar) is not in `[dependencies]`, add it.
-// For now, we assume `anyhow` might be needed and the subtask should check/add it.
-// If adding, it should be `anyhow = "1.0"`.
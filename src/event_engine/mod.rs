@@ -5,3 +5,4 @@
 
 // For now, it will have a submodule for event handling.
 pub mod handler;
+pub mod observer;
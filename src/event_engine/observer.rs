@@ -0,0 +1,103 @@
+// src/event_engine/observer.rs
+
+// The "Observer Registry" of the Event Engine: lets a caller subscribe a
+// callback, filtered by a predicate, to run whenever a transaction's writes
+// become durable.
+
+use super::handler::{Event, EventResult};
+
+/// Which committed transactions a registered observer wants to hear about.
+#[derive(Debug, Clone)]
+pub enum ObserverFilter {
+    /// Notified for every committed transaction.
+    Any,
+    /// Notified only when at least one of the transaction's changed keys
+    /// starts with this prefix (e.g. a table's key-encoding prefix).
+    KeyPrefix(Vec<u8>),
+}
+
+impl ObserverFilter {
+    fn matches(&self, event: &Event) -> bool {
+        match self {
+            Self::Any => true,
+            Self::KeyPrefix(prefix) => event.keys_changed.iter().any(|key| key.starts_with(prefix)),
+        }
+    }
+}
+
+/// Identifies a registered observer, returned by [`ObserverRegistry::register`]
+/// so it can later be passed to [`ObserverRegistry::deregister`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ObserverId(u64);
+
+struct Observer {
+    id: ObserverId,
+    filter: ObserverFilter,
+    callback: Box<dyn Fn(&Event) -> EventResult + Send + Sync>,
+}
+
+impl std::fmt::Debug for Observer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Observer").field("id", &self.id).field("filter", &self.filter).finish_non_exhaustive()
+    }
+}
+
+/// Registry of callbacks notified after a transaction's writes are durable.
+///
+/// Observers are delivered `Event`s in the order they were registered
+/// ([`Self::notify`]'s delivery-ordering guarantee), and a failing
+/// observer's error doesn't stop later observers from running or abort the
+/// commit that produced the event - its `EventResult` is simply collected
+/// for the caller to inspect.
+#[derive(Debug, Default)]
+pub struct ObserverRegistry {
+    observers: Vec<Observer>,
+    next_id: u64,
+}
+
+impl ObserverRegistry {
+    /// Creates an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `callback` to run for every commit whose event matches
+    /// `filter`, returning an id that can later be passed to [`Self::deregister`].
+    pub fn register(
+        &mut self,
+        filter: ObserverFilter,
+        callback: impl Fn(&Event) -> EventResult + Send + Sync + 'static,
+    ) -> ObserverId {
+        let id = ObserverId(self.next_id);
+        self.next_id += 1;
+        self.observers.push(Observer { id, filter, callback: Box::new(callback) });
+        id
+    }
+
+    /// Removes a previously registered observer. Returns `false` if `id`
+    /// isn't currently registered (e.g. it was already deregistered).
+    pub fn deregister(&mut self, id: ObserverId) -> bool {
+        let len_before = self.observers.len();
+        self.observers.retain(|observer| observer.id != id);
+        self.observers.len() != len_before
+    }
+
+    /// `true` if no observer is registered - callers can skip building an
+    /// `Event` entirely in that case.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.observers.is_empty()
+    }
+
+    /// Delivers `event` to every observer whose filter matches it, in
+    /// registration order, collecting each one's result rather than
+    /// stopping at the first failure.
+    pub fn notify(&self, event: &Event) -> Vec<EventResult> {
+        self.observers
+            .iter()
+            .filter(|observer| observer.filter.matches(event))
+            .map(|observer| (observer.callback)(event))
+            .collect()
+    }
+}
@@ -161,11 +161,17 @@
 //! - Optimized query execution with vectorized operations
 
 pub mod api;
+pub mod bench;
 pub mod core;
+pub mod event_engine;
 pub mod wasm;
 
 // Public API exports
-pub use api::{Connection, QueryResult, Row};
+pub use api::{
+    Aggregate, Backup, Blob, Connection, FromColumn, FunctionFlags, FunctionRegistry, OxidbPool,
+    ParamList, PoolConfig, PooledConnection, PreparedStatement, QueryResult, Row, Rows, Savepoint,
+    StepResult, ToColumn, Transaction, TransactionBehavior,
+};
 pub use crate::core::common::types::Value;
 
 // Core module exports for advanced users